@@ -0,0 +1,187 @@
+//! End-to-end transformer throughput benchmarks driven by message corpora representative of
+//! production workloads, where each corpus line is one raw WebSocket text frame.
+//!
+//! Allocations per corpus are counted with a wrapping global allocator and printed before each
+//! benchmark runs, so allocation regressions are visible alongside throughput.
+
+use barter_data::{
+    exchange::{
+        binance::{
+            book::l2::BinanceOrderBookL2Update,
+            spot::l2::{BinanceSpotBookUpdater, BinanceSpotOrderBookL2Update},
+        },
+        okx::{trade::OkxTrades, Okx},
+    },
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide},
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{book::OrderBookUpdater, stateless::StatelessTransformer, ExchangeTransformer},
+    OutboundQueue,
+};
+use barter_integration::{
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Side, SubscriptionId,
+    },
+    protocol::{
+        websocket::{WebSocketParser, WsMessage},
+        StreamParser,
+    },
+    Transformer,
+};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Burst of Binance spot BTCUSDT diff depth updates with contiguous update ids.
+const BINANCE_SPOT_DEPTH_STORM: &str = include_str!("corpus/binance_spot_depth_storm.jsonl");
+
+/// Bursts of Okx BTC-USDT trades messages, each containing one or more trades.
+const OKX_TRADE_BURSTS: &str = include_str!("corpus/okx_trade_bursts.jsonl");
+
+/// [`System`] allocator that counts every allocation.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Raw WebSocket frames of a corpus, as they would be received from the exchange.
+fn frames(corpus: &str) -> Vec<WsMessage> {
+    corpus
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| WsMessage::Text(line.to_owned()))
+        .collect()
+}
+
+/// Print the allocations per frame of a single run of the provided routine.
+fn report_allocations<F>(name: &str, frames: usize, routine: F)
+where
+    F: FnOnce(),
+{
+    let start = ALLOCATIONS.load(Ordering::Relaxed);
+    routine();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - start;
+
+    println!(
+        "{name}: {allocations} allocations over {frames} frames ({:.1} per frame)",
+        allocations as f64 / frames as f64
+    );
+}
+
+/// Deserialise & apply every Binance spot depth update frame to a local [`OrderBook`], as the
+/// [`MultiBookTransformer`](barter_data::transformer::book::MultiBookTransformer) does.
+fn binance_spot_depth(frames: Vec<WsMessage>) -> usize {
+    let first_update_id =
+        match WebSocketParser::parse::<BinanceSpotOrderBookL2Update>(Ok(frames[0].clone())) {
+            Some(Ok(BinanceOrderBookL2Update::Delta(delta))) => delta.first_update_id,
+            _ => panic!("corpus must start with a depth update"),
+        };
+
+    let mut updater = BinanceSpotBookUpdater::new(first_update_id - 1);
+    let mut book = OrderBook {
+        last_update_time: Default::default(),
+        bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+        asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        sequence: None,
+        delta: false,
+    };
+
+    frames
+        .into_iter()
+        .filter_map(|frame| WebSocketParser::parse::<BinanceSpotOrderBookL2Update>(Ok(frame)))
+        .map(|update| updater.update(&mut book, update.unwrap()).unwrap())
+        .filter(Option::is_some)
+        .count()
+}
+
+/// Deserialise & transform every Okx trades frame into normalised
+/// [`PublicTrade`](barter_data::subscription::trade::PublicTrade)s.
+fn okx_trades(
+    transformer: &mut StatelessTransformer<Okx, PublicTrades, OkxTrades>,
+    frames: Vec<WsMessage>,
+) -> usize {
+    frames
+        .into_iter()
+        .filter_map(|frame| WebSocketParser::parse::<OkxTrades>(Ok(frame)))
+        .map(|trades| transformer.transform(trades.unwrap()).len())
+        .sum()
+}
+
+fn okx_trades_transformer() -> StatelessTransformer<Okx, PublicTrades, OkxTrades> {
+    let instrument_map = Map::from_iter([(
+        SubscriptionId::from("trades|BTC-USDT"),
+        Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+    )]);
+
+    let (outbound_tx, _) = OutboundQueue::new();
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(StatelessTransformer::new(outbound_tx, instrument_map))
+        .unwrap()
+}
+
+fn bench_binance_spot_depth_storm(c: &mut Criterion) {
+    let frames = frames(BINANCE_SPOT_DEPTH_STORM);
+    let input = frames.clone();
+    report_allocations("binance_spot_depth_storm", frames.len(), || {
+        binance_spot_depth(input);
+    });
+
+    let mut group = c.benchmark_group("binance_spot_depth_storm");
+    group.throughput(Throughput::Elements(frames.len() as u64));
+    group.bench_function("transform", |b| {
+        b.iter_batched(|| frames.clone(), binance_spot_depth, BatchSize::SmallInput)
+    });
+    group.finish();
+}
+
+fn bench_okx_trade_bursts(c: &mut Criterion) {
+    let frames = frames(OKX_TRADE_BURSTS);
+    let mut transformer = okx_trades_transformer();
+    let input = frames.clone();
+    report_allocations("okx_trade_bursts", frames.len(), || {
+        okx_trades(&mut transformer, input);
+    });
+
+    let mut group = c.benchmark_group("okx_trade_bursts");
+    group.throughput(Throughput::Elements(frames.len() as u64));
+    group.bench_function("transform", |b| {
+        b.iter_batched(
+            || frames.clone(),
+            |frames| okx_trades(&mut transformer, frames),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_binance_spot_depth_storm,
+    bench_okx_trade_bursts
+);
+criterion_main!(benches);
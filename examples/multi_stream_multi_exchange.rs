@@ -5,13 +5,13 @@ use barter_data::{
         kraken::Kraken,
         okx::Okx,
     },
-    streams::Streams,
+    streams::{builder::shared::SharedStreamBuilder, Streams},
     subscription::{
         book::{OrderBooksL1, OrderBooksL2},
         trade::PublicTrades,
     },
 };
-use barter_integration::model::InstrumentKind;
+use barter_integration::model::instrument::kind::InstrumentKind;
 use tokio_stream::StreamExt;
 use tracing::info;
 
@@ -25,6 +25,7 @@ async fn main() {
     // - MarketEvent<DataKind> could use a custom enumeration if more flexibility is required.
     // - Each call to StreamBuilder::subscribe() creates a separate WebSocket connection for those
     //   Subscriptions passed.
+    // - Each SharedStreamBuilder multiplexes many SubKinds over a single WebSocket connection.
 
     // Initialise MarketEvent<DataKind> Streams for various exchanges
     let streams: Streams<MarketEvent<DataKind>> = Streams::builder_multi()
@@ -35,11 +36,11 @@ async fn main() {
                 (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades),
             ])
             .subscribe([
-                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
             ])
             .subscribe([
                 (Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades),
-                (Okx, "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+                (Okx, "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
             ])
         )
 
@@ -49,7 +50,7 @@ async fn main() {
                 (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL1),
             ])
             .subscribe([
-                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, OrderBooksL1),
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OrderBooksL1),
             ])
             .subscribe([
                 (Kraken, "xbt", "usd", InstrumentKind::Spot, OrderBooksL1),
@@ -59,12 +60,28 @@ async fn main() {
         // Add OrderBooksL2 Stream for various exchanges
         .add(Streams::<OrderBooksL2>::builder()
             .subscribe([
-                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
             ])
             .subscribe([
-                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, OrderBooksL2),
+                (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, OrderBooksL2::default()),
             ])
         )
+
+        // Add many SubKinds for a single exchange that share one WebSocket connection
+        .add_shared(SharedStreamBuilder::new()
+            .subscribe([
+                (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
+            ])
+            .subscribe([
+                (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, OrderBooksL1),
+            ])
+        )
+
+        // Add a composite Subscription to many SubKinds that share one WebSocket connection
+        .subscribe((
+            (BinanceFuturesUsd::default(), "eth", "usdt", InstrumentKind::Perpetual),
+            (PublicTrades, OrderBooksL1),
+        ))
         .init()
         .await
         .unwrap();
@@ -3,7 +3,7 @@ use barter_data::{
     streams::Streams,
     subscription::book::OrderBooksL2,
 };
-use barter_integration::model::InstrumentKind;
+use barter_integration::model::instrument::kind::InstrumentKind;
 use tracing::info;
 
 #[rustfmt::skip]
@@ -18,20 +18,25 @@ async fn main() {
 
         // Separate WebSocket connection for BTC_USDT stream since it's very high volume
         .subscribe([
-            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
         ])
 
         // Separate WebSocket connection for ETH_USDT stream since it's very high volume
         .subscribe([
-            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, OrderBooksL2),
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
         ])
 
         // Lower volume Instruments can share a WebSocket connection
         .subscribe([
-            (BinanceSpot::default(), "xrp", "usdt", InstrumentKind::Spot, OrderBooksL2),
-            (BinanceSpot::default(), "sol", "usdt", InstrumentKind::Spot, OrderBooksL2),
-            (BinanceSpot::default(), "avax", "usdt", InstrumentKind::Spot, OrderBooksL2),
-            (BinanceSpot::default(), "ltc", "usdt", InstrumentKind::Spot, OrderBooksL2),
+            (BinanceSpot::default(), "xrp", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+            (BinanceSpot::default(), "sol", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+            (BinanceSpot::default(), "avax", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+            (BinanceSpot::default(), "ltc", "usdt", InstrumentKind::Spot, OrderBooksL2::default()),
+        ])
+
+        // Partial top 20 Level snapshots, rather than a locally maintained OrderBook from deltas
+        .subscribe([
+            (BinanceSpot::default(), "bnb", "usdt", InstrumentKind::Spot, OrderBooksL2::partial(20)),
         ])
         .init()
         .await
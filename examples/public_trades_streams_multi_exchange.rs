@@ -1,4 +1,4 @@
-use barter_integration::model::InstrumentKind;
+use barter_integration::model::instrument::kind::InstrumentKind;
 use futures::StreamExt;
 use tracing::info;
 
@@ -29,8 +29,8 @@ async fn main() {
             (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
         ])
         .subscribe([
-            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
-            (BinanceFuturesUsd::default(), "eth", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
+            (BinanceFuturesUsd::default(), "eth", "usdt", InstrumentKind::Perpetual, PublicTrades),
         ])
         .subscribe([
             (Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades),
@@ -43,15 +43,15 @@ async fn main() {
         .subscribe([
             (Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades),
             (Okx, "eth", "usdt", InstrumentKind::Spot, PublicTrades),
-            (Okx, "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
-            (Okx, "eth", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+            (Okx, "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
+            (Okx, "eth", "usdt", InstrumentKind::Perpetual, PublicTrades),
         ])
         .subscribe([
             (BybitSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades),
             (BybitSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
         ])
         .subscribe([
-            (BybitFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+            (BybitFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
         ])
         .subscribe([
             (Bitmex, "xbt", "usd", InstrumentKind::Spot, PublicTrades)
@@ -0,0 +1,224 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::{
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+        bybit::spot::BybitSpot,
+        coinbase::Coinbase,
+        okx::Okx,
+        ExchangeId,
+    },
+    streams::{bandwidth::BandwidthMeter, config::StreamConfig, Streams},
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use futures::StreamExt;
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// Default comma separated base assets of the subscribed universe, quoted in USDT (USD for
+/// Coinbase).
+const DEFAULT_BASES: &str = "btc,eth,sol,xrp,ada,doge,ltc,link,dot,avax,bch,etc,atom,uni,fil";
+
+// Soak test that subscribes to a configurable universe across exchanges & prints rolling
+// statistics until the configured duration elapses.
+//
+// Configured via environment variables:
+//  - SOAK_BASES: comma separated base assets (default: DEFAULT_BASES)
+//  - SOAK_HOURS: duration of the soak test in hours (default: 4)
+//  - SOAK_REPORT_SECS: interval between statistics reports in seconds (default: 10)
+//  - SOAK_GAP_SECS: silence after which an exchange Instrument counts a gap (default: 60)
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    let bases = env_or("SOAK_BASES", DEFAULT_BASES.to_owned());
+    let bases = bases.split(',').map(str::trim).filter(|base| !base.is_empty()).collect::<Vec<_>>();
+    let duration = Duration::from_secs_f64(env_or("SOAK_HOURS", 4.0) * 3600.0);
+    let report_interval = Duration::from_secs(env_or("SOAK_REPORT_SECS", 10));
+    let gap_threshold = Duration::from_secs(env_or("SOAK_GAP_SECS", 60));
+
+    // Account for every connection, so re-connections can be counted
+    let meter = BandwidthMeter::default();
+    let config = StreamConfig::default().with_bandwidth_meter(meter.clone());
+
+    // Initialise MarketEvent<DataKind> Streams for the universe across exchanges
+    let streams: Streams<MarketEvent<DataKind>> = Streams::builder_multi()
+        .add(Streams::<PublicTrades>::builder()
+            .subscribe_with(bases.iter().map(|base| (BinanceSpot::default(), *base, "usdt", InstrumentKind::Spot, PublicTrades)), config.clone())
+            .subscribe_with(bases.iter().map(|base| (BinanceFuturesUsd::default(), *base, "usdt", InstrumentKind::Perpetual, PublicTrades)), config.clone())
+            .subscribe_with(bases.iter().map(|base| (Okx, *base, "usdt", InstrumentKind::Spot, PublicTrades)), config.clone())
+            .subscribe_with(bases.iter().map(|base| (BybitSpot::default(), *base, "usdt", InstrumentKind::Spot, PublicTrades)), config.clone())
+            .subscribe_with(bases.iter().map(|base| (Coinbase, *base, "usd", InstrumentKind::Spot, PublicTrades)), config.clone())
+        )
+        .add(Streams::<OrderBooksL1>::builder()
+            .subscribe_with(bases.iter().map(|base| (BinanceSpot::default(), *base, "usdt", InstrumentKind::Spot, OrderBooksL1)), config.clone())
+            .subscribe_with(bases.iter().map(|base| (BinanceFuturesUsd::default(), *base, "usdt", InstrumentKind::Perpetual, OrderBooksL1)), config.clone())
+        )
+        .init()
+        .await
+        .unwrap();
+
+    info!(bases = bases.len(), ?duration, ?report_interval, ?gap_threshold, "soak test running");
+
+    let mut joined_stream = streams.join_map().await;
+    let mut stats = SoakStats::new(gap_threshold);
+    let mut report = tokio::time::interval(report_interval);
+    let deadline = tokio::time::sleep(duration);
+    tokio::pin!(deadline);
+
+    loop {
+        tokio::select! {
+            _ = &mut deadline => break,
+            _ = report.tick() => stats.report(&meter),
+            event = joined_stream.next() => match event {
+                Some((exchange, event)) => stats.record(exchange, &event),
+                None => {
+                    warn!("every exchange stream ended");
+                    break;
+                }
+            },
+        }
+    }
+
+    stats.report(&meter);
+    info!(elapsed = ?stats.start.elapsed(), "soak test complete");
+}
+
+/// Rolling statistics of one exchange.
+#[derive(Debug, Default)]
+struct ExchangeStats {
+    /// Events received since the soak test started.
+    total: u64,
+    /// Events received since the previous report.
+    window: u64,
+    trades: u64,
+    books: u64,
+    /// Times an Instrument was silent for longer than the gap threshold.
+    gaps: u64,
+    /// Time of the last event received per Instrument.
+    last_seen: HashMap<Instrument, Instant>,
+}
+
+/// Rolling statistics of every exchange, reported periodically.
+#[derive(Debug)]
+struct SoakStats {
+    start: Instant,
+    last_report: Instant,
+    gap_threshold: Duration,
+    exchanges: BTreeMap<ExchangeId, ExchangeStats>,
+}
+
+impl SoakStats {
+    fn new(gap_threshold: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            start: now,
+            last_report: now,
+            gap_threshold,
+            exchanges: BTreeMap::new(),
+        }
+    }
+
+    fn record(&mut self, exchange: ExchangeId, event: &MarketEvent<DataKind>) {
+        let now = Instant::now();
+        let stats = self.exchanges.entry(exchange).or_default();
+        stats.total += 1;
+        stats.window += 1;
+        match event.kind {
+            DataKind::Trade(_) => stats.trades += 1,
+            _ => stats.books += 1,
+        }
+
+        let previous = stats.last_seen.insert(event.instrument.clone(), now);
+        if previous.is_some_and(|previous| now - previous > self.gap_threshold) {
+            stats.gaps += 1;
+        }
+    }
+
+    fn report(&mut self, meter: &BandwidthMeter) {
+        let now = Instant::now();
+        let elapsed = now - self.last_report;
+        self.last_report = now;
+
+        // Every closed connection was replaced by a re-connection
+        let mut reconnects = HashMap::<ExchangeId, u64>::new();
+        let mut bytes = HashMap::<ExchangeId, u64>::new();
+        for connection in meter.connections() {
+            if !connection.active {
+                *reconnects.entry(connection.exchange).or_default() += 1;
+            }
+            *bytes.entry(connection.exchange).or_default() += connection.bytes;
+        }
+
+        for (exchange, stats) in self.exchanges.iter_mut() {
+            // Instruments silent for longer than the gap threshold are currently gapped
+            let gapped = stats
+                .last_seen
+                .values()
+                .filter(|last_seen| now - **last_seen > self.gap_threshold)
+                .count();
+
+            info!(
+                %exchange,
+                rate = format!("{:.1}/s", stats.window as f64 / elapsed.as_secs_f64()),
+                total = stats.total,
+                trades = stats.trades,
+                books = stats.books,
+                instruments = stats.last_seen.len(),
+                gaps = stats.gaps,
+                gapped,
+                reconnects = reconnects.get(exchange).copied().unwrap_or_default(),
+                bytes = bytes.get(exchange).copied().unwrap_or_default(),
+                "soak statistics"
+            );
+            stats.window = 0;
+        }
+
+        info!(
+            uptime = ?self.start.elapsed(),
+            rss_mib = rss_bytes().map(|rss| format!("{:.1}", rss as f64 / (1024.0 * 1024.0))),
+            "soak process statistics"
+        );
+    }
+}
+
+/// Resident set size of the process, read from `/proc/self/statm` assuming 4KiB pages. Returns
+/// `None` on platforms without procfs.
+fn rss_bytes() -> Option<u64> {
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let pages = statm.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+    Some(pages * 4096)
+}
+
+/// Parse the environment variable `key`, falling back to the `default` if unset or invalid.
+fn env_or<T>(key: &str, default: T) -> T
+where
+    T: std::str::FromStr,
+{
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
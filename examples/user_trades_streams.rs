@@ -0,0 +1,63 @@
+use barter_data::{
+    exchange::{binance::spot::BinanceSpot, ExchangeId},
+    streams::{
+        config::{Credentials, StreamConfig},
+        Streams,
+    },
+    subscription::trade::UserTrades,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Private account streams are authenticated with API Credentials provided in the StreamConfig
+    let credentials = Credentials::new(
+        std::env::var("BINANCE_API_KEY").expect("BINANCE_API_KEY not set"),
+        std::env::var("BINANCE_API_SECRET").expect("BINANCE_API_SECRET not set"),
+    );
+
+    // Initialise UserTrades Streams for BinanceSpot only
+    // '--> fills for markets that are not subscribed to are ignored
+    let mut streams = Streams::<UserTrades>::builder()
+        .subscribe_with(
+            [
+                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, UserTrades),
+                (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, UserTrades),
+            ],
+            StreamConfig::default().with_credentials(credentials),
+        )
+        .init()
+        .await
+        .unwrap();
+
+    // Select the ExchangeId::BinanceSpot stream
+    let mut binance_stream = streams
+        .select(ExchangeId::BinanceSpot)
+        .unwrap();
+
+    while let Some(trade) = binance_stream.recv().await {
+        info!("MarketEvent<UserTrade>: {trade:?}");
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,26 @@
+#![no_main]
+//! Fuzz the binance raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{binance::book::l1::BinanceOrderBookL1, binance::trade::BinanceTrade, ExchangeId},
+    subscription::{book::OrderBookL1, trade::PublicTrade},
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+    let _ = transform_raw::<BinanceTrade, PublicTrade>(
+        ExchangeId::BinanceSpot,
+        instrument.clone(),
+        data,
+    );
+
+    let _ = transform_raw::<BinanceOrderBookL1, OrderBookL1>(
+        ExchangeId::BinanceSpot,
+        instrument.clone(),
+        data,
+    );
+});
@@ -0,0 +1,20 @@
+#![no_main]
+//! Fuzz the bitfinex raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{bitfinex::message::BitfinexMessage, ExchangeId},
+    subscription::trade::PublicTrade,
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+
+    let _ = transform_raw::<BitfinexMessage, PublicTrade>(
+        ExchangeId::Bitfinex,
+        instrument.clone(),
+        data,
+    );
+});
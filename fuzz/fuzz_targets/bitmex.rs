@@ -0,0 +1,16 @@
+#![no_main]
+//! Fuzz the bitmex raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{bitmex::trade::BitmexTrade, ExchangeId},
+    subscription::trade::PublicTrade,
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("xbt", "usd", InstrumentKind::Perpetual));
+
+    let _ = transform_raw::<BitmexTrade, PublicTrade>(ExchangeId::Bitmex, instrument.clone(), data);
+});
@@ -0,0 +1,17 @@
+#![no_main]
+//! Fuzz the bybit raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{bybit::message::BybitMessage, ExchangeId},
+    subscription::trade::PublicTrade,
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+    let _ =
+        transform_raw::<BybitMessage, PublicTrade>(ExchangeId::BybitSpot, instrument.clone(), data);
+});
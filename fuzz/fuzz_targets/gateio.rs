@@ -0,0 +1,29 @@
+#![no_main]
+//! Fuzz the gateio raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{
+        gateio::futures::trade::GateioFuturesTrades, gateio::spot::trade::GateioSpotTrade,
+        ExchangeId,
+    },
+    subscription::trade::PublicTrade,
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+    let _ = transform_raw::<GateioSpotTrade, PublicTrade>(
+        ExchangeId::GateioSpot,
+        instrument.clone(),
+        data,
+    );
+
+    let _ = transform_raw::<GateioFuturesTrades, PublicTrade>(
+        ExchangeId::GateioFuturesUsd,
+        instrument.clone(),
+        data,
+    );
+});
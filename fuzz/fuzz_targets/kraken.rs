@@ -0,0 +1,23 @@
+#![no_main]
+//! Fuzz the kraken raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{kraken::book::l1::KrakenOrderBookL1, kraken::trade::KrakenTrades, ExchangeId},
+    subscription::{book::OrderBookL1, trade::PublicTrade},
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("xbt", "usd", InstrumentKind::Spot));
+
+    let _ =
+        transform_raw::<KrakenTrades, PublicTrade>(ExchangeId::Kraken, instrument.clone(), data);
+
+    let _ = transform_raw::<KrakenOrderBookL1, OrderBookL1>(
+        ExchangeId::Kraken,
+        instrument.clone(),
+        data,
+    );
+});
@@ -0,0 +1,18 @@
+#![no_main]
+//! Fuzz the okx raw payload deserialisation & normalisation.
+
+use barter_data::{
+    exchange::{okx::candle::OkxCandles, okx::trade::OkxTrades, ExchangeId},
+    subscription::{candle::Candle, trade::PublicTrade},
+    transformer::stateless::transform_raw,
+};
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+    let _ = transform_raw::<OkxTrades, PublicTrade>(ExchangeId::Okx, instrument.clone(), data);
+
+    let _ = transform_raw::<OkxCandles, Candle>(ExchangeId::Okx, instrument.clone(), data);
+});
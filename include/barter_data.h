@@ -0,0 +1,57 @@
+/*
+ * Barter-Data C API, enabled via the `capi` feature.
+ *
+ * Build the shared library with:
+ *   cargo rustc --release --features capi --crate-type cdylib
+ */
+#ifndef BARTER_DATA_H
+#define BARTER_DATA_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Opaque handle to running Streams, released with barter_data_streams_free. */
+typedef struct BarterDataStreams BarterDataStreams;
+
+/*
+ * Invoked with each NUL terminated UTF-8 JSON encoded VersionedMarketEvent of `len` bytes
+ * (excluding the NUL terminator). `event` is only valid for the duration of the call.
+ */
+typedef void (*BarterDataCallback)(const char *event, size_t len, void *user_data);
+
+/*
+ * Subscribe to a JSON array of subscriptions of a kind ("public_trades", "order_books_l1" or
+ * "order_books_l2"), blocking until every exchange connection is established.
+ *
+ * eg/ [{"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot"}]
+ *
+ * Events are delivered to the callback from a single dedicated thread. Returns NULL on failure,
+ * in which case barter_data_last_error describes the failure.
+ */
+BarterDataStreams *barter_data_subscribe(
+    const char *kind,
+    const char *subscriptions,
+    BarterDataCallback callback,
+    void *user_data
+);
+
+/*
+ * Stop the Streams & release it's resources, blocking until the final callback invocation has
+ * returned. Must not be called from within the callback. Passing NULL is a no-op.
+ */
+void barter_data_streams_free(BarterDataStreams *streams);
+
+/*
+ * Message of the last error that occurred on the calling thread, or NULL if none has occurred.
+ * Owned by the library & valid until the next failing call on the same thread.
+ */
+const char *barter_data_last_error(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BARTER_DATA_H */
@@ -0,0 +1,225 @@
+#![warn(
+    missing_debug_implementations,
+    missing_copy_implementations,
+    rust_2018_idioms
+)]
+// PyO3 #[pyfunction] expansion triggers false positive PyErr conversions
+#![allow(clippy::useless_conversion)]
+
+//! # Barter-Data Python
+//! PyO3 bindings exposing the Barter-Data [`Streams`] API to Python as an async iterator of
+//! normalised events, using the connectors of the Rust crate.
+//!
+//! Each event is yielded as a `dict` of the versioned
+//! [`VersionedMarketEvent`](barter_data::schema::VersionedMarketEvent) representation.
+
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::{
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot, us::BinanceUs},
+        bitfinex::Bitfinex,
+        bitmex::Bitmex,
+        bybit::{futures::BybitFuturesUsd, spot::BybitSpot},
+        coinbase::Coinbase,
+        gateio::{futures::GateioFuturesUsd, spot::GateioSpot},
+        kraken::Kraken,
+        okx::Okx,
+    },
+    schema::VersionedMarketEvent,
+    streams::{builder::StreamBuilder, Streams},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        SubKind,
+    },
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use pyo3::{
+    exceptions::{PyRuntimeError, PyStopAsyncIteration, PyValueError},
+    prelude::*,
+    types::PyDict,
+};
+use pyo3_async_runtimes::tokio::future_into_py;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+/// Python subscription tuple of (exchange, base, quote, instrument_kind),
+/// eg/ ("binance_spot", "btc", "usdt", "spot").
+type PySubscription = (String, String, String, String);
+
+/// Generate a function that adds a [`PySubscription`] to a [`StreamBuilder`] of the provided
+/// [`SubKind`](barter_data::subscription::SubKind), and the names of the supported exchanges.
+macro_rules! kind_subscriber {
+    ($subscribe:ident, $supported:ident, $kind:expr, $Kind:ty, { $($name:literal => $exchange:expr),* $(,)? }) => {
+        /// Exchanges supported by the associated subscriber function.
+        const $supported: &[&str] = &[$($name),*];
+
+        /// Add a [`PySubscription`] to the provided [`StreamBuilder`].
+        fn $subscribe(
+            builder: StreamBuilder<$Kind>,
+            (exchange, base, quote, instrument_kind): PySubscription,
+        ) -> PyResult<StreamBuilder<$Kind>> {
+            let instrument_kind = parse_instrument_kind(&instrument_kind)?;
+            Ok(match exchange.as_str() {
+                $($name => builder.subscribe([($exchange, base, quote, instrument_kind, $kind)]),)*
+                other => {
+                    return Err(PyValueError::new_err(format!(
+                        "unsupported exchange for {}: {other}",
+                        stringify!($Kind)
+                    )))
+                }
+            })
+        }
+    };
+}
+
+kind_subscriber!(subscribe_public_trades, PUBLIC_TRADES, PublicTrades, PublicTrades, {
+    "binance_spot" => BinanceSpot::default(),
+    "binance_futures_usd" => BinanceFuturesUsd::default(),
+    "binance_us" => BinanceUs::default(),
+    "bitfinex" => Bitfinex,
+    "bitmex" => Bitmex,
+    "bybit_spot" => BybitSpot::default(),
+    "bybit_futures_usd" => BybitFuturesUsd::default(),
+    "coinbase" => Coinbase,
+    "gateio_spot" => GateioSpot::default(),
+    "gateio_futures_usd" => GateioFuturesUsd::default(),
+    "kraken" => Kraken,
+    "okx" => Okx,
+});
+
+kind_subscriber!(subscribe_order_books_l1, ORDER_BOOKS_L1, OrderBooksL1, OrderBooksL1, {
+    "binance_spot" => BinanceSpot::default(),
+    "binance_futures_usd" => BinanceFuturesUsd::default(),
+    "binance_us" => BinanceUs::default(),
+    "kraken" => Kraken,
+});
+
+kind_subscriber!(subscribe_order_books_l2, ORDER_BOOKS_L2, OrderBooksL2::default(), OrderBooksL2, {
+    "binance_spot" => BinanceSpot::default(),
+    "binance_futures_usd" => BinanceFuturesUsd::default(),
+    "binance_us" => BinanceUs::default(),
+});
+
+/// Parse a Python instrument kind (eg/ "spot" or "perpetual").
+fn parse_instrument_kind(kind: &str) -> PyResult<InstrumentKind> {
+    serde_json::from_value(serde_json::Value::String(kind.to_owned()))
+        .map_err(|_| PyValueError::new_err(format!("unsupported instrument kind: {kind}")))
+}
+
+/// Build a [`StreamBuilder`] of the provided [`PySubscription`]s using a subscriber function.
+fn builder<Kind>(
+    subscriptions: Vec<PySubscription>,
+    subscribe: fn(StreamBuilder<Kind>, PySubscription) -> PyResult<StreamBuilder<Kind>>,
+) -> PyResult<StreamBuilder<Kind>>
+where
+    Kind: SubKind,
+{
+    subscriptions
+        .into_iter()
+        .try_fold(StreamBuilder::new(), subscribe)
+}
+
+/// Async iterator of normalised event `dict`s, yielded in the order they are received from each
+/// exchange.
+#[pyclass]
+#[derive(Debug)]
+struct EventStream {
+    rx: Arc<Mutex<mpsc::UnboundedReceiver<MarketEvent<DataKind>>>>,
+}
+
+#[pymethods]
+impl EventStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let rx = Arc::clone(&self.rx);
+        future_into_py(py, async move {
+            let event = rx
+                .lock()
+                .await
+                .recv()
+                .await
+                .ok_or_else(|| PyStopAsyncIteration::new_err("streams terminated"))?;
+
+            let json = serde_json::to_string(&VersionedMarketEvent::from(event))
+                .map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+            Python::with_gil(|py| {
+                let event = py.import_bound("json")?.call_method1("loads", (json,))?;
+                Ok(event.unbind())
+            })
+        })
+    }
+}
+
+/// Initialise [`Streams`] of the provided kind & [`PySubscription`]s, joined into a single
+/// receiver of [`MarketEvent<DataKind>`]s.
+async fn init(
+    kind: String,
+    subscriptions: Vec<PySubscription>,
+) -> PyResult<mpsc::UnboundedReceiver<MarketEvent<DataKind>>> {
+    let multi = Streams::<MarketEvent<DataKind>>::builder_multi();
+    let multi = match kind.as_str() {
+        "public_trades" => multi.add(builder(subscriptions, subscribe_public_trades)?),
+        "order_books_l1" => multi.add(builder(subscriptions, subscribe_order_books_l1)?),
+        "order_books_l2" => multi.add(builder(subscriptions, subscribe_order_books_l2)?),
+        other => return Err(PyValueError::new_err(format!("unsupported kind: {other}"))),
+    };
+
+    let streams = multi
+        .init()
+        .await
+        .map_err(|error| PyRuntimeError::new_err(error.to_string()))?;
+
+    Ok(streams.join().await)
+}
+
+/// Subscribe to the provided (exchange, base, quote, instrument_kind) subscriptions of a kind
+/// ("public_trades", "order_books_l1" or "order_books_l2"), returning an awaitable that resolves
+/// to an async iterator of normalised event `dict`s once every connection is established.
+#[pyfunction]
+fn subscribe(
+    py: Python<'_>,
+    kind: String,
+    subscriptions: Vec<PySubscription>,
+) -> PyResult<Bound<'_, PyAny>> {
+    // StreamBuilder initialisation futures are not Send, so they are driven on a dedicated
+    // thread, while the spawned consumer loops run on the shared tokio runtime
+    let (init_tx, init_rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        let result = pyo3_async_runtimes::tokio::get_runtime().block_on(init(kind, subscriptions));
+        let _ = init_tx.send(result);
+    });
+
+    future_into_py(py, async move {
+        let rx = init_rx
+            .await
+            .map_err(|_| PyRuntimeError::new_err("Streams initialisation failed"))??;
+
+        Ok(EventStream {
+            rx: Arc::new(Mutex::new(rx)),
+        })
+    })
+}
+
+/// Supported exchanges of each subscription kind.
+#[pyfunction]
+fn supported(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let supported = PyDict::new_bound(py);
+    supported.set_item("public_trades", PUBLIC_TRADES)?;
+    supported.set_item("order_books_l1", ORDER_BOOKS_L1)?;
+    supported.set_item("order_books_l2", ORDER_BOOKS_L2)?;
+    Ok(supported)
+}
+
+#[pymodule]
+#[pyo3(name = "barter_data")]
+fn python_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<EventStream>()?;
+    module.add_function(wrap_pyfunction!(subscribe, module)?)?;
+    module.add_function(wrap_pyfunction!(supported, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,475 @@
+use crate::{
+    exchange::{
+        binance::{
+            book::BinanceLevel, futures::l2::BinanceFuturesOrderBookL2Delta,
+            spot::l2::BinanceSpotOrderBookL2Delta,
+        },
+        bybit::{
+            message::BybitPayload,
+            option::book::{BybitLevel, BybitOrderBookL2},
+        },
+    },
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::crc32,
+};
+use barter_integration::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use proptest::{collection::vec, prelude::*, test_runner::TestCaseError};
+use std::collections::BTreeMap;
+
+/// Price increment of every generated [`Level`], exactly representable as an `f64` so that
+/// generated prices compare exactly.
+pub const TICK_SIZE: f64 = 0.5;
+
+/// Number of ticks between zero and the generated mid price, which separates the bid ticks
+/// (below) from the ask ticks (above) so generated books are never crossed.
+pub const MID_TICKS: u64 = 100_000;
+
+/// Number of best [`Level`]s per side included in a [`book_checksum`].
+pub const CHECKSUM_DEPTH: usize = 25;
+
+/// Exchange agnostic OrderBook delta, containing absolute [`Level`] amounts where an amount of
+/// zero removes the [`Level`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BookDelta {
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/// Generate a bid [`Level`] within `depth` ticks below the mid price. One in four amounts is
+/// zero (ie/ a [`Level`] removal).
+pub fn bid_level(depth: u64) -> impl Strategy<Value = Level> {
+    (MID_TICKS - depth..MID_TICKS, amount()).prop_map(|(tick, amount)| level(tick, amount))
+}
+
+/// Generate an ask [`Level`] within `depth` ticks above the mid price. One in four amounts is
+/// zero (ie/ a [`Level`] removal).
+pub fn ask_level(depth: u64) -> impl Strategy<Value = Level> {
+    (MID_TICKS + 1..=MID_TICKS + depth, amount()).prop_map(|(tick, amount)| level(tick, amount))
+}
+
+/// Generate a [`BookDelta`] of up to `max_levels` [`Level`]s per side, each within `depth` ticks
+/// of the mid price.
+pub fn book_delta(depth: u64, max_levels: usize) -> impl Strategy<Value = BookDelta> {
+    (
+        vec(bid_level(depth), 0..=max_levels),
+        vec(ask_level(depth), 0..=max_levels),
+    )
+        .prop_map(|(bids, asks)| BookDelta { bids, asks })
+}
+
+/// Generate a sequence of between one and `max_deltas` [`BookDelta`]s.
+pub fn book_deltas(
+    depth: u64,
+    max_levels: usize,
+    max_deltas: usize,
+) -> impl Strategy<Value = Vec<BookDelta>> {
+    vec(book_delta(depth, max_levels), 1..=max_deltas)
+}
+
+/// Generate an HTTP snapshot `lastUpdateId` & a valid sequence of
+/// [`BinanceSpotOrderBookL2Delta`]s to apply after it, along with the [`BookDelta`] each was
+/// generated from.
+///
+/// The first delta straddles the snapshot (`U <= lastUpdateId + 1 <= u`) and every following
+/// delta starts at the previous `u + 1`.
+pub fn binance_spot_deltas(
+    subscription_id: SubscriptionId,
+    depth: u64,
+    max_levels: usize,
+    max_deltas: usize,
+) -> impl Strategy<Value = (u64, Vec<(BookDelta, BinanceSpotOrderBookL2Delta)>)> {
+    (
+        10..1_000_000_u64,
+        0..3_u64,
+        vec((book_delta(depth, max_levels), 1..5_u64), 1..=max_deltas),
+    )
+        .prop_map(move |(snapshot_id, overlap, deltas)| {
+            let mut next_id = snapshot_id + 1 - overlap;
+            let deltas = deltas
+                .into_iter()
+                .map(|(delta, span)| {
+                    let first_update_id = next_id;
+                    let last_update_id = (first_update_id + span - 1).max(snapshot_id + 1);
+                    next_id = last_update_id + 1;
+
+                    let binance = BinanceSpotOrderBookL2Delta {
+                        subscription_id: subscription_id.clone(),
+                        first_update_id,
+                        last_update_id,
+                        bids: binance_levels(&delta.bids),
+                        asks: binance_levels(&delta.asks),
+                    };
+                    (delta, binance)
+                })
+                .collect();
+
+            (snapshot_id, deltas)
+        })
+}
+
+/// Generate an HTTP snapshot `lastUpdateId` & a valid sequence of
+/// [`BinanceFuturesOrderBookL2Delta`]s to apply after it, along with the [`BookDelta`] each was
+/// generated from.
+///
+/// The first delta straddles the snapshot (`U <= lastUpdateId <= u`) and every following
+/// delta has a `pu` equal to the previous `u`.
+pub fn binance_futures_deltas(
+    subscription_id: SubscriptionId,
+    depth: u64,
+    max_levels: usize,
+    max_deltas: usize,
+) -> impl Strategy<Value = (u64, Vec<(BookDelta, BinanceFuturesOrderBookL2Delta)>)> {
+    (
+        10..1_000_000_u64,
+        0..3_u64,
+        vec((book_delta(depth, max_levels), 1..5_u64), 1..=max_deltas),
+    )
+        .prop_map(move |(snapshot_id, overlap, deltas)| {
+            let mut prev_last_update_id = snapshot_id - overlap - 1;
+            let deltas = deltas
+                .into_iter()
+                .map(|(delta, span)| {
+                    let first_update_id = prev_last_update_id + 1;
+                    let last_update_id = (first_update_id + span - 1).max(snapshot_id);
+
+                    let binance = BinanceFuturesOrderBookL2Delta {
+                        subscription_id: subscription_id.clone(),
+                        first_update_id,
+                        last_update_id,
+                        prev_last_update_id,
+                        bids: binance_levels(&delta.bids),
+                        asks: binance_levels(&delta.asks),
+                    };
+                    prev_last_update_id = last_update_id;
+                    (delta, binance)
+                })
+                .collect();
+
+            (snapshot_id, deltas)
+        })
+}
+
+/// Generate a valid sequence of [`BybitOrderBookL2`] WebSocket messages, starting with a
+/// "snapshot" followed by "delta"s with consecutive update ids, along with the [`BookDelta`]
+/// each was generated from.
+///
+/// The snapshot [`BookDelta`] contains no removals.
+pub fn bybit_book_messages(
+    subscription_id: SubscriptionId,
+    depth: u64,
+    max_levels: usize,
+    max_deltas: usize,
+) -> impl Strategy<Value = Vec<(BookDelta, BybitPayload<BybitOrderBookL2>)>> {
+    (
+        1..1_000_000_u64,
+        book_delta(depth, max_levels),
+        vec(book_delta(depth, max_levels), 0..max_deltas),
+    )
+        .prop_map(move |(first_update_id, snapshot, deltas)| {
+            // Snapshots contain a single positive Level per price
+            let snapshot_side = |levels: Vec<Level>| {
+                levels
+                    .into_iter()
+                    .map(|level| (ticks(level.price), level))
+                    .collect::<BTreeMap<_, _>>()
+                    .into_values()
+                    .filter(|level| level.amount > 0.0)
+                    .collect()
+            };
+            let snapshot = BookDelta {
+                bids: snapshot_side(snapshot.bids),
+                asks: snapshot_side(snapshot.asks),
+            };
+
+            std::iter::once(("snapshot", snapshot))
+                .chain(deltas.into_iter().map(|delta| ("delta", delta)))
+                .zip(first_update_id..)
+                .map(|((kind, delta), update_id)| {
+                    let bybit = BybitPayload {
+                        subscription_id: subscription_id.clone(),
+                        r#type: kind.to_owned(),
+                        time: DateTime::<Utc>::from_timestamp_millis(update_id as i64)
+                            .unwrap_or_default(),
+                        data: BybitOrderBookL2 {
+                            bids: bybit_levels(&delta.bids),
+                            asks: bybit_levels(&delta.asks),
+                            update_id,
+                        },
+                    };
+                    (delta, bybit)
+                })
+                .collect()
+        })
+}
+
+/// Simple reference model of an OrderBook, used to check the [`OrderBook`] produced by the
+/// shared book engine (ie/ [`OrderBookSide::upsert`]) & exchange specific
+/// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater)s.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ReferenceBook {
+    bids: BTreeMap<u64, f64>,
+    asks: BTreeMap<u64, f64>,
+}
+
+impl ReferenceBook {
+    /// Apply the absolute [`Level`] amounts of a [`BookDelta`].
+    pub fn apply(&mut self, delta: &BookDelta) {
+        let upsert = |side: &mut BTreeMap<u64, f64>, level: &Level| {
+            if level.amount == 0.0 {
+                side.remove(&ticks(level.price));
+            } else {
+                side.insert(ticks(level.price), level.amount);
+            }
+        };
+
+        delta
+            .bids
+            .iter()
+            .for_each(|level| upsert(&mut self.bids, level));
+        delta
+            .asks
+            .iter()
+            .for_each(|level| upsert(&mut self.asks, level));
+    }
+
+    /// Replace every [`Level`] with those of a snapshot [`BookDelta`].
+    pub fn replace(&mut self, snapshot: &BookDelta) {
+        *self = Self::default();
+        self.apply(snapshot);
+    }
+
+    /// Construct the sorted [`OrderBook`] this reference model represents.
+    pub fn order_book(&self, last_update_time: DateTime<Utc>) -> OrderBook {
+        let level = |(tick, amount): (&u64, &f64)| Level::new(*tick as f64 * TICK_SIZE, *amount);
+
+        OrderBook {
+            last_update_time,
+            bids: OrderBookSide::new(
+                barter_integration::model::Side::Buy,
+                self.bids.iter().rev().map(level),
+            ),
+            asks: OrderBookSide::new(
+                barter_integration::model::Side::Sell,
+                self.asks.iter().map(level),
+            ),
+            sequence: None,
+            delta: false,
+        }
+    }
+
+    /// [`book_checksum`] of the [`OrderBook`] this reference model represents.
+    pub fn checksum(&self) -> u32 {
+        book_checksum(&self.order_book(DateTime::<Utc>::default()))
+    }
+}
+
+/// Assert the invariants every sorted [`OrderBook`] snapshot must uphold:
+/// - Bids are sorted by descending price & asks by ascending price, without duplicate prices.
+/// - Every [`Level`] has a positive amount.
+/// - The book is not crossed (ie/ the best bid is below the best ask).
+pub fn assert_book_invariants(book: &OrderBook) -> Result<(), TestCaseError> {
+    let bids = book.bids.levels();
+    let asks = book.asks.levels();
+
+    prop_assert!(
+        bids.windows(2).all(|pair| pair[0].price > pair[1].price),
+        "bids are not strictly descending: {:?}",
+        bids
+    );
+    prop_assert!(
+        asks.windows(2).all(|pair| pair[0].price < pair[1].price),
+        "asks are not strictly ascending: {:?}",
+        asks
+    );
+    prop_assert!(
+        bids.iter().chain(asks).all(|level| level.amount > 0.0),
+        "book contains non-positive amounts: {:?}",
+        book
+    );
+    if let (Some(best_bid), Some(best_ask)) = (bids.first(), asks.first()) {
+        prop_assert!(
+            best_bid.price < best_ask.price,
+            "book is crossed: best bid {:?} >= best ask {:?}",
+            best_bid,
+            best_ask
+        );
+    }
+
+    Ok(())
+}
+
+/// Assert the [`OrderBook`] upholds the [`assert_book_invariants`], and it's [`book_checksum`]
+/// matches that of the [`ReferenceBook`].
+pub fn assert_book_matches(
+    book: &OrderBook,
+    reference: &ReferenceBook,
+) -> Result<(), TestCaseError> {
+    assert_book_invariants(book)?;
+    prop_assert_eq!(
+        book_checksum(book),
+        reference.checksum(),
+        "book checksum mismatch\nActual: {:?}\nExpected: {:?}",
+        book,
+        reference.order_book(book.last_update_time)
+    );
+    Ok(())
+}
+
+/// CRC-32 (IEEE) checksum of the best [`CHECKSUM_DEPTH`] bid & ask [`Level`]s of a sorted
+/// [`OrderBook`], interleaved as "bid_price:bid_amount:ask_price:ask_amount:..." in the style
+/// of exchange OrderBook checksums (eg/ Okx).
+pub fn book_checksum(book: &OrderBook) -> u32 {
+    let bids = book.bids.levels().iter().take(CHECKSUM_DEPTH);
+    let mut asks = book.asks.levels().iter().take(CHECKSUM_DEPTH);
+
+    let mut parts = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+    let mut push = |level: &Level| {
+        parts.push(level.price.to_string());
+        parts.push(level.amount.to_string());
+    };
+    for bid in bids {
+        push(bid);
+        if let Some(ask) = asks.next() {
+            push(ask);
+        }
+    }
+    asks.for_each(push);
+
+    crc32(parts.join(":").as_bytes())
+}
+
+fn amount() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        1 => Just(0.0),
+        3 => (1..10_000_u32).prop_map(|lots| f64::from(lots) * 0.001),
+    ]
+}
+
+fn level(tick: u64, amount: f64) -> Level {
+    Level::new(tick as f64 * TICK_SIZE, amount)
+}
+
+fn ticks(price: f64) -> u64 {
+    (price / TICK_SIZE).round() as u64
+}
+
+fn binance_levels(levels: &[Level]) -> Vec<BinanceLevel> {
+    levels
+        .iter()
+        .map(|level| BinanceLevel {
+            price: level.price,
+            amount: level.amount,
+        })
+        .collect()
+}
+
+fn bybit_levels(levels: &[Level]) -> Vec<BybitLevel> {
+    levels
+        .iter()
+        .map(|level| BybitLevel {
+            price: level.price,
+            amount: level.amount,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::{
+                book::l2::BinanceOrderBookL2Update, futures::l2::BinanceFuturesBookUpdater,
+                spot::l2::BinanceSpotBookUpdater,
+            },
+            bybit::option::book::{BybitOptionBookMessage, BybitOptionBookUpdater},
+        },
+        transformer::book::OrderBookUpdater,
+    };
+
+    fn empty_book() -> OrderBook {
+        ReferenceBook::default().order_book(DateTime::<Utc>::default())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_shared_book_engine(deltas in book_deltas(50, 20, 50)) {
+            let mut book = empty_book();
+            let mut reference = ReferenceBook::default();
+
+            for delta in deltas {
+                book.bids.upsert(delta.bids.clone());
+                book.asks.upsert(delta.asks.clone());
+                reference.apply(&delta);
+
+                assert_book_matches(&book.snapshot(), &reference)?;
+            }
+        }
+
+        #[test]
+        fn test_binance_spot_book_updater(
+            (snapshot_id, deltas) in binance_spot_deltas(SubscriptionId::from("btcusdt"), 50, 20, 50)
+        ) {
+            let mut updater = BinanceSpotBookUpdater::new(snapshot_id);
+            let mut book = empty_book();
+            let mut reference = ReferenceBook::default();
+
+            for (delta, binance) in deltas {
+                let snapshot = updater
+                    .update(&mut book, BinanceOrderBookL2Update::Delta(binance))
+                    .map_err(|error| TestCaseError::fail(error.to_string()))?;
+                reference.apply(&delta);
+
+                prop_assert!(snapshot.is_some());
+                assert_book_matches(&book.snapshot(), &reference)?;
+            }
+        }
+
+        #[test]
+        fn test_binance_futures_book_updater(
+            (snapshot_id, deltas) in binance_futures_deltas(SubscriptionId::from("btcusdt"), 50, 20, 50)
+        ) {
+            let mut updater = BinanceFuturesBookUpdater::new(snapshot_id);
+            let mut book = empty_book();
+            let mut reference = ReferenceBook::default();
+
+            for (delta, binance) in deltas {
+                let snapshot = updater
+                    .update(&mut book, BinanceOrderBookL2Update::Delta(binance))
+                    .map_err(|error| TestCaseError::fail(error.to_string()))?;
+                reference.apply(&delta);
+
+                prop_assert!(snapshot.is_some());
+                assert_book_matches(&book.snapshot(), &reference)?;
+            }
+        }
+
+        #[test]
+        fn test_bybit_option_book_updater(
+            messages in bybit_book_messages(SubscriptionId::from("orderbook.25|BTC-30JUN23-20000-C"), 50, 20, 50)
+        ) {
+            let mut updater = BybitOptionBookUpdater {
+                updates_processed: 0,
+                last_update_id: 0,
+            };
+            let mut book = empty_book();
+            let mut reference = ReferenceBook::default();
+
+            for (delta, bybit) in messages {
+                match bybit.r#type.as_str() {
+                    "snapshot" => reference.replace(&delta),
+                    _ => reference.apply(&delta),
+                }
+                let snapshot = updater
+                    .update(&mut book, BybitOptionBookMessage::Book(bybit))
+                    .map_err(|error| TestCaseError::fail(error.to_string()))?;
+
+                prop_assert!(snapshot.is_some());
+                assert_book_matches(&book.snapshot(), &reference)?;
+            }
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent},
+    exchange::{
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot, us::BinanceUs},
+        bitfinex::Bitfinex,
+        bitmex::Bitmex,
+        bybit::{futures::BybitFuturesUsd, spot::BybitSpot},
+        coinbase::Coinbase,
+        gateio::{futures::GateioFuturesUsd, spot::GateioSpot},
+        kraken::Kraken,
+        okx::Okx,
+        ExchangeId,
+    },
+    schema::VersionedMarketEvent,
+    streams::{builder::StreamBuilder, Streams},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+};
+use barter_integration::{error::SocketError, model::instrument::kind::InstrumentKind};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    ffi::{c_char, c_void, CStr, CString},
+    ptr,
+    thread::JoinHandle,
+};
+use tokio::{runtime::Runtime, sync::mpsc};
+
+/// C callback invoked with each serialised event.
+///
+/// `event` is a NUL terminated UTF-8 JSON encoded
+/// [`VersionedMarketEvent`](crate::schema::VersionedMarketEvent) of `len` bytes (excluding the
+/// NUL terminator), only valid for the duration of the call.
+pub type BarterDataCallback =
+    extern "C" fn(event: *const c_char, len: usize, user_data: *mut c_void);
+
+thread_local! {
+    /// Message of the last error that occurred on this thread, see [`barter_data_last_error`].
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Subscription provided to [`barter_data_subscribe`] as an element of a JSON array.
+///
+/// eg/ `{"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot"}`
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SubscriptionRequest {
+    pub exchange: ExchangeId,
+    pub base: String,
+    pub quote: String,
+    pub instrument_kind: InstrumentKind,
+}
+
+/// Opaque handle to running [`Streams`] that deliver events to a [`BarterDataCallback`].
+///
+/// Must be released with [`barter_data_streams_free`].
+#[derive(Debug)]
+pub struct BarterDataStreams {
+    runtime: Option<Runtime>,
+    delivery: Option<JoinHandle<()>>,
+}
+
+impl Drop for BarterDataStreams {
+    fn drop(&mut self) {
+        // Shutdown the runtime first, dropping every event sender so the delivery thread returns
+        drop(self.runtime.take());
+        if let Some(delivery) = self.delivery.take() {
+            let _ = delivery.join();
+        }
+    }
+}
+
+/// [`BarterDataCallback`] & it's associated user data pointer.
+struct Callback {
+    callback: BarterDataCallback,
+    user_data: *mut c_void,
+}
+
+// Safety: the caller of barter_data_subscribe guarantees the user data may be used from the
+// delivery thread until barter_data_streams_free returns.
+unsafe impl Send for Callback {}
+
+impl Callback {
+    fn call(&self, event: &CStr) {
+        (self.callback)(event.as_ptr(), event.to_bytes().len(), self.user_data)
+    }
+}
+
+/// Generate a function that adds the [`SubscriptionRequest`]s of an exchange to a
+/// [`StreamBuilder`] of the provided [`SubKind`](crate::subscription::SubKind).
+macro_rules! kind_subscriber {
+    ($subscribe:ident, $kind:expr, $Kind:ty, { $($exchange_id:path => $exchange:expr),* $(,)? }) => {
+        fn $subscribe(
+            builder: StreamBuilder<$Kind>,
+            exchange: ExchangeId,
+            requests: &[SubscriptionRequest],
+        ) -> Result<StreamBuilder<$Kind>, DataError> {
+            let subscriptions = requests.iter().map(|request| {
+                (
+                    request.base.as_str(),
+                    request.quote.as_str(),
+                    request.instrument_kind,
+                )
+            });
+
+            match exchange {
+                $($exchange_id => Ok(builder.subscribe(subscriptions.map(
+                    |(base, quote, instrument_kind)| ($exchange, base, quote, instrument_kind, $kind)
+                ))),)*
+                unsupported => Err(DataError::Socket(SocketError::Unsupported {
+                    entity: unsupported.as_str(),
+                    item: stringify!($Kind).to_owned(),
+                })),
+            }
+        }
+    };
+}
+
+kind_subscriber!(subscribe_public_trades, PublicTrades, PublicTrades, {
+    ExchangeId::BinanceSpot => BinanceSpot::default(),
+    ExchangeId::BinanceFuturesUsd => BinanceFuturesUsd::default(),
+    ExchangeId::BinanceUs => BinanceUs::default(),
+    ExchangeId::Bitfinex => Bitfinex,
+    ExchangeId::Bitmex => Bitmex,
+    ExchangeId::BybitSpot => BybitSpot::default(),
+    ExchangeId::BybitFuturesUsd => BybitFuturesUsd::default(),
+    ExchangeId::Coinbase => Coinbase,
+    ExchangeId::GateioSpot => GateioSpot::default(),
+    ExchangeId::GateioFuturesUsd => GateioFuturesUsd::default(),
+    ExchangeId::Kraken => Kraken,
+    ExchangeId::Okx => Okx,
+});
+
+kind_subscriber!(subscribe_order_books_l1, OrderBooksL1, OrderBooksL1, {
+    ExchangeId::BinanceSpot => BinanceSpot::default(),
+    ExchangeId::BinanceFuturesUsd => BinanceFuturesUsd::default(),
+    ExchangeId::BinanceUs => BinanceUs::default(),
+    ExchangeId::Kraken => Kraken,
+});
+
+kind_subscriber!(subscribe_order_books_l2, OrderBooksL2::default(), OrderBooksL2, {
+    ExchangeId::BinanceSpot => BinanceSpot::default(),
+    ExchangeId::BinanceFuturesUsd => BinanceFuturesUsd::default(),
+    ExchangeId::BinanceUs => BinanceUs::default(),
+});
+
+/// Group [`SubscriptionRequest`]s by [`ExchangeId`], so each exchange is actioned on a single
+/// connection.
+fn group_by_exchange(
+    requests: Vec<SubscriptionRequest>,
+) -> BTreeMap<ExchangeId, Vec<SubscriptionRequest>> {
+    requests
+        .into_iter()
+        .fold(BTreeMap::new(), |mut groups, request| {
+            groups.entry(request.exchange).or_default().push(request);
+            groups
+        })
+}
+
+/// Function that adds the [`SubscriptionRequest`]s of an exchange to a [`StreamBuilder`].
+type Subscriber<Kind> = fn(
+    StreamBuilder<Kind>,
+    ExchangeId,
+    &[SubscriptionRequest],
+) -> Result<StreamBuilder<Kind>, DataError>;
+
+/// Build a [`StreamBuilder`] of the provided [`SubscriptionRequest`]s using a [`Subscriber`].
+fn builder<Kind>(
+    requests: Vec<SubscriptionRequest>,
+    subscribe: Subscriber<Kind>,
+) -> Result<StreamBuilder<Kind>, DataError>
+where
+    Kind: crate::subscription::SubKind,
+{
+    group_by_exchange(requests)
+        .into_iter()
+        .try_fold(StreamBuilder::new(), |builder, (exchange, requests)| {
+            subscribe(builder, exchange, &requests)
+        })
+}
+
+/// Initialise [`Streams`] of the provided kind & [`SubscriptionRequest`]s, joined into a single
+/// receiver of [`MarketEvent<DataKind>`]s.
+async fn init(
+    kind: &str,
+    requests: Vec<SubscriptionRequest>,
+) -> Result<mpsc::UnboundedReceiver<MarketEvent<DataKind>>, DataError> {
+    let multi = Streams::<MarketEvent<DataKind>>::builder_multi();
+    let multi = match kind {
+        "public_trades" => multi.add(builder(requests, subscribe_public_trades)?),
+        "order_books_l1" => multi.add(builder(requests, subscribe_order_books_l1)?),
+        "order_books_l2" => multi.add(builder(requests, subscribe_order_books_l2)?),
+        other => {
+            return Err(DataError::Socket(SocketError::Unsupported {
+                entity: "capi",
+                item: other.to_owned(),
+            }))
+        }
+    };
+
+    Ok(multi.init().await?.join().await)
+}
+
+/// Deliver each received [`MarketEvent<DataKind>`] to the [`Callback`] as a JSON encoded
+/// [`VersionedMarketEvent`], until every exchange stream has terminated.
+fn deliver(mut rx: mpsc::UnboundedReceiver<MarketEvent<DataKind>>, callback: Callback) {
+    while let Some(event) = rx.blocking_recv() {
+        let Ok(json) = serde_json::to_string(&VersionedMarketEvent::from(event)) else {
+            continue;
+        };
+
+        // Serialised JSON never contains interior NUL bytes
+        if let Ok(event) = CString::new(json) {
+            callback.call(&event);
+        }
+    }
+}
+
+/// Record the message of an error for retrieval via [`barter_data_last_error`].
+fn set_last_error(error: impl std::fmt::Display) {
+    let message =
+        CString::new(error.to_string().replace('\0', "")).expect("NUL bytes have been removed");
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Parse a NUL terminated UTF-8 C string argument.
+///
+/// # Safety
+/// `value` must be null or point to a valid NUL terminated string.
+unsafe fn parse_str<'a>(name: &str, value: *const c_char) -> Result<&'a str, String> {
+    if value.is_null() {
+        return Err(format!("{name} must not be null"));
+    }
+
+    // Safety: value is non-null & NUL terminated as guaranteed by the caller
+    unsafe { CStr::from_ptr(value) }
+        .to_str()
+        .map_err(|error| format!("{name} is not valid UTF-8: {error}"))
+}
+
+/// Subscribe to the provided JSON array of [`SubscriptionRequest`]s of a kind
+/// ("public_trades", "order_books_l1" or "order_books_l2"), blocking until every exchange
+/// connection is established.
+///
+/// Each subsequent event is delivered to the `callback` from a single dedicated thread in the
+/// order it was received, along with the provided `user_data`.
+///
+/// Returns a handle that must be released with [`barter_data_streams_free`], or null on failure,
+/// in which case [`barter_data_last_error`] describes the failure.
+///
+/// # Safety
+/// - `kind` & `subscriptions` must be valid NUL terminated strings.
+/// - `user_data` must be safe to use from another thread until [`barter_data_streams_free`]
+///   returns.
+#[no_mangle]
+pub unsafe extern "C" fn barter_data_subscribe(
+    kind: *const c_char,
+    subscriptions: *const c_char,
+    callback: BarterDataCallback,
+    user_data: *mut c_void,
+) -> *mut BarterDataStreams {
+    // Safety: arguments are valid NUL terminated strings as guaranteed by the caller
+    let args = unsafe { parse_str("kind", kind) }.and_then(|kind| {
+        let subscriptions = unsafe { parse_str("subscriptions", subscriptions) }?;
+        Ok((kind, subscriptions))
+    });
+
+    let (kind, subscriptions) = match args {
+        Ok(args) => args,
+        Err(error) => {
+            set_last_error(error);
+            return ptr::null_mut();
+        }
+    };
+
+    let requests = match serde_json::from_str::<Vec<SubscriptionRequest>>(subscriptions) {
+        Ok(requests) => requests,
+        Err(error) => {
+            set_last_error(format!("invalid subscriptions: {error}"));
+            return ptr::null_mut();
+        }
+    };
+
+    let runtime = match Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            set_last_error(error);
+            return ptr::null_mut();
+        }
+    };
+
+    let rx = match runtime.block_on(init(kind, requests)) {
+        Ok(rx) => rx,
+        Err(error) => {
+            set_last_error(error);
+            return ptr::null_mut();
+        }
+    };
+
+    let callback = Callback {
+        callback,
+        user_data,
+    };
+
+    let delivery = std::thread::Builder::new()
+        .name("barter-data-capi".to_owned())
+        .spawn(move || deliver(rx, callback));
+
+    match delivery {
+        Ok(delivery) => Box::into_raw(Box::new(BarterDataStreams {
+            runtime: Some(runtime),
+            delivery: Some(delivery),
+        })),
+        Err(error) => {
+            set_last_error(error);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Stop the [`BarterDataStreams`] & release it's resources, blocking until the final callback
+/// invocation has returned. Passing null is a no-op.
+///
+/// # Safety
+/// - `streams` must be null or a handle returned by [`barter_data_subscribe`] that has not
+///   already been released.
+/// - Must not be called from within the [`BarterDataCallback`].
+#[no_mangle]
+pub unsafe extern "C" fn barter_data_streams_free(streams: *mut BarterDataStreams) {
+    if !streams.is_null() {
+        // Safety: streams was allocated by barter_data_subscribe & not yet released
+        drop(unsafe { Box::from_raw(streams) });
+    }
+}
+
+/// Message of the last error that occurred on the calling thread, or null if none has occurred.
+///
+/// The returned string is owned by the library & remains valid until the next failing call on
+/// the same thread.
+#[no_mangle]
+pub extern "C" fn barter_data_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" fn noop(_: *const c_char, _: usize, _: *mut c_void) {}
+
+    fn last_error() -> String {
+        // Safety: barter_data_last_error returns a valid NUL terminated string or null
+        unsafe { CStr::from_ptr(barter_data_last_error()) }
+            .to_str()
+            .unwrap()
+            .to_owned()
+    }
+
+    #[test]
+    fn test_barter_data_subscribe_invalid() {
+        struct TestCase {
+            kind: &'static CStr,
+            subscriptions: &'static CStr,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: unsupported kind
+                kind: c"unknown",
+                subscriptions: c"[]",
+                expected: "unknown",
+            },
+            TestCase {
+                // TC1: invalid subscriptions JSON
+                kind: c"public_trades",
+                subscriptions: c"[{\"exchange\": \"unknown\"}]",
+                expected: "invalid subscriptions",
+            },
+            TestCase {
+                // TC2: exchange does not support the kind
+                kind: c"order_books_l2",
+                subscriptions: c"[{\"exchange\": \"okx\", \"base\": \"btc\", \"quote\": \"usdt\", \"instrument_kind\": \"spot\"}]",
+                expected: "okx",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let streams = unsafe {
+                barter_data_subscribe(
+                    test.kind.as_ptr(),
+                    test.subscriptions.as_ptr(),
+                    noop,
+                    ptr::null_mut(),
+                )
+            };
+            assert!(streams.is_null(), "TC{} failed", index);
+            assert!(last_error().contains(test.expected), "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_group_by_exchange() {
+        let request = |exchange, base: &str| SubscriptionRequest {
+            exchange,
+            base: base.to_owned(),
+            quote: "usdt".to_owned(),
+            instrument_kind: InstrumentKind::Spot,
+        };
+
+        let groups = group_by_exchange(vec![
+            request(ExchangeId::Okx, "btc"),
+            request(ExchangeId::BinanceSpot, "btc"),
+            request(ExchangeId::Okx, "eth"),
+        ]);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            groups[&ExchangeId::Okx],
+            vec![
+                request(ExchangeId::Okx, "btc"),
+                request(ExchangeId::Okx, "eth")
+            ]
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::exchange::{Connector, ExchangeId};
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Default interval between [`ClockSync`] exchange server time queries.
+pub const DEFAULT_CLOCK_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Default absolute clock offset above which [`ClockSync`] warns that the local clock is skewed.
+pub const DEFAULT_CLOCK_SKEW_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// Exchange [`Connector`] that serves it's current server time via HTTP.
+#[async_trait]
+pub trait ServerClock
+where
+    Self: Connector,
+{
+    /// Fetch the current exchange server time.
+    async fn server_time() -> Result<DateTime<Utc>, SocketError>;
+}
+
+/// Estimate the offset of an exchange server clock from the local clock, using a server time
+/// fetched between the local `sent` and `received` times.
+///
+/// Assumes the server time was generated half way through the round trip, so the estimate is
+/// accurate to within half the round trip time.
+pub fn estimate_offset(
+    sent: DateTime<Utc>,
+    server_time: DateTime<Utc>,
+    received: DateTime<Utc>,
+) -> chrono::Duration {
+    let midpoint = sent + (received - sent) / 2;
+    server_time - midpoint
+}
+
+/// Handle to a spawned task that periodically estimates the offset of an exchange server clock
+/// from the local clock using the exchange [`ServerClock`].
+///
+/// Use [`Self::corrected`] to convert local timestamps (eg/ a
+/// [`MarketEvent`](crate::event::MarketEvent) `received_time`) to the exchange clock, so they can
+/// be compared with an `exchange_time`. The task is aborted when the [`ClockSync`] is dropped.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     clock::{ClockSync, DEFAULT_CLOCK_SKEW_THRESHOLD, DEFAULT_CLOCK_SYNC_INTERVAL},
+///     exchange::binance::spot::BinanceSpot,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let clock = ClockSync::spawn::<BinanceSpot>(
+///         DEFAULT_CLOCK_SYNC_INTERVAL,
+///         DEFAULT_CLOCK_SKEW_THRESHOLD,
+///     );
+///
+///     let exchange_now = clock.now();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ClockSync {
+    exchange: ExchangeId,
+    offset_us: Arc<AtomicI64>,
+    task: JoinHandle<()>,
+}
+
+impl ClockSync {
+    /// Spawn a task that queries the exchange [`ServerClock`] every `interval`, starting
+    /// immediately, and warns if the estimated offset exceeds the `skew_threshold`.
+    ///
+    /// Failed queries are logged and retried at the next `interval`. Until the first successful
+    /// query the offset is assumed to be zero.
+    pub fn spawn<Exchange>(interval: Duration, skew_threshold: Duration) -> Self
+    where
+        Exchange: ServerClock,
+    {
+        let exchange = Exchange::ID;
+        let offset_us = Arc::new(AtomicI64::new(0));
+        let skew_threshold_us = i64::try_from(skew_threshold.as_micros()).unwrap_or(i64::MAX);
+
+        let task = tokio::spawn({
+            let offset_us = Arc::clone(&offset_us);
+            async move {
+                let mut interval = tokio::time::interval(interval);
+
+                loop {
+                    interval.tick().await;
+
+                    let sent = Utc::now();
+                    let server_time = match Exchange::server_time().await {
+                        Ok(server_time) => server_time,
+                        Err(error) => {
+                            warn!(%exchange, %error, "failed to fetch exchange server time");
+                            continue;
+                        }
+                    };
+                    let received = Utc::now();
+
+                    let rtt_us = (received - sent).num_microseconds().unwrap_or(i64::MAX);
+                    let offset = estimate_offset(sent, server_time, received)
+                        .num_microseconds()
+                        .unwrap_or(i64::MAX);
+                    offset_us.store(offset, Ordering::Relaxed);
+
+                    if offset.saturating_abs() > skew_threshold_us {
+                        warn!(
+                            %exchange,
+                            offset_us = offset,
+                            rtt_us,
+                            skew_threshold_us,
+                            "local clock skew from exchange server time exceeds threshold"
+                        );
+                    } else {
+                        debug!(%exchange, offset_us = offset, rtt_us, "synchronised exchange clock");
+                    }
+                }
+            }
+        });
+
+        Self {
+            exchange,
+            offset_us,
+            task,
+        }
+    }
+
+    /// [`ExchangeId`] of the exchange server clock being tracked.
+    pub fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+
+    /// Most recent estimate of the exchange server clock offset from the local clock, where a
+    /// positive offset means the server clock is ahead.
+    pub fn offset(&self) -> chrono::Duration {
+        chrono::Duration::microseconds(self.offset_us.load(Ordering::Relaxed))
+    }
+
+    /// Convert a local timestamp to the exchange server clock.
+    pub fn corrected(&self, local: DateTime<Utc>) -> DateTime<Utc> {
+        local + self.offset()
+    }
+
+    /// Current time according to the exchange server clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.corrected(Utc::now())
+    }
+}
+
+impl Drop for ClockSync {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_estimate_offset() {
+        struct TestCase {
+            sent_ms: i64,
+            server_ms: i64,
+            received_ms: i64,
+            expected_ms: i64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: synchronised clocks
+                sent_ms: 1_000,
+                server_ms: 1_050,
+                received_ms: 1_100,
+                expected_ms: 0,
+            },
+            TestCase {
+                // TC1: server clock ahead of local clock
+                sent_ms: 1_000,
+                server_ms: 1_550,
+                received_ms: 1_100,
+                expected_ms: 500,
+            },
+            TestCase {
+                // TC2: server clock behind local clock
+                sent_ms: 1_000,
+                server_ms: 800,
+                received_ms: 1_100,
+                expected_ms: -250,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = estimate_offset(
+                Utc.timestamp_millis_opt(test.sent_ms).unwrap(),
+                Utc.timestamp_millis_opt(test.server_ms).unwrap(),
+                Utc.timestamp_millis_opt(test.received_ms).unwrap(),
+            );
+            assert_eq!(
+                actual,
+                chrono::Duration::milliseconds(test.expected_ms),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{
+    event::MarketEvent,
+    spec::{InstrumentSpec, InstrumentSpecMap},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Utc};
+use smallvec::SmallVec;
+use std::collections::{hash_map::Entry, HashMap};
+use tracing::debug;
+
+/// [`ShardedBookManager`](shard::ShardedBookManager) that partitions the [`CompactOrderBook`]s
+/// of many exchange [`Instrument`]s across tasks, with a unified query facade.
+pub mod shard;
+
+/// Number of [`CompactLevel`]s per [`CompactBookSide`] stored inline, above which the levels
+/// spill to the heap.
+pub const INLINE_LEVELS: usize = 32;
+
+/// Fixed-point scale of an exchange [`Instrument`], converting prices to integer ticks & amounts
+/// to integer lots using it's [`InstrumentSpec`] tick size & lot size.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FixedPointScale {
+    tick_size: f64,
+    lot_size: f64,
+}
+
+impl FixedPointScale {
+    /// Construct a new [`Self`] from the provided tick size & lot size, returning `None` if
+    /// either is not positive.
+    pub fn new(tick_size: f64, lot_size: f64) -> Option<Self> {
+        (tick_size > 0.0 && lot_size > 0.0).then_some(Self {
+            tick_size,
+            lot_size,
+        })
+    }
+
+    /// Construct a new [`Self`] from the tick size & lot size of the provided [`InstrumentSpec`].
+    pub fn from_spec(spec: &InstrumentSpec) -> Option<Self> {
+        Self::new(spec.tick_size, spec.lot_size)
+    }
+
+    /// Convert a price to the nearest number of ticks.
+    pub fn ticks(&self, price: f64) -> i64 {
+        (price / self.tick_size).round() as i64
+    }
+
+    /// Convert a number of ticks to a price.
+    pub fn price(&self, ticks: i64) -> f64 {
+        ticks as f64 * self.tick_size
+    }
+
+    /// Convert an amount to the nearest number of lots.
+    pub fn lots(&self, amount: f64) -> u64 {
+        (amount / self.lot_size).round() as u64
+    }
+
+    /// Convert a number of lots to an amount.
+    pub fn amount(&self, lots: u64) -> f64 {
+        lots as f64 * self.lot_size
+    }
+
+    /// Convert a [`Level`] to a fixed-point [`CompactLevel`].
+    pub fn compact(&self, level: Level) -> CompactLevel {
+        CompactLevel {
+            ticks: self.ticks(level.price),
+            lots: self.lots(level.amount),
+        }
+    }
+
+    /// Convert a fixed-point [`CompactLevel`] to a [`Level`].
+    pub fn level(&self, level: CompactLevel) -> Level {
+        Level::new(self.price(level.ticks), self.amount(level.lots))
+    }
+}
+
+/// Fixed-point OrderBook [`Level`], where the price is a number of ticks & the amount is a number
+/// of lots (see [`FixedPointScale`]).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct CompactLevel {
+    pub ticks: i64,
+    pub lots: u64,
+}
+
+/// [`CompactLevel`]s for one [`Side`] of a [`CompactOrderBook`], sorted from best to worst.
+///
+/// The first [`INLINE_LEVELS`] are stored inline, so typical book depths require no heap
+/// allocation, and each upsert is a binary search of contiguous integer levels.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CompactBookSide {
+    side: Side,
+    levels: SmallVec<[CompactLevel; INLINE_LEVELS]>,
+}
+
+impl CompactBookSide {
+    /// Construct a new empty [`Self`].
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            levels: SmallVec::new(),
+        }
+    }
+
+    /// [`CompactLevel`]s of this [`CompactBookSide`], sorted from best to worst.
+    pub fn levels(&self) -> &[CompactLevel] {
+        &self.levels
+    }
+
+    /// Best [`CompactLevel`] of this [`CompactBookSide`], if any.
+    pub fn best(&self) -> Option<CompactLevel> {
+        self.levels.first().copied()
+    }
+
+    /// Upsert a single [`CompactLevel`], removing the existing level at the same price if the
+    /// new amount is zero.
+    pub fn upsert(&mut self, new_level: CompactLevel) {
+        let side = self.side;
+        let position = self.levels.binary_search_by(|level| match side {
+            Side::Buy => new_level.ticks.cmp(&level.ticks),
+            Side::Sell => level.ticks.cmp(&new_level.ticks),
+        });
+
+        match position {
+            Ok(index) if new_level.lots == 0 => {
+                self.levels.remove(index);
+            }
+            Ok(index) => self.levels[index] = new_level,
+            Err(index) if new_level.lots > 0 => self.levels.insert(index, new_level),
+            Err(_) => {
+                debug!(?new_level, side = %self.side, "CompactLevel to remove not found");
+            }
+        }
+    }
+
+    /// Replace every [`CompactLevel`] of this [`CompactBookSide`] with the provided levels.
+    pub fn replace<Iter>(&mut self, levels: Iter)
+    where
+        Iter: IntoIterator<Item = CompactLevel>,
+    {
+        self.levels.clear();
+        self.levels
+            .extend(levels.into_iter().filter(|level| level.lots > 0));
+        let side = self.side;
+        self.levels.sort_unstable_by(|a, b| match side {
+            Side::Buy => b.ticks.cmp(&a.ticks),
+            Side::Sell => a.ticks.cmp(&b.ticks),
+        });
+        self.levels.dedup_by_key(|level| level.ticks);
+    }
+}
+
+/// Memory compact local [`OrderBook`] of an exchange [`Instrument`], storing fixed-point
+/// [`CompactLevel`]s in [`CompactBookSide`]s.
+///
+/// Prices & amounts are rounded to the nearest tick & lot of the [`FixedPointScale`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct CompactOrderBook {
+    pub scale: FixedPointScale,
+    pub last_update_time: DateTime<Utc>,
+    pub sequence: Option<u64>,
+    pub bids: CompactBookSide,
+    pub asks: CompactBookSide,
+}
+
+impl CompactOrderBook {
+    /// Construct a new empty [`Self`] with the provided [`FixedPointScale`].
+    pub fn new(scale: FixedPointScale) -> Self {
+        Self {
+            scale,
+            last_update_time: DateTime::<Utc>::MIN_UTC,
+            sequence: None,
+            bids: CompactBookSide::new(Side::Buy),
+            asks: CompactBookSide::new(Side::Sell),
+        }
+    }
+
+    /// Apply an [`OrderBook`], replacing the book if it is a full snapshot, or upserting it's
+    /// [`Level`]s if it is a delta (see [`OrderBook::delta`]).
+    pub fn apply(&mut self, book: &OrderBook) {
+        let scale = self.scale;
+        let bids = book.bids.levels().iter().map(|level| scale.compact(*level));
+        let asks = book.asks.levels().iter().map(|level| scale.compact(*level));
+
+        if book.delta {
+            bids.for_each(|level| self.bids.upsert(level));
+            asks.for_each(|level| self.asks.upsert(level));
+        } else {
+            self.bids.replace(bids);
+            self.asks.replace(asks);
+        }
+
+        self.last_update_time = book.last_update_time;
+        self.sequence = book.sequence.or(self.sequence);
+    }
+
+    /// Best bid [`Level`], if any.
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids.best().map(|level| self.scale.level(level))
+    }
+
+    /// Best ask [`Level`], if any.
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks.best().map(|level| self.scale.level(level))
+    }
+
+    /// Generate a full sorted [`OrderBook`] snapshot of [`Self`].
+    pub fn snapshot(&self) -> OrderBook {
+        let levels = |side: &CompactBookSide| {
+            side.levels()
+                .iter()
+                .map(|level| self.scale.level(*level))
+                .collect::<Vec<_>>()
+        };
+
+        OrderBook {
+            last_update_time: self.last_update_time,
+            bids: OrderBookSide::new(Side::Buy, levels(&self.bids)),
+            asks: OrderBookSide::new(Side::Sell, levels(&self.asks)),
+            sequence: self.sequence,
+            delta: false,
+        }
+    }
+}
+
+/// Book engine maintaining a [`CompactOrderBook`] for each exchange [`Instrument`], so thousands
+/// of local books can be held simultaneously.
+///
+/// Each [`MarketEvent<OrderBook>`] (full snapshot or delta) is applied to the
+/// [`CompactOrderBook`] of it's exchange [`Instrument`], scaled by the [`FixedPointScale`] of
+/// it's [`InstrumentSpec`]. Events of [`Instrument`]s without a [`FixedPointScale`] are ignored.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DepthEngine {
+    scales: HashMap<(Exchange, Instrument), FixedPointScale>,
+    books: HashMap<(Exchange, Instrument), CompactOrderBook>,
+}
+
+impl DepthEngine {
+    /// Construct a new [`Self`] using the [`FixedPointScale`] of each [`InstrumentSpec`] in the
+    /// provided [`InstrumentSpecMap`].
+    pub fn new(specs: &InstrumentSpecMap) -> Self {
+        specs
+            .0
+            .iter()
+            .filter_map(|((exchange, instrument), spec)| {
+                FixedPointScale::from_spec(spec)
+                    .map(|scale| (Exchange::from(*exchange), instrument.clone(), scale))
+            })
+            .fold(Self::default(), |engine, (exchange, instrument, scale)| {
+                engine.with_scale(exchange, instrument, scale)
+            })
+    }
+
+    /// Add (or replace) the [`FixedPointScale`] of an exchange [`Instrument`].
+    pub fn with_scale(
+        mut self,
+        exchange: Exchange,
+        instrument: Instrument,
+        scale: FixedPointScale,
+    ) -> Self {
+        self.insert_scale(exchange, instrument, scale);
+        self
+    }
+
+    /// Insert (or replace) the [`FixedPointScale`] of an exchange [`Instrument`].
+    pub fn insert_scale(
+        &mut self,
+        exchange: Exchange,
+        instrument: Instrument,
+        scale: FixedPointScale,
+    ) {
+        self.scales.insert((exchange, instrument), scale);
+    }
+
+    /// Apply a [`MarketEvent<OrderBook>`] to the [`CompactOrderBook`] of it's exchange
+    /// [`Instrument`], returning the updated [`CompactOrderBook`].
+    ///
+    /// Returns `None` if the exchange [`Instrument`] has no [`FixedPointScale`].
+    pub fn process(&mut self, event: &MarketEvent<OrderBook>) -> Option<&CompactOrderBook> {
+        let key = (event.exchange.clone(), event.instrument.clone());
+
+        let book = match self.books.entry(key) {
+            Entry::Occupied(book) => book.into_mut(),
+            Entry::Vacant(entry) => {
+                let Some(scale) = self.scales.get(entry.key()) else {
+                    debug!(
+                        exchange = %event.exchange,
+                        instrument = %event.instrument,
+                        "ignoring OrderBook of Instrument without a FixedPointScale"
+                    );
+                    return None;
+                };
+                entry.insert(CompactOrderBook::new(*scale))
+            }
+        };
+
+        book.apply(&event.kind);
+        Some(book)
+    }
+
+    /// Get the [`CompactOrderBook`] of an exchange [`Instrument`].
+    pub fn book(&self, exchange: &Exchange, instrument: &Instrument) -> Option<&CompactOrderBook> {
+        self.books.get(&(exchange.clone(), instrument.clone()))
+    }
+
+    /// Number of [`CompactOrderBook`]s maintained.
+    pub fn len(&self) -> usize {
+        self.books.len()
+    }
+
+    /// Determine if no [`CompactOrderBook`]s are maintained.
+    pub fn is_empty(&self) -> bool {
+        self.books.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, fixture::market_event};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn scale() -> FixedPointScale {
+        FixedPointScale::new(0.5, 0.25).unwrap()
+    }
+
+    #[test]
+    fn test_fixed_point_scale() {
+        let scale = scale();
+        assert_eq!(scale.ticks(100.5), 201);
+        assert_eq!(scale.price(201), 100.5);
+        assert_eq!(scale.lots(1.5), 6);
+        assert_eq!(scale.amount(6), 1.5);
+        assert!(FixedPointScale::new(0.0, 0.1).is_none());
+    }
+
+    #[test]
+    fn test_compact_book_side_upsert() {
+        struct TestCase {
+            side: Side,
+            input: Vec<CompactLevel>,
+            expected: Vec<i64>,
+        }
+
+        let level = |ticks: i64, lots: u64| CompactLevel { ticks, lots };
+
+        let cases = vec![
+            // TC0: bids sorted best (highest) first
+            TestCase {
+                side: Side::Buy,
+                input: vec![level(10, 1), level(12, 1), level(11, 1)],
+                expected: vec![12, 11, 10],
+            },
+            // TC1: asks sorted best (lowest) first
+            TestCase {
+                side: Side::Sell,
+                input: vec![level(10, 1), level(12, 1), level(11, 1)],
+                expected: vec![10, 11, 12],
+            },
+            // TC2: zero lots removes an existing level, and ignores a missing level
+            TestCase {
+                side: Side::Buy,
+                input: vec![level(10, 1), level(11, 1), level(10, 0), level(9, 0)],
+                expected: vec![11],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut side = CompactBookSide::new(test.side);
+            test.input.into_iter().for_each(|level| side.upsert(level));
+            let actual = side
+                .levels()
+                .iter()
+                .map(|level| level.ticks)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_depth_engine_process() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut engine = DepthEngine::default().with_scale(
+            Exchange::from(ExchangeId::BinanceSpot),
+            btc_usdt.clone(),
+            scale(),
+        );
+
+        let event = |instrument: &Instrument, bids: Vec<(f64, f64)>, delta: bool| MarketEvent {
+            instrument: instrument.clone(),
+            ..market_event(OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, vec![(101.0, 1.0)]),
+                sequence: Some(1),
+                delta,
+            })
+        };
+
+        // Full snapshot replaces the book
+        let book = engine
+            .process(&event(&btc_usdt, vec![(99.0, 1.0), (100.0, 2.0)], false))
+            .unwrap();
+        assert_eq!(book.best_bid(), Some(Level::new(100.0, 2.0)));
+        assert_eq!(book.best_ask(), Some(Level::new(101.0, 1.0)));
+
+        // Delta upserts the changed levels
+        let book = engine
+            .process(&event(&btc_usdt, vec![(100.0, 0.0), (99.5, 3.0)], true))
+            .unwrap();
+        assert_eq!(book.best_bid(), Some(Level::new(99.5, 3.0)));
+        assert_eq!(
+            book.snapshot().bids.levels(),
+            &[Level::new(99.5, 3.0), Level::new(99.0, 1.0)]
+        );
+
+        // Instruments without a FixedPointScale are ignored
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        assert!(engine
+            .process(&event(&eth_usdt, vec![(1.0, 1.0)], false))
+            .is_none());
+        assert_eq!(engine.len(), 1);
+    }
+}
@@ -0,0 +1,295 @@
+use super::{CompactOrderBook, DepthEngine, FixedPointScale};
+use crate::{
+    event::MarketEvent,
+    spec::InstrumentSpecMap,
+    subscription::{
+        book::{Level, OrderBook},
+        route::FxBuildHasher,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use std::{hash::BuildHasher, num::NonZeroUsize, sync::Arc};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+use tracing::debug;
+
+/// Default number of shards of a [`ShardedBookManager`].
+pub const DEFAULT_BOOK_SHARDS: usize = 8;
+
+/// Query of the [`CompactOrderBook`] of an exchange [`Instrument`], run by it's shard task.
+type BookQuery = Box<dyn FnOnce(Option<&CompactOrderBook>) + Send>;
+
+/// Command sent to a shard task of a [`ShardedBookManager`].
+enum ShardCommand {
+    Apply(MarketEvent<OrderBook>),
+    Query {
+        exchange: Exchange,
+        instrument: Instrument,
+        query: BookQuery,
+    },
+    Len(oneshot::Sender<usize>),
+}
+
+/// Book manager that partitions the [`CompactOrderBook`]s of many exchange [`Instrument`]s
+/// across N shards by the hash of each exchange [`Instrument`], where every shard is a task
+/// owning a [`DepthEngine`].
+///
+/// Updates of different shards are applied concurrently without a shared lock, and the
+/// [`ShardedBookManager`] acts as a unified query facade that routes each query to the shard of
+/// the requested exchange [`Instrument`]. Updates & queries of a shard are processed in the
+/// order they are sent.
+///
+/// Cloning a [`ShardedBookManager`] yields a handle to the same shards, and each shard task
+/// stops once every handle has been dropped.
+#[derive(Clone, Debug)]
+pub struct ShardedBookManager {
+    shards: Arc<[mpsc::UnboundedSender<ShardCommand>]>,
+}
+
+impl ShardedBookManager {
+    /// Construct a new [`Self`] with the provided number of shards, spawning a task per shard
+    /// that maintains the [`CompactOrderBook`]s of it's partition, scaled by the
+    /// [`FixedPointScale`] of each [`InstrumentSpec`](crate::spec::InstrumentSpec) in the
+    /// [`InstrumentSpecMap`].
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn new(specs: &InstrumentSpecMap, shards: NonZeroUsize) -> Self {
+        let mut engines = vec![DepthEngine::default(); shards.get()];
+        for ((exchange, instrument), spec) in specs.0.iter() {
+            let Some(scale) = FixedPointScale::from_spec(spec) else {
+                continue;
+            };
+
+            let exchange = Exchange::from(*exchange);
+            let shard = shard_index(&exchange, instrument, shards.get());
+            engines[shard].insert_scale(exchange, instrument.clone(), scale);
+        }
+
+        let shards = engines
+            .into_iter()
+            .map(|engine| {
+                let (shard_tx, shard_rx) = mpsc::unbounded_channel();
+                tokio::spawn(run_shard(engine, shard_rx));
+                shard_tx
+            })
+            .collect();
+
+        Self { shards }
+    }
+
+    /// Number of shards of this [`ShardedBookManager`].
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Apply a [`MarketEvent<OrderBook>`] (full snapshot or delta) to the [`CompactOrderBook`]
+    /// of it's exchange [`Instrument`], without waiting for it to be applied.
+    pub fn apply(&self, event: MarketEvent<OrderBook>) {
+        let shard = self.shard(&event.exchange, &event.instrument);
+        if shard.send(ShardCommand::Apply(event)).is_err() {
+            debug!("book shard stopped - dropping OrderBook update");
+        }
+    }
+
+    /// Spawn a task that applies every [`MarketEvent<OrderBook>`] received on the `event_rx`
+    /// (eg/ an [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) stream).
+    pub fn feed(
+        &self,
+        mut event_rx: mpsc::UnboundedReceiver<MarketEvent<OrderBook>>,
+    ) -> JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            while let Some(event) = event_rx.recv().await {
+                manager.apply(event);
+            }
+        })
+    }
+
+    /// Run a query of the [`CompactOrderBook`] of an exchange [`Instrument`] on it's shard,
+    /// returning `None` if the book is not maintained.
+    pub async fn query<F, T>(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        query: F,
+    ) -> Option<T>
+    where
+        F: FnOnce(&CompactOrderBook) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (response_tx, response_rx) = oneshot::channel();
+        let query: BookQuery = Box::new(move |book| {
+            let _ = response_tx.send(book.map(query));
+        });
+
+        self.shard(exchange, instrument)
+            .send(ShardCommand::Query {
+                exchange: exchange.clone(),
+                instrument: instrument.clone(),
+                query,
+            })
+            .ok()?;
+
+        response_rx.await.ok().flatten()
+    }
+
+    /// Full sorted [`OrderBook`] snapshot of an exchange [`Instrument`].
+    pub async fn snapshot(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Option<OrderBook> {
+        self.query(exchange, instrument, CompactOrderBook::snapshot)
+            .await
+    }
+
+    /// Best bid & ask [`Level`]s of an exchange [`Instrument`].
+    pub async fn top_of_book(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Option<(Option<Level>, Option<Level>)> {
+        self.query(exchange, instrument, |book| {
+            (book.best_bid(), book.best_ask())
+        })
+        .await
+    }
+
+    /// Total number of [`CompactOrderBook`]s maintained across every shard.
+    pub async fn len(&self) -> usize {
+        let mut len = 0;
+        for shard in self.shards.iter() {
+            let (len_tx, len_rx) = oneshot::channel();
+            if shard.send(ShardCommand::Len(len_tx)).is_ok() {
+                len += len_rx.await.unwrap_or_default();
+            }
+        }
+        len
+    }
+
+    /// Determine if no [`CompactOrderBook`]s are maintained by any shard.
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+
+    fn shard(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> &mpsc::UnboundedSender<ShardCommand> {
+        &self.shards[shard_index(exchange, instrument, self.shards.len())]
+    }
+}
+
+impl std::fmt::Debug for ShardCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Apply(event) => f.debug_tuple("Apply").field(event).finish(),
+            Self::Query {
+                exchange,
+                instrument,
+                ..
+            } => f
+                .debug_struct("Query")
+                .field("exchange", exchange)
+                .field("instrument", instrument)
+                .finish_non_exhaustive(),
+            Self::Len(_) => f.write_str("Len"),
+        }
+    }
+}
+
+/// Determine the shard of an exchange [`Instrument`] by it's hash.
+fn shard_index(exchange: &Exchange, instrument: &Instrument, shards: usize) -> usize {
+    (FxBuildHasher::default().hash_one((exchange, instrument)) % shards as u64) as usize
+}
+
+/// Run a shard of a [`ShardedBookManager`], applying updates & answering queries until every
+/// [`ShardedBookManager`] handle has been dropped.
+async fn run_shard(mut engine: DepthEngine, mut shard_rx: mpsc::UnboundedReceiver<ShardCommand>) {
+    while let Some(command) = shard_rx.recv().await {
+        match command {
+            ShardCommand::Apply(event) => {
+                engine.process(&event);
+            }
+            ShardCommand::Query {
+                exchange,
+                instrument,
+                query,
+            } => query(engine.book(&exchange, &instrument)),
+            ShardCommand::Len(len_tx) => {
+                let _ = len_tx.send(engine.len());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::ExchangeId, fixture::market_event, spec::InstrumentSpec,
+        subscription::book::OrderBookSide,
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::Utc;
+
+    fn event(instrument: &Instrument, bid: f64, ask: f64) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            instrument: instrument.clone(),
+            ..market_event(OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, vec![(bid, 1.0)]),
+                asks: OrderBookSide::new(Side::Sell, vec![(ask, 1.0)]),
+                sequence: None,
+                delta: false,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sharded_book_manager() {
+        let exchange = Exchange::from(ExchangeId::BinanceSpot);
+        let instruments = ["btc", "eth", "sol", "xrp", "ada"]
+            .map(|base| Instrument::from((base, "usdt", InstrumentKind::Spot)));
+
+        let mut specs = InstrumentSpecMap::default();
+        for instrument in instruments.iter() {
+            specs.insert(
+                ExchangeId::BinanceSpot,
+                instrument.clone(),
+                InstrumentSpec {
+                    tick_size: 0.5,
+                    lot_size: 0.25,
+                    contract_multiplier: 1.0,
+                },
+            );
+        }
+
+        let manager = ShardedBookManager::new(&specs, NonZeroUsize::new(3).unwrap());
+        assert_eq!(manager.shards(), 3);
+        assert!(manager.is_empty().await);
+
+        for (index, instrument) in instruments.iter().enumerate() {
+            manager.apply(event(instrument, index as f64, index as f64 + 1.0));
+        }
+        assert_eq!(manager.len().await, instruments.len());
+
+        // Every query is routed to the shard of the requested Instrument
+        for (index, instrument) in instruments.iter().enumerate() {
+            let actual = manager.top_of_book(&exchange, instrument).await;
+            let expected = (
+                Some(Level::new(index as f64, 1.0)),
+                Some(Level::new(index as f64 + 1.0, 1.0)),
+            );
+            assert_eq!(actual, Some(expected), "TC{} failed", index);
+        }
+
+        // Books that are not maintained yield None
+        let doge_usdt = Instrument::from(("doge", "usdt", InstrumentKind::Spot));
+        manager.apply(event(&doge_usdt, 1.0, 2.0));
+        assert_eq!(manager.snapshot(&exchange, &doge_usdt).await, None);
+    }
+}
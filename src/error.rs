@@ -1,4 +1,8 @@
-use barter_integration::error::SocketError;
+use crate::{exchange::ExchangeId, streams::limits::FrameLimitError};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsError,
+};
+use std::time::Duration;
 use thiserror::Error;
 
 /// All errors generated in `barter-data`.
@@ -17,6 +21,50 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error("SessionExpired: {0} authenticated session expired")]
+    SessionExpired(ExchangeId),
+
+    #[error("FIX error: {0}")]
+    Fix(String),
+
+    #[error("Protobuf error: {0}")]
+    Proto(String),
+
+    #[error("Record error: {0}")]
+    Record(String),
+
+    #[error("Lagged: consumer overrun by the producer, skipped {0} bytes")]
+    Lagged(u64),
+
+    #[error("Reset: shared memory producer reset at epoch {0}, skipped to the latest record")]
+    Reset(u64),
+
+    #[error(
+        "CircuitBreakerTripped: {exchange} exceeded {max_errors} deserialisation errors per {window:?}"
+    )]
+    CircuitBreakerTripped {
+        exchange: ExchangeId,
+        max_errors: u32,
+        window: Duration,
+    },
+
+    #[error(
+        "TradeGap: {exchange} {instrument} missed {missed} trades after trade id {last_trade_id}"
+    )]
+    TradeGap {
+        exchange: ExchangeId,
+        instrument: Instrument,
+        last_trade_id: u64,
+        missed: u64,
+    },
+
+    #[error("InvalidChecksum: {exchange} OrderBook checksum {actual} does not match {expected}")]
+    InvalidChecksum {
+        exchange: ExchangeId,
+        expected: u32,
+        actual: u32,
+    },
 }
 
 impl DataError {
@@ -24,15 +72,39 @@ impl DataError {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_terminal(&self) -> bool {
         match self {
-            DataError::InvalidSequence { .. } => true,
+            DataError::InvalidSequence { .. }
+            | DataError::SessionExpired(_)
+            | DataError::CircuitBreakerTripped { .. } => true,
             _ => false,
         }
     }
+
+    /// Determine the [`FrameLimitError`] of a received frame that exceeded the
+    /// [`FrameLimits`](crate::streams::limits::FrameLimits) of it's connection, if any.
+    pub fn frame_limit(&self) -> Option<&FrameLimitError> {
+        match self {
+            DataError::Socket(SocketError::WebSocket(WsError::Io(error))) => {
+                error.get_ref()?.downcast_ref::<FrameLimitError>()
+            }
+            _ => None,
+        }
+    }
+
+    /// Determine if an error was caused by an exchange message that could not be deserialised.
+    pub fn is_deserialise(&self) -> bool {
+        matches!(
+            self,
+            DataError::Socket(
+                SocketError::Deserialise { .. } | SocketError::DeserialiseBinary { .. }
+            )
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
 
     #[test]
     fn test_data_error_is_terminal() {
@@ -51,10 +123,34 @@ mod tests {
                 expected: true,
             },
             TestCase {
-                // TC1: is not terminal w/ DataError::Socket
+                // TC1: is terminal w/ DataError::SessionExpired
+                input: DataError::SessionExpired(ExchangeId::BinanceSpot),
+                expected: true,
+            },
+            TestCase {
+                // TC2: is not terminal w/ DataError::Socket
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
+            TestCase {
+                // TC3: is terminal w/ DataError::CircuitBreakerTripped
+                input: DataError::CircuitBreakerTripped {
+                    exchange: ExchangeId::BinanceSpot,
+                    max_errors: 10,
+                    window: Duration::from_secs(60),
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC4: is not terminal w/ DataError::TradeGap
+                input: DataError::TradeGap {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    last_trade_id: 10,
+                    missed: 2,
+                },
+                expected: false,
+            },
         ];
 
         for (index, test) in tests.into_iter().enumerate() {
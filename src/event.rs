@@ -4,12 +4,18 @@ use crate::{
     subscription::{
         book::{OrderBook, OrderBookL1},
         candle::Candle,
+        greeks::Greeks,
+        ticker::{MiniTicker, Ticker},
         trade::PublicTrade,
     },
 };
-use barter_integration::model::{Exchange, Instrument};
+use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::{
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
 
 /// Convenient new type containing a collection of [`MarketEvent<T>`](MarketEvent)s.
 #[derive(Debug)]
@@ -38,11 +44,70 @@ impl<T> FromIterator<Result<MarketEvent<T>, DataError>> for MarketIter<T> {
 pub struct MarketEvent<T> {
     pub exchange_time: DateTime<Utc>,
     pub received_time: DateTime<Utc>,
+    /// Monotonic receive timestamp, unaffected by wall-clock adjustments (eg/ NTP jumps), so it
+    /// should be used when measuring latencies between events.
+    ///
+    /// Not serialised since it is only comparable within the receiving process, so deserialised
+    /// events have the default [`MonotonicTime`].
+    #[serde(skip)]
+    pub received_monotonic: MonotonicTime,
+    /// Whether the event was received in real-time, or generated by a backfill or replay.
+    #[serde(default)]
+    pub origin: Origin,
     pub exchange: Exchange,
     pub instrument: Instrument,
     pub kind: T,
 }
 
+/// Monotonic nanosecond timestamp, measured from a process wide [`Instant`] anchor initialised
+/// by the first call to [`MonotonicTime::now`].
+///
+/// Unlike a wall-clock [`DateTime<Utc>`], a [`MonotonicTime`] never goes backwards, but it is only
+/// comparable with other [`MonotonicTime`]s generated by the same process.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct MonotonicTime(pub u64);
+
+impl MonotonicTime {
+    /// Construct a [`MonotonicTime`] representing the current instant.
+    pub fn now() -> Self {
+        static ANCHOR: OnceLock<Instant> = OnceLock::new();
+        let nanos = ANCHOR.get_or_init(Instant::now).elapsed().as_nanos();
+        Self(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+
+    /// [`Duration`] elapsed from an `earlier` [`MonotonicTime`] to this one, or zero if `earlier`
+    /// is later than this one.
+    pub fn duration_since(self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0))
+    }
+}
+
+/// Origin of a [`MarketEvent<T>`](MarketEvent), allowing consumers to treat warm-up data
+/// differently from real-time data (eg/ to avoid trading on it).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Origin {
+    /// Received in real-time from an exchange stream.
+    #[default]
+    Live,
+    /// Fetched after the fact to fill a gap in, or warm-up, an exchange stream (eg/ via HTTP).
+    Backfill,
+    /// Re-generated from previously captured data (eg/ an archived
+    /// [`RawMessage`](crate::streams::tap::RawMessage) replay).
+    SnapshotReplay,
+}
+
+impl Origin {
+    /// Determine if the [`MarketEvent<T>`](MarketEvent) was received in real-time.
+    pub fn is_live(&self) -> bool {
+        matches!(self, Self::Live)
+    }
+}
+
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
 ///
 /// ### Notes
@@ -60,6 +125,9 @@ pub enum DataKind {
     OrderBook(OrderBook),
     Candle(Candle),
     Liquidation(Liquidation),
+    Greeks(Greeks),
+    Ticker(Ticker),
+    MiniTicker(MiniTicker),
 }
 
 impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
@@ -67,6 +135,8 @@ impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::Trade(event.kind),
@@ -79,6 +149,8 @@ impl From<MarketEvent<OrderBookL1>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::OrderBookL1(event.kind),
@@ -91,6 +163,8 @@ impl From<MarketEvent<OrderBook>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::OrderBook(event.kind),
@@ -103,6 +177,8 @@ impl From<MarketEvent<Candle>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::Candle(event.kind),
@@ -115,9 +191,126 @@ impl From<MarketEvent<Liquidation>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::Liquidation(event.kind),
         }
     }
 }
+
+impl From<MarketEvent<Greeks>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Greeks>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::Greeks(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<Ticker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Ticker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::Ticker(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<MiniTicker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<MiniTicker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::MiniTicker(event.kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::market_event;
+
+    #[test]
+    fn test_monotonic_time() {
+        let earlier = MonotonicTime::now();
+        let later = MonotonicTime::now();
+
+        assert!(later >= earlier);
+        assert_eq!(
+            MonotonicTime(1_500).duration_since(MonotonicTime(500)),
+            Duration::from_nanos(1_000)
+        );
+        assert_eq!(
+            MonotonicTime(500).duration_since(MonotonicTime(1_500)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_de_market_event_origin() {
+        let event = MarketEvent {
+            received_monotonic: MonotonicTime::default(),
+            origin: Origin::Backfill,
+            ..market_event(1u8)
+        };
+
+        let mut json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["origin"], "backfill");
+        assert_eq!(
+            serde_json::from_value::<MarketEvent<u8>>(json.clone()).unwrap(),
+            event
+        );
+
+        // Events serialised without an origin are live
+        json.as_object_mut().unwrap().remove("origin");
+        let actual = serde_json::from_value::<MarketEvent<u8>>(json).unwrap();
+        assert_eq!(actual.origin, Origin::Live);
+    }
+
+    #[test]
+    fn test_de_market_event_received_monotonic() {
+        // MarketEvent recorded before the received_monotonic & origin fields were added
+        let input = r#"
+        {
+            "exchange_time": "2023-01-01T00:00:00Z",
+            "received_time": "2023-01-01T00:00:01Z",
+            "exchange": "binance_spot",
+            "instrument": {"base": "btc", "quote": "usdt", "instrument_kind": "spot"},
+            "kind": 1
+        }
+        "#;
+
+        let event = serde_json::from_str::<MarketEvent<u8>>(input).unwrap();
+        assert_eq!(event.received_monotonic, MonotonicTime::default());
+        assert_eq!(event.origin, Origin::Live);
+
+        // Process local received_monotonic is not serialised
+        let mut event = market_event(1u8);
+        event.received_monotonic = MonotonicTime(1_000);
+        let json = serde_json::to_value(&event).unwrap();
+        assert!(json.get("received_monotonic").is_none());
+        assert_eq!(
+            serde_json::from_value::<MarketEvent<u8>>(json)
+                .unwrap()
+                .received_monotonic,
+            MonotonicTime::default()
+        );
+    }
+}
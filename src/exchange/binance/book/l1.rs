@@ -1,10 +1,13 @@
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{binance::channel::BinanceChannel, subscription::ExchangeSub, ExchangeId},
     subscription::book::{Level, OrderBookL1},
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use barter_integration::{
+    de::datetime_utc_from_epoch_duration,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -28,18 +31,34 @@ use serde::{Deserialize, Serialize};
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
 /// ```json
 /// {
-///     "u":22606535573,
-///     "s":"ETHUSDT",
-///     "b":"1215.27000000",
-///     "B":"32.49110000",
-///     "a":"1215.28000000",
-///     "A":"13.93900000"
+///     "e":"bookTicker",
+///     "u":2286618712950,
+///     "s":"BTCUSDT",
+///     "b":"16858.90",
+///     "B":"13.692",
+///     "a":"16859.00",
+///     "A":"30.219",
+///     "T":1671621244670,
+///     "E":1671621244673
 /// }
 /// ```
+///
+/// Notes:
+///  - Only [`BinanceFuturesUsd`](super::super::futures::BinanceFuturesUsd) sends the
+///    transaction time "T" and event time "E". [`BinanceSpot`](super::super::spot::BinanceSpot)
+///    payloads default the `time` to [`Utc::now`] and have no `event_time`.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BinanceOrderBookL1 {
     #[serde(alias = "s", deserialize_with = "de_ob_l1_subscription_id")]
     pub subscription_id: SubscriptionId,
+    #[serde(alias = "u")]
+    pub update_id: u64,
+    #[serde(
+        alias = "E",
+        deserialize_with = "de_option_u64_epoch_ms_as_datetime_utc",
+        default
+    )]
+    pub event_time: Option<DateTime<Utc>>,
     #[serde(
         alias = "T",
         deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc",
@@ -67,6 +86,8 @@ impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBook
         Self(vec![Ok(MarketEvent {
             exchange_time: book.time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: OrderBookL1 {
@@ -78,6 +99,21 @@ impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBook
     }
 }
 
+/// Deserialize an optional [`BinanceOrderBookL1`] epoch millisecond timestamp (eg/ "E") as a
+/// `DateTime<Utc>`.
+pub fn de_option_u64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<u64> as Deserialize>::deserialize(deserializer).map(|epoch_ms| {
+        epoch_ms.map(|epoch_ms| {
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(epoch_ms))
+        })
+    })
+}
+
 /// Deserialize a [`BinanceOrderBookL1`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
 ///
 /// eg/ "@bookTicker|BTCUSDT"
@@ -120,6 +156,8 @@ mod tests {
                 "#,
                     expected: BinanceOrderBookL1 {
                         subscription_id: SubscriptionId::from("@bookTicker|ETHUSDT"),
+                        update_id: 22606535573,
+                        event_time: None,
                         time,
                         best_bid_price: 1215.27000000,
                         best_bid_amount: 32.49110000,
@@ -128,7 +166,7 @@ mod tests {
                     },
                 },
                 TestCase {
-                    // TC1: valid FuturePerpetual BinanceOrderBookL1
+                    // TC1: valid Perpetual BinanceOrderBookL1
                     input: r#"
                     {
                         "e":"bookTicker",
@@ -143,6 +181,10 @@ mod tests {
                     }"#,
                     expected: BinanceOrderBookL1 {
                         subscription_id: SubscriptionId::from("@bookTicker|BTCUSDT"),
+                        update_id: 2286618712950,
+                        event_time: Some(datetime_utc_from_epoch_duration(
+                            std::time::Duration::from_millis(1671621244673),
+                        )),
                         time,
                         best_bid_price: 16858.90,
                         best_bid_amount: 13.692,
@@ -159,4 +201,42 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_binance_futures_order_book_l1_to_market_iter() {
+        let input = r#"
+        {
+            "e":"bookTicker",
+            "u":2286618712950,
+            "s":"BTCUSDT",
+            "b":"16858.90",
+            "B":"13.692",
+            "a":"16859.00",
+            "A":"30.219",
+            "T":1671621244670,
+            "E":1671621244673
+        }"#;
+        let book = serde_json::from_str::<BinanceOrderBookL1>(input).unwrap();
+
+        let actual = MarketIter::<OrderBookL1>::from((
+            ExchangeId::BinanceFuturesUsd,
+            Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::instrument::kind::InstrumentKind::Perpetual,
+            )),
+            book,
+        ))
+        .0
+        .remove(0)
+        .unwrap();
+
+        // Transaction time "T" is used as the exchange time
+        let transaction_time =
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(1671621244670));
+        assert_eq!(actual.exchange_time, transaction_time);
+        assert_eq!(actual.kind.last_update_time, transaction_time);
+        assert_eq!(actual.kind.best_bid, Level::new(16858.90, 13.692));
+        assert_eq!(actual.kind.best_ask, Level::new(16859.00, 30.219));
+    }
 }
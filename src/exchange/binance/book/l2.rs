@@ -1,11 +1,12 @@
 use super::super::channel::BinanceChannel;
 use super::BinanceLevel;
 use crate::{
-    exchange::subscription::ExchangeSub,
-    subscription::book::{OrderBook, OrderBookSide},
+    exchange::subscription::{subscription_id, ExchangeSub},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::InstrumentOrderBook,
     Identifier,
 };
-use barter_integration::model::{Side, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, Side, SubscriptionId};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
@@ -58,10 +59,166 @@ impl From<BinanceOrderBookL2Snapshot> for OrderBook {
             last_update_time: Utc::now(),
             bids: OrderBookSide::new(Side::Buy, snapshot.bids),
             asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+            sequence: Some(snapshot.last_update_id),
+            delta: false,
         }
     }
 }
 
+/// [`Binance`](super::super::Binance) partial OrderBook Level2 WebSocket message, received from
+/// the combined stream endpoint (see [`BinanceChannel::is_partial_depth`]).
+///
+/// Each message is a snapshot of the top levels on each side of the [`OrderBook`], so they
+/// replace the local [`OrderBook`] rather than being applied as deltas.
+///
+/// ### Raw Payload Examples
+/// #### BinanceSpot OrderBookL2Partial
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+/// ```json
+/// {
+///     "stream": "btcusdt@depth5@100ms",
+///     "data": {
+///         "lastUpdateId": 160,
+///         "bids": [
+///             ["0.0024", "10"]
+///         ],
+///         "asks": [
+///             ["0.0026", "100"]
+///         ]
+///     }
+/// }
+/// ```
+///
+/// #### BinanceFuturesUsd OrderBookL2Partial
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+/// ```json
+/// {
+///     "stream": "btcusdt@depth5@100ms",
+///     "data": {
+///         "e": "depthUpdate",
+///         "E": 1571889248277,
+///         "T": 1571889248276,
+///         "s": "BTCUSDT",
+///         "U": 390497796,
+///         "u": 390497878,
+///         "pu": 390497794,
+///         "b": [
+///             ["7403.89", "0.002"]
+///         ],
+///         "a": [
+///             ["7405.96", "3.340"]
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderBookL2Partial {
+    #[serde(
+        rename = "stream",
+        deserialize_with = "de_ob_l2_partial_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: BinanceOrderBookL2PartialData,
+}
+
+/// [`BinanceOrderBookL2Partial`] top levels & last update id.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderBookL2PartialData {
+    #[serde(rename = "lastUpdateId", alias = "u")]
+    pub last_update_id: u64,
+    #[serde(alias = "b")]
+    pub bids: Vec<BinanceLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<BinanceLevel>,
+}
+
+impl From<BinanceOrderBookL2PartialData> for OrderBook {
+    fn from(partial: BinanceOrderBookL2PartialData) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, partial.bids),
+            asks: OrderBookSide::new(Side::Sell, partial.asks),
+            sequence: Some(partial.last_update_id),
+            delta: false,
+        }
+    }
+}
+
+/// [`Binance`](super::super::Binance) OrderBook Level2 WebSocket update, being either a
+/// [`BinanceOrderBookL2Partial`] snapshot, or a server specific `Delta`.
+///
+/// Used as the [`OrderBookUpdater::Update`](crate::transformer::book::OrderBookUpdater::Update)
+/// so that a single [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) stream supports
+/// both partial and diff depth subscriptions.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceOrderBookL2Update<Delta> {
+    Partial(BinanceOrderBookL2Partial),
+    Delta(Delta),
+}
+
+impl<Delta> Identifier<Option<SubscriptionId>> for BinanceOrderBookL2Update<Delta>
+where
+    Delta: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Partial(partial) => Some(partial.subscription_id.clone()),
+            Self::Delta(delta) => delta.id(),
+        }
+    }
+}
+
+/// Initialise the [`InstrumentOrderBook`] of a partial depth [`SubscriptionId`] (eg/
+/// "@depth5@100ms|BTCUSDT") with an empty [`OrderBook`], which is replaced by the first
+/// [`BinanceOrderBookL2Partial`] received over the WebSocket.
+///
+/// Returns `None` for diff depth [`SubscriptionId`]s, which require an initial HTTP snapshot.
+pub fn init_partial_book<Updater>(
+    subscription_id: &SubscriptionId,
+    instrument: &Instrument,
+    updater: Updater,
+) -> Option<InstrumentOrderBook<Updater>> {
+    let (channel, _) = subscription_id.0.split_once('|')?;
+
+    BinanceChannel::ORDER_BOOK_L2_PARTIAL_DEPTHS
+        .iter()
+        .any(|partial| partial.0 == channel)
+        .then(|| InstrumentOrderBook {
+            instrument: instrument.clone(),
+            updater,
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                sequence: None,
+                delta: false,
+            },
+        })
+}
+
+/// Deserialize a [`BinanceOrderBookL2Partial`] "stream" field (eg/ "btcusdt@depth5@100ms") as
+/// the associated [`SubscriptionId`].
+///
+/// eg/ "@depth5@100ms|BTCUSDT"
+pub fn de_ob_l2_partial_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let stream = <&str as Deserialize>::deserialize(deserializer)?;
+    let (market, channel) = stream
+        .find('@')
+        .map(|index| stream.split_at(index))
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(stream),
+                &"<market>@depth<levels>@100ms",
+            )
+        })?;
+
+    Ok(subscription_id(channel, market.to_uppercase()))
+}
+
 /// Deserialize a
 /// [`BinanceSpotOrderBookL2Delta`](super::super::spot::l2::BinanceSpotOrderBookL2Delta) or
 /// [`BinanceFuturesOrderBookL2Delta`](super::super::futures::l2::BinanceFuturesOrderBookL2Delta)
@@ -123,7 +280,7 @@ mod tests {
                     },
                 },
                 TestCase {
-                    // TC1: valid FuturePerpetual BinanceOrderBookL2Snapshot
+                    // TC1: valid Perpetual BinanceOrderBookL2Snapshot
                     input: r#"
                     {
                         "lastUpdateId": 1027024,
@@ -166,5 +323,85 @@ mod tests {
                 );
             }
         }
+
+        #[test]
+        fn test_binance_order_book_l2_partial() {
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceOrderBookL2Partial,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Spot BinanceOrderBookL2Partial
+                    input: r#"
+                    {
+                        "stream": "btcusdt@depth5@100ms",
+                        "data": {
+                            "lastUpdateId": 160,
+                            "bids": [["0.0024", "10"]],
+                            "asks": [["0.0026", "100"]]
+                        }
+                    }
+                    "#,
+                    expected: BinanceOrderBookL2Partial {
+                        subscription_id: SubscriptionId::from("@depth5@100ms|BTCUSDT"),
+                        data: BinanceOrderBookL2PartialData {
+                            last_update_id: 160,
+                            bids: vec![BinanceLevel {
+                                price: 0.0024,
+                                amount: 10.0,
+                            }],
+                            asks: vec![BinanceLevel {
+                                price: 0.0026,
+                                amount: 100.0,
+                            }],
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: valid Perpetual BinanceOrderBookL2Partial
+                    input: r#"
+                    {
+                        "stream": "btcusdt@depth10@100ms",
+                        "data": {
+                            "e": "depthUpdate",
+                            "E": 1571889248277,
+                            "T": 1571889248276,
+                            "s": "BTCUSDT",
+                            "U": 390497796,
+                            "u": 390497878,
+                            "pu": 390497794,
+                            "b": [["7403.89", "0.002"]],
+                            "a": [["7405.96", "3.340"]]
+                        }
+                    }
+                    "#,
+                    expected: BinanceOrderBookL2Partial {
+                        subscription_id: SubscriptionId::from("@depth10@100ms|BTCUSDT"),
+                        data: BinanceOrderBookL2PartialData {
+                            last_update_id: 390497878,
+                            bids: vec![BinanceLevel {
+                                price: 7403.89,
+                                amount: 0.002,
+                            }],
+                            asks: vec![BinanceLevel {
+                                price: 7405.96,
+                                amount: 3.340,
+                            }],
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    serde_json::from_str::<BinanceOrderBookL2Partial>(test.input).unwrap(),
+                    test.expected,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
     }
 }
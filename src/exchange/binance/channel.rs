@@ -1,9 +1,14 @@
-use super::{futures::BinanceFuturesUsd, Binance};
+use super::{futures::BinanceFuturesUsd, spot::BinanceSpot, Binance};
 use crate::{
     subscription::{
+        account::{Balances, OrderUpdates, Positions},
         book::{OrderBooksL1, OrderBooksL2},
+        candle::{Candles, Interval},
+        funding::FundingRates,
+        index::CompositeIndices,
         liquidation::Liquidations,
-        trade::PublicTrades,
+        ticker::{MiniTickers, TickerWindow, Tickers},
+        trade::{AggTrades, PublicTrades, UserTrades},
         Subscription,
     },
     Identifier,
@@ -30,22 +35,188 @@ impl BinanceChannel {
     /// See discord: <https://discord.com/channels/910237311332151317/923160222711812126/975712874582388757>
     pub const TRADES: Self = Self("@trade");
 
+    /// [`Binance`](super::Binance) real-time aggregated trades channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+    pub const AGG_TRADES: Self = Self("@aggTrade");
+
     /// [`Binance`](super::Binance) real-time OrderBook Level1 (top of book) channel name.
     ///
     /// See docs:<https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-book-ticker-streams>
     /// See docs:<https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
     pub const ORDER_BOOK_L1: Self = Self("@bookTicker");
 
+    /// [`BinanceSpotSbe`](super::spot::sbe::BinanceSpotSbe) real-time best bid & ask (top of
+    /// book) channel name.
+    ///
+    /// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/sbe-market-data-streams>
+    pub const BEST_BID_ASK: Self = Self("@bestBidAsk");
+
     /// [`Binance`](super::Binance) OrderBook Level2 channel name (100ms delta updates).
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`Binance`](super::Binance) partial OrderBook Level2 channel name for the top 5 levels
+    /// (100ms snapshots).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+    pub const ORDER_BOOK_L2_DEPTH_5: Self = Self("@depth5@100ms");
+
+    /// [`Binance`](super::Binance) partial OrderBook Level2 channel name for the top 10 levels
+    /// (100ms snapshots).
+    pub const ORDER_BOOK_L2_DEPTH_10: Self = Self("@depth10@100ms");
+
+    /// [`Binance`](super::Binance) partial OrderBook Level2 channel name for the top 20 levels
+    /// (100ms snapshots).
+    pub const ORDER_BOOK_L2_DEPTH_20: Self = Self("@depth20@100ms");
+
+    /// Every [`Binance`](super::Binance) partial OrderBook Level2 channel.
+    pub const ORDER_BOOK_L2_PARTIAL_DEPTHS: [Self; 3] = [
+        Self::ORDER_BOOK_L2_DEPTH_5,
+        Self::ORDER_BOOK_L2_DEPTH_10,
+        Self::ORDER_BOOK_L2_DEPTH_20,
+    ];
+
     /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) all-market liquidation orders
+    /// channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-liquidation-order-streams>
+    pub const LIQUIDATIONS_ALL_MARKET: Self = Self("!forceOrder@arr");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) composite index symbol
+    /// information channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#composite-index-symbol-information-streams>
+    pub const COMPOSITE_INDEX: Self = Self("@compositeIndex");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) mark price & funding rate channel
+    /// name (1s updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const FUNDING_RATES: Self = Self("@markPrice@1s");
+
+    /// [`BinanceSpot`](super::spot::BinanceSpot) rolling 1 hour window ticker channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-rolling-window-statistics-streams>
+    pub const TICKER_1H: Self = Self("@ticker_1h");
+
+    /// [`BinanceSpot`](super::spot::BinanceSpot) rolling 4 hour window ticker channel name.
+    pub const TICKER_4H: Self = Self("@ticker_4h");
+
+    /// [`BinanceSpot`](super::spot::BinanceSpot) rolling 1 day window ticker channel name.
+    pub const TICKER_1D: Self = Self("@ticker_1d");
+
+    /// [`Binance`](super::Binance) individual symbol rolling 24hr mini ticker channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-mini-ticker-stream>
+    pub const MINI_TICKER: Self = Self("@miniTicker");
+
+    /// [`Binance`](super::Binance) all-market rolling 24hr mini ticker channel name.
+    ///
+    /// Note that this stream name is not prefixed with a market.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#all-market-mini-tickers-stream>
+    pub const MINI_TICKER_ALL_MARKET: Self = Self("!miniTicker@arr");
+
+    /// [`Binance`](super::Binance) user data stream channel identifier.
+    ///
+    /// Note that this is not a subscribable stream name - the user data stream is connected to
+    /// with a listenKey, and is only used to identify the market of each order update.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#user-data-streams>
+    pub const USER_DATA: Self = Self("@userData");
+
+    /// Determine if this [`BinanceChannel`] is an all-market stream that is not subscribed to
+    /// per market (eg/ "!miniTicker@arr").
+    pub fn is_all_market(&self) -> bool {
+        self.0.starts_with('!')
+    }
+
+    /// Determine the rolling window ticker [`BinanceChannel`] for the provided [`TickerWindow`].
+    pub fn ticker(window: TickerWindow) -> Self {
+        match window {
+            TickerWindow::Hour1 => Self::TICKER_1H,
+            TickerWindow::Hour4 => Self::TICKER_4H,
+            TickerWindow::Day1 => Self::TICKER_1D,
+        }
+    }
+
+    /// Determine the [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) (mark price or
+    /// index price) candlesticks [`BinanceChannel`] for the provided [`Candles`].
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#index-kline-candlestick-streams>
+    pub fn candles(candles: Candles) -> Self {
+        match candles {
+            Candles::Last(Interval::Minute1) => Self("@kline_1m"),
+            Candles::Last(Interval::Minute3) => Self("@kline_3m"),
+            Candles::Last(Interval::Minute5) => Self("@kline_5m"),
+            Candles::Last(Interval::Minute15) => Self("@kline_15m"),
+            Candles::Last(Interval::Minute30) => Self("@kline_30m"),
+            Candles::Last(Interval::Hour1) => Self("@kline_1h"),
+            Candles::Last(Interval::Hour2) => Self("@kline_2h"),
+            Candles::Last(Interval::Hour4) => Self("@kline_4h"),
+            Candles::Last(Interval::Hour6) => Self("@kline_6h"),
+            Candles::Last(Interval::Hour12) => Self("@kline_12h"),
+            Candles::Last(Interval::Day1) => Self("@kline_1d"),
+            Candles::Last(Interval::Week1) => Self("@kline_1w"),
+            Candles::MarkPrice(Interval::Minute1) => Self("@markPriceKline_1m"),
+            Candles::MarkPrice(Interval::Minute3) => Self("@markPriceKline_3m"),
+            Candles::MarkPrice(Interval::Minute5) => Self("@markPriceKline_5m"),
+            Candles::MarkPrice(Interval::Minute15) => Self("@markPriceKline_15m"),
+            Candles::MarkPrice(Interval::Minute30) => Self("@markPriceKline_30m"),
+            Candles::MarkPrice(Interval::Hour1) => Self("@markPriceKline_1h"),
+            Candles::MarkPrice(Interval::Hour2) => Self("@markPriceKline_2h"),
+            Candles::MarkPrice(Interval::Hour4) => Self("@markPriceKline_4h"),
+            Candles::MarkPrice(Interval::Hour6) => Self("@markPriceKline_6h"),
+            Candles::MarkPrice(Interval::Hour12) => Self("@markPriceKline_12h"),
+            Candles::MarkPrice(Interval::Day1) => Self("@markPriceKline_1d"),
+            Candles::MarkPrice(Interval::Week1) => Self("@markPriceKline_1w"),
+            Candles::IndexPrice(Interval::Minute1) => Self("@indexPriceKline_1m"),
+            Candles::IndexPrice(Interval::Minute3) => Self("@indexPriceKline_3m"),
+            Candles::IndexPrice(Interval::Minute5) => Self("@indexPriceKline_5m"),
+            Candles::IndexPrice(Interval::Minute15) => Self("@indexPriceKline_15m"),
+            Candles::IndexPrice(Interval::Minute30) => Self("@indexPriceKline_30m"),
+            Candles::IndexPrice(Interval::Hour1) => Self("@indexPriceKline_1h"),
+            Candles::IndexPrice(Interval::Hour2) => Self("@indexPriceKline_2h"),
+            Candles::IndexPrice(Interval::Hour4) => Self("@indexPriceKline_4h"),
+            Candles::IndexPrice(Interval::Hour6) => Self("@indexPriceKline_6h"),
+            Candles::IndexPrice(Interval::Hour12) => Self("@indexPriceKline_12h"),
+            Candles::IndexPrice(Interval::Day1) => Self("@indexPriceKline_1d"),
+            Candles::IndexPrice(Interval::Week1) => Self("@indexPriceKline_1w"),
+        }
+    }
+
+    /// Determine the OrderBook Level2 [`BinanceChannel`] for the provided [`OrderBooksL2`].
+    ///
+    /// Binance only offers partial snapshots of the top 5, 10 or 20 levels, so a requested depth
+    /// is rounded up to the nearest supported depth (capped at 20).
+    pub fn order_book_l2(kind: &OrderBooksL2) -> Self {
+        match kind.depth {
+            None => Self::ORDER_BOOK_L2,
+            Some(0..=5) => Self::ORDER_BOOK_L2_DEPTH_5,
+            Some(6..=10) => Self::ORDER_BOOK_L2_DEPTH_10,
+            Some(_) => Self::ORDER_BOOK_L2_DEPTH_20,
+        }
+    }
+
+    /// Determine if this [`BinanceChannel`] is a partial OrderBook Level2 channel.
+    ///
+    /// Partial depth messages do not contain the market, so they must be consumed from the
+    /// combined stream endpoint that wraps each message with it's stream name.
+    pub fn is_partial_depth(&self) -> bool {
+        Self::ORDER_BOOK_L2_PARTIAL_DEPTHS.contains(self)
+    }
 }
 
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, PublicTrades> {
@@ -54,6 +225,12 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Public
     }
 }
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, AggTrades> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::AGG_TRADES
+    }
+}
+
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL1> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::ORDER_BOOK_L1
@@ -62,13 +239,73 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderB
 
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL2> {
     fn id(&self) -> BinanceChannel {
-        BinanceChannel::ORDER_BOOK_L2
+        BinanceChannel::order_book_l2(&self.kind)
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpot, Tickers> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ticker(self.kind.0)
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, MiniTickers> {
+    fn id(&self) -> BinanceChannel {
+        match self.kind {
+            MiniTickers::Instrument => BinanceChannel::MINI_TICKER,
+            MiniTickers::AllMarket => BinanceChannel::MINI_TICKER_ALL_MARKET,
+        }
     }
 }
 
 impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Liquidations> {
     fn id(&self) -> BinanceChannel {
-        BinanceChannel::LIQUIDATIONS
+        match self.kind {
+            Liquidations::Instrument => BinanceChannel::LIQUIDATIONS,
+            Liquidations::AllMarket => BinanceChannel::LIQUIDATIONS_ALL_MARKET,
+        }
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, CompositeIndices> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::COMPOSITE_INDEX
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, FundingRates> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::FUNDING_RATES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Candles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::candles(self.kind)
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, UserTrades> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::USER_DATA
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderUpdates> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::USER_DATA
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Balances> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::USER_DATA
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Positions> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::USER_DATA
     }
 }
 
@@ -77,3 +314,50 @@ impl AsRef<str> for BinanceChannel {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_book_l2_channel() {
+        struct TestCase {
+            input: OrderBooksL2,
+            expected: BinanceChannel,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: diff depth
+                input: OrderBooksL2::default(),
+                expected: BinanceChannel::ORDER_BOOK_L2,
+            },
+            TestCase {
+                // TC1: supported partial depth
+                input: OrderBooksL2::partial(10),
+                expected: BinanceChannel::ORDER_BOOK_L2_DEPTH_10,
+            },
+            TestCase {
+                // TC2: unsupported partial depth is rounded up
+                input: OrderBooksL2::partial(7),
+                expected: BinanceChannel::ORDER_BOOK_L2_DEPTH_10,
+            },
+            TestCase {
+                // TC3: partial depth greater than 20 is capped
+                input: OrderBooksL2::partial(100),
+                expected: BinanceChannel::ORDER_BOOK_L2_DEPTH_20,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BinanceChannel::order_book_l2(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                actual.is_partial_depth(),
+                test.input.depth.is_some(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
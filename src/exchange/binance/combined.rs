@@ -0,0 +1,278 @@
+use super::{
+    book::l1::BinanceOrderBookL1,
+    futures::BinanceServerFuturesUsd,
+    spot::BinanceServerSpot,
+    trade::{BinanceAggTrade, BinanceTrade},
+    us::BinanceServerUs,
+    Binance,
+};
+use crate::{
+    event::MarketIter,
+    exchange::{subscription::subscription_id, ExchangeId, ExchangeServer},
+    subscription::{book::OrderBookL1, trade::PublicTrade},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, SubscriptionId};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) combined stream WebSocket server url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+pub const WEBSOCKET_COMBINED_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/stream";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) combined stream WebSocket server
+/// url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+pub const WEBSOCKET_COMBINED_URL_BINANCE_FUTURES_USD: &str = "wss://fstream.binance.com/stream";
+
+/// [`BinanceUs`](super::us::BinanceUs) combined stream WebSocket server url.
+///
+/// See docs: <https://docs.binance.us/#websocket-streams>
+pub const WEBSOCKET_COMBINED_URL_BINANCE_US: &str = "wss://stream.binance.us:9443/stream";
+
+/// [`Binance`] spot exchange, consuming every stream from the combined stream endpoint.
+pub type BinanceSpotCombined = Binance<Combined<BinanceServerSpot>>;
+
+/// [`Binance`] futures usd exchange, consuming every stream from the combined stream endpoint.
+pub type BinanceFuturesUsdCombined = Binance<Combined<BinanceServerFuturesUsd>>;
+
+/// [`Binance`] US spot exchange, consuming every stream from the combined stream endpoint.
+pub type BinanceUsCombined = Binance<Combined<BinanceServerUs>>;
+
+/// [`ExchangeServer`] that also serves the [`Binance`] combined stream endpoint.
+pub trait BinanceCombinedServer
+where
+    Self: ExchangeServer,
+{
+    /// WebSocket url of the combined stream endpoint (eg/ "wss://stream.binance.com:9443/stream").
+    fn combined_websocket_url() -> &'static str;
+}
+
+impl BinanceCombinedServer for BinanceServerSpot {
+    fn combined_websocket_url() -> &'static str {
+        WEBSOCKET_COMBINED_URL_BINANCE_SPOT
+    }
+}
+
+impl BinanceCombinedServer for BinanceServerFuturesUsd {
+    fn combined_websocket_url() -> &'static str {
+        WEBSOCKET_COMBINED_URL_BINANCE_FUTURES_USD
+    }
+}
+
+impl BinanceCombinedServer for BinanceServerUs {
+    fn combined_websocket_url() -> &'static str {
+        WEBSOCKET_COMBINED_URL_BINANCE_US
+    }
+}
+
+/// [`ExchangeServer`] that connects to the combined stream endpoint of the `Server`, where every
+/// message is wrapped in a [`BinanceCombined`] envelope with it's stream name.
+///
+/// Hundreds of markets can share a single combined stream connection. Each message is routed by
+/// it's stream name, so a [`Binance<Combined<Server>>`](Binance) supports every stream whose
+/// transformer input is a [`BinanceMessage`] (eg/ [`PublicTrades`](crate::subscription::trade::PublicTrades),
+/// [`AggTrades`](crate::subscription::trade::AggTrades),
+/// [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) and
+/// [`MiniTickers`](crate::subscription::ticker::MiniTickers)).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Combined<Server> {
+    server: PhantomData<Server>,
+}
+
+impl<Server> ExchangeServer for Combined<Server>
+where
+    Server: BinanceCombinedServer,
+{
+    const ID: ExchangeId = Server::ID;
+
+    fn websocket_url() -> &'static str {
+        Server::combined_websocket_url()
+    }
+
+    fn regional_hosts() -> &'static [&'static str] {
+        Server::regional_hosts()
+    }
+}
+
+/// [`Binance`] combined stream envelope, wrapping each message with it's stream name.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+/// ```json
+/// {
+///     "stream": "btcusdt@trade",
+///     "data": {
+///         "e": "trade",
+///         "E": 1649839266194,
+///         "s": "BTCUSDT",
+///         "t": 1000000000,
+///         "p": "10000.19",
+///         "q": "0.239000",
+///         "T": 1649839266194,
+///         "m": true
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCombined<T> {
+    pub stream: String,
+    pub data: T,
+}
+
+/// [`Binance`] WebSocket message, either wrapped in a [`BinanceCombined`] envelope when received
+/// from the combined stream endpoint, or received as is from the raw stream endpoint.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceMessage<T> {
+    Combined(BinanceCombined<T>),
+    Raw(T),
+}
+
+impl<T> BinanceMessage<T> {
+    /// Unwrap the message, returning the [`SubscriptionId`] of it's stream name if it was
+    /// received from the combined stream endpoint.
+    pub fn into_parts(self) -> (Option<SubscriptionId>, T) {
+        match self {
+            Self::Combined(combined) => (stream_subscription_id(&combined.stream), combined.data),
+            Self::Raw(data) => (None, data),
+        }
+    }
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BinanceMessage<T>
+where
+    T: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Combined(combined) => {
+                stream_subscription_id(&combined.stream).or_else(|| combined.data.id())
+            }
+            Self::Raw(data) => data.id(),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceMessage<BinanceTrade>)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BinanceMessage<BinanceTrade>),
+    ) -> Self {
+        let (_, trade) = message.into_parts();
+        Self::from((exchange_id, instrument, trade))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceMessage<BinanceAggTrade>)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, message): (
+            ExchangeId,
+            Instrument,
+            BinanceMessage<BinanceAggTrade>,
+        ),
+    ) -> Self {
+        let (_, trade) = message.into_parts();
+        Self::from((exchange_id, instrument, trade))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceMessage<BinanceOrderBookL1>)>
+    for MarketIter<OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, message): (
+            ExchangeId,
+            Instrument,
+            BinanceMessage<BinanceOrderBookL1>,
+        ),
+    ) -> Self {
+        let (_, book) = message.into_parts();
+        Self::from((exchange_id, instrument, book))
+    }
+}
+
+/// Determine the [`SubscriptionId`] associated with a combined stream name.
+///
+/// eg/ "btcusdt@trade" -> "@trade|BTCUSDT"
+///
+/// All-market stream names (eg/ "!miniTicker@arr") do not identify a market, so `None` is
+/// returned.
+pub fn stream_subscription_id(stream: &str) -> Option<SubscriptionId> {
+    if stream.starts_with('!') {
+        return None;
+    }
+
+    let (market, channel) = stream.split_at(stream.find('@')?);
+    Some(subscription_id(channel, market.to_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let cases = vec![
+            // TC0: trade stream
+            TestCase {
+                input: "btcusdt@trade",
+                expected: Some(SubscriptionId::from("@trade|BTCUSDT")),
+            },
+            // TC1: stream with update speed suffix
+            TestCase {
+                input: "ethusdt@depth@100ms",
+                expected: Some(SubscriptionId::from("@depth@100ms|ETHUSDT")),
+            },
+            // TC2: all-market stream
+            TestCase {
+                input: "!miniTicker@arr",
+                expected: None,
+            },
+            // TC3: invalid stream name
+            TestCase {
+                input: "btcusdt",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = stream_subscription_id(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_de_binance_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let cases = vec![
+            // TC0: combined stream trade is routed by it's stream name
+            TestCase {
+                input: r#"{"stream":"btcusdt@trade","data":{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"100.0","q":"1.0","T":1649324825173,"m":true}}"#,
+                expected: Some(SubscriptionId::from("@trade|BTCUSDT")),
+            },
+            // TC1: raw stream trade is routed by it's market
+            TestCase {
+                input: r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":1,"p":"100.0","q":"1.0","T":1649324825173,"m":true}"#,
+                expected: Some(SubscriptionId::from("@trade|ETHUSDT")),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BinanceMessage<BinanceTrade>>(test.input)
+                .unwrap()
+                .id();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use super::{
+    BinanceFuturesUsd, HTTP_INDEX_PRICE_KLINES_URL_BINANCE_FUTURES_USD,
+    HTTP_KLINES_URL_BINANCE_FUTURES_USD, HTTP_MARK_PRICE_KLINES_URL_BINANCE_FUTURES_USD,
+};
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{binance::channel::BinanceChannel, Connector, ExchangeId, ExchangeSub},
+    streams::continuity::HistoricalCandles,
+    subscription::candle::{Candle, Candles, Interval},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use serde::{de::IgnoredAny, Deserialize, Deserializer, Serialize};
+
+/// Maximum number of candles requested from the [`BinanceFuturesUsd`] HTTP candlestick endpoints.
+pub const HISTORICAL_CANDLES_LIMIT: u16 = 1500;
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) (mark price or index price) candlestick
+/// message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+/// #### Candlestick
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 1638747660000,
+///     "s": "BTCUSDT",
+///     "k": {
+///         "t": 1638747660000,
+///         "T": 1638747719999,
+///         "s": "BTCUSDT",
+///         "i": "1m",
+///         "f": 100,
+///         "L": 200,
+///         "o": "0.0010",
+///         "c": "0.0020",
+///         "h": "0.0025",
+///         "l": "0.0015",
+///         "v": "1000",
+///         "n": 100,
+///         "x": true,
+///         "q": "1.0000",
+///         "V": "500",
+///         "Q": "0.500",
+///         "B": "123456"
+///     }
+/// }
+/// ```
+///
+/// #### Index Price Candlestick
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#index-kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "indexPrice_kline",
+///     "E": 1591267070033,
+///     "ps": "BTCUSDT",
+///     "k": {
+///         "t": 1591267020000,
+///         "T": 1591267079999,
+///         "s": "0",
+///         "i": "1m",
+///         "f": 1591267020000,
+///         "L": 1591267070000,
+///         "o": "9542.21900000",
+///         "c": "9542.50440112",
+///         "h": "9542.71289578",
+///         "l": "9541.78000000",
+///         "v": "0",
+///         "n": 51,
+///         "x": false,
+///         "q": "0",
+///         "V": "0",
+///         "Q": "0",
+///         "B": "0"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCandle {
+    #[serde(alias = "e")]
+    pub kind: BinanceCandleKind,
+    #[serde(alias = "s", alias = "ps")]
+    pub market: String,
+    #[serde(alias = "k")]
+    pub candle: BinanceCandleData,
+}
+
+/// [`BinanceCandle`] event type, used to identify the subscribed [`Candles`] variant.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum BinanceCandleKind {
+    #[serde(rename = "kline")]
+    Last,
+    #[serde(rename = "markPrice_kline")]
+    MarkPrice,
+    #[serde(rename = "indexPrice_kline")]
+    IndexPrice,
+}
+
+/// [`BinanceCandle`] OHLCV data.
+///
+/// Note that the trade count of mark & index price candlesticks is the number of price updates.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCandleData {
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub close_time: DateTime<Utc>,
+    #[serde(alias = "i")]
+    pub interval: Interval,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+    #[serde(alias = "x")]
+    pub closed: bool,
+}
+
+impl BinanceCandle {
+    /// Determine the subscribed [`Candles`] variant of this [`BinanceCandle`].
+    pub fn candles(&self) -> Candles {
+        let interval = self.candle.interval;
+        match self.kind {
+            BinanceCandleKind::Last => Candles::Last(interval),
+            BinanceCandleKind::MarkPrice => Candles::MarkPrice(interval),
+            BinanceCandleKind::IndexPrice => Candles::IndexPrice(interval),
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceCandle {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((BinanceChannel::candles(self.candles()), &self.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceCandle)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candle): (ExchangeId, Instrument, BinanceCandle)) -> Self {
+        // Binance pushes in-progress candle updates, so only yield closed candles
+        if !candle.candle.closed {
+            return Self(vec![]);
+        }
+
+        let candle = candle.candle;
+        Self(vec![Ok(MarketEvent {
+            exchange_time: candle.close_time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Candle {
+                close_time: candle.close_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            },
+        })])
+    }
+}
+
+/// [`BinanceFuturesUsd`] HTTP (last, mark price or index price) candlestick.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-data>
+/// ```json
+/// [
+///   1499040000000,
+///   "0.01634790",
+///   "0.80000000",
+///   "0.01575800",
+///   "0.01577100",
+///   "148976.11427815",
+///   1499644799999,
+///   "2434.19055334",
+///   308,
+///   "1756.87402397",
+///   "28.46694368",
+///   "0"
+/// ]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BinanceRestCandle {
+    pub close_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub trade_count: u64,
+}
+
+impl<'de> Deserialize<'de> for BinanceRestCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        type Kline<'a> = (
+            IgnoredAny,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            &'a str,
+            i64,
+            IgnoredAny,
+            u64,
+            IgnoredAny,
+            IgnoredAny,
+            IgnoredAny,
+        );
+
+        let (_, open, high, low, close, volume, close_time, _, trade_count, _, _, _) =
+            Kline::deserialize(deserializer)?;
+
+        let parse = |value: &str| value.parse::<f64>().map_err(serde::de::Error::custom);
+
+        Ok(Self {
+            close_time: DateTime::from_timestamp_millis(close_time)
+                .ok_or_else(|| serde::de::Error::custom("invalid kline close time"))?,
+            open: parse(open)?,
+            high: parse(high)?,
+            low: parse(low)?,
+            close: parse(close)?,
+            volume: parse(volume)?,
+            trade_count,
+        })
+    }
+}
+
+#[async_trait]
+impl HistoricalCandles for BinanceFuturesUsd {
+    async fn historical_candles(
+        instrument: &Instrument,
+        kind: Candles,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MarketEvent<Candle>>, SocketError> {
+        let market = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+
+        // Index price candlesticks are identified by pair rather than symbol
+        let (url, market_param) = match kind {
+            Candles::Last(_) => (HTTP_KLINES_URL_BINANCE_FUTURES_USD, "symbol"),
+            Candles::MarkPrice(_) => (HTTP_MARK_PRICE_KLINES_URL_BINANCE_FUTURES_USD, "symbol"),
+            Candles::IndexPrice(_) => (HTTP_INDEX_PRICE_KLINES_URL_BINANCE_FUTURES_USD, "pair"),
+        };
+
+        // Candlesticks are queried by open time, so request those opening within the window
+        let interval = kind.interval().duration();
+        let candles = reqwest::Client::new()
+            .get(url)
+            .query(&[
+                (market_param, market),
+                ("interval", kind.interval().as_str().to_owned()),
+                (
+                    "startTime",
+                    (start - interval).timestamp_millis().to_string(),
+                ),
+                ("endTime", end.timestamp_millis().to_string()),
+                ("limit", HISTORICAL_CANDLES_LIMIT.to_string()),
+            ])
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<Vec<BinanceRestCandle>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(candles
+            .into_iter()
+            .filter(|candle| candle.close_time >= start && candle.close_time <= end)
+            .map(|candle| MarketEvent {
+                exchange_time: candle.close_time,
+                received_time: Utc::now(),
+                received_monotonic: MonotonicTime::now(),
+                origin: Origin::Backfill,
+                exchange: Exchange::from(Self::ID),
+                instrument: instrument.clone(),
+                kind: Candle {
+                    close_time: candle.close_time,
+                    open: candle.open,
+                    high: candle.high,
+                    low: candle.low,
+                    close: candle.close,
+                    volume: candle.volume,
+                    trade_count: candle.trade_count,
+                },
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_candle_id() {
+            struct TestCase {
+                input: &'static str,
+                expected: SubscriptionId,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: candlestick
+                    input: r#"
+                    {
+                        "e": "kline", "E": 1638747660000, "s": "BTCUSDT",
+                        "k": {
+                            "t": 1638747660000, "T": 1638747719999, "s": "BTCUSDT", "i": "1m",
+                            "f": 100, "L": 200, "o": "0.0010", "c": "0.0020", "h": "0.0025",
+                            "l": "0.0015", "v": "1000", "n": 100, "x": true, "q": "1.0000",
+                            "V": "500", "Q": "0.500", "B": "123456"
+                        }
+                    }
+                    "#,
+                    expected: SubscriptionId::from("@kline_1m|BTCUSDT"),
+                },
+                TestCase {
+                    // TC1: mark price candlestick
+                    input: r#"
+                    {
+                        "e": "markPrice_kline", "E": 1591267398004, "s": "BTCUSDT",
+                        "k": {
+                            "t": 1591267380000, "T": 1591267439999, "s": "BTCUSDT", "i": "1h",
+                            "f": -1, "L": -1, "o": "9542.219", "c": "9542.504", "h": "9542.712",
+                            "l": "9541.780", "v": "0", "n": 59, "x": false, "q": "0", "V": "0",
+                            "Q": "0", "B": "0"
+                        }
+                    }
+                    "#,
+                    expected: SubscriptionId::from("@markPriceKline_1h|BTCUSDT"),
+                },
+                TestCase {
+                    // TC2: index price candlestick
+                    input: r#"
+                    {
+                        "e": "indexPrice_kline", "E": 1591267070033, "ps": "BTCUSDT",
+                        "k": {
+                            "t": 1591267020000, "T": 1591267079999, "s": "0", "i": "1d",
+                            "f": 1591267020000, "L": 1591267070000, "o": "9542.219",
+                            "c": "9542.504", "h": "9542.712", "l": "9541.780", "v": "0",
+                            "n": 51, "x": false, "q": "0", "V": "0", "Q": "0", "B": "0"
+                        }
+                    }
+                    "#,
+                    expected: SubscriptionId::from("@indexPriceKline_1d|BTCUSDT"),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceCandle>(test.input).unwrap();
+                assert_eq!(actual.id(), Some(test.expected), "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_binance_rest_candles() {
+            let input = r#"
+            [
+                [
+                    1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+                    "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+                    "28.46694368", "0"
+                ]
+            ]
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<BinanceRestCandle>>(input).unwrap(),
+                vec![BinanceRestCandle {
+                    close_time: DateTime::from_timestamp_millis(1499644799999).unwrap(),
+                    open: 0.0163479,
+                    high: 0.8,
+                    low: 0.015758,
+                    close: 0.015771,
+                    volume: 148976.11427815,
+                    trade_count: 308,
+                }]
+            );
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use super::{BinanceFuturesUsd, HTTP_FUNDING_INFO_URL_BINANCE_FUTURES_USD};
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{binance::channel::BinanceChannel, ExchangeId, ExchangeSub},
+    funding::{FundingIntervals, DEFAULT_FUNDING_INTERVAL_HOURS},
+    subscription::funding::FundingRate,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price & funding rate message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFundingRate {
+    #[serde(alias = "s", deserialize_with = "de_funding_rate_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(alias = "r", deserialize_with = "barter_integration::de::de_str")]
+    pub rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceFundingRate {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceFundingRate)> for MarketIter<FundingRate> {
+    fn from(
+        (exchange_id, instrument, funding): (ExchangeId, Instrument, BinanceFundingRate),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: funding.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingRate {
+                rate: funding.rate,
+                mark_price: funding.mark_price,
+                next_funding_time: funding.next_funding_time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceFundingRate`] "s" (eg/ "BTCUSDT") as the associated
+/// [`SubscriptionId`] (eg/ "@markPrice@1s|BTCUSDT").
+pub fn de_funding_rate_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::FUNDING_RATES, market)).id())
+}
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP funding information of a market with an
+/// adjusted funding interval.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#get-funding-rate-info>
+/// ```json
+/// {
+///     "symbol": "BLZUSDT",
+///     "adjustedFundingRateCap": "0.02500000",
+///     "adjustedFundingRateFloor": "-0.02500000",
+///     "fundingIntervalHours": 4,
+///     "disclaimer": false
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceFundingInfo {
+    pub symbol: String,
+    #[serde(alias = "fundingIntervalHours")]
+    pub funding_interval_hours: u32,
+}
+
+#[async_trait]
+impl FundingIntervals for BinanceFuturesUsd {
+    async fn funding_intervals(
+        instruments: &[Instrument],
+    ) -> Result<Vec<(Instrument, Duration)>, SocketError> {
+        let adjusted = reqwest::Client::new()
+            .get(HTTP_FUNDING_INFO_URL_BINANCE_FUTURES_USD)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<Vec<BinanceFundingInfo>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        // Markets absent from the funding info use the default funding interval
+        Ok(instruments
+            .iter()
+            .map(|instrument| {
+                let market = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+                let hours = adjusted
+                    .iter()
+                    .find(|info| info.symbol == market)
+                    .map_or(DEFAULT_FUNDING_INTERVAL_HOURS, |info| {
+                        i64::from(info.funding_interval_hours)
+                    });
+                (instrument.clone(), Duration::hours(hours))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_funding_rate() {
+            let input = r#"
+            {
+                "e": "markPriceUpdate",
+                "E": 1562305380000,
+                "s": "BTCUSDT",
+                "p": "11794.15000000",
+                "i": "11784.62659091",
+                "P": "11784.25641265",
+                "r": "0.00038167",
+                "T": 1562306400000
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceFundingRate>(input).unwrap(),
+                BinanceFundingRate {
+                    subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1562305380000)),
+                    mark_price: 11794.15,
+                    rate: 0.00038167,
+                    next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1562306400000
+                    )),
+                }
+            );
+        }
+
+        #[test]
+        fn test_binance_funding_info() {
+            let input = r#"
+            [
+                {
+                    "symbol": "BLZUSDT",
+                    "adjustedFundingRateCap": "0.02500000",
+                    "adjustedFundingRateFloor": "-0.02500000",
+                    "fundingIntervalHours": 4,
+                    "disclaimer": false
+                }
+            ]
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<BinanceFundingInfo>>(input).unwrap(),
+                vec![BinanceFundingInfo {
+                    symbol: "BLZUSDT".to_owned(),
+                    funding_interval_hours: 4,
+                }]
+            );
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{binance::channel::BinanceChannel, ExchangeId, ExchangeSub},
+    subscription::index::{IndexConstituent, IndexConstituents},
+    Identifier,
+};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, SubscriptionId,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) composite index symbol information message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#composite-index-symbol-information-streams>
+/// ```json
+/// {
+///     "e": "compositeIndex",
+///     "E": 1602310596000,
+///     "s": "DEFIUSDT",
+///     "p": "554.41604065",
+///     "C": "baseAsset",
+///     "c": [
+///         {
+///             "b": "BAL",
+///             "q": "USDT",
+///             "w": "1.04884844",
+///             "W": "0.01457800",
+///             "i": "24.33521021"
+///         },
+///         {
+///             "b": "BAND",
+///             "q": "USDT",
+///             "w": "3.53782729",
+///             "W": "0.03935200",
+///             "i": "7.26420084"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCompositeIndex {
+    #[serde(alias = "s", deserialize_with = "de_composite_index_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "c")]
+    pub constituents: Vec<BinanceIndexConstituent>,
+}
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) composite index constituent contained within
+/// a [`BinanceCompositeIndex`] message.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceIndexConstituent {
+    #[serde(alias = "b")]
+    pub base: String,
+    #[serde(alias = "q")]
+    pub quote: String,
+    #[serde(alias = "w", deserialize_with = "barter_integration::de::de_str")]
+    pub weight: f64,
+    #[serde(alias = "W", deserialize_with = "barter_integration::de::de_str")]
+    pub weight_percent: f64,
+    #[serde(alias = "i", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceCompositeIndex {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceCompositeIndex)> for MarketIter<IndexConstituents> {
+    fn from(
+        (exchange_id, instrument, index): (ExchangeId, Instrument, BinanceCompositeIndex),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: index.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: IndexConstituents {
+                price: index.price,
+                constituents: index
+                    .constituents
+                    .into_iter()
+                    .map(|constituent| IndexConstituent {
+                        base: Symbol::from(constituent.base.to_lowercase()),
+                        quote: Symbol::from(constituent.quote.to_lowercase()),
+                        weight: constituent.weight,
+                        weight_percent: constituent.weight_percent,
+                        price: constituent.price,
+                    })
+                    .collect(),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceCompositeIndex`] "s" (eg/ "DEFIUSDT") as the associated
+/// [`SubscriptionId`] (eg/ "@compositeIndex|DEFIUSDT").
+pub fn de_composite_index_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::COMPOSITE_INDEX, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_composite_index() {
+            let input = r#"
+            {
+                "e": "compositeIndex",
+                "E": 1602310596000,
+                "s": "DEFIUSDT",
+                "p": "554.41604065",
+                "C": "baseAsset",
+                "c": [
+                    {
+                        "b": "BAL",
+                        "q": "USDT",
+                        "w": "1.04884844",
+                        "W": "0.01457800",
+                        "i": "24.33521021"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceCompositeIndex>(input).unwrap(),
+                BinanceCompositeIndex {
+                    subscription_id: SubscriptionId::from("@compositeIndex|DEFIUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1602310596000)),
+                    price: 554.41604065,
+                    constituents: vec![BinanceIndexConstituent {
+                        base: "BAL".to_string(),
+                        quote: "USDT".to_string(),
+                        weight: 1.04884844,
+                        weight_percent: 0.014578,
+                        price: 24.33521021,
+                    }],
+                }
+            );
+        }
+    }
+}
@@ -1,19 +1,23 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::{
+        init_partial_book, BinanceOrderBookL2Partial, BinanceOrderBookL2Snapshot,
+        BinanceOrderBookL2Update,
+    },
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
-    subscription::book::OrderBook,
-    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
-    Identifier,
+    subscription::book::{Level, OrderBook},
+    transformer::book::{BookUpdate, InstrumentOrderBook, OrderBookUpdater},
+    Identifier, OutboundTx,
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    model::{Instrument, SubscriptionId},
-    protocol::websocket::WsMessage,
+    model::{instrument::Instrument, SubscriptionId},
 };
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use tokio::sync::mpsc;
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP OrderBook L2 snapshot url.
 ///
@@ -66,6 +70,10 @@ impl Identifier<Option<SubscriptionId>> for BinanceFuturesOrderBookL2Delta {
     }
 }
 
+/// Terse type alias for a [`BinanceFuturesUsd`](super::BinanceFuturesUsd) OrderBook Level2
+/// partial snapshot or delta WebSocket message.
+pub type BinanceFuturesOrderBookL2Update = BinanceOrderBookL2Update<BinanceFuturesOrderBookL2Delta>;
+
 /// [`Binance`](super::super::Binance) [`BinanceServerFuturesUsd`](super::BinanceServerFuturesUsd)
 /// [`OrderBookUpdater`].
 ///
@@ -89,6 +97,8 @@ impl Identifier<Option<SubscriptionId>> for BinanceFuturesOrderBookL2Delta {
 ///  - Uppercase U => first_update_id
 ///  - Lowercase u => last_update_id,
 ///  - Lowercase pu => prev_last_update_id
+///  - Partial depth subscriptions (eg/ "@depth5@100ms") skip steps 2-3, since every snapshot
+///    replaces the local OrderBook. Snapshots are only dropped if they are stale.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#how-to-manage-a-local-order-book-correctly>
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
@@ -155,13 +165,34 @@ impl BinanceFuturesBookUpdater {
     }
 }
 
+impl BinanceFuturesBookUpdater {
+    /// Replace the local [`OrderBook`] with the provided [`BinanceOrderBookL2Partial`] snapshot,
+    /// dropping any partial that is older than the last processed update.
+    pub fn apply_partial(
+        &mut self,
+        book: &mut OrderBook,
+        partial: BinanceOrderBookL2Partial,
+    ) -> Option<BookUpdate> {
+        if partial.data.last_update_id < self.last_update_id {
+            return None;
+        }
+
+        self.updates_processed += 1;
+        self.last_update_id = partial.data.last_update_id;
+        *book = OrderBook::from(partial.data);
+
+        Some(BookUpdate::Snapshot)
+    }
+}
+
 #[async_trait]
 impl OrderBookUpdater for BinanceFuturesBookUpdater {
     type OrderBook = OrderBook;
-    type Update = BinanceFuturesOrderBookL2Delta;
+    type Update = BinanceFuturesOrderBookL2Update;
+    const INIT_SNAPSHOT: bool = true;
 
     async fn init<Exchange, Kind>(
-        _: mpsc::UnboundedSender<WsMessage>,
+        _: OutboundTx,
         instrument: Instrument,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
@@ -191,14 +222,27 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         })
     }
 
-    fn update(
+    fn init_partial(
+        subscription_id: &SubscriptionId,
+        instrument: &Instrument,
+    ) -> Option<InstrumentOrderBook<Self>> {
+        init_partial_book(subscription_id, instrument, Self::new(0))
+    }
+
+    fn apply(
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError> {
+    ) -> Result<Option<BookUpdate>, DataError> {
         // BinanceFuturesUsd: How To Manage A Local OrderBook Correctly
         // See Self's Rust Docs for more information on each numbered step
         // See docs: <https://binance-docs.github.io/apidocs/futures/en/#how-to-manage-a-local-order-book-correctly>
+        let update = match update {
+            BinanceOrderBookL2Update::Partial(partial) => {
+                return Ok(self.apply_partial(book, partial))
+            }
+            BinanceOrderBookL2Update::Delta(delta) => delta,
+        };
 
         // 4. Drop any event where u is < lastUpdateId in the snapshot:
         if update.last_update_id < self.last_update_id {
@@ -216,15 +260,18 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         // Update OrderBook metadata & Levels:
         // 7. The data in each event is the absolute quantity for a price level.
         // 8. If the quantity is 0, remove the price level.
+        let bids = update.bids.into_iter().map(Level::from).collect::<Vec<_>>();
+        let asks = update.asks.into_iter().map(Level::from).collect::<Vec<_>>();
         book.last_update_time = Utc::now();
-        book.bids.upsert(update.bids);
-        book.asks.upsert(update.asks);
+        book.sequence = Some(update.last_update_id);
+        book.bids.upsert(bids.iter().copied());
+        book.asks.upsert(asks.iter().copied());
 
         // Update OrderBookUpdater metadata
         self.updates_processed += 1;
         self.last_update_id = update.last_update_id;
 
-        Ok(Some(book.snapshot()))
+        Ok(Some(BookUpdate::Delta { bids, asks }))
     }
 }
 
@@ -406,8 +453,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(()), Ok(())) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -469,8 +516,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(()), Ok(())) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -505,6 +552,8 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
                         asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                        sequence: None,
+                        delta: false,
                     },
                     input_update: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -532,6 +581,8 @@ mod tests {
                             Side::Sell,
                             vec![Level::new(150, 1), Level::new(110, 1), Level::new(120, 1)],
                         ),
+                        sequence: None,
+                        delta: false,
                     },
                     input_update: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -578,12 +629,17 @@ mod tests {
                                 Level::new(200, 1),
                             ],
                         ),
+                        sequence: Some(110),
+                        delta: false,
                     })),
                 },
             ];
 
             for (index, mut test) in tests.into_iter().enumerate() {
-                let actual = test.updater.update(&mut test.book, test.input_update);
+                let actual = test.updater.update(
+                    &mut test.book,
+                    BinanceOrderBookL2Update::Delta(test.input_update),
+                );
 
                 match (actual, test.expected) {
                     (Ok(Some(actual)), Ok(Some(expected))) => {
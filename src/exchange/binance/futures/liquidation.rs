@@ -1,11 +1,21 @@
-use super::super::BinanceChannel;
+use super::{super::BinanceChannel, BinanceFuturesUsd};
 use crate::{
-    event::{MarketEvent, MarketIter},
-    exchange::ExchangeId,
-    subscription::liquidation::Liquidation,
-    Identifier,
+    error::DataError,
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{subscription::subscription_id, Connector, ExchangeId},
+    subscription::{
+        liquidation::{Liquidation, Liquidations},
+        market_wide::MarketWideMap,
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange, Side, SubscriptionId},
+    Transformer,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -88,6 +98,8 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidati
         Self(vec![Ok(MarketEvent {
             exchange_time: liquidation.order.time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: Liquidation {
@@ -100,6 +112,42 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidati
     }
 }
 
+/// [`BinanceFuturesUsd`] [`Liquidations`] [`ExchangeTransformer`].
+///
+/// Supports both individual symbol and [`Liquidations::AllMarket`] subscriptions, resolving
+/// each [`BinanceLiquidation`] [`Instrument`] via the [`MarketWideMap`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BinanceLiquidations {
+    instrument_map: MarketWideMap,
+}
+
+#[async_trait]
+impl ExchangeTransformer<BinanceFuturesUsd, Liquidations> for BinanceLiquidations {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map: MarketWideMap::init::<BinanceFuturesUsd>(instrument_map).await?,
+        })
+    }
+}
+
+impl Transformer for BinanceLiquidations {
+    type Error = DataError;
+    type Input = BinanceLiquidation;
+    type Output = MarketEvent<Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Ignore symbols not quoted in an all-market subscription quote asset
+        match self.instrument_map.resolve(&input.order.subscription_id) {
+            Some(Ok(instrument)) => {
+                MarketIter::<Liquidation>::from((BinanceFuturesUsd::ID, instrument, input)).0
+            }
+            Some(Err(unidentifiable)) => vec![Err(DataError::Socket(unidentifiable))],
+            None => vec![],
+        }
+    }
+}
+
 /// Deserialize a [`BinanceLiquidationOrder`] "s" (eg/ "BTCUSDT") as the associated
 /// [`SubscriptionId`].
 ///
@@ -108,14 +156,16 @@ pub fn de_liquidation_subscription_id<'de, D>(deserializer: D) -> Result<Subscri
 where
     D: serde::de::Deserializer<'de>,
 {
-    Deserialize::deserialize(deserializer).map(|market: String| {
-        SubscriptionId::from(format!("{}|{}", BinanceChannel::LIQUIDATIONS.0, market))
-    })
+    Deserialize::deserialize(deserializer)
+        .map(|market: String| subscription_id(BinanceChannel::LIQUIDATIONS.0, market))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::market_wide::SymbolTable;
+    use barter_integration::model::instrument::{kind::InstrumentKind, symbol::Symbol};
+    use std::sync::Arc;
 
     mod de {
         use super::*;
@@ -160,4 +210,50 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_binance_liquidations_transform_all_market() {
+        let liquidation = |market: &str| BinanceLiquidation {
+            order: BinanceLiquidationOrder {
+                subscription_id: SubscriptionId::from(format!("@forceOrder|{market}")),
+                side: Side::Sell,
+                price: 1.0,
+                quantity: 1.0,
+                time: Utc::now(),
+            },
+        };
+
+        let mut transformer = BinanceLiquidations {
+            instrument_map: MarketWideMap::new(
+                Map::from_iter([(
+                    SubscriptionId::from("!forceOrder@arr|*USDT"),
+                    Instrument::from(("*", "usdt", InstrumentKind::Perpetual)),
+                )]),
+                Arc::new(SymbolTable::from_iter(
+                    [
+                        ("BTCUSDT", "btc", "usdt"),
+                        ("ETHBUSD", "eth", "busd"),
+                        ("XRPUSDT", "xrp", "usdt"),
+                    ]
+                    .map(|(symbol, base, quote)| {
+                        (symbol.to_owned(), (Symbol::new(base), Symbol::new(quote)))
+                    }),
+                )),
+            ),
+        };
+
+        let actual = ["BTCUSDT", "ETHBUSD", "XRPUSDT"]
+            .into_iter()
+            .flat_map(|market| transformer.transform(liquidation(market)))
+            .map(|event| event.unwrap().instrument)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                Instrument::from(("xrp", "usdt", InstrumentKind::Perpetual)),
+            ]
+        );
+    }
 }
@@ -1,11 +1,38 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
-use super::{Binance, ExchangeServer};
+use self::{
+    candle::BinanceCandle, funding::BinanceFundingRate, index::BinanceCompositeIndex,
+    l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidations,
+};
+#[cfg(feature = "private")]
+use super::user::{BinancePositionData, BinanceUserDataServer};
+use super::{
+    ranking::BinanceTicker24hrServer, rest_trade::BinanceRecentTradesServer,
+    spec::BinanceExchangeInfoServer, time::BinanceTimeServer, Binance, ExchangeServer,
+};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
+    subscription::{
+        book::OrderBooksL2, candle::Candles, funding::FundingRates, index::CompositeIndices,
+        liquidation::Liquidations,
+    },
     transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
+#[cfg(feature = "private")]
+use crate::{
+    subscription::account::Positions, transformer::account::AccountTransformer, PrivateWsStream,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+
+/// Candlestick types, and the [`HistoricalCandles`](crate::streams::continuity::HistoricalCandles)
+/// implementation.
+pub mod candle;
+
+/// Mark price & funding rate types, and the [`FundingIntervals`](crate::funding::FundingIntervals)
+/// implementation.
+pub mod funding;
+
+/// Composite index types.
+pub mod index;
 
 /// Level 2 OrderBook types (top of book) and futures
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
@@ -19,6 +46,58 @@ pub mod liquidation;
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD: &str = "wss://fstream.binance.com/ws";
 
+/// [`BinanceFuturesUsd`] HTTP user data stream listenKey url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#start-user-data-stream-user_stream>
+pub const HTTP_LISTEN_KEY_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/listenKey";
+
+/// [`BinanceFuturesUsd`] HTTP server time url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#check-server-time>
+pub const HTTP_SERVER_TIME_URL_BINANCE_FUTURES_USD: &str = "https://fapi.binance.com/fapi/v1/time";
+
+/// [`BinanceFuturesUsd`] HTTP recent trades url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#recent-trades-list>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/trades";
+
+/// [`BinanceFuturesUsd`] HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// [`BinanceFuturesUsd`] HTTP funding info url, listing markets with an adjusted funding interval.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#get-funding-rate-info>
+pub const HTTP_FUNDING_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/fundingInfo";
+
+/// [`BinanceFuturesUsd`] HTTP candlestick url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-data>
+pub const HTTP_KLINES_URL_BINANCE_FUTURES_USD: &str = "https://fapi.binance.com/fapi/v1/klines";
+
+/// [`BinanceFuturesUsd`] HTTP mark price candlestick url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-kline-candlestick-data>
+pub const HTTP_MARK_PRICE_KLINES_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/markPriceKlines";
+
+/// [`BinanceFuturesUsd`] HTTP index price candlestick url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#index-price-kline-candlestick-data>
+pub const HTTP_INDEX_PRICE_KLINES_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/indexPriceKlines";
+
+/// [`BinanceFuturesUsd`] HTTP 24 hour rolling ticker url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#24hr-ticker-price-change-statistics>
+pub const HTTP_TICKER_24HR_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/ticker/24hr";
+
 /// [`Binance`](super::Binance) futures usd exchange.
 pub type BinanceFuturesUsd = Binance<BinanceServerFuturesUsd>;
 
@@ -34,11 +113,64 @@ impl ExchangeServer for BinanceServerFuturesUsd {
     }
 }
 
+#[cfg(feature = "private")]
+impl BinanceUserDataServer for BinanceServerFuturesUsd {
+    fn listen_key_url() -> &'static str {
+        HTTP_LISTEN_KEY_URL_BINANCE_FUTURES_USD
+    }
+}
+
+impl BinanceTimeServer for BinanceServerFuturesUsd {
+    fn server_time_url() -> &'static str {
+        HTTP_SERVER_TIME_URL_BINANCE_FUTURES_USD
+    }
+}
+
+impl BinanceRecentTradesServer for BinanceServerFuturesUsd {
+    fn recent_trades_url() -> &'static str {
+        HTTP_RECENT_TRADES_URL_BINANCE_FUTURES_USD
+    }
+}
+
+impl BinanceExchangeInfoServer for BinanceServerFuturesUsd {
+    fn exchange_info_url() -> &'static str {
+        HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD
+    }
+}
+
+impl BinanceTicker24hrServer for BinanceServerFuturesUsd {
+    fn ticker_24hr_url() -> &'static str {
+        HTTP_TICKER_24HR_URL_BINANCE_FUTURES_USD
+    }
+
+    fn instrument_kind() -> InstrumentKind {
+        InstrumentKind::Perpetual
+    }
+}
+
 impl StreamSelector<OrderBooksL2> for BinanceFuturesUsd {
     type Stream =
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
 }
 
 impl StreamSelector<Liquidations> for BinanceFuturesUsd {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
+    type Stream = ExchangeWsStream<BinanceLiquidations>;
+}
+
+impl StreamSelector<Candles> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, BinanceCandle>>;
+}
+
+impl StreamSelector<CompositeIndices> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, CompositeIndices, BinanceCompositeIndex>>;
+}
+
+impl StreamSelector<FundingRates> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, FundingRates, BinanceFundingRate>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<Positions> for BinanceFuturesUsd {
+    type Stream = PrivateWsStream<AccountTransformer<Self, Positions, BinancePositionData>>;
 }
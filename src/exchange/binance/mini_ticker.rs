@@ -0,0 +1,312 @@
+use super::{
+    channel::BinanceChannel, combined::BinanceMessage, spec::BinanceExchangeInfoServer, Binance,
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::{Connector, ExchangeServer, ExchangeSub},
+    subscription::{
+        market_wide::MarketWideMap,
+        ticker::{MiniTicker, MiniTickers},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`Binance`] mini ticker WebSocket message, either for an individual symbol, or an array
+/// containing the mini tickers of every symbol that changed (all-market).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceMiniTickerMessage {
+    AllMarket(Vec<BinanceMiniTicker>),
+    Instrument(BinanceMiniTicker),
+}
+
+/// [`Binance`] real-time rolling 24hr mini ticker.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-mini-ticker-stream>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-mini-ticker-stream>
+/// ```json
+/// {
+///     "e": "24hrMiniTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "c": "0.0025",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMiniTicker {
+    #[serde(
+        rename = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last: f64,
+    #[serde(rename = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(rename = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(rename = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quote_volume: f64,
+}
+
+/// [`Binance`] [`MiniTickers`] [`ExchangeTransformer`].
+///
+/// Individual symbol mini tickers are routed to their subscribed [`Instrument`] as normal.
+/// All-market mini tickers are resolved via the [`MarketWideMap`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BinanceMiniTickers<Server> {
+    instrument_map: MarketWideMap,
+    phantom: PhantomData<Server>,
+}
+
+#[async_trait]
+impl<Server> ExchangeTransformer<Binance<Server>, MiniTickers> for BinanceMiniTickers<Server>
+where
+    Server: BinanceExchangeInfoServer + Send + Sync,
+{
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map: MarketWideMap::init::<Binance<Server>>(instrument_map).await?,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Server> Transformer for BinanceMiniTickers<Server>
+where
+    Server: ExchangeServer,
+{
+    type Error = DataError;
+    type Input = BinanceMessage<BinanceMiniTickerMessage>;
+    type Output = MarketEvent<MiniTicker>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let (stream_id, message) = input.into_parts();
+
+        match message {
+            BinanceMiniTickerMessage::Instrument(ticker) => {
+                let subscription_id: SubscriptionId = stream_id.unwrap_or_else(|| {
+                    ExchangeSub::from((BinanceChannel::MINI_TICKER, ticker.market.as_str())).id()
+                });
+
+                match self.instrument_map.resolve(&subscription_id) {
+                    Some(Ok(instrument)) => vec![Ok(market_event::<Server>(instrument, ticker))],
+                    Some(Err(unidentifiable)) => vec![Err(DataError::Socket(unidentifiable))],
+                    None => vec![],
+                }
+            }
+            // Individual symbol subscriptions receive their own stream, so only all-market
+            // quote asset filters are used to avoid yielding duplicate events
+            BinanceMiniTickerMessage::AllMarket(tickers) => tickers
+                .into_iter()
+                .filter_map(|ticker| {
+                    let instrument = self.instrument_map.find_market_wide(&ticker.market)?;
+                    Some(Ok(market_event::<Server>(instrument, ticker)))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Construct a [`MarketEvent<MiniTicker>`] from the provided [`Instrument`] and
+/// [`BinanceMiniTicker`].
+fn market_event<Server>(
+    instrument: Instrument,
+    ticker: BinanceMiniTicker,
+) -> MarketEvent<MiniTicker>
+where
+    Server: ExchangeServer,
+{
+    MarketEvent {
+        exchange_time: ticker.time,
+        received_time: Utc::now(),
+        received_monotonic: MonotonicTime::now(),
+        origin: Origin::Live,
+        exchange: Exchange::from(Binance::<Server>::ID),
+        instrument,
+        kind: MiniTicker {
+            open: ticker.open,
+            high: ticker.high,
+            low: ticker.low,
+            last: ticker.last,
+            volume: ticker.volume,
+            quote_volume: ticker.quote_volume,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{combined::BinanceCombined, spot::BinanceServerSpot},
+        subscription::market_wide::SymbolTable,
+    };
+    use barter_integration::model::instrument::{kind::InstrumentKind, symbol::Symbol};
+    use std::sync::Arc;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_mini_ticker_message() {
+            struct TestCase {
+                input: &'static str,
+                expected_len: Option<usize>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: individual symbol mini ticker
+                    input: r#"
+                    {
+                        "e": "24hrMiniTicker", "E": 1672515782136, "s": "BNBBTC",
+                        "c": "0.0025", "o": "0.0010", "h": "0.0025", "l": "0.0010",
+                        "v": "10000", "q": "18"
+                    }
+                    "#,
+                    expected_len: None,
+                },
+                TestCase {
+                    // TC1: all-market mini tickers
+                    input: r#"
+                    [
+                        {
+                            "e": "24hrMiniTicker", "E": 1672515782136, "s": "BNBUSDT",
+                            "c": "250.1", "o": "240.0", "h": "255.0", "l": "239.5",
+                            "v": "10000", "q": "2500000"
+                        },
+                        {
+                            "e": "24hrMiniTicker", "E": 1672515782136, "s": "ETHBTC",
+                            "c": "0.07", "o": "0.06", "h": "0.08", "l": "0.06",
+                            "v": "100", "q": "7"
+                        }
+                    ]
+                    "#,
+                    expected_len: Some(2),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceMiniTickerMessage>(test.input).unwrap();
+                match (actual, test.expected_len) {
+                    (BinanceMiniTickerMessage::Instrument(_), None) => {
+                        // Test passed
+                    }
+                    (BinanceMiniTickerMessage::AllMarket(tickers), Some(expected)) => {
+                        assert_eq!(tickers.len(), expected, "TC{} failed", index)
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_mini_tickers_transform_all_market() {
+        let ticker = |market: &str| BinanceMiniTicker {
+            time: Utc::now(),
+            market: market.to_string(),
+            last: 1.0,
+            open: 1.0,
+            high: 1.0,
+            low: 1.0,
+            volume: 1.0,
+            quote_volume: 1.0,
+        };
+
+        let mut transformer = BinanceMiniTickers::<BinanceServerSpot> {
+            instrument_map: MarketWideMap::new(
+                Map::from_iter([
+                    (
+                        SubscriptionId::from("!miniTicker@arr|*USDT"),
+                        Instrument::from(("*", "usdt", InstrumentKind::Spot)),
+                    ),
+                    (
+                        SubscriptionId::from("@miniTicker|ETHBTC"),
+                        Instrument::from(("eth", "btc", InstrumentKind::Spot)),
+                    ),
+                ]),
+                Arc::new(SymbolTable::from_iter(
+                    [
+                        ("BNBUSDT", "bnb", "usdt"),
+                        ("ETHBTC", "eth", "btc"),
+                        ("XRPUSDT", "xrp", "usdt"),
+                    ]
+                    .map(|(symbol, base, quote)| {
+                        (symbol.to_owned(), (Symbol::new(base), Symbol::new(quote)))
+                    }),
+                )),
+            ),
+            phantom: PhantomData,
+        };
+
+        let actual = transformer
+            .transform(BinanceMessage::Raw(BinanceMiniTickerMessage::AllMarket(
+                vec![
+                    ticker("BNBUSDT"),
+                    ticker("ETHBTC"),
+                    ticker("USDT"),
+                    ticker("XRPUSDT"),
+                ],
+            )))
+            .into_iter()
+            .map(|event| event.unwrap().instrument)
+            .collect::<Vec<_>>();
+
+        // Only listed symbols quoted in USDT are yielded, with the listed base & quote assets
+        assert_eq!(
+            actual,
+            vec![
+                Instrument::from(("bnb", "usdt", InstrumentKind::Spot)),
+                Instrument::from(("xrp", "usdt", InstrumentKind::Spot)),
+            ]
+        );
+
+        // Individual symbol subscriptions are routed as normal
+        let actual = transformer.transform(BinanceMessage::Raw(
+            BinanceMiniTickerMessage::Instrument(ticker("ETHBTC")),
+        ));
+        assert_eq!(
+            actual.into_iter().next().unwrap().unwrap().instrument,
+            Instrument::from(("eth", "btc", InstrumentKind::Spot))
+        );
+
+        // Combined stream subscriptions are routed by their stream name
+        let actual = transformer.transform(BinanceMessage::Combined(BinanceCombined {
+            stream: "ethbtc@miniTicker".to_owned(),
+            data: BinanceMiniTickerMessage::Instrument(ticker("ETHBTC")),
+        }));
+        assert_eq!(
+            actual.into_iter().next().unwrap().unwrap().instrument,
+            Instrument::from(("eth", "btc", InstrumentKind::Spot))
+        );
+    }
+}
@@ -1,15 +1,41 @@
+#[cfg(feature = "private")]
+use self::user::{BinanceBalanceData, BinanceUserData, BinanceUserDataServer};
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::BinanceOrderBookL1,
+    channel::BinanceChannel,
+    combined::BinanceMessage,
+    market::BinanceMarket,
+    mini_ticker::BinanceMiniTickers,
+    spec::BinanceExchangeInfoServer,
+    subscription::BinanceSubResponse,
+    trade::{BinanceAggTrade, BinanceTrade},
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
+    exchange::{
+        Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector, SubscriptionLimits,
+    },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
+    subscription::{
+        book::OrderBooksL1,
+        ticker::MiniTickers,
+        trade::{AggTrades, PublicTrades},
+        Map,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, model::Instrument, protocol::websocket::WsMessage};
+#[cfg(feature = "private")]
+use crate::{
+    subscription::{
+        account::{Balances, OrderUpdates},
+        trade::UserTrades,
+    },
+    transformer::{account::AccountTransformer, balance::BalanceTransformer},
+    PrivateWsStream,
+};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use std::{fmt::Debug, marker::PhantomData};
 use url::Url;
 
@@ -17,10 +43,18 @@ use url::Url;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod book;
 
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Binance error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Combined stream [`ExchangeServer`] and message envelope types common to every [`Binance`]
+/// server, allowing many markets to share a single multiplexed connection.
+pub mod combined;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
@@ -29,24 +63,63 @@ pub mod futures;
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// Individual symbol & all-market mini ticker types common to both
+/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod mini_ticker;
+
+/// HTTP 24 hour rolling ticker [`VolumeRanking`](crate::ranking::VolumeRanking) implementation
+/// common to every [`Binance`] server.
+pub mod ranking;
+
+/// HTTP recent trades [`RecentTrades`](crate::streams::reconcile::RecentTrades) implementation
+/// common to every [`Binance`] server.
+pub mod rest_trade;
+
+/// HTTP exchange information [`InstrumentSpecs`](crate::spec::InstrumentSpecs) implementation
+/// common to every [`Binance`] server.
+pub mod spec;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BinanceSpot`](spot::BinanceSpot).
 pub mod spot;
 
+/// [`ExchangeServer`] and [`StreamSelector`] implementations for
+/// [`BinanceUs`](us::BinanceUs).
+pub mod us;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) common to both [`BinanceSpot`](spot::BinanceSpot)
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod subscription;
 
+/// HTTP server time [`ServerClock`](crate::clock::ServerClock) implementation common to every
+/// [`Binance`] server.
+pub mod time;
+
 /// Public trade types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
 
+/// User data stream types and listenKey authenticated
+/// [`PrivateConnector`](super::PrivateConnector) implementation common to both
+/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+/// Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub mod user;
+
+/// [`BinanceSpot`](spot::BinanceSpot) & [`BinanceUs`](us::BinanceUs) maximum number of streams
+/// per connection.
+pub const MAX_STREAMS_PER_CONNECTION_BINANCE: usize = 1024;
+
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) maximum number of streams per connection.
+pub const MAX_STREAMS_PER_CONNECTION_BINANCE_FUTURES_USD: usize = 200;
+
 /// Generic [`Binance<Server>`](Binance) exchange.
 ///
 /// ### Notes
 /// A `Server` [`ExchangeServer`](super::ExchangeServer) implementations exists for
-/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+/// [`BinanceSpot`](spot::BinanceSpot), [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) and
+/// [`BinanceUs`](us::BinanceUs).
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Binance<Server> {
     server: PhantomData<Server>,
@@ -67,10 +140,36 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    /// Partial depth [`BinanceChannel`]s are served from the combined stream endpoint (eg/
+    /// "wss://stream.binance.com:9443/stream"), which wraps each message with it's stream name.
+    ///
+    /// Note that the url is resolved per WebSocket connection, so the
+    /// [`StreamBuilder`](crate::streams::builder::StreamBuilder) splits partial depth
+    /// subscriptions onto a separate connection from diff depth subscriptions, unless every
+    /// stream is consumed from the combined stream endpoint (see [`Combined`](combined::Combined)).
+    fn channel_url(channel: &Self::Channel) -> Result<Url, SocketError> {
+        let url = Server::websocket_url();
+        if channel.is_partial_depth() && url.ends_with("/ws") {
+            let base_url = url.trim_end_matches("/ws");
+            Url::parse(&format!("{base_url}/stream")).map_err(SocketError::UrlParse)
+        } else {
+            Self::url()
+        }
+    }
+
+    fn regional_hosts() -> &'static [&'static str] {
+        Server::regional_hosts()
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
+        let mut stream_names = exchange_subs
             .into_iter()
             .map(|sub| {
+                // All-market streams (eg/ "!miniTicker@arr") are not prefixed with a market
+                if sub.channel.is_all_market() {
+                    return sub.channel.as_ref().to_owned();
+                }
+
                 // Note:
                 // Market must be lowercase when subscribing, but lowercase in general since
                 // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
@@ -82,6 +181,10 @@ where
             })
             .collect::<Vec<String>>();
 
+        // Many Subscriptions can share an all-market stream, so remove duplicates
+        stream_names.sort();
+        stream_names.dedup();
+
         vec![WsMessage::Text(
             serde_json::json!({
                 "method": "SUBSCRIBE",
@@ -95,20 +198,76 @@ where
     fn expected_responses(_: &Map<Instrument>) -> usize {
         1
     }
+
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+    fn subscription_limits() -> SubscriptionLimits {
+        let streams_per_connection = match Server::ID {
+            ExchangeId::BinanceFuturesUsd => MAX_STREAMS_PER_CONNECTION_BINANCE_FUTURES_USD,
+            _ => MAX_STREAMS_PER_CONNECTION_BINANCE,
+        };
+
+        SubscriptionLimits {
+            streams_per_connection: Some(streams_per_connection),
+            ..SubscriptionLimits::UNLIMITED
+        }
+    }
 }
 
 impl<Server> StreamSelector<PublicTrades> for Binance<Server>
 where
     Server: ExchangeServer + Debug + Send + Sync,
 {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BinanceTrade>>;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BinanceMessage<BinanceTrade>>>;
+}
+
+impl<Server> StreamSelector<AggTrades> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, AggTrades, BinanceMessage<BinanceAggTrade>>>;
 }
 
 impl<Server> StreamSelector<OrderBooksL1> for Binance<Server>
 where
     Server: ExchangeServer + Debug + Send + Sync,
 {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BinanceOrderBookL1>>;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, OrderBooksL1, BinanceMessage<BinanceOrderBookL1>>,
+    >;
+}
+
+impl<Server> StreamSelector<MiniTickers> for Binance<Server>
+where
+    Server: BinanceExchangeInfoServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<BinanceMiniTickers<Server>>;
+}
+
+#[cfg(feature = "private")]
+impl<Server> StreamSelector<UserTrades> for Binance<Server>
+where
+    Server: BinanceUserDataServer + Debug + Send + Sync,
+{
+    type Stream = PrivateWsStream<AccountTransformer<Self, UserTrades, BinanceUserData>>;
+}
+
+#[cfg(feature = "private")]
+impl<Server> StreamSelector<OrderUpdates> for Binance<Server>
+where
+    Server: BinanceUserDataServer + Debug + Send + Sync,
+{
+    type Stream = PrivateWsStream<AccountTransformer<Self, OrderUpdates, BinanceUserData>>;
+}
+
+#[cfg(feature = "private")]
+impl<Server> StreamSelector<Balances> for Binance<Server>
+where
+    Server: BinanceUserDataServer + Debug + Send + Sync,
+{
+    type Stream = PrivateWsStream<BalanceTransformer<Self, BinanceBalanceData>>;
 }
 
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
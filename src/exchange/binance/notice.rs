@@ -0,0 +1,101 @@
+use super::Binance;
+use crate::{
+    exchange::ExchangeServer,
+    notice::{Notice, NoticeKind, NoticeParser},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`] WebSocket error message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#error-messages>
+/// ```json
+/// {"code": 2, "msg": "Invalid request: unknown variant 'SUBSCRIB'", "id": 1}
+/// ```
+///
+/// ```json
+/// {"error": {"code": -1003, "msg": "Too many requests"}, "id": 1}
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceNotice {
+    Nested { error: BinanceError },
+    Flat(BinanceError),
+}
+
+/// [`BinanceNotice`] error code & message.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct BinanceError {
+    pub code: i64,
+    pub msg: String,
+}
+
+impl<Server> NoticeParser for Binance<Server>
+where
+    Server: ExchangeServer,
+{
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let (BinanceNotice::Nested { error } | BinanceNotice::Flat(error)) =
+            serde_json::from_str::<BinanceNotice>(payload).ok()?;
+
+        let kind = match error.code {
+            -1003 | -1015 => NoticeKind::RateLimit,
+            -1002 | -1022 | -2014 | -2015 => NoticeKind::Unauthorised,
+            -1001 => NoticeKind::Reconnect,
+            0..=3 => NoticeKind::InvalidRequest,
+            _ => NoticeKind::Other,
+        };
+
+        Some(Notice::new(kind, Some(error.code), error.msg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::binance::spot::BinanceSpot;
+
+    #[test]
+    fn test_binance_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: invalid request error
+            TestCase {
+                input: r#"{"code": 2, "msg": "Invalid request: unknown variant", "id": 1}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    Some(2),
+                    "Invalid request: unknown variant",
+                )),
+            },
+            // TC1: nested rate limit error
+            TestCase {
+                input: r#"{"error": {"code": -1003, "msg": "Too many requests"}, "id": 1}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::RateLimit,
+                    Some(-1003),
+                    "Too many requests",
+                )),
+            },
+            // TC2: subscription response is not a notice
+            TestCase {
+                input: r#"{"result": null, "id": 1}"#,
+                expected: None,
+            },
+            // TC3: trade is not a notice
+            TestCase {
+                input: r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BinanceSpot::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::Binance;
+use crate::{
+    exchange::ExchangeServer,
+    ranking::{VolumeRanking, VolumeTicker},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{kind::InstrumentKind, Instrument},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`ExchangeServer`] that serves the [`Binance`] HTTP 24 hour rolling ticker endpoint.
+pub trait BinanceTicker24hrServer
+where
+    Self: ExchangeServer,
+{
+    /// HTTP url used to fetch the 24 hour rolling ticker of every market.
+    fn ticker_24hr_url() -> &'static str;
+
+    /// [`InstrumentKind`] of the markets listed by the endpoint.
+    fn instrument_kind() -> InstrumentKind;
+}
+
+/// [`Binance`] HTTP 24 hour rolling ticker, of which only the volume fields are used.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#24hr-ticker-price-change-statistics>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#24hr-ticker-price-change-statistics>
+/// ```json
+/// {
+///   "symbol": "BTCUSDT",
+///   "priceChange": "-94.99999800",
+///   "lastPrice": "4.00000200",
+///   "volume": "8913.30000000",
+///   "quoteVolume": "15.30000000",
+///   "openTime": 1499783499040,
+///   "closeTime": 1499869899040,
+///   "count": 76
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker24hr {
+    pub symbol: String,
+    #[serde(
+        alias = "quoteVolume",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub quote_volume: f64,
+}
+
+impl BinanceTicker24hr {
+    /// Map the [`Binance`] symbol to an [`Instrument`] if it is quoted in the provided `quote`
+    /// asset.
+    ///
+    /// Delivery futures symbols (eg/ "BTCUSDT_240628") are not perpetual markets, so are omitted.
+    pub fn instrument(&self, quote: &str, kind: InstrumentKind) -> Option<Instrument> {
+        if self.symbol.contains('_') {
+            return None;
+        }
+
+        let symbol = self.symbol.to_lowercase();
+        let base = symbol.strip_suffix(&quote.to_lowercase())?;
+        (!base.is_empty()).then(|| Instrument::from((base, quote, kind)))
+    }
+}
+
+#[async_trait]
+impl<Server> VolumeRanking for Binance<Server>
+where
+    Server: BinanceTicker24hrServer + Send + Sync,
+{
+    async fn volume_tickers(quote: &str) -> Result<Vec<VolumeTicker>, SocketError> {
+        let tickers = reqwest::get(Server::ticker_24hr_url())
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<Vec<BinanceTicker24hr>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(volume_tickers(tickers, quote, Server::instrument_kind()))
+    }
+}
+
+/// Map [`BinanceTicker24hr`]s quoted in the provided `quote` asset to [`VolumeTicker`]s.
+fn volume_tickers(
+    tickers: Vec<BinanceTicker24hr>,
+    quote: &str,
+    kind: InstrumentKind,
+) -> Vec<VolumeTicker> {
+    tickers
+        .into_iter()
+        .filter_map(|ticker| {
+            ticker
+                .instrument(quote, kind)
+                .map(|instrument| VolumeTicker {
+                    instrument,
+                    quote_volume: ticker.quote_volume,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_ticker_24hr() {
+            let input = r#"
+            [
+                {
+                    "symbol": "BTCUSDT", "priceChange": "-94.99999800", "lastPrice": "4.00000200",
+                    "volume": "8913.30000000", "quoteVolume": "15.30000000",
+                    "openTime": 1499783499040, "closeTime": 1499869899040, "count": 76
+                },
+                {
+                    "symbol": "ETHBTC", "volume": "10.0", "quoteVolume": "0.5"
+                }
+            ]
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<BinanceTicker24hr>>(input).unwrap(),
+                vec![
+                    BinanceTicker24hr {
+                        symbol: "BTCUSDT".to_owned(),
+                        quote_volume: 15.3,
+                    },
+                    BinanceTicker24hr {
+                        symbol: "ETHBTC".to_owned(),
+                        quote_volume: 0.5,
+                    },
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_volume_tickers() {
+        let ticker = |symbol: &str, quote_volume: f64| BinanceTicker24hr {
+            symbol: symbol.to_owned(),
+            quote_volume,
+        };
+
+        let input = vec![
+            ticker("BTCUSDT", 30.0),
+            ticker("ETHBTC", 20.0),
+            ticker("BTCUSDT_240628", 10.0),
+            ticker("USDT", 5.0),
+            ticker("SOLUSDT", 1.0),
+        ];
+
+        assert_eq!(
+            volume_tickers(input, "usdt", InstrumentKind::Perpetual),
+            vec![
+                VolumeTicker {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                    quote_volume: 30.0,
+                },
+                VolumeTicker {
+                    instrument: Instrument::from(("sol", "usdt", InstrumentKind::Perpetual)),
+                    quote_volume: 1.0,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use super::{trade::de_side_from_buyer_is_maker, Binance};
+use crate::{
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::{Connector, ExchangeServer},
+    streams::reconcile::RecentTrades,
+    subscription::trade::PublicTrade,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, Side},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of recent trades requested from the [`Binance`] HTTP recent trades endpoint.
+pub const RECENT_TRADES_LIMIT: u16 = 1000;
+
+/// [`ExchangeServer`] that serves the [`Binance`] HTTP recent trades endpoint.
+pub trait BinanceRecentTradesServer
+where
+    Self: ExchangeServer,
+{
+    /// HTTP url used to fetch the recent trades of a market.
+    fn recent_trades_url() -> &'static str;
+}
+
+/// [`Binance`] HTTP recent trade.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#recent-trades-list>
+/// ```json
+/// {
+///   "id": 28457,
+///   "price": "4.00000100",
+///   "qty": "12.00000000",
+///   "quoteQty": "48.000012",
+///   "time": 1499865549590,
+///   "isBuyerMaker": true,
+///   "isBestMatch": true
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceRestTrade {
+    pub id: u64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "qty", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(
+        alias = "isBuyerMaker",
+        deserialize_with = "de_side_from_buyer_is_maker"
+    )]
+    pub side: Side,
+}
+
+#[async_trait]
+impl<Server> RecentTrades for Binance<Server>
+where
+    Server: BinanceRecentTradesServer + Send + Sync,
+{
+    async fn recent_trades(
+        instrument: &Instrument,
+    ) -> Result<Vec<MarketEvent<PublicTrade>>, SocketError> {
+        let market = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+
+        let trades = reqwest::Client::new()
+            .get(Server::recent_trades_url())
+            .query(&[
+                ("symbol", market),
+                ("limit", RECENT_TRADES_LIMIT.to_string()),
+            ])
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<Vec<BinanceRestTrade>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(trades
+            .into_iter()
+            .map(|trade| MarketEvent {
+                exchange_time: trade.time,
+                received_time: Utc::now(),
+                received_monotonic: MonotonicTime::now(),
+                origin: Origin::Backfill,
+                exchange: Exchange::from(Self::ID),
+                instrument: instrument.clone(),
+                kind: PublicTrade {
+                    buyer_is_maker: Some(trade.side == Side::Sell),
+                    ..PublicTrade::new(trade.id, trade.price, trade.amount, trade.side)
+                },
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_rest_trades() {
+            let input = r#"
+            [
+                {
+                    "id": 28457, "price": "4.00000100", "qty": "12.00000000",
+                    "quoteQty": "48.000012", "time": 1499865549590, "isBuyerMaker": true,
+                    "isBestMatch": true
+                },
+                {
+                    "id": 28458, "price": "4.00000200", "qty": "1.50000000",
+                    "quoteQty": "6.000003", "time": 1499865549591, "isBuyerMaker": false
+                }
+            ]
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<BinanceRestTrade>>(input).unwrap(),
+                vec![
+                    BinanceRestTrade {
+                        id: 28457,
+                        price: 4.000001,
+                        amount: 12.0,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1499865549590
+                        )),
+                        side: Side::Sell,
+                    },
+                    BinanceRestTrade {
+                        id: 28458,
+                        price: 4.000002,
+                        amount: 1.5,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1499865549591
+                        )),
+                        side: Side::Buy,
+                    },
+                ]
+            );
+        }
+    }
+}
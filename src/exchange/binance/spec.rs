@@ -0,0 +1,213 @@
+use super::Binance;
+use crate::{
+    exchange::ExchangeServer,
+    spec::{InstrumentSpec, InstrumentSpecs},
+    subscription::market_wide::{MarketSymbols, SymbolTable},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{symbol::Symbol, Instrument},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`ExchangeServer`] that serves the [`Binance`] HTTP exchange information endpoint.
+pub trait BinanceExchangeInfoServer
+where
+    Self: ExchangeServer,
+{
+    /// HTTP url used to fetch the trading rules of every market.
+    fn exchange_info_url() -> &'static str;
+}
+
+/// [`Binance`] HTTP exchange information response, containing the trading rules of every market.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+/// ```json
+/// {
+///   "timezone": "UTC",
+///   "symbols": [
+///     {
+///       "symbol": "BTCUSDT",
+///       "status": "TRADING",
+///       "baseAsset": "BTC",
+///       "quoteAsset": "USDT",
+///       "filters": [
+///         {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+///         {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"}
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// [`Binance`] market trading rules contained in a [`BinanceExchangeInfo`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolInfo {
+    pub symbol: String,
+    #[serde(alias = "baseAsset")]
+    pub base: Symbol,
+    #[serde(alias = "quoteAsset")]
+    pub quote: Symbol,
+    pub filters: Vec<BinanceSymbolFilter>,
+}
+
+/// [`Binance`] market trading rule filter, of which only the price & lot size filters are used.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "filterType")]
+pub enum BinanceSymbolFilter {
+    #[serde(rename = "PRICE_FILTER")]
+    Price {
+        #[serde(
+            alias = "tickSize",
+            deserialize_with = "barter_integration::de::de_str"
+        )]
+        tick_size: f64,
+    },
+    #[serde(rename = "LOT_SIZE")]
+    LotSize {
+        #[serde(
+            alias = "stepSize",
+            deserialize_with = "barter_integration::de::de_str"
+        )]
+        step_size: f64,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl BinanceSymbolInfo {
+    /// Construct the [`InstrumentSpec`] of this market from it's price & lot size filters.
+    pub fn spec(&self) -> Option<InstrumentSpec> {
+        let tick_size = self.filters.iter().find_map(|filter| match filter {
+            BinanceSymbolFilter::Price { tick_size } => Some(*tick_size),
+            _ => None,
+        })?;
+        let lot_size = self.filters.iter().find_map(|filter| match filter {
+            BinanceSymbolFilter::LotSize { step_size } => Some(*step_size),
+            _ => None,
+        })?;
+
+        Some(InstrumentSpec {
+            tick_size,
+            lot_size,
+            // Binance spot & USD-M futures amounts are denominated in the base asset
+            contract_multiplier: 1.0,
+        })
+    }
+}
+
+/// Fetch the [`BinanceExchangeInfo`] served by the provided [`BinanceExchangeInfoServer`].
+async fn exchange_info<Server>() -> Result<BinanceExchangeInfo, SocketError>
+where
+    Server: BinanceExchangeInfoServer,
+{
+    reqwest::get(Server::exchange_info_url())
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(SocketError::Http)?
+        .json::<BinanceExchangeInfo>()
+        .await
+        .map_err(SocketError::Http)
+}
+
+#[async_trait]
+impl<Server> InstrumentSpecs for Binance<Server>
+where
+    Server: BinanceExchangeInfoServer + Send + Sync,
+{
+    async fn instrument_specs(
+        instruments: &[Instrument],
+    ) -> Result<Vec<(Instrument, InstrumentSpec)>, SocketError> {
+        let info = exchange_info::<Server>().await?;
+
+        Ok(instruments
+            .iter()
+            .filter_map(|instrument| {
+                let market = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+                info.symbols
+                    .iter()
+                    .find(|symbol| symbol.symbol == market)
+                    .and_then(BinanceSymbolInfo::spec)
+                    .map(|spec| (instrument.clone(), spec))
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<Server> MarketSymbols for Binance<Server>
+where
+    Server: BinanceExchangeInfoServer + Send + Sync,
+{
+    async fn market_symbols() -> Result<SymbolTable, SocketError> {
+        Ok(exchange_info::<Server>()
+            .await?
+            .symbols
+            .into_iter()
+            .map(|info| (info.symbol, (info.base, info.quote)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_exchange_info() {
+            let input = r#"
+            {
+                "timezone": "UTC",
+                "symbols": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "status": "TRADING",
+                        "baseAsset": "BTC",
+                        "quoteAsset": "USDT",
+                        "filters": [
+                            {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+                            {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+                            {"filterType": "MAX_NUM_ORDERS", "maxNumOrders": 200}
+                        ]
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BinanceExchangeInfo>(input).unwrap();
+            assert_eq!(
+                actual,
+                BinanceExchangeInfo {
+                    symbols: vec![BinanceSymbolInfo {
+                        symbol: "BTCUSDT".to_owned(),
+                        base: Symbol::new("btc"),
+                        quote: Symbol::new("usdt"),
+                        filters: vec![
+                            BinanceSymbolFilter::Price { tick_size: 0.01 },
+                            BinanceSymbolFilter::LotSize { step_size: 0.00001 },
+                            BinanceSymbolFilter::Other,
+                        ],
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.symbols[0].spec(),
+                Some(InstrumentSpec {
+                    tick_size: 0.01,
+                    lot_size: 0.00001,
+                    contract_multiplier: 1.0,
+                })
+            );
+        }
+    }
+}
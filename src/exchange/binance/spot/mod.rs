@@ -1,21 +1,65 @@
-use self::l2::BinanceSpotBookUpdater;
-use super::{Binance, ExchangeServer};
+use self::{l2::BinanceSpotBookUpdater, ticker::BinanceTicker};
+#[cfg(feature = "private")]
+use super::user::BinanceUserDataServer;
+use super::{
+    ranking::BinanceTicker24hrServer, rest_trade::BinanceRecentTradesServer,
+    spec::BinanceExchangeInfoServer, time::BinanceTimeServer, Binance, ExchangeServer,
+};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::book::OrderBooksL2,
-    transformer::book::MultiBookTransformer,
+    subscription::{book::OrderBooksL2, ticker::Tickers},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
+use barter_integration::model::instrument::kind::InstrumentKind;
 
 /// Level 2 OrderBook types (top of book) and spot
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
 pub mod l2;
 
+/// Rolling window ticker types.
+pub mod ticker;
+
+/// [`BinanceSpotSbe`](sbe::BinanceSpotSbe) [`Connector`](crate::exchange::Connector) for the
+/// binary SBE encoded spot market data streams.
+pub mod sbe;
+
 /// [`BinanceSpot`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/ws";
 
+/// [`BinanceSpot`] alternative WebSocket hosts, including the market data only mirror.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#general-wss-information>
+pub const REGIONAL_HOSTS_BINANCE_SPOT: &[&str] =
+    &["stream.binance.com:443", "data-stream.binance.vision:443"];
+
+/// [`BinanceSpot`] HTTP user data stream listenKey url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+pub const HTTP_LISTEN_KEY_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/userDataStream";
+
+/// [`BinanceSpot`] HTTP server time url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#check-server-time>
+pub const HTTP_SERVER_TIME_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/time";
+
+/// [`BinanceSpot`] HTTP recent trades url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#recent-trades-list>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/trades";
+
+/// [`BinanceSpot`] HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// [`BinanceSpot`] HTTP 24 hour rolling ticker url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#24hr-ticker-price-change-statistics>
+pub const HTTP_TICKER_24HR_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/ticker/24hr";
+
 /// [`Binance`](super::Binance) spot exchange.
 pub type BinanceSpot = Binance<BinanceServerSpot>;
 
@@ -29,9 +73,52 @@ impl ExchangeServer for BinanceServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_SPOT
     }
+
+    fn regional_hosts() -> &'static [&'static str] {
+        REGIONAL_HOSTS_BINANCE_SPOT
+    }
+}
+
+#[cfg(feature = "private")]
+impl BinanceUserDataServer for BinanceServerSpot {
+    fn listen_key_url() -> &'static str {
+        HTTP_LISTEN_KEY_URL_BINANCE_SPOT
+    }
+}
+
+impl BinanceTimeServer for BinanceServerSpot {
+    fn server_time_url() -> &'static str {
+        HTTP_SERVER_TIME_URL_BINANCE_SPOT
+    }
+}
+
+impl BinanceRecentTradesServer for BinanceServerSpot {
+    fn recent_trades_url() -> &'static str {
+        HTTP_RECENT_TRADES_URL_BINANCE_SPOT
+    }
+}
+
+impl BinanceExchangeInfoServer for BinanceServerSpot {
+    fn exchange_info_url() -> &'static str {
+        HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT
+    }
+}
+
+impl BinanceTicker24hrServer for BinanceServerSpot {
+    fn ticker_24hr_url() -> &'static str {
+        HTTP_TICKER_24HR_URL_BINANCE_SPOT
+    }
+
+    fn instrument_kind() -> InstrumentKind {
+        InstrumentKind::Spot
+    }
 }
 
 impl StreamSelector<OrderBooksL2> for BinanceSpot {
     type Stream =
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
 }
+
+impl StreamSelector<Tickers> for BinanceSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, BinanceTicker>>;
+}
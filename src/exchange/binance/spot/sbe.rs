@@ -0,0 +1,484 @@
+use super::BinanceSpot;
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{
+        binance::{
+            channel::BinanceChannel, market::BinanceMarket, subscription::BinanceSubResponse,
+        },
+        Connector, ExchangeId, ExchangeSub, StreamSelector,
+    },
+    sbe::{de_sbe, decimal, SbeDecode, SbeHeader, SbeReader},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::{Level, OrderBookL1, OrderBooksL1},
+        trade::{PublicTrade, PublicTrades},
+        Map, Subscription,
+    },
+    transformer::stateless::StatelessTransformer,
+    ExchangeBinaryStream, Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserializer;
+use url::Url;
+
+/// [`BinanceSpotSbe`] WebSocket server base url.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/sbe-market-data-streams>
+pub const WEBSOCKET_BASE_URL_BINANCE_SPOT_SBE: &str = "wss://stream-sbe.binance.com:9443/ws";
+
+/// HTTP header the [`BinanceSpotSbe`] Ed25519 API key must be provided in when connecting.
+pub const HEADER_BINANCE_API_KEY: &str = "X-MBX-APIKEY";
+
+/// [`BinanceSpotSbe`] market data stream SBE schema id.
+pub const SCHEMA_ID_BINANCE_SPOT_SBE: u16 = 1;
+
+/// [`Binance`](super::super::Binance) spot exchange SBE market data streams.
+///
+/// Market data is served as binary SBE encoded messages, which are decoded by the
+/// [`BinaryParser`](crate::sbe::BinaryParser) into the same normalised [`MarketEvent<T>`]s as
+/// [`BinanceSpot`], so events are identified by [`ExchangeId::BinanceSpot`].
+///
+/// Connecting requires an Ed25519 API key in the [`HEADER_BINANCE_API_KEY`] header, which is
+/// provided via a [`HeaderConnector`](crate::transport::HeaderConnector) transport.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::binance::spot::sbe::{BinanceSpotSbe, HEADER_BINANCE_API_KEY},
+///     streams::{config::StreamConfig, Streams},
+///     subscription::trade::PublicTrades,
+///     transport::HeaderConnector,
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = StreamConfig::default()
+///         .with_transport(HeaderConnector::default().with_header(HEADER_BINANCE_API_KEY, "api_key"));
+///
+///     let streams = Streams::<PublicTrades>::builder()
+///         .subscribe_with(
+///             [(BinanceSpotSbe, "btc", "usdt", InstrumentKind::Spot, PublicTrades)],
+///             config,
+///         )
+///         .init()
+///         .await
+///         .unwrap();
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceSpotSbe;
+
+impl Connector for BinanceSpotSbe {
+    const ID: ExchangeId = ExchangeId::BinanceSpot;
+    type Channel = BinanceChannel;
+    type Market = BinanceMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = BinanceSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(WEBSOCKET_BASE_URL_BINANCE_SPOT_SBE).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // SBE streams are subscribed to with the same JSON requests as the BinanceSpot streams
+        BinanceSpot::requests(exchange_subs)
+    }
+
+    fn expected_responses(_: &Map<Instrument>) -> usize {
+        1
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BinanceSpotSbe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let input = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let expected = Self::ID.as_str();
+
+        if input.as_str() == Self::ID.as_str() {
+            Ok(Self)
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(input.as_str()),
+                &expected,
+            ))
+        }
+    }
+}
+
+impl serde::Serialize for BinanceSpotSbe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(Self::ID.as_str())
+    }
+}
+
+impl StreamSelector<PublicTrades> for BinanceSpotSbe {
+    type Stream = ExchangeBinaryStream<StatelessTransformer<Self, PublicTrades, BinanceSbeTrades>>;
+}
+
+impl StreamSelector<OrderBooksL1> for BinanceSpotSbe {
+    type Stream =
+        ExchangeBinaryStream<StatelessTransformer<Self, OrderBooksL1, BinanceSbeBestBidAsk>>;
+}
+
+impl<Kind> Identifier<BinanceMarket> for Subscription<BinanceSpotSbe, Kind> {
+    fn id(&self) -> BinanceMarket {
+        BinanceMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotSbe, PublicTrades> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TRADES
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceSpotSbe, OrderBooksL1> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::BEST_BID_ASK
+    }
+}
+
+/// [`BinanceSpotSbe`] `TradesStreamEvent` SBE message (template id 10000).
+///
+/// ### Encoding
+/// Root block of `eventTime` (i64 µs), `transactTime` (i64 µs), `priceExponent` (i8) &
+/// `qtyExponent` (i8), followed by a `trades` group (u16 blockLength, u32 numInGroup) of `id`
+/// (i64), `price` (i64 mantissa), `qty` (i64 mantissa) & `isBuyerMaker` (u8) entries, followed by
+/// the `symbol` varString8.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/sbe-market-data-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct BinanceSbeTrades {
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub trades: Vec<BinanceSbeTrade>,
+}
+
+/// Entry of a [`BinanceSbeTrades`] `trades` group.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct BinanceSbeTrade {
+    pub id: u64,
+    pub price: f64,
+    pub amount: f64,
+    pub side: Side,
+}
+
+impl SbeDecode for BinanceSbeTrades {
+    const SCHEMA_ID: u16 = SCHEMA_ID_BINANCE_SPOT_SBE;
+    const TEMPLATE_ID: u16 = 10000;
+
+    fn decode_body(header: SbeHeader, reader: &mut SbeReader<'_>) -> Result<Self, String> {
+        let block_start = reader.position();
+        let _event_time = reader.i64()?;
+        let time = datetime_utc_from_epoch_us(reader.i64()?)?;
+        let price_exponent = reader.i8()?;
+        let qty_exponent = reader.i8()?;
+        reader.seek(block_start + header.block_length as usize)?;
+
+        let entry_length = reader.u16()? as usize;
+        let num_trades = reader.u32()?;
+        let trades = (0..num_trades)
+            .map(|_| {
+                let entry_start = reader.position();
+                let id = reader.i64()? as u64;
+                let price = decimal(reader.i64()?, price_exponent);
+                let amount = decimal(reader.i64()?, qty_exponent);
+                let side = match reader.u8()? {
+                    0 => Side::Buy,
+                    _ => Side::Sell,
+                };
+                reader.seek(entry_start + entry_length)?;
+
+                Ok(BinanceSbeTrade {
+                    id,
+                    price,
+                    amount,
+                    side,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let market = reader.var_string8()?;
+
+        Ok(Self {
+            subscription_id: ExchangeSub::from((BinanceChannel::TRADES, market)).id(),
+            time,
+            trades,
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BinanceSbeTrades {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_sbe(deserializer)
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceSbeTrades {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceSbeTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, BinanceSbeTrades)) -> Self {
+        trades
+            .trades
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trades.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        buyer_is_maker: Some(trade.side == Side::Sell),
+                        ..PublicTrade::new(trade.id, trade.price, trade.amount, trade.side)
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// [`BinanceSpotSbe`] `BestBidAskStreamEvent` SBE message (template id 10001).
+///
+/// ### Encoding
+/// Root block of `eventTime` (i64 µs), `bookUpdateId` (i64), `priceExponent` (i8),
+/// `qtyExponent` (i8), `bidPrice`, `bidQty`, `askPrice` & `askQty` (i64 mantissas), followed by
+/// the `symbol` varString8.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/sbe-market-data-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct BinanceSbeBestBidAsk {
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub update_id: u64,
+    pub best_bid: Level,
+    pub best_ask: Level,
+}
+
+impl SbeDecode for BinanceSbeBestBidAsk {
+    const SCHEMA_ID: u16 = SCHEMA_ID_BINANCE_SPOT_SBE;
+    const TEMPLATE_ID: u16 = 10001;
+
+    fn decode_body(header: SbeHeader, reader: &mut SbeReader<'_>) -> Result<Self, String> {
+        let block_start = reader.position();
+        let time = datetime_utc_from_epoch_us(reader.i64()?)?;
+        let update_id = reader.i64()? as u64;
+        let price_exponent = reader.i8()?;
+        let qty_exponent = reader.i8()?;
+        let best_bid = Level::new(
+            decimal(reader.i64()?, price_exponent),
+            decimal(reader.i64()?, qty_exponent),
+        );
+        let best_ask = Level::new(
+            decimal(reader.i64()?, price_exponent),
+            decimal(reader.i64()?, qty_exponent),
+        );
+        reader.seek(block_start + header.block_length as usize)?;
+
+        let market = reader.var_string8()?;
+
+        Ok(Self {
+            subscription_id: ExchangeSub::from((BinanceChannel::BEST_BID_ASK, market)).id(),
+            time,
+            update_id,
+            best_bid,
+            best_ask,
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BinanceSbeBestBidAsk {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de_sbe(deserializer)
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceSbeBestBidAsk {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceSbeBestBidAsk)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, Instrument, BinanceSbeBestBidAsk),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: book.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.time,
+                best_bid: book.best_bid,
+                best_ask: book.best_ask,
+            },
+        })])
+    }
+}
+
+/// Convert a microsecond epoch timestamp into a [`DateTime<Utc>`].
+fn datetime_utc_from_epoch_us(micros: i64) -> Result<DateTime<Utc>, String> {
+    DateTime::from_timestamp_micros(micros).ok_or_else(|| format!("invalid timestamp: {micros}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::sbe::BinaryParser;
+        use barter_integration::protocol::StreamParser;
+
+        /// Encode an SBE message header followed by the provided body.
+        fn encode(block_length: u16, template_id: u16, body: &[u8]) -> Vec<u8> {
+            [
+                &block_length.to_le_bytes()[..],
+                &template_id.to_le_bytes(),
+                &SCHEMA_ID_BINANCE_SPOT_SBE.to_le_bytes(),
+                &0u16.to_le_bytes(),
+                body,
+            ]
+            .concat()
+        }
+
+        fn var_string8(value: &str) -> Vec<u8> {
+            [&[value.len() as u8][..], value.as_bytes()].concat()
+        }
+
+        #[test]
+        fn test_binance_sbe_trades() {
+            let body = [
+                // Root block
+                &1_700_000_000_000_001i64.to_le_bytes()[..],
+                &1_700_000_000_000_000i64.to_le_bytes(),
+                &(-2i8).to_le_bytes(),
+                &(-4i8).to_le_bytes(),
+                // Trades group header
+                &25u16.to_le_bytes(),
+                &2u32.to_le_bytes(),
+                // Trade entries
+                &10i64.to_le_bytes(),
+                &3_500_012i64.to_le_bytes(),
+                &2_500i64.to_le_bytes(),
+                &[0],
+                &11i64.to_le_bytes(),
+                &3_500_000i64.to_le_bytes(),
+                &10_000i64.to_le_bytes(),
+                &[1],
+                &var_string8("ETHUSDT"),
+            ]
+            .concat();
+
+            let actual = BinaryParser::parse::<BinanceSbeTrades>(Ok(WsMessage::Binary(encode(
+                18, 10000, &body,
+            ))))
+            .unwrap()
+            .unwrap();
+
+            let expected = BinanceSbeTrades {
+                subscription_id: SubscriptionId::from("@trade|ETHUSDT"),
+                time: DateTime::from_timestamp_micros(1_700_000_000_000_000).unwrap(),
+                trades: vec![
+                    BinanceSbeTrade {
+                        id: 10,
+                        price: 35000.12,
+                        amount: 0.25,
+                        side: Side::Buy,
+                    },
+                    BinanceSbeTrade {
+                        id: 11,
+                        price: 35000.0,
+                        amount: 1.0,
+                        side: Side::Sell,
+                    },
+                ],
+            };
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn test_binance_sbe_best_bid_ask() {
+            let body = [
+                &1_700_000_000_000_000i64.to_le_bytes()[..],
+                &42i64.to_le_bytes(),
+                &(-2i8).to_le_bytes(),
+                &(-3i8).to_le_bytes(),
+                &3_500_012i64.to_le_bytes(),
+                &1_500i64.to_le_bytes(),
+                &3_500_013i64.to_le_bytes(),
+                &250i64.to_le_bytes(),
+                &var_string8("ETHUSDT"),
+            ]
+            .concat();
+
+            struct TestCase {
+                input: WsMessage,
+                expected: Result<BinanceSbeBestBidAsk, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid BestBidAskStreamEvent
+                    input: WsMessage::Binary(encode(50, 10001, &body)),
+                    expected: Ok(BinanceSbeBestBidAsk {
+                        subscription_id: SubscriptionId::from("@bestBidAsk|ETHUSDT"),
+                        time: DateTime::from_timestamp_micros(1_700_000_000_000_000).unwrap(),
+                        update_id: 42,
+                        best_bid: Level::new(35000.12, 1.5),
+                        best_ask: Level::new(35000.13, 0.25),
+                    }),
+                },
+                TestCase {
+                    // TC1: TradesStreamEvent template is not a BestBidAskStreamEvent
+                    input: WsMessage::Binary(encode(50, 10000, &body)),
+                    expected: Err(SocketError::Sink),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = BinaryParser::parse::<BinanceSbeBestBidAsk>(Ok(test.input)).unwrap();
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
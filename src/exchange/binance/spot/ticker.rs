@@ -0,0 +1,174 @@
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{subscription::subscription_id, ExchangeId},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceSpot`](super::BinanceSpot) real-time rolling window ticker message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-rolling-window-statistics-streams>
+/// ```json
+/// {
+///     "e": "1hTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "c": "0.0025",
+///     "w": "0.0018",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 1675216573749,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(
+        rename = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price_change: f64,
+    #[serde(rename = "P", deserialize_with = "barter_integration::de::de_str")]
+    pub price_change_percent: f64,
+    #[serde(rename = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last: f64,
+    #[serde(rename = "w", deserialize_with = "barter_integration::de::de_str")]
+    pub weighted_avg_price: f64,
+    #[serde(rename = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(rename = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quote_volume: f64,
+    #[serde(
+        rename = "O",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub open_time: DateTime<Utc>,
+    #[serde(
+        rename = "C",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub close_time: DateTime<Utc>,
+    #[serde(rename = "n")]
+    pub trade_count: u64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        // Event type contains the rolling window (eg/ "1hTicker" => "@ticker_1h|BNBBTC")
+        let window = self.event_type.strip_suffix("Ticker")?;
+        Some(subscription_id(format!("@ticker_{window}"), &self.market))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BinanceTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                open_time: ticker.open_time,
+                close_time: ticker.close_time,
+                open: ticker.open,
+                high: ticker.high,
+                low: ticker.low,
+                last: ticker.last,
+                price_change: ticker.price_change,
+                price_change_percent: ticker.price_change_percent,
+                weighted_avg_price: ticker.weighted_avg_price,
+                volume: ticker.volume,
+                quote_volume: ticker.quote_volume,
+                trade_count: ticker.trade_count,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_ticker() {
+            let input = r#"
+            {
+                "e": "4hTicker",
+                "E": 1672515782136,
+                "s": "BNBBTC",
+                "p": "0.0015",
+                "P": "250.00",
+                "o": "0.0010",
+                "h": "0.0025",
+                "l": "0.0010",
+                "c": "0.0025",
+                "w": "0.0018",
+                "v": "10000",
+                "q": "18",
+                "O": 0,
+                "C": 1675216573749,
+                "F": 0,
+                "L": 18150,
+                "n": 18151
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BinanceTicker>(input).unwrap();
+
+            assert_eq!(
+                actual,
+                BinanceTicker {
+                    event_type: "4hTicker".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672515782136)),
+                    market: "BNBBTC".to_string(),
+                    price_change: 0.0015,
+                    price_change_percent: 250.0,
+                    open: 0.0010,
+                    high: 0.0025,
+                    low: 0.0010,
+                    last: 0.0025,
+                    weighted_avg_price: 0.0018,
+                    volume: 10000.0,
+                    quote_volume: 18.0,
+                    open_time: datetime_utc_from_epoch_duration(Duration::from_millis(0)),
+                    close_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1675216573749
+                    )),
+                    trade_count: 18151,
+                }
+            );
+            assert_eq!(actual.id(), Some(SubscriptionId::from("@ticker_4h|BNBBTC")));
+        }
+    }
+}
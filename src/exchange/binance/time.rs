@@ -0,0 +1,75 @@
+use super::Binance;
+use crate::{clock::ServerClock, exchange::ExchangeServer};
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`ExchangeServer`] that serves the [`Binance`] HTTP server time endpoint.
+pub trait BinanceTimeServer
+where
+    Self: ExchangeServer,
+{
+    /// HTTP url used to fetch the current server time.
+    fn server_time_url() -> &'static str;
+}
+
+/// [`Binance`] HTTP server time response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#check-server-time>
+/// ```json
+/// {
+///   "serverTime": 1499827319559
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceServerTime {
+    #[serde(
+        alias = "serverTime",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub server_time: DateTime<Utc>,
+}
+
+#[async_trait]
+impl<Server> ServerClock for Binance<Server>
+where
+    Server: BinanceTimeServer + Send + Sync,
+{
+    async fn server_time() -> Result<DateTime<Utc>, SocketError> {
+        reqwest::get(Server::server_time_url())
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<BinanceServerTime>()
+            .await
+            .map(|time| time.server_time)
+            .map_err(SocketError::Http)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_server_time() {
+            let input = r#"{"serverTime": 1499827319559}"#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceServerTime>(input).unwrap(),
+                BinanceServerTime {
+                    server_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1499827319559
+                    )),
+                }
+            );
+        }
+    }
+}
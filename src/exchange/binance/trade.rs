@@ -1,11 +1,11 @@
 use super::BinanceChannel;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{ExchangeId, ExchangeSub},
     subscription::trade::PublicTrade,
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -36,7 +36,7 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
-/// #### FuturePerpetual Side::Sell Trade
+/// #### Perpetual Side::Sell Trade
 /// ```json
 /// {
 ///     "e": "trade",
@@ -67,6 +67,12 @@ pub struct BinanceTrade {
     pub amount: f64,
     #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
     pub side: Side,
+    /// Only provided by [`BinanceSpot`](super::spot::BinanceSpot).
+    #[serde(alias = "b", default)]
+    pub buyer_order_id: Option<u64>,
+    /// Only provided by [`BinanceSpot`](super::spot::BinanceSpot).
+    #[serde(alias = "a", default)]
+    pub seller_order_id: Option<u64>,
 }
 
 impl Identifier<Option<SubscriptionId>> for BinanceTrade {
@@ -80,18 +86,114 @@ impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketIter<PublicTrade> {
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
-                id: trade.id.to_string(),
-                price: trade.price,
-                amount: trade.amount,
-                side: trade.side,
+                buyer_order_id: trade.buyer_order_id.map(|id| id.to_string()),
+                seller_order_id: trade.seller_order_id.map(|id| id.to_string()),
+                buyer_is_maker: Some(trade.side == Side::Sell),
+                ..PublicTrade::new(trade.id, trade.price, trade.amount, trade.side)
             },
         })])
     }
 }
 
+/// Binance real-time aggregated trade message, aggregating the fills of an aggressor order at
+/// the same price.
+///
+/// Note:
+/// For [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) only market trades are
+/// aggregated, so unlike the "@trade" stream, insurance fund & ADL trades are not included.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+/// #### Spot Side::Sell AggTrade
+/// ```json
+/// {
+///     "e": "aggTrade",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "a": 12345,
+///     "p": "0.001",
+///     "q": "100",
+///     "f": 100,
+///     "l": 105,
+///     "T": 1672515782136,
+///     "m": true,
+///     "M": true
+/// }
+/// ```
+///
+/// #### Perpetual Side::Buy AggTrade
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+/// ```json
+/// {
+///     "e": "aggTrade",
+///     "E": 123456789,
+///     "s": "BTCUSDT",
+///     "a": 5933014,
+///     "p": "0.001",
+///     "q": "100",
+///     "f": 100,
+///     "l": 105,
+///     "T": 123456785,
+///     "m": false
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAggTrade {
+    #[serde(alias = "s", deserialize_with = "de_agg_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "a")]
+    pub id: u64,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceAggTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceAggTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BinanceAggTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: PublicTrade {
+                buyer_is_maker: Some(trade.side == Side::Sell),
+                ..PublicTrade::new(trade.id, trade.price, trade.amount, trade.side)
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceAggTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@aggTrade|BTCUSDT").
+pub fn de_agg_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::AGG_TRADES, market)).id())
+}
+
 /// Deserialize a [`BinanceTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
 /// (eg/ "@trade|BTCUSDT").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -123,6 +225,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
 
     mod de {
         use super::*;
@@ -157,6 +260,8 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        buyer_order_id: Some(10108767791),
+                        seller_order_id: Some(10108764858),
                     }),
                 },
                 TestCase {
@@ -172,7 +277,7 @@ mod tests {
                     }),
                 },
                 TestCase {
-                    // TC2: FuturePerpetual trade w/ type MARKET
+                    // TC2: Perpetual trade w/ type MARKET
                     input: r#"
                     {
                         "e": "trade","E": 1649839266194,"T": 1749354825200,"s": "ETHUSDT",
@@ -188,10 +293,12 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Sell,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
                 TestCase {
-                    // TC3: FuturePerpetual trade w/ type LIQUIDATION
+                    // TC3: Perpetual trade w/ type LIQUIDATION
                     input: r#"
                     {
                         "e": "trade","E": 1649839266194,"T": 1749354825200,"s": "ETHUSDT",
@@ -207,10 +314,12 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
                 TestCase {
-                    // TC4: FuturePerpetual trade w/ type LIQUIDATION
+                    // TC4: Perpetual trade w/ type LIQUIDATION
                     input: r#"{
                         "e": "trade","E": 1649839266194,"T": 1749354825200,"s": "ETHUSDT",
                         "t": 1000000000,"p":"10000.19","q":"0.239000","X": "INSURANCE_FUND","m": false
@@ -224,6 +333,8 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
             ];
@@ -244,5 +355,111 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_binance_agg_trade() {
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceAggTrade,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Spot aggregated trade
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true
+                    }
+                    "#,
+                    expected: BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        id: 12345,
+                        price: 0.001,
+                        amount: 100.0,
+                        side: Side::Sell,
+                    },
+                },
+                TestCase {
+                    // TC1: Perpetual aggregated trade without "M" field
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":123456789,"s":"BTCUSDT","a":5933014,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":123456785,"m":false
+                    }
+                    "#,
+                    expected: BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(123456785)),
+                        id: 5933014,
+                        price: 0.001,
+                        amount: 100.0,
+                        side: Side::Buy,
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceAggTrade>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_trade_aggressor_side() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+        struct TestCase {
+            buyer_is_maker: bool,
+            expected_side: Side,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: buy maker order hit by a sell aggressor
+                buyer_is_maker: true,
+                expected_side: Side::Sell,
+            },
+            TestCase {
+                // TC1: sell maker order lifted by a buy aggressor
+                buyer_is_maker: false,
+                expected_side: Side::Buy,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let m = test.buyer_is_maker;
+            let trade = serde_json::from_str::<BinanceTrade>(&format!(
+                r#"{{"e":"trade","E":1,"s":"BTCUSDT","t":1,"p":"1.0","q":"1.0","T":1,"m":{m}}}"#
+            ))
+            .unwrap();
+            let agg_trade = serde_json::from_str::<BinanceAggTrade>(&format!(
+                r#"{{"e":"aggTrade","E":1,"s":"BTCUSDT","a":1,"p":"1.0","q":"1.0","f":1,"l":1,"T":1,"m":{m}}}"#
+            ))
+            .unwrap();
+
+            let trades = [
+                MarketIter::<PublicTrade>::from((
+                    ExchangeId::BinanceSpot,
+                    instrument.clone(),
+                    trade,
+                )),
+                MarketIter::<PublicTrade>::from((
+                    ExchangeId::BinanceSpot,
+                    instrument.clone(),
+                    agg_trade,
+                )),
+            ];
+
+            for MarketIter(events) in trades {
+                let trade = events.into_iter().next().unwrap().unwrap().kind;
+                assert_eq!(trade.side, test.expected_side, "TC{} failed", index);
+                assert_eq!(trade.buyer_is_maker, Some(m), "TC{} failed", index);
+            }
+        }
     }
 }
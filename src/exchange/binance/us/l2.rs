@@ -0,0 +1,78 @@
+use super::super::{
+    book::l2::init_partial_book,
+    spot::l2::{BinanceSpotBookUpdater, BinanceSpotOrderBookL2Update},
+};
+use crate::{
+    error::DataError,
+    subscription::book::OrderBook,
+    transformer::book::{BookUpdate, InstrumentOrderBook, OrderBookUpdater},
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::model::{instrument::Instrument, SubscriptionId};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceUs`](super::BinanceUs) HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://docs.binance.us/#get-order-book-depth>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/depth";
+
+/// [`Binance`](super::super::Binance) [`BinanceServerUs`](super::BinanceServerUs)
+/// [`OrderBookUpdater`].
+///
+/// Binance.US manages a local OrderBook with the same algorithm as
+/// [`BinanceSpot`](super::super::spot::BinanceSpot), so this delegates to the
+/// [`BinanceSpotBookUpdater`], fetching the initial snapshot from the Binance.US servers.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceUsBookUpdater(pub BinanceSpotBookUpdater);
+
+#[async_trait]
+impl OrderBookUpdater for BinanceUsBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BinanceSpotOrderBookL2Update;
+    const INIT_SNAPSHOT: bool = true;
+
+    async fn init<Exchange, Kind>(
+        _: OutboundTx,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        let InstrumentOrderBook {
+            instrument,
+            updater,
+            book,
+        } = BinanceSpotBookUpdater::init_from_snapshot_url(
+            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_US,
+            instrument,
+        )
+        .await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self(updater),
+            book,
+        })
+    }
+
+    fn init_partial(
+        subscription_id: &SubscriptionId,
+        instrument: &Instrument,
+    ) -> Option<InstrumentOrderBook<Self>> {
+        init_partial_book(
+            subscription_id,
+            instrument,
+            Self(BinanceSpotBookUpdater::new(0)),
+        )
+    }
+
+    fn apply(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<BookUpdate>, DataError> {
+        self.0.apply(book, update)
+    }
+}
@@ -0,0 +1,76 @@
+use self::l2::BinanceUsBookUpdater;
+use super::{
+    rest_trade::BinanceRecentTradesServer, spec::BinanceExchangeInfoServer,
+    time::BinanceTimeServer, Binance, ExchangeServer,
+};
+use crate::{
+    exchange::{ExchangeId, StreamSelector},
+    subscription::book::OrderBooksL2,
+    transformer::book::MultiBookTransformer,
+    ExchangeWsStream,
+};
+
+/// Level 2 OrderBook [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater)
+/// implementation for [`BinanceUs`].
+pub mod l2;
+
+/// [`BinanceUs`] WebSocket server base url.
+///
+/// See docs: <https://docs.binance.us/#websocket-streams>
+pub const WEBSOCKET_BASE_URL_BINANCE_US: &str = "wss://stream.binance.us:9443/ws";
+
+/// [`BinanceUs`] HTTP server time url.
+///
+/// See docs: <https://docs.binance.us/#check-server-time>
+pub const HTTP_SERVER_TIME_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/time";
+
+/// [`BinanceUs`] HTTP recent trades url.
+///
+/// See docs: <https://docs.binance.us/#recent-trades>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/trades";
+
+/// [`BinanceUs`] HTTP exchange information url.
+///
+/// See docs: <https://docs.binance.us/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/exchangeInfo";
+
+/// [`Binance`](super::Binance) US spot exchange.
+///
+/// Binance.US is a distinct exchange to [`BinanceSpot`](super::spot::BinanceSpot) with its own
+/// servers and symbol universe (eg/ many markets are quoted in "usd"), but it shares an identical
+/// API, so all of the [`BinanceSpot`](super::spot::BinanceSpot) transformers are re-used.
+pub type BinanceUs = Binance<BinanceServerUs>;
+
+/// [`Binance`](super::Binance) US [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceServerUs;
+
+impl ExchangeServer for BinanceServerUs {
+    const ID: ExchangeId = ExchangeId::BinanceUs;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BINANCE_US
+    }
+}
+
+impl BinanceTimeServer for BinanceServerUs {
+    fn server_time_url() -> &'static str {
+        HTTP_SERVER_TIME_URL_BINANCE_US
+    }
+}
+
+impl BinanceRecentTradesServer for BinanceServerUs {
+    fn recent_trades_url() -> &'static str {
+        HTTP_RECENT_TRADES_URL_BINANCE_US
+    }
+}
+
+impl BinanceExchangeInfoServer for BinanceServerUs {
+    fn exchange_info_url() -> &'static str {
+        HTTP_EXCHANGE_INFO_URL_BINANCE_US
+    }
+}
+
+impl StreamSelector<OrderBooksL2> for BinanceUs {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceUsBookUpdater>>;
+}
@@ -0,0 +1,973 @@
+use super::{channel::BinanceChannel, Binance};
+use crate::{
+    credentials::{Credentials, KeepAlive, SessionStatus},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{
+        subscription::ExchangeSub, ExchangeId, ExchangeServer, PrivateConnector, PrivateSession,
+    },
+    subscription::{
+        account::{Balance, ExecutionEvent, OrderStatus, Position},
+        trade::UserTrade,
+        Map,
+    },
+    transformer::{account::AccountMessage, balance::BalanceUpdate},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{symbol::Symbol, Instrument},
+        Exchange, Side, SubscriptionId,
+    },
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use url::Url;
+
+/// Interval at which a [`Binance`] user data stream listenKey is kept alive. A listenKey expires
+/// 60 minutes after it was last kept alive.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+pub const LISTEN_KEY_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// [`ExchangeServer`] that serves a [`Binance`] user data stream, authenticated with a listenKey.
+pub trait BinanceUserDataServer
+where
+    Self: ExchangeServer,
+{
+    /// HTTP url used to create a user data stream listenKey.
+    fn listen_key_url() -> &'static str;
+}
+
+#[async_trait]
+impl<Server> PrivateConnector for Binance<Server>
+where
+    Server: BinanceUserDataServer + Sync,
+{
+    /// Create a listenKey and connect to the user data stream it identifies, spawning a
+    /// [`KeepAlive`] task that keeps the listenKey alive every
+    /// [`LISTEN_KEY_KEEP_ALIVE_INTERVAL`].
+    ///
+    /// If the listenKey expires (eg/ a "listenKeyExpired" event, or a failed keep-alive), the
+    /// stream yields a terminal [`DataError::SessionExpired`](crate::error::DataError) and is
+    /// re-initialised with a new listenKey.
+    async fn authenticate(credentials: &Credentials) -> Result<PrivateSession, SocketError> {
+        let BinanceListenKey { listen_key } =
+            create_listen_key(Server::listen_key_url(), credentials).await?;
+
+        let url = Url::parse(&format!("{}/{listen_key}", Server::websocket_url()))
+            .map_err(SocketError::UrlParse)?;
+
+        let credentials = credentials.clone();
+        let keep_alive = KeepAlive::spawn(Server::ID, LISTEN_KEY_KEEP_ALIVE_INTERVAL, move || {
+            let credentials = credentials.clone();
+            let listen_key = listen_key.clone();
+            async move {
+                keep_alive_listen_key(Server::listen_key_url(), &credentials, &listen_key).await
+            }
+        });
+
+        Ok(PrivateSession {
+            url,
+            requests: vec![],
+            keep_alive: Some(keep_alive),
+        })
+    }
+
+    /// User data streams push every account event once connected, so there is nothing to
+    /// subscribe to.
+    fn private_requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        vec![]
+    }
+
+    fn private_expected_responses(_: &Map<Instrument>) -> usize {
+        0
+    }
+}
+
+/// [`Binance`] user data stream listenKey HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+/// ```json
+/// {
+///   "listenKey": "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceListenKey {
+    pub listen_key: String,
+}
+
+/// Create a new user data stream listenKey using the provided HTTP url.
+pub async fn create_listen_key(
+    url: &str,
+    credentials: &Credentials,
+) -> Result<BinanceListenKey, SocketError> {
+    reqwest::Client::new()
+        .post(url)
+        .header("X-MBX-APIKEY", &credentials.api_key)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(SocketError::Http)?
+        .json::<BinanceListenKey>()
+        .await
+        .map_err(SocketError::Http)
+}
+
+/// Keep the provided user data stream listenKey alive for another 60 minutes using the provided
+/// HTTP url, determining if the listenKey has [`SessionStatus::Expired`].
+pub async fn keep_alive_listen_key(
+    url: &str,
+    credentials: &Credentials,
+    listen_key: &str,
+) -> Result<SessionStatus, SocketError> {
+    let response = reqwest::Client::new()
+        .put(url)
+        .header("X-MBX-APIKEY", &credentials.api_key)
+        .query(&[("listenKey", listen_key)])
+        .send()
+        .await
+        .map_err(SocketError::Http)?;
+
+    // Binance responds with error code -1125 "This listenKey does not exist." once expired
+    if response.status() == reqwest::StatusCode::BAD_REQUEST {
+        return Ok(SessionStatus::Expired);
+    }
+
+    response
+        .error_for_status()
+        .map(|_| SessionStatus::Alive)
+        .map_err(SocketError::Http)
+}
+
+/// [`Binance`] user data stream event.
+///
+/// Events other than order updates (eg/ "outboundAccountPosition", "ACCOUNT_UPDATE") are
+/// deserialised as [`BinanceUserData::Other`] and ignored. See [`BinanceBalanceData`] and
+/// [`BinancePositionData`] for those.
+///
+/// A "listenKeyExpired" event means the stream must re-initialise with a new listenKey, see
+/// [`AccountMessage`].
+///
+/// ### Raw Payload Examples
+/// #### Spot Order Update
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#payload-order-update>
+/// ```json
+/// {
+///   "e": "executionReport",
+///   "E": 1499405658658,
+///   "s": "ETHBTC",
+///   "c": "mUvoqJxFIILMdfAW5iGSOW",
+///   "S": "BUY",
+///   "o": "LIMIT",
+///   "f": "GTC",
+///   "q": "1.00000000",
+///   "p": "0.10264410",
+///   "x": "TRADE",
+///   "X": "FILLED",
+///   "i": 4293153,
+///   "l": "1.00000000",
+///   "z": "1.00000000",
+///   "L": "0.10264410",
+///   "n": "0.00010264",
+///   "N": "BNB",
+///   "T": 1499405658657,
+///   "t": 1234,
+///   "m": false
+/// }
+/// ```
+///
+/// #### Futures Order Update
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#event-order-update>
+/// ```json
+/// {
+///   "e": "ORDER_TRADE_UPDATE",
+///   "E": 1568879465651,
+///   "T": 1568879465650,
+///   "o": {
+///     "s": "BTCUSDT",
+///     "c": "TEST",
+///     "S": "SELL",
+///     "o": "MARKET",
+///     "q": "0.001",
+///     "p": "0",
+///     "x": "TRADE",
+///     "X": "FILLED",
+///     "i": 8886774,
+///     "l": "0.001",
+///     "z": "0.001",
+///     "L": "7103.04",
+///     "N": "USDT",
+///     "n": "0.0028412",
+///     "T": 1568879465650,
+///     "t": 1357,
+///     "m": false
+///   }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceUserData {
+    #[serde(rename = "executionReport")]
+    ExecutionReport(BinanceOrderUpdate),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate {
+        #[serde(rename = "o")]
+        order: BinanceOrderUpdate,
+    },
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired,
+    #[serde(other)]
+    Other,
+}
+
+impl AccountMessage for BinanceUserData {
+    fn is_session_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired)
+    }
+}
+
+impl IntoIterator for BinanceUserData {
+    type Item = BinanceOrderUpdate;
+    type IntoIter = std::option::IntoIter<BinanceOrderUpdate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::ExecutionReport(order) | Self::OrderTradeUpdate { order } => Some(order),
+            Self::ListenKeyExpired | Self::Other => None,
+        }
+        .into_iter()
+    }
+}
+
+/// [`Binance`] order update common to both spot "executionReport" and futures
+/// "ORDER_TRADE_UPDATE" events.
+///
+/// See [`BinanceUserData`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "C", default)]
+    pub original_client_order_id: Option<String>,
+    #[serde(rename = "S")]
+    pub side: Side,
+    #[serde(rename = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "z", deserialize_with = "barter_integration::de::de_str")]
+    pub filled_quantity: f64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "L", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub last_quantity: f64,
+    #[serde(rename = "n", deserialize_with = "barter_integration::de::de_str")]
+    pub fee: f64,
+    #[serde(rename = "N", default)]
+    pub fee_asset: Option<String>,
+    #[serde(rename = "m")]
+    pub maker: bool,
+    #[serde(
+        rename = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl BinanceOrderUpdate {
+    /// [`Binance`] execution type of an order update that is a fill.
+    pub const EXECUTION_TYPE_TRADE: &'static str = "TRADE";
+
+    /// Determine the normalised [`OrderStatus`] of this [`BinanceOrderUpdate`], returning `None`
+    /// for transient statuses (eg/ "PENDING_CANCEL").
+    pub fn status(&self) -> Option<OrderStatus> {
+        match self.order_status.as_str() {
+            "NEW" => Some(OrderStatus::New),
+            "PARTIALLY_FILLED" => Some(OrderStatus::PartiallyFilled),
+            "FILLED" => Some(OrderStatus::Filled),
+            "CANCELED" => Some(OrderStatus::Cancelled),
+            "REJECTED" => Some(OrderStatus::Rejected),
+            "EXPIRED" | "EXPIRED_IN_MATCH" => Some(OrderStatus::Expired),
+            _ => None,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceOrderUpdate {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((BinanceChannel::USER_DATA, &self.symbol)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceOrderUpdate)> for MarketIter<ExecutionEvent> {
+    fn from(
+        (exchange_id, instrument, order): (ExchangeId, Instrument, BinanceOrderUpdate),
+    ) -> Self {
+        let Some(status) = order.status() else {
+            return Self(vec![]);
+        };
+
+        // Spot cancellations are sent with the original client order id in "C"
+        let client_order_id = order
+            .original_client_order_id
+            .filter(|original| !original.is_empty())
+            .unwrap_or(order.client_order_id);
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: order.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: ExecutionEvent {
+                order_id: order.order_id.to_string(),
+                client_order_id: Some(client_order_id),
+                side: order.side,
+                status,
+                price: order.price,
+                quantity: order.quantity,
+                filled_quantity: order.filled_quantity,
+            },
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceOrderUpdate)> for MarketIter<UserTrade> {
+    fn from(
+        (exchange_id, instrument, order): (ExchangeId, Instrument, BinanceOrderUpdate),
+    ) -> Self {
+        if order.execution_type != BinanceOrderUpdate::EXECUTION_TYPE_TRADE {
+            return Self(vec![]);
+        }
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: order.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: UserTrade {
+                id: order.trade_id.to_string(),
+                order_id: order.order_id.to_string(),
+                price: order.last_price,
+                amount: order.last_quantity,
+                side: order.side,
+                fee: order.fee,
+                fee_asset: order.fee_asset.map(Symbol::new),
+                maker: order.maker,
+            },
+        })])
+    }
+}
+
+/// [`Binance`] user data stream balance event, common to both spot "outboundAccountPosition"
+/// and futures "ACCOUNT_UPDATE" events.
+///
+/// ### Raw Payload Examples
+/// #### Spot Account Update
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#payload-account-update>
+/// ```json
+/// {
+///   "e": "outboundAccountPosition",
+///   "E": 1564034571105,
+///   "u": 1564034571073,
+///   "B": [
+///     {
+///       "a": "ETH",
+///       "f": "10000.000000",
+///       "l": "0.000000"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### Futures Account Update
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#event-balance-and-position-update>
+/// ```json
+/// {
+///   "e": "ACCOUNT_UPDATE",
+///   "E": 1564745798939,
+///   "T": 1564745798938,
+///   "a": {
+///     "m": "ORDER",
+///     "B": [
+///       {
+///         "a": "USDT",
+///         "wb": "122624.12345678",
+///         "cw": "100.12345678",
+///         "bc": "50.12345678"
+///       }
+///     ],
+///     "P": [
+///       {
+///         "s": "BTCUSDT",
+///         "pa": "-20",
+///         "ep": "6563.66500",
+///         "cr": "0",
+///         "up": "2850.21200",
+///         "mt": "isolated",
+///         "iw": "13200.70726908",
+///         "ps": "BOTH"
+///       }
+///     ]
+///   }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceBalanceData {
+    #[serde(rename = "outboundAccountPosition")]
+    AccountPosition(BinanceAccountPosition),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(BinanceAccountUpdate),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired,
+    #[serde(other)]
+    Other,
+}
+
+impl AccountMessage for BinanceBalanceData {
+    fn is_session_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired)
+    }
+}
+
+impl IntoIterator for BinanceBalanceData {
+    type Item = BalanceUpdate;
+    type IntoIter = std::vec::IntoIter<BalanceUpdate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::AccountPosition(account) => account
+                .balances
+                .into_iter()
+                .map(|balance| BalanceUpdate {
+                    time: account.time,
+                    balance: Balance {
+                        asset: Symbol::new(balance.asset),
+                        total: balance.free + balance.locked,
+                        free: balance.free,
+                    },
+                })
+                .collect::<Vec<_>>(),
+            // Futures balance updates do not include the available balance, so the cross wallet
+            // balance is used instead
+            Self::AccountUpdate(account) => account
+                .data
+                .balances
+                .into_iter()
+                .map(|balance| BalanceUpdate {
+                    time: account.time,
+                    balance: Balance {
+                        asset: Symbol::new(balance.asset),
+                        total: balance.wallet_balance,
+                        free: balance.cross_wallet_balance,
+                    },
+                })
+                .collect::<Vec<_>>(),
+            Self::ListenKeyExpired | Self::Other => vec![],
+        }
+        .into_iter()
+    }
+}
+
+/// [`Binance`] futures user data stream position event.
+///
+/// See [`BinanceBalanceData`] for full raw payload examples.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinancePositionData {
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(BinanceAccountUpdate),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired,
+    #[serde(other)]
+    Other,
+}
+
+impl AccountMessage for BinancePositionData {
+    fn is_session_expired(&self) -> bool {
+        matches!(self, Self::ListenKeyExpired)
+    }
+}
+
+impl IntoIterator for BinancePositionData {
+    type Item = BinancePositionUpdate;
+    type IntoIter = std::vec::IntoIter<BinancePositionUpdate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::AccountUpdate(account) => account
+                .data
+                .positions
+                .into_iter()
+                .map(|position| BinancePositionUpdate {
+                    time: account.time,
+                    position,
+                })
+                .collect::<Vec<_>>(),
+            Self::ListenKeyExpired | Self::Other => vec![],
+        }
+        .into_iter()
+    }
+}
+
+/// [`Binance`] spot "outboundAccountPosition" event, containing every asset balance that changed.
+///
+/// See [`BinanceBalanceData`] for full raw payload examples.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountPosition {
+    #[serde(
+        rename = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceSpotBalance>,
+}
+
+/// [`Binance`] spot asset balance.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceSpotBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "f", deserialize_with = "barter_integration::de::de_str")]
+    pub free: f64,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub locked: f64,
+}
+
+/// [`Binance`] futures "ACCOUNT_UPDATE" event, containing every asset balance and position that
+/// changed.
+///
+/// See [`BinanceBalanceData`] for full raw payload examples.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountUpdate {
+    #[serde(
+        rename = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "a")]
+    pub data: BinanceAccountUpdateData,
+}
+
+/// [`Binance`] futures "ACCOUNT_UPDATE" event data.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountUpdateData {
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceFuturesBalance>,
+    #[serde(rename = "P")]
+    pub positions: Vec<BinanceFuturesPosition>,
+}
+
+/// [`Binance`] futures asset balance.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "wb", deserialize_with = "barter_integration::de::de_str")]
+    pub wallet_balance: f64,
+    #[serde(rename = "cw", deserialize_with = "barter_integration::de::de_str")]
+    pub cross_wallet_balance: f64,
+}
+
+/// [`Binance`] futures position.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(rename = "ep", deserialize_with = "barter_integration::de::de_str")]
+    pub entry_price: f64,
+    #[serde(rename = "up", deserialize_with = "barter_integration::de::de_str")]
+    pub unrealised_pnl: f64,
+}
+
+/// [`BinanceFuturesPosition`] with the time of the "ACCOUNT_UPDATE" event that contained it.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct BinancePositionUpdate {
+    pub time: DateTime<Utc>,
+    pub position: BinanceFuturesPosition,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinancePositionUpdate {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((BinanceChannel::USER_DATA, &self.position.symbol)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinancePositionUpdate)> for MarketIter<Position> {
+    fn from(
+        (exchange_id, instrument, update): (ExchangeId, Instrument, BinancePositionUpdate),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: update.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Position {
+                quantity: update.position.amount,
+                entry_price: update.position.entry_price,
+                unrealised_pnl: update.position.unrealised_pnl,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_user_data() {
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceUserData,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: spot executionReport fill
+                    input: r#"
+                    {
+                        "e": "executionReport", "E": 1499405658658, "s": "ETHBTC",
+                        "c": "mUvoqJxFIILMdfAW5iGSOW", "S": "BUY", "o": "LIMIT", "f": "GTC",
+                        "q": "1.00000000", "p": "0.10264410", "P": "0.00000000",
+                        "F": "0.00000000", "g": -1, "C": "", "x": "TRADE", "X": "FILLED",
+                        "r": "NONE", "i": 4293153, "l": "1.00000000", "z": "1.00000000",
+                        "L": "0.10264410", "n": "0.00010264", "N": "BNB", "T": 1499405658657,
+                        "t": 1234, "I": 8641984, "w": false, "m": false, "M": true,
+                        "O": 1499405658657, "Z": "0.10264410", "Y": "0.10264410",
+                        "Q": "0.00000000"
+                    }
+                    "#,
+                    expected: BinanceUserData::ExecutionReport(BinanceOrderUpdate {
+                        symbol: "ETHBTC".to_string(),
+                        client_order_id: "mUvoqJxFIILMdfAW5iGSOW".to_string(),
+                        original_client_order_id: Some("".to_string()),
+                        side: Side::Buy,
+                        price: 0.10264410,
+                        quantity: 1.0,
+                        execution_type: "TRADE".to_string(),
+                        order_status: "FILLED".to_string(),
+                        order_id: 4293153,
+                        filled_quantity: 1.0,
+                        trade_id: 1234,
+                        last_price: 0.10264410,
+                        last_quantity: 1.0,
+                        fee: 0.00010264,
+                        fee_asset: Some("BNB".to_string()),
+                        maker: false,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1499405658657,
+                        )),
+                    }),
+                },
+                TestCase {
+                    // TC1: futures ORDER_TRADE_UPDATE new order w/o commission asset
+                    input: r#"
+                    {
+                        "e": "ORDER_TRADE_UPDATE", "E": 1568879465651, "T": 1568879465650,
+                        "o": {
+                            "s": "BTCUSDT", "c": "TEST", "S": "SELL", "o": "LIMIT",
+                            "f": "GTC", "q": "0.001", "p": "7103.04", "ap": "0", "sp": "0",
+                            "x": "NEW", "X": "NEW", "i": 8886774, "l": "0", "z": "0",
+                            "L": "0", "n": "0", "T": 1568879465650, "t": 0, "b": "0",
+                            "a": "9.91", "m": false, "R": false, "wt": "CONTRACT_PRICE",
+                            "ot": "LIMIT", "ps": "LONG", "cp": false, "rp": "0"
+                        }
+                    }
+                    "#,
+                    expected: BinanceUserData::OrderTradeUpdate {
+                        order: BinanceOrderUpdate {
+                            symbol: "BTCUSDT".to_string(),
+                            client_order_id: "TEST".to_string(),
+                            original_client_order_id: None,
+                            side: Side::Sell,
+                            price: 7103.04,
+                            quantity: 0.001,
+                            execution_type: "NEW".to_string(),
+                            order_status: "NEW".to_string(),
+                            order_id: 8886774,
+                            filled_quantity: 0.0,
+                            trade_id: 0,
+                            last_price: 0.0,
+                            last_quantity: 0.0,
+                            fee: 0.0,
+                            fee_asset: None,
+                            maker: false,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1568879465650,
+                            )),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC2: other user data event is ignored
+                    input: r#"
+                    {
+                        "e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+                        "B": [{"a": "ETH", "f": "10000.000000", "l": "0.000000"}]
+                    }
+                    "#,
+                    expected: BinanceUserData::Other,
+                },
+                TestCase {
+                    // TC3: listenKey expired
+                    input: r#"
+                    {
+                        "e": "listenKeyExpired", "E": 1576653824250,
+                        "listenKey": "WsCMN0a4KHUPTQuX6IUnqEZfB1inxmv1qR4kbf1LuEjur5VdbzqvyxqG9TSjVVxv"
+                    }
+                    "#,
+                    expected: BinanceUserData::ListenKeyExpired,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceUserData>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_binance_balance_data() {
+            struct TestCase {
+                input: &'static str,
+                expected: Vec<BalanceUpdate>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: spot account update
+                    input: r#"
+                    {
+                        "e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+                        "B": [{"a": "ETH", "f": "10000.000000", "l": "5.000000"}]
+                    }
+                    "#,
+                    expected: vec![BalanceUpdate {
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1564034571105,
+                        )),
+                        balance: Balance {
+                            asset: Symbol::new("eth"),
+                            total: 10005.0,
+                            free: 10000.0,
+                        },
+                    }],
+                },
+                TestCase {
+                    // TC1: futures account update
+                    input: r#"
+                    {
+                        "e": "ACCOUNT_UPDATE", "E": 1564745798939, "T": 1564745798938,
+                        "a": {
+                            "m": "ORDER",
+                            "B": [
+                                {"a": "USDT", "wb": "122624.12345678", "cw": "100.12345678",
+                                "bc": "50.12345678"}
+                            ],
+                            "P": [
+                                {"s": "BTCUSDT", "pa": "-20", "ep": "6563.66500", "cr": "0",
+                                "up": "2850.21200", "mt": "isolated", "iw": "13200.70726908",
+                                "ps": "BOTH"}
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: vec![BalanceUpdate {
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1564745798939,
+                        )),
+                        balance: Balance {
+                            asset: Symbol::new("usdt"),
+                            total: 122624.12345678,
+                            free: 100.12345678,
+                        },
+                    }],
+                },
+                TestCase {
+                    // TC2: order update is ignored
+                    input: r#"{"e": "executionReport", "E": 1499405658658, "s": "ETHBTC"}"#,
+                    expected: vec![],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceBalanceData>(test.input)
+                    .unwrap()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_position_update_market_iter() {
+        let update = BinancePositionUpdate {
+            time: Utc::now(),
+            position: BinanceFuturesPosition {
+                symbol: "BTCUSDT".to_string(),
+                amount: -20.0,
+                entry_price: 6563.665,
+                unrealised_pnl: 2850.212,
+            },
+        };
+        assert_eq!(update.id(), Some(SubscriptionId::from("@userData|BTCUSDT")));
+
+        let actual = MarketIter::<Position>::from((
+            ExchangeId::BinanceFuturesUsd,
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            update,
+        ))
+        .0
+        .into_iter()
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![Position {
+                quantity: -20.0,
+                entry_price: 6563.665,
+                unrealised_pnl: 2850.212,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_binance_order_update_market_iter() {
+        struct TestCase {
+            execution_type: &'static str,
+            order_status: &'static str,
+            expected_status: Option<OrderStatus>,
+            expected_user_trades: usize,
+        }
+
+        let order = |test: &TestCase| BinanceOrderUpdate {
+            symbol: "ETHBTC".to_string(),
+            client_order_id: "web_cancel".to_string(),
+            original_client_order_id: Some("mUvoqJxFIILMdfAW5iGSOW".to_string()),
+            side: Side::Buy,
+            price: 0.10264410,
+            quantity: 1.0,
+            execution_type: test.execution_type.to_string(),
+            order_status: test.order_status.to_string(),
+            order_id: 4293153,
+            filled_quantity: 0.5,
+            trade_id: 1234,
+            last_price: 0.10264410,
+            last_quantity: 0.5,
+            fee: 0.00010264,
+            fee_asset: Some("BNB".to_string()),
+            maker: false,
+            time: Utc::now(),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: new order is an ExecutionEvent, but not a UserTrade
+                execution_type: "NEW",
+                order_status: "NEW",
+                expected_status: Some(OrderStatus::New),
+                expected_user_trades: 0,
+            },
+            TestCase {
+                // TC1: partial fill is both an ExecutionEvent and a UserTrade
+                execution_type: "TRADE",
+                order_status: "PARTIALLY_FILLED",
+                expected_status: Some(OrderStatus::PartiallyFilled),
+                expected_user_trades: 1,
+            },
+            TestCase {
+                // TC2: cancellation
+                execution_type: "CANCELED",
+                order_status: "CANCELED",
+                expected_status: Some(OrderStatus::Cancelled),
+                expected_user_trades: 0,
+            },
+            TestCase {
+                // TC3: transient status is ignored
+                execution_type: "PENDING_CANCEL",
+                order_status: "PENDING_CANCEL",
+                expected_status: None,
+                expected_user_trades: 0,
+            },
+        ];
+
+        let instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        for (index, test) in tests.into_iter().enumerate() {
+            let order = order(&test);
+            assert_eq!(
+                order.id(),
+                Some(SubscriptionId::from("@userData|ETHBTC")),
+                "TC{} failed",
+                index
+            );
+
+            let events = MarketIter::<ExecutionEvent>::from((
+                ExchangeId::BinanceSpot,
+                instrument.clone(),
+                order.clone(),
+            ))
+            .0
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+            assert_eq!(
+                events.first().map(|event| event.status),
+                test.expected_status,
+                "TC{} failed",
+                index
+            );
+            if let Some(event) = events.first() {
+                assert_eq!(
+                    event.client_order_id.as_deref(),
+                    Some("mUvoqJxFIILMdfAW5iGSOW"),
+                    "TC{} failed",
+                    index
+                );
+            }
+
+            let user_trades =
+                MarketIter::<UserTrade>::from((ExchangeId::BinanceSpot, instrument.clone(), order));
+            assert_eq!(
+                user_trades.0.len(),
+                test.expected_user_trades,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        lending::{LendingBooks, LendingTrades},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +21,12 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time OrderBook channel, subscribed to with a precision of "P0" (ie/
+    /// aggregated by price, or by rate & period for margin funding).
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+    pub const BOOK: Self = Self("book");
 }
 
 impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
@@ -25,6 +35,18 @@ impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
     }
 }
 
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, LendingTrades> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::TRADES
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, LendingBooks> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::BOOK
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,546 @@
+use super::{market::BitfinexMarket, Bitfinex, HTTP_BOOK_URL_BITFINEX};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{Connector, ExchangeId},
+    subscription::{
+        lending::{LendingBook, LendingBooks, LendingLevel, LendingTrade},
+        trade::TradeId,
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, Side, SubscriptionId},
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Number of [`Bitfinex`] margin funding book levels requested per side.
+pub const FUNDING_BOOK_DEPTH: u16 = 25;
+
+/// [`Bitfinex`] real-time margin funding trade message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+/// #### Funding Trade
+/// Format: \[CHANNEL_ID, "fte", \[ID, MTS, AMOUNT, RATE, PERIOD\]\], <br> where +/- of amount
+/// indicates if the taker borrowed or lent.
+/// ```json
+/// [337371,"fte",[133323543,1574694605000,-59.84,0.00023647,2]]
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// [337371,"hb"]
+/// ```
+///
+/// ## Notes:
+/// - As per [`BitfinexTrade`](super::trade::BitfinexTrade)s, tag="ftu" trades are identical to
+///   the faster tag="fte" trades, so are considered only as additional Heartbeats.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexFundingTradeMessage {
+    pub channel_id: u32,
+    pub trade: Option<BitfinexFundingTrade>,
+}
+
+/// [`Bitfinex`] margin funding trade.
+///
+/// See [`BitfinexFundingTradeMessage`] for full raw payload examples.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexFundingTrade {
+    pub id: u64,
+    pub time: DateTime<Utc>,
+    pub side: Side,
+    pub amount: f64,
+    pub rate: f64,
+    pub period_days: u32,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexFundingTradeMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.trade
+            .map(|_| SubscriptionId::from(self.channel_id.to_string()))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitfinexFundingTradeMessage)> for MarketIter<LendingTrade> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BitfinexFundingTradeMessage),
+    ) -> Self {
+        let Some(trade) = message.trade else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: LendingTrade {
+                id: TradeId::from(trade.id),
+                rate: trade.rate,
+                period_days: trade.period_days,
+                amount: trade.amount,
+                side: trade.side,
+            },
+        })])
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexFundingTradeMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexFundingTradeMessage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexFundingTradeMessage from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Funding Trade: [CHANNEL_ID, <"fte", "ftu">, [ID, MTS, AMOUNT, RATE, PERIOD]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+                let message_tag: String = extract_next(&mut seq, "message_tag")?;
+
+                let trade = match message_tag.as_str() {
+                    "hb" | "ftu" => None,
+                    "fte" => {
+                        let (id, time_millis, amount, rate, period_days): (
+                            u64,
+                            u64,
+                            f64,
+                            f64,
+                            u32,
+                        ) = extract_next(&mut seq, "BitfinexFundingTrade")?;
+
+                        Some(BitfinexFundingTrade {
+                            id,
+                            time: datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_millis(time_millis),
+                            ),
+                            side: match amount.is_sign_positive() {
+                                true => Side::Buy,
+                                false => Side::Sell,
+                            },
+                            amount: amount.abs(),
+                            rate,
+                            period_days,
+                        })
+                    }
+                    other => {
+                        return Err(serde::de::Error::unknown_variant(
+                            other,
+                            &["heartbeat (hb)", "funding trade (fte | ftu)"],
+                        ))
+                    }
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexFundingTradeMessage { channel_id, trade })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexFundingTradeMessage
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// [`Bitfinex`] real-time margin funding book message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// #### Snapshot
+/// Format: \[CHANNEL_ID, \[\[RATE, PERIOD, COUNT, AMOUNT\], ...\]\]
+/// ```json
+/// [338225,[[0.0002,2,5,-1500.5],[0.00025,30,1,250]]]
+/// ```
+///
+/// #### Update
+/// Format: \[CHANNEL_ID, \[RATE, PERIOD, COUNT, AMOUNT\]\], <br> where a COUNT of 0 removes the
+/// level.
+/// ```json
+/// [338225,[0.0002,2,0,-1]]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexFundingBookMessage {
+    pub channel_id: u32,
+    pub payload: BitfinexFundingBookPayload,
+}
+
+/// [`Bitfinex`] margin funding book variants.
+///
+/// See [`BitfinexFundingBookMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitfinexFundingBookPayload {
+    Heartbeat(String),
+    Update(BitfinexFundingLevel),
+    Snapshot(Vec<BitfinexFundingLevel>),
+}
+
+/// [`Bitfinex`] margin funding book level.
+///
+/// Unlike trading books, a positive `AMOUNT` is a lender offer (ask), and a negative `AMOUNT`
+/// is a borrower bid.
+///
+/// #### Raw Payload Examples
+/// Format: \[RATE, PERIOD, COUNT, AMOUNT\]
+/// ```json
+/// [0.0002,2,5,-1500.5]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexFundingLevel(pub f64, pub u32, pub u32, pub f64);
+
+impl BitfinexFundingLevel {
+    /// [`LendingBook`] [`Side`] of this level.
+    pub fn side(&self) -> Side {
+        match self.3.is_sign_positive() {
+            true => Side::Sell,
+            false => Side::Buy,
+        }
+    }
+}
+
+impl From<BitfinexFundingLevel> for LendingLevel {
+    fn from(BitfinexFundingLevel(rate, period_days, count, amount): BitfinexFundingLevel) -> Self {
+        Self {
+            rate,
+            period_days,
+            // Levels w/ a count of zero are removed
+            amount: if count == 0 { 0.0 } else { amount.abs() },
+            count,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BitfinexFundingBookMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexFundingBookMessage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexFundingBookMessage from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[RATE, PERIOD, COUNT, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [RATE, PERIOD, COUNT, AMOUNT]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+                let payload = extract_next(&mut seq, "payload")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexFundingBookMessage {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexFundingBookMessage
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// [`LendingBook`] of a [`Bitfinex`] margin funding currency [`Instrument`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct InstrumentLendingBook {
+    pub instrument: Instrument,
+    pub book: LendingBook,
+}
+
+/// [`Bitfinex`] [`LendingBooks`] [`ExchangeTransformer`] that maintains the local [`LendingBook`]
+/// of each margin funding currency.
+///
+/// The WebSocket snapshot sent after subscribing is consumed during subscription validation, so
+/// each [`LendingBook`] is initialised from a HTTP snapshot. Public Bitfinex books are not
+/// sequenced, so this is best effort: levels updated between the subscription and the snapshot
+/// are corrected by their next update. Any subsequent WebSocket snapshot replaces the book.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BitfinexFundingBookTransformer {
+    pub book_map: Map<InstrumentLendingBook>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Bitfinex, LendingBooks> for BitfinexFundingBookTransformer {
+    async fn new(_: OutboundTx, map: Map<Instrument>) -> Result<Self, DataError> {
+        let snapshots = map
+            .0
+            .into_iter()
+            .map(|(subscription_id, instrument)| async move {
+                let levels = fetch_funding_book(&instrument).await?;
+                let mut book = LendingBook {
+                    last_update_time: Utc::now(),
+                    ..LendingBook::default()
+                };
+                levels
+                    .into_iter()
+                    .for_each(|level| book.upsert(level.side(), LendingLevel::from(level)));
+                book.sort();
+
+                Ok::<_, DataError>((subscription_id, InstrumentLendingBook { instrument, book }))
+            });
+
+        let book_map = futures::future::try_join_all(snapshots)
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(Self { book_map })
+    }
+
+    fn initial_events(
+        &mut self,
+        instruments: &[Instrument],
+    ) -> Vec<Result<MarketEvent<LendingBook>, DataError>> {
+        self.book_map
+            .0
+            .values()
+            .filter(|book| instruments.contains(&book.instrument))
+            .map(|book| Ok(lending_book_event(book)))
+            .collect()
+    }
+}
+
+impl Transformer for BitfinexFundingBookTransformer {
+    type Error = DataError;
+    type Input = BitfinexFundingBookMessage;
+    type Output = MarketEvent<LendingBook>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let subscription_id = SubscriptionId::from(input.channel_id.to_string());
+
+        let levels = match input.payload {
+            BitfinexFundingBookPayload::Heartbeat(_) => return vec![],
+            BitfinexFundingBookPayload::Update(level) => vec![level],
+            BitfinexFundingBookPayload::Snapshot(levels) => levels,
+        };
+
+        let book = match self.book_map.find_mut(&subscription_id) {
+            Ok(book) => book,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        if levels.len() > 1 {
+            book.book.bids.clear();
+            book.book.asks.clear();
+        }
+        levels
+            .into_iter()
+            .for_each(|level| book.book.upsert(level.side(), LendingLevel::from(level)));
+        book.book.sort();
+        book.book.last_update_time = Utc::now();
+
+        vec![Ok(lending_book_event(book))]
+    }
+}
+
+/// Construct a [`MarketEvent<LendingBook>`](MarketEvent) from the [`InstrumentLendingBook`].
+fn lending_book_event(book: &InstrumentLendingBook) -> MarketEvent<LendingBook> {
+    MarketEvent {
+        exchange_time: book.book.last_update_time,
+        received_time: Utc::now(),
+        received_monotonic: MonotonicTime::now(),
+        origin: Origin::Live,
+        exchange: Exchange::from(Bitfinex::ID),
+        instrument: book.instrument.clone(),
+        kind: book.book.clone(),
+    }
+}
+
+/// Fetch the HTTP margin funding book snapshot of the provided [`Instrument`] base currency.
+async fn fetch_funding_book(
+    instrument: &Instrument,
+) -> Result<Vec<BitfinexFundingLevel>, SocketError> {
+    let market = BitfinexMarket::funding(instrument);
+
+    reqwest::Client::new()
+        .get(format!("{HTTP_BOOK_URL_BITFINEX}/{}/P0", market.as_ref()))
+        .query(&[("len", FUNDING_BOOK_DEPTH)])
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(SocketError::Http)?
+        .json::<Vec<BitfinexFundingLevel>>()
+        .await
+        .map_err(SocketError::Http)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_de_bitfinex_funding_trade_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<BitfinexFundingTradeMessage>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: funding trade where the taker lent
+                    input: r#"[337371,"fte",[133323543,1574694605000,-59.84,0.00023647,2]]"#,
+                    expected: Some(BitfinexFundingTradeMessage {
+                        channel_id: 337371,
+                        trade: Some(BitfinexFundingTrade {
+                            id: 133323543,
+                            time: datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_millis(1574694605000),
+                            ),
+                            side: Side::Sell,
+                            amount: 59.84,
+                            rate: 0.00023647,
+                            period_days: 2,
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC1: duplicate "ftu" funding trade is a heartbeat
+                    input: r#"[337371,"ftu",[133323543,1574694605000,-59.84,0.00023647,2]]"#,
+                    expected: Some(BitfinexFundingTradeMessage {
+                        channel_id: 337371,
+                        trade: None,
+                    }),
+                },
+                TestCase {
+                    // TC2: unknown message tag
+                    input: r#"[337371,"te",[1225484398,1665452200022,0.08980641,19027.02]]"#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexFundingTradeMessage>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_de_bitfinex_funding_book_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitfinexFundingBookPayload,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: snapshot
+                    input: r#"[338225,[[0.0002,2,5,-1500.5],[0.00025,30,1,250]]]"#,
+                    expected: BitfinexFundingBookPayload::Snapshot(vec![
+                        BitfinexFundingLevel(0.0002, 2, 5, -1500.5),
+                        BitfinexFundingLevel(0.00025, 30, 1, 250.0),
+                    ]),
+                },
+                TestCase {
+                    // TC1: level removal update
+                    input: r#"[338225,[0.0002,2,0,-1]]"#,
+                    expected: BitfinexFundingBookPayload::Update(BitfinexFundingLevel(
+                        0.0002, 2, 0, -1.0,
+                    )),
+                },
+                TestCase {
+                    // TC2: heartbeat
+                    input: r#"[338225,"hb"]"#,
+                    expected: BitfinexFundingBookPayload::Heartbeat("hb".to_owned()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexFundingBookMessage>(test.input)
+                    .unwrap()
+                    .payload;
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_funding_book_transformer() {
+        let instrument = Instrument::from(("usd", "usd", InstrumentKind::Spot));
+        let mut transformer = BitfinexFundingBookTransformer {
+            book_map: Map::from_iter([(
+                SubscriptionId::from("338225"),
+                InstrumentLendingBook {
+                    instrument,
+                    book: LendingBook::default(),
+                },
+            )]),
+        };
+        let update = |payload| BitfinexFundingBookMessage {
+            channel_id: 338225,
+            payload,
+        };
+
+        // Snapshot replaces the book, w/ negative amounts as borrower bids
+        let book = transformer
+            .transform(update(BitfinexFundingBookPayload::Snapshot(vec![
+                BitfinexFundingLevel(0.0002, 2, 5, -1500.5),
+                BitfinexFundingLevel(0.00025, 30, 1, 250.0),
+            ])))
+            .remove(0)
+            .unwrap()
+            .kind;
+        assert_eq!(book.best_bid_rate(), Some(0.0002));
+        assert_eq!(book.best_ask_rate(), Some(0.00025));
+
+        // Update w/ a zero count removes the level
+        let book = transformer
+            .transform(update(BitfinexFundingBookPayload::Update(
+                BitfinexFundingLevel(0.0002, 2, 0, -1.0),
+            )))
+            .remove(0)
+            .unwrap()
+            .kind;
+        assert!(book.bids.is_empty());
+        assert_eq!(book.asks.len(), 1);
+
+        // Heartbeat yields nothing
+        assert!(transformer
+            .transform(update(BitfinexFundingBookPayload::Heartbeat(
+                "hb".to_owned()
+            )))
+            .is_empty());
+    }
+}
@@ -1,22 +1,59 @@
 use super::Bitfinex;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    subscription::{
+        lending::{LendingBooks, LendingTrades},
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use barter_integration::model::instrument::Instrument;
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Bitfinex`](super::Bitfinex) market that can be subscribed to.
 ///
+/// Trading pairs are prefixed with "t" (eg/ "tBTCUSD"), and margin funding currencies are
+/// prefixed with "f" (eg/ "fUSD").
+///
 /// See docs: <https://docs.bitfinex.com/docs/ws-public>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexMarket(pub String);
 
-impl<Kind> Identifier<BitfinexMarket> for Subscription<Bitfinex, Kind> {
-    fn id(&self) -> BitfinexMarket {
-        BitfinexMarket(format!(
+impl BitfinexMarket {
+    /// Construct the [`BitfinexMarket`] trading pair of the provided [`Instrument`]
+    /// (eg/ "tBTCUSD").
+    pub fn trading(instrument: &Instrument) -> Self {
+        Self(format!(
             "t{}{}",
-            self.instrument.base.to_string().to_uppercase(),
-            self.instrument.quote.to_string().to_uppercase()
+            instrument.base.to_string().to_uppercase(),
+            instrument.quote.to_string().to_uppercase()
         ))
     }
+
+    /// Construct the [`BitfinexMarket`] margin funding currency of the provided [`Instrument`]
+    /// base (eg/ "fUSD").
+    pub fn funding(instrument: &Instrument) -> Self {
+        Self(format!("f{}", instrument.base.to_string().to_uppercase()))
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, PublicTrades> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::trading(&self.instrument)
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, LendingTrades> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::funding(&self.instrument)
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, LendingBooks> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::funding(&self.instrument)
+    }
 }
 
 impl AsRef<str> for BitfinexMarket {
@@ -4,7 +4,7 @@ use crate::{
 };
 use barter_integration::{
     de::extract_next,
-    model::{Instrument, SubscriptionId},
+    model::{instrument::Instrument, SubscriptionId},
 };
 use serde::Serialize;
 
@@ -20,13 +20,20 @@
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    channel::BitfinexChannel,
+    funding::{BitfinexFundingBookTransformer, BitfinexFundingTradeMessage, FUNDING_BOOK_DEPTH},
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    subscription::BitfinexPlatformEvent,
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector, SubscriptionLimits},
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
+    subscription::{
+        lending::{LendingBooks, LendingTrades},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
@@ -35,10 +42,18 @@ use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Bitfinex error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Margin funding (lending) trade & book types, and the
+/// [`LendingBooks`](crate::subscription::lending::LendingBooks) transformer for [`Bitfinex`].
+pub mod funding;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -62,6 +77,16 @@ pub mod validator;
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
 pub const BASE_URL_BITFINEX: &str = "wss://api-pub.bitfinex.com/ws/2";
 
+/// [`Bitfinex`] HTTP book snapshot url.
+///
+/// See docs: <https://docs.bitfinex.com/reference/rest-public-book>
+pub const HTTP_BOOK_URL_BITFINEX: &str = "https://api-pub.bitfinex.com/v2/book";
+
+/// [`Bitfinex`] maximum number of public channel subscriptions per connection.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#how-to-connect>
+pub const MAX_SUBSCRIPTIONS_PER_CONNECTION_BITFINEX: usize = 25;
+
 /// [`Bitfinex`] exchange.
 ///
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
@@ -86,19 +111,40 @@ impl Connector for Bitfinex {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
-                        "event": "subscribe",
-                        "channel": channel.as_ref(),
-                        "symbol": market.as_ref(),
-                    })
-                    .to_string(),
-                )
+                let mut request = json!({
+                    "event": "subscribe",
+                    "channel": channel.as_ref(),
+                    "symbol": market.as_ref(),
+                });
+
+                // Raw precision is not available for funding books, so request aggregated levels
+                if channel == BitfinexChannel::BOOK {
+                    request["prec"] = json!("P0");
+                    request["len"] = json!(FUNDING_BOOK_DEPTH.to_string());
+                }
+
+                WsMessage::Text(request.to_string())
             })
             .collect()
     }
+
+    fn subscription_limits() -> SubscriptionLimits {
+        SubscriptionLimits {
+            streams_per_connection: Some(MAX_SUBSCRIPTIONS_PER_CONNECTION_BITFINEX),
+            ..SubscriptionLimits::UNLIMITED
+        }
+    }
 }
 
 impl StreamSelector<PublicTrades> for Bitfinex {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BitfinexMessage>>;
 }
+
+impl StreamSelector<LendingTrades> for Bitfinex {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, LendingTrades, BitfinexFundingTradeMessage>>;
+}
+
+impl StreamSelector<LendingBooks> for Bitfinex {
+    type Stream = ExchangeWsStream<BitfinexFundingBookTransformer>;
+}
@@ -0,0 +1,105 @@
+use super::Bitfinex;
+use crate::notice::{Notice, NoticeKind, NoticeParser};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`] WebSocket error or info event.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#info-messages>
+/// #### Error
+/// ```json
+/// {"event": "error", "msg": "subscribe: limit", "code": 10305}
+/// ```
+///
+/// #### Info
+/// ```json
+/// {"event": "info", "code": 20051, "msg": "Stopping. Please try to reconnect"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum BitfinexNotice {
+    Error {
+        code: u32,
+        msg: String,
+    },
+    Info {
+        #[serde(default)]
+        code: Option<u32>,
+        #[serde(default)]
+        msg: Option<String>,
+    },
+}
+
+impl NoticeParser for Bitfinex {
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let (code, message) = match serde_json::from_str::<BitfinexNotice>(payload).ok()? {
+            BitfinexNotice::Error { code, msg } => (code, msg),
+            // Info events without a code (eg/ the version event on connect) are not notices
+            BitfinexNotice::Info {
+                code: Some(code),
+                msg,
+            } => (code, msg.unwrap_or_default()),
+            BitfinexNotice::Info { code: None, .. } => return None,
+        };
+
+        let kind = match code {
+            10305 => NoticeKind::RateLimit,
+            10100 => NoticeKind::Unauthorised,
+            10000..=10399 => NoticeKind::InvalidRequest,
+            20051 => NoticeKind::Reconnect,
+            20060 => NoticeKind::Maintenance,
+            _ => NoticeKind::Other,
+        };
+
+        Some(Notice::new(kind, Some(code), message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitfinex_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: channel limit error
+            TestCase {
+                input: r#"{"event": "error", "msg": "subscribe: limit", "code": 10305}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::RateLimit,
+                    Some(10305),
+                    "subscribe: limit",
+                )),
+            },
+            // TC1: server restart info
+            TestCase {
+                input: r#"{"event": "info", "code": 20051, "msg": "Stopping. Please try to reconnect"}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::Reconnect,
+                    Some(20051),
+                    "Stopping. Please try to reconnect",
+                )),
+            },
+            // TC2: maintenance start info
+            TestCase {
+                input: r#"{"event": "info", "code": 20060}"#,
+                expected: Some(Notice::new(NoticeKind::Maintenance, Some(20060), "")),
+            },
+            // TC3: version info is not a notice
+            TestCase {
+                input: r#"{"event": "info", "version": 2, "serverId": "abc", "platform": {"status": 1}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bitfinex::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::ExchangeId,
     subscription::trade::PublicTrade,
 };
 use barter_integration::{
     de::{datetime_utc_from_epoch_duration, extract_next},
-    model::{Exchange, Instrument, Side},
+    model::{instrument::Instrument, Exchange, Side},
 };
 use chrono::{DateTime, Utc};
 use serde::Serialize;
@@ -31,7 +31,7 @@ use serde::Serialize;
 ///
 /// ## Notes:
 /// - [`Bitfinex`](super::Bitfinex) trades subscriptions results in receiving tag="te" & tag="tu"
-/// trades, both of which are identical.
+///   trades, both of which are identical.
 /// - "te" trades arrive marginally faster.
 /// - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 ///
@@ -50,14 +50,11 @@ impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
-            kind: PublicTrade {
-                id: trade.id.to_string(),
-                price: trade.price,
-                amount: trade.amount,
-                side: trade.side,
-            },
+            kind: PublicTrade::new(trade.id, trade.price, trade.amount, trade.side),
         })])
     }
 }
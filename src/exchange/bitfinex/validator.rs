@@ -3,16 +3,14 @@ use crate::{
     exchange::{Connector, ExchangeSub},
     subscriber::validator::SubscriptionValidator,
     subscription::{Map, SubKind},
+    transport::BoxTransport,
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    model::{Instrument, SubscriptionId},
-    protocol::{
-        websocket::{WebSocket, WebSocketParser},
-        StreamParser,
-    },
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::{websocket::WebSocketParser, StreamParser},
     Validator,
 };
 use futures::StreamExt;
@@ -39,7 +37,7 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
 
     async fn validate<Exchange, Kind>(
         mut map: Map<Instrument>,
-        websocket: &mut WebSocket,
+        websocket: &mut BoxTransport,
     ) -> Result<Map<Instrument>, SocketError>
     where
         Exchange: Connector + Send,
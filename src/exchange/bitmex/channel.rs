@@ -1,6 +1,6 @@
 use crate::{
     exchange::bitmex::Bitmex,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{risk::InsuranceFunds, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl BitmexChannel {
     ///
     /// See docs: <https://www.bitmex.com/app/wsAPI>
     pub const TRADES: Self = Self("trade");
+
+    /// [`Bitmex`](super::Bitmex) insurance fund channel name, serving every currency.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+    pub const INSURANCE: Self = Self("insurance");
 }
 
 impl Identifier<BitmexChannel> for Subscription<Bitmex, PublicTrades> {
@@ -25,6 +30,12 @@ impl Identifier<BitmexChannel> for Subscription<Bitmex, PublicTrades> {
     }
 }
 
+impl Identifier<BitmexChannel> for Subscription<Bitmex, InsuranceFunds> {
+    fn id(&self) -> BitmexChannel {
+        BitmexChannel::INSURANCE
+    }
+}
+
 impl AsRef<str> for BitmexChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,197 @@
+use super::{message::BitmexMessage, Bitmex};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::Connector,
+    subscription::{
+        risk::{InsuranceFund, InsuranceFunds},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{
+        instrument::{symbol::Symbol, Instrument},
+        Exchange,
+    },
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`BitmexInsurance`](BitmexInsuranceInner) real-time insurance fund
+/// WebSocket message.
+pub type BitmexInsurance = BitmexMessage<BitmexInsuranceInner>;
+
+/// [`Bitmex`] insurance fund balance, denominated in the smallest unit of the currency
+/// (eg/ "XBt" satoshis).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+/// ```json
+/// {
+///     "table": "insurance",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "currency": "XBt",
+///             "timestamp": "2023-02-18T12:00:00.000Z",
+///             "walletBalance": 8810803592613
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexInsuranceInner {
+    pub currency: String,
+    pub timestamp: DateTime<Utc>,
+    #[serde(rename = "walletBalance")]
+    pub wallet_balance: f64,
+}
+
+impl BitmexInsuranceInner {
+    /// Normalise the [`Bitmex`] currency and wallet balance into an [`InsuranceFund`] asset
+    /// [`Symbol`] and balance.
+    pub fn insurance_fund(&self) -> InsuranceFund {
+        let (asset, scale) = match self.currency.as_str() {
+            "XBt" => ("xbt".to_owned(), 1e-8),
+            "USDt" => ("usdt".to_owned(), 1e-6),
+            "Gwei" => ("eth".to_owned(), 1e-9),
+            currency => (currency.to_lowercase(), 1.0),
+        };
+
+        InsuranceFund {
+            asset: Symbol::from(asset),
+            balance: self.wallet_balance * scale,
+        }
+    }
+}
+
+/// [`Bitmex`] [`InsuranceFunds`] [`ExchangeTransformer`].
+///
+/// Bitmex streams the insurance fund balance of every currency over a single "insurance" table,
+/// so each update is yielded once for every subscribed [`Instrument`] that has the currency as
+/// its base or quote, and ignored if there are none.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BitmexInsuranceFunds {
+    instruments: Vec<Instrument>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Bitmex, InsuranceFunds> for BitmexInsuranceFunds {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self::from(instrument_map))
+    }
+}
+
+impl From<Map<Instrument>> for BitmexInsuranceFunds {
+    fn from(instrument_map: Map<Instrument>) -> Self {
+        let mut instruments = instrument_map.0.into_values().collect::<Vec<_>>();
+        instruments.sort();
+        instruments.dedup();
+
+        Self { instruments }
+    }
+}
+
+impl Transformer for BitmexInsuranceFunds {
+    type Error = DataError;
+    type Input = BitmexInsurance;
+    type Output = MarketEvent<InsuranceFund>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .data
+            .into_iter()
+            .flat_map(|insurance| {
+                let fund = insurance.insurance_fund();
+                self.instruments
+                    .iter()
+                    .filter(|instrument| {
+                        instrument.base == fund.asset || instrument.quote == fund.asset
+                    })
+                    .map(|instrument| {
+                        Ok(MarketEvent {
+                            exchange_time: insurance.timestamp,
+                            received_time: Utc::now(),
+                            received_monotonic: MonotonicTime::now(),
+                            origin: Origin::Live,
+                            exchange: Exchange::from(Bitmex::ID),
+                            instrument: instrument.clone(),
+                            kind: fund.clone(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_bitmex_insurance_funds_transform() {
+        let input = r#"
+        {
+            "table": "insurance",
+            "action": "partial",
+            "data": [
+                {
+                    "currency": "XBt",
+                    "timestamp": "2023-02-18T12:00:00.000Z",
+                    "walletBalance": 8810803592613
+                },
+                {
+                    "currency": "USDt",
+                    "timestamp": "2023-02-18T12:00:00.000Z",
+                    "walletBalance": 2500000000
+                },
+                {
+                    "currency": "Gwei",
+                    "timestamp": "2023-02-18T12:00:00.000Z",
+                    "walletBalance": 1000000000000
+                }
+            ]
+        }
+        "#;
+
+        let xbt_usd = Instrument::from(("xbt", "usd", InstrumentKind::Perpetual));
+        let xbt_usdt = Instrument::from(("xbt", "usdt", InstrumentKind::Perpetual));
+        let mut transformer = BitmexInsuranceFunds::from(Map::from_iter([
+            (SubscriptionId::from("insurance|XBTUSD"), xbt_usd.clone()),
+            (SubscriptionId::from("insurance|XBTUSDT"), xbt_usdt.clone()),
+        ]));
+
+        let actual = transformer
+            .transform(serde_json::from_str(input).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind)
+            })
+            .collect::<Vec<_>>();
+
+        let xbt = InsuranceFund {
+            asset: Symbol::from("xbt"),
+            balance: 88108.03592613,
+        };
+        let usdt = InsuranceFund {
+            asset: Symbol::from("usdt"),
+            balance: 2500.0,
+        };
+        assert_eq!(
+            actual,
+            vec![
+                (xbt_usd, xbt.clone()),
+                (xbt_usdt.clone(), xbt),
+                (xbt_usdt, usdt)
+            ]
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{exchange::bitmex::trade::BitmexTrade, Identifier};
+use crate::{
+    exchange::{bitmex::trade::BitmexTrade, subscription::subscription_id},
+    Identifier,
+};
 use barter_integration::model::SubscriptionId;
 use serde::{Deserialize, Serialize};
 
@@ -36,7 +39,7 @@ impl Identifier<Option<SubscriptionId>> for BitmexTrade {
     fn id(&self) -> Option<SubscriptionId> {
         self.data
             .first()
-            .map(|trade| SubscriptionId(format!("{}|{}", self.table, trade.symbol)))
+            .map(|trade| subscription_id(&self.table, &trade.symbol))
             .or(None)
     }
 }
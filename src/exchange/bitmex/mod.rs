@@ -1,26 +1,36 @@
 use crate::{
     exchange::{
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
-            trade::BitmexTrade,
+            channel::BitmexChannel, insurance::BitmexInsuranceFunds, market::BitmexMarket,
+            subscription::BitmexSubResponse, trade::BitmexTrade,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
+    subscription::{risk::InsuranceFunds, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, model::Instrument, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use serde::de::{Error, Unexpected};
 use std::fmt::Debug;
 use url::Url;
 
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Bitmex error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Insurance fund types and [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) for
+/// [`Bitmex`].
+pub mod insurance;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -56,11 +66,19 @@ impl Connector for Bitmex {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
+        let mut stream_names = exchange_subs
             .into_iter()
-            .map(|sub| format!("{}:{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .map(|sub| match sub.channel {
+                // Bitmex insurance funds of every currency are streamed over a single table
+                BitmexChannel::INSURANCE => sub.channel.as_ref().to_owned(),
+                _ => format!("{}:{}", sub.channel.as_ref(), sub.market.as_ref()),
+            })
             .collect::<Vec<String>>();
 
+        // Remove duplicate topics (eg/ several instruments sharing the insurance table)
+        stream_names.sort();
+        stream_names.dedup();
+
         vec![WsMessage::Text(
             serde_json::json!({
                 "op": "subscribe",
@@ -79,6 +97,10 @@ impl StreamSelector<PublicTrades> for Bitmex {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BitmexTrade>>;
 }
 
+impl StreamSelector<InsuranceFunds> for Bitmex {
+    type Stream = ExchangeWsStream<BitmexInsuranceFunds>;
+}
+
 impl<'de> serde::Deserialize<'de> for Bitmex {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -88,7 +110,7 @@ impl<'de> serde::Deserialize<'de> for Bitmex {
         let expected = Self::ID.as_str();
 
         if input == Self::ID.as_str() {
-            Ok(Self::default())
+            Ok(Self)
         } else {
             Err(Error::invalid_value(Unexpected::Str(input), &expected))
         }
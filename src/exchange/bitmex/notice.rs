@@ -0,0 +1,76 @@
+use super::Bitmex;
+use crate::notice::{Notice, NoticeKind, NoticeParser};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitmex`] WebSocket error message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+/// ```json
+/// {"status": 400, "error": "Unknown table: gibberish", "meta": {}, "request": {"op": "subscribe", "args": ["gibberish"]}}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitmexNotice {
+    pub status: u16,
+    pub error: String,
+}
+
+impl NoticeParser for Bitmex {
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let BitmexNotice { status, error } = serde_json::from_str(payload).ok()?;
+
+        let kind = match status {
+            429 => NoticeKind::RateLimit,
+            401 | 403 => NoticeKind::Unauthorised,
+            503 => NoticeKind::Maintenance,
+            400..=499 => NoticeKind::InvalidRequest,
+            _ => NoticeKind::Other,
+        };
+
+        Some(Notice::new(kind, Some(status), error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmex_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: unknown table error
+            TestCase {
+                input: r#"{"status": 400, "error": "Unknown table: gibberish", "meta": {}, "request": {"op": "subscribe"}}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    Some(400),
+                    "Unknown table: gibberish",
+                )),
+            },
+            // TC1: rate limit error
+            TestCase {
+                input: r#"{"status": 429, "error": "Rate limit exceeded, retry in 1 seconds.", "meta": {"retryAfter": 1}}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::RateLimit,
+                    Some(429),
+                    "Rate limit exceeded, retry in 1 seconds.",
+                )),
+            },
+            // TC2: subscription success is not a notice
+            TestCase {
+                input: r#"{"success": true, "subscribe": "trade:XBTUSD"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bitmex::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
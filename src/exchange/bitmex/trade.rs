@@ -1,9 +1,9 @@
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{bitmex::message::BitmexMessage, ExchangeId},
     subscription::trade::PublicTrade,
 };
-use barter_integration::model::{Exchange, Instrument, Side};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -59,14 +59,11 @@ impl From<(ExchangeId, Instrument, BitmexTrade)> for MarketIter<PublicTrade> {
                     Ok(MarketEvent {
                         exchange_time: trade.timestamp,
                         received_time: Utc::now(),
+                        received_monotonic: MonotonicTime::now(),
+                        origin: Origin::Live,
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
-                        kind: PublicTrade {
-                            id: trade.id,
-                            price: trade.price,
-                            amount: trade.amount,
-                            side: trade.side,
-                        },
+                        kind: PublicTrade::new(trade.id, trade.price, trade.amount, trade.side),
                     })
                 })
                 .collect(),
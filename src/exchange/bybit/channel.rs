@@ -1,6 +1,16 @@
 use crate::{
-    exchange::bybit::Bybit,
-    subscription::{trade::PublicTrades, Subscription},
+    exchange::{
+        bybit::{futures::BybitFuturesUsd, option::BybitOptions, Bybit},
+        ExchangeId, ExchangeServer,
+    },
+    subscription::{
+        account::{Balances, OrderUpdates, Positions},
+        book::OrderBooksL2,
+        greeks::OptionGreeks,
+        risk::{AdlIndicators, InsuranceFunds},
+        trade::{PublicTrades, UserTrades},
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +27,115 @@ impl BybitChannel {
     ///
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`](super::Bybit) option level 2 OrderBook channel name (25 levels).
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2_OPTION: Self = Self("orderbook.25");
+
+    /// [`Bybit`](super::Bybit) tickers channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`Bybit`](super::Bybit) USDT settled insurance pool channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/insurance-pool>
+    pub const INSURANCE_USDT: Self = Self("insurance.USDT");
+
+    /// [`Bybit`](super::Bybit) USDC settled insurance pool channel name.
+    pub const INSURANCE_USDC: Self = Self("insurance.USDC");
+
+    /// [`Bybit`](super::Bybit) USDT settled auto-deleveraging alert channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/adl-alert>
+    pub const ADL_ALERT_USDT: Self = Self("adlAlert.USDT");
+
+    /// [`Bybit`](super::Bybit) USDC settled auto-deleveraging alert channel name.
+    pub const ADL_ALERT_USDC: Self = Self("adlAlert.USDC");
+
+    /// Determine if this [`BybitChannel`] is streamed per settle coin (eg/ "insurance.USDT"),
+    /// and so is subscribed to without a market.
+    pub fn is_settle_coin(&self) -> bool {
+        Self::is_settle_coin_channel(self.0)
+    }
+
+    /// Determine if the provided channel name is streamed per settle coin (see
+    /// [`BybitChannel::is_settle_coin`]).
+    pub fn is_settle_coin_channel(channel: &str) -> bool {
+        [
+            Self::INSURANCE_USDT,
+            Self::INSURANCE_USDC,
+            Self::ADL_ALERT_USDT,
+            Self::ADL_ALERT_USDC,
+        ]
+        .iter()
+        .any(|settle_coin| settle_coin.0 == channel)
+    }
+
+    /// [`Bybit`](super::Bybit) private execution topic, suffixed with a category when
+    /// subscribing to the executions of a single category (eg/ "execution.spot").
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/execution>
+    pub const EXECUTION_TOPIC: &'static str = "execution";
+
+    /// [`Bybit`](super::Bybit) private spot execution channel name.
+    pub const EXECUTION_SPOT: Self = Self("execution.spot");
+
+    /// [`Bybit`](super::Bybit) private linear (USDT perpetual) execution channel name.
+    pub const EXECUTION_LINEAR: Self = Self("execution.linear");
+
+    /// [`Bybit`](super::Bybit) private option execution channel name.
+    pub const EXECUTION_OPTION: Self = Self("execution.option");
+
+    /// Determine the private execution [`BybitChannel`] for the category served by the provided
+    /// [`ExchangeId`].
+    pub fn execution(exchange: ExchangeId) -> Self {
+        match exchange {
+            ExchangeId::BybitFuturesUsd => Self::EXECUTION_LINEAR,
+            ExchangeId::BybitOptions => Self::EXECUTION_OPTION,
+            _ => Self::EXECUTION_SPOT,
+        }
+    }
+
+    /// [`Bybit`](super::Bybit) private position topic, suffixed with a category when
+    /// subscribing to the positions of a single category (eg/ "position.linear").
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/position>
+    pub const POSITION_TOPIC: &'static str = "position";
+
+    /// [`Bybit`](super::Bybit) private linear (USDT perpetual) position channel name.
+    pub const POSITION_LINEAR: Self = Self("position.linear");
+
+    /// [`Bybit`](super::Bybit) private wallet channel name, serving every coin balance.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/wallet>
+    pub const WALLET: Self = Self("wallet");
+
+    /// [`Bybit`](super::Bybit) private order topic, suffixed with a category when subscribing
+    /// to the order updates of a single category (eg/ "order.spot").
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/order>
+    pub const ORDER_TOPIC: &'static str = "order";
+
+    /// [`Bybit`](super::Bybit) private spot order channel name.
+    pub const ORDER_SPOT: Self = Self("order.spot");
+
+    /// [`Bybit`](super::Bybit) private linear (USDT perpetual) order channel name.
+    pub const ORDER_LINEAR: Self = Self("order.linear");
+
+    /// [`Bybit`](super::Bybit) private option order channel name.
+    pub const ORDER_OPTION: Self = Self("order.option");
+
+    /// Determine the private order [`BybitChannel`] for the category served by the provided
+    /// [`ExchangeId`].
+    pub fn order(exchange: ExchangeId) -> Self {
+        match exchange {
+            ExchangeId::BybitFuturesUsd => Self::ORDER_LINEAR,
+            ExchangeId::BybitOptions => Self::ORDER_OPTION,
+            _ => Self::ORDER_SPOT,
+        }
+    }
 }
 
 impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrades> {
@@ -25,6 +144,66 @@ impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrad
     }
 }
 
+impl Identifier<BybitChannel> for Subscription<BybitOptions, OrderBooksL2> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L2_OPTION
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitOptions, OptionGreeks> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TICKERS
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, UserTrades>
+where
+    Server: ExchangeServer,
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::execution(Server::ID)
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, OrderUpdates>
+where
+    Server: ExchangeServer,
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::order(Server::ID)
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, Balances> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::WALLET
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitFuturesUsd, InsuranceFunds> {
+    fn id(&self) -> BybitChannel {
+        match self.instrument.quote.as_ref() {
+            "usdc" => BybitChannel::INSURANCE_USDC,
+            _ => BybitChannel::INSURANCE_USDT,
+        }
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitFuturesUsd, AdlIndicators> {
+    fn id(&self) -> BybitChannel {
+        match self.instrument.quote.as_ref() {
+            "usdc" => BybitChannel::ADL_ALERT_USDC,
+            _ => BybitChannel::ADL_ALERT_USDT,
+        }
+    }
+}
+
+impl Identifier<BybitChannel> for Subscription<BybitFuturesUsd, Positions> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::POSITION_LINEAR
+    }
+}
+
 impl AsRef<str> for BybitChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,6 +1,10 @@
 use super::{Bybit, ExchangeServer};
 use crate::exchange::ExchangeId;
 
+/// Insurance pool & auto-deleveraging alert types and [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)
+/// for [`BybitFuturesUsd`].
+pub mod risk;
+
 /// [`BybitFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
@@ -0,0 +1,348 @@
+use super::BybitFuturesUsd;
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::{bybit::subscription::BybitResponse, Connector},
+    subscription::{
+        risk::{AdlIndicator, AdlIndicators, InsuranceFund, InsuranceFunds},
+        Map, SubKind,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{
+        instrument::{symbol::Symbol, Instrument},
+        Exchange,
+    },
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`BybitFuturesUsd`] settle coin topic message (eg/ "insurance.USDT"), containing data that
+/// applies to many contracts.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitRiskMessage<T> {
+    Response(BybitResponse),
+    Payload(BybitRiskPayload<T>),
+}
+
+/// [`BybitFuturesUsd`] settle coin topic payload.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitRiskPayload<T> {
+    pub topic: String,
+    #[serde(
+        alias = "ts",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub data: Vec<T>,
+}
+
+/// [`BybitFuturesUsd`] settle coin topic data element that can be routed to the subscribed
+/// [`Instrument`]s using the contract symbols it applies to.
+pub trait BybitRiskData {
+    type Event;
+
+    /// Contract symbols (eg/ "BTCUSDT") this data applies to.
+    fn symbols(&self) -> Vec<&str>;
+
+    /// Normalise this data into a Barter `Event`.
+    fn event(&self) -> Self::Event;
+}
+
+/// [`BybitFuturesUsd`] insurance pool balance update.
+///
+/// Bybit shares an insurance pool between every contract listed in the comma separated
+/// "symbols".
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/insurance-pool>
+/// ```json
+/// {
+///     "topic": "insurance.USDT",
+///     "type": "delta",
+///     "ts": 1747722930000,
+///     "data": [
+///         {
+///             "coin": "USDT",
+///             "symbols": "GRIFFAINUSDT,XCNUSDT",
+///             "balance": "25193.66876270",
+///             "updateTime": "1747722930000"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitInsurance {
+    pub coin: String,
+    pub symbols: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub balance: f64,
+}
+
+impl BybitRiskData for BybitInsurance {
+    type Event = InsuranceFund;
+
+    fn symbols(&self) -> Vec<&str> {
+        self.symbols.split(',').collect()
+    }
+
+    fn event(&self) -> Self::Event {
+        InsuranceFund {
+            asset: Symbol::from(self.coin.to_lowercase()),
+            balance: self.balance,
+        }
+    }
+}
+
+/// [`BybitFuturesUsd`] auto-deleveraging alert for a single contract.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/adl-alert>
+/// ```json
+/// {
+///     "topic": "adlAlert.USDT",
+///     "type": "snapshot",
+///     "ts": 1757733300000,
+///     "data": [
+///         {
+///             "c": "USDT",
+///             "s": "BTCUSDT",
+///             "b": "21341.96922776",
+///             "mb": "21341.96922776",
+///             "i_pr": "-0.3",
+///             "pr": "-0.000008",
+///             "adl_tt": "10000",
+///             "adl_sr": "-0.25"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitAdlAlert {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b", deserialize_with = "barter_integration::de::de_str")]
+    pub balance: f64,
+    #[serde(rename = "mb", deserialize_with = "barter_integration::de::de_str")]
+    pub max_balance: f64,
+    #[serde(rename = "i_pr", deserialize_with = "barter_integration::de::de_str")]
+    pub pnl_ratio_threshold: f64,
+    #[serde(rename = "pr", deserialize_with = "barter_integration::de::de_str")]
+    pub pnl_ratio: f64,
+    #[serde(rename = "adl_tt", deserialize_with = "barter_integration::de::de_str")]
+    pub trigger_threshold: f64,
+    #[serde(rename = "adl_sr", deserialize_with = "barter_integration::de::de_str")]
+    pub stop_ratio: f64,
+}
+
+impl BybitRiskData for BybitAdlAlert {
+    type Event = AdlIndicator;
+
+    fn symbols(&self) -> Vec<&str> {
+        vec![self.symbol.as_str()]
+    }
+
+    fn event(&self) -> Self::Event {
+        AdlIndicator {
+            state: None,
+            insurance_balance: self.balance,
+            max_insurance_balance: Some(self.max_balance),
+            pnl_ratio: Some(self.pnl_ratio),
+            pnl_ratio_threshold: Some(self.pnl_ratio_threshold),
+            trigger_threshold: Some(self.trigger_threshold),
+            stop_ratio: Some(self.stop_ratio),
+        }
+    }
+}
+
+/// [`BybitFuturesUsd`] [`ExchangeTransformer`] for [`InsuranceFunds`] & [`AdlIndicators`].
+///
+/// Bybit streams these per settle coin (eg/ "insurance.USDT") rather than per contract, so each
+/// data element is routed to every subscribed [`Instrument`] it applies to. Data of contracts
+/// that were not subscribed to is ignored.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BybitRiskTransformer<Kind, Data> {
+    instrument_map: Map<Instrument>,
+    phantom: PhantomData<(Kind, Data)>,
+}
+
+/// [`BybitFuturesUsd`] [`InsuranceFunds`] [`ExchangeTransformer`].
+pub type BybitInsuranceFunds = BybitRiskTransformer<InsuranceFunds, BybitInsurance>;
+
+/// [`BybitFuturesUsd`] [`AdlIndicators`] [`ExchangeTransformer`].
+pub type BybitAdlIndicators = BybitRiskTransformer<AdlIndicators, BybitAdlAlert>;
+
+#[async_trait]
+impl<Kind, Data> ExchangeTransformer<BybitFuturesUsd, Kind> for BybitRiskTransformer<Kind, Data>
+where
+    Kind: SubKind<Event = Data::Event> + Send,
+    Data: BybitRiskData + DeserializeOwned + Send,
+{
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Kind, Data> Transformer for BybitRiskTransformer<Kind, Data>
+where
+    Data: BybitRiskData + DeserializeOwned,
+{
+    type Error = DataError;
+    type Input = BybitRiskMessage<Data>;
+    type Output = MarketEvent<Data::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let payload = match input {
+            BybitRiskMessage::Payload(payload) => payload,
+            BybitRiskMessage::Response(_) => return vec![],
+        };
+
+        payload
+            .data
+            .iter()
+            .flat_map(|data| {
+                data.symbols()
+                    .into_iter()
+                    .filter_map(|symbol| {
+                        // Route using each contract symbol, ignoring un-subscribed contracts
+                        self.instrument_map
+                            .find_channel_market(&payload.topic, symbol)
+                            .ok()
+                    })
+                    .map(|instrument| {
+                        Ok(MarketEvent {
+                            exchange_time: payload.time,
+                            received_time: Utc::now(),
+                            received_monotonic: MonotonicTime::now(),
+                            origin: Origin::Live,
+                            exchange: Exchange::from(BybitFuturesUsd::ID),
+                            instrument,
+                            kind: data.event(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_bybit_insurance_funds_transform() {
+        let input = r#"
+        {
+            "topic": "insurance.USDT",
+            "type": "delta",
+            "ts": 1747722930000,
+            "data": [
+                {
+                    "coin": "USDT",
+                    "symbols": "BTCUSDT,ETHUSDT,XCNUSDT",
+                    "balance": "25193.66876270",
+                    "updateTime": "1747722930000"
+                }
+            ]
+        }
+        "#;
+
+        // Only subscribed to BTCUSDT & ETHUSDT, which share the insurance pool with XCNUSDT
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Perpetual));
+        let mut transformer = BybitInsuranceFunds {
+            instrument_map: Map::from_iter([
+                (SubscriptionId::from("insurance.USDT|BTCUSDT"), btc.clone()),
+                (SubscriptionId::from("insurance.USDT|ETHUSDT"), eth.clone()),
+            ]),
+            phantom: PhantomData,
+        };
+
+        let actual = transformer
+            .transform(serde_json::from_str(input).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind)
+            })
+            .collect::<Vec<_>>();
+
+        let fund = InsuranceFund {
+            asset: Symbol::from("usdt"),
+            balance: 25193.6687627,
+        };
+        assert_eq!(actual, vec![(btc, fund.clone()), (eth, fund)]);
+    }
+
+    #[test]
+    fn test_bybit_adl_indicators_transform() {
+        let input = r#"
+        {
+            "topic": "adlAlert.USDT",
+            "type": "snapshot",
+            "ts": 1757733300000,
+            "data": [
+                {
+                    "c": "USDT",
+                    "s": "BTCUSDT",
+                    "b": "21341.96922776",
+                    "mb": "21341.96922776",
+                    "i_pr": "-0.3",
+                    "pr": "-0.000008",
+                    "adl_tt": "10000",
+                    "adl_sr": "-0.25"
+                },
+                {
+                    "c": "USDT",
+                    "s": "ETHUSDT",
+                    "b": "21341.96922776",
+                    "mb": "21341.96922776",
+                    "i_pr": "-0.3",
+                    "pr": "-0.000012",
+                    "adl_tt": "10000",
+                    "adl_sr": "-0.25"
+                }
+            ]
+        }
+        "#;
+
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let mut transformer = BybitAdlIndicators {
+            instrument_map: Map::from_iter([(
+                SubscriptionId::from("adlAlert.USDT|BTCUSDT"),
+                btc.clone(),
+            )]),
+            phantom: PhantomData,
+        };
+
+        let actual = transformer.transform(serde_json::from_str(input).unwrap());
+
+        assert_eq!(actual.len(), 1);
+        let event = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(event.instrument, btc);
+        assert_eq!(
+            event.kind,
+            AdlIndicator {
+                state: None,
+                insurance_balance: 21341.96922776,
+                max_insurance_balance: Some(21341.96922776),
+                pnl_ratio: Some(-0.000008),
+                pnl_ratio_threshold: Some(-0.3),
+                trigger_threshold: Some(10000.0),
+                stop_ratio: Some(-0.25),
+            }
+        );
+    }
+}
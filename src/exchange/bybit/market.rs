@@ -1,4 +1,5 @@
 use crate::{exchange::bybit::Bybit, subscription::Subscription, Identifier};
+use barter_integration::model::instrument::kind::{InstrumentKind, OptionKind};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Bybit`](super::Bybit)
@@ -12,7 +13,30 @@ impl<Server, Kind> Identifier<BybitMarket> for Subscription<Bybit<Server>, Kind>
     fn id(&self) -> BybitMarket {
         // Notes:
         // - Must be uppercase since Bybit sends message with uppercase MARKET (eg/ BTCUSDT).
-        BybitMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        // - Option symbols are constructed from the OptionContract: eg/ BTC-30JUN23-20000-C
+        BybitMarket(match self.instrument.kind {
+            InstrumentKind::Spot | InstrumentKind::Perpetual => {
+                format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase()
+            }
+            InstrumentKind::Future(future) => format!(
+                "{}{}-{}",
+                self.instrument.base,
+                self.instrument.quote,
+                future.expiry.format("%-d%b%y")
+            )
+            .to_uppercase(),
+            InstrumentKind::Option(option) => format!(
+                "{}-{}-{}-{}",
+                self.instrument.base,
+                option.expiry.format("%-d%b%y"),
+                option.strike.normalize(),
+                match option.kind {
+                    OptionKind::Call => "C",
+                    OptionKind::Put => "P",
+                }
+            )
+            .to_uppercase(),
+        })
     }
 }
 
@@ -21,3 +45,74 @@ impl AsRef<str> for BybitMarket {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::bybit::{option::BybitOptions, spot::BybitSpot},
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::model::instrument::kind::{OptionContract, OptionExercise};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_bybit_market() {
+        struct TestCase {
+            input: BybitMarket,
+            expected: BybitMarket,
+        }
+
+        let option = |kind, day, strike| {
+            InstrumentKind::Option(OptionContract {
+                kind,
+                exercise: OptionExercise::European,
+                expiry: Utc.with_ymd_and_hms(2023, 6, day, 8, 0, 0).unwrap(),
+                strike: rust_decimal::Decimal::from(strike),
+            })
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: Spot market
+                input: Subscription::from((
+                    BybitSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+                .id(),
+                expected: BybitMarket("BTCUSDT".to_string()),
+            },
+            TestCase {
+                // TC1: Option call market
+                input: Subscription::from((
+                    BybitOptions::default(),
+                    "btc",
+                    "usdc",
+                    option(OptionKind::Call, 30, 20000),
+                    PublicTrades,
+                ))
+                .id(),
+                expected: BybitMarket("BTC-30JUN23-20000-C".to_string()),
+            },
+            TestCase {
+                // TC2: Option put market with single digit expiry day
+                input: Subscription::from((
+                    BybitOptions::default(),
+                    "eth",
+                    "usdc",
+                    option(OptionKind::Put, 2, 1850),
+                    PublicTrades,
+                ))
+                .id(),
+                expected: BybitMarket("ETH-2JUN23-1850-P".to_string()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(test.input, test.expected, "TC{} failed", index);
+        }
+    }
+}
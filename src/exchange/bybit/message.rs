@@ -2,12 +2,13 @@ use crate::{
     event::MarketIter,
     exchange::{
         bybit::{channel::BybitChannel, subscription::BybitResponse, trade::BybitTrade},
+        subscription::subscription_id,
         ExchangeId,
     },
     subscription::trade::PublicTrade,
     Identifier,
 };
-use barter_integration::model::{Instrument, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{
     de::{Error, Unexpected},
@@ -60,10 +61,10 @@ pub struct BybitPayload<T> {
     pub data: T,
 }
 
-/// Deserialize a [`BybitPayload`] "s" (eg/ "publicTrade.BTCUSDT") as the associated
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "publicTrade.BTCUSDT") as the associated
 /// [`SubscriptionId`].
 ///
-/// eg/ "publicTrade|BTCUSDT"
+/// eg/ "publicTrade|BTCUSDT", "orderbook.25|BTC-30JUN23-20000-C", "tickers|BTC-30JUN23-20000-C"
 pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -72,10 +73,15 @@ where
     let mut tokens = input.split('.');
 
     match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
-            "{}|{market}",
-            BybitChannel::TRADES.0
-        ))),
+        (Some("publicTrade"), Some(market), None) => {
+            Ok(subscription_id(BybitChannel::TRADES.0, market))
+        }
+        (Some("tickers"), Some(market), None) => {
+            Ok(subscription_id(BybitChannel::TICKERS.0, market))
+        }
+        (Some("orderbook"), Some(depth), Some(market)) => {
+            Ok(subscription_id(format!("orderbook.{depth}"), market))
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
@@ -1,23 +1,49 @@
+#[cfg(feature = "private")]
+use crate::{
+    exchange::bybit::user::{BybitExecutions, BybitOrders, BybitPositions, BybitWallets},
+    subscription::{
+        account::{Balances, OrderUpdates, Positions},
+        trade::UserTrades,
+    },
+    transformer::{account::AccountTransformer, balance::BalanceTransformer},
+    PrivateWsStream,
+};
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
+            channel::BybitChannel,
+            futures::{
+                risk::{BybitAdlIndicators, BybitInsuranceFunds},
+                BybitFuturesUsd,
+            },
+            market::BybitMarket,
+            message::BybitMessage,
+            spot::BybitSpot,
             subscription::BybitResponse,
         },
-        subscription::ExchangeSub,
-        Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector,
+        subscription::{ExchangeSub, SUBSCRIPTION_ID_SEPARATOR},
+        Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector, SubscriptionLimits,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
+    subscription::{
+        risk::{AdlIndicators, InsuranceFunds},
+        trade::PublicTrades,
+        Map,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, model::Instrument, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use serde::de::{Error, Unexpected};
 use std::{fmt::Debug, marker::PhantomData, time::Duration};
-use tokio::time;
 use url::Url;
 
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Bybit error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -34,6 +60,10 @@ pub mod market;
 /// [`BybitSpot`](spot::BybitSpot)
 pub mod message;
 
+/// [`ExchangeServer`] and [`StreamSelector`] implementations for
+/// [`BybitOptions`](option::BybitOptions).
+pub mod option;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BybitSpot`](spot::BybitSpot).
 pub mod spot;
@@ -43,15 +73,36 @@ pub mod spot;
 /// and [`BybitFuturesUsd`](futures::BybitFuturesUsd).
 pub mod subscription;
 
+/// HTTP server time [`ServerClock`](crate::clock::ServerClock) implementation common to every
+/// [`Bybit`] server.
+pub mod time;
+
 /// Public trade types common to both [`BybitSpot`](spot::BybitSpot) and
 /// [`BybitFuturesUsd`](futures::BybitFuturesUsd).
 pub mod trade;
 
+/// Private execution types and the authenticated [`PrivateConnector`](super::PrivateConnector)
+/// implementation common to every [`Bybit`] server. Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub mod user;
+
+/// [`Bybit`] alternative public WebSocket hosts serving the same paths as "stream.bybit.com".
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+pub const REGIONAL_HOSTS_BYBIT: &[&str] = &["stream.bytick.com"];
+
+/// [`BybitSpot`](spot::BybitSpot) maximum number of topics per subscribe request.
+pub const MAX_ARGS_PER_REQUEST_BYBIT_SPOT: usize = 10;
+
+/// [`BybitOptions`](option::BybitOptions) maximum number of topics per connection.
+pub const MAX_ARGS_PER_CONNECTION_BYBIT_OPTIONS: usize = 2000;
+
 /// Generic [`Bybit<Server>`](Bybit) exchange.
 ///
 /// ### Notes
 /// A `Server` [`ExchangeServer`](super::ExchangeServer) implementations exists for
-/// [`BybitSpot`](spot::BybitSpot) and [`BybitFuturesUsd`](futures::BybitFuturesUsd).
+/// [`BybitSpot`](spot::BybitSpot), [`BybitFuturesUsd`](futures::BybitFuturesUsd) and
+/// [`BybitOptions`](option::BybitOptions).
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Bybit<Server> {
     server: PhantomData<Server>,
@@ -72,9 +123,13 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn regional_hosts() -> &'static [&'static str] {
+        REGIONAL_HOSTS_BYBIT
+    }
+
     fn ping_interval() -> Option<PingInterval> {
         Some(PingInterval {
-            interval: time::interval(Duration::from_millis(5_000)),
+            interval: tokio::time::interval(Duration::from_millis(5_000)),
             ping: || {
                 WsMessage::Text(
                     serde_json::json!({
@@ -86,33 +141,141 @@ where
         })
     }
 
+    /// Topics are batched into one subscribe request, or as few as the
+    /// [`SubscriptionLimits::args_per_request`] limit allows.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
+        let mut stream_names = exchange_subs
             .into_iter()
-            .map(|sub| format!("{}.{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .map(|sub| Self::topic(sub.channel.as_ref(), sub.market.as_ref()))
             .collect::<Vec<String>>();
 
-        vec![WsMessage::Text(
-            serde_json::json!({
-                "op": "subscribe",
-                "args": stream_names
+        // Remove duplicate topics (eg/ several option contracts sharing a base coin)
+        stream_names.sort();
+        stream_names.dedup();
+
+        let args_per_request = Self::subscription_limits()
+            .args_per_request
+            .unwrap_or(stream_names.len())
+            .max(1);
+
+        stream_names
+            .chunks(args_per_request)
+            .map(|stream_names| {
+                WsMessage::Text(
+                    serde_json::json!({
+                        "op": "subscribe",
+                        "args": stream_names
+                    })
+                    .to_string(),
+                )
             })
-            .to_string(),
-        )]
+            .collect()
+    }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        let mut stream_names = map
+            .0
+            .keys()
+            .filter_map(|id| id.as_ref().split_once(SUBSCRIPTION_ID_SEPARATOR))
+            .map(|(channel, market)| Self::topic(channel, market))
+            .collect::<Vec<String>>();
+        stream_names.sort();
+        stream_names.dedup();
+
+        match Self::subscription_limits().args_per_request {
+            Some(args_per_request) => stream_names.len().div_ceil(args_per_request.max(1)),
+            None => 1,
+        }
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
-        1
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-subscribe-to-topics>
+    fn subscription_limits() -> SubscriptionLimits {
+        match Self::ID {
+            ExchangeId::BybitSpot => SubscriptionLimits {
+                args_per_request: Some(MAX_ARGS_PER_REQUEST_BYBIT_SPOT),
+                ..SubscriptionLimits::UNLIMITED
+            },
+            ExchangeId::BybitOptions => SubscriptionLimits {
+                streams_per_connection: Some(MAX_ARGS_PER_CONNECTION_BYBIT_OPTIONS),
+                ..SubscriptionLimits::UNLIMITED
+            },
+            _ => SubscriptionLimits::UNLIMITED,
+        }
     }
 }
 
-impl<Server> StreamSelector<PublicTrades> for Bybit<Server>
+impl<Server> Bybit<Server>
 where
-    Server: ExchangeServer + Debug + Send + Sync,
+    Server: ExchangeServer,
 {
+    /// Determine the [`Bybit`] topic subscribed to for the provided channel & market (eg/
+    /// "publicTrade.BTCUSDT").
+    fn topic(channel: &str, market: &str) -> String {
+        match (Self::ID, channel) {
+            // Bybit option public trades are streamed per base coin (eg/ publicTrade.BTC)
+            (ExchangeId::BybitOptions, channel) if channel == BybitChannel::TRADES.0 => format!(
+                "{}.{}",
+                channel,
+                market.split('-').next().unwrap_or_default()
+            ),
+            // Bybit insurance & ADL alerts are streamed per settle coin (eg/ insurance.USDT)
+            (_, channel) if BybitChannel::is_settle_coin_channel(channel) => channel.to_owned(),
+            (_, channel) => format!("{channel}.{market}"),
+        }
+    }
+}
+
+impl StreamSelector<PublicTrades> for BybitSpot {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BybitMessage>>;
 }
 
+impl StreamSelector<PublicTrades> for BybitFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BybitMessage>>;
+}
+
+impl StreamSelector<InsuranceFunds> for BybitFuturesUsd {
+    type Stream = ExchangeWsStream<BybitInsuranceFunds>;
+}
+
+impl StreamSelector<AdlIndicators> for BybitFuturesUsd {
+    type Stream = ExchangeWsStream<BybitAdlIndicators>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<UserTrades> for BybitSpot {
+    type Stream = PrivateWsStream<AccountTransformer<Self, UserTrades, BybitExecutions>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<UserTrades> for BybitFuturesUsd {
+    type Stream = PrivateWsStream<AccountTransformer<Self, UserTrades, BybitExecutions>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<OrderUpdates> for BybitSpot {
+    type Stream = PrivateWsStream<AccountTransformer<Self, OrderUpdates, BybitOrders>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<OrderUpdates> for BybitFuturesUsd {
+    type Stream = PrivateWsStream<AccountTransformer<Self, OrderUpdates, BybitOrders>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<Balances> for BybitSpot {
+    type Stream = PrivateWsStream<BalanceTransformer<Self, BybitWallets>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<Balances> for BybitFuturesUsd {
+    type Stream = PrivateWsStream<BalanceTransformer<Self, BybitWallets>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<Positions> for BybitFuturesUsd {
+    type Stream = PrivateWsStream<AccountTransformer<Self, Positions, BybitPositions>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
 where
     Server: ExchangeServer,
@@ -144,3 +307,36 @@ where
         serializer.serialize_str(exchange_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Identifier;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_bybit_spot_batched_requests() {
+        let exchange_subs = (0..25)
+            .map(|index| {
+                ExchangeSub::from((
+                    BybitChannel::TRADES,
+                    BybitMarket(format!("COIN{index}USDT")),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let map = exchange_subs
+            .iter()
+            .map(|sub| (sub.id(), instrument.clone()))
+            .collect::<Map<Instrument>>();
+
+        // BybitSpot topics are batched at most 10 per subscribe request
+        let requests = BybitSpot::requests(exchange_subs);
+        assert_eq!(requests.len(), 3);
+        assert_eq!(BybitSpot::expected_responses(&map), 3);
+
+        // Other Bybit servers batch every topic into one subscribe request
+        assert_eq!(BybitFuturesUsd::expected_responses(&map), 1);
+    }
+}
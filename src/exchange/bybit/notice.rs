@@ -0,0 +1,101 @@
+use super::Bybit;
+use crate::{
+    exchange::ExchangeServer,
+    notice::{Notice, NoticeKind, NoticeParser},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`Bybit`] WebSocket failure response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+/// ```json
+/// {
+///     "success": false,
+///     "ret_msg": "error:handler not found,topic:publicTrade.GIBBERISH",
+///     "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+///     "req_id": "",
+///     "op": "subscribe"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitNotice {
+    pub success: bool,
+    pub ret_msg: String,
+    #[serde(default)]
+    pub op: String,
+}
+
+impl<Server> NoticeParser for Bybit<Server>
+where
+    Server: ExchangeServer,
+{
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let notice = serde_json::from_str::<BybitNotice>(payload).ok()?;
+        if notice.success {
+            return None;
+        }
+
+        // Bybit failure responses are only identified by their message
+        let message = notice.ret_msg.to_lowercase();
+        let kind = if message.contains("too many") || message.contains("frequen") {
+            NoticeKind::RateLimit
+        } else if notice.op == "auth" || message.contains("auth") || message.contains("sign") {
+            NoticeKind::Unauthorised
+        } else {
+            NoticeKind::InvalidRequest
+        };
+
+        Some(Notice::new(kind, None::<String>, notice.ret_msg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bybit::spot::BybitSpot;
+
+    #[test]
+    fn test_bybit_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: invalid topic failure
+            TestCase {
+                input: r#"
+                {
+                    "success": false, "ret_msg": "error:handler not found,topic:publicTrade.X",
+                    "conn_id": "2324d924", "req_id": "", "op": "subscribe"
+                }
+                "#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    None::<String>,
+                    "error:handler not found,topic:publicTrade.X",
+                )),
+            },
+            // TC1: authentication failure
+            TestCase {
+                input: r#"{"success": false, "ret_msg": "error sign!", "op": "auth"}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::Unauthorised,
+                    None::<String>,
+                    "error sign!",
+                )),
+            },
+            // TC2: subscription success is not a notice
+            TestCase {
+                input: r#"{"success": true, "ret_msg": "subscribe", "op": "subscribe"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BybitSpot::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
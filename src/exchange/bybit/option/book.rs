@@ -0,0 +1,291 @@
+use crate::{
+    error::DataError,
+    exchange::bybit::{message::BybitPayload, subscription::BybitResponse},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{BookUpdate, InstrumentOrderBook, OrderBookUpdater},
+    Identifier, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::model::{instrument::Instrument, Side, SubscriptionId};
+use serde::{Deserialize, Serialize};
+
+/// [`BybitOptions`](super::BybitOptions) OrderBook Level2 WebSocket message, or a
+/// [`BybitResponse`] (eg/ pong) received over the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitOptionBookMessage {
+    Response(BybitResponse),
+    Book(BybitPayload<BybitOrderBookL2>),
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitOptionBookMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitOptionBookMessage::Book(book) => Some(book.subscription_id.clone()),
+            BybitOptionBookMessage::Response(_) => None,
+        }
+    }
+}
+
+/// [`BybitOptions`](super::BybitOptions) OrderBook Level2 snapshot or delta.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.25.BTC-30JUN23-20000-C",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTC-30JUN23-20000-C",
+///         "b": [["1500", "2.5"]],
+///         "a": [["1600", "1.2"]],
+///         "u": 177400507,
+///         "seq": 66544703342
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2 {
+    #[serde(rename = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<BybitLevel>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+}
+
+/// [`Bybit`](super::super::Bybit) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["1500", "2.5"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Bybit`](super::super::Bybit) [`BybitServerOptions`](super::BybitServerOptions)
+/// [`OrderBookUpdater`].
+///
+/// Bybit: How To Manage A Local OrderBook
+///
+/// 1. After subscribing, a "snapshot" message containing the entire OrderBook is received.
+/// 2. Subsequent "delta" messages contain the absolute quantity for a price level.
+/// 3. If the quantity is 0, remove the price level.
+/// 4. Each delta update_id should be equal to the previous update_id + 1.
+/// 5. A new "snapshot" (eg/ after a service restart) replaces the entire local OrderBook.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BybitOptionBookUpdater {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitOptionBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BybitOptionBookMessage;
+
+    async fn init<Exchange, Kind>(
+        _: OutboundTx,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Bybit sends the initial OrderBook snapshot over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self {
+                updates_processed: 0,
+                last_update_id: 0,
+            },
+            book: OrderBook {
+                last_update_time: chrono::Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                sequence: None,
+                delta: false,
+            },
+        })
+    }
+
+    fn apply(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<BookUpdate>, DataError> {
+        let BybitOptionBookMessage::Book(update) = update else {
+            return Ok(None);
+        };
+
+        let applied = match update.r#type.as_str() {
+            // 1. & 5. Snapshot replaces the entire local OrderBook
+            "snapshot" => {
+                book.bids = OrderBookSide::new(Side::Buy, update.data.bids);
+                book.asks = OrderBookSide::new(Side::Sell, update.data.asks);
+                BookUpdate::Snapshot
+            }
+            // 2. - 4. Delta upserts price levels, validating the update_id sequence
+            _ => {
+                if self.updates_processed == 0 || update.data.update_id != self.last_update_id + 1 {
+                    return Err(DataError::InvalidSequence {
+                        prev_last_update_id: self.last_update_id,
+                        first_update_id: update.data.update_id,
+                    });
+                }
+                let bids = update
+                    .data
+                    .bids
+                    .into_iter()
+                    .map(Level::from)
+                    .collect::<Vec<_>>();
+                let asks = update
+                    .data
+                    .asks
+                    .into_iter()
+                    .map(Level::from)
+                    .collect::<Vec<_>>();
+                book.bids.upsert(bids.iter().copied());
+                book.asks.upsert(asks.iter().copied());
+                BookUpdate::Delta { bids, asks }
+            }
+        };
+
+        // Update OrderBook metadata & OrderBookUpdater metadata
+        book.last_update_time = update.time;
+        book.sequence = Some(update.data.update_id);
+        self.updates_processed += 1;
+        self.last_update_id = update.data.update_id;
+
+        Ok(Some(applied))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_option_book_message() {
+            let input = r#"
+            {
+                "topic": "orderbook.25.BTC-30JUN23-20000-C",
+                "type": "snapshot",
+                "ts": 1672304484978,
+                "data": {
+                    "s": "BTC-30JUN23-20000-C",
+                    "b": [["1500", "2.5"]],
+                    "a": [["1600", "1.2"]],
+                    "u": 177400507,
+                    "seq": 66544703342
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<BybitOptionBookMessage>(input).unwrap();
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("orderbook.25|BTC-30JUN23-20000-C"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        let message = |kind: &str, update_id: u64, bids: Vec<(f64, f64)>| {
+            BybitOptionBookMessage::Book(BybitPayload {
+                subscription_id: SubscriptionId::from("orderbook.25|BTC-30JUN23-20000-C"),
+                r#type: kind.to_string(),
+                time: chrono::Utc::now(),
+                data: BybitOrderBookL2 {
+                    bids: bids
+                        .into_iter()
+                        .map(|(price, amount)| BybitLevel { price, amount })
+                        .collect(),
+                    asks: vec![],
+                    update_id,
+                },
+            })
+        };
+
+        struct TestCase {
+            input: BybitOptionBookMessage,
+            expected_bids: Result<Vec<Level>, ()>,
+        }
+
+        let mut updater = BybitOptionBookUpdater {
+            updates_processed: 0,
+            last_update_id: 0,
+        };
+        let mut book = OrderBook {
+            last_update_time: chrono::Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            sequence: None,
+            delta: false,
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: delta before any snapshot is an invalid sequence
+                input: message("delta", 10, vec![(1.0, 1.0)]),
+                expected_bids: Err(()),
+            },
+            TestCase {
+                // TC1: snapshot replaces the OrderBook
+                input: message("snapshot", 10, vec![(1.0, 1.0), (2.0, 1.0)]),
+                expected_bids: Ok(vec![Level::new(2.0, 1.0), Level::new(1.0, 1.0)]),
+            },
+            TestCase {
+                // TC2: sequential delta removes a level & inserts another
+                input: message("delta", 11, vec![(1.0, 0.0), (3.0, 5.0)]),
+                expected_bids: Ok(vec![Level::new(3.0, 5.0), Level::new(2.0, 1.0)]),
+            },
+            TestCase {
+                // TC3: out of sequence delta
+                input: message("delta", 13, vec![(4.0, 1.0)]),
+                expected_bids: Err(()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = updater.update(&mut book, test.input);
+            match (actual, test.expected_bids) {
+                (Ok(Some(actual)), Ok(expected)) => {
+                    assert_eq!(
+                        actual.bids,
+                        OrderBookSide::new(Side::Buy, expected),
+                        "TC{index} failed"
+                    )
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
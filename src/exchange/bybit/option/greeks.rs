@@ -0,0 +1,199 @@
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{
+        bybit::{message::BybitPayload, subscription::BybitResponse},
+        ExchangeId,
+    },
+    subscription::greeks::Greeks,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`BybitOptions`](super::BybitOptions) tickers WebSocket message, or a [`BybitResponse`]
+/// (eg/ pong) received over the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitOptionTickerMessage {
+    Response(BybitResponse),
+    Ticker(BybitPayload<BybitOptionTicker>),
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitOptionTickerMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitOptionTickerMessage::Ticker(ticker) => Some(ticker.subscription_id.clone()),
+            BybitOptionTickerMessage::Response(_) => None,
+        }
+    }
+}
+
+/// [`BybitOptions`](super::BybitOptions) option ticker, containing the contract greeks and
+/// implied volatilities.
+///
+/// Bybit sends a bid/ask implied volatility of "0" when there is no bid/ask.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+/// ```json
+/// {
+///     "id": "tickers.BTC-6JAN23-17500-C-2480334983-1672917511074",
+///     "topic": "tickers.BTC-6JAN23-17500-C",
+///     "ts": 1672917511074,
+///     "data": {
+///         "symbol": "BTC-6JAN23-17500-C",
+///         "bidPrice": "0",
+///         "bidSize": "0",
+///         "bidIv": "0",
+///         "askPrice": "10",
+///         "askSize": "5.1",
+///         "askIv": "0.514",
+///         "lastPrice": "10",
+///         "highPrice24h": "25",
+///         "lowPrice24h": "5",
+///         "markPrice": "7.86976724",
+///         "indexPrice": "16823.73",
+///         "markPriceIv": "0.4896",
+///         "underlyingPrice": "16815.1",
+///         "openInterest": "49.85",
+///         "turnover24h": "446802.8473",
+///         "volume24h": "26.55",
+///         "totalVolume": "86",
+///         "totalTurnover": "1437431",
+///         "delta": "0.047831",
+///         "gamma": "0.00021453",
+///         "vega": "0.81351067",
+///         "theta": "-19.9115368",
+///         "predictedDeliveryPrice": "0",
+///         "change24h": "-0.33333334"
+///     },
+///     "type": "snapshot"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOptionTicker {
+    pub symbol: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub bid_iv: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub ask_iv: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price_iv: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub underlying_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub delta: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub gamma: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub vega: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub theta: f64,
+}
+
+impl From<(ExchangeId, Instrument, BybitOptionTickerMessage)> for MarketIter<Greeks> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BybitOptionTickerMessage),
+    ) -> Self {
+        let BybitOptionTickerMessage::Ticker(ticker) = message else {
+            return Self(vec![]);
+        };
+
+        let non_zero = |iv: f64| (iv != 0.0).then_some(iv);
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Greeks {
+                delta: ticker.data.delta,
+                gamma: ticker.data.gamma,
+                vega: ticker.data.vega,
+                theta: ticker.data.theta,
+                mark_price: Some(ticker.data.mark_price),
+                mark_iv: ticker.data.mark_price_iv,
+                bid_iv: non_zero(ticker.data.bid_iv),
+                ask_iv: non_zero(ticker.data.ask_iv),
+                underlying_price: ticker.data.underlying_price,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_option_ticker_message() {
+            let input = r#"
+            {
+                "id": "tickers.BTC-6JAN23-17500-C-2480334983-1672917511074",
+                "topic": "tickers.BTC-6JAN23-17500-C",
+                "ts": 1672917511074,
+                "data": {
+                    "symbol": "BTC-6JAN23-17500-C",
+                    "bidPrice": "0",
+                    "bidSize": "0",
+                    "bidIv": "0",
+                    "askPrice": "10",
+                    "askSize": "5.1",
+                    "askIv": "0.514",
+                    "lastPrice": "10",
+                    "highPrice24h": "25",
+                    "lowPrice24h": "5",
+                    "markPrice": "7.86976724",
+                    "indexPrice": "16823.73",
+                    "markPriceIv": "0.4896",
+                    "underlyingPrice": "16815.1",
+                    "openInterest": "49.85",
+                    "turnover24h": "446802.8473",
+                    "volume24h": "26.55",
+                    "totalVolume": "86",
+                    "totalTurnover": "1437431",
+                    "delta": "0.047831",
+                    "gamma": "0.00021453",
+                    "vega": "0.81351067",
+                    "theta": "-19.9115368",
+                    "predictedDeliveryPrice": "0",
+                    "change24h": "-0.33333334"
+                },
+                "type": "snapshot"
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BybitOptionTickerMessage>(input).unwrap(),
+                BybitOptionTickerMessage::Ticker(BybitPayload {
+                    subscription_id: SubscriptionId::from("tickers|BTC-6JAN23-17500-C"),
+                    r#type: "snapshot".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672917511074)),
+                    data: BybitOptionTicker {
+                        symbol: "BTC-6JAN23-17500-C".to_string(),
+                        bid_iv: 0.0,
+                        ask_iv: 0.514,
+                        mark_price: 7.86976724,
+                        mark_price_iv: 0.4896,
+                        underlying_price: 16815.1,
+                        delta: 0.047831,
+                        gamma: 0.00021453,
+                        vega: 0.81351067,
+                        theta: -19.9115368,
+                    },
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use self::{
+    book::BybitOptionBookUpdater, greeks::BybitOptionTickerMessage, trade::BybitOptionTrades,
+};
+use super::{Bybit, ExchangeServer};
+use crate::{
+    exchange::{ExchangeId, StreamSelector},
+    subscription::{book::OrderBooksL2, greeks::OptionGreeks, trade::PublicTrades},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream,
+};
+
+/// Level 2 OrderBook types and option
+/// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
+pub mod book;
+
+/// Option ticker types yielding [`OptionGreeks`].
+pub mod greeks;
+
+/// Option public trade [`ExchangeTransformer`](crate::transformer::ExchangeTransformer).
+pub mod trade;
+
+/// [`BybitOptions`] WebSocket server base url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+pub const WEBSOCKET_BASE_URL_BYBIT_OPTIONS: &str = "wss://stream.bybit.com/v5/public/option";
+
+/// [`Bybit`](super::Bybit) options exchange.
+pub type BybitOptions = Bybit<BybitServerOptions>;
+
+/// [`Bybit`](super::Bybit) options [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BybitServerOptions;
+
+impl ExchangeServer for BybitServerOptions {
+    const ID: ExchangeId = ExchangeId::BybitOptions;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BYBIT_OPTIONS
+    }
+}
+
+impl StreamSelector<PublicTrades> for BybitOptions {
+    type Stream = ExchangeWsStream<BybitOptionTrades>;
+}
+
+impl StreamSelector<OrderBooksL2> for BybitOptions {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BybitOptionBookUpdater>>;
+}
+
+impl StreamSelector<OptionGreeks> for BybitOptions {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, OptionGreeks, BybitOptionTickerMessage>>;
+}
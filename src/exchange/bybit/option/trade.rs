@@ -0,0 +1,132 @@
+use super::BybitOptions;
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::{
+        bybit::{channel::BybitChannel, message::BybitMessage},
+        Connector,
+    },
+    subscription::{
+        trade::{PublicTrade, PublicTrades},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange},
+    Transformer,
+};
+use chrono::Utc;
+
+/// [`BybitOptions`] [`PublicTrades`] [`ExchangeTransformer`].
+///
+/// Bybit streams option public trades per base coin (eg/ "publicTrade.BTC") rather than per
+/// contract, so every trade is routed to it's subscribed [`Instrument`] using the trade symbol
+/// (eg/ "BTC-30JUN23-20000-C"). Trades of option contracts that were not subscribed to are ignored.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BybitOptionTrades {
+    instrument_map: Map<Instrument>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<BybitOptions, PublicTrades> for BybitOptionTrades {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self { instrument_map })
+    }
+}
+
+impl Transformer for BybitOptionTrades {
+    type Error = DataError;
+    type Input = BybitMessage;
+    type Output = MarketEvent<PublicTrade>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let trades = match input {
+            BybitMessage::Trade(trades) => trades,
+            BybitMessage::Response(_) => return vec![],
+        };
+
+        trades
+            .data
+            .into_iter()
+            .filter_map(|trade| {
+                // Route each trade using it's own symbol, ignoring un-subscribed contracts
+                let instrument = self
+                    .instrument_map
+                    .find_channel_market(BybitChannel::TRADES.as_ref(), &trade.market)
+                    .ok()?;
+
+                Some(Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(BybitOptions::ID),
+                    instrument,
+                    kind: PublicTrade::new(trade.id, trade.price, trade.amount, trade.side),
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_bybit_option_trades_transform() {
+        let input = r#"
+        {
+            "id": "publicTrade.BTC-1672917511074",
+            "topic": "publicTrade.BTC",
+            "ts": 1672917511074,
+            "data": [
+                {
+                    "T": 1672917511073,
+                    "s": "BTC-30JUN23-20000-C",
+                    "S": "Buy",
+                    "v": "0.1",
+                    "p": "1550",
+                    "L": "PlusTick",
+                    "i": "a9b4c3d2-0f5e-5a5d-9c0a-3bd2d1d3e5f1",
+                    "BT": false
+                },
+                {
+                    "T": 1672917511073,
+                    "s": "BTC-30JUN23-25000-P",
+                    "S": "Sell",
+                    "v": "0.2",
+                    "p": "3120",
+                    "L": "MinusTick",
+                    "i": "b9b4c3d2-0f5e-5a5d-9c0a-3bd2d1d3e5f1",
+                    "BT": false
+                }
+            ],
+            "type": "snapshot"
+        }
+        "#;
+
+        // Only subscribed to the BTC-30JUN23-20000-C contract
+        let instrument = Instrument::from(("btc", "usdc", InstrumentKind::Spot));
+        let mut transformer = BybitOptionTrades {
+            instrument_map: Map::from_iter([(
+                SubscriptionId::from("publicTrade|BTC-30JUN23-20000-C"),
+                instrument.clone(),
+            )]),
+        };
+
+        let actual = transformer.transform(serde_json::from_str::<BybitMessage>(input).unwrap());
+
+        assert_eq!(actual.len(), 1);
+        let event = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(event.instrument, instrument);
+        assert_eq!(event.kind.price, 1550.0);
+    }
+}
@@ -0,0 +1,89 @@
+use super::Bybit;
+use crate::{clock::ServerClock, exchange::ExchangeServer};
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bybit`] HTTP server time url, common to every [`Bybit`] server.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/time>
+pub const HTTP_SERVER_TIME_URL_BYBIT: &str = "https://api.bybit.com/v5/market/time";
+
+/// [`Bybit`] HTTP server time response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/time>
+/// ```json
+/// {
+///   "retCode": 0,
+///   "retMsg": "OK",
+///   "result": {
+///     "timeSecond": "1688639403",
+///     "timeNano": "1688639403423213947"
+///   },
+///   "retExtInfo": {},
+///   "time": 1688639403423
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitServerTime {
+    #[serde(
+        alias = "time",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub server_time: DateTime<Utc>,
+}
+
+#[async_trait]
+impl<Server> ServerClock for Bybit<Server>
+where
+    Server: ExchangeServer + Send + Sync,
+{
+    async fn server_time() -> Result<DateTime<Utc>, SocketError> {
+        reqwest::get(HTTP_SERVER_TIME_URL_BYBIT)
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<BybitServerTime>()
+            .await
+            .map(|time| time.server_time)
+            .map_err(SocketError::Http)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_server_time() {
+            let input = r#"
+            {
+                "retCode": 0,
+                "retMsg": "OK",
+                "result": {
+                    "timeSecond": "1688639403",
+                    "timeNano": "1688639403423213947"
+                },
+                "retExtInfo": {},
+                "time": 1688639403423
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BybitServerTime>(input).unwrap(),
+                BybitServerTime {
+                    server_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1688639403423
+                    )),
+                }
+            );
+        }
+    }
+}
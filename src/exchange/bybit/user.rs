@@ -0,0 +1,816 @@
+use super::{channel::BybitChannel, market::BybitMarket, subscription::BybitResponse, Bybit};
+use crate::{
+    credentials::{Credentials, SignatureMethod},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{
+        subscription::{subscription_id, ExchangeSub},
+        ExchangeId, ExchangeServer, PrivateConnector, PrivateSession,
+    },
+    subscription::{
+        account::{Balance, ExecutionEvent, OrderStatus, Position},
+        trade::UserTrade,
+        Map,
+    },
+    transformer::{account::AccountMessage, balance::BalanceUpdate},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{symbol::Symbol, Instrument},
+        Exchange, Side, SubscriptionId,
+    },
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// [`Bybit`] private WebSocket server base url, serving account streams for every category.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+pub const WEBSOCKET_BASE_URL_BYBIT_PRIVATE: &str = "wss://stream.bybit.com/v5/private";
+
+/// Milliseconds from now that a [`Bybit`] auth request signature remains valid for.
+const AUTH_EXPIRY_WINDOW_MS: i64 = 10_000;
+
+#[async_trait]
+impl<Server> PrivateConnector for Bybit<Server>
+where
+    Server: ExchangeServer + Sync,
+{
+    /// Sign an "auth" request that is sent once connected to the private server.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#authentication>
+    async fn authenticate(credentials: &Credentials) -> Result<PrivateSession, SocketError> {
+        let expires = Utc::now().timestamp_millis() + AUTH_EXPIRY_WINDOW_MS;
+
+        Ok(PrivateSession {
+            url: Url::parse(WEBSOCKET_BASE_URL_BYBIT_PRIVATE).map_err(SocketError::UrlParse)?,
+            requests: vec![auth_request(credentials, expires)?],
+            keep_alive: None,
+        })
+    }
+
+    /// Private topics are not per market (eg/ "execution.spot"), so each topic is subscribed to
+    /// once.
+    fn private_requests(
+        exchange_subs: Vec<ExchangeSub<BybitChannel, BybitMarket>>,
+    ) -> Vec<WsMessage> {
+        let mut topics = exchange_subs
+            .into_iter()
+            .map(|sub| sub.channel.as_ref().to_owned())
+            .collect::<Vec<String>>();
+        topics.sort();
+        topics.dedup();
+
+        vec![WsMessage::Text(
+            serde_json::json!({
+                "op": "subscribe",
+                "args": topics
+            })
+            .to_string(),
+        )]
+    }
+
+    /// One response to the "auth" request, and one to the "subscribe" request.
+    fn private_expected_responses(_: &Map<Instrument>) -> usize {
+        2
+    }
+}
+
+/// Construct a [`Bybit`] "auth" request, signed with the [`Credentials`] secret.
+pub fn auth_request(credentials: &Credentials, expires: i64) -> Result<WsMessage, SocketError> {
+    let signature = credentials.sign(
+        SignatureMethod::HmacSha256Hex,
+        &format!("GET/realtime{expires}"),
+    )?;
+
+    Ok(WsMessage::Text(
+        serde_json::json!({
+            "op": "auth",
+            "args": [credentials.api_key, expires, signature]
+        })
+        .to_string(),
+    ))
+}
+
+/// Terse type alias for a [`Bybit`] private execution (fill) WebSocket message.
+pub type BybitExecutions = BybitUserMessage<BybitExecution>;
+
+/// Terse type alias for a [`Bybit`] private order update WebSocket message.
+pub type BybitOrders = BybitUserMessage<BybitOrder>;
+
+/// Terse type alias for a [`Bybit`] private position WebSocket message.
+pub type BybitPositions = BybitUserMessage<BybitPosition>;
+
+/// [`Bybit`] private WebSocket message supporting [`BybitUserPayload`]s, as well as responses
+/// (eg/ pongs) received over the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitUserMessage<T> {
+    Response(BybitResponse),
+    Payload(BybitUserPayload<T>),
+    Operation { op: String },
+}
+
+impl<T> AccountMessage for BybitUserMessage<T> {}
+
+impl<T> IntoIterator for BybitUserMessage<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::Payload(payload) => payload.data.into_iter(),
+            Self::Response(_) | Self::Operation { .. } => vec![].into_iter(),
+        }
+    }
+}
+
+/// [`Bybit`] private topic WebSocket message containing a batch of account updates.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/execution>
+/// ```json
+/// {
+///   "id": "592324803b2785-26fa-4214-9963-bdd4727f07be",
+///   "topic": "execution",
+///   "creationTime": 1672364174455,
+///   "data": [
+///     {
+///       "category": "linear",
+///       "symbol": "XRPUSDT",
+///       "execFee": "0.005061",
+///       "execId": "7e2ae69c-4edf-5800-a352-893d52b446aa",
+///       "execPrice": "0.3374",
+///       "execQty": "25",
+///       "execType": "Trade",
+///       "execValue": "8.435",
+///       "isMaker": false,
+///       "feeRate": "0.0006",
+///       "orderId": "f6e324ff-99c2-4e89-9739-3086e47f9381",
+///       "orderLinkId": "",
+///       "orderPrice": "0.3207",
+///       "orderQty": "25",
+///       "orderType": "Market",
+///       "side": "Sell",
+///       "execTime": "1672364174443",
+///       "seq": 4688002127
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitUserPayload<T> {
+    pub topic: String,
+    #[serde(
+        rename = "creationTime",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub creation_time: DateTime<Utc>,
+    pub data: Vec<T>,
+}
+
+/// [`Bybit`] private execution.
+///
+/// See [`BybitUserPayload`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitExecution {
+    pub category: String,
+    pub symbol: String,
+    pub exec_id: String,
+    pub order_id: String,
+    pub exec_type: String,
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub exec_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub exec_qty: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub exec_fee: f64,
+    pub is_maker: bool,
+    #[serde(deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc")]
+    pub exec_time: DateTime<Utc>,
+}
+
+impl BybitExecution {
+    /// [`Bybit`] execution type of a fill (as opposed to eg/ "Funding" or "BustTrade").
+    pub const EXEC_TYPE_TRADE: &'static str = "Trade";
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitExecution {
+    fn id(&self) -> Option<SubscriptionId> {
+        (self.exec_type == Self::EXEC_TYPE_TRADE).then(|| {
+            subscription_id(
+                format!("{}.{}", BybitChannel::EXECUTION_TOPIC, self.category),
+                &self.symbol,
+            )
+        })
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitExecution)> for MarketIter<UserTrade> {
+    fn from(
+        (exchange_id, instrument, execution): (ExchangeId, Instrument, BybitExecution),
+    ) -> Self {
+        // Bybit executions do not include the fee currency
+        Self(vec![Ok(MarketEvent {
+            exchange_time: execution.exec_time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: UserTrade {
+                id: execution.exec_id,
+                order_id: execution.order_id,
+                price: execution.exec_price,
+                amount: execution.exec_qty,
+                side: execution.side,
+                fee: execution.exec_fee,
+                fee_asset: None,
+                maker: execution.is_maker,
+            },
+        })])
+    }
+}
+
+/// [`Bybit`] private order update.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/order>
+/// ```json
+/// {
+///   "category": "spot",
+///   "symbol": "ETHUSDT",
+///   "orderId": "1466565014307389952",
+///   "orderLinkId": "1690901408253",
+///   "side": "Buy",
+///   "orderStatus": "PartiallyFilled",
+///   "price": "1850",
+///   "qty": "0.1",
+///   "cumExecQty": "0.05",
+///   "orderType": "Limit",
+///   "updatedTime": "1690901410528"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrder {
+    pub category: String,
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    pub side: Side,
+    pub order_status: String,
+    #[serde(deserialize_with = "de_bybit_f64_or_zero")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub qty: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub cum_exec_qty: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc")]
+    pub updated_time: DateTime<Utc>,
+}
+
+impl BybitOrder {
+    /// Determine the normalised [`OrderStatus`] of this [`BybitOrder`], returning `None` for
+    /// statuses without a normalised equivalent.
+    pub fn status(&self) -> Option<OrderStatus> {
+        match self.order_status.as_str() {
+            "New" | "Untriggered" | "Triggered" => Some(OrderStatus::New),
+            "PartiallyFilled" => Some(OrderStatus::PartiallyFilled),
+            "Filled" => Some(OrderStatus::Filled),
+            "Cancelled" | "PartiallyFilledCanceled" | "Deactivated" => Some(OrderStatus::Cancelled),
+            "Rejected" => Some(OrderStatus::Rejected),
+            _ => None,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitOrder {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(subscription_id(
+            format!("{}.{}", BybitChannel::ORDER_TOPIC, self.category),
+            &self.symbol,
+        ))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitOrder)> for MarketIter<ExecutionEvent> {
+    fn from((exchange_id, instrument, order): (ExchangeId, Instrument, BybitOrder)) -> Self {
+        let Some(status) = order.status() else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: order.updated_time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: ExecutionEvent {
+                order_id: order.order_id,
+                client_order_id: (!order.order_link_id.is_empty()).then_some(order.order_link_id),
+                side: order.side,
+                status,
+                price: order.price,
+                quantity: order.qty,
+                filled_quantity: order.cum_exec_qty,
+            },
+        })])
+    }
+}
+
+/// [`Bybit`] private wallet WebSocket message, yielding a [`BalanceUpdate`] for every coin.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/wallet>
+/// ```json
+/// {
+///   "id": "592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e",
+///   "topic": "wallet",
+///   "creationTime": 1700034722104,
+///   "data": [
+///     {
+///       "accountType": "UNIFIED",
+///       "coin": [
+///         {
+///           "coin": "BTC",
+///           "walletBalance": "0.01",
+///           "locked": "0.002",
+///           "availableToWithdraw": "0.008"
+///         }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct BybitWallets(pub BybitUserMessage<BybitWallet>);
+
+impl AccountMessage for BybitWallets {}
+
+impl IntoIterator for BybitWallets {
+    type Item = BalanceUpdate;
+    type IntoIter = std::vec::IntoIter<BalanceUpdate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let BybitUserMessage::Payload(payload) = self.0 else {
+            return vec![].into_iter();
+        };
+
+        payload
+            .data
+            .into_iter()
+            .flat_map(|wallet| wallet.coin)
+            .map(|coin| BalanceUpdate {
+                time: payload.creation_time,
+                balance: Balance {
+                    asset: Symbol::new(coin.coin),
+                    total: coin.wallet_balance,
+                    free: coin.wallet_balance - coin.locked,
+                },
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// [`Bybit`] private wallet of an account type (eg/ "UNIFIED").
+///
+/// See [`BybitWallets`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWallet {
+    pub account_type: String,
+    pub coin: Vec<BybitCoin>,
+}
+
+/// [`Bybit`] private wallet coin balance.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitCoin {
+    pub coin: String,
+    #[serde(deserialize_with = "de_bybit_f64_or_zero")]
+    pub wallet_balance: f64,
+    #[serde(deserialize_with = "de_bybit_f64_or_zero")]
+    pub locked: f64,
+}
+
+/// [`Bybit`] private position.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/position>
+/// ```json
+/// {
+///   "category": "linear",
+///   "symbol": "BTCUSDT",
+///   "side": "Sell",
+///   "size": "0.01",
+///   "entryPrice": "30000",
+///   "unrealisedPnl": "-1.5",
+///   "positionStatus": "Normal",
+///   "updatedTime": "1672364174449"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPosition {
+    pub category: String,
+    pub symbol: String,
+    /// Empty (or "None") once the position is closed.
+    pub side: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub size: f64,
+    #[serde(deserialize_with = "de_bybit_f64_or_zero")]
+    pub entry_price: f64,
+    #[serde(deserialize_with = "de_bybit_f64_or_zero")]
+    pub unrealised_pnl: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc")]
+    pub updated_time: DateTime<Utc>,
+}
+
+impl BybitPosition {
+    /// [`Bybit`] side of a short position.
+    pub const SIDE_SELL: &'static str = "Sell";
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitPosition {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(subscription_id(
+            format!("{}.{}", BybitChannel::POSITION_TOPIC, self.category),
+            &self.symbol,
+        ))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitPosition)> for MarketIter<Position> {
+    fn from((exchange_id, instrument, position): (ExchangeId, Instrument, BybitPosition)) -> Self {
+        let quantity = if position.side == BybitPosition::SIDE_SELL {
+            -position.size
+        } else {
+            position.size
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: position.updated_time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Position {
+                quantity,
+                entry_price: position.entry_price,
+                unrealised_pnl: position.unrealised_pnl,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`Bybit`] private numeric field, which [`Bybit`] sends as an empty string when
+/// not applicable (eg/ the price of some market orders).
+fn de_bybit_f64_or_zero<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+    if input.is_empty() {
+        Ok(0.0)
+    } else {
+        input.parse::<f64>().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_user_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: BybitExecutions,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: execution message
+                    input: r#"
+                    {
+                        "id": "592324803b2785-26fa-4214-9963-bdd4727f07be",
+                        "topic": "execution.linear",
+                        "creationTime": 1672364174455,
+                        "data": [
+                            {
+                                "category": "linear", "symbol": "XRPUSDT", "execFee": "0.005061",
+                                "execId": "7e2ae69c-4edf-5800-a352-893d52b446aa",
+                                "execPrice": "0.3374", "execQty": "25", "execType": "Trade",
+                                "execValue": "8.435", "isMaker": false, "feeRate": "0.0006",
+                                "tradeIv": "", "markIv": "", "blockTradeId": "",
+                                "markPrice": "0.3391", "indexPrice": "", "underlyingPrice": "",
+                                "leavesQty": "0", "orderId": "f6e324ff-99c2-4e89-9739-3086e47f9381",
+                                "orderLinkId": "", "orderPrice": "0.3207", "orderQty": "25",
+                                "orderType": "Market", "stopOrderType": "UNKNOWN", "side": "Sell",
+                                "execTime": "1672364174443", "isLeverage": "0", "closedSize": "",
+                                "seq": 4688002127
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: BybitUserMessage::Payload(BybitUserPayload {
+                        topic: "execution.linear".to_string(),
+                        creation_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672364174455,
+                        )),
+                        data: vec![BybitExecution {
+                            category: "linear".to_string(),
+                            symbol: "XRPUSDT".to_string(),
+                            exec_id: "7e2ae69c-4edf-5800-a352-893d52b446aa".to_string(),
+                            order_id: "f6e324ff-99c2-4e89-9739-3086e47f9381".to_string(),
+                            exec_type: "Trade".to_string(),
+                            side: Side::Sell,
+                            exec_price: 0.3374,
+                            exec_qty: 25.0,
+                            exec_fee: 0.005061,
+                            is_maker: false,
+                            exec_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672364174443,
+                            )),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: private pong
+                    input: r#"
+                    {
+                        "req_id": "",
+                        "op": "pong",
+                        "args": ["1675418560633"],
+                        "conn_id": "cfcb4ocsvfriu23r3er0-1b"
+                    }
+                    "#,
+                    expected: BybitUserMessage::Operation {
+                        op: "pong".to_string(),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitExecutions>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_bybit_wallets() {
+            let input = r#"
+            {
+                "id": "592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e",
+                "topic": "wallet",
+                "creationTime": 1700034722104,
+                "data": [
+                    {
+                        "accountType": "UNIFIED", "accountIMRate": "0", "totalEquity": "3.3",
+                        "coin": [
+                            {
+                                "coin": "BTC", "equity": "1.5", "usdValue": "45000",
+                                "walletBalance": "1.5", "locked": "0.5",
+                                "availableToWithdraw": "1", "unrealisedPnl": "0"
+                            },
+                            {
+                                "coin": "USDT", "equity": "100", "usdValue": "100",
+                                "walletBalance": "100", "locked": "",
+                                "availableToWithdraw": "", "unrealisedPnl": "0"
+                            }
+                        ]
+                    }
+                ]
+            }
+            "#;
+
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1700034722104));
+            assert_eq!(
+                serde_json::from_str::<BybitWallets>(input)
+                    .unwrap()
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                vec![
+                    BalanceUpdate {
+                        time,
+                        balance: Balance {
+                            asset: Symbol::new("btc"),
+                            total: 1.5,
+                            free: 1.0,
+                        },
+                    },
+                    BalanceUpdate {
+                        time,
+                        balance: Balance {
+                            asset: Symbol::new("usdt"),
+                            total: 100.0,
+                            free: 100.0,
+                        },
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_bybit_orders() {
+            let input = r#"
+            {
+                "id": "5923240c6880ab-c59f-420b-9adb-3639adc9dd90",
+                "topic": "order.spot",
+                "creationTime": 1690901410529,
+                "data": [
+                    {
+                        "category": "spot", "symbol": "ETHUSDT",
+                        "orderId": "1466565014307389952", "orderLinkId": "1690901408253",
+                        "side": "Buy", "orderStatus": "PartiallyFilled", "price": "1850",
+                        "qty": "0.1", "cumExecQty": "0.05", "orderType": "Limit",
+                        "timeInForce": "GTC", "updatedTime": "1690901410528"
+                    },
+                    {
+                        "category": "spot", "symbol": "ETHUSDT",
+                        "orderId": "1466565014307389953", "orderLinkId": "",
+                        "side": "Sell", "orderStatus": "Filled", "price": "",
+                        "qty": "0.1", "cumExecQty": "0.1", "orderType": "Market",
+                        "timeInForce": "IOC", "updatedTime": "1690901410530"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BybitOrders>(input)
+                    .unwrap()
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                vec![
+                    BybitOrder {
+                        category: "spot".to_string(),
+                        symbol: "ETHUSDT".to_string(),
+                        order_id: "1466565014307389952".to_string(),
+                        order_link_id: "1690901408253".to_string(),
+                        side: Side::Buy,
+                        order_status: "PartiallyFilled".to_string(),
+                        price: 1850.0,
+                        qty: 0.1,
+                        cum_exec_qty: 0.05,
+                        updated_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1690901410528,
+                        )),
+                    },
+                    BybitOrder {
+                        category: "spot".to_string(),
+                        symbol: "ETHUSDT".to_string(),
+                        order_id: "1466565014307389953".to_string(),
+                        order_link_id: "".to_string(),
+                        side: Side::Sell,
+                        order_status: "Filled".to_string(),
+                        price: 0.0,
+                        qty: 0.1,
+                        cum_exec_qty: 0.1,
+                        updated_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1690901410530,
+                        )),
+                    },
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_bybit_position_market_iter() {
+        struct TestCase {
+            side: &'static str,
+            size: f64,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: long position
+                side: "Buy",
+                size: 0.01,
+                expected: 0.01,
+            },
+            TestCase {
+                // TC1: short position
+                side: "Sell",
+                size: 0.01,
+                expected: -0.01,
+            },
+            TestCase {
+                // TC2: closed position
+                side: "",
+                size: 0.0,
+                expected: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let position = BybitPosition {
+                category: "linear".to_string(),
+                symbol: "BTCUSDT".to_string(),
+                side: test.side.to_string(),
+                size: test.size,
+                entry_price: 30000.0,
+                unrealised_pnl: -1.5,
+                updated_time: Utc::now(),
+            };
+            assert_eq!(
+                position.id(),
+                Some(SubscriptionId::from("position.linear|BTCUSDT")),
+                "TC{} failed",
+                index
+            );
+
+            let actual = MarketIter::<Position>::from((
+                ExchangeId::BybitFuturesUsd,
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                position,
+            ))
+            .0
+            .remove(0)
+            .unwrap()
+            .kind;
+            assert_eq!(actual.quantity, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bybit_order_status() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<OrderStatus>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: conditional order triggered
+                input: "Triggered",
+                expected: Some(OrderStatus::New),
+            },
+            TestCase {
+                // TC1: partially filled then cancelled
+                input: "PartiallyFilledCanceled",
+                expected: Some(OrderStatus::Cancelled),
+            },
+            TestCase {
+                // TC2: unknown status
+                input: "Unknown",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let order = BybitOrder {
+                category: "linear".to_string(),
+                symbol: "BTCUSDT".to_string(),
+                order_id: "order".to_string(),
+                order_link_id: "".to_string(),
+                side: Side::Buy,
+                order_status: test.input.to_string(),
+                price: 30000.0,
+                qty: 1.0,
+                cum_exec_qty: 0.0,
+                updated_time: Utc::now(),
+            };
+            assert_eq!(order.status(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_auth_request() {
+        let credentials = Credentials::new("api_key", "secret");
+
+        let WsMessage::Text(actual) = auth_request(&credentials, 1662350400000).unwrap() else {
+            panic!("auth request is not a WsMessage::Text");
+        };
+
+        let actual = serde_json::from_str::<serde_json::Value>(&actual).unwrap();
+        assert_eq!(actual["op"], "auth");
+        assert_eq!(actual["args"][0], "api_key");
+        assert_eq!(actual["args"][1], 1662350400000i64);
+
+        // Signature is a hex encoded HMAC-SHA256 of "GET/realtime{expires}"
+        assert_eq!(
+            actual["args"][2],
+            "d7ca36fea9ef1287007fd4b15af961e91d419a3d3f3ccbdf23585170ac116cd4"
+        );
+    }
+}
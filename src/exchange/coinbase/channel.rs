@@ -1,6 +1,6 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{status::SystemStatus, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,17 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] heartbeat channel, subscribed to alongside [`Self::TRADES`] for liveness and
+    /// trade id gap detection.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#heartbeat-channel>
+    pub const HEARTBEAT: Self = Self("heartbeat");
+
+    /// [`Coinbase`] status channel, yielding the trading status of every product.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#status-channel>
+    pub const STATUS: Self = Self("status");
 }
 
 impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
@@ -25,6 +36,12 @@ impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
     }
 }
 
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, SystemStatus> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::STATUS
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
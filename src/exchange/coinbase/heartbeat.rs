@@ -0,0 +1,273 @@
+use super::{
+    trade::{de_trade_subscription_id, CoinbaseTrade},
+    Coinbase,
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::Connector,
+    subscription::{
+        route::RoutingTable,
+        trade::{PublicTrade, PublicTrades},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Coinbase real-time heartbeat WebSocket message, sent every second for each product subscribed
+/// to the `heartbeat` channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#heartbeat-channel>
+/// ```json
+/// {
+///     "type": "heartbeat",
+///     "sequence": 90,
+///     "last_trade_id": 20,
+///     "product_id": "BTC-USD",
+///     "time": "2014-11-07T08:19:28.464459Z"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseHeartbeat {
+    #[serde(alias = "product_id", deserialize_with = "de_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub sequence: u64,
+    pub last_trade_id: u64,
+    pub time: DateTime<Utc>,
+}
+
+/// Coinbase WebSocket message received by a [`PublicTrades`] connection, which subscribes to
+/// both the `matches` & `heartbeat` channels of each product.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseTradeMessage {
+    #[serde(alias = "last_match")]
+    Match(CoinbaseTrade),
+    Heartbeat(CoinbaseHeartbeat),
+}
+
+/// [`Coinbase`] [`PublicTrades`] [`ExchangeTransformer`] that tracks the last trade id of each
+/// product, using the `heartbeat` channel for liveness between trades.
+///
+/// Trade ids are sequential per product, so a [`DataError::TradeGap`] is yielded if a match skips
+/// trade ids, or a heartbeat reports a `last_trade_id` beyond the last match received. The
+/// consumer loop forwards each gap to the
+/// [`ReconcileTrigger`](crate::streams::reconcile::ReconcileTrigger) of the connection (if any),
+/// so the missed matches are backfilled via REST.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CoinbaseTradeTransformer {
+    instrument_map: RoutingTable<Instrument>,
+    last_trade_ids: HashMap<SubscriptionId, u64>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Coinbase, PublicTrades> for CoinbaseTradeTransformer {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map: RoutingTable::from(instrument_map),
+            last_trade_ids: HashMap::new(),
+        })
+    }
+}
+
+impl Transformer for CoinbaseTradeTransformer {
+    type Error = DataError;
+    type Input = CoinbaseTradeMessage;
+    type Output = MarketEvent<PublicTrade>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let (subscription_id, trade_id) = match &input {
+            CoinbaseTradeMessage::Match(trade) => (&trade.subscription_id, trade.id),
+            CoinbaseTradeMessage::Heartbeat(heartbeat) => {
+                (&heartbeat.subscription_id, heartbeat.last_trade_id)
+            }
+        };
+
+        let instrument = match self.instrument_map.find(subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // A match follows on from the previous trade id, whereas a heartbeat reports it
+        let last_trade_id = self.last_trade_ids.get(subscription_id).copied();
+        let expected_trade_id = match &input {
+            CoinbaseTradeMessage::Match(_) => last_trade_id.map(|last| last + 1),
+            CoinbaseTradeMessage::Heartbeat(_) => last_trade_id,
+        };
+
+        let gap = match (last_trade_id, expected_trade_id) {
+            (Some(last_trade_id), Some(expected)) if trade_id > expected => {
+                let missed = trade_id - expected;
+                warn!(
+                    exchange = %Coinbase::ID,
+                    %instrument,
+                    last_trade_id,
+                    missed,
+                    "detected Coinbase trade id gap"
+                );
+                Some(DataError::TradeGap {
+                    exchange: Coinbase::ID,
+                    instrument: instrument.clone(),
+                    last_trade_id,
+                    missed,
+                })
+            }
+            _ => None,
+        };
+
+        if last_trade_id.is_none_or(|last| trade_id > last) {
+            self.last_trade_ids
+                .insert(subscription_id.clone(), trade_id);
+        }
+
+        let events = match input {
+            CoinbaseTradeMessage::Match(trade) => {
+                MarketIter::<PublicTrade>::from((Coinbase::ID, instrument, trade)).0
+            }
+            CoinbaseTradeMessage::Heartbeat(_) => Vec::new(),
+        };
+
+        gap.map(Err).into_iter().chain(events).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{subscription::trade::TradeId, OutboundQueue};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_de_coinbase_trade_message() {
+            let input = r#"
+            {
+                "type": "heartbeat", "sequence": 90, "last_trade_id": 20,
+                "product_id": "BTC-USD", "time": "2014-11-07T08:19:28.464459Z"
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<CoinbaseTradeMessage>(input).unwrap(),
+                CoinbaseTradeMessage::Heartbeat(CoinbaseHeartbeat {
+                    subscription_id: SubscriptionId::from("matches|BTC-USD"),
+                    sequence: 90,
+                    last_trade_id: 20,
+                    time: "2014-11-07T08:19:28.464459Z".parse().unwrap(),
+                })
+            );
+
+            let input = r#"
+            {
+                "type": "last_match", "trade_id": 10, "sequence": 50,
+                "time": "2014-11-07T08:19:27.028459Z",
+                "product_id": "BTC-USD", "size": "5.23512", "price": "400.23", "side": "sell"
+            }
+            "#;
+
+            assert!(matches!(
+                serde_json::from_str::<CoinbaseTradeMessage>(input).unwrap(),
+                CoinbaseTradeMessage::Match(CoinbaseTrade { id: 10, .. })
+            ));
+        }
+    }
+
+    fn trade(id: u64) -> CoinbaseTradeMessage {
+        serde_json::from_str(&format!(
+            r#"{{"type":"match","trade_id":{id},"time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"1.0","price":"400.0","side":"sell"}}"#
+        ))
+        .unwrap()
+    }
+
+    fn heartbeat(last_trade_id: u64) -> CoinbaseTradeMessage {
+        serde_json::from_str(&format!(
+            r#"{{"type":"heartbeat","sequence":1,"last_trade_id":{last_trade_id},"product_id":"BTC-USD","time":"2014-11-07T08:19:28.464459Z"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_coinbase_trade_transformer() {
+        struct TestCase {
+            input: CoinbaseTradeMessage,
+            // Trade ids yielded, and the number of trades missed by any gap
+            expected: (Vec<u64>, Option<u64>),
+        }
+
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let (outbound_tx, _) = OutboundQueue::new();
+        let mut transformer =
+            <CoinbaseTradeTransformer as ExchangeTransformer<Coinbase, PublicTrades>>::new(
+                outbound_tx,
+                Map::from_iter([(SubscriptionId::from("matches|BTC-USD"), instrument)]),
+            )
+            .await
+            .unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: first heartbeat initialises the last trade id
+                input: heartbeat(10),
+                expected: (vec![], None),
+            },
+            TestCase {
+                // TC1: match following on from the heartbeat
+                input: trade(11),
+                expected: (vec![11], None),
+            },
+            TestCase {
+                // TC2: heartbeat without new trades
+                input: heartbeat(11),
+                expected: (vec![], None),
+            },
+            TestCase {
+                // TC3: match skipping trade ids 12 & 13
+                input: trade(14),
+                expected: (vec![14], Some(2)),
+            },
+            TestCase {
+                // TC4: heartbeat reporting trade ids 15 & 16 were missed
+                input: heartbeat(16),
+                expected: (vec![], Some(2)),
+            },
+            TestCase {
+                // TC5: stale match is still yielded, without a gap
+                input: trade(15),
+                expected: (vec![15], None),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let output = transformer.transform(test.input);
+
+            let trade_ids = output
+                .iter()
+                .filter_map(|result| result.as_ref().ok())
+                .map(|event| match event.kind.id {
+                    TradeId::U64(id) => id,
+                    _ => panic!("TC{index} failed: unexpected TradeId"),
+                })
+                .collect::<Vec<_>>();
+            let missed = output.iter().find_map(|result| match result {
+                Err(DataError::TradeGap { missed, .. }) => Some(*missed),
+                _ => None,
+            });
+
+            assert_eq!((trade_ids, missed), test.expected, "TC{} failed", index);
+        }
+    }
+}
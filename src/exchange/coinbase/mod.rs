@@ -1,19 +1,25 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
-    trade::CoinbaseTrade,
+    channel::CoinbaseChannel, heartbeat::CoinbaseTradeTransformer, market::CoinbaseMarket,
+    status::CoinbaseStatus, subscription::CoinbaseSubResponse,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{status::SystemStatus, trade::PublicTrades, Map},
+    transformer::status::StatusTransformer,
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Coinbase error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -26,14 +32,30 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Coinbase`].
 pub mod subscription;
 
+/// Product status types for [`Coinbase`].
+pub mod status;
+
 /// Public trade types for [`Coinbase`].
 pub mod trade;
 
+/// Heartbeat types and the trade id gap detecting [`PublicTrades`]
+/// [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) for [`Coinbase`].
+pub mod heartbeat;
+
+/// HTTP recent trades [`RecentTrades`](crate::streams::reconcile::RecentTrades) implementation
+/// for [`Coinbase`].
+pub mod rest_trade;
+
 /// [`Coinbase`] server base url.
 ///
 /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview>
 pub const BASE_URL_COINBASE: &str = "wss://ws-feed.exchange.coinbase.com";
 
+/// [`Coinbase`] HTTP recent trades url.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducttrades>
+pub const HTTP_TRADES_URL_COINBASE: &str = "https://api.exchange.coinbase.com/products";
+
 /// [`Coinbase`] exchange.
 ///
 /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview>
@@ -54,23 +76,65 @@ impl Connector for Coinbase {
         Url::parse(BASE_URL_COINBASE).map_err(SocketError::UrlParse)
     }
 
+    /// The status channel yields the status of every product, so every product shares one
+    /// status subscription.
+    ///
+    /// Trade subscriptions also subscribe to the heartbeat channel of the product, whose
+    /// messages are routed to the same trades [`Subscription`](crate::subscription::Subscription)
+    /// for trade id gap detection.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        let (status_subs, exchange_subs): (Vec<_>, Vec<_>) = exchange_subs
+            .into_iter()
+            .partition(|sub| sub.channel == CoinbaseChannel::STATUS);
+
+        let status_request = (!status_subs.is_empty()).then(|| {
+            WsMessage::Text(
+                json!({
+                    "type": "subscribe",
+                    "channels": [CoinbaseChannel::STATUS.as_ref()],
+                })
+                .to_string(),
+            )
+        });
+
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                let channels = if channel == CoinbaseChannel::TRADES {
+                    vec![channel.as_ref(), CoinbaseChannel::HEARTBEAT.as_ref()]
+                } else {
+                    vec![channel.as_ref()]
+                };
+
                 WsMessage::Text(
                     json!({
                         "type": "subscribe",
                         "product_ids": [market.as_ref()],
-                        "channels": [channel.as_ref()],
+                        "channels": channels,
                     })
                     .to_string(),
                 )
             })
+            .chain(status_request)
             .collect()
     }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        let status_prefix = format!("{}|", CoinbaseChannel::STATUS.as_ref());
+        let num_status_subs = map
+            .0
+            .keys()
+            .filter(|id| id.as_ref().starts_with(&status_prefix))
+            .count();
+
+        map.0.len() - num_status_subs + usize::from(num_status_subs > 0)
+    }
 }
 
 impl StreamSelector<PublicTrades> for Coinbase {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, CoinbaseTrade>>;
+    type Stream = ExchangeWsStream<CoinbaseTradeTransformer>;
+}
+
+impl StreamSelector<SystemStatus> for Coinbase {
+    type Stream = ExchangeWsStream<StatusTransformer<Self, CoinbaseStatus>>;
 }
@@ -0,0 +1,78 @@
+use super::Coinbase;
+use crate::notice::{Notice, NoticeKind, NoticeParser};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`] WebSocket error message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview#errors>
+/// ```json
+/// {"type": "error", "message": "Failed to subscribe", "reason": "GIBBERISH-USD is not a valid product"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CoinbaseNotice {
+    Error {
+        message: String,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
+impl NoticeParser for Coinbase {
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let CoinbaseNotice::Error { message, reason } = serde_json::from_str(payload).ok()?;
+
+        let message = match reason {
+            Some(reason) => format!("{message}: {reason}"),
+            None => message,
+        };
+
+        // Coinbase errors are only identified by their message
+        let lowercase = message.to_lowercase();
+        let kind = if lowercase.contains("rate limit") || lowercase.contains("too many") {
+            NoticeKind::RateLimit
+        } else if lowercase.contains("auth") || lowercase.contains("signature") {
+            NoticeKind::Unauthorised
+        } else {
+            NoticeKind::InvalidRequest
+        };
+
+        Some(Notice::new(kind, None::<String>, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coinbase_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: invalid product error
+            TestCase {
+                input: r#"{"type": "error", "message": "Failed to subscribe", "reason": "GIBBERISH-USD is not a valid product"}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    None::<String>,
+                    "Failed to subscribe: GIBBERISH-USD is not a valid product",
+                )),
+            },
+            // TC1: subscriptions response is not a notice
+            TestCase {
+                input: r#"{"type": "subscriptions", "channels": []}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Coinbase::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::{Coinbase, HTTP_TRADES_URL_COINBASE};
+use crate::{
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::Connector,
+    streams::reconcile::RecentTrades,
+    subscription::trade::PublicTrade,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange, Side},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of recent trades requested from the [`Coinbase`] HTTP recent trades endpoint.
+pub const RECENT_TRADES_LIMIT: u16 = 1000;
+
+/// [`Coinbase`] HTTP recent trade.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducttrades>
+/// ```json
+/// {
+///     "time": "2014-11-07T22:19:28.578544Z",
+///     "trade_id": 74,
+///     "price": "10.00000000",
+///     "size": "0.01000000",
+///     "side": "buy"
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseRestTrade {
+    #[serde(alias = "trade_id")]
+    pub id: u64,
+    pub time: DateTime<Utc>,
+    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    pub side: Side,
+}
+
+impl From<CoinbaseRestTrade> for PublicTrade {
+    fn from(trade: CoinbaseRestTrade) -> Self {
+        // Coinbase trade "side" is the side of the maker order, so the aggressor is the opposite
+        let side = match trade.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+
+        Self {
+            buyer_is_maker: Some(trade.side == Side::Buy),
+            ..Self::new(trade.id, trade.price, trade.amount, side)
+        }
+    }
+}
+
+#[async_trait]
+impl RecentTrades for Coinbase {
+    async fn recent_trades(
+        instrument: &Instrument,
+    ) -> Result<Vec<MarketEvent<PublicTrade>>, SocketError> {
+        let market = format!("{}-{}", instrument.base, instrument.quote).to_uppercase();
+
+        // Coinbase rejects HTTP requests without a User-Agent
+        let trades = reqwest::Client::new()
+            .get(format!("{HTTP_TRADES_URL_COINBASE}/{market}/trades"))
+            .header(reqwest::header::USER_AGENT, "barter-data")
+            .query(&[("limit", RECENT_TRADES_LIMIT)])
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<Vec<CoinbaseRestTrade>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(trades
+            .into_iter()
+            .map(|trade| MarketEvent {
+                exchange_time: trade.time,
+                received_time: Utc::now(),
+                received_monotonic: MonotonicTime::now(),
+                origin: Origin::Backfill,
+                exchange: Exchange::from(Self::ID),
+                instrument: instrument.clone(),
+                kind: PublicTrade::from(trade),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_rest_trades() {
+            let input = r#"
+            [
+                {
+                    "time": "2014-11-07T22:19:28.578544Z", "trade_id": 74,
+                    "price": "10.00000000", "size": "0.01000000", "side": "buy"
+                },
+                {
+                    "time": "2014-11-07T01:08:43.642366Z", "trade_id": 73,
+                    "price": "100.00000000", "size": "0.01000000", "side": "sell"
+                }
+            ]
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<Vec<CoinbaseRestTrade>>(input).unwrap(),
+                vec![
+                    CoinbaseRestTrade {
+                        id: 74,
+                        time: "2014-11-07T22:19:28.578544Z".parse().unwrap(),
+                        amount: 0.01,
+                        price: 10.0,
+                        side: Side::Buy,
+                    },
+                    CoinbaseRestTrade {
+                        id: 73,
+                        time: "2014-11-07T01:08:43.642366Z".parse().unwrap(),
+                        amount: 0.01,
+                        price: 100.0,
+                        side: Side::Sell,
+                    },
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_coinbase_rest_trade_aggressor_side() {
+        let trade = |side| CoinbaseRestTrade {
+            id: 74,
+            time: "2014-11-07T22:19:28.578544Z".parse().unwrap(),
+            amount: 0.01,
+            price: 10.0,
+            side,
+        };
+
+        // Maker "side" is inverted to the aggressor side
+        let buy_maker = PublicTrade::from(trade(Side::Buy));
+        assert_eq!(buy_maker.side, Side::Sell);
+        assert_eq!(buy_maker.buyer_is_maker, Some(true));
+
+        let sell_maker = PublicTrade::from(trade(Side::Sell));
+        assert_eq!(sell_maker.side, Side::Buy);
+        assert_eq!(sell_maker.buyer_is_maker, Some(false));
+    }
+}
@@ -0,0 +1,196 @@
+use super::CoinbaseChannel;
+use crate::{
+    exchange::ExchangeSub,
+    subscription::status::{ExchangeStatus, TradingStatus},
+    transformer::status::StatusUpdate,
+    Identifier,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::Coinbase) status WebSocket message, containing the trading status of
+/// every product.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#status-channel>
+/// ```json
+/// {
+///     "type": "status",
+///     "products": [
+///         {
+///             "id": "BTC-USD",
+///             "base_currency": "BTC",
+///             "quote_currency": "USD",
+///             "base_increment": "0.00000001",
+///             "quote_increment": "0.01",
+///             "display_name": "BTC/USD",
+///             "status": "online",
+///             "status_message": "",
+///             "min_market_funds": "1",
+///             "post_only": false,
+///             "limit_only": false,
+///             "cancel_only": false,
+///             "auction_mode": false,
+///             "type": "spot",
+///             "fx_stablecoin": false,
+///             "max_slippage_percentage": "0.02000000"
+///         }
+///     ],
+///     "currencies": []
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseStatus {
+    pub products: Vec<CoinbaseProductStatus>,
+}
+
+/// [`Coinbase`](super::Coinbase) product trading status contained within a [`CoinbaseStatus`]
+/// message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseProductStatus {
+    pub id: String,
+    pub status: String,
+    pub status_message: String,
+    pub post_only: bool,
+    pub limit_only: bool,
+    pub cancel_only: bool,
+    #[serde(default)]
+    pub trading_disabled: bool,
+}
+
+impl From<&CoinbaseProductStatus> for TradingStatus {
+    fn from(product: &CoinbaseProductStatus) -> Self {
+        if product.status != "online" || product.trading_disabled {
+            TradingStatus::Offline
+        } else if product.cancel_only {
+            TradingStatus::CancelOnly
+        } else if product.post_only {
+            TradingStatus::PostOnly
+        } else if product.limit_only {
+            TradingStatus::LimitOnly
+        } else {
+            TradingStatus::Online
+        }
+    }
+}
+
+impl IntoIterator for CoinbaseStatus {
+    type Item = StatusUpdate;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // Coinbase status messages do not contain a timestamp
+        let time = Utc::now();
+
+        self.products
+            .into_iter()
+            .map(|product| StatusUpdate {
+                time,
+                subscription_id: Some(
+                    ExchangeSub::from((CoinbaseChannel::STATUS, product.id.as_str())).id(),
+                ),
+                status: ExchangeStatus {
+                    status: TradingStatus::from(&product),
+                    message: (!product.status_message.is_empty()).then_some(product.status_message),
+                    start_time: None,
+                    end_time: None,
+                },
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::model::SubscriptionId;
+
+        #[test]
+        fn test_coinbase_status() {
+            let input = r#"
+            {
+                "type": "status",
+                "products": [
+                    {
+                        "id": "BTC-USD",
+                        "base_currency": "BTC",
+                        "quote_currency": "USD",
+                        "display_name": "BTC/USD",
+                        "status": "online",
+                        "status_message": "",
+                        "post_only": false,
+                        "limit_only": false,
+                        "cancel_only": false,
+                        "auction_mode": false,
+                        "type": "spot"
+                    },
+                    {
+                        "id": "ETH-USD",
+                        "base_currency": "ETH",
+                        "quote_currency": "USD",
+                        "display_name": "ETH/USD",
+                        "status": "online",
+                        "status_message": "Cancel only mode",
+                        "post_only": false,
+                        "limit_only": false,
+                        "cancel_only": true,
+                        "auction_mode": false,
+                        "type": "spot"
+                    },
+                    {
+                        "id": "XYZ-USD",
+                        "base_currency": "XYZ",
+                        "quote_currency": "USD",
+                        "display_name": "XYZ/USD",
+                        "status": "delisted",
+                        "status_message": "",
+                        "post_only": false,
+                        "limit_only": false,
+                        "cancel_only": false,
+                        "auction_mode": false,
+                        "type": "spot"
+                    }
+                ],
+                "currencies": []
+            }
+            "#;
+
+            let actual = serde_json::from_str::<CoinbaseStatus>(input)
+                .unwrap()
+                .into_iter()
+                .map(|update| {
+                    (
+                        update.subscription_id.unwrap(),
+                        update.status.status,
+                        update.status.message,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                actual,
+                vec![
+                    (
+                        SubscriptionId::from("status|BTC-USD"),
+                        TradingStatus::Online,
+                        None
+                    ),
+                    (
+                        SubscriptionId::from("status|ETH-USD"),
+                        TradingStatus::CancelOnly,
+                        Some("Cancel only mode".to_string())
+                    ),
+                    (
+                        SubscriptionId::from("status|XYZ-USD"),
+                        TradingStatus::Offline,
+                        None
+                    ),
+                ]
+            );
+        }
+    }
+}
@@ -1,16 +1,19 @@
 use super::CoinbaseChannel;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{ExchangeId, ExchangeSub},
     subscription::trade::PublicTrade,
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Coinbase real-time trade WebSocket message.
 ///
+/// Note: the "side" is the side of the maker order, which is inverted to the aggressor
+/// [`PublicTrade::side`] when normalised.
+///
 /// ### Raw Payload Examples
 /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
 /// ```json
@@ -40,6 +43,10 @@ pub struct CoinbaseTrade {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
     pub price: f64,
     pub side: Side,
+    #[serde(default)]
+    pub maker_order_id: Option<String>,
+    #[serde(default)]
+    pub taker_order_id: Option<String>,
 }
 
 impl Identifier<Option<SubscriptionId>> for CoinbaseTrade {
@@ -50,16 +57,30 @@ impl Identifier<Option<SubscriptionId>> for CoinbaseTrade {
 
 impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketIter<PublicTrade> {
     fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, CoinbaseTrade)) -> Self {
+        // Coinbase match "side" is the side of the maker order, so the aggressor is the opposite
+        let buyer_is_maker = trade.side == Side::Buy;
+        let side = match trade.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        let (buyer_order_id, seller_order_id) = if buyer_is_maker {
+            (trade.maker_order_id, trade.taker_order_id)
+        } else {
+            (trade.taker_order_id, trade.maker_order_id)
+        };
+
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
-                id: trade.id.to_string(),
-                price: trade.price,
-                amount: trade.amount,
-                side: trade.side,
+                buyer_order_id,
+                seller_order_id,
+                buyer_is_maker: Some(buyer_is_maker),
+                ..PublicTrade::new(trade.id, trade.price, trade.amount, side)
             },
         })])
     }
@@ -78,7 +99,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use barter_integration::error::SocketError;
+    use barter_integration::{error::SocketError, model::instrument::kind::InstrumentKind};
     use chrono::NaiveDateTime;
     use serde::de::Error;
     use std::str::FromStr;
@@ -115,10 +136,11 @@ mod tests {
                     price: 400.23,
                     amount: 5.23512,
                     side: Side::Sell,
-                    time: DateTime::from_utc(
-                        NaiveDateTime::from_str("2014-11-07T08:19:27.028459").unwrap(),
-                        Utc,
-                    ),
+                    maker_order_id: Some("ac928c66-ca53-498f-9c13-a110027a60e8".to_owned()),
+                    taker_order_id: Some("132fb6ae-456b-4654-b4e0-d681ac05cea1".to_owned()),
+                    time: NaiveDateTime::from_str("2014-11-07T08:19:27.028459")
+                        .unwrap()
+                        .and_utc(),
                 }),
             },
         ];
@@ -139,4 +161,63 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_coinbase_trade_aggressor_side() {
+        let input = |side| {
+            format!(
+                r#"{{
+                    "type": "match","trade_id": 10,"sequence": 50,
+                    "maker_order_id": "maker", "taker_order_id": "taker",
+                    "time": "2014-11-07T08:19:27.028459Z",
+                    "product_id": "BTC-USD", "size": "5.23512", "price": "400.23", "side": "{side}"
+                }}"#
+            )
+        };
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+
+        struct TestCase {
+            side: &'static str,
+            expected_side: Side,
+            expected_buyer_is_maker: bool,
+            expected_buyer_order_id: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: sell maker order lifted by a buy aggressor
+                side: "sell",
+                expected_side: Side::Buy,
+                expected_buyer_is_maker: false,
+                expected_buyer_order_id: "taker",
+            },
+            TestCase {
+                // TC1: buy maker order hit by a sell aggressor
+                side: "buy",
+                expected_side: Side::Sell,
+                expected_buyer_is_maker: true,
+                expected_buyer_order_id: "maker",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let trade = serde_json::from_str::<CoinbaseTrade>(&input(test.side)).unwrap();
+            let MarketIter(events) =
+                MarketIter::<PublicTrade>::from((ExchangeId::Coinbase, instrument.clone(), trade));
+            let trade = events.into_iter().next().unwrap().unwrap().kind;
+            assert_eq!(trade.side, test.expected_side, "TC{} failed", index);
+            assert_eq!(
+                trade.buyer_is_maker,
+                Some(test.expected_buyer_is_maker),
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                trade.buyer_order_id.as_deref(),
+                Some(test.expected_buyer_order_id),
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
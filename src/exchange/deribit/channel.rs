@@ -0,0 +1,67 @@
+use super::Deribit;
+use crate::{
+    subscription::{
+        greeks::OptionGreeks, trade::BlockTrades, volatility::VolatilityIndices, Subscription,
+    },
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Deribit`] channel to be
+/// subscribed to.
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct DeribitChannel(pub &'static str);
+
+impl DeribitChannel {
+    /// [`Deribit`] real-time volatility index (DVOL) channel name.
+    ///
+    /// See docs: <https://docs.deribit.com/#deribit_volatility_index-index_name>
+    pub const VOLATILITY_INDEX: Self = Self("deribit_volatility_index");
+
+    /// [`Deribit`] real-time trades channel name.
+    ///
+    /// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+    pub const TRADES: Self = Self("trades");
+
+    /// [`Deribit`] real-time ticker channel name, containing option greeks & implied volatilities.
+    ///
+    /// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+    pub const TICKER: Self = Self("ticker");
+
+    /// Determine the notification interval suffix of this channel (eg/ "100ms"), if any.
+    ///
+    /// Deribit only serves the un-aggregated "raw" interval to authorised connections, so public
+    /// instrument channels are subscribed to with the "100ms" interval.
+    pub fn interval(&self) -> Option<&'static str> {
+        match *self {
+            Self::TRADES | Self::TICKER => Some("100ms"),
+            _ => None,
+        }
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, VolatilityIndices> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::VOLATILITY_INDEX
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, BlockTrades> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TRADES
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, OptionGreeks> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TICKER
+    }
+}
+
+impl AsRef<str> for DeribitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
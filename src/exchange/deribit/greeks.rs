@@ -0,0 +1,234 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::greeks::Greeks,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) ticker WebSocket message.
+pub type DeribitTickerMessage = DeribitMessage<DeribitOptionTicker>;
+
+/// [`Deribit`](super::Deribit) option ticker, containing the contract greeks and implied
+/// volatilities.
+///
+/// Deribit implied volatilities are percentages (eg/ 80.45 => 80.45%), and a bid/ask implied
+/// volatility of 0 is sent when there is no bid/ask. The mark price is denominated in the
+/// settlement currency of the contract (eg/ BTC for inverse BTC options).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+/// ```json
+/// {
+///     "timestamp": 1623060194301,
+///     "state": "open",
+///     "settlement_price": 0.0295,
+///     "open_interest": 3890.4,
+///     "min_price": 0.019,
+///     "max_price": 0.0595,
+///     "mark_price": 0.0345,
+///     "mark_iv": 80.45,
+///     "last_price": 0.0325,
+///     "interest_rate": 0,
+///     "instrument_name": "BTC-25JUN21-36000-C",
+///     "index_price": 36305.85,
+///     "greeks": {
+///         "vega": 38.15448,
+///         "theta": -55.15138,
+///         "rho": 2.34817,
+///         "gamma": 0.00005,
+///         "delta": 0.60753
+///     },
+///     "estimated_delivery_price": 36305.85,
+///     "bid_iv": 77.16,
+///     "best_bid_price": 0.034,
+///     "best_bid_amount": 5.1,
+///     "best_ask_price": 0.035,
+///     "best_ask_amount": 24.6,
+///     "ask_iv": 82.49,
+///     "underlying_price": 36339.47,
+///     "underlying_index": "BTC-25JUN21"
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitOptionTicker {
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub mark_price: f64,
+    pub mark_iv: f64,
+    pub bid_iv: f64,
+    pub ask_iv: f64,
+    pub underlying_price: f64,
+    pub greeks: DeribitGreeks,
+}
+
+/// [`Deribit`](super::Deribit) option greeks contained within a [`DeribitOptionTicker`].
+///
+/// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitGreeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+}
+
+impl From<(ExchangeId, Instrument, DeribitTickerMessage)> for MarketIter<Greeks> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, DeribitTickerMessage),
+    ) -> Self {
+        let ticker = message.params.data;
+
+        // Normalise percentage implied volatilities as fractions, where a bid/ask is present
+        let fraction = |iv: f64| iv / 100.0;
+        let non_zero = |iv: f64| (iv != 0.0).then(|| fraction(iv));
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Greeks {
+                delta: ticker.greeks.delta,
+                gamma: ticker.greeks.gamma,
+                vega: ticker.greeks.vega,
+                theta: ticker.greeks.theta,
+                mark_price: Some(ticker.mark_price),
+                mark_iv: fraction(ticker.mark_iv),
+                bid_iv: non_zero(ticker.bid_iv),
+                ask_iv: non_zero(ticker.ask_iv),
+                underlying_price: ticker.underlying_price,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::{
+        InstrumentKind, OptionContract, OptionExercise, OptionKind,
+    };
+    use chrono::TimeZone;
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_ticker_message() {
+            let input = r#"
+            {
+                "jsonrpc": "2.0",
+                "method": "subscription",
+                "params": {
+                    "channel": "ticker.BTC-25JUN21-36000-C.100ms",
+                    "data": {
+                        "timestamp": 1623060194301,
+                        "state": "open",
+                        "settlement_price": 0.0295,
+                        "open_interest": 3890.4,
+                        "min_price": 0.019,
+                        "max_price": 0.0595,
+                        "mark_price": 0.0345,
+                        "mark_iv": 80.45,
+                        "last_price": 0.0325,
+                        "interest_rate": 0,
+                        "instrument_name": "BTC-25JUN21-36000-C",
+                        "index_price": 36305.85,
+                        "greeks": {
+                            "vega": 38.15448,
+                            "theta": -55.15138,
+                            "rho": 2.34817,
+                            "gamma": 0.00005,
+                            "delta": 0.60753
+                        },
+                        "estimated_delivery_price": 36305.85,
+                        "bid_iv": 77.16,
+                        "best_bid_price": 0.034,
+                        "best_bid_amount": 5.1,
+                        "best_ask_price": 0.035,
+                        "best_ask_amount": 24.6,
+                        "ask_iv": 82.49,
+                        "underlying_price": 36339.47,
+                        "underlying_index": "BTC-25JUN21"
+                    }
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<DeribitTickerMessage>(input).unwrap(),
+                DeribitTickerMessage {
+                    params: DeribitParams {
+                        subscription_id: SubscriptionId::from("ticker|BTC-25JUN21-36000-C"),
+                        data: DeribitOptionTicker {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1623060194301
+                            )),
+                            mark_price: 0.0345,
+                            mark_iv: 80.45,
+                            bid_iv: 77.16,
+                            ask_iv: 82.49,
+                            underlying_price: 36339.47,
+                            greeks: DeribitGreeks {
+                                delta: 0.60753,
+                                gamma: 0.00005,
+                                vega: 38.15448,
+                                theta: -55.15138,
+                            },
+                        },
+                    },
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_deribit_ticker_normalised_as_greeks() {
+        let message = serde_json::from_str::<DeribitTickerMessage>(
+            r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"ticker.BTC-25JUN21-36000-C.100ms","data":{"timestamp":1623060194301,"mark_price":0.0345,"mark_iv":80.0,"bid_iv":0.0,"ask_iv":82.5,"underlying_price":36339.47,"greeks":{"delta":0.6,"gamma":0.00005,"vega":38.1,"theta":-55.1,"rho":2.3}}}}"#,
+        )
+        .unwrap();
+
+        let instrument = Instrument::from((
+            "btc",
+            "usd",
+            InstrumentKind::Option(OptionContract {
+                kind: OptionKind::Call,
+                exercise: OptionExercise::European,
+                expiry: Utc.with_ymd_and_hms(2021, 6, 25, 8, 0, 0).unwrap(),
+                strike: rust_decimal::Decimal::from(36000),
+            }),
+        ));
+        let event = MarketIter::<Greeks>::from((ExchangeId::Deribit, instrument, message))
+            .0
+            .remove(0)
+            .unwrap();
+
+        // Implied volatilities are fractions, & the absent bid implied volatility is None
+        assert_eq!(
+            event.kind,
+            Greeks {
+                delta: 0.6,
+                gamma: 0.00005,
+                vega: 38.1,
+                theta: -55.1,
+                mark_price: Some(0.0345),
+                mark_iv: 0.8,
+                bid_iv: None,
+                ask_iv: Some(0.825),
+                underlying_price: 36339.47,
+            }
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use super::Deribit;
+use crate::{subscription::Subscription, Identifier};
+use barter_integration::model::instrument::kind::{InstrumentKind, OptionKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Deribit`] market that
+/// can be subscribed to.
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitMarket(pub String);
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, Kind> {
+    fn id(&self) -> DeribitMarket {
+        // Notes:
+        // - Spot markets are Deribit index names, which must be lowercase (eg/ btc_usd).
+        // - Inverse (ie/ usd quoted) instruments are named by their base (eg/ BTC-PERPETUAL),
+        //   whereas linear instruments include the quote (eg/ SOL_USDC-PERPETUAL).
+        // - Option symbols are constructed from the OptionContract: eg/ BTC-27DEC24-60000-C, where
+        //   fractional strikes use a 'd' decimal separator (eg/ XRP_USDC-27DEC24-0d625-C).
+        let underlying = || match self.instrument.quote.as_ref() {
+            "usd" => self.instrument.base.to_string().to_uppercase(),
+            _ => format!("{}_{}", self.instrument.base, self.instrument.quote).to_uppercase(),
+        };
+        let expiry = |expiry: DateTime<Utc>| expiry.format("%-d%b%y").to_string().to_uppercase();
+
+        DeribitMarket(match self.instrument.kind {
+            InstrumentKind::Spot => {
+                format!("{}_{}", self.instrument.base, self.instrument.quote).to_lowercase()
+            }
+            InstrumentKind::Perpetual => format!("{}-PERPETUAL", underlying()),
+            InstrumentKind::Future(future) => {
+                format!("{}-{}", underlying(), expiry(future.expiry))
+            }
+            InstrumentKind::Option(option) => format!(
+                "{}-{}-{}-{}",
+                underlying(),
+                expiry(option.expiry),
+                option.strike.normalize().to_string().replace('.', "d"),
+                match option.kind {
+                    OptionKind::Call => "C",
+                    OptionKind::Put => "P",
+                }
+            ),
+        })
+    }
+}
+
+impl AsRef<str> for DeribitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::BlockTrades;
+    use barter_integration::model::instrument::kind::{
+        FutureContract, OptionContract, OptionExercise,
+    };
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_deribit_market() {
+        struct TestCase {
+            input: DeribitMarket,
+            expected: DeribitMarket,
+        }
+
+        let expiry = Utc.with_ymd_and_hms(2024, 12, 27, 8, 0, 0).unwrap();
+        let option = |kind, strike| {
+            InstrumentKind::Option(OptionContract {
+                kind,
+                exercise: OptionExercise::European,
+                expiry,
+                strike,
+            })
+        };
+        let market =
+            |base, quote, kind| Subscription::from((Deribit, base, quote, kind, BlockTrades)).id();
+
+        let cases = vec![
+            TestCase {
+                // TC0: Spot market is a lowercase index name
+                input: market("btc", "usd", InstrumentKind::Spot),
+                expected: DeribitMarket("btc_usd".to_string()),
+            },
+            TestCase {
+                // TC1: Inverse perpetual market
+                input: market("btc", "usd", InstrumentKind::Perpetual),
+                expected: DeribitMarket("BTC-PERPETUAL".to_string()),
+            },
+            TestCase {
+                // TC2: Linear perpetual market
+                input: market("sol", "usdc", InstrumentKind::Perpetual),
+                expected: DeribitMarket("SOL_USDC-PERPETUAL".to_string()),
+            },
+            TestCase {
+                // TC3: Inverse future market
+                input: market(
+                    "eth",
+                    "usd",
+                    InstrumentKind::Future(FutureContract { expiry }),
+                ),
+                expected: DeribitMarket("ETH-27DEC24".to_string()),
+            },
+            TestCase {
+                // TC4: Inverse option call market
+                input: market("btc", "usd", option(OptionKind::Call, Decimal::from(60000))),
+                expected: DeribitMarket("BTC-27DEC24-60000-C".to_string()),
+            },
+            TestCase {
+                // TC5: Linear option put market with a fractional strike
+                input: market("xrp", "usdc", option(OptionKind::Put, Decimal::new(625, 3))),
+                expected: DeribitMarket("XRP_USDC-27DEC24-0d625-P".to_string()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(test.input, test.expected, "TC{} failed", index);
+        }
+    }
+}
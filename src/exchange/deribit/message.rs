@@ -0,0 +1,103 @@
+use crate::{exchange::subscription::subscription_id, Identifier};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) JSON-RPC subscription notification containing a channel
+/// data payload `T`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#subscriptions>
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "method": "subscription",
+///     "params": {
+///         "channel": "deribit_volatility_index.btc_usd",
+///         "data": {
+///             "volatility": 129.36,
+///             "timestamp": 1619777946007,
+///             "index_name": "btc_usd"
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitMessage<T> {
+    pub params: DeribitParams<T>,
+}
+
+/// [`DeribitMessage`] parameters, identifying the channel of the data payload `T`.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitParams<T> {
+    #[serde(rename = "channel", deserialize_with = "de_channel_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for DeribitMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.params.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`DeribitMessage`] "channel" (eg/ "deribit_volatility_index.btc_usd") as the
+/// associated [`SubscriptionId`] (eg/ "deribit_volatility_index|btc_usd").
+///
+/// Any channel interval suffix is not part of the [`SubscriptionId`], so "trades.BTC-PERPETUAL.100ms"
+/// is deserialised as "trades|BTC-PERPETUAL".
+pub fn de_channel_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let channel = <&str as Deserialize>::deserialize(deserializer)?;
+
+    let mut parts = channel.split('.');
+    match (parts.next(), parts.next()) {
+        (Some(channel), Some(market)) => Ok(subscription_id(channel, market)),
+        _ => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(channel),
+            &"channel.market",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_de_channel_subscription_id() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<SubscriptionId, ()>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: channel without an interval
+                    input: r#""deribit_volatility_index.btc_usd""#,
+                    expected: Ok(SubscriptionId::from("deribit_volatility_index|btc_usd")),
+                },
+                TestCase {
+                    // TC1: channel with an interval
+                    input: r#""trades.BTC-PERPETUAL.100ms""#,
+                    expected: Ok(SubscriptionId::from("trades|BTC-PERPETUAL")),
+                },
+                TestCase {
+                    // TC2: channel without a market is invalid
+                    input: r#""trades""#,
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let mut deserializer = serde_json::Deserializer::from_str(test.input);
+                let actual = de_channel_subscription_id(&mut deserializer).map_err(|_| ());
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
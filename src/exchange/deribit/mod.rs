@@ -0,0 +1,186 @@
+use self::{
+    channel::DeribitChannel, greeks::DeribitTickerMessage, market::DeribitMarket,
+    subscription::DeribitSubResponse, trade::DeribitBlockTrades,
+    volatility::DeribitVolatilityIndexMessage,
+};
+use crate::{
+    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{greeks::OptionGreeks, trade::BlockTrades, volatility::VolatilityIndices},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Option ticker types yielding [`OptionGreeks`].
+pub mod greeks;
+
+/// Generic [`DeribitMessage<T>`](message::DeribitMessage) subscription notification.
+pub mod message;
+
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Deribit JSON-RPC error
+/// messages.
+pub mod notice;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Deribit`].
+pub mod subscription;
+
+/// Public trade types for [`Deribit`].
+pub mod trade;
+
+/// Volatility index (DVOL) types for [`Deribit`].
+pub mod volatility;
+
+/// [`Deribit`] server base url.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+pub const BASE_URL_DERIBIT: &str = "wss://www.deribit.com/ws/api/v2";
+
+/// [`Deribit`] exchange.
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Deribit;
+
+impl Connector for Deribit {
+    const ID: ExchangeId = ExchangeId::Deribit;
+    type Channel = DeribitChannel;
+    type Market = DeribitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = DeribitSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_DERIBIT).map_err(SocketError::UrlParse)
+    }
+
+    /// Each channel is subscribed to with a distinct JSON-RPC request, since Deribit omits
+    /// channels it could not subscribe to from an otherwise successful response.
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .enumerate()
+            .map(|(id, ExchangeSub { channel, market })| {
+                let channel = match channel.interval() {
+                    Some(interval) => {
+                        format!("{}.{}.{}", channel.as_ref(), market.as_ref(), interval)
+                    }
+                    None => format!("{}.{}", channel.as_ref(), market.as_ref()),
+                };
+
+                WsMessage::Text(
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "method": "public/subscribe",
+                        "params": {
+                            "channels": [channel]
+                        }
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl StreamSelector<VolatilityIndices> for Deribit {
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, VolatilityIndices, DeribitVolatilityIndexMessage>,
+    >;
+}
+
+impl StreamSelector<BlockTrades> for Deribit {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, BlockTrades, DeribitBlockTrades>>;
+}
+
+impl StreamSelector<OptionGreeks> for Deribit {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OptionGreeks, DeribitTickerMessage>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{subscription::Subscription, Identifier};
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_deribit_requests() {
+        let subscription = Subscription::<Deribit, VolatilityIndices>::new(
+            Deribit,
+            ("btc", "usd", InstrumentKind::Spot),
+            VolatilityIndices,
+        );
+        let exchange_sub = ExchangeSub {
+            channel: Identifier::<DeribitChannel>::id(&subscription),
+            market: Identifier::<DeribitMarket>::id(&subscription),
+        };
+
+        // Subscription notifications are routed using the canonical channel|market id
+        assert_eq!(
+            Identifier::<SubscriptionId>::id(&exchange_sub),
+            SubscriptionId::from("deribit_volatility_index|btc_usd")
+        );
+
+        let requests = Deribit::requests(vec![exchange_sub]);
+        assert_eq!(
+            requests,
+            vec![WsMessage::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 0,
+                    "method": "public/subscribe",
+                    "params": {"channels": ["deribit_volatility_index.btc_usd"]}
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_deribit_requests_with_interval() {
+        let subscription = Subscription::<Deribit, BlockTrades>::new(
+            Deribit,
+            ("btc", "usd", InstrumentKind::Perpetual),
+            BlockTrades,
+        );
+        let exchange_sub = ExchangeSub {
+            channel: Identifier::<DeribitChannel>::id(&subscription),
+            market: Identifier::<DeribitMarket>::id(&subscription),
+        };
+
+        // Interval suffix is subscribed to, but is not part of the canonical channel|market id
+        assert_eq!(
+            Identifier::<SubscriptionId>::id(&exchange_sub),
+            SubscriptionId::from("trades|BTC-PERPETUAL")
+        );
+
+        let requests = Deribit::requests(vec![exchange_sub]);
+        assert_eq!(
+            requests,
+            vec![WsMessage::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 0,
+                    "method": "public/subscribe",
+                    "params": {"channels": ["trades.BTC-PERPETUAL.100ms"]}
+                })
+                .to_string()
+            )]
+        );
+    }
+}
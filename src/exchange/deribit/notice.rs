@@ -0,0 +1,77 @@
+use super::{subscription::DeribitError, Deribit};
+use crate::notice::{Notice, NoticeKind, NoticeParser};
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`] WebSocket JSON-RPC error message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#rpc-error-codes>
+/// ```json
+/// {"jsonrpc": "2.0", "id": 3, "error": {"message": "too_many_requests", "code": 10028}, "testnet": false}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitNotice {
+    pub error: DeribitError,
+}
+
+impl NoticeParser for Deribit {
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let DeribitNotice {
+            error: DeribitError { code, message },
+        } = serde_json::from_str(payload).ok()?;
+
+        let kind = match code {
+            10028 => NoticeKind::RateLimit,
+            13004 | 13009 | 13778 => NoticeKind::Unauthorised,
+            13028 => NoticeKind::Maintenance,
+            11050 | -32602 | -32601 | -32600 => NoticeKind::InvalidRequest,
+            _ => NoticeKind::Other,
+        };
+
+        Some(Notice::new(kind, Some(code), message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deribit_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: rate limit error
+            TestCase {
+                input: r#"{"jsonrpc": "2.0", "id": 3, "error": {"message": "too_many_requests", "code": 10028}, "testnet": false}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::RateLimit,
+                    Some(10028),
+                    "too_many_requests",
+                )),
+            },
+            // TC1: invalid params error
+            TestCase {
+                input: r#"{"jsonrpc": "2.0", "id": 0, "error": {"message": "Invalid params", "data": {"param": "channels"}, "code": -32602}}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    Some(-32602),
+                    "Invalid params",
+                )),
+            },
+            // TC2: subscription notification is not a notice
+            TestCase {
+                input: r#"{"jsonrpc": "2.0", "method": "subscription", "params": {"channel": "deribit_volatility_index.btc_usd", "data": {}}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Deribit::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) JSON-RPC subscription response, or error response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#public-subscribe>
+/// #### Subscription Success
+/// Deribit responds with the subscribed channels, omitting any that could not be subscribed to.
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "id": 0,
+///     "result": ["deribit_volatility_index.btc_usd"],
+///     "usIn": 1619777946007422,
+///     "usOut": 1619777946007668,
+///     "usDiff": 246,
+///     "testnet": false
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "id": 0,
+///     "error": {"message": "Invalid params", "data": {"reason": "invalid format", "param": "channels"}, "code": -32602},
+///     "testnet": false
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DeribitSubResponse {
+    Subscribed { id: u64, result: Vec<String> },
+    Error { id: u64, error: DeribitError },
+}
+
+/// [`Deribit`](super::Deribit) JSON-RPC error.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl Validator for DeribitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            Self::Subscribed { result, .. } if !result.is_empty() => Ok(self),
+            Self::Subscribed { id, .. } => Err(SocketError::Subscribe(format!(
+                "received empty subscription response for request id {id}"
+            ))),
+            Self::Error { id, error } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response for request id {id}: {} ({})",
+                error.message, error.code
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_deribit_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input: r#"{"jsonrpc":"2.0","id":0,"result":["deribit_volatility_index.btc_usd"],"usIn":1619777946007422,"usOut":1619777946007668,"usDiff":246,"testnet":false}"#,
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response omits the unknown channel
+                input: r#"{"jsonrpc":"2.0","id":1,"result":[],"testnet":false}"#,
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: input response is failed subscription
+                input: r#"{"jsonrpc":"2.0","id":2,"error":{"message":"Invalid params","data":{"reason":"invalid format","param":"channels"},"code":-32602},"testnet":false}"#,
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<DeribitSubResponse>(test.input)
+                .unwrap()
+                .validate()
+                .is_ok();
+            assert_eq!(actual, test.is_valid, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::trade::{PublicTrade, TradeId},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) real-time trades WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+/// #### Block Trade
+/// ```json
+/// {
+///     "jsonrpc": "2.0",
+///     "method": "subscription",
+///     "params": {
+///         "channel": "trades.BTC-PERPETUAL.100ms",
+///         "data": [
+///             {
+///                 "trade_seq": 30289432,
+///                 "trade_id": "48079254",
+///                 "timestamp": 1590484156350,
+///                 "tick_direction": 0,
+///                 "price": 8950.0,
+///                 "mark_price": 8948.9,
+///                 "instrument_name": "BTC-PERPETUAL",
+///                 "index_price": 8955.88,
+///                 "direction": "sell",
+///                 "amount": 10.0,
+///                 "block_trade_id": "BLOCK-24617",
+///                 "block_trade_leg_count": 1
+///             }
+///         ]
+///     }
+/// }
+/// ```
+pub type DeribitTrades = DeribitMessage<Vec<DeribitTrade>>;
+
+/// [`Deribit`](super::Deribit) trade, where the `direction` is the aggressor (ie/ taker) side.
+///
+/// Perpetual & future trade amounts are denominated in USD for inverse instruments, whereas option
+/// trade amounts are denominated in the base asset.
+///
+/// See [`DeribitTrades`] for full raw payload examples.
+///
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitTrade {
+    #[serde(
+        rename = "trade_id",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub id: TradeId,
+    pub price: f64,
+    pub amount: f64,
+    #[serde(rename = "direction")]
+    pub side: Side,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    /// Identifier of the block trade this trade is a leg of, if it is a block trade.
+    #[serde(default)]
+    pub block_trade_id: Option<String>,
+}
+
+/// [`Deribit`](super::Deribit) real-time trades WebSocket message, normalised as
+/// [`BlockTrades`](crate::subscription::trade::BlockTrades).
+///
+/// Deribit reports block trades on the same trades channel as lit trades, so only the trades with
+/// a `block_trade_id` are yielded.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct DeribitBlockTrades(pub DeribitTrades);
+
+impl Identifier<Option<SubscriptionId>> for DeribitBlockTrades {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.0.id()
+    }
+}
+
+impl From<(ExchangeId, Instrument, DeribitBlockTrades)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trades): (ExchangeId, Instrument, DeribitBlockTrades),
+    ) -> Self {
+        trades
+            .0
+            .params
+            .data
+            .into_iter()
+            .filter(|trade| trade.block_trade_id.is_some())
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        block: true,
+                        ..PublicTrade::new(trade.id, trade.price, trade.amount, trade.side)
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_trades() {
+            let input = r#"
+            {
+                "jsonrpc": "2.0",
+                "method": "subscription",
+                "params": {
+                    "channel": "trades.BTC-PERPETUAL.100ms",
+                    "data": [
+                        {
+                            "trade_seq": 30289432,
+                            "trade_id": "48079254",
+                            "timestamp": 1590484156350,
+                            "tick_direction": 0,
+                            "price": 8950.0,
+                            "mark_price": 8948.9,
+                            "instrument_name": "BTC-PERPETUAL",
+                            "index_price": 8955.88,
+                            "direction": "sell",
+                            "amount": 10.0,
+                            "block_trade_id": "BLOCK-24617",
+                            "block_trade_leg_count": 1
+                        }
+                    ]
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<DeribitTrades>(input).unwrap(),
+                DeribitTrades {
+                    params: DeribitParams {
+                        subscription_id: SubscriptionId::from("trades|BTC-PERPETUAL"),
+                        data: vec![DeribitTrade {
+                            id: TradeId::U64(48079254),
+                            price: 8950.0,
+                            amount: 10.0,
+                            side: Side::Sell,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1590484156350
+                            )),
+                            block_trade_id: Some("BLOCK-24617".to_string()),
+                        }],
+                    },
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_deribit_block_trades_skip_lit_trades() {
+        let trades = serde_json::from_str::<DeribitBlockTrades>(
+            r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.100ms","data":[{"trade_id":"48079254","timestamp":1590484156350,"price":8950.0,"direction":"buy","amount":10.0},{"trade_id":"48079255","timestamp":1590484156350,"price":8951.0,"direction":"sell","amount":20.0,"block_trade_id":"BLOCK-24617","block_trade_leg_count":1}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            trades.id(),
+            Some(SubscriptionId::from("trades|BTC-PERPETUAL"))
+        );
+
+        let MarketIter(events) = MarketIter::<PublicTrade>::from((
+            ExchangeId::Deribit,
+            Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+            trades,
+        ));
+
+        let trades = events
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            trades,
+            vec![PublicTrade {
+                block: true,
+                ..PublicTrade::new(48079255, 8951.0, 20.0, Side::Sell)
+            }]
+        );
+    }
+}
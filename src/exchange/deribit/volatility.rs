@@ -0,0 +1,116 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::volatility::VolatilityIndex,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) volatility index WebSocket message.
+pub type DeribitVolatilityIndexMessage = DeribitMessage<DeribitVolatilityIndex>;
+
+/// [`Deribit`](super::Deribit) volatility index (DVOL) update, where the volatility is a
+/// percentage (eg/ 129.36 => 129.36%).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#deribit_volatility_index-index_name>
+/// ```json
+/// {
+///     "volatility": 129.36,
+///     "timestamp": 1619777946007,
+///     "index_name": "btc_usd"
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitVolatilityIndex {
+    pub volatility: f64,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, DeribitVolatilityIndexMessage)> for MarketIter<VolatilityIndex> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, DeribitVolatilityIndexMessage),
+    ) -> Self {
+        let index = message.params.data;
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: index.time,
+            received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: VolatilityIndex {
+                volatility: index.volatility / 100.0,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    mod de {
+        use super::*;
+        use crate::exchange::deribit::message::DeribitParams;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_deribit_volatility_index_message() {
+            let input = r#"
+            {
+                "jsonrpc": "2.0",
+                "method": "subscription",
+                "params": {
+                    "channel": "deribit_volatility_index.btc_usd",
+                    "data": {
+                        "volatility": 129.36,
+                        "timestamp": 1619777946007,
+                        "index_name": "btc_usd"
+                    }
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<DeribitVolatilityIndexMessage>(input).unwrap(),
+                DeribitVolatilityIndexMessage {
+                    params: DeribitParams {
+                        subscription_id: SubscriptionId::from("deribit_volatility_index|btc_usd"),
+                        data: DeribitVolatilityIndex {
+                            volatility: 129.36,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1619777946007
+                            )),
+                        },
+                    },
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_deribit_volatility_index_normalised_as_fraction() {
+        let message = serde_json::from_str::<DeribitVolatilityIndexMessage>(
+            r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"deribit_volatility_index.eth_usd","data":{"volatility":50.0,"timestamp":1619777946007,"index_name":"eth_usd"}}}"#,
+        )
+        .unwrap();
+
+        let instrument = Instrument::from(("eth", "usd", InstrumentKind::Spot));
+        let event = MarketIter::<VolatilityIndex>::from((ExchangeId::Deribit, instrument, message))
+            .0
+            .remove(0)
+            .unwrap();
+
+        assert_eq!(event.kind, VolatilityIndex { volatility: 0.5 });
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     subscription::{trade::PublicTrades, Subscription},
     Identifier,
 };
-use barter_integration::model::InstrumentKind;
+use barter_integration::model::instrument::kind::InstrumentKind;
 use serde::Serialize;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
@@ -18,17 +18,25 @@ impl GateioChannel {
     /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
     pub const SPOT_TRADES: Self = Self("spot.trades");
 
-    /// Gateio [`InstrumentKind::FuturePerpetual`] real-time trades channel.
+    /// Gateio [`InstrumentKind::Perpetual`] real-time trades channel.
     ///
     /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
     pub const FUTURE_PERPETUAL_TRADES: Self = Self("futures.trades");
+
+    /// Gateio [`InstrumentKind::Option`] real-time trades channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-trades-channel>
+    pub const OPTION_TRADES: Self = Self("options.trades");
 }
 
 impl<Server> Identifier<GateioChannel> for Subscription<Server, PublicTrades> {
     fn id(&self) -> GateioChannel {
         match self.instrument.kind {
             InstrumentKind::Spot => GateioChannel::SPOT_TRADES,
-            InstrumentKind::FuturePerpetual => GateioChannel::FUTURE_PERPETUAL_TRADES,
+            InstrumentKind::Future(_) | InstrumentKind::Perpetual => {
+                GateioChannel::FUTURE_PERPETUAL_TRADES
+            }
+            InstrumentKind::Option(_) => GateioChannel::OPTION_TRADES,
         }
     }
 }
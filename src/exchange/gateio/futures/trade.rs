@@ -1,11 +1,11 @@
 use super::super::message::GateioMessage;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{ExchangeId, ExchangeSub},
     subscription::trade::PublicTrade,
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -63,18 +63,20 @@ impl From<(ExchangeId, Instrument, GateioFuturesTrades)> for MarketIter<PublicTr
                 Ok(MarketEvent {
                     exchange_time: trade.time,
                     received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
-                    kind: PublicTrade {
-                        id: trade.id.to_string(),
-                        price: trade.price,
-                        amount: trade.amount,
-                        side: if trade.amount.is_sign_positive() {
+                    kind: PublicTrade::new(
+                        trade.id,
+                        trade.price,
+                        trade.amount,
+                        if trade.amount.is_sign_positive() {
                             Side::Buy
                         } else {
                             Side::Sell
                         },
-                    },
+                    ),
                 })
             })
             .collect()
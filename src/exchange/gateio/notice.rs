@@ -0,0 +1,94 @@
+use super::{message::GateioError, Gateio};
+use crate::{
+    exchange::ExchangeServer,
+    notice::{Notice, NoticeKind, NoticeParser},
+};
+use serde::{Deserialize, Serialize};
+
+/// [`Gateio`] WebSocket error message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-response>
+/// ```json
+/// {
+///     "time": 1606292218,
+///     "channel": "spot.trades",
+///     "event": "subscribe",
+///     "error": {"code": 2, "message": "unknown currency pair GIBBERISH_USD"},
+///     "result": null
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioNotice {
+    pub channel: String,
+    pub error: GateioError,
+}
+
+impl<Server> NoticeParser for Gateio<Server>
+where
+    Server: ExchangeServer,
+{
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let GateioNotice { channel, error } = serde_json::from_str(payload).ok()?;
+
+        // Error codes: 1 invalid request body, 2 invalid argument, 3 server side error
+        let kind = match error.code {
+            1 | 2 => NoticeKind::InvalidRequest,
+            4 => NoticeKind::Unauthorised,
+            _ => NoticeKind::Other,
+        };
+
+        Some(Notice::new(
+            kind,
+            Some(error.code),
+            format!("{channel}: {}", error.message),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::gateio::spot::GateioSpot;
+
+    #[test]
+    fn test_gateio_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: invalid argument error
+            TestCase {
+                input: r#"
+                {
+                    "time": 1606292218, "channel": "spot.trades", "event": "subscribe",
+                    "error": {"code": 2, "message": "unknown currency pair GIBBERISH_USD"},
+                    "result": null
+                }
+                "#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    Some(2),
+                    "spot.trades: unknown currency pair GIBBERISH_USD",
+                )),
+            },
+            // TC1: subscription success is not a notice
+            TestCase {
+                input: r#"
+                {
+                    "time": 1606292218, "channel": "spot.trades", "event": "subscribe",
+                    "result": {"status": "success"}
+                }
+                "#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = GateioSpot::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use super::super::message::GateioMessage;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{ExchangeId, ExchangeSub},
     subscription::trade::PublicTrade,
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -57,14 +57,16 @@ impl From<(ExchangeId, Instrument, GateioSpotTrade)> for MarketIter<PublicTrade>
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.data.time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
-            kind: PublicTrade {
-                id: trade.data.id.to_string(),
-                price: trade.data.price,
-                amount: trade.data.amount,
-                side: trade.data.side,
-            },
+            kind: PublicTrade::new(
+                trade.data.id,
+                trade.data.price,
+                trade.data.amount,
+                trade.data.side,
+            ),
         })])
     }
 }
@@ -2,14 +2,14 @@ use super::super::KrakenMessage;
 use crate::exchange::kraken::channel::KrakenChannel;
 use crate::exchange::subscription::ExchangeSub;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::ExchangeId,
     subscription::book::{Level, OrderBookL1},
     Identifier,
 };
 use barter_integration::{
     de::extract_next,
-    model::{Exchange, Instrument, SubscriptionId},
+    model::{instrument::Instrument, Exchange, SubscriptionId},
 };
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -61,6 +61,8 @@ impl From<(ExchangeId, Instrument, KrakenOrderBookL1)> for MarketIter<OrderBookL
             KrakenOrderBookL1::Data(book) => Self(vec![Ok(MarketEvent {
                 exchange_time: book.spread.time,
                 received_time: Utc::now(),
+                received_monotonic: MonotonicTime::now(),
+                origin: Origin::Live,
                 exchange: Exchange::from(exchange_id),
                 instrument,
                 kind: OrderBookL1 {
@@ -0,0 +1,751 @@
+use super::super::{channel::KrakenChannel, message::KrakenMessage, Kraken};
+use crate::{
+    error::DataError,
+    exchange::{subscription::ExchangeSub, Connector},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{crc32, BookUpdate, InstrumentOrderBook, OrderBookUpdater},
+    Identifier, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Number of [`Level`]s per side of a [`Kraken`] OrderBook subscription, which is also the depth
+/// covered by the [`Kraken`] OrderBook checksum.
+pub const KRAKEN_BOOK_DEPTH: usize = 10;
+
+/// Terse type alias for an [`Kraken`] real-time OrderBook Level2 WebSocket message.
+pub type KrakenOrderBookL2 = KrakenMessage<KrakenOrderBookL2Inner>;
+
+/// [`Kraken`] real-time OrderBook Level2 snapshot or update, and the associated
+/// [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// #### Snapshot
+/// ```json
+/// [
+///     0,
+///     {
+///         "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+///         "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+///     },
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+///
+/// #### Update
+/// Asks & bids may be sent in separate objects, with the checksum in the last object.
+/// ```json
+/// [
+///     1234,
+///     {"a": [["5541.30000", "2.50700000", "1534614248.456738", "r"]]},
+///     {"b": [["5541.20000", "0.00000000", "1534614248.456738"]], "c": "974942666"},
+///     "book-10",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenOrderBookL2Inner {
+    pub subscription_id: SubscriptionId,
+    pub pair: String,
+    pub snapshot: bool,
+    pub bids: Vec<KrakenLevel>,
+    pub asks: Vec<KrakenLevel>,
+    pub checksum: Option<u32>,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL2Inner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Decimal places of the price & amount strings of a [`Kraken`] market, which are required to
+/// reproduce the [`Kraken`] OrderBook checksum.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+pub struct KrakenPrecision {
+    pub price: usize,
+    pub amount: usize,
+}
+
+/// [`Kraken`] OrderBook level.
+///
+/// #### Raw Payload Examples
+/// ```json
+/// ["5541.30000", "2.50700000", "1534614248.456738", "r"]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenLevel {
+    pub price: f64,
+    pub amount: f64,
+    pub time: DateTime<Utc>,
+    /// True if the [`Kraken`] "r" flag is set, indicating a republished level (eg/ a level
+    /// re-entering the subscribed depth after a better level was removed).
+    pub republish: bool,
+    pub precision: KrakenPrecision,
+}
+
+impl From<KrakenLevel> for Level {
+    fn from(level: KrakenLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenLevel from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenLevel Sequence Format:
+                // [price, volume, timestamp, updateType]
+                // <https://docs.kraken.com/websockets/#message-book>
+                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let amount = extract_next::<SeqAccessor, String>(&mut seq, "volume")?;
+                let time = extract_next::<SeqAccessor, String>(&mut seq, "timestamp")?;
+
+                // Extract optional updateType, where "r" is a republished level
+                let republish = seq
+                    .next_element::<String>()?
+                    .is_some_and(|kind| kind == "r");
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                let parse = |value: &str| {
+                    value
+                        .parse::<f64>()
+                        .map_err(serde::de::Error::custom::<std::num::ParseFloatError>)
+                };
+
+                Ok(KrakenLevel {
+                    price: parse(&price)?,
+                    amount: parse(&amount)?,
+                    time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(parse(&time)?)),
+                    republish,
+                    precision: KrakenPrecision {
+                        price: decimals(&price),
+                        amount: decimals(&amount),
+                    },
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenOrderBookL2Inner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// Object containing the snapshot or update levels of a [`KrakenOrderBookL2Inner`].
+        #[derive(Deserialize)]
+        struct KrakenBookData {
+            #[serde(rename = "as")]
+            snapshot_asks: Option<Vec<KrakenLevel>>,
+            #[serde(rename = "bs")]
+            snapshot_bids: Option<Vec<KrakenLevel>>,
+            #[serde(rename = "a", default)]
+            asks: Vec<KrakenLevel>,
+            #[serde(rename = "b", default)]
+            bids: Vec<KrakenLevel>,
+            #[serde(rename = "c")]
+            checksum: Option<String>,
+        }
+
+        /// Element following the channelID, ending with the channelName & pair.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum KrakenBookElement {
+            Data(KrakenBookData),
+            Name(String),
+        }
+
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenOrderBookL2Inner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenOrderBookL2Inner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenOrderBookL2Inner Sequence Format:
+                // [channelID, data, (data), channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract data objects until the channelName (eg/ "book-10") is reached
+                let mut book = KrakenOrderBookL2Inner {
+                    subscription_id: SubscriptionId::from(""),
+                    pair: String::new(),
+                    snapshot: false,
+                    bids: vec![],
+                    asks: vec![],
+                    checksum: None,
+                };
+                loop {
+                    match extract_next::<SeqAccessor, KrakenBookElement>(&mut seq, "data")? {
+                        KrakenBookElement::Data(data) => {
+                            book.snapshot |=
+                                data.snapshot_asks.is_some() || data.snapshot_bids.is_some();
+                            book.asks.extend(data.snapshot_asks.into_iter().flatten());
+                            book.bids.extend(data.snapshot_bids.into_iter().flatten());
+                            book.asks.extend(data.asks);
+                            book.bids.extend(data.bids);
+                            if let Some(checksum) = data.checksum {
+                                book.checksum =
+                                    Some(checksum.parse().map_err(serde::de::Error::custom)?);
+                            }
+                        }
+                        KrakenBookElement::Name(channel)
+                            if channel.starts_with(KrakenChannel::ORDER_BOOK_L2.as_ref()) =>
+                        {
+                            break
+                        }
+                        KrakenBookElement::Name(channel) => {
+                            return Err(serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Str(&channel),
+                                &"Kraken OrderBook channelName (eg/ \"book-10\")",
+                            ))
+                        }
+                    }
+                }
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "book|{pair}")
+                book.pair = extract_next::<SeqAccessor, String>(&mut seq, "pair")?;
+                book.subscription_id =
+                    ExchangeSub::from((KrakenChannel::ORDER_BOOK_L2, book.pair.as_str())).id();
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(book)
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenOrderBookL2Inner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// [`Kraken`] OrderBook Level2 [`OrderBookUpdater`].
+///
+/// Kraken: How To Maintain A Local OrderBook
+///
+/// 1. After subscribing, a snapshot of the top [`KRAKEN_BOOK_DEPTH`] levels per side is received.
+/// 2. Subsequent updates contain the absolute volume of a price level, where a volume of 0
+///    removes the level. Republished levels (flagged "r") are applied the same way.
+/// 3. Levels beyond the subscribed depth are not removed by updates, so the local OrderBook
+///    must be truncated to [`KRAKEN_BOOK_DEPTH`] levels per side after every update.
+/// 4. Each update contains a CRC32 checksum of the top 10 levels, which must match the checksum
+///    of the local OrderBook.
+/// 5. If the checksum does not match, the pair is re-subscribed to receive a fresh snapshot,
+///    dropping any updates received in the meantime.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Clone, Debug)]
+pub struct KrakenBookUpdater {
+    pub control_tx: mpsc::UnboundedSender<WsMessage>,
+    pub updates_processed: u64,
+    pub precision: KrakenPrecision,
+    pub resyncing: bool,
+}
+
+impl KrakenBookUpdater {
+    /// Re-subscribe to the OrderBook of the provided `pair` to receive a fresh snapshot.
+    ///
+    /// The unsubscribe & resubscribe requests are sent via the high priority control lane of the
+    /// [`OutboundQueue`](crate::OutboundQueue), so they are never queued behind bulk messages.
+    fn resync(&mut self, pair: &str) {
+        self.resyncing = true;
+        for event in ["unsubscribe", "subscribe"] {
+            let request = WsMessage::Text(
+                json!({
+                    "event": event,
+                    "pair": [pair],
+                    "subscription": channel_subscription(KrakenChannel::ORDER_BOOK_L2),
+                })
+                .to_string(),
+            );
+
+            if self.control_tx.send(request).is_err() {
+                warn!(
+                    pair,
+                    "failed to re-subscribe to Kraken OrderBook - WebSocket sink dropped"
+                );
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = KrakenOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        outbound_tx: OutboundTx,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Kraken sends the initial OrderBook snapshot over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self {
+                control_tx: outbound_tx.control,
+                updates_processed: 0,
+                precision: KrakenPrecision::default(),
+                resyncing: false,
+            },
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                sequence: None,
+                delta: false,
+            },
+        })
+    }
+
+    fn apply(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<BookUpdate>, DataError> {
+        let KrakenOrderBookL2::Data(update) = update else {
+            return Ok(None);
+        };
+
+        let last_update_time = update
+            .bids
+            .iter()
+            .chain(&update.asks)
+            .map(|level| level.time)
+            .max();
+
+        let applied = if update.snapshot {
+            // 1. & 5. Snapshot replaces the entire local OrderBook
+            if let Some(level) = update.asks.first().or(update.bids.first()) {
+                self.precision = level.precision;
+            }
+            self.resyncing = false;
+            book.bids = OrderBookSide::new(Side::Buy, update.bids);
+            book.asks = OrderBookSide::new(Side::Sell, update.asks);
+            BookUpdate::Snapshot
+        } else {
+            // 5. Drop updates received before the re-subscription snapshot
+            if self.resyncing || self.updates_processed == 0 {
+                return Ok(None);
+            }
+
+            // 2. & 3. Upsert the updated levels & truncate to the subscribed depth
+            let bids = update.bids.into_iter().map(Level::from).collect::<Vec<_>>();
+            let asks = update.asks.into_iter().map(Level::from).collect::<Vec<_>>();
+            book.bids.upsert(bids.iter().copied());
+            book.asks.upsert(asks.iter().copied());
+            BookUpdate::Delta { bids, asks }
+        };
+
+        book.bids.truncate(KRAKEN_BOOK_DEPTH);
+        book.asks.truncate(KRAKEN_BOOK_DEPTH);
+
+        // 4. & 5. Validate the checksum, re-subscribing if the local OrderBook has diverged
+        if let Some(expected) = update.checksum {
+            let actual = checksum(book, self.precision);
+            if actual != expected {
+                self.resync(&update.pair);
+                return Err(DataError::InvalidChecksum {
+                    exchange: Kraken::ID,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        // Update OrderBook metadata & OrderBookUpdater metadata
+        if let Some(last_update_time) = last_update_time {
+            book.last_update_time = last_update_time;
+        }
+        self.updates_processed += 1;
+
+        Ok(Some(applied))
+    }
+}
+
+/// Generate the [`Kraken`] "subscription" object used to subscribe to the provided
+/// [`KrakenChannel`], which includes the depth of OrderBook subscriptions.
+pub fn channel_subscription(channel: KrakenChannel) -> serde_json::Value {
+    if channel == KrakenChannel::ORDER_BOOK_L2 {
+        json!({ "name": channel.as_ref(), "depth": KRAKEN_BOOK_DEPTH })
+    } else {
+        json!({ "name": channel.as_ref() })
+    }
+}
+
+/// Calculate the [`Kraken`] CRC32 checksum of the top 10 asks (ascending) followed by the top 10
+/// bids (descending) of a sorted [`OrderBook`].
+///
+/// Each price & amount is formatted with the market [`KrakenPrecision`], with the decimal point
+/// and any leading zeros removed, before being concatenated.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+pub fn checksum(book: &OrderBook, precision: KrakenPrecision) -> u32 {
+    let format = |value: f64, decimals: usize| {
+        format!("{value:.decimals$}")
+            .replace('.', "")
+            .trim_start_matches('0')
+            .to_owned()
+    };
+
+    let input = book
+        .asks
+        .levels()
+        .iter()
+        .take(10)
+        .chain(book.bids.levels().iter().take(10))
+        .flat_map(|level| {
+            [
+                format(level.price, precision.price),
+                format(level.amount, precision.amount),
+            ]
+        })
+        .collect::<String>();
+
+    crc32(input.as_bytes())
+}
+
+/// Number of decimal places of a numeric string (eg/ "5541.30000" has 5).
+fn decimals(value: &str) -> usize {
+    value
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::kraken::Kraken,
+        subscription::{book::OrderBooksL2, Map},
+        transformer::{book::MultiBookTransformer, ExchangeTransformer},
+        OutboundQueue,
+    };
+    use barter_integration::{model::instrument::kind::InstrumentKind, Transformer};
+
+    const PRECISION: KrakenPrecision = KrakenPrecision {
+        price: 1,
+        amount: 8,
+    };
+
+    fn level(price: f64, amount: f64) -> KrakenLevel {
+        KrakenLevel {
+            price,
+            amount,
+            time: DateTime::<Utc>::default(),
+            republish: false,
+            precision: PRECISION,
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_message_order_book_l2() {
+            let input = r#"
+            [
+                1234,
+                {"a": [["5541.30000", "2.50700000", "1534614248.456738", "r"]]},
+                {"b": [["5541.20000", "0.00000000", "1534614248.456738"]], "c": "974942666"},
+                "book-10",
+                "XBT/USD"
+            ]
+            "#;
+
+            let time = datetime_utc_from_epoch_duration(Duration::from_secs_f64(1534614248.456738));
+            let precision = KrakenPrecision {
+                price: 5,
+                amount: 8,
+            };
+
+            assert_eq!(
+                serde_json::from_str::<KrakenOrderBookL2>(input).unwrap(),
+                KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                    subscription_id: SubscriptionId::from("book|XBT/USD"),
+                    pair: "XBT/USD".to_owned(),
+                    snapshot: false,
+                    bids: vec![KrakenLevel {
+                        price: 5541.2,
+                        amount: 0.0,
+                        time,
+                        republish: false,
+                        precision,
+                    }],
+                    asks: vec![KrakenLevel {
+                        price: 5541.3,
+                        amount: 2.507,
+                        time,
+                        republish: true,
+                        precision,
+                    }],
+                    checksum: Some(974942666),
+                })
+            );
+
+            let input = r#"
+            [
+                0,
+                {
+                    "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+                    "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+                },
+                "book-10",
+                "XBT/USD"
+            ]
+            "#;
+
+            let KrakenOrderBookL2::Data(actual) =
+                serde_json::from_str::<KrakenOrderBookL2>(input).unwrap()
+            else {
+                panic!("expected KrakenOrderBookL2::Data");
+            };
+            assert!(actual.snapshot);
+            assert_eq!((actual.asks.len(), actual.bids.len()), (1, 1));
+            assert_eq!(actual.checksum, None);
+        }
+    }
+
+    #[test]
+    fn test_checksum() {
+        let book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(0.05, 0.5)]),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(0.0501, 12.0)]),
+            sequence: None,
+            delta: false,
+        };
+        let precision = KrakenPrecision {
+            price: 5,
+            amount: 8,
+        };
+
+        // "0.05010" "12.00000000" "0.05000" "0.50000000"
+        let expected = crc32(b"50101200000000500050000000");
+        assert_eq!(checksum(&book, precision), expected);
+    }
+
+    #[test]
+    fn test_update() {
+        let (outbound_tx, mut outbound) = OutboundQueue::new();
+        let mut updater = KrakenBookUpdater {
+            control_tx: outbound_tx.control,
+            updates_processed: 0,
+            precision: KrakenPrecision::default(),
+            resyncing: false,
+        };
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            sequence: None,
+            delta: false,
+        };
+
+        let message = |snapshot: bool, bids: Vec<KrakenLevel>, checksum: Option<u32>| {
+            KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                subscription_id: SubscriptionId::from("book|XBT/USD"),
+                pair: "XBT/USD".to_owned(),
+                snapshot,
+                bids,
+                asks: vec![level(10.0, 1.0)],
+                checksum,
+            })
+        };
+        let valid_checksum = |bids: &[Level]| {
+            let book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, bids.to_vec()),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(10.0, 1.0)]),
+                sequence: None,
+                delta: false,
+            };
+            Some(checksum(&book, PRECISION))
+        };
+
+        struct TestCase {
+            input: KrakenOrderBookL2,
+            // Ok(None) if the update is dropped, Err(()) if the checksum is invalid
+            expected_bids: Result<Option<Vec<Level>>, ()>,
+        }
+
+        let snapshot_bids = (0..12)
+            .map(|index| level(9.0 - f64::from(index) * 0.1, 1.0))
+            .collect::<Vec<_>>();
+        let truncated = snapshot_bids
+            .iter()
+            .take(KRAKEN_BOOK_DEPTH)
+            .copied()
+            .map(Level::from)
+            .collect::<Vec<_>>();
+        let mut updated = truncated.clone();
+        updated[0] = Level::new(9.0, 2.0);
+
+        let tests = vec![
+            TestCase {
+                // TC0: update before the initial snapshot is dropped
+                input: message(false, vec![level(9.0, 2.0)], None),
+                expected_bids: Ok(None),
+            },
+            TestCase {
+                // TC1: snapshot replaces the OrderBook, truncated to the subscribed depth
+                input: message(true, snapshot_bids.clone(), None),
+                expected_bids: Ok(Some(truncated.clone())),
+            },
+            TestCase {
+                // TC2: update w/ a valid checksum
+                input: message(false, vec![level(9.0, 2.0)], valid_checksum(&updated)),
+                expected_bids: Ok(Some(updated.clone())),
+            },
+            TestCase {
+                // TC3: update w/ an invalid checksum re-subscribes
+                input: message(false, vec![level(8.9, 3.0)], Some(0)),
+                expected_bids: Err(()),
+            },
+            TestCase {
+                // TC4: update received while re-subscribing is dropped
+                input: message(false, vec![level(8.8, 3.0)], None),
+                expected_bids: Ok(None),
+            },
+            TestCase {
+                // TC5: re-subscription snapshot replaces the diverged OrderBook
+                input: message(true, snapshot_bids, valid_checksum(&truncated)),
+                expected_bids: Ok(Some(truncated)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = updater.update(&mut book, test.input);
+            match (actual, test.expected_bids) {
+                (Ok(actual), Ok(expected)) => {
+                    let actual = actual.map(|book| book.bids);
+                    let expected = expected.map(|bids| OrderBookSide::new(Side::Buy, bids));
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(DataError::InvalidChecksum { expected, .. }), Err(_)) => {
+                    assert_eq!(expected, 0, "TC{} failed", index);
+
+                    // Pair is unsubscribed & re-subscribed via the control lane
+                    let requests = [
+                        outbound.control_rx.try_recv(),
+                        outbound.control_rx.try_recv(),
+                    ]
+                    .map(|request| match request {
+                        Ok(WsMessage::Text(request)) => request,
+                        other => panic!("TC{index} failed: unexpected {other:?}"),
+                    });
+                    assert!(requests[0].contains(r#""event":"unsubscribe""#));
+                    assert!(requests[1].contains(r#""event":"subscribe""#));
+                    assert!(requests[1].contains(r#""depth":10"#));
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resync_overtakes_queued_bulk_messages() {
+        let (outbound_tx, mut outbound) = OutboundQueue::new();
+        let mut transformer = MultiBookTransformer::<Kraken, OrderBooksL2, KrakenBookUpdater>::new(
+            outbound_tx.clone(),
+            Map::from_iter([(
+                SubscriptionId::from("book|XBT/USD"),
+                Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+            )]),
+        )
+        .await
+        .unwrap();
+
+        // Backlog of bulk messages queued before the OrderBook diverges
+        for index in 0..2 {
+            outbound_tx
+                .bulk
+                .send(WsMessage::Text(format!("bulk_{index}")))
+                .unwrap();
+        }
+
+        let message = |snapshot: bool, checksum: Option<u32>| {
+            KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                subscription_id: SubscriptionId::from("book|XBT/USD"),
+                pair: "XBT/USD".to_owned(),
+                snapshot,
+                bids: vec![level(9.0, 1.0)],
+                asks: vec![level(10.0, 1.0)],
+                checksum,
+            })
+        };
+        assert!(transformer.transform(message(true, None))[0].is_ok());
+        assert!(transformer.transform(message(false, Some(0)))[0].is_err());
+
+        // Resync unsubscribe & resubscribe are sent before the queued bulk messages
+        let mut sent = Vec::new();
+        for _ in 0..4 {
+            match outbound.recv().await {
+                Some(WsMessage::Text(message)) => sent.push(message),
+                other => panic!("unexpected outbound message: {other:?}"),
+            }
+        }
+        assert!(sent[0].contains(r#""event":"unsubscribe""#));
+        assert!(sent[1].contains(r#""event":"subscribe""#));
+        assert_eq!(sent[2..], ["bulk_0", "bulk_1"]);
+    }
+}
@@ -1,2 +1,8 @@
 /// Level 1 OrderBook types (top of book).
 pub mod l1;
+
+/// Level 2 OrderBook types and the checksum validating [`OrderBookUpdater`] for
+/// [`Kraken`](super::Kraken).
+///
+/// [`OrderBookUpdater`]: crate::transformer::book::OrderBookUpdater
+pub mod l2;
@@ -1,6 +1,11 @@
 use super::Kraken;
 use crate::{
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        status::SystemStatus,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -22,6 +27,17 @@ impl KrakenChannel {
     ///
     /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
     pub const ORDER_BOOK_L1: Self = Self("spread");
+
+    /// [`Kraken`] real-time OrderBook Level2 channel name, subscribed to with a depth of
+    /// [`KRAKEN_BOOK_DEPTH`](super::book::l2::KRAKEN_BOOK_DEPTH) levels.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-book>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// [`Kraken`] system status "channel", which is sent upon connection without subscribing.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+    pub const SYSTEM_STATUS: Self = Self("systemStatus");
 }
 
 impl Identifier<KrakenChannel> for Subscription<Kraken, PublicTrades> {
@@ -36,6 +52,18 @@ impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL1> {
     }
 }
 
+impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL2> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<KrakenChannel> for Subscription<Kraken, SystemStatus> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::SYSTEM_STATUS
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
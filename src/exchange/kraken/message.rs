@@ -100,6 +100,9 @@ where
 #[serde(tag = "event", rename_all = "camelCase")]
 pub enum KrakenEvent {
     Heartbeat,
+    /// Subscription status received after (re)subscribing or unsubscribing while subscriptions
+    /// are active (eg/ during an OrderBook checksum recovery).
+    SubscriptionStatus,
     Error(KrakenError),
 }
 
@@ -147,6 +150,17 @@ mod tests {
                         message: "Malformed request".to_string(),
                     }))),
                 },
+                TestCase {
+                    // TC2: valid KrakenTrades::Event(KrakenEvent::SubscriptionStatus)
+                    input: r#"
+                    {
+                        "channelID": 10001, "channelName": "book-10", "event": "subscriptionStatus",
+                        "pair": "XBT/USD", "status": "unsubscribed",
+                        "subscription": {"depth": 10, "name": "book"}
+                    }
+                    "#,
+                    expected: Ok(KrakenMessage::Event(KrakenEvent::SubscriptionStatus)),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
@@ -1,15 +1,32 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::{
+        l1::KrakenOrderBookL1,
+        l2::{channel_subscription, KrakenBookUpdater},
+    },
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    status::KrakenSystemStatusMessage,
+    subscription::KrakenSubResponse,
+    trade::KrakenTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        status::SystemStatus,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::{
+        book::MultiBookTransformer, stateless::StatelessTransformer, status::StatusTransformer,
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
@@ -17,6 +34,10 @@ use url::Url;
 /// Order book types for [`Kraken`]
 pub mod book;
 
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Kraken error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -28,6 +49,9 @@ pub mod market;
 /// [`KrakenMessage`](message::KrakenMessage) type for [`Kraken`].
 pub mod message;
 
+/// System status types for [`Kraken`].
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration) for [`Kraken`].
 pub mod subscription;
@@ -60,23 +84,31 @@ impl Connector for Kraken {
         Url::parse(BASE_URL_KRAKEN).map_err(SocketError::UrlParse)
     }
 
+    /// The system status is sent upon connection, so is not subscribed to.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
+            .filter(|sub| sub.channel != KrakenChannel::SYSTEM_STATUS)
             .map(|ExchangeSub { channel, market }| {
                 WsMessage::Text(
                     json!({
                         "event": "subscribe",
                         "pair": [market.as_ref()],
-                        "subscription": {
-                            "name": channel.as_ref()
-                        }
+                        "subscription": channel_subscription(channel),
                     })
                     .to_string(),
                 )
             })
             .collect()
     }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        let system_status_prefix = format!("{}|", KrakenChannel::SYSTEM_STATUS.as_ref());
+        map.0
+            .keys()
+            .filter(|id| !id.as_ref().starts_with(&system_status_prefix))
+            .count()
+    }
 }
 
 impl StreamSelector<PublicTrades> for Kraken {
@@ -86,3 +118,11 @@ impl StreamSelector<PublicTrades> for Kraken {
 impl StreamSelector<OrderBooksL1> for Kraken {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, KrakenOrderBookL1>>;
 }
+
+impl StreamSelector<OrderBooksL2> for Kraken {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, KrakenBookUpdater>>;
+}
+
+impl StreamSelector<SystemStatus> for Kraken {
+    type Stream = ExchangeWsStream<StatusTransformer<Self, KrakenSystemStatusMessage>>;
+}
@@ -0,0 +1,141 @@
+use super::Kraken;
+use crate::notice::{Notice, NoticeKind, NoticeParser};
+use serde::{Deserialize, Serialize};
+
+/// [`Kraken`] WebSocket error or system status event.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#errortypes>
+/// #### Error
+/// ```json
+/// {"errorMessage": "Exceeded msg rate", "event": "error"}
+/// ```
+///
+/// #### Subscription Error
+/// ```json
+/// {
+///     "errorMessage": "Currency pair not supported XBT/XYZ",
+///     "event": "subscriptionStatus",
+///     "pair": "XBT/XYZ",
+///     "status": "error",
+///     "subscription": {"name": "trade"}
+/// }
+/// ```
+///
+/// #### System Status
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+/// ```json
+/// {"connectionID": 8628615390848610000, "event": "systemStatus", "status": "maintenance", "version": "1.0.0"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+pub enum KrakenNotice {
+    Error {
+        #[serde(rename = "errorMessage")]
+        error_message: String,
+    },
+    SubscriptionStatus {
+        status: String,
+        #[serde(rename = "errorMessage", default)]
+        error_message: Option<String>,
+    },
+    SystemStatus {
+        status: String,
+    },
+}
+
+impl NoticeParser for Kraken {
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let message = match serde_json::from_str::<KrakenNotice>(payload).ok()? {
+            KrakenNotice::Error { error_message } => error_message,
+            KrakenNotice::SubscriptionStatus {
+                status,
+                error_message,
+            } if status == "error" => error_message.unwrap_or(status),
+            KrakenNotice::SystemStatus { status } if status != "online" => {
+                return Some(Notice::new(
+                    NoticeKind::Maintenance,
+                    None::<String>,
+                    format!("system status: {status}"),
+                ));
+            }
+            _ => return None,
+        };
+
+        // Kraken errors are only identified by their message
+        let lowercase = message.to_lowercase();
+        let kind = if lowercase.contains("rate") {
+            NoticeKind::RateLimit
+        } else if lowercase.contains("eapi:invalid") || lowercase.contains("token") {
+            NoticeKind::Unauthorised
+        } else {
+            NoticeKind::InvalidRequest
+        };
+
+        Some(Notice::new(kind, None::<String>, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kraken_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: rate limit error
+            TestCase {
+                input: r#"{"errorMessage": "Exceeded msg rate", "event": "error"}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::RateLimit,
+                    None::<String>,
+                    "Exceeded msg rate",
+                )),
+            },
+            // TC1: subscription error
+            TestCase {
+                input: r#"
+                {
+                    "errorMessage": "Currency pair not supported XBT/XYZ",
+                    "event": "subscriptionStatus", "pair": "XBT/XYZ", "status": "error",
+                    "subscription": {"name": "trade"}
+                }
+                "#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    None::<String>,
+                    "Currency pair not supported XBT/XYZ",
+                )),
+            },
+            // TC2: maintenance system status
+            TestCase {
+                input: r#"{"connectionID": 1, "event": "systemStatus", "status": "maintenance", "version": "1.0.0"}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::Maintenance,
+                    None::<String>,
+                    "system status: maintenance",
+                )),
+            },
+            // TC3: online system status is not a notice
+            TestCase {
+                input: r#"{"connectionID": 1, "event": "systemStatus", "status": "online", "version": "1.0.0"}"#,
+                expected: None,
+            },
+            // TC4: heartbeat is not a notice
+            TestCase {
+                input: r#"{"event": "heartbeat"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Kraken::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
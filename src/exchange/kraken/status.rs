@@ -0,0 +1,138 @@
+use super::message::KrakenMessage;
+use crate::{
+    subscription::status::{ExchangeStatus, TradingStatus},
+    transformer::status::StatusUpdate,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Kraken`](super::Kraken) system status WebSocket message.
+pub type KrakenSystemStatusMessage = KrakenMessage<KrakenSystemStatus>;
+
+/// [`Kraken`](super::Kraken) system status, sent upon connection and whenever the status changes.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+/// ```json
+/// {
+///   "connectionID": 8628615390848610000,
+///   "event": "systemStatus",
+///   "status": "online",
+///   "version": "1.0.0"
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenSystemStatus {
+    pub status: KrakenStatus,
+}
+
+/// [`Kraken`](super::Kraken) system status variants.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenStatus {
+    Online,
+    Maintenance,
+    CancelOnly,
+    LimitOnly,
+    PostOnly,
+}
+
+impl From<KrakenStatus> for TradingStatus {
+    fn from(status: KrakenStatus) -> Self {
+        match status {
+            KrakenStatus::Online => TradingStatus::Online,
+            KrakenStatus::Maintenance => TradingStatus::Maintenance,
+            KrakenStatus::CancelOnly => TradingStatus::CancelOnly,
+            KrakenStatus::LimitOnly => TradingStatus::LimitOnly,
+            KrakenStatus::PostOnly => TradingStatus::PostOnly,
+        }
+    }
+}
+
+impl IntoIterator for KrakenSystemStatusMessage {
+    type Item = StatusUpdate;
+    type IntoIter = std::option::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            // Kraken system status messages do not contain a timestamp
+            KrakenMessage::Data(system_status) => Some(StatusUpdate {
+                time: Utc::now(),
+                subscription_id: None,
+                status: ExchangeStatus {
+                    status: TradingStatus::from(system_status.status),
+                    message: None,
+                    start_time: None,
+                    end_time: None,
+                },
+            }),
+            KrakenMessage::Event(_) => None,
+        }
+        .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::kraken::message::KrakenEvent;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_system_status_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenSystemStatusMessage, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenSystemStatus
+                    input: r#"
+                    {
+                        "connectionID": 8628615390848610000,
+                        "event": "systemStatus",
+                        "status": "cancel_only",
+                        "version": "1.0.0"
+                    }
+                    "#,
+                    expected: Ok(KrakenMessage::Data(KrakenSystemStatus {
+                        status: KrakenStatus::CancelOnly,
+                    })),
+                },
+                TestCase {
+                    // TC1: valid KrakenEvent::Heartbeat
+                    input: r#"{"event": "heartbeat"}"#,
+                    expected: Ok(KrakenMessage::Event(KrakenEvent::Heartbeat)),
+                },
+                TestCase {
+                    // TC2: invalid KrakenSystemStatus w/ unknown status
+                    input: r#"{"event": "systemStatus", "status": "unknown"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenSystemStatusMessage>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
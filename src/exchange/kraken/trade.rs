@@ -1,13 +1,13 @@
-use super::KrakenMessage;
+use super::{channel::KrakenChannel, KrakenMessage};
 use crate::{
-    event::{MarketEvent, MarketIter},
-    exchange::ExchangeId,
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{subscription::subscription_id, ExchangeId},
     subscription::trade::PublicTrade,
     Identifier,
 };
 use barter_integration::{
     de::{datetime_utc_from_epoch_duration, extract_next},
-    model::{Exchange, Instrument, Side, SubscriptionId},
+    model::{instrument::Instrument, Exchange, Side, SubscriptionId},
 };
 use chrono::{DateTime, Utc};
 use serde::Serialize;
@@ -51,7 +51,7 @@ impl Identifier<Option<SubscriptionId>> for KrakenTradesInner {
 fn custom_kraken_trade_id(trade: &KrakenTrade) -> String {
     format!(
         "{}_{}_{}_{}",
-        trade.time.timestamp_nanos(),
+        trade.time.timestamp_nanos_opt().unwrap_or_default(),
         trade.side,
         trade.price,
         trade.amount
@@ -68,14 +68,16 @@ impl From<(ExchangeId, Instrument, KrakenTrades)> for MarketIter<PublicTrade> {
                     Ok(MarketEvent {
                         exchange_time: trade.time,
                         received_time: Utc::now(),
+                        received_monotonic: MonotonicTime::now(),
+                        origin: Origin::Live,
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
-                        kind: PublicTrade {
-                            id: custom_kraken_trade_id(&trade),
-                            price: trade.price,
-                            amount: trade.amount,
-                            side: trade.side,
-                        },
+                        kind: PublicTrade::new(
+                            custom_kraken_trade_id(&trade),
+                            trade.price,
+                            trade.amount,
+                            trade.side,
+                        ),
                     })
                 })
                 .collect(),
@@ -120,7 +122,7 @@ impl<'de> serde::de::Deserialize<'de> for KrakenTradesInner {
 
                 // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "trade|{pair}")
                 let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
-                    .map(|pair| SubscriptionId::from(format!("trade|{pair}")))?;
+                    .map(|pair| subscription_id(KrakenChannel::TRADES, pair))?;
 
                 // Ignore any additional elements or SerDe will fail
                 //  '--> Exchange may add fields without warning
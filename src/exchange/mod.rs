@@ -1,11 +1,15 @@
 use self::subscription::ExchangeSub;
+#[cfg(feature = "private")]
+use crate::credentials::{Credentials, KeepAlive};
 use crate::{
     subscriber::{validator::SubscriptionValidator, Subscriber},
     subscription::{Map, SubKind},
     MarketStream,
 };
+#[cfg(feature = "private")]
+use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError, model::Instrument, protocol::websocket::WsMessage, Validator,
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage, Validator,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
@@ -14,7 +18,7 @@ use std::{
 };
 use url::Url;
 
-/// `BinanceSpot` & `BinanceFuturesUsd` [`Connector`] and [`StreamSelector`] implementations.
+/// `BinanceSpot`, `BinanceFuturesUsd` & `BinanceUs` [`Connector`] and [`StreamSelector`] implementations.
 pub mod binance;
 
 /// `Bitfinex` [`Connector`] and [`StreamSelector`] implementations.
@@ -29,6 +33,9 @@ pub mod bybit;
 /// `Coinbase` [`Connector`] and [`StreamSelector`] implementations.
 pub mod coinbase;
 
+/// `Deribit` [`Connector`] and [`StreamSelector`] implementations.
+pub mod deribit;
+
 /// `GateioSpot`, `GateioFuturesUsd` & `GateioFuturesBtc` [`Connector`] and [`StreamSelector`]
 /// implementations.
 pub mod gateio;
@@ -110,6 +117,25 @@ where
     /// Base [`Url`] of the exchange server being connected with.
     fn url() -> Result<Url, SocketError>;
 
+    /// Base [`Url`] of the exchange server that serves the provided [`Self::Channel`].
+    ///
+    /// Defaults to [`Self::url`]. Exchanges that serve some channels from a secondary server
+    /// override this to route those [`Subscription`](crate::subscription::Subscription)s
+    /// transparently (eg/ [`Okx`](okx::Okx) candles are served by the "business" endpoint).
+    fn channel_url(_: &Self::Channel) -> Result<Url, SocketError> {
+        Self::url()
+    }
+
+    /// Alternative regional hosts (eg/ "stream.bytick.com" or "host:port") that serve the same
+    /// paths as the [`Self::channel_url`] host, probed by a
+    /// [`LatencyProbe`](crate::streams::probe::LatencyProbe) to select the lowest latency
+    /// endpoint.
+    ///
+    /// Defaults to no alternative hosts.
+    fn regional_hosts() -> &'static [&'static str] {
+        &[]
+    }
+
     /// Defines [`PingInterval`] of custom application-level
     /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings for the exchange
     /// server being connected with.
@@ -135,10 +161,120 @@ where
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// Documented [`SubscriptionLimits`] of the exchange server, used by the
+    /// [`StreamBuilder`](crate::streams::builder::StreamBuilder) to split
+    /// [`Subscription`](crate::subscription::Subscription)s across connections, or reject them
+    /// with a clear error.
+    ///
+    /// Defaults to [`SubscriptionLimits::UNLIMITED`].
+    fn subscription_limits() -> SubscriptionLimits {
+        SubscriptionLimits::UNLIMITED
+    }
+}
+
+/// Documented subscription limits of an exchange server (see [`Connector::subscription_limits`]).
+///
+/// Exceeding these limits usually results in an opaque disconnection by the exchange server.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct SubscriptionLimits {
+    /// Maximum number of streams (eg/ channel-market pairs) served by a single connection.
+    pub streams_per_connection: Option<usize>,
+    /// Maximum number of subscription args sent in a single subscribe request.
+    pub args_per_request: Option<usize>,
+    /// Maximum number of streams across every connection.
+    pub total_topics: Option<usize>,
+}
+
+impl SubscriptionLimits {
+    /// [`SubscriptionLimits`] of an exchange server without any documented limits.
+    pub const UNLIMITED: Self = Self {
+        streams_per_connection: None,
+        args_per_request: None,
+        total_topics: None,
+    };
+
+    /// Split the provided de-duplicated subscriptions into the fewest connections allowed by the
+    /// `streams_per_connection` limit.
+    ///
+    /// `total` is the number of subscriptions across every connection of the exchange server
+    /// (including these), and is checked against the `total_topics` limit.
+    pub fn split<T>(
+        &self,
+        exchange: ExchangeId,
+        subscriptions: Vec<T>,
+        total: usize,
+    ) -> Result<Vec<Vec<T>>, SocketError> {
+        if let Some(max) = self.total_topics.filter(|max| total > *max) {
+            return Err(SocketError::Subscribe(format!(
+                "{exchange} supports at most {max} total topics, but {total} Subscriptions were \
+                 requested"
+            )));
+        }
+
+        let Some(max) = self.streams_per_connection else {
+            return Ok(vec![subscriptions]);
+        };
+        if max == 0 {
+            return Err(SocketError::Subscribe(format!(
+                "{exchange} streams_per_connection limit must be non-zero"
+            )));
+        }
+
+        let mut connections = Vec::with_capacity(subscriptions.len().div_ceil(max));
+        let mut subscriptions = subscriptions.into_iter().peekable();
+        while subscriptions.peek().is_some() {
+            connections.push(subscriptions.by_ref().take(max).collect());
+        }
+
+        Ok(connections)
+    }
+}
+
+/// Extends a [`Connector`] with the ability to connect to the exchange server that serves private
+/// account streams (eg/ [`UserTrades`](crate::subscription::trade::UserTrades)), authenticating
+/// with the API [`Credentials`] provided in the
+/// [`StreamConfig`](crate::streams::config::StreamConfig). Enabled via the `private` feature.
+#[cfg(feature = "private")]
+#[async_trait]
+pub trait PrivateConnector
+where
+    Self: Connector,
+{
+    /// Authenticate with the exchange using the provided [`Credentials`], returning the
+    /// [`PrivateSession`] used to connect to the private WebSocket server.
+    ///
+    /// This may involve HTTP requests (eg/ creating a Binance listenKey), or signing a login
+    /// [`WsMessage`] that is sent once connected (eg/ Bybit, Okx).
+    async fn authenticate(credentials: &Credentials) -> Result<PrivateSession, SocketError>;
+
+    /// Defines how to translate a collection of [`ExchangeSub`]s into the private [`WsMessage`]
+    /// subscription payloads sent after the [`PrivateSession`] login requests.
+    fn private_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage>;
+
+    /// Number of responses expected from the exchange server in response to the
+    /// [`PrivateSession`] login requests and the [`Self::private_requests`].
+    fn private_expected_responses(map: &Map<Instrument>) -> usize;
+}
+
+/// Authenticated private WebSocket session returned by [`PrivateConnector::authenticate`].
+#[cfg(feature = "private")]
+#[derive(Debug)]
+pub struct PrivateSession {
+    /// Private WebSocket [`Url`] to connect to.
+    pub url: Url,
+    /// Login [`WsMessage`]s sent before any private subscription requests.
+    pub requests: Vec<WsMessage>,
+    /// Optional [`KeepAlive`] task that keeps the session valid (eg/ a Binance listenKey).
+    pub keep_alive: Option<KeepAlive>,
 }
 
 /// Used when an exchange has servers different
-/// [`InstrumentKind`](barter_integration::model::InstrumentKind) market data on distinct servers,
+/// [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind) market data on distinct servers,
 /// allowing all the [`Connector`] logic to be identical apart from what this trait provides.
 ///
 /// ### Examples
@@ -147,6 +283,13 @@ where
 pub trait ExchangeServer: Default + Debug + Clone + Send {
     const ID: ExchangeId;
     fn websocket_url() -> &'static str;
+
+    /// Alternative regional hosts serving the same paths as the [`Self::websocket_url`] host.
+    ///
+    /// See [`Connector::regional_hosts`].
+    fn regional_hosts() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// Defines the frequency and construction function for custom
@@ -161,7 +304,7 @@ pub struct PingInterval {
 /// Unique identifier an exchange server [`Connector`].
 ///
 /// ### Notes
-/// An exchange may server different [`InstrumentKind`](barter_integration::model::InstrumentKind)
+/// An exchange may server different [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind)
 /// market data on distinct servers (eg/ Binance, Gateio). Such exchanges have multiple [`Self`]
 /// variants, and often utilise the [`ExchangeServer`] trait.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
@@ -169,11 +312,14 @@ pub struct PingInterval {
 pub enum ExchangeId {
     BinanceFuturesUsd,
     BinanceSpot,
+    BinanceUs,
     Bitfinex,
     Bitmex,
     BybitSpot,
     BybitFuturesUsd,
+    BybitOptions,
     Coinbase,
+    Deribit,
     GateioFuturesBtc,
     GateioFuturesUsd,
     GateioSpot,
@@ -199,11 +345,14 @@ impl ExchangeId {
         match self {
             ExchangeId::BinanceSpot => "binance_spot",
             ExchangeId::BinanceFuturesUsd => "binance_futures_usd",
+            ExchangeId::BinanceUs => "binance_us",
             ExchangeId::Bitfinex => "bitfinex",
             ExchangeId::Bitmex => "bitmex",
             ExchangeId::BybitSpot => "bybit_spot",
             ExchangeId::BybitFuturesUsd => "bybit_futures_usd",
+            ExchangeId::BybitOptions => "bybit_options",
             ExchangeId::Coinbase => "coinbase",
+            ExchangeId::Deribit => "deribit",
             ExchangeId::GateioSpot => "gateio_spot",
             ExchangeId::GateioFuturesUsd => "gateio_futures_usd",
             ExchangeId::GateioFuturesBtc => "gateio_futures_btc",
@@ -213,18 +362,19 @@ impl ExchangeId {
     }
 
     /// Determines whether the [`Connector`] associated with this [`ExchangeId`] supports the
-    /// ingestion of [`InstrumentKind::Spot`](barter_integration::model::InstrumentKind) market data.
+    /// ingestion of [`InstrumentKind::Spot`](barter_integration::model::instrument::kind::InstrumentKind) market data.
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_spot(&self) -> bool {
         match self {
             ExchangeId::BinanceFuturesUsd => false,
             ExchangeId::BybitFuturesUsd => false,
+            ExchangeId::BybitOptions => false,
             _ => true,
         }
     }
 
     /// Determines whether the [`Connector`] associated with this [`ExchangeId`] supports the
-    /// collection of [`InstrumentKind::Future**`](barter_integration::model::InstrumentKind)
+    /// collection of [`InstrumentKind::Future**`](barter_integration::model::instrument::kind::InstrumentKind)
     /// market data.
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_futures(&self) -> bool {
@@ -232,8 +382,73 @@ impl ExchangeId {
             ExchangeId::BinanceFuturesUsd => true,
             ExchangeId::BybitFuturesUsd => true,
             ExchangeId::Bitmex => true,
+            ExchangeId::Deribit => true,
             ExchangeId::Okx => true,
             _ => false,
         }
     }
+
+    /// Determines whether the [`Connector`] associated with this [`ExchangeId`] supports the
+    /// collection of [`InstrumentKind::Option`](barter_integration::model::instrument::kind::InstrumentKind)
+    /// market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_options(&self) -> bool {
+        match self {
+            ExchangeId::BybitOptions | ExchangeId::Deribit | ExchangeId::Okx => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_limits_split() {
+        struct TestCase {
+            limits: SubscriptionLimits,
+            subscriptions: Vec<u8>,
+            total: usize,
+            expected: Result<Vec<Vec<u8>>, ()>,
+        }
+
+        let cases = vec![
+            // TC0: unlimited exchange uses a single connection
+            TestCase {
+                limits: SubscriptionLimits::UNLIMITED,
+                subscriptions: vec![1, 2, 3],
+                total: 3,
+                expected: Ok(vec![vec![1, 2, 3]]),
+            },
+            // TC1: subscriptions split across the fewest connections
+            TestCase {
+                limits: SubscriptionLimits {
+                    streams_per_connection: Some(2),
+                    ..SubscriptionLimits::UNLIMITED
+                },
+                subscriptions: vec![1, 2, 3, 4, 5],
+                total: 5,
+                expected: Ok(vec![vec![1, 2], vec![3, 4], vec![5]]),
+            },
+            // TC2: total topics across every connection exceeds the limit
+            TestCase {
+                limits: SubscriptionLimits {
+                    total_topics: Some(4),
+                    ..SubscriptionLimits::UNLIMITED
+                },
+                subscriptions: vec![1, 2],
+                total: 5,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test
+                .limits
+                .split(ExchangeId::BybitSpot, test.subscriptions, test.total)
+                .map_err(|_| ());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
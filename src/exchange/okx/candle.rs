@@ -0,0 +1,323 @@
+use super::{channel::OkxChannel, trade::OkxMessage};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::candle::Candle,
+};
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::time::Duration;
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time candlesticks WebSocket message.
+pub type OkxCandles = OkxMessage<OkxCandle>;
+
+/// [`Okx`](super::Okx) real-time candlestick WebSocket message.
+///
+/// Mark price and index candlesticks have no volume, so the data only contains the
+/// `[ts, o, h, l, c, confirm]` fields.
+///
+/// ### Raw Payload Examples
+/// #### Candlestick
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "candle1D",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     [
+///       "1597026383085",
+///       "8533.02",
+///       "8553.74",
+///       "8527.17",
+///       "8548.26",
+///       "45247",
+///       "529.5858061",
+///       "529.5858061",
+///       "1"
+///     ]
+///   ]
+/// }
+/// ```
+///
+/// #### Mark Price Candlestick
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "mark-price-candle1D",
+///     "instId": "BTC-USD-190628"
+///   },
+///   "data": [
+///     ["1597026383085", "3.721", "3.743", "3.677", "3.708", "0"]
+///   ]
+/// }
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-candlesticks-channel>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxCandle {
+    pub open_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub confirmed: bool,
+}
+
+impl<'de> serde::Deserialize<'de> for OkxCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxCandle struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Candle: [ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]
+                // Mark price & index candle: [ts, o, h, l, c, confirm]
+                let open_time = parse::<u64, SeqAccessor::Error>(extract_next(&mut seq, "ts")?)
+                    .map(|epoch_ms| {
+                        datetime_utc_from_epoch_duration(Duration::from_millis(epoch_ms))
+                    })?;
+                let open = parse(extract_next(&mut seq, "o")?)?;
+                let high = parse(extract_next(&mut seq, "h")?)?;
+                let low = parse(extract_next(&mut seq, "l")?)?;
+                let close = parse(extract_next(&mut seq, "c")?)?;
+
+                // Remaining fields are either: [confirm] or [vol, volCcy, volCcyQuote, confirm]
+                let mut remaining = Vec::with_capacity(4);
+                while remaining.len() < 4 {
+                    match seq.next_element::<String>()? {
+                        Some(element) => remaining.push(element),
+                        None => break,
+                    }
+                }
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                let (volume, confirm) = match remaining.as_slice() {
+                    [] => return Err(serde::de::Error::missing_field("vol")),
+                    [confirm] => (0.0, Some(confirm)),
+                    [volume, ..] => (parse(volume.clone())?, remaining.get(3)),
+                };
+                let confirmed = confirm.is_some_and(|confirm| confirm == "1");
+
+                Ok(OkxCandle {
+                    open_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    confirmed,
+                })
+            }
+        }
+
+        /// Parse an [`Okx`](super::Okx) stringified number.
+        fn parse<T, E>(input: String) -> Result<T, E>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+            E: serde::de::Error,
+        {
+            input.parse::<T>().map_err(E::custom)
+        }
+
+        // Use Visitor implementation to deserialise the Okx candle
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxCandles)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candles): (ExchangeId, Instrument, OkxCandles)) -> Self {
+        // Determine the candle Interval from the subscribed channel (eg/ "candle1m|BTC-USDT")
+        let channel = candles
+            .subscription_id
+            .as_ref()
+            .split('|')
+            .next()
+            .unwrap_or_default();
+
+        let Some(interval) = OkxChannel::candle_interval(channel) else {
+            return Self(vec![Err(DataError::Socket(SocketError::Unsupported {
+                entity: exchange_id.as_str(),
+                item: channel.to_owned(),
+            }))]);
+        };
+
+        // Okx pushes in-progress candle updates, so only yield confirmed (ie/ closed) candles
+        candles
+            .data
+            .into_iter()
+            .filter(|candle| candle.confirmed)
+            .map(|candle| {
+                let close_time = candle.open_time + interval.duration();
+                Ok(MarketEvent {
+                    exchange_time: close_time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Candle {
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume,
+                        trade_count: 0,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::model::SubscriptionId;
+
+        #[test]
+        fn test_okx_message_mark_price_candles() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "mark-price-candle1D",
+                    "instId": "BTC-USD-190628"
+                },
+                "data": [
+                    ["1597026383085", "3.721", "3.743", "3.677", "3.708", "1"]
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxCandles>(input).unwrap(),
+                OkxCandles {
+                    subscription_id: SubscriptionId::from("mark-price-candle1D|BTC-USD-190628"),
+                    data: vec![OkxCandle {
+                        open_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                        open: 3.721,
+                        high: 3.743,
+                        low: 3.677,
+                        close: 3.708,
+                        volume: 0.0,
+                        confirmed: true,
+                    }],
+                }
+            );
+        }
+
+        #[test]
+        fn test_okx_message_candles() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "candle1D",
+                    "instId": "BTC-USDT"
+                },
+                "data": [
+                    [
+                        "1597026383085",
+                        "8533.02",
+                        "8553.74",
+                        "8527.17",
+                        "8548.26",
+                        "45247",
+                        "529.5858061",
+                        "529.5858061",
+                        "0"
+                    ]
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxCandles>(input).unwrap(),
+                OkxCandles {
+                    subscription_id: SubscriptionId::from("candle1D|BTC-USDT"),
+                    data: vec![OkxCandle {
+                        open_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                        open: 8533.02,
+                        high: 8553.74,
+                        low: 8527.17,
+                        close: 8548.26,
+                        volume: 45247.0,
+                        confirmed: false,
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_candles_to_market_iter() {
+        let open_time = datetime_utc_from_epoch_duration(Duration::from_millis(1597026383085));
+        let candle = |confirmed| OkxCandle {
+            open_time,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            confirmed,
+        };
+
+        let input = OkxCandles {
+            subscription_id: "candle1m|BTC-USDT".into(),
+            data: vec![candle(false), candle(true)],
+        };
+
+        let actual = MarketIter::<Candle>::from((
+            ExchangeId::Okx,
+            Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::instrument::kind::InstrumentKind::Spot,
+            )),
+            input,
+        ))
+        .0;
+
+        // Only the confirmed candle is yielded
+        assert_eq!(actual.len(), 1);
+        let event = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(
+            event.kind.close_time,
+            open_time + chrono::Duration::minutes(1)
+        );
+        assert_eq!(event.kind.close, 1.5);
+    }
+}
@@ -1,6 +1,17 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        account::{OrderUpdates, Positions},
+        candle::{Candles, Interval},
+        greeks::OptionGreeks,
+        index::CompositeIndices,
+        limit::PriceLimits,
+        liquidation::Liquidations,
+        risk::AdlIndicators,
+        status::SystemStatus,
+        trade::{BlockTrades, PublicTrades, UserTrades},
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +28,253 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] public block trades channel, served by the [`Okx`] "business" endpoint.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#block-trading-websocket-public-channel-block-trades-channel>
+    pub const BLOCK_TRADES: Self = Self("block-trades");
+
+    /// [`Okx`] option summary channel, yielding the greeks of every option contract in an
+    /// instrument family (eg/ "BTC-USD").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-option-summary-channel>
+    pub const OPTION_SUMMARY: Self = Self("opt-summary");
+
+    /// [`Okx`] index tickers channel, subscribed to using an index name (eg/ "BTC-USDT").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+    pub const INDEX_TICKERS: Self = Self("index-tickers");
+
+    /// [`Okx`] price limit channel, yielding the maximum buy and minimum sell price.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-price-limit-channel>
+    pub const PRICE_LIMIT: Self = Self("price-limit");
+
+    /// [`Okx`] liquidation orders channel, yielding the liquidations of every contract of an
+    /// instrument type (eg/ "SWAP").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATION_ORDERS: Self = Self("liquidation-orders");
+
+    /// [`Okx`] auto-deleveraging (ADL) warning channel, yielding the insurance fund state of
+    /// every instrument family of an instrument type (eg/ "SWAP").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-adl-warning-channel>
+    pub const ADL_WARNING: Self = Self("adl-warning");
+
+    /// [`Okx`] system status channel, yielding the scheduled and ongoing maintenance of every
+    /// Okx service.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#status-websocket-status-channel>
+    pub const STATUS: Self = Self("status");
+
+    /// [`Okx`] private order updates channel, served by the [`Okx`] private endpoint.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-private-channel-order-channel>
+    pub const ORDERS: Self = Self("orders");
+
+    /// [`Okx`] private positions channel, served by the [`Okx`] private endpoint.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-private-channel-positions-channel>
+    pub const POSITIONS: Self = Self("positions");
+
+    /// [`Okx`] 1 minute candlesticks channel.
+    ///
+    /// Note that every candlesticks channel, including the mark price and index candlesticks
+    /// channels, is served by the [`Okx`] "business" endpoint.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+    pub const CANDLE_1M: Self = Self("candle1m");
+    pub const CANDLE_3M: Self = Self("candle3m");
+    pub const CANDLE_5M: Self = Self("candle5m");
+    pub const CANDLE_15M: Self = Self("candle15m");
+    pub const CANDLE_30M: Self = Self("candle30m");
+    pub const CANDLE_1H: Self = Self("candle1H");
+    pub const CANDLE_2H: Self = Self("candle2H");
+    pub const CANDLE_4H: Self = Self("candle4H");
+    pub const CANDLE_6H: Self = Self("candle6H");
+    pub const CANDLE_12H: Self = Self("candle12H");
+    pub const CANDLE_1D: Self = Self("candle1D");
+    pub const CANDLE_1W: Self = Self("candle1W");
+
+    /// [`Okx`] 1 minute mark price candlesticks channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-candlesticks-channel>
+    pub const MARK_PRICE_CANDLE_1M: Self = Self("mark-price-candle1m");
+    pub const MARK_PRICE_CANDLE_3M: Self = Self("mark-price-candle3m");
+    pub const MARK_PRICE_CANDLE_5M: Self = Self("mark-price-candle5m");
+    pub const MARK_PRICE_CANDLE_15M: Self = Self("mark-price-candle15m");
+    pub const MARK_PRICE_CANDLE_30M: Self = Self("mark-price-candle30m");
+    pub const MARK_PRICE_CANDLE_1H: Self = Self("mark-price-candle1H");
+    pub const MARK_PRICE_CANDLE_2H: Self = Self("mark-price-candle2H");
+    pub const MARK_PRICE_CANDLE_4H: Self = Self("mark-price-candle4H");
+    pub const MARK_PRICE_CANDLE_6H: Self = Self("mark-price-candle6H");
+    pub const MARK_PRICE_CANDLE_12H: Self = Self("mark-price-candle12H");
+    pub const MARK_PRICE_CANDLE_1D: Self = Self("mark-price-candle1D");
+    pub const MARK_PRICE_CANDLE_1W: Self = Self("mark-price-candle1W");
+
+    /// [`Okx`] 1 minute index candlesticks channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-candlesticks-channel>
+    pub const INDEX_CANDLE_1M: Self = Self("index-candle1m");
+    pub const INDEX_CANDLE_3M: Self = Self("index-candle3m");
+    pub const INDEX_CANDLE_5M: Self = Self("index-candle5m");
+    pub const INDEX_CANDLE_15M: Self = Self("index-candle15m");
+    pub const INDEX_CANDLE_30M: Self = Self("index-candle30m");
+    pub const INDEX_CANDLE_1H: Self = Self("index-candle1H");
+    pub const INDEX_CANDLE_2H: Self = Self("index-candle2H");
+    pub const INDEX_CANDLE_4H: Self = Self("index-candle4H");
+    pub const INDEX_CANDLE_6H: Self = Self("index-candle6H");
+    pub const INDEX_CANDLE_12H: Self = Self("index-candle12H");
+    pub const INDEX_CANDLE_1D: Self = Self("index-candle1D");
+    pub const INDEX_CANDLE_1W: Self = Self("index-candle1W");
+
+    /// Construct the [`Okx`] candlesticks channel for the provided [`Candles`].
+    pub fn candles(candles: Candles) -> Self {
+        match candles {
+            Candles::Last(interval) => Self::candle(interval),
+            Candles::MarkPrice(interval) => Self::mark_price_candle(interval),
+            Candles::IndexPrice(interval) => Self::index_candle(interval),
+        }
+    }
+
+    /// Construct the [`Okx`] candlesticks channel for the provided [`Interval`].
+    pub fn candle(interval: Interval) -> Self {
+        match interval {
+            Interval::Minute1 => Self::CANDLE_1M,
+            Interval::Minute3 => Self::CANDLE_3M,
+            Interval::Minute5 => Self::CANDLE_5M,
+            Interval::Minute15 => Self::CANDLE_15M,
+            Interval::Minute30 => Self::CANDLE_30M,
+            Interval::Hour1 => Self::CANDLE_1H,
+            Interval::Hour2 => Self::CANDLE_2H,
+            Interval::Hour4 => Self::CANDLE_4H,
+            Interval::Hour6 => Self::CANDLE_6H,
+            Interval::Hour12 => Self::CANDLE_12H,
+            Interval::Day1 => Self::CANDLE_1D,
+            Interval::Week1 => Self::CANDLE_1W,
+        }
+    }
+
+    /// Construct the [`Okx`] mark price candlesticks channel for the provided [`Interval`].
+    pub fn mark_price_candle(interval: Interval) -> Self {
+        match interval {
+            Interval::Minute1 => Self::MARK_PRICE_CANDLE_1M,
+            Interval::Minute3 => Self::MARK_PRICE_CANDLE_3M,
+            Interval::Minute5 => Self::MARK_PRICE_CANDLE_5M,
+            Interval::Minute15 => Self::MARK_PRICE_CANDLE_15M,
+            Interval::Minute30 => Self::MARK_PRICE_CANDLE_30M,
+            Interval::Hour1 => Self::MARK_PRICE_CANDLE_1H,
+            Interval::Hour2 => Self::MARK_PRICE_CANDLE_2H,
+            Interval::Hour4 => Self::MARK_PRICE_CANDLE_4H,
+            Interval::Hour6 => Self::MARK_PRICE_CANDLE_6H,
+            Interval::Hour12 => Self::MARK_PRICE_CANDLE_12H,
+            Interval::Day1 => Self::MARK_PRICE_CANDLE_1D,
+            Interval::Week1 => Self::MARK_PRICE_CANDLE_1W,
+        }
+    }
+
+    /// Construct the [`Okx`] index candlesticks channel for the provided [`Interval`].
+    pub fn index_candle(interval: Interval) -> Self {
+        match interval {
+            Interval::Minute1 => Self::INDEX_CANDLE_1M,
+            Interval::Minute3 => Self::INDEX_CANDLE_3M,
+            Interval::Minute5 => Self::INDEX_CANDLE_5M,
+            Interval::Minute15 => Self::INDEX_CANDLE_15M,
+            Interval::Minute30 => Self::INDEX_CANDLE_30M,
+            Interval::Hour1 => Self::INDEX_CANDLE_1H,
+            Interval::Hour2 => Self::INDEX_CANDLE_2H,
+            Interval::Hour4 => Self::INDEX_CANDLE_4H,
+            Interval::Hour6 => Self::INDEX_CANDLE_6H,
+            Interval::Hour12 => Self::INDEX_CANDLE_12H,
+            Interval::Day1 => Self::INDEX_CANDLE_1D,
+            Interval::Week1 => Self::INDEX_CANDLE_1W,
+        }
+    }
+
+    /// Determine the [`Interval`] of an [`Okx`] (mark price or index) candlesticks channel,
+    /// returning `None` if the channel is not a candlesticks channel.
+    pub fn candle_interval(channel: &str) -> Option<Interval> {
+        let channel = channel
+            .strip_prefix("mark-price-")
+            .or_else(|| channel.strip_prefix("index-"))
+            .unwrap_or(channel);
+
+        Some(match channel {
+            "candle1m" => Interval::Minute1,
+            "candle3m" => Interval::Minute3,
+            "candle5m" => Interval::Minute5,
+            "candle15m" => Interval::Minute15,
+            "candle30m" => Interval::Minute30,
+            "candle1H" => Interval::Hour1,
+            "candle2H" => Interval::Hour2,
+            "candle4H" => Interval::Hour4,
+            "candle6H" => Interval::Hour6,
+            "candle12H" => Interval::Hour12,
+            "candle1D" => Interval::Day1,
+            "candle1W" => Interval::Week1,
+            _ => return None,
+        })
+    }
+
+    /// Determines if this [`OkxChannel`] is subscribed to per instrument type (eg/ "SWAP")
+    /// rather than per instrument.
+    pub fn is_instrument_type_wide(&self) -> bool {
+        *self == Self::LIQUIDATION_ORDERS || *self == Self::ADL_WARNING
+    }
+
+    /// Determines if this [`OkxChannel`] is served by the [`Okx`] "business" endpoint.
+    pub fn is_business(&self) -> bool {
+        Self::candle_interval(self.0).is_some() || *self == Self::BLOCK_TRADES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, CompositeIndices> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::INDEX_TICKERS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, PriceLimits> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::PRICE_LIMIT
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATION_ORDERS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, AdlIndicators> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ADL_WARNING
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, SystemStatus> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::STATUS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, UserTrades> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDERS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderUpdates> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDERS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Positions> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::POSITIONS
+    }
 }
 
 impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
@@ -25,6 +283,24 @@ impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
     }
 }
 
+impl Identifier<OkxChannel> for Subscription<Okx, BlockTrades> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::BLOCK_TRADES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OptionGreeks> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::OPTION_SUMMARY
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Candles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::candles(self.kind)
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,248 @@
+use super::{channel::OkxChannel, Okx};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::Connector,
+    subscription::{
+        greeks::{Greeks, OptionGreeks},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange},
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] option summary WebSocket message, containing the greeks & implied volatilities of
+/// every option contract in an instrument family (eg/ "BTC-USD").
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-option-summary-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "opt-summary",
+///     "instFamily": "BTC-USD"
+///   },
+///   "data": [
+///     {
+///       "instType": "OPTION",
+///       "instId": "BTC-USD-241013-70000-P",
+///       "uly": "BTC-USD",
+///       "delta": "-1.1180902625",
+///       "gamma": "2.2361957091",
+///       "vega": "0.0000000001",
+///       "theta": "0.0000032334",
+///       "lever": "8465.7547506357",
+///       "markVol": "0.3675503331",
+///       "bidVol": "0",
+///       "askVol": "1.1669998535",
+///       "realVol": "",
+///       "deltaBS": "-0.9999672034",
+///       "gammaBS": "0.0000000002",
+///       "thetaBS": "28.2649858387",
+///       "vegaBS": "0.0000114332",
+///       "ts": "1728703155650",
+///       "fwdPx": "62604.6993093463",
+///       "volLv": "0.2044711229"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOptionSummaries {
+    pub data: Vec<OkxOptionSummary>,
+}
+
+/// [`Okx`] option contract summary contained within an [`OkxOptionSummaries`] message.
+///
+/// The Black-Scholes greeks (eg/ "deltaBS") are used since they are denominated in the quote
+/// currency, consistent with other exchanges.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOptionSummary {
+    #[serde(rename = "instId")]
+    pub market: String,
+    #[serde(
+        rename = "deltaBS",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub delta: f64,
+    #[serde(
+        rename = "gammaBS",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub gamma: f64,
+    #[serde(rename = "vegaBS", deserialize_with = "barter_integration::de::de_str")]
+    pub vega: f64,
+    #[serde(
+        rename = "thetaBS",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub theta: f64,
+    #[serde(
+        rename = "markVol",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub mark_iv: f64,
+    #[serde(rename = "bidVol", deserialize_with = "barter_integration::de::de_str")]
+    pub bid_iv: f64,
+    #[serde(rename = "askVol", deserialize_with = "barter_integration::de::de_str")]
+    pub ask_iv: f64,
+    #[serde(rename = "fwdPx", deserialize_with = "barter_integration::de::de_str")]
+    pub forward_price: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] [`OptionGreeks`] [`ExchangeTransformer`].
+///
+/// Okx streams option summaries per instrument family (eg/ "BTC-USD") rather than per contract,
+/// so every summary is routed to it's subscribed [`Instrument`] using the contract instId (eg/
+/// "BTC-USD-241013-70000-P"). Summaries of contracts that were not subscribed to are ignored.
+///
+/// Okx option summaries do not contain a mark price, and the underlying price is the forward
+/// price of the contract expiry.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OkxOptionGreeks {
+    instrument_map: Map<Instrument>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, OptionGreeks> for OkxOptionGreeks {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self { instrument_map })
+    }
+}
+
+impl Transformer for OkxOptionGreeks {
+    type Error = DataError;
+    type Input = OkxOptionSummaries;
+    type Output = MarketEvent<Greeks>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let non_zero = |iv: f64| (iv != 0.0).then_some(iv);
+
+        input
+            .data
+            .into_iter()
+            .filter_map(|summary| {
+                // Route each summary using it's own instId, ignoring un-subscribed contracts
+                let instrument = self
+                    .instrument_map
+                    .find_channel_market(OkxChannel::OPTION_SUMMARY.as_ref(), &summary.market)
+                    .ok()?;
+
+                Some(Ok(MarketEvent {
+                    exchange_time: summary.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(Okx::ID),
+                    instrument,
+                    kind: Greeks {
+                        delta: summary.delta,
+                        gamma: summary.gamma,
+                        vega: summary.vega,
+                        theta: summary.theta,
+                        mark_price: None,
+                        mark_iv: summary.mark_iv,
+                        bid_iv: non_zero(summary.bid_iv),
+                        ask_iv: non_zero(summary.ask_iv),
+                        underlying_price: summary.forward_price,
+                    },
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{
+        instrument::kind::{InstrumentKind, OptionContract, OptionExercise, OptionKind},
+        SubscriptionId,
+    };
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_okx_option_greeks_transform() {
+        let input = r#"
+        {
+            "arg": {"channel": "opt-summary", "instFamily": "BTC-USD"},
+            "data": [
+                {
+                    "instType": "OPTION", "instId": "BTC-USD-241013-70000-P", "uly": "BTC-USD",
+                    "delta": "-1.1180902625", "gamma": "2.2361957091", "vega": "0.0000000001",
+                    "theta": "0.0000032334", "lever": "8465.7547506357", "markVol": "0.3675503331",
+                    "bidVol": "0", "askVol": "1.1669998535", "realVol": "",
+                    "deltaBS": "-0.9999672034", "gammaBS": "0.0000000002", "thetaBS": "28.2649858387",
+                    "vegaBS": "0.0000114332", "ts": "1728703155650", "fwdPx": "62604.6993093463",
+                    "volLv": "0.2044711229"
+                },
+                {
+                    "instType": "OPTION", "instId": "BTC-USD-241013-75000-P", "uly": "BTC-USD",
+                    "delta": "-1", "gamma": "0", "vega": "0", "theta": "0", "lever": "1",
+                    "markVol": "0.4", "bidVol": "0.3", "askVol": "0.5", "realVol": "",
+                    "deltaBS": "-1", "gammaBS": "0", "thetaBS": "0", "vegaBS": "0",
+                    "ts": "1728703155650", "fwdPx": "62604.6993093463", "volLv": "0.2"
+                }
+            ]
+        }
+        "#;
+
+        let instrument = Instrument::from((
+            "btc",
+            "usd",
+            InstrumentKind::Option(OptionContract {
+                kind: OptionKind::Put,
+                exercise: OptionExercise::European,
+                expiry: Utc.with_ymd_and_hms(2024, 10, 13, 8, 0, 0).unwrap(),
+                strike: rust_decimal::Decimal::from(70000),
+            }),
+        ));
+
+        let mut transformer = OkxOptionGreeks {
+            instrument_map: Map::from_iter([(
+                SubscriptionId::from("opt-summary|BTC-USD-241013-70000-P"),
+                instrument.clone(),
+            )]),
+        };
+
+        let actual = transformer
+            .transform(serde_json::from_str::<OkxOptionSummaries>(input).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![(
+                instrument,
+                Greeks {
+                    delta: -0.9999672034,
+                    gamma: 0.0000000002,
+                    vega: 0.0000114332,
+                    theta: 28.2649858387,
+                    mark_price: None,
+                    mark_iv: 0.3675503331,
+                    bid_iv: None,
+                    ask_iv: Some(1.1669998535),
+                    underlying_price: 62604.6993093463,
+                }
+            )]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::index::IndexConstituents,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) index tickers WebSocket message.
+pub type OkxIndexTickers = OkxMessage<OkxIndexTicker>;
+
+/// [`Okx`](super::Okx) index ticker, subscribed to using the index name (eg/ "BTC-USDT") of a
+/// [`Spot`](barter_integration::model::instrument::kind::InstrumentKind::Spot) [`Instrument`].
+///
+/// Okx does not stream the index constituents, so only the index price is normalised.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "index-tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instId": "BTC-USDT",
+///       "idxPx": "0.1",
+///       "high24h": "0.5",
+///       "low24h": "0.1",
+///       "open24h": "0.1",
+///       "sodUtc0": "0.1",
+///       "sodUtc8": "0.1",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxIndexTicker {
+    #[serde(rename = "idxPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxIndexTickers)> for MarketIter<IndexConstituents> {
+    fn from((exchange_id, instrument, tickers): (ExchangeId, Instrument, OkxIndexTickers)) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: ticker.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: IndexConstituents {
+                        price: ticker.price,
+                        constituents: vec![],
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_index_tickers() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "index-tickers",
+                    "instId": "BTC-USDT"
+                },
+                "data": [
+                    {
+                        "instId": "BTC-USDT",
+                        "idxPx": "0.1",
+                        "high24h": "0.5",
+                        "low24h": "0.1",
+                        "open24h": "0.1",
+                        "sodUtc0": "0.1",
+                        "sodUtc8": "0.1",
+                        "ts": "1597026383085"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxIndexTickers>(input).unwrap(),
+                OkxIndexTickers {
+                    subscription_id: SubscriptionId::from("index-tickers|BTC-USDT"),
+                    data: vec![OkxIndexTicker {
+                        price: 0.1,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use super::Okx;
+use crate::subscription::market_wide::{MarketSymbols, SymbolTable};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::symbol::Symbol};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] HTTP perpetual swap instruments url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_SWAP_INSTRUMENTS_URL_OKX: &str =
+    "https://www.okx.com/api/v5/public/instruments?instType=SWAP";
+
+/// [`Okx`] HTTP instruments response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///   "code": "0",
+///   "msg": "",
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "instFamily": "BTC-USDT",
+///       "uly": "BTC-USDT",
+///       "baseCcy": "",
+///       "quoteCcy": "",
+///       "settleCcy": "USDT",
+///       "ctVal": "0.01",
+///       "ctValCcy": "BTC",
+///       "state": "live"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub data: Vec<OkxInstrument>,
+}
+
+/// [`Okx`] instrument contained within an [`OkxInstruments`] response.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstrument {
+    #[serde(alias = "instId")]
+    pub id: String,
+    #[serde(alias = "instFamily")]
+    pub family: String,
+}
+
+impl OkxInstrument {
+    /// Base & quote assets of the instrument family (eg/ "BTC-USDT" -> ("btc", "usdt")).
+    pub fn assets(&self) -> Option<(Symbol, Symbol)> {
+        self.family
+            .split_once('-')
+            .map(|(base, quote)| (Symbol::new(base), Symbol::new(quote)))
+    }
+}
+
+#[async_trait]
+impl MarketSymbols for Okx {
+    /// Fetch the [`SymbolTable`] of every [`Okx`] perpetual swap, keyed by instId (eg/
+    /// "BTC-USDT-SWAP").
+    async fn market_symbols() -> Result<SymbolTable, SocketError> {
+        Ok(reqwest::get(HTTP_SWAP_INSTRUMENTS_URL_OKX)
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<OkxInstruments>()
+            .await
+            .map_err(SocketError::Http)?
+            .data
+            .into_iter()
+            .filter_map(|instrument| instrument.assets().map(|assets| (instrument.id, assets)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_instruments() {
+            let input = r#"
+            {
+                "code": "0",
+                "msg": "",
+                "data": [
+                    {
+                        "instType": "SWAP",
+                        "instId": "BTC-USDT-SWAP",
+                        "instFamily": "BTC-USDT",
+                        "uly": "BTC-USDT",
+                        "baseCcy": "",
+                        "quoteCcy": "",
+                        "settleCcy": "USDT",
+                        "ctVal": "0.01",
+                        "ctValCcy": "BTC",
+                        "state": "live"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxInstruments>(input).unwrap();
+            assert_eq!(
+                actual,
+                OkxInstruments {
+                    data: vec![OkxInstrument {
+                        id: "BTC-USDT-SWAP".to_owned(),
+                        family: "BTC-USDT".to_owned(),
+                    }],
+                }
+            );
+            assert_eq!(
+                actual.data[0].assets(),
+                Some((Symbol::new("btc"), Symbol::new("usdt")))
+            );
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::limit::PriceLimit,
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) price limit WebSocket message.
+pub type OkxPriceLimits = OkxMessage<OkxPriceLimit>;
+
+/// [`Okx`](super::Okx) maximum buy and minimum sell price, which are empty if the price limit is
+/// not enabled.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-price-limit-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "price-limit",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instId": "BTC-USDT-SWAP",
+///       "buyLmt": "200",
+///       "sellLmt": "300",
+///       "ts": "1597026383085",
+///       "enabled": true
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxPriceLimit {
+    #[serde(rename = "buyLmt", deserialize_with = "de_okx_optional_f64")]
+    pub buy_limit: Option<f64>,
+    #[serde(rename = "sellLmt", deserialize_with = "de_okx_optional_f64")]
+    pub sell_limit: Option<f64>,
+    pub enabled: bool,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<OkxPriceLimit> for PriceLimit {
+    fn from(limit: OkxPriceLimit) -> Self {
+        if limit.enabled {
+            Self {
+                buy_limit: limit.buy_limit,
+                sell_limit: limit.sell_limit,
+            }
+        } else {
+            Self::default()
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxPriceLimits)> for MarketIter<PriceLimit> {
+    fn from((exchange_id, instrument, limits): (ExchangeId, Instrument, OkxPriceLimits)) -> Self {
+        limits
+            .data
+            .into_iter()
+            .map(|limit| {
+                Ok(MarketEvent {
+                    exchange_time: limit.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: PriceLimit::from(limit),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize an [`Okx`](super::Okx) String f64 that is empty if not applicable.
+pub fn de_okx_optional_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    input.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_okx_price_limit() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<PriceLimit, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: enabled price limit
+                    input: r#"
+                    {
+                        "instId": "BTC-USDT-SWAP",
+                        "buyLmt": "200",
+                        "sellLmt": "300",
+                        "ts": "1597026383085",
+                        "enabled": true
+                    }
+                    "#,
+                    expected: Ok(PriceLimit {
+                        buy_limit: Some(200.0),
+                        sell_limit: Some(300.0),
+                    }),
+                },
+                TestCase {
+                    // TC1: disabled price limit
+                    input: r#"
+                    {
+                        "instId": "BTC-USDT-SWAP",
+                        "buyLmt": "",
+                        "sellLmt": "",
+                        "ts": "1597026383085",
+                        "enabled": false
+                    }
+                    "#,
+                    expected: Ok(PriceLimit {
+                        buy_limit: None,
+                        sell_limit: None,
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid price limit
+                    input: r#"
+                    {
+                        "instId": "BTC-USDT-SWAP",
+                        "buyLmt": "invalid",
+                        "sellLmt": "300",
+                        "ts": "1597026383085",
+                        "enabled": true
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    serde_json::from_str::<OkxPriceLimit>(test.input).map(PriceLimit::from);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
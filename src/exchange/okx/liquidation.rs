@@ -0,0 +1,285 @@
+use super::{channel::OkxChannel, Okx};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::Connector,
+    subscription::{
+        liquidation::{Liquidation, Liquidations},
+        market_wide::MarketWideMap,
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    },
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] liquidation orders WebSocket message, containing the liquidations of every contract of
+/// an instrument type (eg/ "SWAP").
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationOrders {
+    pub data: Vec<OkxLiquidationOrder>,
+}
+
+/// [`Okx`] liquidation orders of a single contract, contained within an
+/// [`OkxLiquidationOrders`] message.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationOrder {
+    #[serde(rename = "instId")]
+    pub market: String,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+/// [`Okx`] liquidation order, where the size is the number of contracts for derivatives.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] [`Liquidations`] [`ExchangeTransformer`].
+///
+/// Okx streams liquidations per instrument type (eg/ "SWAP") rather than per contract, so each
+/// liquidation is routed to it's subscribed [`Instrument`] using the contract instId (eg/
+/// "BTC-USDT-SWAP"). Liquidations of contracts that were not subscribed to are ignored.
+///
+/// [`Liquidations::AllMarket`] subscriptions resolve perpetual swap liquidations only, since the
+/// expiry of other contracts is not known.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OkxLiquidations {
+    instrument_map: MarketWideMap,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, Liquidations> for OkxLiquidations {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map: MarketWideMap::init::<Okx>(instrument_map).await?,
+        })
+    }
+}
+
+impl OkxLiquidations {
+    /// Resolve the subscribed [`Instrument`] of the provided contract instId, if any.
+    fn resolve(&mut self, market: &str) -> Option<Instrument> {
+        if let Ok(instrument) = self
+            .instrument_map
+            .instrument_map
+            .find_channel_market(OkxChannel::LIQUIDATION_ORDERS.as_ref(), market)
+        {
+            return Some(instrument);
+        }
+
+        // Market-wide subscriptions resolve listed perpetual swaps (eg/ "BTC-USDT-SWAP")
+        self.instrument_map
+            .find_market_wide(market)
+            .filter(|instrument| instrument.kind == InstrumentKind::Perpetual)
+    }
+}
+
+impl Transformer for OkxLiquidations {
+    type Error = DataError;
+    type Input = OkxLiquidationOrders;
+    type Output = MarketEvent<Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .data
+            .into_iter()
+            .filter_map(|order| Some((self.resolve(&order.market)?, order.details)))
+            .flat_map(|(instrument, details)| {
+                details.into_iter().map(move |detail| {
+                    Ok(MarketEvent {
+                        exchange_time: detail.time,
+                        received_time: Utc::now(),
+                        received_monotonic: MonotonicTime::now(),
+                        origin: Origin::Live,
+                        exchange: Exchange::from(Okx::ID),
+                        instrument: instrument.clone(),
+                        kind: Liquidation {
+                            side: detail.side,
+                            price: detail.price,
+                            quantity: detail.quantity,
+                            time: detail.time,
+                        },
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::market_wide::SymbolTable;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        model::{instrument::symbol::Symbol, SubscriptionId},
+    };
+    use std::{sync::Arc, time::Duration};
+
+    fn liquidations(market: &str) -> OkxLiquidationOrders {
+        serde_json::from_str(&format!(
+            r#"
+            {{
+                "arg": {{"channel": "liquidation-orders", "instType": "SWAP"}},
+                "data": [
+                    {{
+                        "details": [
+                            {{
+                                "bkLoss": "0", "bkPx": "0.007831", "ccy": "", "posSide": "short",
+                                "side": "buy", "sz": "13", "ts": "1692266434010"
+                            }}
+                        ],
+                        "instFamily": "", "instId": "{market}", "instType": "SWAP", "uly": ""
+                    }}
+                ]
+            }}
+            "#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_okx_liquidations_transform() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Instrument>,
+        }
+
+        let eth = Instrument::from(("eth", "btc", InstrumentKind::Perpetual));
+        let mut transformer = OkxLiquidations {
+            instrument_map: MarketWideMap::new(
+                Map::from_iter([
+                    (
+                        SubscriptionId::from("liquidation-orders|ETH-BTC-SWAP"),
+                        eth.clone(),
+                    ),
+                    (
+                        SubscriptionId::from("liquidation-orders|*-USDT-SWAP"),
+                        Instrument::from(("*", "usdt", InstrumentKind::Perpetual)),
+                    ),
+                ]),
+                Arc::new(SymbolTable::from_iter(
+                    [
+                        ("ETH-BTC-SWAP", "eth", "btc"),
+                        ("IOST-USDT-SWAP", "iost", "usdt"),
+                        ("BTC-USD-SWAP", "btc", "usd"),
+                    ]
+                    .map(|(symbol, base, quote)| {
+                        (symbol.to_owned(), (Symbol::new(base), Symbol::new(quote)))
+                    }),
+                )),
+            ),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: individual subscription
+                input: "ETH-BTC-SWAP",
+                expected: Some(eth),
+            },
+            TestCase {
+                // TC1: perpetual swap resolved by the market-wide subscription
+                input: "IOST-USDT-SWAP",
+                expected: Some(Instrument::from((
+                    "iost",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                ))),
+            },
+            TestCase {
+                // TC2: un-subscribed quote asset is ignored
+                input: "BTC-USD-SWAP",
+                expected: None,
+            },
+            TestCase {
+                // TC3: expiring future is ignored by the market-wide subscription
+                input: "BTC-USDT-241227",
+                expected: None,
+            },
+            TestCase {
+                // TC4: perpetual swap that is not listed by the exchange is ignored
+                input: "XYZ-USDT-SWAP",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer.transform(liquidations(test.input));
+            let expected = test
+                .expected
+                .map(|instrument| {
+                    vec![(
+                        instrument,
+                        Liquidation {
+                            side: Side::Buy,
+                            price: 0.007831,
+                            quantity: 13.0,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1692266434010,
+                            )),
+                        },
+                    )]
+                })
+                .unwrap_or_default();
+
+            let actual = actual
+                .into_iter()
+                .map(|event| {
+                    let event = event.unwrap();
+                    (event.instrument, event.kind)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::Okx;
 use crate::{subscription::Subscription, Identifier};
-use barter_integration::model::InstrumentKind;
+use barter_integration::model::instrument::kind::{InstrumentKind, OptionKind};
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
@@ -16,13 +16,57 @@ impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind> {
             InstrumentKind::Spot => {
                 format!("{}-{}", self.instrument.base, self.instrument.quote).to_uppercase()
             }
-            InstrumentKind::FuturePerpetual => {
+            InstrumentKind::Perpetual => {
                 format!("{}-{}-SWAP", self.instrument.base, self.instrument.quote).to_uppercase()
             }
+            InstrumentKind::Future(future) => format!(
+                "{}-{}-{}",
+                self.instrument.base,
+                self.instrument.quote,
+                future.expiry.format("%y%m%d")
+            )
+            .to_uppercase(),
+            InstrumentKind::Option(option) => format!(
+                "{}-{}-{}-{}-{}",
+                self.instrument.base,
+                self.instrument.quote,
+                option.expiry.format("%y%m%d"),
+                option.strike.normalize(),
+                match option.kind {
+                    OptionKind::Call => "C",
+                    OptionKind::Put => "P",
+                }
+            )
+            .to_uppercase(),
         })
     }
 }
 
+impl OkxMarket {
+    /// Determine the instrument family of this market (eg/ "BTC-USD" for
+    /// "BTC-USD-241013-70000-P").
+    pub fn instrument_family(&self) -> &str {
+        self.0
+            .match_indices('-')
+            .nth(1)
+            .map_or(self.0.as_str(), |(index, _)| &self.0[..index])
+    }
+
+    /// Determine the instrument type of this market (eg/ "SWAP" for "BTC-USDT-SWAP"), used to
+    /// subscribe to channels that are served per instrument type.
+    pub fn instrument_type(&self) -> &'static str {
+        if self.0.ends_with("-SWAP") {
+            return "SWAP";
+        }
+
+        match self.0.matches('-').count() {
+            0 | 1 => "SPOT",
+            2 => "FUTURES",
+            _ => "OPTION",
+        }
+    }
+}
+
 impl AsRef<str> for OkxMarket {
     fn as_ref(&self) -> &str {
         &self.0
@@ -1,22 +1,75 @@
+#[cfg(feature = "private")]
+use self::user::{OkxOrders, OkxPositions};
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    candle::OkxCandles,
+    channel::OkxChannel,
+    greeks::OkxOptionGreeks,
+    index::OkxIndexTickers,
+    limit::OkxPriceLimits,
+    liquidation::OkxLiquidations,
+    market::OkxMarket,
+    risk::OkxAdlIndicators,
+    status::OkxStatuses,
+    subscription::OkxSubResponse,
+    trade::{OkxBlockTrades, OkxTrades},
+    validator::OkxSubValidator,
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscriber::WebSocketSubscriber,
+    subscription::{
+        candle::Candles,
+        greeks::OptionGreeks,
+        index::CompositeIndices,
+        limit::PriceLimits,
+        liquidation::Liquidations,
+        risk::AdlIndicators,
+        status::SystemStatus,
+        trade::{BlockTrades, PublicTrades},
+        Map,
+    },
+    transformer::{stateless::StatelessTransformer, status::StatusTransformer},
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+#[cfg(feature = "private")]
+use crate::{
+    subscription::{
+        account::{OrderUpdates, Positions},
+        trade::UserTrades,
+    },
+    PrivateWsStream,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// Candlestick types for [`Okx`].
+pub mod candle;
+
+/// [`NoticeParser`](crate::notice::NoticeParser) implementation for Okx error & notice
+/// messages.
+pub mod notice;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// HTTP perpetual swap instruments [`MarketSymbols`](crate::subscription::market_wide::MarketSymbols)
+/// implementation for [`Okx`].
+pub mod instrument;
+
+/// Price limit types for [`Okx`].
+pub mod limit;
+
+/// Liquidation orders [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) yielding
+/// [`Liquidations`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -25,14 +78,70 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
 
+/// ADL warning [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) yielding
+/// [`AdlIndicators`].
+pub mod risk;
+
+/// System status types for [`Okx`].
+pub mod status;
+
+/// HTTP server time [`ServerClock`](crate::clock::ServerClock) implementation for [`Okx`].
+pub mod time;
+
+/// Option summary [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) yielding
+/// [`OptionGreeks`].
+pub mod greeks;
+
+/// Index ticker types for [`Okx`].
+pub mod index;
+
 /// Public trade types for [`Okx`].
 pub mod trade;
 
+/// [`Okx`] specific [`SubscriptionValidator`](crate::subscriber::validator::SubscriptionValidator)
+/// that correlates each subscription acknowledgement with it's subscription arg.
+pub mod validator;
+
+/// Private order types and the authenticated [`PrivateConnector`](super::PrivateConnector)
+/// implementation for [`Okx`]. Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub mod user;
+
 /// [`Okx`] server base url.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#overview-api-resources-and-support>
 pub const BASE_URL_OKX: &str = "wss://wsaws.okx.com:8443/ws/v5/public";
 
+/// [`Okx`] "business" server base url that serves candlestick channels.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-production-trading-services>
+pub const BASE_URL_OKX_BUSINESS: &str = "wss://ws.okx.com:8443/ws/v5/business";
+
+/// [`Okx`] regional hosts, where "wsaws.okx.com" is served from AWS & "ws.okx.com" from the
+/// primary data centre.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-production-trading-services>
+pub const REGIONAL_HOSTS_OKX: &[&str] = &["ws.okx.com:8443", "wsaws.okx.com:8443"];
+
+/// Maximum length of an [`Okx`] subscribe frame, including every batched subscription arg.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-subscribe>
+pub const MAX_SUBSCRIBE_FRAME_BYTES: usize = 64 * 1024;
+
+/// Length of an [`Okx`] subscribe frame without any subscription args.
+const SUBSCRIBE_FRAME_OVERHEAD_BYTES: usize = r#"{"args":[],"op":"subscribe"}"#.len();
+
+/// Construct an [`Okx`] subscribe frame from the provided batch of subscription args.
+fn subscribe_frame(args: Vec<serde_json::Value>) -> WsMessage {
+    WsMessage::Text(
+        json!({
+            "op": "subscribe",
+            "args": args,
+        })
+        .to_string(),
+    )
+}
+
 /// [`Okx`] exchange.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api>
@@ -46,24 +155,298 @@ impl Connector for Okx {
     type Channel = OkxChannel;
     type Market = OkxMarket;
     type Subscriber = WebSocketSubscriber;
-    type SubValidator = WebSocketSubValidator;
+    type SubValidator = OkxSubValidator;
     type SubResponse = OkxSubResponse;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(BASE_URL_OKX).map_err(SocketError::UrlParse)
     }
 
+    fn channel_url(channel: &Self::Channel) -> Result<Url, SocketError> {
+        if channel.is_business() {
+            Url::parse(BASE_URL_OKX_BUSINESS).map_err(SocketError::UrlParse)
+        } else {
+            Self::url()
+        }
+    }
+
+    fn regional_hosts() -> &'static [&'static str] {
+        REGIONAL_HOSTS_OKX
+    }
+
+    /// Option summaries are subscribed to per instrument family, so every option contract of a
+    /// family shares one subscription. Liquidation orders & ADL warnings are subscribed to per
+    /// instrument type (eg/ "SWAP"). Every instrument shares one system status subscription.
+    ///
+    /// Every subscription of a connection is batched into as few subscribe frames as the
+    /// [`MAX_SUBSCRIBE_FRAME_BYTES`] limit allows.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        vec![WsMessage::Text(
-            json!({
-                "op": "subscribe",
-                "args": &exchange_subs,
+        let mut exchange_subs = exchange_subs
+            .into_iter()
+            .map(|sub| match sub.channel {
+                OkxChannel::OPTION_SUMMARY => ExchangeSub {
+                    market: OkxMarket(sub.market.instrument_family().to_owned()),
+                    channel: sub.channel,
+                },
+                OkxChannel::STATUS => ExchangeSub {
+                    market: OkxMarket(String::new()),
+                    channel: sub.channel,
+                },
+                channel if channel.is_instrument_type_wide() => ExchangeSub {
+                    market: OkxMarket(sub.market.instrument_type().to_owned()),
+                    channel: sub.channel,
+                },
+                _ => sub,
             })
-            .to_string(),
-        )]
+            .collect::<Vec<_>>();
+        exchange_subs.sort();
+        exchange_subs.dedup();
+
+        // Batch every subscription into as few subscribe frames as the frame size limit allows
+        let mut frames = Vec::new();
+        let mut args = Vec::new();
+        let mut frame_len = SUBSCRIBE_FRAME_OVERHEAD_BYTES;
+
+        for sub in exchange_subs {
+            let arg = serde_json::to_value(&sub).expect("OkxSubArg always serialises");
+            let arg_len = arg.to_string().len() + 1;
+
+            if !args.is_empty() && frame_len + arg_len > MAX_SUBSCRIBE_FRAME_BYTES {
+                frames.push(subscribe_frame(std::mem::take(&mut args)));
+                frame_len = SUBSCRIBE_FRAME_OVERHEAD_BYTES;
+            }
+
+            frame_len += arg_len;
+            args.push(arg);
+        }
+
+        if !args.is_empty() {
+            frames.push(subscribe_frame(args));
+        }
+
+        frames
+    }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        expected_subscriptions(map).len()
     }
 }
 
+/// Determine the sorted & de-duplicated [`SubscriptionId`]s that are expected to be
+/// acknowledged by [`Okx`] for the provided [`Map<Instrument>`].
+///
+/// Every option contract of an instrument family shares one option summary subscription, every
+/// contract of an instrument type shares one liquidation orders & ADL warning subscription, and
+/// every instrument shares one system status subscription.
+pub fn expected_subscriptions(map: &Map<Instrument>) -> Vec<SubscriptionId> {
+    let option_summary_prefix = format!("{}|", OkxChannel::OPTION_SUMMARY.as_ref());
+    let status_prefix = format!("{}|", OkxChannel::STATUS.as_ref());
+    let instrument_type_prefixes = [OkxChannel::LIQUIDATION_ORDERS, OkxChannel::ADL_WARNING]
+        .map(|channel| format!("{}|", channel.as_ref()));
+    let mut subscriptions = map
+        .0
+        .keys()
+        .map(|id| {
+            let id = id.as_ref();
+            let instrument_type_wide = instrument_type_prefixes
+                .iter()
+                .find_map(|prefix| Some((prefix, id.strip_prefix(prefix.as_str())?)));
+
+            if let Some(market) = id.strip_prefix(&option_summary_prefix) {
+                let family = OkxMarket(market.to_owned()).instrument_family().to_owned();
+                SubscriptionId(format!("{option_summary_prefix}{family}"))
+            } else if let Some((prefix, market)) = instrument_type_wide {
+                let instrument_type = OkxMarket(market.to_owned()).instrument_type();
+                SubscriptionId(format!("{prefix}{instrument_type}"))
+            } else if id.starts_with(&status_prefix) {
+                SubscriptionId(status_prefix.clone())
+            } else {
+                SubscriptionId(id.to_owned())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    subscriptions.sort();
+    subscriptions.dedup();
+    subscriptions
+}
+
 impl StreamSelector<PublicTrades> for Okx {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, OkxTrades>>;
 }
+
+impl StreamSelector<BlockTrades> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, BlockTrades, OkxBlockTrades>>;
+}
+
+impl StreamSelector<OptionGreeks> for Okx {
+    type Stream = ExchangeWsStream<OkxOptionGreeks>;
+}
+
+impl StreamSelector<Candles> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, OkxCandles>>;
+}
+
+impl StreamSelector<CompositeIndices> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, CompositeIndices, OkxIndexTickers>>;
+}
+
+impl StreamSelector<PriceLimits> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PriceLimits, OkxPriceLimits>>;
+}
+
+impl StreamSelector<Liquidations> for Okx {
+    type Stream = ExchangeWsStream<OkxLiquidations>;
+}
+
+impl StreamSelector<AdlIndicators> for Okx {
+    type Stream = ExchangeWsStream<OkxAdlIndicators>;
+}
+
+impl StreamSelector<SystemStatus> for Okx {
+    type Stream = ExchangeWsStream<StatusTransformer<Self, OkxStatuses>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<UserTrades> for Okx {
+    type Stream = PrivateWsStream<StatelessTransformer<Self, UserTrades, OkxOrders>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<OrderUpdates> for Okx {
+    type Stream = PrivateWsStream<StatelessTransformer<Self, OrderUpdates, OkxOrders>>;
+}
+
+#[cfg(feature = "private")]
+impl StreamSelector<Positions> for Okx {
+    type Stream = PrivateWsStream<StatelessTransformer<Self, Positions, OkxPositions>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Identifier;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_okx_status_requests() {
+        let exchange_subs = ["BTC-USDT", "ETH-USDT"]
+            .into_iter()
+            .map(|market| ExchangeSub::from((OkxChannel::STATUS, OkxMarket(market.into()))))
+            .collect::<Vec<_>>();
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let map = exchange_subs
+            .iter()
+            .map(|sub| (sub.id(), instrument.clone()))
+            .collect::<Map<Instrument>>();
+
+        assert_eq!(Okx::expected_responses(&map), 1);
+        assert_eq!(
+            Okx::requests(exchange_subs),
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": [{"channel": "status"}],
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_okx_batched_requests() {
+        let exchange_subs = (0..4000)
+            .map(|index| {
+                ExchangeSub::from((OkxChannel::TRADES, OkxMarket(format!("BTC-USD-{index:06}"))))
+            })
+            .collect::<Vec<_>>();
+
+        let requests = Okx::requests(exchange_subs);
+
+        // Subscriptions are batched into as few frames as the frame size limit allows
+        assert_eq!(requests.len(), 3);
+
+        let mut args = 0;
+        for request in requests {
+            let WsMessage::Text(request) = request else {
+                panic!("expected text subscribe frame");
+            };
+            assert!(request.len() <= MAX_SUBSCRIBE_FRAME_BYTES);
+
+            let request = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+            assert_eq!(request["op"], "subscribe");
+            args += request["args"].as_array().unwrap().len();
+        }
+        assert_eq!(args, 4000);
+    }
+
+    #[test]
+    fn test_okx_option_summary_requests() {
+        let exchange_subs = ["BTC-USD-241013-70000-P", "BTC-USD-241013-75000-C"]
+            .into_iter()
+            .map(|market| ExchangeSub::from((OkxChannel::OPTION_SUMMARY, OkxMarket(market.into()))))
+            .chain([ExchangeSub::from((
+                OkxChannel::TRADES,
+                OkxMarket("BTC-USDT".into()),
+            ))])
+            .collect::<Vec<_>>();
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let map = exchange_subs
+            .iter()
+            .map(|sub| (sub.id(), instrument.clone()))
+            .collect::<Map<Instrument>>();
+
+        assert_eq!(Okx::expected_responses(&map), 2);
+        assert_eq!(
+            Okx::requests(exchange_subs),
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": [
+                        {"channel": "opt-summary", "instFamily": "BTC-USD"},
+                        {"channel": "trades", "instId": "BTC-USDT"},
+                    ],
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_okx_instrument_type_wide_requests() {
+        let exchange_subs = ["BTC-USDT-SWAP", "ETH-USDT-SWAP", "BTC-USD-241227"]
+            .into_iter()
+            .map(|market| {
+                ExchangeSub::from((OkxChannel::LIQUIDATION_ORDERS, OkxMarket(market.into())))
+            })
+            .chain([ExchangeSub::from((
+                OkxChannel::ADL_WARNING,
+                OkxMarket("BTC-USDT-SWAP".into()),
+            ))])
+            .collect::<Vec<_>>();
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let map = exchange_subs
+            .iter()
+            .map(|sub| (sub.id(), instrument.clone()))
+            .collect::<Map<Instrument>>();
+
+        assert_eq!(Okx::expected_responses(&map), 3);
+        assert_eq!(
+            Okx::requests(exchange_subs),
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": [
+                        {"channel": "adl-warning", "instType": "SWAP"},
+                        {"channel": "liquidation-orders", "instType": "FUTURES"},
+                        {"channel": "liquidation-orders", "instType": "SWAP"},
+                    ],
+                })
+                .to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use super::Okx;
+use crate::notice::{Notice, NoticeKind, NoticeParser};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] WebSocket error or notice event.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+/// #### Error
+/// ```json
+/// {"event": "error", "code": "60012", "msg": "Invalid request: {\"op\": \"subscribe\"}"}
+/// ```
+///
+/// #### Notice
+/// ```json
+/// {
+///     "event": "notice",
+///     "code": "64008",
+///     "msg": "The connection will soon be closed for a service upgrade. Please reconnect."
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum OkxNotice {
+    Error { code: String, msg: String },
+    Notice { code: String, msg: String },
+}
+
+impl NoticeParser for Okx {
+    fn parse_notice(payload: &str) -> Option<Notice> {
+        let (code, msg) = match serde_json::from_str::<OkxNotice>(payload).ok()? {
+            OkxNotice::Error { code, msg } | OkxNotice::Notice { code, msg } => (code, msg),
+        };
+
+        let kind = match code.as_str() {
+            "60014" | "63999" => NoticeKind::RateLimit,
+            "60004" | "60005" | "60006" | "60007" | "60008" | "60009" | "60011" | "60024" => {
+                NoticeKind::Unauthorised
+            }
+            "64008" => NoticeKind::Reconnect,
+            code if code.starts_with("60") => NoticeKind::InvalidRequest,
+            _ => NoticeKind::Other,
+        };
+
+        Some(Notice::new(kind, Some(code), msg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_parse_notice() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Notice>,
+        }
+
+        let cases = vec![
+            // TC0: invalid request error
+            TestCase {
+                input: r#"{"event": "error", "code": "60012", "msg": "Invalid request"}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::InvalidRequest,
+                    Some("60012"),
+                    "Invalid request",
+                )),
+            },
+            // TC1: service upgrade notice
+            TestCase {
+                input: r#"{"event": "notice", "code": "64008", "msg": "Please reconnect."}"#,
+                expected: Some(Notice::new(
+                    NoticeKind::Reconnect,
+                    Some("64008"),
+                    "Please reconnect.",
+                )),
+            },
+            // TC2: subscription success is not a notice
+            TestCase {
+                input: r#"{"event": "subscribe", "arg": {"channel": "trades", "instId": "BTC-USDT"}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Okx::parse_notice(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
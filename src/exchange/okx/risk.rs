@@ -0,0 +1,241 @@
+use super::{channel::OkxChannel, limit::de_okx_optional_f64, market::OkxMarket, Okx};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::{subscription::SUBSCRIPTION_ID_SEPARATOR, Connector},
+    subscription::{
+        risk::{AdlIndicator, AdlIndicators, AdlState},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange},
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] auto-deleveraging (ADL) warning WebSocket message, containing the insurance fund state
+/// of every instrument family of an instrument type (eg/ "SWAP").
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-adl-warning-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "adl-warning",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instFamily": "BTC-USDT",
+///       "state": "warning",
+///       "bal": "280784384.9564228289548144",
+///       "ccy": "USDT",
+///       "maxBal": "281234567.1234567890123456",
+///       "maxBalTs": "1700210763001",
+///       "decRate": "0.00160",
+///       "adlType": "",
+///       "ts": "1700210763001"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxAdlWarnings {
+    pub data: Vec<OkxAdlWarning>,
+}
+
+/// [`Okx`] insurance fund ADL warning of an instrument family, contained within an
+/// [`OkxAdlWarnings`] message.
+///
+/// The "maxBal" is empty unless the insurance fund is in the warning state.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxAdlWarning {
+    #[serde(rename = "instType")]
+    pub instrument_type: String,
+    #[serde(rename = "instFamily")]
+    pub instrument_family: String,
+    pub state: OkxAdlState,
+    #[serde(rename = "bal", deserialize_with = "barter_integration::de::de_str")]
+    pub balance: f64,
+    #[serde(rename = "maxBal", deserialize_with = "de_okx_optional_f64")]
+    pub max_balance: Option<f64>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] insurance fund ADL state.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxAdlState {
+    Normal,
+    Warning,
+    Adl,
+}
+
+impl From<OkxAdlState> for AdlState {
+    fn from(state: OkxAdlState) -> Self {
+        match state {
+            OkxAdlState::Normal => AdlState::Normal,
+            OkxAdlState::Warning => AdlState::Warning,
+            OkxAdlState::Adl => AdlState::Active,
+        }
+    }
+}
+
+impl From<&OkxAdlWarning> for AdlIndicator {
+    fn from(warning: &OkxAdlWarning) -> Self {
+        Self {
+            state: Some(AdlState::from(warning.state)),
+            insurance_balance: warning.balance,
+            max_insurance_balance: warning.max_balance,
+            pnl_ratio: None,
+            pnl_ratio_threshold: None,
+            trigger_threshold: None,
+            stop_ratio: None,
+        }
+    }
+}
+
+/// [`Okx`] [`AdlIndicators`] [`ExchangeTransformer`].
+///
+/// Okx streams ADL warnings per instrument family (eg/ "BTC-USDT") of an instrument type, so each
+/// warning is routed to every subscribed [`Instrument`] of that family & type. Warnings of
+/// families that were not subscribed to are ignored.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OkxAdlIndicators {
+    /// Subscribed [`Instrument`]s with their [`OkxMarket`] instrument type & family.
+    instruments: Vec<(&'static str, String, Instrument)>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, AdlIndicators> for OkxAdlIndicators {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self::from(instrument_map))
+    }
+}
+
+impl From<Map<Instrument>> for OkxAdlIndicators {
+    fn from(instrument_map: Map<Instrument>) -> Self {
+        let prefix = format!(
+            "{}{SUBSCRIPTION_ID_SEPARATOR}",
+            OkxChannel::ADL_WARNING.as_ref()
+        );
+
+        let instruments = instrument_map
+            .0
+            .into_iter()
+            .filter_map(|(subscription_id, instrument)| {
+                let market = OkxMarket(subscription_id.as_ref().strip_prefix(&prefix)?.to_owned());
+                Some((
+                    market.instrument_type(),
+                    market.instrument_family().to_owned(),
+                    instrument,
+                ))
+            })
+            .collect();
+
+        Self { instruments }
+    }
+}
+
+impl Transformer for OkxAdlIndicators {
+    type Error = DataError;
+    type Input = OkxAdlWarnings;
+    type Output = MarketEvent<AdlIndicator>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .data
+            .iter()
+            .flat_map(|warning| {
+                self.instruments
+                    .iter()
+                    .filter(|(instrument_type, instrument_family, _)| {
+                        *instrument_type == warning.instrument_type
+                            && *instrument_family == warning.instrument_family
+                    })
+                    .map(move |(_, _, instrument)| {
+                        Ok(MarketEvent {
+                            exchange_time: warning.time,
+                            received_time: Utc::now(),
+                            received_monotonic: MonotonicTime::now(),
+                            origin: Origin::Live,
+                            exchange: Exchange::from(Okx::ID),
+                            instrument: instrument.clone(),
+                            kind: AdlIndicator::from(warning),
+                        })
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_okx_adl_indicators_transform() {
+        let input = r#"
+        {
+            "arg": {"channel": "adl-warning", "instType": "SWAP"},
+            "data": [
+                {
+                    "instType": "SWAP", "instFamily": "BTC-USDT", "state": "warning",
+                    "bal": "280784384.5", "ccy": "USDT",
+                    "maxBal": "281234567.25", "maxBalTs": "1700210763001",
+                    "decRate": "0.00160", "adlType": "", "ts": "1700210763001"
+                },
+                {
+                    "instType": "SWAP", "instFamily": "ETH-USDT", "state": "normal",
+                    "bal": "1000", "ccy": "USDT", "maxBal": "", "maxBalTs": "",
+                    "decRate": "", "adlType": "", "ts": "1700210763001"
+                }
+            ]
+        }
+        "#;
+
+        // Subscribed to the BTC-USDT perpetual only, so the ETH-USDT warning is ignored
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let mut transformer = OkxAdlIndicators::from(Map::from_iter([(
+            SubscriptionId::from("adl-warning|BTC-USDT-SWAP"),
+            btc.clone(),
+        )]));
+
+        let actual = transformer
+            .transform(serde_json::from_str(input).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![(
+                btc,
+                AdlIndicator {
+                    state: Some(AdlState::Warning),
+                    insurance_balance: 280784384.5,
+                    max_insurance_balance: Some(281234567.25),
+                    pnl_ratio: None,
+                    pnl_ratio_threshold: None,
+                    trigger_threshold: None,
+                    stop_ratio: None,
+                }
+            )]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    subscription::status::{ExchangeStatus, TradingStatus},
+    transformer::status::StatusUpdate,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) system status WebSocket message, containing scheduled and ongoing
+/// maintenance of every Okx service.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#status-websocket-status-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "status"
+///   },
+///   "data": [
+///     {
+///       "begin": "1672823400000",
+///       "end": "1672825980000",
+///       "href": "",
+///       "preOpenBegin": "",
+///       "scheDesc": "",
+///       "serviceType": "0",
+///       "state": "scheduled",
+///       "maintType": "1",
+///       "env": "1",
+///       "system": "unified",
+///       "title": "Trading account WebSocket system upgrade",
+///       "ts": "1672826038470"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxStatuses {
+    pub data: Vec<OkxStatus>,
+}
+
+/// [`Okx`](super::Okx) system maintenance contained within an [`OkxStatuses`] message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxStatus {
+    pub title: String,
+    pub state: OkxMaintenanceState,
+    #[serde(deserialize_with = "de_okx_optional_epoch_ms")]
+    pub begin: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "de_okx_optional_epoch_ms")]
+    pub end: Option<DateTime<Utc>>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) system maintenance state.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxMaintenanceState {
+    Scheduled,
+    Ongoing,
+    /// Maintenance is complete, but the system has not yet re-opened for trading.
+    PreOpen,
+    Completed,
+    Canceled,
+}
+
+impl From<OkxMaintenanceState> for TradingStatus {
+    fn from(state: OkxMaintenanceState) -> Self {
+        match state {
+            OkxMaintenanceState::Scheduled => TradingStatus::MaintenanceScheduled,
+            OkxMaintenanceState::Ongoing | OkxMaintenanceState::PreOpen => {
+                TradingStatus::Maintenance
+            }
+            OkxMaintenanceState::Completed | OkxMaintenanceState::Canceled => TradingStatus::Online,
+        }
+    }
+}
+
+impl IntoIterator for OkxStatuses {
+    type Item = StatusUpdate;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data
+            .into_iter()
+            .map(|status| StatusUpdate {
+                time: status.time,
+                subscription_id: None,
+                status: ExchangeStatus {
+                    status: TradingStatus::from(status.state),
+                    message: Some(status.title),
+                    start_time: status.begin,
+                    end_time: status.end,
+                },
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// Deserialize an [`Okx`](super::Okx) String epoch millisecond timestamp that is empty if not
+/// applicable (eg/ "preOpenBegin").
+fn de_okx_optional_epoch_ms<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    input
+        .parse::<i64>()
+        .map_err(serde::de::Error::custom)
+        .and_then(|epoch_ms| {
+            DateTime::from_timestamp_millis(epoch_ms)
+                .ok_or_else(|| serde::de::Error::custom("epoch ms out of range"))
+                .map(Some)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use chrono::TimeZone;
+
+        #[test]
+        fn test_okx_statuses() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "status"
+                },
+                "data": [
+                    {
+                        "begin": "1672823400000",
+                        "end": "1672825980000",
+                        "href": "",
+                        "preOpenBegin": "",
+                        "scheDesc": "",
+                        "serviceType": "0",
+                        "state": "ongoing",
+                        "maintType": "1",
+                        "env": "1",
+                        "system": "unified",
+                        "title": "Trading account WebSocket system upgrade",
+                        "ts": "1672826038470"
+                    },
+                    {
+                        "begin": "",
+                        "end": "",
+                        "href": "",
+                        "preOpenBegin": "",
+                        "scheDesc": "",
+                        "serviceType": "1",
+                        "state": "completed",
+                        "maintType": "1",
+                        "env": "1",
+                        "system": "unified",
+                        "title": "Spot system upgrade",
+                        "ts": "1672826038470"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxStatuses>(input)
+                .unwrap()
+                .into_iter()
+                .map(|update| update.status)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                actual,
+                vec![
+                    ExchangeStatus {
+                        status: TradingStatus::Maintenance,
+                        message: Some("Trading account WebSocket system upgrade".to_string()),
+                        start_time: Some(Utc.timestamp_millis_opt(1672823400000).unwrap()),
+                        end_time: Some(Utc.timestamp_millis_opt(1672825980000).unwrap()),
+                    },
+                    ExchangeStatus {
+                        status: TradingStatus::Online,
+                        message: Some("Spot system upgrade".to_string()),
+                        start_time: None,
+                        end_time: None,
+                    },
+                ]
+            );
+        }
+    }
+}
@@ -1,17 +1,31 @@
 use super::{channel::OkxChannel, market::OkxMarket};
-use crate::exchange::subscription::ExchangeSub;
-use barter_integration::{error::SocketError, Validator};
+use crate::exchange::subscription::{subscription_id, ExchangeSub};
+use barter_integration::{error::SocketError, model::SubscriptionId, Validator};
 use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
 // Implement custom Serialize to assist aesthetics of <Okx as Connector>::requests() function.
+//
+// Note that the OkxChannel::OPTION_SUMMARY is subscribed to per instrument family, so the market
+// is expected to have already been mapped to it's OkxMarket::instrument_family, and instrument
+// type wide channels to it's OkxMarket::instrument_type. The OkxChannel::STATUS is subscribed to
+// without a market.
 impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        let market_field = match self.channel {
+            OkxChannel::STATUS => None,
+            OkxChannel::OPTION_SUMMARY => Some("instFamily"),
+            channel if channel.is_instrument_type_wide() => Some("instType"),
+            _ => Some("instId"),
+        };
+
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+        if let Some(market_field) = market_field {
+            state.serialize_field(market_field, self.market.as_ref())?;
+        }
         state.end()
     }
 }
@@ -23,13 +37,23 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 /// ```json
 /// {
 ///   "event": "subscribe",
-///   "args": {
+///   "arg": {
 ///     "channel": "trades",
 ///     "instId": "BTC-USD-191227"
 ///   }
 /// }
 /// ```
 ///
+/// #### Login Ok Response
+/// ```json
+/// {
+///   "event": "login",
+///   "code": "0",
+///   "msg": "",
+///   "connId": "a4d3ae55"
+/// }
+/// ```
+///
 /// #### Subscription Trades Error Response
 /// ```json
 /// {
@@ -44,7 +68,12 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 #[serde(tag = "event", rename_all = "lowercase")]
 pub enum OkxSubResponse {
     #[serde(rename = "subscribe")]
-    Subscribed,
+    Subscribed {
+        #[serde(alias = "args")]
+        arg: OkxSubArg,
+    },
+    #[serde(rename = "login")]
+    LoggedIn,
     Error {
         code: String,
         #[serde(rename = "msg")]
@@ -58,7 +87,7 @@ impl Validator for OkxSubResponse {
         Self: Sized,
     {
         match self {
-            Self::Subscribed => Ok(self),
+            Self::Subscribed { .. } | Self::LoggedIn => Ok(self),
             Self::Error { code, message } => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {code} with message: {message}",
             ))),
@@ -66,6 +95,25 @@ impl Validator for OkxSubResponse {
     }
 }
 
+/// [`OkxSubResponse`] acknowledged subscription arg.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxSubArg {
+    pub channel: String,
+    #[serde(rename = "instId", alias = "instFamily", default)]
+    pub market: Option<String>,
+    #[serde(rename = "instType", default)]
+    pub instrument_type: Option<String>,
+}
+
+impl OkxSubArg {
+    /// Determine the [`SubscriptionId`] of the acknowledged subscription, normalised such that
+    /// it can be correlated with [`expected_subscriptions`](super::expected_subscriptions).
+    pub fn subscription_id(&self) -> SubscriptionId {
+        let market = self.market.as_deref().or(self.instrument_type.as_deref());
+        subscription_id(&self.channel, market.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,10 +137,28 @@ mod tests {
                     "args": {"channel": "trades", "instId": "BTC-USD-191227"}
                 }
                 "#,
-                    expected: Ok(OkxSubResponse::Subscribed),
+                    expected: Ok(OkxSubResponse::Subscribed {
+                        arg: OkxSubArg {
+                            channel: "trades".to_string(),
+                            market: Some("BTC-USD-191227".to_string()),
+                            instrument_type: None,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is login success
+                    input: r#"
+                {
+                    "event": "login",
+                    "code": "0",
+                    "msg": "",
+                    "connId": "a4d3ae55"
+                }
+                "#,
+                    expected: Ok(OkxSubResponse::LoggedIn),
                 },
                 TestCase {
-                    // TC1: input response is failed subscription
+                    // TC2: input response is failed subscription
                     input: r#"
                 {
                     "event": "error",
@@ -105,6 +171,22 @@ mod tests {
                         message: "Invalid request: {\"op\": \"subscribe\", \"args\":[{ \"channel\" : \"trades\", \"instId\" : \"BTC-USD-191227\"}]}".to_string()
                     }),
                 },
+                TestCase {
+                    // TC3: input response is instrument type wide subscription success
+                    input: r#"
+                {
+                    "event": "subscribe",
+                    "arg": {"channel": "liquidation-orders", "instType": "SWAP"}
+                }
+                "#,
+                    expected: Ok(OkxSubResponse::Subscribed {
+                        arg: OkxSubArg {
+                            channel: "liquidation-orders".to_string(),
+                            market: None,
+                            instrument_type: Some("SWAP".to_string()),
+                        },
+                    }),
+                },
             ];
 
             for (index, test) in cases.into_iter().enumerate() {
@@ -135,7 +217,13 @@ mod tests {
         let cases = vec![
             TestCase {
                 // TC0: input response is subscription success
-                input_response: OkxSubResponse::Subscribed,
+                input_response: OkxSubResponse::Subscribed {
+                    arg: OkxSubArg {
+                        channel: "status".to_string(),
+                        market: None,
+                        instrument_type: None,
+                    },
+                },
                 is_valid: true,
             },
             TestCase {
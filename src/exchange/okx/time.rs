@@ -0,0 +1,87 @@
+use super::Okx;
+use crate::clock::ServerClock;
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`] HTTP server time url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-system-time>
+pub const HTTP_SERVER_TIME_URL_OKX: &str = "https://www.okx.com/api/v5/public/time";
+
+/// [`Okx`] HTTP server time response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-system-time>
+/// ```json
+/// {
+///   "code": "0",
+///   "msg": "",
+///   "data": [
+///     {
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxServerTime {
+    pub data: Vec<OkxTime>,
+}
+
+/// [`Okx`] server time contained within an [`OkxServerTime`] response.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxTime {
+    #[serde(
+        alias = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+#[async_trait]
+impl ServerClock for Okx {
+    async fn server_time() -> Result<DateTime<Utc>, SocketError> {
+        reqwest::get(HTTP_SERVER_TIME_URL_OKX)
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<OkxServerTime>()
+            .await
+            .map_err(SocketError::Http)?
+            .data
+            .first()
+            .map(|time| time.time)
+            .ok_or_else(|| {
+                SocketError::Exchange("Okx server time response without data".to_owned())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_server_time() {
+            let input = r#"{"code": "0", "msg": "", "data": [{"ts": "1597026383085"}]}"#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxServerTime>(input).unwrap(),
+                OkxServerTime {
+                    data: vec![OkxTime {
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085
+                        )),
+                    }],
+                }
+            );
+        }
+    }
+}
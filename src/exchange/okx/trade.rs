@@ -1,16 +1,50 @@
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, TradeId},
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for an [`Okx`](super::Okx) real-time trades WebSocket message.
 pub type OkxTrades = OkxMessage<OkxTrade>;
 
+/// [`Okx`](super::Okx) public block trades WebSocket message, normalised into
+/// [`PublicTrade`]s flagged as [`PublicTrade::block`].
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "block-trades",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instId": "BTC-USDT-SWAP",
+///       "tradeId": "633971452580106242",
+///       "px": "26808.1",
+///       "sz": "150",
+///       "side": "sell",
+///       "ts": "1697422572972"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#block-trading-websocket-public-channel-block-trades-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct OkxBlockTrades(pub OkxTrades);
+
+impl Identifier<Option<SubscriptionId>> for OkxBlockTrades {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.0.id()
+    }
+}
+
 /// [`Okx`](super::Okx) market data WebSocket message.
 ///
 /// ### Raw Payload Examples
@@ -58,8 +92,11 @@ impl<T> Identifier<Option<SubscriptionId>> for OkxMessage<T> {
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct OkxTrade {
-    #[serde(rename = "tradeId")]
-    pub id: String,
+    #[serde(
+        rename = "tradeId",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub id: TradeId,
     #[serde(rename = "px", deserialize_with = "barter_integration::de::de_str")]
     pub price: f64,
     #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
@@ -81,14 +118,27 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
                 Ok(MarketEvent {
                     exchange_time: trade.time,
                     received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
-                    kind: PublicTrade {
-                        id: trade.id,
-                        price: trade.price,
-                        amount: trade.amount,
-                        side: trade.side,
-                    },
+                    kind: PublicTrade::new(trade.id, trade.price, trade.amount, trade.side),
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxBlockTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, OkxBlockTrades)) -> Self {
+        let MarketIter(events) = Self::from((exchange_id, instrument, trades.0));
+
+        events
+            .into_iter()
+            .map(|event| {
+                event.map(|mut event| {
+                    event.kind.block = true;
+                    event
                 })
             })
             .collect()
@@ -116,6 +166,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
 
     mod de {
         use super::*;
@@ -148,7 +199,7 @@ mod tests {
             let expected: Result<OkxTrades, SocketError> = Ok(OkxTrades {
                 subscription_id: SubscriptionId::from("trades|BTC-USDT"),
                 data: vec![OkxTrade {
-                    id: "130639474".to_string(),
+                    id: TradeId::U64(130639474),
                     price: 42219.9,
                     amount: 0.12060306,
                     side: Side::Buy,
@@ -170,4 +221,51 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_okx_block_trades_market_iter() {
+        let input = r#"
+        {
+            "arg": {
+                "channel": "block-trades",
+                "instId": "BTC-USDT-SWAP"
+            },
+            "data": [
+                {
+                    "instId": "BTC-USDT-SWAP",
+                    "tradeId": "633971452580106242",
+                    "px": "26808.1",
+                    "sz": "150",
+                    "side": "sell",
+                    "ts": "1697422572972"
+                }
+            ]
+        }
+        "#;
+
+        let trades = serde_json::from_str::<OkxBlockTrades>(input).unwrap();
+        assert_eq!(
+            trades.id(),
+            Some(SubscriptionId::from("block-trades|BTC-USDT-SWAP"))
+        );
+
+        let MarketIter(events) = MarketIter::<PublicTrade>::from((
+            ExchangeId::Okx,
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            trades,
+        ));
+
+        let trades = events
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            trades,
+            vec![PublicTrade {
+                block: true,
+                ..PublicTrade::new(633971452580106242, 26808.1, 150.0, Side::Sell)
+            }]
+        );
+    }
 }
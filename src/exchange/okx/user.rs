@@ -0,0 +1,557 @@
+use super::{channel::OkxChannel, market::OkxMarket, trade::OkxMessage, Okx};
+use crate::{
+    credentials::{Credentials, SignatureMethod},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::{subscription::ExchangeSub, ExchangeId, PrivateConnector, PrivateSession},
+    subscription::{
+        account::{ExecutionEvent, OrderStatus, Position},
+        trade::UserTrade,
+        Map,
+    },
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{symbol::Symbol, Instrument},
+        Exchange, Side,
+    },
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use url::Url;
+
+/// [`Okx`] private server base url that serves account channels.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-production-trading-services>
+pub const BASE_URL_OKX_PRIVATE: &str = "wss://ws.okx.com:8443/ws/v5/private";
+
+/// Terse type alias for an [`Okx`] private orders channel WebSocket message.
+pub type OkxOrders = OkxMessage<OkxOrder>;
+
+/// Terse type alias for an [`Okx`] private positions channel WebSocket message.
+pub type OkxPositions = OkxMessage<OkxPosition>;
+
+#[async_trait]
+impl PrivateConnector for Okx {
+    /// Sign a "login" request that is sent once connected to the private server.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-login>
+    async fn authenticate(credentials: &Credentials) -> Result<PrivateSession, SocketError> {
+        Ok(PrivateSession {
+            url: Url::parse(BASE_URL_OKX_PRIVATE).map_err(SocketError::UrlParse)?,
+            requests: vec![login_request(credentials, Utc::now().timestamp())?],
+            keep_alive: None,
+        })
+    }
+
+    fn private_requests(exchange_subs: Vec<ExchangeSub<OkxChannel, OkxMarket>>) -> Vec<WsMessage> {
+        let args = exchange_subs
+            .iter()
+            .map(|sub| {
+                json!({
+                    "channel": sub.channel.as_ref(),
+                    "instType": "ANY",
+                    "instId": sub.market.as_ref(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::Text(
+            json!({
+                "op": "subscribe",
+                "args": args,
+            })
+            .to_string(),
+        )]
+    }
+
+    /// One response to the "login" request, and one for each subscribed channel.
+    fn private_expected_responses(map: &Map<Instrument>) -> usize {
+        1 + map.0.len()
+    }
+}
+
+/// Construct an [`Okx`] "login" request, signed with the [`Credentials`] secret.
+pub fn login_request(credentials: &Credentials, timestamp: i64) -> Result<WsMessage, SocketError> {
+    let passphrase = credentials.passphrase.as_ref().ok_or_else(|| {
+        SocketError::Subscribe("Okx private streams require a Credentials passphrase".to_owned())
+    })?;
+
+    let sign = credentials.sign(
+        SignatureMethod::HmacSha256Base64,
+        &format!("{timestamp}GET/users/self/verify"),
+    )?;
+
+    Ok(WsMessage::Text(
+        json!({
+            "op": "login",
+            "args": [{
+                "apiKey": credentials.api_key,
+                "passphrase": passphrase,
+                "timestamp": timestamp.to_string(),
+                "sign": sign,
+            }]
+        })
+        .to_string(),
+    ))
+}
+
+/// [`Okx`] private order update.
+///
+/// Fill fields are empty for order updates that are not caused by a fill (eg/ a new order).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-private-channel-order-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "orders",
+///     "instType": "ANY",
+///     "instId": "BTC-USDT",
+///     "uid": "614488474791936"
+///   },
+///   "data": [
+///     {
+///       "instType": "SPOT",
+///       "instId": "BTC-USDT",
+///       "ordId": "312269865356374016",
+///       "clOrdId": "b1",
+///       "px": "30000",
+///       "sz": "0.01",
+///       "side": "buy",
+///       "tradeId": "242589207",
+///       "fillPx": "30000",
+///       "fillSz": "0.01",
+///       "fillFee": "-0.00000001",
+///       "fillFeeCcy": "BTC",
+///       "execType": "T",
+///       "accFillSz": "0.01",
+///       "state": "filled",
+///       "fillTime": "1597026383085",
+///       "uTime": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxOrder {
+    #[serde(rename = "ordId")]
+    pub order_id: String,
+    #[serde(rename = "clOrdId")]
+    pub client_order_id: String,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub px: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub sz: f64,
+    pub side: Side,
+    pub trade_id: String,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub fill_px: f64,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub fill_sz: f64,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub fill_fee: f64,
+    pub fill_fee_ccy: String,
+    pub exec_type: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub acc_fill_sz: f64,
+    pub state: String,
+    #[serde(
+        rename = "uTime",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl OkxOrder {
+    /// [`Okx`] execution type of a fill where the order was the maker.
+    pub const EXEC_TYPE_MAKER: &'static str = "M";
+
+    /// Determine if this [`OkxOrder`] update was caused by a fill.
+    pub fn is_fill(&self) -> bool {
+        !self.trade_id.is_empty()
+    }
+
+    /// Determine the normalised [`OrderStatus`] of this [`OkxOrder`] update, returning `None`
+    /// for states without a normalised equivalent.
+    pub fn status(&self) -> Option<OrderStatus> {
+        match self.state.as_str() {
+            "live" => Some(OrderStatus::New),
+            "partially_filled" => Some(OrderStatus::PartiallyFilled),
+            "filled" => Some(OrderStatus::Filled),
+            "canceled" | "mmp_canceled" => Some(OrderStatus::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxOrders)> for MarketIter<ExecutionEvent> {
+    fn from((exchange_id, instrument, orders): (ExchangeId, Instrument, OkxOrders)) -> Self {
+        orders
+            .data
+            .into_iter()
+            .filter_map(|order| {
+                let status = order.status()?;
+                Some(Ok(MarketEvent {
+                    exchange_time: order.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: ExecutionEvent {
+                        order_id: order.order_id,
+                        client_order_id: (!order.client_order_id.is_empty())
+                            .then_some(order.client_order_id),
+                        side: order.side,
+                        status,
+                        price: order.px,
+                        quantity: order.sz,
+                        filled_quantity: order.acc_fill_sz,
+                    },
+                }))
+            })
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxOrders)> for MarketIter<UserTrade> {
+    fn from((exchange_id, instrument, orders): (ExchangeId, Instrument, OkxOrders)) -> Self {
+        orders
+            .data
+            .into_iter()
+            .filter(OkxOrder::is_fill)
+            .map(|order| {
+                Ok(MarketEvent {
+                    exchange_time: order.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: UserTrade {
+                        maker: order.exec_type == OkxOrder::EXEC_TYPE_MAKER,
+                        id: order.trade_id,
+                        order_id: order.order_id,
+                        price: order.fill_px,
+                        amount: order.fill_sz,
+                        side: order.side,
+                        // Okx fees are negative when charged, and positive for rebates
+                        fee: -order.fill_fee,
+                        fee_asset: (!order.fill_fee_ccy.is_empty())
+                            .then(|| Symbol::new(order.fill_fee_ccy)),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// [`Okx`] private position.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-private-channel-positions-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "positions",
+///     "instType": "ANY",
+///     "instId": "BTC-USDT-SWAP",
+///     "uid": "614488474791936"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "posSide": "net",
+///       "pos": "-2",
+///       "avgPx": "30000",
+///       "upl": "15.5",
+///       "mgnMode": "cross",
+///       "uTime": "1619507758793"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPosition {
+    pub pos_side: String,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub pos: f64,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub avg_px: f64,
+    #[serde(deserialize_with = "de_okx_f64_or_zero")]
+    pub upl: f64,
+    #[serde(
+        rename = "uTime",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl OkxPosition {
+    /// [`Okx`] position side of a short position in long/short mode, where the position size is
+    /// always positive. In net mode the position side is "net" and the size is signed.
+    pub const POS_SIDE_SHORT: &'static str = "short";
+}
+
+impl From<(ExchangeId, Instrument, OkxPositions)> for MarketIter<Position> {
+    fn from((exchange_id, instrument, positions): (ExchangeId, Instrument, OkxPositions)) -> Self {
+        positions
+            .data
+            .into_iter()
+            .map(|position| {
+                let quantity = if position.pos_side == OkxPosition::POS_SIDE_SHORT {
+                    -position.pos.abs()
+                } else {
+                    position.pos
+                };
+
+                Ok(MarketEvent {
+                    exchange_time: position.time,
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Position {
+                        quantity,
+                        entry_price: position.avg_px,
+                        unrealised_pnl: position.upl,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize an [`Okx`] private numeric field, which [`Okx`] sends as an empty string when not
+/// applicable (eg/ the fill price of an order update that is not caused by a fill).
+fn de_okx_f64_or_zero<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+    if input.is_empty() {
+        Ok(0.0)
+    } else {
+        input.parse::<f64>().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_orders() {
+            let input = r#"
+            {
+                "arg": {"channel": "orders", "instType": "ANY", "instId": "BTC-USDT", "uid": "614488474791936"},
+                "data": [
+                    {
+                        "instType": "SPOT", "instId": "BTC-USDT", "ordId": "312269865356374016",
+                        "clOrdId": "b1", "px": "30000", "sz": "0.01", "side": "buy",
+                        "tradeId": "242589207", "fillPx": "30000", "fillSz": "0.01",
+                        "fillFee": "-0.00000001", "fillFeeCcy": "BTC", "execType": "T",
+                        "accFillSz": "0.01", "state": "filled", "fillTime": "1597026383085",
+                        "uTime": "1597026383085"
+                    },
+                    {
+                        "instType": "SPOT", "instId": "BTC-USDT", "ordId": "312269865356374017",
+                        "clOrdId": "", "px": "", "sz": "0.02", "side": "sell", "tradeId": "",
+                        "fillPx": "", "fillSz": "0", "fillFee": "0", "fillFeeCcy": "",
+                        "execType": "", "accFillSz": "0", "state": "live", "fillTime": "",
+                        "uTime": "1597026383086"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxOrders>(input).unwrap(),
+                OkxOrders {
+                    subscription_id: SubscriptionId::from("orders|BTC-USDT"),
+                    data: vec![
+                        OkxOrder {
+                            order_id: "312269865356374016".to_string(),
+                            client_order_id: "b1".to_string(),
+                            px: 30000.0,
+                            sz: 0.01,
+                            side: Side::Buy,
+                            trade_id: "242589207".to_string(),
+                            fill_px: 30000.0,
+                            fill_sz: 0.01,
+                            fill_fee: -0.00000001,
+                            fill_fee_ccy: "BTC".to_string(),
+                            exec_type: "T".to_string(),
+                            acc_fill_sz: 0.01,
+                            state: "filled".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085
+                            )),
+                        },
+                        OkxOrder {
+                            order_id: "312269865356374017".to_string(),
+                            client_order_id: "".to_string(),
+                            px: 0.0,
+                            sz: 0.02,
+                            side: Side::Sell,
+                            trade_id: "".to_string(),
+                            fill_px: 0.0,
+                            fill_sz: 0.0,
+                            fill_fee: 0.0,
+                            fill_fee_ccy: "".to_string(),
+                            exec_type: "".to_string(),
+                            acc_fill_sz: 0.0,
+                            state: "live".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383086
+                            )),
+                        },
+                    ],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_positions_market_iter() {
+        let input = r#"
+        {
+            "arg": {"channel": "positions", "instType": "ANY", "instId": "BTC-USDT-SWAP"},
+            "data": [
+                {
+                    "instType": "SWAP", "instId": "BTC-USDT-SWAP", "posSide": "net", "pos": "-2",
+                    "avgPx": "30000", "upl": "15.5", "mgnMode": "cross", "uTime": "1619507758793"
+                },
+                {
+                    "instType": "SWAP", "instId": "BTC-USDT-SWAP", "posSide": "short", "pos": "1",
+                    "avgPx": "31000", "upl": "-2", "mgnMode": "isolated", "uTime": "1619507758794"
+                },
+                {
+                    "instType": "SWAP", "instId": "BTC-USDT-SWAP", "posSide": "net", "pos": "0",
+                    "avgPx": "", "upl": "", "mgnMode": "cross", "uTime": "1619507758795"
+                }
+            ]
+        }
+        "#;
+
+        let positions = serde_json::from_str::<OkxPositions>(input).unwrap();
+        assert_eq!(
+            positions.subscription_id,
+            SubscriptionId::from("positions|BTC-USDT-SWAP")
+        );
+
+        let actual = MarketIter::<Position>::from((
+            ExchangeId::Okx,
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            positions,
+        ))
+        .0
+        .into_iter()
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                Position {
+                    quantity: -2.0,
+                    entry_price: 30000.0,
+                    unrealised_pnl: 15.5,
+                },
+                Position {
+                    quantity: -1.0,
+                    entry_price: 31000.0,
+                    unrealised_pnl: -2.0,
+                },
+                Position {
+                    quantity: 0.0,
+                    entry_price: 0.0,
+                    unrealised_pnl: 0.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_login_request() {
+        let credentials = Credentials::new("api_key", "secret");
+
+        // Okx requires a passphrase
+        assert!(login_request(&credentials, 1538054050).is_err());
+
+        let WsMessage::Text(actual) =
+            login_request(&credentials.with_passphrase("passphrase"), 1538054050).unwrap()
+        else {
+            panic!("login request is not a WsMessage::Text");
+        };
+
+        let actual = serde_json::from_str::<serde_json::Value>(&actual).unwrap();
+        assert_eq!(actual["op"], "login");
+        assert_eq!(actual["args"][0]["apiKey"], "api_key");
+        assert_eq!(actual["args"][0]["passphrase"], "passphrase");
+        assert_eq!(actual["args"][0]["timestamp"], "1538054050");
+
+        // Sign is a base64 encoded HMAC-SHA256 of "{timestamp}GET/users/self/verify"
+        assert_eq!(
+            actual["args"][0]["sign"],
+            "Gj2hQIVKFcXbiwCak8SmVOu5mxPCizWDdmUAhbx8Z+s="
+        );
+    }
+
+    #[test]
+    fn test_okx_orders_execution_events() {
+        let order = |state: &str| OkxOrder {
+            order_id: "312269865356374016".to_string(),
+            client_order_id: "".to_string(),
+            px: 30000.0,
+            sz: 0.01,
+            side: Side::Buy,
+            trade_id: "".to_string(),
+            fill_px: 0.0,
+            fill_sz: 0.0,
+            fill_fee: 0.0,
+            fill_fee_ccy: "".to_string(),
+            exec_type: "".to_string(),
+            acc_fill_sz: 0.0,
+            state: state.to_string(),
+            time: Utc::now(),
+        };
+
+        let orders = OkxOrders {
+            subscription_id: SubscriptionId::from("orders|BTC-USDT"),
+            data: vec![order("canceled"), order("unknown"), order("live")],
+        };
+
+        let actual = MarketIter::<ExecutionEvent>::from((
+            ExchangeId::Okx,
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            orders,
+        ))
+        .0
+        .into_iter()
+        .map(|event| {
+            let event = event.unwrap().kind;
+            (event.status, event.client_order_id)
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![(OrderStatus::Cancelled, None), (OrderStatus::New, None)]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use super::{expected_subscriptions, subscription::OkxSubResponse};
+use crate::{
+    exchange::Connector,
+    subscriber::validator::SubscriptionValidator,
+    subscription::{Map, SubKind},
+    transport::BoxTransport,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::{websocket::WebSocketParser, StreamParser},
+    Validator,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tracing::debug;
+
+/// [`Okx`](super::Okx) specific [`SubscriptionValidator`].
+///
+/// ### Notes
+/// - Every subscription arg of a batched subscribe frame is acknowledged individually, so each
+///   [`OkxSubResponse`] is correlated with the arg it acknowledges.
+/// - Duplicate & unknown acknowledgements are ignored rather than counted, and a validation
+///   timeout reports the subscriptions that remain unacknowledged.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxSubValidator;
+
+#[async_trait]
+impl SubscriptionValidator for OkxSubValidator {
+    type Parser = WebSocketParser;
+
+    async fn validate<Exchange, Kind>(
+        instrument_map: Map<Instrument>,
+        websocket: &mut BoxTransport,
+    ) -> Result<Map<Instrument>, SocketError>
+    where
+        Exchange: Connector + Send,
+        Kind: SubKind + Send,
+    {
+        // Establish exchange specific subscription validation parameters
+        let timeout = Exchange::subscription_timeout();
+
+        // Parameter to keep track of unacknowledged Subscriptions
+        let mut pending = expected_subscriptions(&instrument_map)
+            .into_iter()
+            .collect::<HashSet<SubscriptionId>>();
+        let expected_responses = pending.len();
+
+        loop {
+            // Break if all Subscriptions were acknowledged
+            if pending.is_empty() {
+                debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
+                break Ok(instrument_map);
+            }
+
+            tokio::select! {
+                // If timeout reached, return SubscribeError
+                _ = tokio::time::sleep(timeout) => {
+                    let mut pending = pending.into_iter().map(|id| id.0).collect::<Vec<_>>();
+                    pending.sort();
+                    break Err(SocketError::Subscribe(format!(
+                        "subscription validation timeout reached: {timeout:?} with unacknowledged subscriptions: {pending:?}"
+                    )))
+                },
+                // Parse incoming messages and determine subscription outcomes
+                message = websocket.next() => {
+                    let response = match message {
+                        Some(response) => response,
+                        None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
+                    };
+
+                    match Self::Parser::parse::<OkxSubResponse>(response) {
+                        Some(Ok(response)) => match response.validate() {
+                            // Subscription success
+                            Ok(OkxSubResponse::Subscribed { arg }) => {
+                                let subscription_id = arg.subscription_id();
+                                let acknowledged = pending.remove(&subscription_id);
+                                debug!(
+                                    exchange = %Exchange::ID,
+                                    %subscription_id,
+                                    acknowledged,
+                                    pending = pending.len(),
+                                    "received valid Ok subscription response",
+                                );
+                            }
+
+                            // Login success is not a Subscription outcome
+                            Ok(OkxSubResponse::LoggedIn) => continue,
+
+                            // Subscription failure
+                            Err(err) => break Err(err),
+
+                            // Not reachable after OkxSubResponse validate()
+                            Ok(OkxSubResponse::Error { code, message }) => {
+                                panic!("{code}: {message}")
+                            }
+                        }
+                        Some(Err(SocketError::Deserialise { error, payload })) if pending.len() < expected_responses => {
+                            // Already active subscription payloads, so skip to next SubResponse
+                            debug!(
+                                exchange = %Exchange::ID,
+                                ?error,
+                                pending = pending.len(),
+                                %payload,
+                                "failed to deserialise non SubResponse payload"
+                            );
+                            continue
+                        }
+                        Some(Err(SocketError::Terminated(close_frame))) => {
+                            break Err(SocketError::Subscribe(
+                                format!("received WebSocket CloseFrame: {close_frame}")
+                            ))
+                        }
+                        _ => {
+                            // Pings, Pongs, Frames, etc.
+                            continue
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,165 @@
+use super::ExchangeId;
 use crate::{subscription::Subscription, Identifier};
 use barter_integration::model::SubscriptionId;
 use serde::Deserialize;
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Mutex, OnceLock},
+};
+
+/// Separator between the channel & market of a canonical [`SubscriptionId`].
+pub const SUBSCRIPTION_ID_SEPARATOR: char = '|';
+
+/// Construct the canonical `channel|market` [`SubscriptionId`] used to route exchange messages
+/// to the subscribed [`Instrument`](barter_integration::model::instrument::Instrument).
+///
+/// Every exchange module should derive [`SubscriptionId`]s via this function (or a
+/// [`SubscriptionKey`]) so the subscription & message sides can never disagree on the format.
+pub fn subscription_id<Channel, Market>(channel: Channel, market: Market) -> SubscriptionId
+where
+    Channel: AsRef<str>,
+    Market: AsRef<str>,
+{
+    let (channel, market) = (channel.as_ref(), market.as_ref());
+    let mut id = String::with_capacity(channel.len() + market.len() + 1);
+    write_subscription_id(&mut id, channel, market);
+    SubscriptionId(id)
+}
+
+/// Write the canonical `channel|market` [`SubscriptionId`] into the provided buffer.
+pub(crate) fn write_subscription_id(buffer: &mut String, channel: &str, market: &str) {
+    buffer.push_str(channel);
+    buffer.push(SUBSCRIPTION_ID_SEPARATOR);
+    buffer.push_str(market);
+}
+
+/// Interned string (eg/ an exchange channel or market) that is cheap to copy, compare & hash.
+///
+/// Equal strings are interned to the same process lifetime allocation, so equality & hashing
+/// use the address rather than the contents. Only bounded sets of strings (eg/ the subscribed
+/// channels & markets) should be interned, since interned strings are never freed.
+#[derive(Copy, Clone)]
+pub struct Interned(&'static str);
+
+impl Interned {
+    /// Intern the provided string, returning the existing [`Interned`] if present.
+    pub fn new(value: &str) -> Self {
+        static INTERNER: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+        let mut interner = INTERNER
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match interner.get(value) {
+            Some(interned) => Self(interned),
+            None => {
+                let interned: &'static str = Box::leak(value.to_owned().into_boxed_str());
+                interner.insert(interned);
+                Self(interned)
+            }
+        }
+    }
+
+    /// Interned string slice.
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl PartialEq for Interned {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for Interned {}
+
+impl Hash for Interned {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state)
+    }
+}
+
+impl PartialOrd for Interned {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interned {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(other.0)
+    }
+}
+
+impl AsRef<str> for Interned {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Display for Interned {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::fmt::Debug for Interned {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.0, f)
+    }
+}
+
+/// Typed identifier of an exchange channel & market subscription, used in place of a stringly
+/// [`SubscriptionId`] where the exchange is known.
+///
+/// A [`SubscriptionKey`] is [`Copy`] and hashes the interned channel & market addresses, and
+/// converts to the canonical [`SubscriptionId`] (see [`subscription_id`]).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubscriptionKey {
+    pub exchange: ExchangeId,
+    pub channel: Interned,
+    pub market: Interned,
+}
+
+impl SubscriptionKey {
+    /// Construct a new [`Self`], interning the provided channel & market.
+    pub fn new<Channel, Market>(exchange: ExchangeId, channel: Channel, market: Market) -> Self
+    where
+        Channel: AsRef<str>,
+        Market: AsRef<str>,
+    {
+        Self {
+            exchange,
+            channel: Interned::new(channel.as_ref()),
+            market: Interned::new(market.as_ref()),
+        }
+    }
+
+    /// Canonical [`SubscriptionId`] of this [`SubscriptionKey`].
+    pub fn id(&self) -> SubscriptionId {
+        subscription_id(self.channel, self.market)
+    }
+}
+
+impl Display for SubscriptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}{SUBSCRIPTION_ID_SEPARATOR}{}",
+            self.exchange, self.channel, self.market
+        )
+    }
+}
+
+impl From<SubscriptionKey> for SubscriptionId {
+    fn from(key: SubscriptionKey) -> Self {
+        key.id()
+    }
+}
 
 /// Defines an exchange specific market and channel combination used by an exchange
 /// [`Connector`](super::Connector) to build the
@@ -47,11 +206,7 @@ where
     Market: AsRef<str>,
 {
     fn id(&self) -> SubscriptionId {
-        SubscriptionId::from(format!(
-            "{}|{}",
-            self.channel.as_ref(),
-            self.market.as_ref()
-        ))
+        subscription_id(&self.channel, &self.market)
     }
 }
 
@@ -70,6 +225,11 @@ where
             market: sub.id(),
         }
     }
+
+    /// Construct the typed [`SubscriptionKey`] of this [`ExchangeSub`] for the provided exchange.
+    pub fn key(&self, exchange: ExchangeId) -> SubscriptionKey {
+        SubscriptionKey::new(exchange, &self.channel, &self.market)
+    }
 }
 
 impl<Channel, Market> From<(Channel, Market)> for ExchangeSub<Channel, Market>
@@ -81,3 +241,71 @@ where
         Self { channel, market }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::Map;
+    use barter_integration::error::SocketError;
+
+    #[test]
+    fn test_subscription_key() {
+        let key = SubscriptionKey::new(ExchangeId::BinanceSpot, "@trade", String::from("BTCUSDT"));
+
+        // Equal strings are interned to the same allocation
+        assert_eq!(key.channel, Interned::new("@trade"));
+        assert!(std::ptr::eq(
+            key.market.as_str(),
+            Interned::new("BTCUSDT").as_str()
+        ));
+        assert_ne!(key.market, Interned::new("ETHUSDT"));
+
+        // Key, ExchangeSub & free function derive the same canonical SubscriptionId
+        let expected = SubscriptionId::from("@trade|BTCUSDT");
+        assert_eq!(key.id(), expected);
+        assert_eq!(ExchangeSub::from(("@trade", "BTCUSDT")).id(), expected);
+        assert_eq!(subscription_id("@trade", "BTCUSDT"), expected);
+        assert_eq!(
+            ExchangeSub::from(("@trade", "BTCUSDT")).key(ExchangeId::BinanceSpot),
+            key
+        );
+    }
+
+    #[test]
+    fn test_map_find_key() {
+        let map = Map::from_iter([(subscription_id("@trade", "BTCUSDT"), 1)]);
+
+        struct TestCase {
+            input: SubscriptionKey,
+            expected: Option<u8>,
+        }
+
+        let cases = vec![
+            // TC0: subscribed key
+            TestCase {
+                input: SubscriptionKey::new(ExchangeId::BinanceSpot, "@trade", "BTCUSDT"),
+                expected: Some(1),
+            },
+            // TC1: un-subscribed market
+            TestCase {
+                input: SubscriptionKey::new(ExchangeId::BinanceSpot, "@trade", "ETHUSDT"),
+                expected: None,
+            },
+            // TC2: un-subscribed channel
+            TestCase {
+                input: SubscriptionKey::new(ExchangeId::BinanceSpot, "@depth", "BTCUSDT"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = map.find_key(&test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        assert!(matches!(
+            map.find_channel_market("@trade", "ETHUSDT"),
+            Err(SocketError::Unidentifiable(id)) if id == subscription_id("@trade", "ETHUSDT")
+        ));
+    }
+}
@@ -0,0 +1,397 @@
+use super::{msg_type, tag, FixMessage, FixSession};
+use crate::{
+    credentials::{Credentials, SignatureMethod},
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        coinbase::{market::CoinbaseMarket, Coinbase},
+        ExchangeId,
+    },
+    subscription::{
+        trade::{PublicTrade, PublicTrades},
+        Map, Subscription,
+    },
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpStream,
+};
+use tokio_rustls::{
+    client::TlsStream,
+    rustls::{ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName},
+    TlsConnector,
+};
+use tracing::{debug, info, warn};
+
+/// [`Coinbase`] FIX market data server host.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/fix-msg-market-data>
+pub const HOST_COINBASE_FIX_MD: &str = "fix-md.exchange.coinbase.com";
+
+/// [`Coinbase`] FIX market data server TLS port.
+pub const PORT_COINBASE_FIX_MD: u16 = 6121;
+
+/// [`Coinbase`] FIX 5.0 SP2 session `BeginString`.
+pub const BEGIN_STRING_COINBASE_FIX: &str = "FIXT.1.1";
+
+/// [`Coinbase`] FIX session `TargetCompID`.
+pub const TARGET_COMP_ID_COINBASE: &str = "Coinbase";
+
+/// [`Coinbase`] FIX session `DefaultApplVerID` (ie/ FIX 5.0 SP2).
+const DEFAULT_APPL_VER_ID_FIX50SP2: &str = "9";
+
+/// Interval the session must be kept alive with `Heartbeat`s.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// `MDReqID` of the [`PublicTrades`] `MarketDataRequest`.
+const MD_REQ_ID_TRADES: &str = "barter-trades";
+
+/// [`Coinbase`] Exchange FIX market data session that normalises `MarketDataIncrementalRefresh`
+/// trade entries into the same [`MarketEvent<PublicTrade>`]s as the
+/// [`Coinbase`] WebSocket integration.
+///
+/// Authenticates with the API key, secret & passphrase of the provided [`Credentials`], and
+/// subscribes to the [`Subscription`] markets (eg/ "BTC-USD") with a single
+/// `MarketDataRequest`. Book entries of the market data are skipped.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     credentials::Credentials,
+///     exchange::coinbase::Coinbase,
+///     fix::coinbase::CoinbaseFixMd,
+///     subscription::{trade::PublicTrades, Subscription},
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let credentials = Credentials::new("api_key", "secret").with_passphrase("passphrase");
+///     let subscriptions = [Subscription::from((
+///         Coinbase,
+///         "btc",
+///         "usd",
+///         InstrumentKind::Spot,
+///         PublicTrades,
+///     ))];
+///
+///     let mut session = CoinbaseFixMd::connect(&credentials, &subscriptions)
+///         .await
+///         .unwrap();
+///
+///     while let Some(trade) = session.next().await {
+///         println!("{trade:?}");
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CoinbaseFixMd<Io> {
+    session: FixSession<Io>,
+    instrument_map: Map<Instrument>,
+    buffer: VecDeque<Result<MarketEvent<PublicTrade>, DataError>>,
+}
+
+impl CoinbaseFixMd<TlsStream<TcpStream>> {
+    /// Connect to the [`Coinbase`] FIX market data server over TLS, then logon & subscribe.
+    pub async fn connect(
+        credentials: &Credentials,
+        subscriptions: &[Subscription<Coinbase, PublicTrades>],
+    ) -> Result<Self, DataError> {
+        let mut roots = RootCertStore::empty();
+        roots.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        }));
+
+        let config = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+        let server_name = ServerName::try_from(HOST_COINBASE_FIX_MD)
+            .map_err(|error| DataError::Fix(format!("invalid server name: {error}")))?;
+
+        let tcp = TcpStream::connect((HOST_COINBASE_FIX_MD, PORT_COINBASE_FIX_MD))
+            .await
+            .map_err(|error| DataError::Fix(format!("failed to connect: {error}")))?;
+
+        let tls = TlsConnector::from(Arc::new(config))
+            .connect(server_name, tcp)
+            .await
+            .map_err(|error| DataError::Fix(format!("failed TLS handshake: {error}")))?;
+        debug!(exchange = %ExchangeId::Coinbase, "connected to FIX market data server");
+
+        Self::init(tls, credentials, subscriptions).await
+    }
+}
+
+impl<Io> CoinbaseFixMd<Io>
+where
+    Io: AsyncRead + AsyncWrite + Unpin,
+{
+    /// Logon & subscribe over an established connection to the [`Coinbase`] FIX market data
+    /// server (eg/ a TLS connection, or a local stunnel).
+    pub async fn init(
+        io: Io,
+        credentials: &Credentials,
+        subscriptions: &[Subscription<Coinbase, PublicTrades>],
+    ) -> Result<Self, DataError> {
+        let exchange = ExchangeId::Coinbase;
+        if subscriptions.is_empty() {
+            return Err(DataError::from(SocketError::Subscribe(
+                "no Subscriptions to action".to_owned(),
+            )));
+        }
+
+        let mut session = FixSession::new(
+            io,
+            BEGIN_STRING_COINBASE_FIX,
+            credentials.api_key.as_str(),
+            TARGET_COMP_ID_COINBASE,
+            HEARTBEAT_INTERVAL,
+        );
+
+        // Logon & await the Logon response
+        let logon = logon(&mut session, credentials)?;
+        session.send(&logon).await?;
+        loop {
+            let message = session.next().await.ok_or_else(|| {
+                DataError::Fix("connection closed before Logon response".to_owned())
+            })??;
+
+            match message.msg_type() {
+                Some(msg_type::LOGON) => break,
+                Some(msg_type::LOGOUT | msg_type::REJECT) => {
+                    return Err(DataError::from(SocketError::Subscribe(format!(
+                        "{exchange} FIX Logon failed: {}",
+                        message.get(tag::TEXT).unwrap_or_default()
+                    ))))
+                }
+                _ => continue,
+            }
+        }
+        debug!(%exchange, ?subscriptions, "logged on to FIX market data session");
+
+        // Map Subscriptions to Coinbase markets & send the MarketDataRequest
+        let instrument_map = subscriptions
+            .iter()
+            .map(|subscription| {
+                let market = Identifier::<CoinbaseMarket>::id(subscription);
+                (market.0.as_str().into(), subscription.instrument.clone())
+            })
+            .collect::<Map<Instrument>>();
+
+        let request = market_data_request(&mut session, &instrument_map);
+        session.send(&request).await?;
+
+        info!(%exchange, "subscribed to FIX market data session");
+        Ok(Self {
+            session,
+            instrument_map,
+            buffer: VecDeque::new(),
+        })
+    }
+
+    /// Receive the next [`MarketEvent<PublicTrade>`], returning `None` once the session ends.
+    ///
+    /// Session level messages are handled internally, and a `MarketDataRequestReject` or
+    /// `Logout` ends the session.
+    pub async fn next(&mut self) -> Option<Result<MarketEvent<PublicTrade>, DataError>> {
+        let exchange = ExchangeId::Coinbase;
+
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                return Some(event);
+            }
+
+            let message = match self.session.next().await? {
+                Ok(message) => message,
+                Err(error) => return Some(Err(error)),
+            };
+
+            match message.msg_type() {
+                Some(msg_type::MARKET_DATA_INCREMENTAL_REFRESH) => self.buffer.extend(
+                    MarketIter::<PublicTrade>::from((exchange, &self.instrument_map, &message)).0,
+                ),
+                Some(msg_type::MARKET_DATA_REQUEST_REJECT | msg_type::LOGOUT) => {
+                    warn!(
+                        %exchange,
+                        text = message.get(tag::TEXT).unwrap_or_default(),
+                        "FIX market data session ended"
+                    );
+                    return None;
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+/// Construct the signed [`Coinbase`] FIX `Logon` message.
+///
+/// The `RawData` signature is the base64 HMAC-SHA256 of the SOH delimited `SendingTime`,
+/// `MsgType`, `MsgSeqNum`, `SenderCompID`, `TargetCompID` & `Password`, keyed by the base64
+/// decoded secret.
+pub fn logon<Io>(
+    session: &mut FixSession<Io>,
+    credentials: &Credentials,
+) -> Result<FixMessage, DataError>
+where
+    Io: AsyncRead + AsyncWrite + Unpin,
+{
+    let passphrase = credentials.passphrase.as_deref().ok_or_else(|| {
+        SocketError::Subscribe(format!(
+            "{} FIX market data requires a Credentials passphrase",
+            ExchangeId::Coinbase
+        ))
+    })?;
+
+    let message = session.message(msg_type::LOGON);
+    let prehash = [
+        tag::SENDING_TIME,
+        tag::MSG_TYPE,
+        tag::MSG_SEQ_NUM,
+        tag::SENDER_COMP_ID,
+        tag::TARGET_COMP_ID,
+    ]
+    .into_iter()
+    .map(|tag| message.get(tag).unwrap_or_default())
+    .chain(std::iter::once(passphrase))
+    .collect::<Vec<_>>()
+    .join("\x01");
+
+    let signature = credentials.sign(SignatureMethod::HmacSha256Base64DecodedSecret, &prehash)?;
+
+    Ok(message
+        .with(tag::ENCRYPT_METHOD, 0)
+        .with(tag::HEART_BT_INT, HEARTBEAT_INTERVAL.as_secs())
+        .with(tag::RESET_SEQ_NUM_FLAG, "Y")
+        .with(tag::USERNAME, &credentials.api_key)
+        .with(tag::PASSWORD, passphrase)
+        .with(tag::RAW_DATA_LENGTH, signature.len())
+        .with(tag::RAW_DATA, signature)
+        .with(tag::DEFAULT_APPL_VER_ID, DEFAULT_APPL_VER_ID_FIX50SP2))
+}
+
+/// Construct the [`Coinbase`] FIX `MarketDataRequest` subscribing to snapshot & updates of every
+/// market in the [`Map<Instrument>`].
+pub fn market_data_request<Io>(
+    session: &mut FixSession<Io>,
+    instrument_map: &Map<Instrument>,
+) -> FixMessage
+where
+    Io: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut markets = instrument_map
+        .0
+        .keys()
+        .map(|market| market.as_ref())
+        .collect::<Vec<&str>>();
+    markets.sort_unstable();
+
+    let message = session
+        .message(msg_type::MARKET_DATA_REQUEST)
+        .with(tag::MD_REQ_ID, MD_REQ_ID_TRADES)
+        .with(tag::SUBSCRIPTION_REQUEST_TYPE, 1)
+        .with(tag::MARKET_DEPTH, 0)
+        .with(tag::NO_RELATED_SYM, markets.len());
+
+    markets
+        .into_iter()
+        .fold(message, |message, market| message.with(tag::SYMBOL, market))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+    async fn read_message(server: &mut DuplexStream) -> FixMessage {
+        let mut buffer = vec![0u8; 4096];
+        let read = server.read(&mut buffer).await.unwrap();
+        FixMessage::decode(&buffer[..read]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_coinbase_fix_md_session() {
+        let (client, mut server) = tokio::io::duplex(4096);
+        let credentials = Credentials::new("api_key", "c2VjcmV0").with_passphrase("passphrase");
+        let subscriptions = [Subscription::from((
+            Coinbase,
+            "btc",
+            "usd",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+
+        let exchange = tokio::spawn(async move {
+            // Validate the signed Logon
+            let logon = read_message(&mut server).await;
+            assert_eq!(logon.msg_type(), Some(msg_type::LOGON));
+            assert_eq!(logon.get(tag::SENDER_COMP_ID), Some("api_key"));
+            assert_eq!(logon.get(tag::TARGET_COMP_ID), Some("Coinbase"));
+            assert_eq!(logon.get(tag::PASSWORD), Some("passphrase"));
+
+            let prehash = format!(
+                "{}\x01A\x011\x01api_key\x01Coinbase\x01passphrase",
+                logon.get(tag::SENDING_TIME).unwrap()
+            );
+            let signature = Credentials::new("api_key", "c2VjcmV0")
+                .sign(SignatureMethod::HmacSha256Base64DecodedSecret, &prehash)
+                .unwrap();
+            assert_eq!(logon.get(tag::RAW_DATA), Some(signature.as_str()));
+
+            let response = FixMessage::new(msg_type::LOGON);
+            server
+                .write_all(&response.encode(BEGIN_STRING_COINBASE_FIX))
+                .await
+                .unwrap();
+
+            // Validate the MarketDataRequest
+            let request = read_message(&mut server).await;
+            assert_eq!(request.msg_type(), Some(msg_type::MARKET_DATA_REQUEST));
+            assert_eq!(request.get(tag::MSG_SEQ_NUM), Some("2"));
+            assert_eq!(request.get(tag::SYMBOL), Some("BTC-USD"));
+
+            let refresh = FixMessage::new(msg_type::MARKET_DATA_INCREMENTAL_REFRESH)
+                .with(tag::MD_REQ_ID, MD_REQ_ID_TRADES)
+                .with(tag::NO_MD_ENTRIES, 1)
+                .with(tag::MD_UPDATE_ACTION, 0)
+                .with(tag::MD_ENTRY_TYPE, 2)
+                .with(tag::SYMBOL, "BTC-USD")
+                .with(tag::MD_ENTRY_PX, "16500.5")
+                .with(tag::MD_ENTRY_SIZE, "0.25")
+                .with(tag::TRANSACT_TIME, "20230101-00:00:00.123")
+                .with(tag::TRADE_ID, "12345")
+                .with(tag::AGGRESSOR_SIDE, 1);
+            server
+                .write_all(&refresh.encode(BEGIN_STRING_COINBASE_FIX))
+                .await
+                .unwrap();
+
+            server
+        });
+
+        let mut session = CoinbaseFixMd::init(client, &credentials, &subscriptions)
+            .await
+            .unwrap();
+
+        let trade = session.next().await.unwrap().unwrap();
+        assert_eq!(trade.instrument, subscriptions[0].instrument);
+        assert_eq!(trade.kind.price, 16500.5);
+        assert_eq!(trade.kind.amount, 0.25);
+        assert_eq!(trade.kind.side, Side::Buy);
+        assert_eq!(trade.kind.buyer_is_maker, Some(false));
+
+        // Session ends once the exchange closes the connection
+        drop(exchange.await.unwrap());
+        assert!(session.next().await.is_none());
+    }
+}
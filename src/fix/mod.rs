@@ -0,0 +1,702 @@
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::{
+        trade::{PublicTrade, TradeId},
+        Map,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// [`CoinbaseFixMd`](coinbase::CoinbaseFixMd) market data session for the Coinbase Exchange
+/// FIX 5.0 SP2 API.
+pub mod coinbase;
+
+/// FIX field delimiter (ie/ the SOH character).
+pub const SOH: u8 = 0x01;
+
+/// FIX UTCTimestamp format used for outbound `SendingTime`s.
+const UTC_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// Length of the FIX `CheckSum` trailer (eg/ "10=123|").
+const CHECK_SUM_LEN: usize = 7;
+
+/// Number of bytes read from the connection at a time.
+const READ_CHUNK_LEN: usize = 4096;
+
+/// FIX tags used by the market data adapters.
+pub mod tag {
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECK_SUM: u32 = 10;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const SYMBOL: u32 = 55;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const TRANSACT_TIME: u32 = 60;
+    pub const RAW_DATA_LENGTH: u32 = 95;
+    pub const RAW_DATA: u32 = 96;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const NO_RELATED_SYM: u32 = 146;
+    pub const MD_REQ_ID: u32 = 262;
+    pub const SUBSCRIPTION_REQUEST_TYPE: u32 = 263;
+    pub const MARKET_DEPTH: u32 = 264;
+    pub const NO_MD_ENTRIES: u32 = 268;
+    pub const MD_ENTRY_TYPE: u32 = 269;
+    pub const MD_ENTRY_PX: u32 = 270;
+    pub const MD_ENTRY_SIZE: u32 = 271;
+    pub const MD_UPDATE_ACTION: u32 = 279;
+    pub const USERNAME: u32 = 553;
+    pub const PASSWORD: u32 = 554;
+    pub const TRADE_ID: u32 = 1003;
+    pub const DEFAULT_APPL_VER_ID: u32 = 1137;
+    pub const AGGRESSOR_SIDE: u32 = 2446;
+}
+
+/// FIX `MsgType`s used by the market data adapters.
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const REJECT: &str = "3";
+    pub const LOGOUT: &str = "5";
+    pub const LOGON: &str = "A";
+    pub const MARKET_DATA_REQUEST: &str = "V";
+    pub const MARKET_DATA_SNAPSHOT_FULL_REFRESH: &str = "W";
+    pub const MARKET_DATA_INCREMENTAL_REFRESH: &str = "X";
+    pub const MARKET_DATA_REQUEST_REJECT: &str = "Y";
+}
+
+/// FIX message, excluding the `BeginString`, `BodyLength` & `CheckSum` fields that are derived
+/// when encoding.
+///
+/// Fields are stored in order since FIX repeating groups are order sensitive, and the first field
+/// is expected to be the `MsgType`.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct FixMessage {
+    pub fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Construct a new [`Self`] of the provided `MsgType`.
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            fields: vec![(tag::MSG_TYPE, msg_type.to_owned())],
+        }
+    }
+
+    /// Append a field to the message.
+    pub fn with<V>(mut self, tag: u32, value: V) -> Self
+    where
+        V: ToString,
+    {
+        self.fields.push((tag, value.to_string()));
+        self
+    }
+
+    /// First value of the provided tag, if present.
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == tag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// `MsgType` of the message, if present.
+    pub fn msg_type(&self) -> Option<&str> {
+        self.get(tag::MSG_TYPE)
+    }
+
+    /// Fields of each entry in the repeating group counted by `count_tag`, where every entry
+    /// starts with the `first_tag`.
+    ///
+    /// The final entry extends to the end of the message, so this is only suitable for message
+    /// types where the repeating group is the last component of the body.
+    pub fn groups(&self, count_tag: u32, first_tag: u32) -> Vec<&[(u32, String)]> {
+        let Some(start) = self
+            .fields
+            .iter()
+            .position(|(field, _)| *field == count_tag)
+        else {
+            return Vec::new();
+        };
+
+        let fields = &self.fields[start + 1..];
+        let starts = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, (field, _))| *field == first_tag)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        starts
+            .iter()
+            .zip(starts.iter().skip(1).chain(std::iter::once(&fields.len())))
+            .map(|(&start, &end)| &fields[start..end])
+            .collect()
+    }
+
+    /// Encode the message into a FIX frame with the provided `BeginString` (eg/ "FIXT.1.1"),
+    /// deriving the `BodyLength` & `CheckSum`.
+    pub fn encode(&self, begin_string: &str) -> Vec<u8> {
+        let body = self
+            .fields
+            .iter()
+            .map(|(tag, value)| format!("{tag}={value}\x01"))
+            .collect::<String>();
+
+        let mut frame = format!(
+            "{}={begin_string}\x01{}={}\x01{body}",
+            tag::BEGIN_STRING,
+            tag::BODY_LENGTH,
+            body.len()
+        )
+        .into_bytes();
+
+        let check_sum = check_sum(&frame);
+        frame.extend_from_slice(format!("{}={check_sum:03}\x01", tag::CHECK_SUM).as_bytes());
+        frame
+    }
+
+    /// Determine the length of the first complete FIX frame in the buffer, returning `None` if
+    /// more bytes are required.
+    pub fn frame_len(buffer: &[u8]) -> Result<Option<usize>, DataError> {
+        let mut delimiters = buffer
+            .iter()
+            .enumerate()
+            .filter(|(_, byte)| **byte == SOH)
+            .map(|(index, _)| index);
+
+        let (Some(begin_string_end), Some(body_length_end)) =
+            (delimiters.next(), delimiters.next())
+        else {
+            return Ok(None);
+        };
+
+        if !buffer.starts_with(b"8=") {
+            return Err(DataError::Fix(
+                "frame does not start with BeginString".to_owned(),
+            ));
+        }
+
+        let body_length = std::str::from_utf8(&buffer[begin_string_end + 1..body_length_end])
+            .ok()
+            .and_then(|field| field.strip_prefix("9="))
+            .and_then(|length| length.parse::<usize>().ok())
+            .ok_or_else(|| {
+                DataError::Fix("frame does not contain a valid BodyLength".to_owned())
+            })?;
+
+        let frame_len = body_length_end + 1 + body_length + CHECK_SUM_LEN;
+        Ok((buffer.len() >= frame_len).then_some(frame_len))
+    }
+
+    /// Decode a complete FIX frame, validating the `BodyLength` & `CheckSum`.
+    pub fn decode(frame: &[u8]) -> Result<Self, DataError> {
+        let frame_len = Self::frame_len(frame)?
+            .filter(|frame_len| *frame_len == frame.len())
+            .ok_or_else(|| DataError::Fix(format!("incomplete frame: {frame:?}")))?;
+
+        let (content, trailer) = frame.split_at(frame_len - CHECK_SUM_LEN);
+        let expected = std::str::from_utf8(trailer)
+            .ok()
+            .and_then(|trailer| trailer.strip_prefix("10="))
+            .and_then(|check_sum| check_sum.trim_end_matches('\x01').parse::<u8>().ok())
+            .ok_or_else(|| DataError::Fix("frame does not end with a valid CheckSum".to_owned()))?;
+
+        if check_sum(content) != expected {
+            return Err(DataError::Fix(format!(
+                "invalid CheckSum for frame: {frame:?}"
+            )));
+        }
+
+        let content = std::str::from_utf8(content)
+            .map_err(|error| DataError::Fix(format!("frame is not valid UTF-8: {error}")))?;
+
+        let fields = content
+            .split_terminator('\x01')
+            .skip(2)
+            .map(|field| {
+                field
+                    .split_once('=')
+                    .and_then(|(tag, value)| Some((tag.parse().ok()?, value.to_owned())))
+                    .ok_or_else(|| DataError::Fix(format!("invalid field: {field}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { fields })
+    }
+}
+
+/// Sum of the bytes modulo 256, as used by the FIX `CheckSum`.
+fn check_sum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+}
+
+/// Format a FIX UTCTimestamp (eg/ "20230101-00:00:00.000").
+pub fn format_utc_timestamp(time: DateTime<Utc>) -> String {
+    time.format(UTC_TIMESTAMP_FORMAT).to_string()
+}
+
+/// Parse a FIX UTCTimestamp with optional fractional seconds (eg/ "20230101-00:00:00.123456").
+pub fn parse_utc_timestamp(time: &str) -> Result<DateTime<Utc>, DataError> {
+    NaiveDateTime::parse_from_str(time, "%Y%m%d-%H:%M:%S%.f")
+        .map(|time| time.and_utc())
+        .map_err(|error| DataError::Fix(format!("invalid UTCTimestamp {time}: {error}")))
+}
+
+/// FIX `MDUpdateAction` of a [`FixMdEntry`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MdUpdateAction {
+    New,
+    Change,
+    Delete,
+}
+
+/// FIX `MDEntryType` of a [`FixMdEntry`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MdEntryType {
+    Bid,
+    Offer,
+    Trade,
+    Other(String),
+}
+
+/// Entry of a FIX `MarketDataIncrementalRefresh` or `MarketDataSnapshotFullRefresh`
+/// `NoMDEntries` repeating group.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FixMdEntry {
+    pub action: Option<MdUpdateAction>,
+    pub kind: MdEntryType,
+    pub symbol: Option<String>,
+    pub price: Option<f64>,
+    pub amount: Option<f64>,
+    pub time: Option<DateTime<Utc>>,
+    pub trade_id: Option<String>,
+    pub aggressor: Option<Side>,
+}
+
+impl FixMdEntry {
+    /// Parse every `NoMDEntries` entry of a market data message. Entries without a `Symbol`
+    /// inherit the message level `Symbol`, if present.
+    pub fn parse_all(message: &FixMessage) -> Result<Vec<Self>, DataError> {
+        let first_tag = match message.msg_type() {
+            Some(msg_type::MARKET_DATA_INCREMENTAL_REFRESH) => tag::MD_UPDATE_ACTION,
+            _ => tag::MD_ENTRY_TYPE,
+        };
+
+        message
+            .groups(tag::NO_MD_ENTRIES, first_tag)
+            .into_iter()
+            .map(|group| {
+                let mut entry = Self::parse(group)?;
+                if entry.symbol.is_none() {
+                    entry.symbol = message.get(tag::SYMBOL).map(str::to_owned);
+                }
+                Ok(entry)
+            })
+            .collect()
+    }
+
+    /// Parse the fields of a single `NoMDEntries` entry.
+    pub fn parse(fields: &[(u32, String)]) -> Result<Self, DataError> {
+        let get = |tag: u32| {
+            fields
+                .iter()
+                .find(|(field, _)| *field == tag)
+                .map(|(_, value)| value.as_str())
+        };
+        let parse_f64 = |tag: u32| {
+            get(tag)
+                .map(|value| {
+                    value
+                        .parse::<f64>()
+                        .map_err(|error| DataError::Fix(format!("invalid tag {tag}: {error}")))
+                })
+                .transpose()
+        };
+
+        let action = match get(tag::MD_UPDATE_ACTION) {
+            None => None,
+            Some("0") => Some(MdUpdateAction::New),
+            Some("1") => Some(MdUpdateAction::Change),
+            Some("2") => Some(MdUpdateAction::Delete),
+            Some(other) => return Err(DataError::Fix(format!("invalid MDUpdateAction: {other}"))),
+        };
+
+        let kind = match get(tag::MD_ENTRY_TYPE) {
+            Some("0") => MdEntryType::Bid,
+            Some("1") => MdEntryType::Offer,
+            Some("2") => MdEntryType::Trade,
+            Some(other) => MdEntryType::Other(other.to_owned()),
+            None => {
+                return Err(DataError::Fix(
+                    "entry does not contain MDEntryType".to_owned(),
+                ))
+            }
+        };
+
+        let aggressor = match get(tag::AGGRESSOR_SIDE) {
+            None => None,
+            Some("1") => Some(Side::Buy),
+            Some("2") => Some(Side::Sell),
+            Some(other) => return Err(DataError::Fix(format!("invalid AggressorSide: {other}"))),
+        };
+
+        Ok(Self {
+            action,
+            kind,
+            symbol: get(tag::SYMBOL).map(str::to_owned),
+            price: parse_f64(tag::MD_ENTRY_PX)?,
+            amount: parse_f64(tag::MD_ENTRY_SIZE)?,
+            time: get(tag::TRANSACT_TIME)
+                .map(parse_utc_timestamp)
+                .transpose()?,
+            trade_id: get(tag::TRADE_ID).map(str::to_owned),
+            aggressor,
+        })
+    }
+}
+
+impl From<(ExchangeId, &Map<Instrument>, &FixMessage)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument_map, message): (ExchangeId, &Map<Instrument>, &FixMessage),
+    ) -> Self {
+        let entries = match FixMdEntry::parse_all(message) {
+            Ok(entries) => entries,
+            Err(error) => return Self(vec![Err(error)]),
+        };
+
+        let sending_time = message
+            .get(tag::SENDING_TIME)
+            .and_then(|time| parse_utc_timestamp(time).ok());
+
+        entries
+            .into_iter()
+            .filter(|entry| entry.kind == MdEntryType::Trade)
+            .map(|entry| {
+                let symbol = entry
+                    .symbol
+                    .ok_or_else(|| DataError::Fix("trade does not contain a Symbol".to_owned()))?;
+                let instrument = instrument_map.find(&SubscriptionId::from(symbol))?;
+
+                let (Some(price), Some(amount), Some(side)) =
+                    (entry.price, entry.amount, entry.aggressor)
+                else {
+                    return Err(DataError::Fix(
+                        "trade does not contain MDEntryPx, MDEntrySize & AggressorSide".to_owned(),
+                    ));
+                };
+
+                let id = match entry.trade_id {
+                    Some(id) => id
+                        .parse::<u64>()
+                        .map(TradeId::from)
+                        .unwrap_or(TradeId::from(id)),
+                    None => TradeId::None,
+                };
+
+                Ok(MarketEvent {
+                    exchange_time: entry.time.or(sending_time).unwrap_or_else(Utc::now),
+                    received_time: Utc::now(),
+                    received_monotonic: MonotonicTime::now(),
+                    origin: Origin::Live,
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    // AggressorSide is the taker side, so the buy order was the maker if it's a sell
+                    kind: PublicTrade {
+                        buyer_is_maker: Some(side == Side::Sell),
+                        ..PublicTrade::new(id, price, amount, side)
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// FIX session over a byte stream (eg/ a TLS connection) that frames inbound messages, stamps
+/// outbound message headers, and maintains the session with heartbeats.
+#[derive(Debug)]
+pub struct FixSession<Io> {
+    io: Io,
+    buffer: Vec<u8>,
+    begin_string: &'static str,
+    sender_comp_id: String,
+    target_comp_id: String,
+    next_seq_num: u64,
+    heartbeat_interval: Duration,
+}
+
+impl<Io> FixSession<Io>
+where
+    Io: AsyncRead + AsyncWrite + Unpin,
+{
+    /// Construct a new [`Self`] over the provided connection.
+    pub fn new<S>(
+        io: Io,
+        begin_string: &'static str,
+        sender_comp_id: S,
+        target_comp_id: S,
+        heartbeat_interval: Duration,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            io,
+            buffer: Vec::with_capacity(READ_CHUNK_LEN),
+            begin_string,
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+            next_seq_num: 1,
+            heartbeat_interval,
+        }
+    }
+
+    /// Construct the next outbound message of the provided `MsgType`, including the
+    /// `SenderCompID`, `TargetCompID`, `MsgSeqNum` & `SendingTime` header fields.
+    pub fn message(&mut self, msg_type: &str) -> FixMessage {
+        let message = FixMessage::new(msg_type)
+            .with(tag::SENDER_COMP_ID, &self.sender_comp_id)
+            .with(tag::TARGET_COMP_ID, &self.target_comp_id)
+            .with(tag::MSG_SEQ_NUM, self.next_seq_num)
+            .with(tag::SENDING_TIME, format_utc_timestamp(Utc::now()));
+
+        self.next_seq_num += 1;
+        message
+    }
+
+    /// Send a message constructed via [`Self::message`].
+    pub async fn send(&mut self, message: &FixMessage) -> Result<(), DataError> {
+        self.io
+            .write_all(&message.encode(self.begin_string))
+            .await
+            .map_err(|error| DataError::Fix(format!("failed to send message: {error}")))
+    }
+
+    /// Receive the next application or session level message, responding to `TestRequest`s and
+    /// sending a `Heartbeat` whenever the connection has been idle for the heartbeat interval.
+    ///
+    /// Returns `None` once the connection is closed.
+    pub async fn next(&mut self) -> Option<Result<FixMessage, DataError>> {
+        loop {
+            match FixMessage::frame_len(&self.buffer) {
+                Ok(Some(frame_len)) => {
+                    let message = FixMessage::decode(&self.buffer[..frame_len]);
+                    self.buffer.drain(..frame_len);
+
+                    if let Ok(message) = &message {
+                        if message.msg_type() == Some(msg_type::TEST_REQUEST) {
+                            let heartbeat = self.message(msg_type::HEARTBEAT).with(
+                                tag::TEST_REQ_ID,
+                                message.get(tag::TEST_REQ_ID).unwrap_or_default(),
+                            );
+                            if let Err(error) = self.send(&heartbeat).await {
+                                return Some(Err(error));
+                            }
+                        }
+                    }
+
+                    return Some(message);
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    // Discard the unframeable bytes, since the session cannot be recovered
+                    self.buffer.clear();
+                    return Some(Err(error));
+                }
+            }
+
+            let mut chunk = [0u8; READ_CHUNK_LEN];
+            match tokio::time::timeout(self.heartbeat_interval, self.io.read(&mut chunk)).await {
+                Ok(Ok(0)) => return None,
+                Ok(Ok(read)) => self.buffer.extend_from_slice(&chunk[..read]),
+                Ok(Err(error)) => {
+                    return Some(Err(DataError::Fix(format!(
+                        "failed to receive message: {error}"
+                    ))))
+                }
+                Err(_) => {
+                    let heartbeat = self.message(msg_type::HEARTBEAT);
+                    if let Err(error) = self.send(&heartbeat).await {
+                        return Some(Err(error));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    /// Replace the human readable "|" delimiter with SOH.
+    fn soh(frame: &str) -> Vec<u8> {
+        frame.replace('|', "\x01").into_bytes()
+    }
+
+    #[test]
+    fn test_fix_message_encode_decode() {
+        let message = FixMessage::new(msg_type::HEARTBEAT)
+            .with(tag::SENDER_COMP_ID, "CLIENT")
+            .with(tag::TARGET_COMP_ID, "Coinbase")
+            .with(tag::MSG_SEQ_NUM, 2);
+
+        let encoded = message.encode("FIXT.1.1");
+        assert_eq!(
+            encoded,
+            soh("8=FIXT.1.1|9=32|35=0|49=CLIENT|56=Coinbase|34=2|10=047|")
+        );
+        assert_eq!(FixMessage::decode(&encoded).unwrap(), message);
+    }
+
+    #[test]
+    fn test_fix_message_frame_len() {
+        struct TestCase {
+            input: Vec<u8>,
+            expected: Result<Option<usize>, DataError>,
+        }
+
+        let frame = soh("8=FIXT.1.1|9=32|35=0|49=CLIENT|56=Coinbase|34=2|10=047|");
+
+        let tests = vec![
+            TestCase {
+                // TC0: complete frame followed by a partial frame
+                input: [frame.clone(), soh("8=FIXT.1.1|9=")].concat(),
+                expected: Ok(Some(frame.len())),
+            },
+            TestCase {
+                // TC1: partial frame missing the CheckSum
+                input: frame[..frame.len() - 3].to_vec(),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC2: partial frame missing the BodyLength
+                input: soh("8=FIXT.1.1|9=3"),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC3: frame that does not start with BeginString
+                input: soh("9=32|35=0|"),
+                expected: Err(DataError::Fix(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = FixMessage::frame_len(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fix_message_decode_invalid_check_sum() {
+        let frame = soh("8=FIXT.1.1|9=32|35=0|49=CLIENT|56=Coinbase|34=2|10=048|");
+        assert!(matches!(FixMessage::decode(&frame), Err(DataError::Fix(_))));
+    }
+
+    #[test]
+    fn test_incremental_refresh_to_public_trades() {
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let instrument_map =
+            Map::from_iter([(SubscriptionId::from("BTC-USD"), instrument.clone())]);
+
+        let message = FixMessage::new(msg_type::MARKET_DATA_INCREMENTAL_REFRESH)
+            .with(tag::SENDING_TIME, "20230101-00:00:01.000")
+            .with(tag::MD_REQ_ID, "1")
+            .with(tag::NO_MD_ENTRIES, 3)
+            // Book entry is not a trade
+            .with(tag::MD_UPDATE_ACTION, 0)
+            .with(tag::MD_ENTRY_TYPE, 0)
+            .with(tag::SYMBOL, "BTC-USD")
+            .with(tag::MD_ENTRY_PX, "16500.01")
+            .with(tag::MD_ENTRY_SIZE, "2")
+            .with(tag::MD_UPDATE_ACTION, 0)
+            .with(tag::MD_ENTRY_TYPE, 2)
+            .with(tag::SYMBOL, "BTC-USD")
+            .with(tag::MD_ENTRY_PX, "16500.5")
+            .with(tag::MD_ENTRY_SIZE, "0.25")
+            .with(tag::TRANSACT_TIME, "20230101-00:00:00.123456")
+            .with(tag::TRADE_ID, "12345")
+            .with(tag::AGGRESSOR_SIDE, 2)
+            // Trade of an unsubscribed Symbol
+            .with(tag::MD_UPDATE_ACTION, 0)
+            .with(tag::MD_ENTRY_TYPE, 2)
+            .with(tag::SYMBOL, "ETH-USD")
+            .with(tag::MD_ENTRY_PX, "1200")
+            .with(tag::MD_ENTRY_SIZE, "1")
+            .with(tag::AGGRESSOR_SIDE, 1);
+
+        let actual =
+            MarketIter::<PublicTrade>::from((ExchangeId::Coinbase, &instrument_map, &message)).0;
+        assert_eq!(actual.len(), 2);
+
+        let trade = actual[0].as_ref().unwrap();
+        assert_eq!(
+            trade.exchange_time,
+            parse_utc_timestamp("20230101-00:00:00.123456").unwrap()
+        );
+        assert_eq!(trade.instrument, instrument);
+        assert_eq!(trade.kind.id, TradeId::from(12345));
+        assert_eq!(trade.kind.price, 16500.5);
+        assert_eq!(trade.kind.amount, 0.25);
+        assert_eq!(trade.kind.side, Side::Sell);
+        assert_eq!(trade.kind.buyer_is_maker, Some(true));
+
+        assert!(matches!(
+            &actual[1],
+            Err(DataError::Socket(
+                barter_integration::error::SocketError::Unidentifiable(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fix_session_responds_to_test_request() {
+        let (client, mut server) = tokio::io::duplex(READ_CHUNK_LEN);
+        let mut session = FixSession::new(
+            client,
+            "FIXT.1.1",
+            "CLIENT",
+            "Coinbase",
+            Duration::from_secs(30),
+        );
+
+        let test_request = FixMessage::new(msg_type::TEST_REQUEST).with(tag::TEST_REQ_ID, "ping");
+        server
+            .write_all(&test_request.encode("FIXT.1.1"))
+            .await
+            .unwrap();
+
+        let received = session.next().await.unwrap().unwrap();
+        assert_eq!(received, test_request);
+
+        let mut buffer = vec![0u8; READ_CHUNK_LEN];
+        let read = server.read(&mut buffer).await.unwrap();
+        let heartbeat = FixMessage::decode(&buffer[..read]).unwrap();
+        assert_eq!(heartbeat.msg_type(), Some(msg_type::HEARTBEAT));
+        assert_eq!(heartbeat.get(tag::TEST_REQ_ID), Some("ping"));
+        assert_eq!(heartbeat.get(tag::MSG_SEQ_NUM), Some("1"));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::ExchangeId,
+    subscription::trade::{PublicTrade, TradeId},
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Exchange, Side,
+};
+use chrono::Utc;
+
+/// Construct a live [`MarketEvent<T>`] test fixture containing the provided `kind`, received now
+/// from [`ExchangeId::BinanceSpot`] for the btc_usdt spot [`Instrument`].
+///
+/// Tests override the fields they depend on using struct update syntax, eg/
+/// `MarketEvent { exchange_time, ..market_event(kind) }`.
+pub fn market_event<T>(kind: T) -> MarketEvent<T> {
+    let now = Utc::now();
+    MarketEvent {
+        exchange_time: now,
+        received_time: now,
+        received_monotonic: MonotonicTime::now(),
+        origin: Origin::Live,
+        exchange: Exchange::from(ExchangeId::BinanceSpot),
+        instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        kind,
+    }
+}
+
+/// Construct a buy [`PublicTrade`] test fixture without a [`TradeId`].
+pub fn trade(price: f64, amount: f64) -> PublicTrade {
+    PublicTrade::new(TradeId::None, price, amount, Side::Buy)
+}
+
+/// Construct a [`market_event`] test fixture containing a buy [`PublicTrade`] with the provided
+/// [`TradeId`].
+pub fn trade_event<Id>(id: Id, price: f64, amount: f64) -> MarketEvent<PublicTrade>
+where
+    Id: Into<TradeId>,
+{
+    market_event(PublicTrade::new(id, price, amount, Side::Buy))
+}
@@ -0,0 +1,256 @@
+use crate::{
+    event::MarketEvent,
+    exchange::{Connector, ExchangeId},
+    subscription::funding::FundingRate,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// Default perpetual funding interval used by most exchanges (eg/ 00:00, 08:00 & 16:00 UTC).
+pub const DEFAULT_FUNDING_INTERVAL_HOURS: i64 = 8;
+
+/// Exchange [`Connector`] that serves the funding interval of it's perpetual markets via HTTP.
+#[async_trait]
+pub trait FundingIntervals
+where
+    Self: Connector,
+{
+    /// Fetch the funding interval of each provided perpetual [`Instrument`].
+    async fn funding_intervals(
+        instruments: &[Instrument],
+    ) -> Result<Vec<(Instrument, Duration)>, SocketError>;
+}
+
+/// Funding schedule of a perpetual [`Instrument`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FundingSchedule {
+    /// Time between consecutive funding payments (eg/ 8h or 1h).
+    pub interval: Duration,
+    /// Time of the next funding payment, if a [`FundingRate`] has been received.
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
+
+impl Default for FundingSchedule {
+    fn default() -> Self {
+        Self {
+            interval: Duration::hours(DEFAULT_FUNDING_INTERVAL_HOURS),
+            next_funding_time: None,
+        }
+    }
+}
+
+impl FundingSchedule {
+    /// Upcoming `n` funding payment times, starting from the `next_funding_time`.
+    pub fn upcoming(&self, n: usize) -> Vec<DateTime<Utc>> {
+        self.next_funding_time
+            .map(|next| {
+                (0..n as i32)
+                    .map(|payment| next + self.interval * payment)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Calendar of upcoming funding payments for each exchange perpetual [`Instrument`], maintained
+/// from exchange [`FundingIntervals`] metadata and [`FundingRates`](crate::subscription::funding::FundingRates)
+/// [`MarketEvent<FundingRate>`] streams.
+///
+/// Funding intervals observed in the stream (ie/ the `next_funding_time` advancing at a funding
+/// payment) take precedence over the fetched metadata, so venue interval changes are picked up
+/// without restarting.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::{binance::futures::BinanceFuturesUsd, ExchangeId},
+///     funding::FundingCalendar,
+/// };
+/// use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+///
+///     let mut calendar = FundingCalendar::default();
+///     calendar
+///         .fetch::<BinanceFuturesUsd>(&[btc_usdt.clone()])
+///         .await
+///         .unwrap();
+///
+///     let interval = calendar.interval(ExchangeId::BinanceFuturesUsd, &btc_usdt);
+///     println!("funding interval: {interval:?}");
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FundingCalendar(pub HashMap<(Exchange, Instrument), FundingSchedule>);
+
+impl FundingCalendar {
+    /// Fetch & insert the exchange [`FundingIntervals`] of the provided perpetual [`Instrument`]s.
+    pub async fn fetch<E>(&mut self, instruments: &[Instrument]) -> Result<(), SocketError>
+    where
+        E: FundingIntervals,
+    {
+        for (instrument, interval) in E::funding_intervals(instruments).await? {
+            self.0
+                .entry((Exchange::from(E::ID), instrument))
+                .or_default()
+                .interval = interval;
+        }
+        Ok(())
+    }
+
+    /// Update the [`FundingSchedule`] of an exchange [`Instrument`] using a
+    /// [`MarketEvent<FundingRate>`].
+    ///
+    /// The funding interval is inferred when the `next_funding_time` advances promptly after the
+    /// previous funding time (within half the new interval), so gaps in the stream spanning
+    /// several payments are not mistaken for a longer interval.
+    pub fn update(&mut self, event: &MarketEvent<FundingRate>) {
+        let schedule = self
+            .0
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_default();
+
+        let next = event.kind.next_funding_time;
+
+        if let Some(previous) = schedule.next_funding_time {
+            let interval = next - previous;
+            if interval > Duration::zero()
+                && event.exchange_time >= previous
+                && event.exchange_time - previous < interval / 2
+            {
+                schedule.interval = interval;
+            }
+        }
+
+        schedule.next_funding_time = Some(next);
+    }
+
+    /// Get the [`FundingSchedule`] of an exchange perpetual [`Instrument`].
+    pub fn schedule(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+    ) -> Option<&FundingSchedule> {
+        self.0.get(&(Exchange::from(exchange), instrument.clone()))
+    }
+
+    /// Get the funding interval of an exchange perpetual [`Instrument`].
+    pub fn interval(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<Duration> {
+        self.schedule(exchange, instrument)
+            .map(|schedule| schedule.interval)
+    }
+
+    /// Get the next funding time of an exchange perpetual [`Instrument`].
+    pub fn next_funding_time(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+    ) -> Option<DateTime<Utc>> {
+        self.schedule(exchange, instrument)
+            .and_then(|schedule| schedule.next_funding_time)
+    }
+
+    /// Get the upcoming `n` funding times of an exchange perpetual [`Instrument`].
+    pub fn upcoming(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        n: usize,
+    ) -> Vec<DateTime<Utc>> {
+        self.schedule(exchange, instrument)
+            .map(|schedule| schedule.upcoming(n))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::market_event;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::TimeZone;
+
+    fn funding_event(
+        exchange_time: DateTime<Utc>,
+        next: DateTime<Utc>,
+    ) -> MarketEvent<FundingRate> {
+        MarketEvent {
+            exchange_time,
+            received_time: exchange_time,
+            exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            ..market_event(FundingRate {
+                rate: 0.0001,
+                mark_price: 100.0,
+                next_funding_time: next,
+            })
+        }
+    }
+
+    #[test]
+    fn test_funding_calendar_update() {
+        let time =
+            |hour| Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() + Duration::hours(hour);
+
+        struct TestCase {
+            events: Vec<MarketEvent<FundingRate>>,
+            expected_interval: Duration,
+            expected_upcoming: Vec<DateTime<Utc>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: single event uses the default interval
+                events: vec![funding_event(time(1), time(8))],
+                expected_interval: Duration::hours(8),
+                expected_upcoming: vec![time(8), time(16), time(24)],
+            },
+            TestCase {
+                // TC1: prompt rollover infers a 1h interval
+                events: vec![
+                    funding_event(time(7), time(8)),
+                    funding_event(time(8), time(9)),
+                ],
+                expected_interval: Duration::hours(1),
+                expected_upcoming: vec![time(9), time(10), time(11)],
+            },
+            TestCase {
+                // TC2: stream gap spanning several payments does not infer an interval
+                events: vec![
+                    funding_event(time(7), time(8)),
+                    funding_event(time(13), time(16)),
+                ],
+                expected_interval: Duration::hours(8),
+                expected_upcoming: vec![time(16), time(24), time(32)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut calendar = FundingCalendar::default();
+            for event in &test.events {
+                calendar.update(event);
+            }
+
+            let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+            assert_eq!(
+                calendar.interval(ExchangeId::BinanceFuturesUsd, &instrument),
+                Some(test.expected_interval),
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                calendar.upcoming(ExchangeId::BinanceFuturesUsd, &instrument, 3),
+                test.expected_upcoming,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    zero_copy::{access, ArchivedEventRecord, RecordEncoder, RecordKind},
+};
+use memmap2::{MmapOptions, MmapRaw};
+use rkyv::AlignedVec;
+use std::{
+    fs::{File, OpenOptions},
+    path::Path,
+    sync::atomic::{fence, AtomicU64, Ordering},
+};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Magic bytes identifying a Barter-Data shared memory ring buffer, including it's layout version.
+const MAGIC: u64 = u64::from_le_bytes(*b"BARTSHM2");
+
+/// Offset of the data region capacity in the shared memory header.
+const CAPACITY_OFFSET: usize = 8;
+
+/// Offset of the producer epoch, incremented each time a [`SharedMemoryProducer`] resumes an
+/// existing ring buffer, and set to [`CLOSED`] when the ring buffer file is replaced.
+const EPOCH_OFFSET: usize = 16;
+
+/// Epoch marking a ring buffer whose file was replaced by a [`SharedMemoryProducer`] with an
+/// incompatible layout, so it's consumers must re-open the file.
+const CLOSED: u64 = u64::MAX;
+
+/// Offset of the end position of the bytes claimed (and possibly being written) by the producer.
+/// Kept on it's own cache line.
+const CLAIM_OFFSET: usize = 64;
+
+/// Offset of the end position of the bytes committed (ie/ fully written) by the producer.
+/// Kept on it's own cache line.
+const COMMIT_OFFSET: usize = 128;
+
+/// Offset of the ring buffer data region.
+const DATA_OFFSET: usize = 192;
+
+/// Size of each frame header (the little endian `u32` record length, padded to 8 bytes).
+const FRAME_HEADER: usize = 8;
+
+/// Frame header length marking the unused space at the end of the data region before a wrap.
+const SKIP: u32 = u32::MAX;
+
+/// Default capacity of a [`SharedMemoryProducer`] data region (ie/ 64MiB).
+pub const DEFAULT_SHARED_MEMORY_CAPACITY: u64 = 64 * 1024 * 1024;
+
+/// Memory mapped shared memory ring buffer region.
+#[derive(Debug)]
+struct Region {
+    map: MmapRaw,
+    capacity: u64,
+}
+
+impl Region {
+    /// Atomic `u64` of the header at the provided offset.
+    fn atomic(&self, offset: usize) -> &AtomicU64 {
+        debug_assert!(offset.is_multiple_of(8) && offset + 8 <= DATA_OFFSET);
+        // Safety: the mapping is page aligned & at least DATA_OFFSET long, so every 8 byte
+        // aligned header offset is a valid, aligned AtomicU64 that lives as long as the mapping.
+        unsafe { &*(self.map.as_ptr().add(offset) as *const AtomicU64) }
+    }
+
+    /// Pointer to the provided data region offset.
+    fn data(&self, offset: u64) -> *mut u8 {
+        debug_assert!(offset < self.capacity);
+        // Safety: offsets are always reduced modulo the capacity of the mapped data region.
+        unsafe { self.map.as_mut_ptr().add(DATA_OFFSET + offset as usize) }
+    }
+
+    /// Determine the data region offset of a logical ring buffer position.
+    fn offset(&self, position: u64) -> u64 {
+        position % self.capacity
+    }
+}
+
+/// Single producer of [`EventRecord`](crate::zero_copy::EventRecord)s into a shared memory ring
+/// buffer, readable by any number of [`SharedMemoryConsumer`]s on the same host.
+///
+/// The producer never waits for consumers: when the ring buffer wraps, the oldest records are
+/// overwritten, and consumers that fall more than a ring buffer capacity behind are notified with
+/// a [`DataError::Lagged`].
+///
+/// A restarted producer resumes the existing ring buffer from it's last committed record, so
+/// consumers that remain open continue uninterrupted.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::ipc::{SharedMemoryConsumer, SharedMemoryProducer, DEFAULT_SHARED_MEMORY_CAPACITY};
+///
+/// // Producer process
+/// let mut producer =
+///     SharedMemoryProducer::create("/dev/shm/barter_data", DEFAULT_SHARED_MEMORY_CAPACITY).unwrap();
+///
+/// // Consumer process
+/// let mut consumer = SharedMemoryConsumer::open("/dev/shm/barter_data").unwrap();
+/// loop {
+///     match consumer.try_recv() {
+///         Ok(Some(record)) => println!("{} {}", &*record.exchange, record.exchange_time),
+///         Ok(None) => std::hint::spin_loop(),
+///         Err(error) => eprintln!("{error}"),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SharedMemoryProducer {
+    region: Region,
+    encoder: RecordEncoder,
+    position: u64,
+}
+
+impl SharedMemoryProducer {
+    /// Create the shared memory file at the provided path (eg/ in /dev/shm) with a data region of
+    /// the provided capacity in bytes, rounded up to a multiple of 8.
+    ///
+    /// If the file already contains a ring buffer of the same capacity (eg/ after a producer
+    /// restart), it is resumed from it's last committed record & the producer epoch is advanced.
+    /// Any other existing file is replaced rather than truncated, since consumers may still have
+    /// it mapped.
+    pub fn create<P>(path: P, capacity: u64) -> Result<Self, DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let capacity = capacity.div_ceil(8) * 8;
+
+        if let Some(producer) = Self::resume(path, capacity)? {
+            return Ok(producer);
+        }
+
+        // Unlink the existing file so the mappings of it's consumers remain valid
+        match std::fs::remove_file(path) {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(io_error(error)),
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(io_error)?;
+        file.set_len(DATA_OFFSET as u64 + capacity)
+            .map_err(io_error)?;
+
+        let map = MmapRaw::map_raw(&file).map_err(io_error)?;
+        let region = Region { map, capacity };
+
+        region
+            .atomic(CAPACITY_OFFSET)
+            .store(capacity, Ordering::Relaxed);
+        region.atomic(EPOCH_OFFSET).store(0, Ordering::Relaxed);
+        region.atomic(CLAIM_OFFSET).store(0, Ordering::Relaxed);
+        region.atomic(COMMIT_OFFSET).store(0, Ordering::Relaxed);
+        region.atomic(0).store(MAGIC, Ordering::Release);
+
+        Ok(Self {
+            region,
+            encoder: RecordEncoder::default(),
+            position: 0,
+        })
+    }
+
+    /// Resume the existing ring buffer at the provided path if it has the provided capacity,
+    /// otherwise mark it [`CLOSED`] (if it is a ring buffer) so it's consumers re-open the file.
+    fn resume(path: &Path, capacity: u64) -> Result<Option<Self>, DataError> {
+        let file = match OpenOptions::new().read(true).write(true).open(path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(io_error(error)),
+        };
+        let len = file.metadata().map_err(io_error)?.len();
+        if len < DATA_OFFSET as u64 {
+            return Ok(None);
+        }
+
+        let map = MmapRaw::map_raw(&file).map_err(io_error)?;
+        let region = Region { map, capacity };
+        if region.atomic(0).load(Ordering::Acquire) != MAGIC {
+            return Ok(None);
+        }
+
+        if region.atomic(CAPACITY_OFFSET).load(Ordering::Relaxed) != capacity
+            || len != DATA_OFFSET as u64 + capacity
+        {
+            region.atomic(EPOCH_OFFSET).store(CLOSED, Ordering::Release);
+            return Ok(None);
+        }
+
+        // Discard any bytes the previous producer claimed but never committed
+        let position = region.atomic(COMMIT_OFFSET).load(Ordering::Acquire);
+        region
+            .atomic(CLAIM_OFFSET)
+            .store(position, Ordering::Relaxed);
+        let epoch = region.atomic(EPOCH_OFFSET).load(Ordering::Relaxed);
+        region
+            .atomic(EPOCH_OFFSET)
+            .store(epoch.wrapping_add(1) % CLOSED, Ordering::Release);
+
+        Ok(Some(Self {
+            region,
+            encoder: RecordEncoder::default(),
+            position,
+        }))
+    }
+
+    /// Encode & publish a [`MarketEvent<T>`] to every [`SharedMemoryConsumer`].
+    ///
+    /// Records larger than half the ring buffer capacity are rejected.
+    pub fn publish<T>(&mut self, event: &MarketEvent<T>) -> Result<(), DataError>
+    where
+        T: RecordKind,
+    {
+        let record = self.encoder.encode(event)?;
+        let frame = frame_len(record.len());
+        if frame > self.region.capacity / 2 {
+            return Err(DataError::Record(format!(
+                "record of {} bytes exceeds the shared memory capacity",
+                record.len()
+            )));
+        }
+
+        let mut position = self.position;
+        let offset = self.region.offset(position);
+        let wrap = offset + frame > self.region.capacity;
+        if wrap {
+            position += self.region.capacity - offset;
+        }
+
+        // Claim the bytes before overwriting them, so consumers reading them can detect it
+        let claim = self.region.atomic(CLAIM_OFFSET);
+        claim.store(position + frame, Ordering::Relaxed);
+        fence(Ordering::Release);
+
+        // Safety: frames are 8 byte aligned & fit within the data region before the wrap, and
+        // this is the only writer of the data region.
+        unsafe {
+            if wrap {
+                write_header(self.region.data(offset), SKIP);
+            }
+            let data = self.region.data(self.region.offset(position));
+            write_header(data, record.len() as u32);
+            std::ptr::copy_nonoverlapping(record.as_ptr(), data.add(FRAME_HEADER), record.len());
+        }
+
+        self.position = position + frame;
+        self.region
+            .atomic(COMMIT_OFFSET)
+            .store(self.position, Ordering::Release);
+
+        Ok(())
+    }
+}
+
+/// Consumer of the [`EventRecord`](crate::zero_copy::EventRecord)s published by a
+/// [`SharedMemoryProducer`] on the same host.
+///
+/// Each record is copied into a consumer owned aligned buffer & validated before it is accessed,
+/// so a producer overwriting the record concurrently is detected rather than observed.
+#[derive(Debug)]
+pub struct SharedMemoryConsumer {
+    region: Region,
+    buffer: AlignedVec,
+    position: u64,
+    epoch: u64,
+}
+
+impl SharedMemoryConsumer {
+    /// Open the shared memory file created by a [`SharedMemoryProducer`] at the provided path,
+    /// starting from the most recently published record.
+    pub fn open<P>(path: P) -> Result<Self, DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path).map_err(io_error)?;
+        let len = file.metadata().map_err(io_error)?.len();
+        if len < DATA_OFFSET as u64 {
+            return Err(DataError::Record(
+                "shared memory file is too small".to_owned(),
+            ));
+        }
+
+        let map = MmapOptions::new()
+            .map_raw_read_only(&file)
+            .map_err(io_error)?;
+        let mut region = Region { map, capacity: 0 };
+
+        if region.atomic(0).load(Ordering::Acquire) != MAGIC {
+            return Err(DataError::Record(
+                "shared memory file is not a Barter-Data ring buffer".to_owned(),
+            ));
+        }
+
+        region.capacity = region.atomic(CAPACITY_OFFSET).load(Ordering::Relaxed);
+        if region.capacity == 0 || DATA_OFFSET as u64 + region.capacity > len {
+            return Err(DataError::Record(
+                "shared memory ring buffer capacity is invalid".to_owned(),
+            ));
+        }
+
+        let epoch = region.atomic(EPOCH_OFFSET).load(Ordering::Acquire);
+        if epoch == CLOSED {
+            return Err(closed_error());
+        }
+        let position = region.atomic(COMMIT_OFFSET).load(Ordering::Acquire);
+
+        Ok(Self {
+            region,
+            buffer: AlignedVec::new(),
+            position,
+            epoch,
+        })
+    }
+
+    /// Receive the next published record without waiting, returning `None` if the consumer is up
+    /// to date.
+    ///
+    /// If the consumer fell more than a ring buffer capacity behind the producer, it skips to the
+    /// most recently published record and returns a [`DataError::Lagged`] with the number of
+    /// bytes skipped. If the producer committed position is behind the consumer (eg/ the ring
+    /// buffer was reset), it skips to the most recently published record and returns a
+    /// [`DataError::Reset`].
+    pub fn try_recv(&mut self) -> Result<Option<&ArchivedEventRecord<'static>>, DataError> {
+        loop {
+            let epoch = self.region.atomic(EPOCH_OFFSET).load(Ordering::Acquire);
+            if epoch == CLOSED {
+                return Err(closed_error());
+            }
+            self.epoch = epoch;
+
+            let commit = self.region.atomic(COMMIT_OFFSET).load(Ordering::Acquire);
+            match commit.checked_sub(self.position) {
+                None => return Err(self.reset(commit)),
+                Some(0) => return Ok(None),
+                Some(unread) if unread > self.region.capacity => return Err(self.lagged(commit)),
+                Some(_) => {}
+            }
+
+            let offset = self.region.offset(self.position);
+
+            // Safety: the data region is mapped for the lifetime of the consumer, and any bytes
+            // overwritten by the producer during the copy are discarded by the claim check below.
+            let len = unsafe { read_header(self.region.data(offset)) };
+
+            if len == SKIP {
+                self.validate()?;
+                self.position += self.region.capacity - offset;
+                continue;
+            }
+
+            let len = len as usize;
+            if offset + frame_len(len) > self.region.capacity {
+                self.validate()?;
+                return Err(DataError::Record(
+                    "shared memory ring buffer frame is corrupt".to_owned(),
+                ));
+            }
+
+            self.buffer.clear();
+            // Safety: see above, the frame fits within the data region before the wrap.
+            unsafe {
+                let data =
+                    std::slice::from_raw_parts(self.region.data(offset).add(FRAME_HEADER), len);
+                self.buffer.extend_from_slice(data);
+            }
+            self.validate()?;
+
+            self.position += frame_len(len);
+            return access(&self.buffer).map(Some);
+        }
+    }
+
+    /// Validate the bytes just read from the current position were not claimed by the producer
+    /// for overwriting while they were read.
+    fn validate(&mut self) -> Result<(), DataError> {
+        fence(Ordering::Acquire);
+        let claim = self.region.atomic(CLAIM_OFFSET).load(Ordering::Relaxed);
+        match claim.checked_sub(self.position) {
+            Some(claimed) if claimed <= self.region.capacity => Ok(()),
+            Some(_) => {
+                let commit = self.region.atomic(COMMIT_OFFSET).load(Ordering::Acquire);
+                Err(self.lagged(commit))
+            }
+            None => {
+                let commit = self.region.atomic(COMMIT_OFFSET).load(Ordering::Acquire);
+                Err(self.reset(commit))
+            }
+        }
+    }
+
+    /// Skip to the provided commit position, returning the [`DataError::Lagged`] bytes skipped.
+    fn lagged(&mut self, commit: u64) -> DataError {
+        match commit.checked_sub(self.position) {
+            Some(skipped) => {
+                self.position = commit;
+                DataError::Lagged(skipped)
+            }
+            None => self.reset(commit),
+        }
+    }
+
+    /// Skip to the provided commit position behind the consumer, returning a
+    /// [`DataError::Reset`] with the current producer epoch.
+    fn reset(&mut self, commit: u64) -> DataError {
+        self.position = commit;
+        DataError::Reset(self.epoch)
+    }
+
+    /// Producer epoch last observed by the consumer, advanced each time a restarted
+    /// [`SharedMemoryProducer`] resumes the ring buffer.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+}
+
+/// Publish every [`MarketEvent<T>`] received to a [`SharedMemoryProducer`], until the event
+/// sender is dropped.
+pub async fn publish_shared_memory<T>(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    mut producer: SharedMemoryProducer,
+) where
+    T: RecordKind,
+{
+    while let Some(event) = event_rx.recv().await {
+        if let Err(error) = producer.publish(&event) {
+            warn!(%error, "failed to publish MarketEvent to shared memory");
+        }
+    }
+    debug!("MarketEvent sender dropped - stopping shared memory publishing");
+}
+
+/// Length of a frame containing a record of the provided length, padded to 8 bytes.
+fn frame_len(len: usize) -> u64 {
+    (FRAME_HEADER + len.div_ceil(8) * 8) as u64
+}
+
+/// Write a frame header containing the provided record length.
+///
+/// Safety: `data` must be valid for writing a [`FRAME_HEADER`].
+unsafe fn write_header(data: *mut u8, len: u32) {
+    let mut header = [0u8; FRAME_HEADER];
+    header[..4].copy_from_slice(&len.to_le_bytes());
+    std::ptr::copy_nonoverlapping(header.as_ptr(), data, FRAME_HEADER);
+}
+
+/// Read the record length of a frame header.
+///
+/// Safety: `data` must be valid for reading a [`FRAME_HEADER`].
+unsafe fn read_header(data: *const u8) -> u32 {
+    let mut len = [0u8; 4];
+    std::ptr::copy_nonoverlapping(data, len.as_mut_ptr(), len.len());
+    u32::from_le_bytes(len)
+}
+
+/// [`DataError`] for a ring buffer whose file was replaced by an incompatible producer.
+fn closed_error() -> DataError {
+    DataError::Record("shared memory ring buffer was replaced, re-open the file".to_owned())
+}
+
+/// [`DataError`] for a shared memory file I/O error.
+fn io_error(error: std::io::Error) -> DataError {
+    DataError::Record(format!("shared memory: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::DataKind,
+        fixture::trade_event,
+        subscription::trade::{PublicTrade, TradeId},
+    };
+
+    fn trade_id(record: &ArchivedEventRecord<'_>) -> Option<u64> {
+        match DataKind::from(&record.kind) {
+            DataKind::Trade(PublicTrade {
+                id: TradeId::U64(id),
+                ..
+            }) => Some(id),
+            _ => None,
+        }
+    }
+
+    fn path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("barter_data_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_shared_memory_wrap() {
+        let path = path("wrap");
+        let mut producer = SharedMemoryProducer::create(&path, 1024).unwrap();
+        let mut consumer = SharedMemoryConsumer::open(&path).unwrap();
+
+        // Many times the capacity, so every frame position around the wrap is exercised
+        for id in 0..200 {
+            producer.publish(&trade_event(id, 100.0, 1.0)).unwrap();
+            let record = consumer.try_recv().unwrap().unwrap();
+            assert_eq!(trade_id(record), Some(id));
+            assert!(consumer.try_recv().unwrap().is_none());
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_shared_memory_lagged() {
+        let path = path("lagged");
+        let mut producer = SharedMemoryProducer::create(&path, 1024).unwrap();
+        let mut consumer = SharedMemoryConsumer::open(&path).unwrap();
+
+        for id in 0..100 {
+            producer.publish(&trade_event(id, 100.0, 1.0)).unwrap();
+        }
+
+        // Consumer overrun by the producer skips to the latest record
+        assert!(matches!(consumer.try_recv(), Err(DataError::Lagged(_))));
+        assert!(consumer.try_recv().unwrap().is_none());
+
+        producer.publish(&trade_event(100, 100.0, 1.0)).unwrap();
+        let record = consumer.try_recv().unwrap().unwrap();
+        assert_eq!(trade_id(record), Some(100));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_shared_memory_producer_restart() {
+        let path = path("restart");
+        let mut producer = SharedMemoryProducer::create(&path, 1024).unwrap();
+        let mut consumer = SharedMemoryConsumer::open(&path).unwrap();
+
+        producer.publish(&trade_event(1, 100.0, 1.0)).unwrap();
+        producer.publish(&trade_event(2, 100.0, 1.0)).unwrap();
+        let record = consumer.try_recv().unwrap().unwrap();
+        assert_eq!(trade_id(record), Some(1));
+
+        // Restarted producer resumes the live ring buffer without truncating it, so the open
+        // consumer continues from where it left off
+        drop(producer);
+        let mut producer = SharedMemoryProducer::create(&path, 1024).unwrap();
+        producer.publish(&trade_event(3, 100.0, 1.0)).unwrap();
+
+        for id in [2, 3] {
+            let record = consumer.try_recv().unwrap().unwrap();
+            assert_eq!(trade_id(record), Some(id));
+        }
+        assert!(consumer.try_recv().unwrap().is_none());
+        assert_eq!(consumer.epoch(), 1);
+
+        // Producer committed position behind the consumer is treated as a reset
+        producer.position = 0;
+        producer
+            .region
+            .atomic(CLAIM_OFFSET)
+            .store(0, Ordering::Relaxed);
+        producer
+            .region
+            .atomic(COMMIT_OFFSET)
+            .store(0, Ordering::Release);
+        producer.publish(&trade_event(4, 100.0, 1.0)).unwrap();
+        assert!(matches!(consumer.try_recv(), Err(DataError::Reset(1))));
+        assert!(consumer.try_recv().unwrap().is_none());
+
+        producer.publish(&trade_event(5, 100.0, 1.0)).unwrap();
+        let record = consumer.try_recv().unwrap().unwrap();
+        assert_eq!(trade_id(record), Some(5));
+
+        // Producer restarted with a different capacity replaces the file, closing the old ring
+        // buffer for it's open consumers
+        drop(producer);
+        let mut producer = SharedMemoryProducer::create(&path, 2048).unwrap();
+        assert!(matches!(consumer.try_recv(), Err(DataError::Record(_))));
+
+        let mut consumer = SharedMemoryConsumer::open(&path).unwrap();
+        producer.publish(&trade_event(6, 100.0, 1.0)).unwrap();
+        let record = consumer.try_recv().unwrap().unwrap();
+        assert_eq!(trade_id(record), Some(6));
+        assert_eq!(consumer.epoch(), 0);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -3,6 +3,10 @@
     missing_copy_implementations,
     rust_2018_idioms
 )]
+// DataError is large because it embeds the Barter-Integration SocketError (136 bytes), which every
+// exchange stream surfaces as is. Errors are only constructed off the hot path, so they are
+// returned unboxed rather than boxing the SocketError at each of the many conversion sites.
+#![allow(clippy::result_large_err)]
 
 //! # Barter-Data
 //! A high-performance WebSocket integration library for streaming public market data from leading cryptocurrency
@@ -18,6 +22,10 @@
 //! - Pass [`Subscription`]s to the [`StreamBuilder::subscribe`](streams::builder::StreamBuilder::subscribe) method.
 //! - Each call to the [`StreamBuilder::subscribe`](streams::builder::StreamBuilder::subscribe)
 //!   method opens a new WebSocket connection to the exchange - giving you full control.
+//! - Use [`StreamBuilder::subscribe_with`](streams::builder::StreamBuilder::subscribe_with) to
+//!   provide a [`StreamConfig`](streams::config::StreamConfig) for the connection (eg/ a custom
+//!   WebSocket endpoint, or API `Credentials` for private account streams such as
+//!   [`UserTrades`](subscription::trade::UserTrades) via the `private` feature).
 //! - Call [`StreamBuilder::init`](streams::builder::StreamBuilder::init) to start streaming!
 //!
 //! ## Examples
@@ -35,7 +43,7 @@
 //!     streams::Streams,
 //!     subscription::trade::PublicTrades,
 //! };
-//! use barter_integration::model::InstrumentKind;
+//! use barter_integration::model::instrument::kind::InstrumentKind;
 //! use futures::StreamExt;
 //!
 //! #[tokio::main]
@@ -48,8 +56,8 @@
 //!             (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
 //!         ])
 //!         .subscribe([
-//!             (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
-//!             (BinanceFuturesUsd::default(), "eth", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+//!             (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
+//!             (BinanceFuturesUsd::default(), "eth", "usdt", InstrumentKind::Perpetual, PublicTrades),
 //!         ])
 //!         .subscribe([
 //!             (Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades),
@@ -62,8 +70,8 @@
 //!         .subscribe([
 //!             (Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades),
 //!             (Okx, "eth", "usdt", InstrumentKind::Spot, PublicTrades),
-//!             (Okx, "btc", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
-//!             (Okx, "eth", "usdt", InstrumentKind::FuturePerpetual, PublicTrades),
+//!             (Okx, "btc", "usdt", InstrumentKind::Perpetual, PublicTrades),
+//!             (Okx, "eth", "usdt", InstrumentKind::Perpetual, PublicTrades),
 //!        ])
 //!         .init()
 //!         .await
@@ -81,32 +89,131 @@
 //! }
 //! ```
 
+#[cfg(feature = "private")]
+use crate::{
+    credentials::KeepAlive, exchange::PrivateConnector,
+    subscriber::private::PrivateWebSocketSubscriber,
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
     exchange::{Connector, ExchangeId, PingInterval},
+    normalise::AssetNormaliser,
+    sbe::BinaryParser,
+    streams::{config::StreamConfig, tap::TappedWsStream},
     subscriber::Subscriber,
-    subscription::{SubKind, Subscription},
+    subscription::{Map, SubKind, Subscription},
     transformer::ExchangeTransformer,
+    transport::{BoxTransport, TransportSink},
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    model::instrument::Instrument,
+    protocol::{
+        websocket::{WebSocketParser, WsError, WsMessage},
+        StreamParser,
+    },
     ExchangeStream,
 };
 use futures::{SinkExt, Stream, StreamExt};
+use std::fmt::Debug;
+#[cfg(feature = "private")]
+use std::{
+    fmt::Formatter,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use tokio::sync::mpsc;
 use tracing::{debug, error};
 
+/// [`proptest`] strategies generating valid OrderBook delta sequences per exchange book
+/// protocol, and the OrderBook invariants they must uphold. Enabled for downstream tests via the
+/// `proptest` feature.
+#[cfg(any(test, feature = "proptest"))]
+pub mod arbitrary;
+
+/// C FFI that subscribes to [`Streams`](streams::Streams) & delivers each serialised event to a
+/// registered callback, so C, C++ & C# systems can embed Barter-Data. Enabled via the `capi`
+/// feature.
+#[cfg(feature = "capi")]
+pub mod capi;
+
+/// Exchange server [`ClockSync`](clock::ClockSync) used to estimate the offset of exchange
+/// server clocks from the local clock.
+pub mod clock;
+
+/// API [`Credentials`](credentials::Credentials) used to authenticate private account streams,
+/// exchange specific request signing, and session keep-alive tasks. Enabled via the `private`
+/// feature.
+#[cfg(feature = "private")]
+pub mod credentials;
+
+/// Memory compact [`DepthEngine`](depth::DepthEngine) that maintains the local
+/// [`OrderBook`](subscription::book::OrderBook)s of many exchange [`Instrument`]s using
+/// fixed-point levels derived from each [`InstrumentSpec`](spec::InstrumentSpec).
+pub mod depth;
+
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
 
+/// Perpetual [`FundingCalendar`](funding::FundingCalendar) of upcoming funding payment times &
+/// intervals, maintained from exchange metadata and funding rate streams.
+pub mod funding;
+
+/// Native FIX market data adapters (eg/ [`CoinbaseFixMd`](fix::coinbase::CoinbaseFixMd)) that
+/// normalise FIX market data into the same [`MarketEvent<T>`](event::MarketEvent)s. Enabled via
+/// the `fix` feature.
+#[cfg(feature = "fix")]
+pub mod fix;
+
 /// Defines the generic [`MarketEvent<T>`](event::MarketEvent) used in every [`MarketStream`].
 pub mod event;
 
+/// Shared [`MarketEvent<T>`](event::MarketEvent) & [`PublicTrade`](subscription::trade::PublicTrade)
+/// test fixtures.
+#[cfg(test)]
+pub(crate) mod fixture;
+
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// Lock-free shared memory ring buffer [`SharedMemoryProducer`](ipc::SharedMemoryProducer) &
+/// [`SharedMemoryConsumer`](ipc::SharedMemoryConsumer) of zero-copy records, so co-located
+/// processes can read market data without sockets. Enabled via the `shm` feature.
+#[cfg(feature = "shm")]
+pub mod ipc;
+
+/// [`AssetNormaliser`](normalise::AssetNormaliser) that normalises exchange specific asset codes
+/// (eg/ "XBT") & contract sizing so [`Instrument`]s are comparable across exchanges.
+pub mod normalise;
+
+/// Typed [`ExchangeNotice`](notice::ExchangeNotice) events parsed from exchange error & notice
+/// frames (eg/ rate limit warnings, maintenance notices) via each exchange's
+/// [`NoticeParser`](notice::NoticeParser).
+pub mod notice;
+
+/// [`BinaryParser`](sbe::BinaryParser) binary decoding pathway & SBE decoding primitives for
+/// exchanges that serve binary encoded market data.
+pub mod sbe;
+
+/// Protobuf [`MarketEvent`](proto::MarketEvent) types generated from
+/// `proto/market_event.proto`, and conversions to & from the normalised Barter models. Enabled
+/// via the `proto` feature.
+#[cfg(feature = "proto")]
+pub mod proto;
+
+/// Versioned & documented serde representation of a
+/// [`MarketEvent`](event::MarketEvent), and it's JSON Schema.
+pub mod schema;
+
+/// Top-N [`Instrument`] selection by rolling 24 hour volume, fetched via the exchange
+/// [`VolumeRanking`](ranking::VolumeRanking), used to build the subscriptions of a liquid universe.
+pub mod ranking;
+
+/// [`InstrumentSpec`](spec::InstrumentSpec) tick size, lot size & contract multiplier lookup of
+/// exchange [`Instrument`]s, fetched via the exchange [`InstrumentSpecs`](spec::InstrumentSpecs).
+pub mod spec;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
@@ -134,9 +241,32 @@ pub mod subscription;
 ///   [`OrderBooksL3`](crate::subscription::book::OrderBooksL3) streams.
 pub mod transformer;
 
-/// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
-/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+/// In-memory [`MockExchange`](testing::MockExchange) server with scripted fault injection (eg/
+/// half-open connections, delayed pongs, duplicate & partial frames) for deterministic
+/// resiliency testing. Enabled for downstream tests via the `testing` feature.
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+/// Pluggable [`Transport`](transport::Transport) abstraction over the WebSocket client used to
+/// connect to exchanges, defaulting to tokio-tungstenite.
+pub mod transport;
+
+/// Zero-copy [`rkyv`] [`EventRecord`](zero_copy::EventRecord)s of
+/// [`MarketEvent<T>`](event::MarketEvent)s for recording to shared memory or disk without per-event
+/// serde allocation. Enabled via the `rkyv` feature.
+#[cfg(feature = "rkyv")]
+pub mod zero_copy;
+
+/// Convenient type alias for an [`ExchangeStream`] utilising a WebSocket
+/// [`Transport`](transport::Transport), optionally tapped by a [`RawTap`](streams::tap::RawTap).
+pub type ExchangeWsStream<Transformer> =
+    ExchangeStream<WebSocketParser, TappedWsStream, Transformer>;
+
+/// Convenient type alias for an [`ExchangeStream`] utilising a WebSocket
+/// [`Transport`](transport::Transport) that serves binary encoded (eg/ SBE) market data, decoded
+/// by the [`BinaryParser`](sbe::BinaryParser).
+pub type ExchangeBinaryStream<Transformer> =
+    ExchangeStream<BinaryParser, TappedWsStream, Transformer>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -152,65 +282,298 @@ where
     Exchange: Connector,
     Kind: SubKind,
 {
-    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &StreamConfig,
+    ) -> Result<Self, DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
 }
 
 #[async_trait]
-impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind> for ExchangeWsStream<Transformer>
+impl<Exchange, Kind, Parser, Transformer> MarketStream<Exchange, Kind>
+    for ExchangeStream<Parser, TappedWsStream, Transformer>
 where
+    Parser: StreamParser<Message = WsMessage, Error = WsError> + Send,
     Exchange: Connector + Send + Sync,
     Kind: SubKind + Send + Sync,
     Transformer: ExchangeTransformer<Exchange, Kind> + Send,
     Kind::Event: Send,
 {
-    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &StreamConfig,
+    ) -> Result<Self, DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
         // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions, config).await?;
+
+        init_exchange_ws_stream::<Exchange, Kind, Parser, Transformer>(
+            websocket,
+            map,
+            subscriptions,
+            config,
+        )
+        .await
+    }
+}
+
+/// [`ExchangeWsStream`] connected to an exchange private WebSocket server, authenticated with
+/// the [`Credentials`](credentials::Credentials) provided in the [`StreamConfig`].
+///
+/// Used by [`StreamSelector`](exchange::StreamSelector)s of private account [`SubKind`]s (eg/
+/// [`UserTrades`](subscription::trade::UserTrades)).
+///
+/// Owns the optional session [`KeepAlive`] task, which is aborted when the stream is dropped.
+/// Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub struct PrivateWsStream<StreamTransformer>
+where
+    StreamTransformer: barter_integration::Transformer,
+{
+    pub stream: ExchangeWsStream<StreamTransformer>,
+    pub keep_alive: Option<KeepAlive>,
+}
+
+#[cfg(feature = "private")]
+impl<StreamTransformer> Debug for PrivateWsStream<StreamTransformer>
+where
+    StreamTransformer: barter_integration::Transformer,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrivateWsStream")
+            .field("keep_alive", &self.keep_alive)
+            .finish_non_exhaustive()
+    }
+}
 
-        // Split WebSocket into WsStream & WsSink components
-        let (ws_sink, ws_stream) = websocket.split();
+#[cfg(feature = "private")]
+impl<StreamTransformer> Stream for PrivateWsStream<StreamTransformer>
+where
+    StreamTransformer: barter_integration::Transformer<Error = DataError>,
+{
+    type Item = Result<StreamTransformer::Output, DataError>;
 
-        // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
-        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
-        tokio::spawn(distribute_messages_to_exchange(
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Yield a terminal DataError once the session expires, so the stream is re-initialised
+        // with a new session
+        if let Some(keep_alive) = self.keep_alive.as_mut() {
+            if let Poll::Ready(exchange) = keep_alive.poll_expired(cx) {
+                self.keep_alive = None;
+                return Poll::Ready(Some(Err(DataError::SessionExpired(exchange))));
+            }
+        }
+
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(feature = "private")]
+#[async_trait]
+impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind> for PrivateWsStream<Transformer>
+where
+    Exchange: PrivateConnector + Send + Sync,
+    Kind: SubKind + Send + Sync,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send,
+    Kind::Event: Send,
+{
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &StreamConfig,
+    ) -> Result<Self, DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Authenticate, connect & subscribe
+        let (websocket, map, keep_alive) =
+            PrivateWebSocketSubscriber::subscribe(subscriptions, config).await?;
+
+        init_exchange_ws_stream::<Exchange, Kind, WebSocketParser, Transformer>(
+            websocket,
+            map,
+            subscriptions,
+            config,
+        )
+        .await
+        .map(|stream| Self { stream, keep_alive })
+    }
+}
+
+/// Determine the subscribed [`Instrument`]s that require an initial snapshot to be yielded before
+/// any delta updates (see [`SubKind::initial_snapshot`]), normalised by the [`AssetNormaliser`].
+pub(crate) fn initial_snapshots<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    normaliser: &AssetNormaliser,
+) -> Vec<Instrument>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    subscribed_instruments(subscriptions, normaliser, SubKind::initial_snapshot)
+}
+
+/// Determine the subscribed [`Instrument`]s that require only the delta of each update to be
+/// yielded (see [`SubKind::deltas`]), normalised by the [`AssetNormaliser`].
+pub(crate) fn delta_instruments<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    normaliser: &AssetNormaliser,
+) -> Vec<Instrument>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    subscribed_instruments(subscriptions, normaliser, SubKind::deltas)
+}
+
+/// Determine the subscribed [`Instrument`]s with a [`SubKind`] satisfying the `filter`,
+/// normalised by the [`AssetNormaliser`].
+fn subscribed_instruments<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    normaliser: &AssetNormaliser,
+    filter: fn(&Kind) -> bool,
+) -> Vec<Instrument>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+{
+    subscriptions
+        .iter()
+        .filter(|subscription| filter(&subscription.kind))
+        .map(|subscription| normaliser.normalise_instrument(Exchange::ID, &subscription.instrument))
+        .collect()
+}
+
+/// Initialise an [`ExchangeWsStream`] from a subscribed [`BoxTransport`], spawning the tasks that
+/// distribute outbound messages & custom application-level pings to the exchange.
+///
+/// Any [`ExchangeTransformer::initial_events`] of the [`Instrument`]s that require an initial
+/// snapshot are yielded before the first exchange message.
+async fn init_exchange_ws_stream<Exchange, Kind, Parser, Transformer>(
+    websocket: BoxTransport,
+    map: Map<Instrument>,
+    subscriptions: &[Subscription<Exchange, Kind>],
+    config: &StreamConfig,
+) -> Result<ExchangeStream<Parser, TappedWsStream, Transformer>, DataError>
+where
+    Parser: StreamParser,
+    Exchange: Connector,
+    Kind: SubKind,
+    Transformer: ExchangeTransformer<Exchange, Kind>,
+{
+    // Split Transport into TransportStream & TransportSink components
+    let (ws_sink, ws_stream) = websocket.split();
+
+    // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
+    let (outbound_tx, outbound) = OutboundQueue::new();
+    tokio::spawn(distribute_messages_to_exchange(
+        Exchange::ID,
+        ws_sink,
+        outbound,
+    ));
+
+    // Spawn optional task to distribute custom application-level pings to the exchange
+    if let Some(ping_interval) = Exchange::ping_interval() {
+        tokio::spawn(schedule_pings_to_exchange(
             Exchange::ID,
-            ws_sink,
-            ws_sink_rx,
+            outbound_tx.control.clone(),
+            ping_interval,
         ));
+    }
 
-        // Spawn optional task to distribute custom application-level pings to the exchange
-        if let Some(ping_interval) = Exchange::ping_interval() {
-            tokio::spawn(schedule_pings_to_exchange(
-                Exchange::ID,
-                ws_sink_tx.clone(),
-                ping_interval,
-            ));
-        }
+    // Register the connection with the BandwidthMeter if configured
+    let meter = config
+        .bandwidth_meter
+        .as_ref()
+        .map(|meter| meter.register(Exchange::ID, &map));
 
-        // Construct Transformer associated with this Exchange and SubKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+    // Construct Transformer associated with this Exchange and SubKind
+    let mut transformer = Transformer::new(outbound_tx, map).await?;
+    transformer.yield_deltas(&delta_instruments(subscriptions, &config.normaliser));
+    let initial_events =
+        transformer.initial_events(&initial_snapshots(subscriptions, &config.normaliser));
+
+    // Tap the WsStream if a RawTap is configured
+    let ws_stream = TappedWsStream::new(Exchange::ID, ws_stream, config.raw_tap.clone())
+        .with_meter(meter)
+        .with_limits(config.frame_limits);
+
+    let mut stream = ExchangeStream::new(ws_stream, transformer);
+    stream.buffer.extend(initial_events);
+    Ok(stream)
+}
+
+/// Transmitters for the per-connection [`OutboundQueue`], provided to every
+/// [`ExchangeTransformer`].
+#[derive(Clone, Debug)]
+pub struct OutboundTx {
+    /// Transmits high priority control [`WsMessage`]s (eg/ OrderBook resync unsubscribe &
+    /// resubscribe, custom application-level pings).
+    pub control: mpsc::UnboundedSender<WsMessage>,
+    /// Transmits bulk [`WsMessage`]s (eg/ [`ExchangeTransformer`] pongs).
+    pub bulk: mpsc::UnboundedSender<WsMessage>,
+}
+
+/// Per-connection outbound [`WsMessage`] queue that prioritises control messages over bulk
+/// messages, so control messages are never blocked behind a backlog of bulk messages (eg/ when
+/// re-subscribing to a diverged OrderBook whilst a backlog of bulk messages is still being sent).
+#[derive(Debug)]
+pub struct OutboundQueue {
+    pub control_rx: mpsc::UnboundedReceiver<WsMessage>,
+    pub bulk_rx: mpsc::UnboundedReceiver<WsMessage>,
+}
+
+impl OutboundQueue {
+    /// Construct a new [`Self`], along with it's [`OutboundTx`] transmitters.
+    pub fn new() -> (OutboundTx, Self) {
+        let (control, control_rx) = mpsc::unbounded_channel();
+        let (bulk, bulk_rx) = mpsc::unbounded_channel();
+        (
+            OutboundTx { control, bulk },
+            Self {
+                control_rx,
+                bulk_rx,
+            },
+        )
+    }
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+    /// Receive the next outbound [`WsMessage`], always preferring queued control messages.
+    ///
+    /// Returns `None` once every control & bulk [`OutboundTx`] transmitter has been dropped (ie/
+    /// neither the [`ExchangeTransformer`] nor the ping task exist).
+    pub async fn recv(&mut self) -> Option<WsMessage> {
+        let mut control_open = true;
+        let mut bulk_open = true;
+        loop {
+            tokio::select! {
+                biased;
+                message = self.control_rx.recv(), if control_open => match message {
+                    Some(message) => break Some(message),
+                    None => control_open = false,
+                },
+                message = self.bulk_rx.recv(), if bulk_open => match message {
+                    Some(message) => break Some(message),
+                    None => bulk_open = false,
+                },
+                else => break None,
+            }
+        }
     }
 }
 
-/// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`] to the exchange via
-/// the [`WsSink`].
+/// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`] (and any control messages) to
+/// the exchange via the [`TransportSink`], prioritised by the [`OutboundQueue`].
 ///
 /// **Note:**
 /// ExchangeTransformer is operating in a synchronous trait context so we use this separate task
 /// to avoid adding `#[\async_trait\]` to the transformer - this avoids allocations.
 pub async fn distribute_messages_to_exchange(
     exchange: ExchangeId,
-    mut ws_sink: WsSink,
-    mut ws_sink_rx: mpsc::UnboundedReceiver<WsMessage>,
+    mut ws_sink: TransportSink,
+    mut outbound: OutboundQueue,
 ) {
-    while let Some(message) = ws_sink_rx.recv().await {
+    while let Some(message) = outbound.recv().await {
         if let Err(error) = ws_sink.send(message).await {
             if barter_integration::protocol::websocket::is_websocket_disconnected(&error) {
                 break;
@@ -220,7 +583,7 @@ pub async fn distribute_messages_to_exchange(
             error!(
                 %exchange,
                 %error,
-                "failed to send  output message to the exchange via WsSink"
+                "failed to send  output message to the exchange via TransportSink"
             );
         }
     }
@@ -234,7 +597,7 @@ pub async fn distribute_messages_to_exchange(
 ///  - This is additional to the protocol-level pings already handled by `tokio_tungstenite`.
 pub async fn schedule_pings_to_exchange(
     exchange: ExchangeId,
-    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    control_tx: mpsc::UnboundedSender<WsMessage>,
     PingInterval { mut interval, ping }: PingInterval,
 ) {
     loop {
@@ -245,8 +608,61 @@ pub async fn schedule_pings_to_exchange(
         let payload = ping();
         debug!(%exchange, %payload, "sending custom application-level ping to exchange");
 
-        if ws_sink_tx.send(payload).is_err() {
+        if control_tx.send(payload).is_err() {
             break;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_outbound_queue_prioritises_control_messages() {
+        let (OutboundTx { control, bulk }, mut outbound) = OutboundQueue::new();
+
+        bulk.send(WsMessage::Text("subscribe_0".to_owned()))
+            .unwrap();
+        bulk.send(WsMessage::Text("subscribe_1".to_owned()))
+            .unwrap();
+        control
+            .send(WsMessage::Text("unsubscribe".to_owned()))
+            .unwrap();
+        drop(control);
+
+        assert_eq!(
+            outbound.recv().await,
+            Some(WsMessage::Text("unsubscribe".to_owned()))
+        );
+        assert_eq!(
+            outbound.recv().await,
+            Some(WsMessage::Text("subscribe_0".to_owned()))
+        );
+        assert_eq!(
+            outbound.recv().await,
+            Some(WsMessage::Text("subscribe_1".to_owned()))
+        );
+
+        // Queue ends once every transmitter of both lanes is dropped
+        drop(bulk);
+        assert_eq!(outbound.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_outbound_queue_outlives_dropped_bulk_transmitter() {
+        let (OutboundTx { control, bulk }, mut outbound) = OutboundQueue::new();
+
+        // Transformers that never send bulk messages drop their transmitter, but pings are still
+        // distributed via the control lane
+        drop(bulk);
+        control.send(WsMessage::Text("ping".to_owned())).unwrap();
+        assert_eq!(
+            outbound.recv().await,
+            Some(WsMessage::Text("ping".to_owned()))
+        );
+
+        drop(control);
+        assert_eq!(outbound.recv().await, None);
+    }
+}
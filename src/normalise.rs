@@ -0,0 +1,355 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    exchange::{Connector, ExchangeId},
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        liquidation::Liquidation,
+        trade::PublicTrade,
+        Subscription,
+    },
+};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange,
+};
+use std::collections::HashMap;
+
+/// Built-in exchange asset codes that differ from the Barter asset code, as
+/// `(exchange, exchange asset code, Barter asset code)`.
+pub const ASSET_ALIASES: &[(ExchangeId, &str, &str)] = &[
+    (ExchangeId::Bitfinex, "ust", "usdt"),
+    (ExchangeId::Bitmex, "xbt", "btc"),
+    (ExchangeId::Kraken, "xbt", "btc"),
+    (ExchangeId::Kraken, "xdg", "doge"),
+];
+
+/// Normalises exchange specific asset codes (eg/ Kraken "XBT") & contract sizing so
+/// [`Instrument`]s and amounts are comparable across exchanges.
+///
+/// Applied by the [`StreamConfig`](crate::streams::config::StreamConfig) of every stream during
+/// subscription mapping: [`Subscription`]s using either the Barter or the exchange asset code are
+/// subscribed to using the exchange asset code, and every [`MarketEvent<T>`] is emitted with the
+/// normalised Barter [`Instrument`].
+///
+/// Contract multipliers (eg/ an [`Instrument`] contract being worth 0.01 BTC) are not applied by
+/// streams, since most [`SubKind`](crate::subscription::SubKind) outputs have no amount. Apply
+/// them to [`ContractSized`] events via [`AssetNormaliser::normalise_event`].
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::{exchange::ExchangeId, normalise::AssetNormaliser};
+/// use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+///
+/// let normaliser = AssetNormaliser::default()
+///     .with_alias(ExchangeId::BinanceSpot, "bchabc", "bch")
+///     .with_contract_multiplier(
+///         ExchangeId::Okx,
+///         Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+///         0.01,
+///     );
+///
+/// assert_eq!(
+///     normaliser.normalise_instrument(
+///         ExchangeId::Kraken,
+///         &Instrument::from(("xbt", "usd", InstrumentKind::Spot))
+///     ),
+///     Instrument::from(("btc", "usd", InstrumentKind::Spot))
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct AssetNormaliser {
+    /// Exchange asset code to Barter asset code.
+    to_barter: HashMap<(ExchangeId, Symbol), Symbol>,
+    /// Barter asset code to exchange asset code.
+    to_exchange: HashMap<(ExchangeId, Symbol), Symbol>,
+    /// Contract multiplier of each normalised exchange [`Instrument`].
+    contract_multipliers: HashMap<(Exchange, Instrument), f64>,
+}
+
+impl Eq for AssetNormaliser {}
+
+impl Default for AssetNormaliser {
+    fn default() -> Self {
+        ASSET_ALIASES.iter().fold(
+            Self::empty(),
+            |normaliser, (exchange, exchange_code, barter_code)| {
+                normaliser.with_alias(*exchange, *exchange_code, *barter_code)
+            },
+        )
+    }
+}
+
+impl AssetNormaliser {
+    /// Construct a new [`Self`] without the built-in [`ASSET_ALIASES`].
+    pub fn empty() -> Self {
+        Self {
+            to_barter: HashMap::new(),
+            to_exchange: HashMap::new(),
+            contract_multipliers: HashMap::new(),
+        }
+    }
+
+    /// Add (or override) an exchange asset code alias for the provided Barter asset code.
+    pub fn with_alias<S>(mut self, exchange: ExchangeId, exchange_code: S, barter_code: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        let exchange_code = exchange_code.into();
+        let barter_code = barter_code.into();
+        self.to_barter
+            .insert((exchange, exchange_code.clone()), barter_code.clone());
+        self.to_exchange
+            .insert((exchange, barter_code), exchange_code);
+        self
+    }
+
+    /// Add (or override) the contract multiplier of an exchange [`Instrument`], ie/ the amount
+    /// of the base asset one contract is worth.
+    pub fn with_contract_multiplier<I>(
+        mut self,
+        exchange: ExchangeId,
+        instrument: I,
+        multiplier: f64,
+    ) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        let instrument = self.normalise_instrument(exchange, &instrument.into());
+        self.contract_multipliers
+            .insert((Exchange::from(exchange), instrument), multiplier);
+        self
+    }
+
+    /// Normalise an exchange asset code to the Barter asset code.
+    pub fn normalise_symbol(&self, exchange: ExchangeId, symbol: &Symbol) -> Symbol {
+        self.to_barter
+            .get(&(exchange, symbol.clone()))
+            .unwrap_or(symbol)
+            .clone()
+    }
+
+    /// Translate a Barter asset code to the exchange asset code.
+    pub fn exchange_symbol(&self, exchange: ExchangeId, symbol: &Symbol) -> Symbol {
+        self.to_exchange
+            .get(&(exchange, symbol.clone()))
+            .unwrap_or(symbol)
+            .clone()
+    }
+
+    /// Normalise the asset codes of an exchange [`Instrument`].
+    pub fn normalise_instrument(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+    ) -> Instrument {
+        Instrument {
+            base: self.normalise_symbol(exchange, &instrument.base),
+            quote: self.normalise_symbol(exchange, &instrument.quote),
+            kind: instrument.kind,
+        }
+    }
+
+    /// Translate the asset codes of an [`Instrument`] to the exchange asset codes.
+    pub fn exchange_instrument(&self, exchange: ExchangeId, instrument: &Instrument) -> Instrument {
+        let instrument = self.normalise_instrument(exchange, instrument);
+        Instrument {
+            base: self.exchange_symbol(exchange, &instrument.base),
+            quote: self.exchange_symbol(exchange, &instrument.quote),
+            kind: instrument.kind,
+        }
+    }
+
+    /// Translate a [`Subscription`] to use the exchange asset codes, as required to determine
+    /// the exchange market it subscribes to.
+    pub fn exchange_subscription<Exchange, Kind>(
+        &self,
+        subscription: &Subscription<Exchange, Kind>,
+    ) -> Subscription<Exchange, Kind>
+    where
+        Exchange: Connector,
+        Kind: Clone,
+    {
+        Subscription {
+            exchange: subscription.exchange.clone(),
+            instrument: self.exchange_instrument(Exchange::ID, &subscription.instrument),
+            kind: subscription.kind.clone(),
+        }
+    }
+
+    /// Contract multiplier of a normalised exchange [`Instrument`], if configured.
+    pub fn contract_multiplier(&self, exchange: &Exchange, instrument: &Instrument) -> Option<f64> {
+        self.contract_multipliers
+            .get(&(exchange.clone(), instrument.clone()))
+            .copied()
+    }
+
+    /// Scale the amounts of a [`MarketEvent<T>`] from contracts to the base asset using the
+    /// contract multiplier of it's exchange [`Instrument`], if configured.
+    pub fn normalise_event<T>(&self, event: &mut MarketEvent<T>)
+    where
+        T: ContractSized,
+    {
+        if let Some(multiplier) = self.contract_multiplier(&event.exchange, &event.instrument) {
+            event.kind.scale_amounts(multiplier);
+        }
+    }
+}
+
+/// [`MarketEvent<T>`] kind with amounts denominated in exchange contracts, which can be scaled to
+/// the base asset by an [`AssetNormaliser`].
+pub trait ContractSized {
+    /// Multiply every amount by the provided contract multiplier.
+    fn scale_amounts(&mut self, multiplier: f64);
+}
+
+impl ContractSized for PublicTrade {
+    fn scale_amounts(&mut self, multiplier: f64) {
+        self.amount *= multiplier;
+    }
+}
+
+impl ContractSized for OrderBookL1 {
+    fn scale_amounts(&mut self, multiplier: f64) {
+        self.best_bid.amount *= multiplier;
+        self.best_ask.amount *= multiplier;
+    }
+}
+
+impl ContractSized for OrderBook {
+    fn scale_amounts(&mut self, multiplier: f64) {
+        self.bids.scale_amounts(multiplier);
+        self.asks.scale_amounts(multiplier);
+    }
+}
+
+impl ContractSized for Liquidation {
+    fn scale_amounts(&mut self, multiplier: f64) {
+        self.quantity *= multiplier;
+    }
+}
+
+impl ContractSized for DataKind {
+    fn scale_amounts(&mut self, multiplier: f64) {
+        match self {
+            DataKind::Trade(trade) => trade.scale_amounts(multiplier),
+            DataKind::OrderBookL1(book) => book.scale_amounts(multiplier),
+            DataKind::OrderBook(book) => book.scale_amounts(multiplier),
+            DataKind::Liquidation(liquidation) => liquidation.scale_amounts(multiplier),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixture::trade_event, subscription::trade::TradeId};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_asset_normaliser_instruments() {
+        let normaliser =
+            AssetNormaliser::default().with_alias(ExchangeId::BinanceSpot, "bchabc", "bch");
+
+        struct TestCase {
+            exchange: ExchangeId,
+            input: Instrument,
+            expected_normalised: Instrument,
+            expected_exchange: Instrument,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: built-in Kraken alias from the exchange asset code
+                exchange: ExchangeId::Kraken,
+                input: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+                expected_normalised: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                expected_exchange: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+            },
+            TestCase {
+                // TC1: built-in Kraken alias from the Barter asset code
+                exchange: ExchangeId::Kraken,
+                input: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                expected_normalised: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                expected_exchange: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+            },
+            TestCase {
+                // TC2: built-in alias of the quote asset
+                exchange: ExchangeId::Bitfinex,
+                input: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                expected_normalised: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                expected_exchange: Instrument::from(("eth", "ust", InstrumentKind::Spot)),
+            },
+            TestCase {
+                // TC3: user alias
+                exchange: ExchangeId::BinanceSpot,
+                input: Instrument::from(("bchabc", "usdt", InstrumentKind::Spot)),
+                expected_normalised: Instrument::from(("bch", "usdt", InstrumentKind::Spot)),
+                expected_exchange: Instrument::from(("bchabc", "usdt", InstrumentKind::Spot)),
+            },
+            TestCase {
+                // TC4: aliases only apply to their exchange
+                exchange: ExchangeId::Coinbase,
+                input: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+                expected_normalised: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+                expected_exchange: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                normaliser.normalise_instrument(test.exchange, &test.input),
+                test.expected_normalised,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                normaliser.exchange_instrument(test.exchange, &test.input),
+                test.expected_exchange,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_asset_normaliser_exchange_subscription() {
+        use crate::{
+            exchange::kraken::{market::KrakenMarket, Kraken},
+            subscription::trade::PublicTrades,
+            Identifier,
+        };
+
+        let subscription =
+            Subscription::new(Kraken, ("btc", "usd", InstrumentKind::Spot), PublicTrades);
+        let actual: KrakenMarket = AssetNormaliser::default()
+            .exchange_subscription(&subscription)
+            .id();
+        assert_eq!(actual, KrakenMarket("XBT/USD".to_owned()));
+    }
+
+    #[test]
+    fn test_asset_normaliser_normalise_event() {
+        let normaliser = AssetNormaliser::default().with_contract_multiplier(
+            ExchangeId::Okx,
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            0.01,
+        );
+
+        let trade = |exchange: ExchangeId| MarketEvent {
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            ..trade_event(TradeId::None, 20000.0, 150.0)
+        };
+
+        // Contract multiplier is applied to it's exchange Instrument
+        let mut event = trade(ExchangeId::Okx);
+        normaliser.normalise_event(&mut event);
+        assert_eq!(event.kind.amount, 1.5);
+
+        // Instruments without a contract multiplier are unchanged
+        let mut event = trade(ExchangeId::BinanceFuturesUsd);
+        normaliser.normalise_event(&mut event);
+        assert_eq!(event.kind.amount, 150.0);
+    }
+}
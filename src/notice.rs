@@ -0,0 +1,201 @@
+use crate::{
+    exchange::{
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot, us::BinanceUs},
+        bitfinex::Bitfinex,
+        bitmex::Bitmex,
+        bybit::{futures::BybitFuturesUsd, option::BybitOptions, spot::BybitSpot},
+        coinbase::Coinbase,
+        deribit::Deribit,
+        gateio::{
+            futures::{GateioFuturesBtc, GateioFuturesUsd},
+            spot::GateioSpot,
+        },
+        kraken::Kraken,
+        okx::Okx,
+        Connector, ExchangeId,
+    },
+    streams::tap::{RawMessage, RawPayload},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc};
+use tracing::{debug, warn};
+
+/// Normalised Barter [`ExchangeNotice`] parsed from an exchange error or notice frame (eg/ rate
+/// limit warnings, invalid arguments, maintenance notices), which would otherwise be discarded.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ExchangeNotice {
+    pub exchange: ExchangeId,
+    pub received_time: DateTime<Utc>,
+    pub kind: NoticeKind,
+    /// Exchange specific error or notice code, if provided.
+    pub code: Option<String>,
+    /// Exchange provided description of the notice.
+    pub message: String,
+}
+
+/// [`ExchangeNotice`] fields parsed by an exchange [`NoticeParser`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Notice {
+    pub kind: NoticeKind,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl Notice {
+    /// Construct a new [`Self`] from the provided [`NoticeKind`], optional code & message.
+    pub fn new<Code, Message>(kind: NoticeKind, code: Option<Code>, message: Message) -> Self
+    where
+        Code: ToString,
+        Message: Into<String>,
+    {
+        Self {
+            kind,
+            code: code.map(|code| code.to_string()),
+            message: message.into(),
+        }
+    }
+}
+
+/// Normalised Barter kind of [`ExchangeNotice`], indicating how an application could react.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoticeKind {
+    /// Request rate limit exceeded or approaching - slow down.
+    RateLimit,
+    /// Request rejected due to invalid arguments (eg/ unknown market) - fix & resubscribe.
+    InvalidRequest,
+    /// Authentication rejected - check the credentials.
+    Unauthorised,
+    /// Exchange maintenance in progress or scheduled - pause trading.
+    Maintenance,
+    /// Exchange server is restarting - expect a disconnection & resubscribe.
+    Reconnect,
+    /// Exchange messages could not be deserialised (eg/ after a schema change) - check the feed.
+    ParseError,
+    /// Too many exchange messages could not be deserialised, so a
+    /// [`CircuitBreaker`](crate::streams::breaker::CircuitBreaker) tripped.
+    CircuitBreaker,
+    /// Any other error or notice.
+    Other,
+}
+
+/// Exchange [`Connector`] that sends error & notice frames over it's WebSocket, which can be
+/// parsed into [`Notice`]s.
+pub trait NoticeParser
+where
+    Self: Connector,
+{
+    /// Parse a [`Notice`] from a raw text frame, returning `None` for every other frame.
+    fn parse_notice(payload: &str) -> Option<Notice>;
+}
+
+/// Parse a [`Notice`] from a raw text frame received from the provided exchange using it's
+/// [`NoticeParser`].
+pub fn parse_notice(exchange: ExchangeId, payload: &str) -> Option<Notice> {
+    match exchange {
+        ExchangeId::BinanceFuturesUsd => BinanceFuturesUsd::parse_notice(payload),
+        ExchangeId::BinanceSpot => BinanceSpot::parse_notice(payload),
+        ExchangeId::BinanceUs => BinanceUs::parse_notice(payload),
+        ExchangeId::Bitfinex => Bitfinex::parse_notice(payload),
+        ExchangeId::Bitmex => Bitmex::parse_notice(payload),
+        ExchangeId::BybitSpot => BybitSpot::parse_notice(payload),
+        ExchangeId::BybitFuturesUsd => BybitFuturesUsd::parse_notice(payload),
+        ExchangeId::BybitOptions => BybitOptions::parse_notice(payload),
+        ExchangeId::Coinbase => Coinbase::parse_notice(payload),
+        ExchangeId::Deribit => Deribit::parse_notice(payload),
+        ExchangeId::GateioFuturesBtc => GateioFuturesBtc::parse_notice(payload),
+        ExchangeId::GateioFuturesUsd => GateioFuturesUsd::parse_notice(payload),
+        ExchangeId::GateioSpot => GateioSpot::parse_notice(payload),
+        ExchangeId::Kraken => Kraken::parse_notice(payload),
+        ExchangeId::Okx => Okx::parse_notice(payload),
+    }
+}
+
+/// Parse every [`RawMessage`] text frame received from a [`RawTap`](crate::streams::tap::RawTap)
+/// into an [`ExchangeNotice`] event stream, sending each to the `notice_tx`.
+///
+/// Runs until the [`RawTap`](crate::streams::tap::RawTap) or the `notice_tx` receiver has been
+/// dropped.
+pub async fn exchange_notices(
+    mut raw_rx: broadcast::Receiver<RawMessage>,
+    notice_tx: mpsc::UnboundedSender<ExchangeNotice>,
+) {
+    loop {
+        let raw = match raw_rx.recv().await {
+            Ok(raw) => raw,
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                warn!(
+                    missed,
+                    "ExchangeNotice parser lagged - skipped raw messages"
+                );
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        let RawPayload::Text(payload) = &raw.payload else {
+            continue;
+        };
+
+        let Some(Notice {
+            kind,
+            code,
+            message,
+        }) = parse_notice(raw.exchange, payload)
+        else {
+            continue;
+        };
+
+        let notice = ExchangeNotice {
+            exchange: raw.exchange,
+            received_time: raw.received_time,
+            kind,
+            code,
+            message,
+        };
+
+        if notice_tx.send(notice).is_err() {
+            debug!("ExchangeNotice receiver dropped - stopping ExchangeNotice parsing");
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::tap::RawTap;
+    use barter_integration::protocol::websocket::WsMessage;
+
+    #[tokio::test]
+    async fn test_exchange_notices() {
+        let tap = RawTap::new(16);
+        let (notice_tx, mut notice_rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(exchange_notices(tap.subscribe(), notice_tx));
+
+        // Ordinary data frames are not notices
+        tap.send(
+            ExchangeId::Okx,
+            &WsMessage::Text(
+                r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[]}"#.to_owned(),
+            ),
+        );
+        tap.send(
+            ExchangeId::Okx,
+            &WsMessage::Text(
+                r#"{"event":"error","code":"60014","msg":"Requests too frequent."}"#.to_owned(),
+            ),
+        );
+        drop(tap);
+
+        let notice = notice_rx.recv().await.unwrap();
+        assert_eq!(notice.exchange, ExchangeId::Okx);
+        assert_eq!(notice.kind, NoticeKind::RateLimit);
+        assert_eq!(notice.code.as_deref(), Some("60014"));
+        assert_eq!(notice.message, "Requests too frequent.");
+
+        handle.await.unwrap();
+        assert!(notice_rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,384 @@
+// Prost types for `proto/market_event.proto`, maintained by hand in the format prost-build emits,
+// so the `proto` feature does not require protoc. Keep every message, field & tag in sync with the
+// `.proto` definitions whenever either changes.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketEvent {
+    #[prost(message, optional, tag = "1")]
+    pub exchange_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub received_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Exchange identifier (eg/ "binance_spot").
+    #[prost(string, tag = "3")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub instrument: ::core::option::Option<Instrument>,
+    /// Unset for events encoded before the origin was added, which are all live.
+    #[prost(enumeration = "Origin", tag = "5")]
+    pub origin: i32,
+    #[prost(oneof = "market_event::Kind", tags = "10, 11, 12, 13, 14, 15, 16, 17")]
+    pub kind: ::core::option::Option<market_event::Kind>,
+}
+/// Nested message and enum types in `MarketEvent`.
+pub mod market_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "10")]
+        Trade(super::PublicTrade),
+        #[prost(message, tag = "11")]
+        OrderBookL1(super::OrderBookL1),
+        #[prost(message, tag = "12")]
+        OrderBook(super::OrderBook),
+        #[prost(message, tag = "13")]
+        Candle(super::Candle),
+        #[prost(message, tag = "14")]
+        Liquidation(super::Liquidation),
+        #[prost(message, tag = "15")]
+        Greeks(super::Greeks),
+        #[prost(message, tag = "16")]
+        Ticker(super::Ticker),
+        #[prost(message, tag = "17")]
+        MiniTicker(super::MiniTicker),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Instrument {
+    #[prost(string, tag = "1")]
+    pub base: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub quote: ::prost::alloc::string::String,
+    #[prost(oneof = "instrument::Kind", tags = "3, 4, 5, 6")]
+    pub kind: ::core::option::Option<instrument::Kind>,
+}
+/// Nested message and enum types in `Instrument`.
+pub mod instrument {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "3")]
+        Spot(super::Spot),
+        #[prost(message, tag = "4")]
+        Future(super::FutureContract),
+        #[prost(message, tag = "5")]
+        Perpetual(super::Perpetual),
+        #[prost(message, tag = "6")]
+        Option(super::OptionContract),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Spot {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Perpetual {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FutureContract {
+    #[prost(message, optional, tag = "1")]
+    pub expiry: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OptionContract {
+    #[prost(enumeration = "OptionKind", tag = "1")]
+    pub kind: i32,
+    #[prost(enumeration = "OptionExercise", tag = "2")]
+    pub exercise: i32,
+    #[prost(message, optional, tag = "3")]
+    pub expiry: ::core::option::Option<::prost_types::Timestamp>,
+    /// Decimal strike price (eg/ "70000").
+    #[prost(string, tag = "4")]
+    pub strike: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PublicTrade {
+    #[prost(double, tag = "3")]
+    pub price: f64,
+    #[prost(double, tag = "4")]
+    pub amount: f64,
+    #[prost(enumeration = "Side", tag = "5")]
+    pub side: i32,
+    #[prost(bool, tag = "6")]
+    pub side_inferred: bool,
+    #[prost(bool, tag = "7")]
+    pub block: bool,
+    #[prost(string, optional, tag = "8")]
+    pub buyer_order_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "9")]
+    pub seller_order_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "10")]
+    pub buyer_is_maker: ::core::option::Option<bool>,
+    #[prost(double, optional, tag = "11")]
+    pub notional: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "12")]
+    pub usd_value: ::core::option::Option<f64>,
+    /// Unset if the exchange does not provide a trade id.
+    #[prost(oneof = "public_trade::Id", tags = "1, 2")]
+    pub id: ::core::option::Option<public_trade::Id>,
+}
+/// Nested message and enum types in `PublicTrade`.
+pub mod public_trade {
+    /// Unset if the exchange does not provide a trade id.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Id {
+        #[prost(uint64, tag = "1")]
+        IdU64(u64),
+        #[prost(string, tag = "2")]
+        IdString(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Level {
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    #[prost(double, tag = "2")]
+    pub amount: f64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookL1 {
+    #[prost(message, optional, tag = "1")]
+    pub last_update_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub best_bid: ::core::option::Option<Level>,
+    #[prost(message, optional, tag = "3")]
+    pub best_ask: ::core::option::Option<Level>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBook {
+    #[prost(message, optional, tag = "1")]
+    pub last_update_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, repeated, tag = "2")]
+    pub bids: ::prost::alloc::vec::Vec<Level>,
+    #[prost(message, repeated, tag = "3")]
+    pub asks: ::prost::alloc::vec::Vec<Level>,
+    #[prost(uint64, optional, tag = "4")]
+    pub sequence: ::core::option::Option<u64>,
+    /// Set if bids & asks only contain the levels changed by an update.
+    #[prost(bool, tag = "5")]
+    pub delta: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Candle {
+    #[prost(message, optional, tag = "1")]
+    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(double, tag = "2")]
+    pub open: f64,
+    #[prost(double, tag = "3")]
+    pub high: f64,
+    #[prost(double, tag = "4")]
+    pub low: f64,
+    #[prost(double, tag = "5")]
+    pub close: f64,
+    #[prost(double, tag = "6")]
+    pub volume: f64,
+    #[prost(uint64, tag = "7")]
+    pub trade_count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Liquidation {
+    #[prost(enumeration = "Side", tag = "1")]
+    pub side: i32,
+    #[prost(double, tag = "2")]
+    pub price: f64,
+    #[prost(double, tag = "3")]
+    pub quantity: f64,
+    #[prost(message, optional, tag = "4")]
+    pub time: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Greeks {
+    #[prost(double, tag = "1")]
+    pub delta: f64,
+    #[prost(double, tag = "2")]
+    pub gamma: f64,
+    #[prost(double, tag = "3")]
+    pub vega: f64,
+    #[prost(double, tag = "4")]
+    pub theta: f64,
+    #[prost(double, optional, tag = "5")]
+    pub mark_price: ::core::option::Option<f64>,
+    #[prost(double, tag = "6")]
+    pub mark_iv: f64,
+    #[prost(double, optional, tag = "7")]
+    pub bid_iv: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "8")]
+    pub ask_iv: ::core::option::Option<f64>,
+    #[prost(double, tag = "9")]
+    pub underlying_price: f64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ticker {
+    #[prost(message, optional, tag = "1")]
+    pub open_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub close_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(double, tag = "3")]
+    pub open: f64,
+    #[prost(double, tag = "4")]
+    pub high: f64,
+    #[prost(double, tag = "5")]
+    pub low: f64,
+    #[prost(double, tag = "6")]
+    pub last: f64,
+    #[prost(double, tag = "7")]
+    pub price_change: f64,
+    #[prost(double, tag = "8")]
+    pub price_change_percent: f64,
+    #[prost(double, tag = "9")]
+    pub weighted_avg_price: f64,
+    #[prost(double, tag = "10")]
+    pub volume: f64,
+    #[prost(double, tag = "11")]
+    pub quote_volume: f64,
+    #[prost(uint64, tag = "12")]
+    pub trade_count: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MiniTicker {
+    #[prost(double, tag = "1")]
+    pub open: f64,
+    #[prost(double, tag = "2")]
+    pub high: f64,
+    #[prost(double, tag = "3")]
+    pub low: f64,
+    #[prost(double, tag = "4")]
+    pub last: f64,
+    #[prost(double, tag = "5")]
+    pub volume: f64,
+    #[prost(double, tag = "6")]
+    pub quote_volume: f64,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Origin {
+    Unspecified = 0,
+    Live = 1,
+    Backfill = 2,
+    SnapshotReplay = 3,
+}
+impl Origin {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Origin::Unspecified => "ORIGIN_UNSPECIFIED",
+            Origin::Live => "ORIGIN_LIVE",
+            Origin::Backfill => "ORIGIN_BACKFILL",
+            Origin::SnapshotReplay => "ORIGIN_SNAPSHOT_REPLAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORIGIN_UNSPECIFIED" => Some(Self::Unspecified),
+            "ORIGIN_LIVE" => Some(Self::Live),
+            "ORIGIN_BACKFILL" => Some(Self::Backfill),
+            "ORIGIN_SNAPSHOT_REPLAY" => Some(Self::SnapshotReplay),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OptionKind {
+    Unspecified = 0,
+    Call = 1,
+    Put = 2,
+}
+impl OptionKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OptionKind::Unspecified => "OPTION_KIND_UNSPECIFIED",
+            OptionKind::Call => "OPTION_KIND_CALL",
+            OptionKind::Put => "OPTION_KIND_PUT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OPTION_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "OPTION_KIND_CALL" => Some(Self::Call),
+            "OPTION_KIND_PUT" => Some(Self::Put),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OptionExercise {
+    Unspecified = 0,
+    American = 1,
+    Bermudan = 2,
+    European = 3,
+}
+impl OptionExercise {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OptionExercise::Unspecified => "OPTION_EXERCISE_UNSPECIFIED",
+            OptionExercise::American => "OPTION_EXERCISE_AMERICAN",
+            OptionExercise::Bermudan => "OPTION_EXERCISE_BERMUDAN",
+            OptionExercise::European => "OPTION_EXERCISE_EUROPEAN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OPTION_EXERCISE_UNSPECIFIED" => Some(Self::Unspecified),
+            "OPTION_EXERCISE_AMERICAN" => Some(Self::American),
+            "OPTION_EXERCISE_BERMUDAN" => Some(Self::Bermudan),
+            "OPTION_EXERCISE_EUROPEAN" => Some(Self::European),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Side {
+    Unspecified = 0,
+    Buy = 1,
+    Sell = 2,
+}
+impl Side {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Side::Unspecified => "SIDE_UNSPECIFIED",
+            Side::Buy => "SIDE_BUY",
+            Side::Sell => "SIDE_SELL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SIDE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SIDE_BUY" => Some(Self::Buy),
+            "SIDE_SELL" => Some(Self::Sell),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,529 @@
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent as BarterMarketEvent, MonotonicTime, Origin as BarterOrigin},
+    subscription::{
+        book::{self, OrderBookSide},
+        candle, greeks, liquidation, ticker,
+        trade::{self, TradeId},
+    },
+};
+use barter_integration::model::{
+    self,
+    instrument::{self as barter_instrument, kind},
+    Exchange,
+};
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Prost types of the `proto/market_event.proto` messages, maintained by hand.
+#[allow(missing_copy_implementations, clippy::all)]
+mod generated;
+pub use generated::*;
+
+impl<T> From<BarterMarketEvent<T>> for MarketEvent
+where
+    BarterMarketEvent<T>: Into<BarterMarketEvent<DataKind>>,
+{
+    fn from(event: BarterMarketEvent<T>) -> Self {
+        let event = event.into();
+        let mut proto = Self {
+            exchange_time: Some(timestamp(event.exchange_time)),
+            received_time: Some(timestamp(event.received_time)),
+            exchange: event.exchange.to_string(),
+            instrument: Some(Instrument::from(event.instrument)),
+            origin: 0,
+            kind: Some(market_event::Kind::from(event.kind)),
+        };
+        proto.set_origin(Origin::from(event.origin));
+        proto
+    }
+}
+
+impl TryFrom<MarketEvent> for BarterMarketEvent<DataKind> {
+    type Error = DataError;
+
+    /// Note: the `received_monotonic` timestamp is not transmitted, so it is set to the default.
+    fn try_from(event: MarketEvent) -> Result<Self, Self::Error> {
+        let origin = BarterOrigin::from(event.origin());
+        Ok(Self {
+            exchange_time: datetime(event.exchange_time, "exchange_time")?,
+            received_time: datetime(event.received_time, "received_time")?,
+            received_monotonic: MonotonicTime::default(),
+            origin,
+            exchange: Exchange::from(event.exchange),
+            instrument: required(event.instrument, "instrument")?.try_into()?,
+            kind: required(event.kind, "kind")?.try_into()?,
+        })
+    }
+}
+
+impl From<barter_instrument::Instrument> for Instrument {
+    fn from(instrument: barter_instrument::Instrument) -> Self {
+        let kind = match instrument.kind {
+            kind::InstrumentKind::Spot => instrument::Kind::Spot(Spot {}),
+            kind::InstrumentKind::Perpetual => instrument::Kind::Perpetual(Perpetual {}),
+            kind::InstrumentKind::Future(future) => instrument::Kind::Future(FutureContract {
+                expiry: Some(timestamp(future.expiry)),
+            }),
+            kind::InstrumentKind::Option(option) => {
+                let mut contract = OptionContract {
+                    kind: 0,
+                    exercise: 0,
+                    expiry: Some(timestamp(option.expiry)),
+                    strike: option.strike.to_string(),
+                };
+                contract.set_kind(match option.kind {
+                    kind::OptionKind::Call => OptionKind::Call,
+                    kind::OptionKind::Put => OptionKind::Put,
+                });
+                contract.set_exercise(match option.exercise {
+                    kind::OptionExercise::American => OptionExercise::American,
+                    kind::OptionExercise::Bermudan => OptionExercise::Bermudan,
+                    kind::OptionExercise::European => OptionExercise::European,
+                });
+                instrument::Kind::Option(contract)
+            }
+        };
+
+        Self {
+            base: instrument.base.to_string(),
+            quote: instrument.quote.to_string(),
+            kind: Some(kind),
+        }
+    }
+}
+
+impl TryFrom<Instrument> for barter_instrument::Instrument {
+    type Error = DataError;
+
+    fn try_from(instrument: Instrument) -> Result<Self, Self::Error> {
+        let kind = match required(instrument.kind, "instrument.kind")? {
+            instrument::Kind::Spot(_) => kind::InstrumentKind::Spot,
+            instrument::Kind::Perpetual(_) => kind::InstrumentKind::Perpetual,
+            instrument::Kind::Future(future) => {
+                kind::InstrumentKind::Future(kind::FutureContract {
+                    expiry: datetime(future.expiry, "future.expiry")?,
+                })
+            }
+            instrument::Kind::Option(option) => {
+                let kind = match option.kind() {
+                    OptionKind::Call => kind::OptionKind::Call,
+                    OptionKind::Put => kind::OptionKind::Put,
+                    OptionKind::Unspecified => return Err(missing("option.kind")),
+                };
+                let exercise = match option.exercise() {
+                    OptionExercise::American => kind::OptionExercise::American,
+                    OptionExercise::Bermudan => kind::OptionExercise::Bermudan,
+                    OptionExercise::European => kind::OptionExercise::European,
+                    OptionExercise::Unspecified => return Err(missing("option.exercise")),
+                };
+                kind::InstrumentKind::Option(kind::OptionContract {
+                    kind,
+                    exercise,
+                    expiry: datetime(option.expiry, "option.expiry")?,
+                    strike: option.strike.parse().map_err(|error| {
+                        DataError::Proto(format!("invalid option.strike: {error}"))
+                    })?,
+                })
+            }
+        };
+
+        Ok(Self::from((instrument.base, instrument.quote, kind)))
+    }
+}
+
+impl From<DataKind> for market_event::Kind {
+    fn from(kind: DataKind) -> Self {
+        match kind {
+            DataKind::Trade(trade) => {
+                let mut proto = PublicTrade {
+                    id: match trade.id {
+                        TradeId::U64(id) => Some(public_trade::Id::IdU64(id)),
+                        TradeId::String(id) => Some(public_trade::Id::IdString(id)),
+                        TradeId::None => None,
+                    },
+                    price: trade.price,
+                    amount: trade.amount,
+                    side: 0,
+                    side_inferred: trade.side_inferred,
+                    block: trade.block,
+                    buyer_order_id: trade.buyer_order_id,
+                    seller_order_id: trade.seller_order_id,
+                    buyer_is_maker: trade.buyer_is_maker,
+                    notional: trade.notional,
+                    usd_value: trade.usd_value,
+                };
+                proto.set_side(Side::from(trade.side));
+                Self::Trade(proto)
+            }
+            DataKind::OrderBookL1(book) => Self::OrderBookL1(OrderBookL1 {
+                last_update_time: Some(timestamp(book.last_update_time)),
+                best_bid: Some(Level::from(book.best_bid)),
+                best_ask: Some(Level::from(book.best_ask)),
+            }),
+            DataKind::OrderBook(book) => Self::OrderBook(OrderBook {
+                last_update_time: Some(timestamp(book.last_update_time)),
+                bids: book
+                    .bids
+                    .levels()
+                    .iter()
+                    .copied()
+                    .map(Level::from)
+                    .collect(),
+                asks: book
+                    .asks
+                    .levels()
+                    .iter()
+                    .copied()
+                    .map(Level::from)
+                    .collect(),
+                sequence: book.sequence,
+                delta: book.delta,
+            }),
+            DataKind::Candle(candle) => Self::Candle(Candle {
+                close_time: Some(timestamp(candle.close_time)),
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            }),
+            DataKind::Liquidation(liquidation) => {
+                let mut proto = Liquidation {
+                    side: 0,
+                    price: liquidation.price,
+                    quantity: liquidation.quantity,
+                    time: Some(timestamp(liquidation.time)),
+                };
+                proto.set_side(Side::from(liquidation.side));
+                Self::Liquidation(proto)
+            }
+            DataKind::Greeks(greeks) => Self::Greeks(Greeks {
+                delta: greeks.delta,
+                gamma: greeks.gamma,
+                vega: greeks.vega,
+                theta: greeks.theta,
+                mark_price: greeks.mark_price,
+                mark_iv: greeks.mark_iv,
+                bid_iv: greeks.bid_iv,
+                ask_iv: greeks.ask_iv,
+                underlying_price: greeks.underlying_price,
+            }),
+            DataKind::Ticker(ticker) => Self::Ticker(Ticker {
+                open_time: Some(timestamp(ticker.open_time)),
+                close_time: Some(timestamp(ticker.close_time)),
+                open: ticker.open,
+                high: ticker.high,
+                low: ticker.low,
+                last: ticker.last,
+                price_change: ticker.price_change,
+                price_change_percent: ticker.price_change_percent,
+                weighted_avg_price: ticker.weighted_avg_price,
+                volume: ticker.volume,
+                quote_volume: ticker.quote_volume,
+                trade_count: ticker.trade_count,
+            }),
+            DataKind::MiniTicker(ticker) => Self::MiniTicker(MiniTicker {
+                open: ticker.open,
+                high: ticker.high,
+                low: ticker.low,
+                last: ticker.last,
+                volume: ticker.volume,
+                quote_volume: ticker.quote_volume,
+            }),
+        }
+    }
+}
+
+impl TryFrom<market_event::Kind> for DataKind {
+    type Error = DataError;
+
+    fn try_from(kind: market_event::Kind) -> Result<Self, Self::Error> {
+        Ok(match kind {
+            market_event::Kind::Trade(trade) => Self::Trade(trade::PublicTrade {
+                id: match trade.id.clone() {
+                    Some(public_trade::Id::IdU64(id)) => TradeId::U64(id),
+                    Some(public_trade::Id::IdString(id)) => TradeId::String(id),
+                    None => TradeId::None,
+                },
+                price: trade.price,
+                amount: trade.amount,
+                side: model::Side::try_from(trade.side())?,
+                side_inferred: trade.side_inferred,
+                block: trade.block,
+                buyer_order_id: trade.buyer_order_id,
+                seller_order_id: trade.seller_order_id,
+                buyer_is_maker: trade.buyer_is_maker,
+                notional: trade.notional,
+                usd_value: trade.usd_value,
+            }),
+            market_event::Kind::OrderBookL1(book) => Self::OrderBookL1(book::OrderBookL1 {
+                last_update_time: datetime(book.last_update_time, "last_update_time")?,
+                best_bid: required(book.best_bid, "best_bid")?.into(),
+                best_ask: required(book.best_ask, "best_ask")?.into(),
+            }),
+            market_event::Kind::OrderBook(book) => Self::OrderBook(book::OrderBook {
+                last_update_time: datetime(book.last_update_time, "last_update_time")?,
+                bids: OrderBookSide::new(
+                    model::Side::Buy,
+                    book.bids.into_iter().map(book::Level::from),
+                ),
+                asks: OrderBookSide::new(
+                    model::Side::Sell,
+                    book.asks.into_iter().map(book::Level::from),
+                ),
+                sequence: book.sequence,
+                delta: book.delta,
+            }),
+            market_event::Kind::Candle(candle) => Self::Candle(candle::Candle {
+                close_time: datetime(candle.close_time, "close_time")?,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            }),
+            market_event::Kind::Liquidation(liquidation) => {
+                Self::Liquidation(liquidation::Liquidation {
+                    side: model::Side::try_from(liquidation.side())?,
+                    price: liquidation.price,
+                    quantity: liquidation.quantity,
+                    time: datetime(liquidation.time, "time")?,
+                })
+            }
+            market_event::Kind::Greeks(greeks) => Self::Greeks(greeks::Greeks {
+                delta: greeks.delta,
+                gamma: greeks.gamma,
+                vega: greeks.vega,
+                theta: greeks.theta,
+                mark_price: greeks.mark_price,
+                mark_iv: greeks.mark_iv,
+                bid_iv: greeks.bid_iv,
+                ask_iv: greeks.ask_iv,
+                underlying_price: greeks.underlying_price,
+            }),
+            market_event::Kind::Ticker(ticker) => Self::Ticker(ticker::Ticker {
+                open_time: datetime(ticker.open_time, "open_time")?,
+                close_time: datetime(ticker.close_time, "close_time")?,
+                open: ticker.open,
+                high: ticker.high,
+                low: ticker.low,
+                last: ticker.last,
+                price_change: ticker.price_change,
+                price_change_percent: ticker.price_change_percent,
+                weighted_avg_price: ticker.weighted_avg_price,
+                volume: ticker.volume,
+                quote_volume: ticker.quote_volume,
+                trade_count: ticker.trade_count,
+            }),
+            market_event::Kind::MiniTicker(ticker) => Self::MiniTicker(ticker::MiniTicker {
+                open: ticker.open,
+                high: ticker.high,
+                low: ticker.low,
+                last: ticker.last,
+                volume: ticker.volume,
+                quote_volume: ticker.quote_volume,
+            }),
+        })
+    }
+}
+
+impl From<BarterOrigin> for Origin {
+    fn from(origin: BarterOrigin) -> Self {
+        match origin {
+            BarterOrigin::Live => Self::Live,
+            BarterOrigin::Backfill => Self::Backfill,
+            BarterOrigin::SnapshotReplay => Self::SnapshotReplay,
+        }
+    }
+}
+
+impl From<Origin> for BarterOrigin {
+    /// Note: events encoded before the origin was added leave it unspecified, and are all live.
+    fn from(origin: Origin) -> Self {
+        match origin {
+            Origin::Unspecified | Origin::Live => Self::Live,
+            Origin::Backfill => Self::Backfill,
+            Origin::SnapshotReplay => Self::SnapshotReplay,
+        }
+    }
+}
+
+impl From<model::Side> for Side {
+    fn from(side: model::Side) -> Self {
+        match side {
+            model::Side::Buy => Self::Buy,
+            model::Side::Sell => Self::Sell,
+        }
+    }
+}
+
+impl TryFrom<Side> for model::Side {
+    type Error = DataError;
+
+    fn try_from(side: Side) -> Result<Self, Self::Error> {
+        match side {
+            Side::Buy => Ok(Self::Buy),
+            Side::Sell => Ok(Self::Sell),
+            Side::Unspecified => Err(missing("side")),
+        }
+    }
+}
+
+impl From<book::Level> for Level {
+    fn from(level: book::Level) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl From<Level> for book::Level {
+    fn from(level: Level) -> Self {
+        Self::new(level.price, level.amount)
+    }
+}
+
+/// Convert a [`DateTime<Utc>`] into a protobuf [`prost_types::Timestamp`].
+fn timestamp(time: DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }
+}
+
+/// Convert a required protobuf [`prost_types::Timestamp`] field into a [`DateTime<Utc>`].
+fn datetime(
+    timestamp: Option<prost_types::Timestamp>,
+    field: &'static str,
+) -> Result<DateTime<Utc>, DataError> {
+    let timestamp = required(timestamp, field)?;
+    Utc.timestamp_opt(timestamp.seconds, timestamp.nanos as u32)
+        .single()
+        .ok_or_else(|| DataError::Proto(format!("invalid {field} timestamp")))
+}
+
+/// Unwrap a required protobuf message field.
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, DataError> {
+    value.ok_or_else(|| missing(field))
+}
+
+/// [`DataError`] for a protobuf message missing a required field.
+fn missing(field: &'static str) -> DataError {
+    DataError::Proto(format!("missing required field: {field}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::market_event;
+    use prost::Message;
+
+    #[test]
+    fn test_market_event_round_trip() {
+        let time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let event = |kind| BarterMarketEvent {
+            exchange_time: time,
+            received_time: time,
+            received_monotonic: MonotonicTime::default(),
+            instrument: barter_instrument::Instrument::from((
+                "btc",
+                "usdt",
+                kind::InstrumentKind::Perpetual,
+            )),
+            ..market_event(kind)
+        };
+
+        let tests = vec![
+            // TC0: trade w/ numeric id
+            event(DataKind::Trade(trade::PublicTrade {
+                side_inferred: true,
+                ..trade::PublicTrade::new(1, 100.0, 1.0, model::Side::Sell)
+            })),
+            // TC1: OrderBook w/ levels on both sides
+            event(DataKind::OrderBook(book::OrderBook {
+                last_update_time: time,
+                bids: OrderBookSide::new(model::Side::Buy, vec![(100.0, 1.0), (99.0, 2.0)]),
+                asks: OrderBookSide::new(model::Side::Sell, vec![(101.0, 1.0)]),
+                sequence: None,
+                delta: false,
+            })),
+            // TC2: Greeks w/ optional fields unset
+            event(DataKind::Greeks(greeks::Greeks {
+                delta: 0.5,
+                gamma: 0.1,
+                vega: 10.0,
+                theta: -1.0,
+                mark_price: None,
+                mark_iv: 0.6,
+                bid_iv: Some(0.55),
+                ask_iv: None,
+                underlying_price: 100.0,
+            })),
+            // TC3: backfilled trade
+            BarterMarketEvent {
+                origin: BarterOrigin::Backfill,
+                ..event(DataKind::Trade(trade::PublicTrade::new(
+                    2,
+                    100.0,
+                    1.0,
+                    model::Side::Buy,
+                )))
+            },
+            // TC4: replayed trade
+            BarterMarketEvent {
+                origin: BarterOrigin::SnapshotReplay,
+                ..event(DataKind::Trade(trade::PublicTrade::new(
+                    3,
+                    100.0,
+                    1.0,
+                    model::Side::Buy,
+                )))
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let encoded = MarketEvent::from(test.clone()).encode_to_vec();
+            let decoded = MarketEvent::decode(encoded.as_slice()).unwrap();
+            let actual = BarterMarketEvent::<DataKind>::try_from(decoded).unwrap();
+            assert_eq!(actual, test, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_market_event_unspecified_origin() {
+        // Events encoded before the origin was added are live
+        let mut event = MarketEvent::from(BarterMarketEvent {
+            origin: BarterOrigin::Backfill,
+            ..market_event(DataKind::Trade(trade::PublicTrade::new(
+                1,
+                100.0,
+                1.0,
+                model::Side::Buy,
+            )))
+        });
+        event.origin = 0;
+
+        let actual = BarterMarketEvent::<DataKind>::try_from(event).unwrap();
+        assert_eq!(actual.origin, BarterOrigin::Live);
+    }
+
+    #[test]
+    fn test_market_event_missing_field() {
+        let event = MarketEvent {
+            exchange_time: None,
+            received_time: None,
+            exchange: "binance_spot".to_owned(),
+            instrument: None,
+            origin: 0,
+            kind: None,
+        };
+
+        match BarterMarketEvent::<DataKind>::try_from(event) {
+            Err(DataError::Proto(_)) => {}
+            other => panic!("expected DataError::Proto, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::exchange::Connector;
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Rolling 24 hour traded volume of an exchange [`Instrument`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VolumeTicker {
+    pub instrument: Instrument,
+    /// Volume traded over the last 24 hours, denominated in the quote asset.
+    pub quote_volume: f64,
+}
+
+/// Exchange [`Connector`] that serves the rolling 24 hour volume of it's markets via HTTP.
+#[async_trait]
+pub trait VolumeRanking
+where
+    Self: Connector,
+{
+    /// Fetch the [`VolumeTicker`] of every listed [`Instrument`] quoted in the provided `quote`
+    /// asset (eg/ "usdt").
+    async fn volume_tickers(quote: &str) -> Result<Vec<VolumeTicker>, SocketError>;
+
+    /// Fetch the `n` [`Instrument`]s quoted in the provided `quote` asset with the highest
+    /// rolling 24 hour quote volume, in descending order of volume.
+    async fn top_by_volume(quote: &str, n: usize) -> Result<Vec<Instrument>, SocketError> {
+        Self::volume_tickers(quote)
+            .await
+            .map(|tickers| top_by_volume(tickers, n))
+    }
+}
+
+/// Select the `n` [`Instrument`]s with the highest quote volume, in descending order of volume.
+///
+/// Ties are broken by [`Instrument`] ordering so the selection is deterministic, and tickers
+/// with a non-finite volume are ignored.
+pub fn top_by_volume(mut tickers: Vec<VolumeTicker>, n: usize) -> Vec<Instrument> {
+    tickers.retain(|ticker| ticker.quote_volume.is_finite());
+    tickers.sort_by(|a, b| {
+        b.quote_volume
+            .partial_cmp(&a.quote_volume)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.instrument.cmp(&b.instrument))
+    });
+
+    tickers
+        .into_iter()
+        .take(n)
+        .map(|ticker| ticker.instrument)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn ticker(base: &str, quote_volume: f64) -> VolumeTicker {
+        VolumeTicker {
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            quote_volume,
+        }
+    }
+
+    fn instrument(base: &str) -> Instrument {
+        Instrument::from((base, "usdt", InstrumentKind::Spot))
+    }
+
+    #[test]
+    fn test_top_by_volume() {
+        struct TestCase {
+            input: (Vec<VolumeTicker>, usize),
+            expected: Vec<Instrument>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: top n in descending order of volume
+                input: (
+                    vec![
+                        ticker("eth", 20.0),
+                        ticker("btc", 30.0),
+                        ticker("sol", 10.0),
+                    ],
+                    2,
+                ),
+                expected: vec![instrument("btc"), instrument("eth")],
+            },
+            TestCase {
+                // TC1: fewer tickers than n
+                input: (vec![ticker("eth", 20.0)], 5),
+                expected: vec![instrument("eth")],
+            },
+            TestCase {
+                // TC2: ties broken by Instrument, non-finite volumes ignored
+                input: (
+                    vec![
+                        ticker("xrp", 5.0),
+                        ticker("nan", f64::NAN),
+                        ticker("ada", 5.0),
+                    ],
+                    3,
+                ),
+                expected: vec![instrument("ada"), instrument("xrp")],
+            },
+            TestCase {
+                // TC3: n of zero
+                input: (vec![ticker("eth", 20.0)], 0),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (tickers, n) = test.input;
+            let actual = top_by_volume(tickers, n);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
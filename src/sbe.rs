@@ -0,0 +1,321 @@
+use crate::transport::BoxTransport;
+use barter_integration::{
+    error::SocketError,
+    protocol::{
+        websocket::{WebSocketParser, WsError, WsMessage},
+        StreamParser,
+    },
+};
+use serde::de::{DeserializeOwned, Deserializer, Visitor};
+use std::fmt::Formatter;
+
+/// [`StreamParser`] for exchanges that serve binary encoded (eg/ SBE) market data.
+///
+/// Binary frames are handed to the `Output` [`Deserialize`](serde::Deserialize) implementation
+/// via [`Deserializer::deserialize_bytes`], so binary message types decode themselves (see
+/// [`de_sbe`]) and flow through the same transformer pipeline as JSON messages. Every other
+/// frame (eg/ JSON text subscription responses) is parsed by the [`WebSocketParser`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BinaryParser;
+
+impl StreamParser for BinaryParser {
+    type Stream = BoxTransport;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsMessage::Binary(payload)) => Some(
+                Output::deserialize(
+                    serde::de::value::BytesDeserializer::<serde_json::Error>::new(&payload),
+                )
+                .map_err(|error| SocketError::DeserialiseBinary { error, payload }),
+            ),
+            input => WebSocketParser::parse(input),
+        }
+    }
+}
+
+/// SBE message header preceding every SBE encoded message.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SbeHeader {
+    /// Length of the root block of fixed length fields.
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+/// Little-endian cursor over an SBE encoded message.
+#[derive(Copy, Clone, Debug)]
+pub struct SbeReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SbeReader<'a> {
+    /// Construct a new [`Self`] positioned at the start of the provided bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Current position of the cursor.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Read the next `len` bytes.
+    pub fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.position + len;
+        let bytes = self.bytes.get(self.position..end).ok_or_else(|| {
+            format!(
+                "SBE message truncated: expected {end} bytes, received {}",
+                self.bytes.len()
+            )
+        })?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// Advance the cursor to the provided position (eg/ the end of a block containing fields
+    /// unknown to this schema version).
+    pub fn seek(&mut self, position: usize) -> Result<(), String> {
+        if position > self.bytes.len() {
+            return Err(format!(
+                "SBE message truncated: expected {position} bytes, received {}",
+                self.bytes.len()
+            ));
+        }
+        self.position = position;
+        Ok(())
+    }
+
+    pub fn u8(&mut self) -> Result<u8, String> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    pub fn i8(&mut self) -> Result<i8, String> {
+        self.take(1).map(|bytes| bytes[0] as i8)
+    }
+
+    pub fn u16(&mut self) -> Result<u16, String> {
+        self.take(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, String> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().expect("slice is 4 bytes")))
+    }
+
+    pub fn i64(&mut self) -> Result<i64, String> {
+        self.take(8)
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().expect("slice is 8 bytes")))
+    }
+
+    /// Read the [`SbeHeader`].
+    pub fn header(&mut self) -> Result<SbeHeader, String> {
+        Ok(SbeHeader {
+            block_length: self.u16()?,
+            template_id: self.u16()?,
+            schema_id: self.u16()?,
+            version: self.u16()?,
+        })
+    }
+
+    /// Read a variable length UTF-8 string prefixed with a `u8` length (ie/ SBE `varString8`).
+    pub fn var_string8(&mut self) -> Result<&'a str, String> {
+        let len = self.u8()? as usize;
+        self.take(len).and_then(|bytes| {
+            std::str::from_utf8(bytes).map_err(|error| format!("invalid SBE varString8: {error}"))
+        })
+    }
+}
+
+/// Fixed point decimal encoded as an integer mantissa & a base 10 exponent.
+pub fn decimal(mantissa: i64, exponent: i8) -> f64 {
+    // Divide by an exact power of ten for negative exponents to avoid rounding errors
+    if exponent < 0 {
+        mantissa as f64 / 10f64.powi(-(exponent as i32))
+    } else {
+        mantissa as f64 * 10f64.powi(exponent as i32)
+    }
+}
+
+/// Decode an SBE encoded message from the provided bytes.
+pub trait SbeDecode
+where
+    Self: Sized,
+{
+    /// SBE schema id of the message.
+    const SCHEMA_ID: u16;
+
+    /// SBE template id of the message.
+    const TEMPLATE_ID: u16;
+
+    /// Decode the message body following the [`SbeHeader`], where the `reader` is positioned at
+    /// the start of the root block.
+    fn decode_body(header: SbeHeader, reader: &mut SbeReader<'_>) -> Result<Self, String>;
+
+    /// Decode the [`SbeHeader`] & message body, validating the schema & template ids.
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = SbeReader::new(bytes);
+        let header = reader.header()?;
+
+        if header.schema_id != Self::SCHEMA_ID || header.template_id != Self::TEMPLATE_ID {
+            return Err(format!(
+                "unexpected SBE schema {} template {}, expected schema {} template {}",
+                header.schema_id,
+                header.template_id,
+                Self::SCHEMA_ID,
+                Self::TEMPLATE_ID
+            ));
+        }
+
+        Self::decode_body(header, &mut reader)
+    }
+}
+
+/// Deserialize an [`SbeDecode`] message from the bytes provided by the [`BinaryParser`].
+///
+/// Used to implement [`Deserialize`](serde::Deserialize) for SBE message types.
+pub fn de_sbe<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: SbeDecode,
+{
+    struct SbeVisitor<T>(std::marker::PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for SbeVisitor<T>
+    where
+        T: SbeDecode,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("SBE encoded binary message")
+        }
+
+        fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            T::decode(bytes).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_bytes(SbeVisitor(std::marker::PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct TestMessage {
+        value: i64,
+        name: String,
+    }
+
+    impl SbeDecode for TestMessage {
+        const SCHEMA_ID: u16 = 1;
+        const TEMPLATE_ID: u16 = 7;
+
+        fn decode_body(header: SbeHeader, reader: &mut SbeReader<'_>) -> Result<Self, String> {
+            let block_start = reader.position();
+            let value = reader.i64()?;
+            reader.seek(block_start + header.block_length as usize)?;
+            let name = reader.var_string8()?.to_owned();
+            Ok(Self { value, name })
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for TestMessage {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            de_sbe(deserializer)
+        }
+    }
+
+    fn encode(template_id: u16, value: i64, name: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // Root block contains a trailing field unknown to the TestMessage schema version
+        bytes.extend_from_slice(&10u16.to_le_bytes());
+        bytes.extend_from_slice(&template_id.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&value.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.push(name.len() as u8);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_binary_parser() {
+        struct TestCase {
+            input: WsMessage,
+            expected: Option<Result<TestMessage, SocketError>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: binary frame is SBE decoded, skipping unknown root block fields
+                input: WsMessage::Binary(encode(7, -42, "BTCUSDT")),
+                expected: Some(Ok(TestMessage {
+                    value: -42,
+                    name: "BTCUSDT".to_owned(),
+                })),
+            },
+            TestCase {
+                // TC1: binary frame of another template is an error
+                input: WsMessage::Binary(encode(8, -42, "BTCUSDT")),
+                expected: Some(Err(SocketError::Sink)),
+            },
+            TestCase {
+                // TC2: truncated binary frame is an error
+                input: WsMessage::Binary(encode(7, -42, "BTCUSDT")[..12].to_vec()),
+                expected: Some(Err(SocketError::Sink)),
+            },
+            TestCase {
+                // TC3: JSON text frame is parsed by the WebSocketParser
+                input: WsMessage::Text(r#"{"result":null,"id":1}"#.to_owned()),
+                expected: Some(Err(SocketError::Sink)),
+            },
+            TestCase {
+                // TC4: control frame is skipped
+                input: WsMessage::Ping(vec![]),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BinaryParser::parse::<TestMessage>(Ok(test.input));
+            match (actual, test.expected) {
+                (Some(Ok(actual)), Some(Ok(expected))) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Some(Err(_)), Some(Err(_))) | (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimal() {
+        assert_eq!(decimal(6500012, -2), 65000.12);
+        assert_eq!(decimal(25, 0), 25.0);
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    event::{DataKind, MarketEvent, MonotonicTime, Origin},
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        candle::Candle,
+        greeks::Greeks,
+        liquidation::Liquidation,
+        ticker::{MiniTicker, Ticker},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Map, Value};
+
+/// Current version of the [`VersionedMarketEvent`] serde schema.
+///
+/// Incremented whenever a change to the serialised representation of a [`VersionedMarketEvent`]
+/// could break existing consumers (eg/ renaming or removing a field). Adding optional fields does
+/// not require a new version.
+///
+/// ### Versions
+/// - 1: initial representation.
+/// - 2: adds the [`Origin`] of the event, which defaults to [`Origin::Live`] for version 1
+///   events.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Versioned & documented serde representation of a [`MarketEvent<DataKind>`], used for recorded
+/// data and cross-language consumers that must survive crate upgrades.
+///
+/// The [`MarketEvent`] `received_monotonic` timestamp is omitted since it is only comparable
+/// within the process that generated it.
+///
+/// See [`json_schema`] for the JSON Schema of this representation.
+///
+/// ### Serialised Example
+/// ```json
+/// {
+///     "schema_version": 2,
+///     "exchange_time": "2023-01-01T00:00:00Z",
+///     "received_time": "2023-01-01T00:00:00.100Z",
+///     "origin": "live",
+///     "exchange": "binance_spot",
+///     "instrument": { "base": "btc", "quote": "usdt", "instrument_kind": "spot" },
+///     "kind": {
+///         "type": "trade",
+///         "data": { "id": 1, "price": 100.0, "amount": 1.0, "side": "Buy", "side_inferred": false, "block": false }
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VersionedMarketEvent {
+    /// [`SCHEMA_VERSION`] of the representation. Deserialising a newer version fails.
+    #[serde(deserialize_with = "de_schema_version")]
+    pub schema_version: u32,
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    /// [`Origin`] of the event, absent from version 1 events (which are all [`Origin::Live`]).
+    #[serde(default)]
+    pub origin: Origin,
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub kind: EventKind,
+}
+
+/// Adjacently tagged kinds of [`VersionedMarketEvent`], with a snake_case `type` tag and the
+/// event `data`.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum EventKind {
+    Trade(PublicTrade),
+    OrderBookL1(OrderBookL1),
+    OrderBook(OrderBook),
+    Candle(Candle),
+    Liquidation(Liquidation),
+    Greeks(Greeks),
+    Ticker(Ticker),
+    MiniTicker(MiniTicker),
+}
+
+impl From<DataKind> for EventKind {
+    fn from(kind: DataKind) -> Self {
+        match kind {
+            DataKind::Trade(trade) => Self::Trade(trade),
+            DataKind::OrderBookL1(book) => Self::OrderBookL1(book),
+            DataKind::OrderBook(book) => Self::OrderBook(book),
+            DataKind::Candle(candle) => Self::Candle(candle),
+            DataKind::Liquidation(liquidation) => Self::Liquidation(liquidation),
+            DataKind::Greeks(greeks) => Self::Greeks(greeks),
+            DataKind::Ticker(ticker) => Self::Ticker(ticker),
+            DataKind::MiniTicker(ticker) => Self::MiniTicker(ticker),
+        }
+    }
+}
+
+impl From<EventKind> for DataKind {
+    fn from(kind: EventKind) -> Self {
+        match kind {
+            EventKind::Trade(trade) => Self::Trade(trade),
+            EventKind::OrderBookL1(book) => Self::OrderBookL1(book),
+            EventKind::OrderBook(book) => Self::OrderBook(book),
+            EventKind::Candle(candle) => Self::Candle(candle),
+            EventKind::Liquidation(liquidation) => Self::Liquidation(liquidation),
+            EventKind::Greeks(greeks) => Self::Greeks(greeks),
+            EventKind::Ticker(ticker) => Self::Ticker(ticker),
+            EventKind::MiniTicker(ticker) => Self::MiniTicker(ticker),
+        }
+    }
+}
+
+impl<T> From<MarketEvent<T>> for VersionedMarketEvent
+where
+    MarketEvent<T>: Into<MarketEvent<DataKind>>,
+{
+    fn from(event: MarketEvent<T>) -> Self {
+        let event = event.into();
+        Self {
+            schema_version: SCHEMA_VERSION,
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            origin: event.origin,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: EventKind::from(event.kind),
+        }
+    }
+}
+
+impl From<VersionedMarketEvent> for MarketEvent<DataKind> {
+    /// Note: the `received_monotonic` timestamp is not recorded, so it is set to the default.
+    fn from(event: VersionedMarketEvent) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: MonotonicTime::default(),
+            origin: event.origin,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::from(event.kind),
+        }
+    }
+}
+
+/// Deserialize a [`VersionedMarketEvent`] `schema_version`, failing if it is newer than the
+/// supported [`SCHEMA_VERSION`].
+fn de_schema_version<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let version = u32::deserialize(deserializer)?;
+    match version <= SCHEMA_VERSION {
+        true => Ok(version),
+        false => Err(serde::de::Error::custom(format!(
+            "unsupported schema_version {version}, latest supported is {SCHEMA_VERSION}"
+        ))),
+    }
+}
+
+/// Generate the JSON Schema (draft 2020-12) of the current [`VersionedMarketEvent`]
+/// representation.
+pub fn json_schema() -> Value {
+    let kinds = [
+        ("trade", "PublicTrade"),
+        ("order_book_l1", "OrderBookL1"),
+        ("order_book", "OrderBook"),
+        ("candle", "Candle"),
+        ("liquidation", "Liquidation"),
+        ("greeks", "Greeks"),
+        ("ticker", "Ticker"),
+        ("mini_ticker", "MiniTicker"),
+    ]
+    .into_iter()
+    .map(|(tag, definition)| {
+        object(
+            &[
+                ("type", json!({ "const": tag })),
+                ("data", json!({ "$ref": format!("#/$defs/{definition}") })),
+            ],
+            &[],
+        )
+    })
+    .collect::<Vec<_>>();
+
+    let mut schema = object(
+        &[
+            (
+                "schema_version",
+                json!({ "type": "integer", "minimum": 1, "maximum": SCHEMA_VERSION }),
+            ),
+            ("exchange_time", date_time()),
+            ("received_time", date_time()),
+            ("exchange", json!({ "type": "string" })),
+            ("instrument", json!({ "$ref": "#/$defs/Instrument" })),
+            ("kind", json!({ "oneOf": kinds })),
+        ],
+        &[(
+            "origin",
+            json!({ "enum": ["live", "backfill", "snapshot_replay"], "default": "live" }),
+        )],
+    );
+
+    let number = json!({ "type": "number" });
+    let optional_number = json!({ "type": ["number", "null"] });
+    let integer = json!({ "type": "integer", "minimum": 0 });
+    let side = json!({ "enum": ["Buy", "Sell"] });
+    let level = json!({ "$ref": "#/$defs/Level" });
+    let book_side = json!({ "$ref": "#/$defs/OrderBookSide" });
+
+    let definitions = json!({
+        "Instrument": object(
+            &[
+                ("base", json!({ "type": "string" })),
+                ("quote", json!({ "type": "string" })),
+                ("instrument_kind", json!({
+                    "description": "\"spot\", \"perpetual\", or an object keyed by \"future\" or \"option\" containing the contract",
+                })),
+            ],
+            &[],
+        ),
+        "Level": object(&[("price", number.clone()), ("amount", number.clone())], &[]),
+        "OrderBookSide": object(
+            &[("side", side.clone()), ("levels", json!({ "type": "array", "items": level }))],
+            &[],
+        ),
+        "PublicTrade": object(
+            &[
+                ("id", json!({ "type": ["integer", "string", "null"] })),
+                ("price", number.clone()),
+                ("amount", number.clone()),
+                ("side", side.clone()),
+            ],
+            &[
+                ("side_inferred", json!({ "type": "boolean" })),
+                ("block", json!({ "type": "boolean" })),
+                ("buyer_order_id", json!({ "type": "string" })),
+                ("seller_order_id", json!({ "type": "string" })),
+                ("buyer_is_maker", json!({ "type": "boolean" })),
+                ("notional", number.clone()),
+                ("usd_value", number.clone()),
+            ],
+        ),
+        "OrderBookL1": object(
+            &[
+                ("last_update_time", date_time()),
+                ("best_bid", level.clone()),
+                ("best_ask", level),
+            ],
+            &[],
+        ),
+        "OrderBook": object(
+            &[
+                ("last_update_time", date_time()),
+                ("bids", book_side.clone()),
+                ("asks", book_side),
+            ],
+            &[
+                ("sequence", json!({ "type": ["integer", "null"] })),
+                ("delta", json!({ "type": "boolean" })),
+            ],
+        ),
+        "Candle": object(
+            &[
+                ("close_time", date_time()),
+                ("open", number.clone()),
+                ("high", number.clone()),
+                ("low", number.clone()),
+                ("close", number.clone()),
+                ("volume", number.clone()),
+                ("trade_count", integer.clone()),
+            ],
+            &[],
+        ),
+        "Liquidation": object(
+            &[
+                ("side", side),
+                ("price", number.clone()),
+                ("quantity", number.clone()),
+                ("time", date_time()),
+            ],
+            &[],
+        ),
+        "Greeks": object(
+            &[
+                ("delta", number.clone()),
+                ("gamma", number.clone()),
+                ("vega", number.clone()),
+                ("theta", number.clone()),
+                ("mark_price", optional_number.clone()),
+                ("mark_iv", number.clone()),
+                ("bid_iv", optional_number.clone()),
+                ("ask_iv", optional_number),
+                ("underlying_price", number.clone()),
+            ],
+            &[],
+        ),
+        "Ticker": object(
+            &[
+                ("open_time", date_time()),
+                ("close_time", date_time()),
+                ("open", number.clone()),
+                ("high", number.clone()),
+                ("low", number.clone()),
+                ("last", number.clone()),
+                ("price_change", number.clone()),
+                ("price_change_percent", number.clone()),
+                ("weighted_avg_price", number.clone()),
+                ("volume", number.clone()),
+                ("quote_volume", number.clone()),
+                ("trade_count", integer),
+            ],
+            &[],
+        ),
+        "MiniTicker": object(
+            &[
+                ("open", number.clone()),
+                ("high", number.clone()),
+                ("low", number.clone()),
+                ("last", number.clone()),
+                ("volume", number.clone()),
+                ("quote_volume", number),
+            ],
+            &[],
+        ),
+    });
+
+    if let Value::Object(schema) = &mut schema {
+        schema.insert(
+            "$schema".to_owned(),
+            json!("https://json-schema.org/draft/2020-12/schema"),
+        );
+        schema.insert("title".to_owned(), json!("MarketEvent"));
+        schema.insert("$defs".to_owned(), definitions);
+    }
+
+    schema
+}
+
+/// JSON Schema of an RFC 3339 date-time string.
+fn date_time() -> Value {
+    json!({ "type": "string", "format": "date-time" })
+}
+
+/// JSON Schema of an object with the provided required & optional properties.
+fn object(required: &[(&str, Value)], optional: &[(&str, Value)]) -> Value {
+    let properties = required
+        .iter()
+        .chain(optional)
+        .map(|(name, schema)| (name.to_string(), schema.clone()))
+        .collect::<Map<_, _>>();
+
+    json!({
+        "type": "object",
+        "required": required.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+        "properties": properties,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture;
+    use chrono::TimeZone;
+
+    fn trade_event() -> VersionedMarketEvent {
+        let time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        VersionedMarketEvent::from(MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            ..fixture::trade_event(1, 100.0, 1.0)
+        })
+    }
+
+    #[test]
+    fn test_versioned_market_event_serde() {
+        // Pins the v2 representation, update SCHEMA_VERSION if this needs to change
+        let expected = r#"{"schema_version":2,"exchange_time":"2023-01-01T00:00:00Z","received_time":"2023-01-01T00:00:00Z","origin":"live","exchange":"binance_spot","instrument":{"base":"btc","quote":"usdt","instrument_kind":"spot"},"kind":{"type":"trade","data":{"id":1,"price":100.0,"amount":1.0,"side":"Buy","side_inferred":false,"block":false}}}"#;
+
+        let event = trade_event();
+        assert_eq!(serde_json::to_string(&event).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_str::<VersionedMarketEvent>(expected).unwrap(),
+            event
+        );
+    }
+
+    #[test]
+    fn test_versioned_market_event_origin() {
+        // Version 1 events have no origin, and were all live
+        let v1 = r#"{"schema_version":1,"exchange_time":"2023-01-01T00:00:00Z","received_time":"2023-01-01T00:00:00Z","exchange":"binance_spot","instrument":{"base":"btc","quote":"usdt","instrument_kind":"spot"},"kind":{"type":"trade","data":{"id":1,"price":100.0,"amount":1.0,"side":"Buy","side_inferred":false,"block":false}}}"#;
+        let event = serde_json::from_str::<VersionedMarketEvent>(v1).unwrap();
+        assert_eq!(event.origin, Origin::Live);
+
+        // Non-live origin survives the round trip to & from a MarketEvent<DataKind>
+        for origin in [Origin::Backfill, Origin::SnapshotReplay] {
+            let event = VersionedMarketEvent {
+                origin,
+                ..trade_event()
+            };
+            let json = serde_json::to_string(&event).unwrap();
+            let actual = MarketEvent::<DataKind>::from(
+                serde_json::from_str::<VersionedMarketEvent>(&json).unwrap(),
+            );
+            assert_eq!(actual.origin, origin);
+            assert_eq!(VersionedMarketEvent::from(actual), event);
+        }
+    }
+
+    #[test]
+    fn test_de_schema_version() {
+        struct TestCase {
+            input: u32,
+            expected_ok: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: current version is supported
+                input: SCHEMA_VERSION,
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: newer version is rejected
+                input: SCHEMA_VERSION + 1,
+                expected_ok: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut event = serde_json::to_value(trade_event()).unwrap();
+            event["schema_version"] = json!(test.input);
+            let actual = serde_json::from_value::<VersionedMarketEvent>(event);
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let schema = json_schema();
+
+        // Every EventKind variant has a definition
+        let kinds = schema["properties"]["kind"]["oneOf"].as_array().unwrap();
+        assert_eq!(kinds.len(), 8);
+        for kind in kinds {
+            let reference = kind["properties"]["data"]["$ref"].as_str().unwrap();
+            let definition = reference.trim_start_matches("#/$defs/");
+            assert!(schema["$defs"][definition].is_object(), "{definition}");
+        }
+
+        // Serialised trade fields match the PublicTrade definition
+        let event = serde_json::to_value(trade_event()).unwrap();
+        let trade = event["kind"]["data"].as_object().unwrap();
+        let properties = schema["$defs"]["PublicTrade"]["properties"]
+            .as_object()
+            .unwrap();
+        assert!(trade.keys().all(|field| properties.contains_key(field)));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    exchange::{Connector, ExchangeId},
+    normalise::AssetNormaliser,
+};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Trading rules of an exchange [`Instrument`], used to round & validate prices and amounts.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentSpec {
+    /// Minimum price increment.
+    pub tick_size: f64,
+    /// Minimum amount increment.
+    pub lot_size: f64,
+    /// Amount of the base asset one contract is worth (1.0 for spot [`Instrument`]s).
+    pub contract_multiplier: f64,
+}
+
+impl InstrumentSpec {
+    /// Round a price to the nearest multiple of the [`Self::tick_size`].
+    pub fn round_price(&self, price: f64) -> f64 {
+        round_to(price, self.tick_size)
+    }
+
+    /// Round an amount down to a multiple of the [`Self::lot_size`].
+    pub fn round_amount(&self, amount: f64) -> f64 {
+        match self.lot_size > 0.0 {
+            true => (amount / self.lot_size + f64::EPSILON).floor() * self.lot_size,
+            false => amount,
+        }
+    }
+
+    /// Determine if a price is a multiple of the [`Self::tick_size`].
+    pub fn is_valid_price(&self, price: f64) -> bool {
+        is_multiple(price, self.tick_size)
+    }
+
+    /// Determine if an amount is a multiple of the [`Self::lot_size`].
+    pub fn is_valid_amount(&self, amount: f64) -> bool {
+        is_multiple(amount, self.lot_size)
+    }
+}
+
+/// Round a value to the nearest multiple of the provided increment.
+fn round_to(value: f64, increment: f64) -> f64 {
+    match increment > 0.0 {
+        true => (value / increment).round() * increment,
+        false => value,
+    }
+}
+
+/// Determine if a value is a multiple of the provided increment, within floating point error.
+fn is_multiple(value: f64, increment: f64) -> bool {
+    increment <= 0.0 || ((value / increment) - (value / increment).round()).abs() < 1e-6
+}
+
+/// Exchange [`Connector`] that serves the [`InstrumentSpec`]s of it's markets via HTTP.
+#[async_trait]
+pub trait InstrumentSpecs
+where
+    Self: Connector,
+{
+    /// Fetch the [`InstrumentSpec`] of each provided [`Instrument`] listed by the exchange.
+    /// [`Instrument`]s that are not listed are omitted.
+    async fn instrument_specs(
+        instruments: &[Instrument],
+    ) -> Result<Vec<(Instrument, InstrumentSpec)>, SocketError>;
+}
+
+/// Lookup of the [`InstrumentSpec`] of each exchange [`Instrument`], so consumers can round &
+/// validate [`MarketEvent<T>`](crate::event::MarketEvent)s without separate REST plumbing.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::{binance::spot::BinanceSpot, ExchangeId},
+///     spec::InstrumentSpecMap,
+/// };
+/// use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+///
+///     let mut specs = InstrumentSpecMap::default();
+///     specs
+///         .fetch::<BinanceSpot>(&[btc_usdt.clone()])
+///         .await
+///         .unwrap();
+///
+///     let spec = specs.get(ExchangeId::BinanceSpot, &btc_usdt).unwrap();
+///     println!("tick size: {}", spec.tick_size);
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct InstrumentSpecMap(pub HashMap<(ExchangeId, Instrument), InstrumentSpec>);
+
+impl InstrumentSpecMap {
+    /// Insert (or replace) the [`InstrumentSpec`] of an exchange [`Instrument`].
+    pub fn insert(&mut self, exchange: ExchangeId, instrument: Instrument, spec: InstrumentSpec) {
+        self.0.insert((exchange, instrument), spec);
+    }
+
+    /// Fetch & insert the exchange [`InstrumentSpecs`] of the provided [`Instrument`]s.
+    pub async fn fetch<E>(&mut self, instruments: &[Instrument]) -> Result<(), SocketError>
+    where
+        E: InstrumentSpecs,
+    {
+        for (instrument, spec) in E::instrument_specs(instruments).await? {
+            self.insert(E::ID, instrument, spec);
+        }
+        Ok(())
+    }
+
+    /// Get the [`InstrumentSpec`] of an exchange [`Instrument`].
+    pub fn get(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<&InstrumentSpec> {
+        self.0.get(&(exchange, instrument.clone()))
+    }
+
+    /// Add the contract multiplier of every contract [`InstrumentSpec`] to the provided
+    /// [`AssetNormaliser`].
+    pub fn contract_multipliers(&self, normaliser: AssetNormaliser) -> AssetNormaliser {
+        self.0
+            .iter()
+            .filter(|(_, spec)| spec.contract_multiplier != 1.0)
+            .fold(normaliser, |normaliser, ((exchange, instrument), spec)| {
+                normaliser.with_contract_multiplier(
+                    *exchange,
+                    instrument.clone(),
+                    spec.contract_multiplier,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instrument_spec() {
+        let spec = InstrumentSpec {
+            tick_size: 0.01,
+            lot_size: 0.001,
+            contract_multiplier: 1.0,
+        };
+
+        struct TestCase {
+            price: f64,
+            amount: f64,
+            expected_price: f64,
+            expected_amount: f64,
+            expected_valid: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid price & amount are unchanged
+                price: 100.25,
+                amount: 1.5,
+                expected_price: 100.25,
+                expected_amount: 1.5,
+                expected_valid: true,
+            },
+            TestCase {
+                // TC1: price rounded to nearest tick & amount rounded down to lot
+                price: 100.256,
+                amount: 1.5009,
+                expected_price: 100.26,
+                expected_amount: 1.5,
+                expected_valid: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert!(
+                (spec.round_price(test.price) - test.expected_price).abs() < 1e-9,
+                "TC{} failed",
+                index
+            );
+            assert!(
+                (spec.round_amount(test.amount) - test.expected_amount).abs() < 1e-9,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                spec.is_valid_price(test.price) && spec.is_valid_amount(test.amount),
+                test.expected_valid,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
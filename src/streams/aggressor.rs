@@ -0,0 +1,174 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::{book::OrderBookL1, trade::PublicTrade},
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use std::collections::HashMap;
+
+/// Stage that infers the aggressor [`Side`] of [`PublicTrade`]s using the quote rule, falling back
+/// to the tick rule (ie/ Lee-Ready classification).
+///
+/// A trade at or above the prevailing best ask, or above the mid price, is classified as a buy,
+/// and vice versa for sells. Trades at the mid price (or before any [`OrderBookL1`] has been
+/// observed) are classified by comparing their price with the previous trade. Inferred trades are
+/// flagged via [`PublicTrade::side_inferred`], and trades that cannot be classified retain the
+/// exchange provided side.
+///
+/// Apply this stage to a combined [`MarketEvent<DataKind>`] stream containing both the
+/// [`PublicTrade`]s and [`OrderBookL1`]s of the affected instruments.
+#[derive(Debug, Default)]
+pub struct AggressorInference {
+    states: HashMap<(Exchange, Instrument), InferenceState>,
+}
+
+/// Prevailing quote & previous trade of an exchange [`Instrument`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+struct InferenceState {
+    quote: Option<(f64, f64)>,
+    last_trade: Option<(f64, Side)>,
+}
+
+impl AggressorInference {
+    /// Construct a new [`Self`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process a [`MarketEvent<DataKind>`], updating the prevailing quote with any
+    /// [`OrderBookL1`], and inferring the aggressor [`Side`] of any [`PublicTrade`].
+    pub fn process(&mut self, event: &mut MarketEvent<DataKind>) {
+        match &mut event.kind {
+            DataKind::OrderBookL1(book) => {
+                self.update_quote(&event.exchange, &event.instrument, book)
+            }
+            DataKind::Trade(trade) => self.infer(&event.exchange, &event.instrument, trade),
+            _ => {}
+        }
+    }
+
+    /// Update the prevailing quote of an exchange [`Instrument`] using a
+    /// [`MarketEvent<OrderBookL1>`].
+    pub fn process_book(&mut self, event: &MarketEvent<OrderBookL1>) {
+        self.update_quote(&event.exchange, &event.instrument, &event.kind)
+    }
+
+    /// Infer the aggressor [`Side`] of a [`MarketEvent<PublicTrade>`].
+    pub fn process_trade(&mut self, event: &mut MarketEvent<PublicTrade>) {
+        self.infer(&event.exchange, &event.instrument, &mut event.kind)
+    }
+
+    fn update_quote(&mut self, exchange: &Exchange, instrument: &Instrument, book: &OrderBookL1) {
+        self.state(exchange, instrument).quote = Some((book.best_bid.price, book.best_ask.price));
+    }
+
+    fn infer(&mut self, exchange: &Exchange, instrument: &Instrument, trade: &mut PublicTrade) {
+        let state = self.state(exchange, instrument);
+
+        if let Some(side) = classify(trade.price, state.quote, state.last_trade) {
+            trade.side = side;
+            trade.side_inferred = true;
+        }
+
+        state.last_trade = Some((trade.price, trade.side));
+    }
+
+    fn state(&mut self, exchange: &Exchange, instrument: &Instrument) -> &mut InferenceState {
+        self.states
+            .entry((exchange.clone(), instrument.clone()))
+            .or_default()
+    }
+}
+
+/// Classify the aggressor [`Side`] of a trade at the provided price using the prevailing
+/// `(best_bid, best_ask)` quote, falling back to the tick rule using the previous trade.
+fn classify(
+    price: f64,
+    quote: Option<(f64, f64)>,
+    last_trade: Option<(f64, Side)>,
+) -> Option<Side> {
+    if let Some((best_bid, best_ask)) = quote {
+        let mid_price = (best_bid + best_ask) / 2.0;
+        if price >= best_ask || price > mid_price {
+            return Some(Side::Buy);
+        }
+        if price <= best_bid || price < mid_price {
+            return Some(Side::Sell);
+        }
+    }
+
+    // Tick rule: zero ticks inherit the side of the previous trade
+    last_trade.map(|(last_price, last_side)| {
+        if price > last_price {
+            Side::Buy
+        } else if price < last_price {
+            Side::Sell
+        } else {
+            last_side
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        struct TestCase {
+            price: f64,
+            quote: Option<(f64, f64)>,
+            last_trade: Option<(f64, Side)>,
+            expected: Option<Side>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trade at best ask is a buy
+                price: 101.0,
+                quote: Some((100.0, 101.0)),
+                last_trade: None,
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC1: trade at best bid is a sell
+                price: 100.0,
+                quote: Some((100.0, 101.0)),
+                last_trade: None,
+                expected: Some(Side::Sell),
+            },
+            TestCase {
+                // TC2: trade inside the spread above mid price is a buy
+                price: 100.75,
+                quote: Some((100.0, 101.0)),
+                last_trade: None,
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC3: trade at mid price falls back to the tick rule uptick
+                price: 100.5,
+                quote: Some((100.0, 101.0)),
+                last_trade: Some((100.25, Side::Sell)),
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC4: zero tick without a quote inherits the previous side
+                price: 100.5,
+                quote: None,
+                last_trade: Some((100.5, Side::Sell)),
+                expected: Some(Side::Sell),
+            },
+            TestCase {
+                // TC5: no quote or previous trade cannot be classified
+                price: 100.5,
+                quote: None,
+                last_trade: None,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = classify(test.price, test.quote, test.last_trade);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
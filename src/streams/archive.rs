@@ -0,0 +1,480 @@
+use super::tap::{RawMessage, RawPayload};
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+};
+use tokio::{sync::broadcast, task::JoinHandle};
+use tracing::{debug, warn};
+
+/// Default uncompressed size of [`RawMessage`] records after which an [`ArchiveWriter`] rolls
+/// over to a new segment file.
+pub const DEFAULT_MAX_SEGMENT_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Default zstd compression level used by an [`ArchiveWriter`].
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+
+/// Name of the archive index file, containing one JSON [`SegmentIndex`] line per finished
+/// segment.
+pub const INDEX_FILE_NAME: &str = "index.jsonl";
+
+/// Archive record payload kind tag of a [`RawPayload::Text`].
+const PAYLOAD_TEXT: u8 = 0;
+
+/// Archive record payload kind tag of a [`RawPayload::Binary`].
+const PAYLOAD_BINARY: u8 = 1;
+
+/// Configuration of an [`ArchiveWriter`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ArchiveConfig {
+    /// Uncompressed size of records after which the active segment is finished.
+    pub max_segment_bytes: u64,
+    /// zstd compression level of each segment.
+    pub compression_level: i32,
+}
+
+impl Default for ArchiveConfig {
+    fn default() -> Self {
+        Self {
+            max_segment_bytes: DEFAULT_MAX_SEGMENT_BYTES,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+}
+
+impl ArchiveConfig {
+    /// Roll over to a new segment after the provided uncompressed size of records.
+    pub fn with_max_segment_bytes(mut self, max_segment_bytes: u64) -> Self {
+        self.max_segment_bytes = max_segment_bytes;
+        self
+    }
+
+    /// Compress segments with the provided zstd compression level.
+    pub fn with_compression_level(mut self, compression_level: i32) -> Self {
+        self.compression_level = compression_level;
+        self
+    }
+}
+
+/// Index entry of a finished archive segment, used to locate the segments covering a period
+/// of time for replay.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SegmentIndex {
+    /// Segment file name, relative to the archive directory.
+    pub file: String,
+    pub records: u64,
+    /// Uncompressed size of the segment records.
+    pub bytes: u64,
+    pub first_received_time: DateTime<Utc>,
+    pub last_received_time: DateTime<Utc>,
+}
+
+/// Writer that archives the [`RawMessage`]s of a [`RawTap`](super::tap::RawTap) into a
+/// directory of zstd compressed segment files, along with an [`INDEX_FILE_NAME`] index.
+///
+/// Each segment is a single zstd stream of length-prefixed records, preserving every raw frame
+/// exactly for replay (see [`read_segment`]). A record is encoded (little endian) as:
+/// - `u32` length of the remainder of the record.
+/// - `i64` received time in nanoseconds since the Unix epoch.
+/// - `u8` [`ExchangeId`] length, followed by the [`ExchangeId::as_str`] bytes.
+/// - `u8` payload kind (`0` text, `1` binary), followed by the payload bytes.
+///
+/// Segments are only added to the index once finished, so a segment that is missing from the
+/// index (eg/ after a crash) may be truncated.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::streams::{
+///     archive::{ArchiveConfig, ArchiveWriter},
+///     config::StreamConfig,
+///     tap::{RawTap, DEFAULT_RAW_TAP_CAPACITY},
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let tap = RawTap::new(DEFAULT_RAW_TAP_CAPACITY);
+///
+///     let archive = ArchiveWriter::new("./archive", ArchiveConfig::default())
+///         .unwrap()
+///         .spawn(tap.subscribe());
+///
+///     let config = StreamConfig::default().with_raw_tap(tap);
+/// }
+/// ```
+pub struct ArchiveWriter {
+    dir: PathBuf,
+    config: ArchiveConfig,
+    next_segment: u64,
+    segment: Option<ActiveSegment>,
+}
+
+impl std::fmt::Debug for ArchiveWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArchiveWriter")
+            .field("dir", &self.dir)
+            .field("config", &self.config)
+            .field("next_segment", &self.next_segment)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Segment currently being written by an [`ArchiveWriter`].
+struct ActiveSegment {
+    encoder: zstd::Encoder<'static, BufWriter<File>>,
+    index: SegmentIndex,
+}
+
+impl ArchiveWriter {
+    /// Construct a new [`Self`] that archives to the provided directory, creating it if
+    /// required. Segment numbering continues after any segments already in the index.
+    pub fn new<P>(dir: P, config: ArchiveConfig) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+
+        let next_segment = read_index(&dir)?.len() as u64;
+
+        Ok(Self {
+            dir,
+            config,
+            next_segment,
+            segment: None,
+        })
+    }
+
+    /// Append a [`RawMessage`] record to the active segment, finishing the segment once it
+    /// exceeds the [`ArchiveConfig::max_segment_bytes`].
+    pub fn write(&mut self, message: &RawMessage) -> io::Result<()> {
+        let record = encode_record(message)?;
+
+        let segment = match self.segment.as_mut() {
+            Some(segment) => segment,
+            None => {
+                let segment = self.open_segment(message.received_time)?;
+                self.segment.insert(segment)
+            }
+        };
+
+        segment.encoder.write_all(&record)?;
+        segment.index.records += 1;
+        segment.index.bytes += record.len() as u64;
+        segment.index.last_received_time = message.received_time;
+
+        if segment.index.bytes >= self.config.max_segment_bytes {
+            self.finish_segment()?;
+        }
+
+        Ok(())
+    }
+
+    /// Finish the active segment (if any), adding it to the index.
+    pub fn finish_segment(&mut self) -> io::Result<()> {
+        let Some(ActiveSegment { encoder, index }) = self.segment.take() else {
+            return Ok(());
+        };
+
+        encoder.finish()?.flush()?;
+
+        let mut index_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(INDEX_FILE_NAME))?;
+        let mut line = serde_json::to_vec(&index)?;
+        line.push(b'\n');
+        index_file.write_all(&line)?;
+
+        debug!(file = %index.file, records = index.records, "finished archive segment");
+        Ok(())
+    }
+
+    /// Spawn a blocking task that archives every [`RawMessage`] received from the
+    /// `raw_rx` until every [`RawTap`](super::tap::RawTap) has been dropped, finishing the
+    /// active segment before returning.
+    ///
+    /// [`RawMessage`]s missed due to lagging behind the [`RawTap`](super::tap::RawTap) are
+    /// logged rather than treated as an error.
+    pub fn spawn(
+        mut self,
+        mut raw_rx: broadcast::Receiver<RawMessage>,
+    ) -> JoinHandle<io::Result<()>> {
+        tokio::task::spawn_blocking(move || {
+            loop {
+                match raw_rx.blocking_recv() {
+                    Ok(message) => self.write(&message)?,
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        warn!(
+                            missed,
+                            "archive lagged behind RawTap - RawMessages not archived"
+                        );
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+
+            self.finish_segment()
+        })
+    }
+
+    fn open_segment(&mut self, received_time: DateTime<Utc>) -> io::Result<ActiveSegment> {
+        let file = format!("segment-{:08}.zst", self.next_segment);
+        self.next_segment += 1;
+
+        let writer = BufWriter::new(File::create(self.dir.join(&file))?);
+        let encoder = zstd::Encoder::new(writer, self.config.compression_level)?;
+
+        Ok(ActiveSegment {
+            encoder,
+            index: SegmentIndex {
+                file,
+                records: 0,
+                bytes: 0,
+                first_received_time: received_time,
+                last_received_time: received_time,
+            },
+        })
+    }
+}
+
+impl Drop for ArchiveWriter {
+    fn drop(&mut self) {
+        if let Err(error) = self.finish_segment() {
+            warn!(%error, "failed to finish archive segment");
+        }
+    }
+}
+
+/// Read the [`SegmentIndex`] of every finished segment in the archive directory, in the order
+/// they were written.
+pub fn read_index<P>(dir: P) -> io::Result<Vec<SegmentIndex>>
+where
+    P: AsRef<Path>,
+{
+    let file = match File::open(dir.as_ref().join(INDEX_FILE_NAME)) {
+        Ok(file) => file,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error),
+    };
+
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.is_empty()))
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
+
+/// Open an archive segment file, returning an [`Iterator`] over it's [`RawMessage`] records.
+pub fn read_segment<P>(path: P) -> io::Result<SegmentReader>
+where
+    P: AsRef<Path>,
+{
+    let decoder = zstd::Decoder::new(File::open(path)?)?;
+    Ok(SegmentReader { decoder })
+}
+
+/// [`Iterator`] over the [`RawMessage`] records of an archive segment.
+pub struct SegmentReader {
+    decoder: zstd::Decoder<'static, BufReader<File>>,
+}
+
+impl std::fmt::Debug for SegmentReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SegmentReader").finish_non_exhaustive()
+    }
+}
+
+impl Iterator for SegmentReader {
+    type Item = io::Result<RawMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut length = [0; 4];
+        match self.decoder.read_exact(&mut length) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return None,
+            Err(error) => return Some(Err(error)),
+        }
+
+        let mut record = vec![0; u32::from_le_bytes(length) as usize];
+        Some(
+            self.decoder
+                .read_exact(&mut record)
+                .and_then(|()| decode_record(&record)),
+        )
+    }
+}
+
+/// Encode a [`RawMessage`] into a length-prefixed archive record.
+fn encode_record(message: &RawMessage) -> io::Result<Vec<u8>> {
+    let received_time = message.received_time.timestamp_nanos_opt().ok_or_else(|| {
+        io::Error::new(
+            ErrorKind::InvalidInput,
+            "received_time out of nanosecond range",
+        )
+    })?;
+    let exchange = message.exchange.as_str().as_bytes();
+    let (kind, payload) = match &message.payload {
+        RawPayload::Text(text) => (PAYLOAD_TEXT, text.as_bytes()),
+        RawPayload::Binary(bytes) => (PAYLOAD_BINARY, bytes.as_slice()),
+    };
+
+    let length = u32::try_from(8 + 1 + exchange.len() + 1 + payload.len())
+        .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "RawMessage too large to archive"))?;
+
+    let mut record = Vec::with_capacity(4 + length as usize);
+    record.extend_from_slice(&length.to_le_bytes());
+    record.extend_from_slice(&received_time.to_le_bytes());
+    record.push(exchange.len() as u8);
+    record.extend_from_slice(exchange);
+    record.push(kind);
+    record.extend_from_slice(payload);
+    Ok(record)
+}
+
+/// Decode an archive record (excluding it's length prefix) into a [`RawMessage`].
+fn decode_record(record: &[u8]) -> io::Result<RawMessage> {
+    let invalid = |why: &str| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid archive record: {why}"),
+        )
+    };
+
+    let (received_time, rest) = record
+        .split_first_chunk::<8>()
+        .ok_or_else(|| invalid("missing received_time"))?;
+    let received_time = DateTime::from_timestamp_nanos(i64::from_le_bytes(*received_time));
+
+    let (exchange_len, rest) = rest
+        .split_first()
+        .ok_or_else(|| invalid("missing exchange"))?;
+    if rest.len() <= *exchange_len as usize {
+        return Err(invalid("truncated exchange"));
+    }
+    let (exchange, rest) = rest.split_at(*exchange_len as usize);
+    let exchange = std::str::from_utf8(exchange)
+        .ok()
+        .and_then(|exchange| {
+            serde_json::from_value::<ExchangeId>(serde_json::Value::String(exchange.to_owned()))
+                .ok()
+        })
+        .ok_or_else(|| invalid("unknown exchange"))?;
+
+    let (kind, payload) = rest
+        .split_first()
+        .ok_or_else(|| invalid("missing payload kind"))?;
+    let payload = match *kind {
+        PAYLOAD_TEXT => RawPayload::Text(
+            String::from_utf8(payload.to_vec()).map_err(|_| invalid("non utf-8 text payload"))?,
+        ),
+        PAYLOAD_BINARY => RawPayload::Binary(payload.to_vec()),
+        _ => return Err(invalid("unknown payload kind")),
+    };
+
+    Ok(RawMessage {
+        exchange,
+        received_time,
+        payload,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(nanos: i64, payload: RawPayload) -> RawMessage {
+        RawMessage {
+            exchange: ExchangeId::BinanceFuturesUsd,
+            received_time: DateTime::from_timestamp_nanos(nanos),
+            payload,
+        }
+    }
+
+    #[test]
+    fn test_archive_round_trip() {
+        let dir = std::env::temp_dir().join(format!("barter-data-archive-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let messages = vec![
+            message(1, RawPayload::Text("{\"e\":\"trade\"}".to_owned())),
+            message(2, RawPayload::Binary(vec![0, 1, 2])),
+            message(3, RawPayload::Text(String::new())),
+        ];
+
+        // Roll over to a new segment after every second record
+        let first_record_len = encode_record(&messages[0]).unwrap().len() as u64;
+        let config = ArchiveConfig::default().with_max_segment_bytes(first_record_len + 1);
+
+        let mut writer = ArchiveWriter::new(&dir, config).unwrap();
+        for message in &messages {
+            writer.write(message).unwrap();
+        }
+        drop(writer);
+
+        let index = read_index(&dir).unwrap();
+        assert_eq!(
+            index
+                .iter()
+                .map(|segment| (segment.file.as_str(), segment.records))
+                .collect::<Vec<_>>(),
+            vec![("segment-00000000.zst", 2), ("segment-00000001.zst", 1)]
+        );
+        assert_eq!(index[1].first_received_time, messages[2].received_time);
+
+        let actual = index
+            .iter()
+            .flat_map(|segment| read_segment(dir.join(&segment.file)).unwrap())
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(actual, messages);
+
+        // Re-opened ArchiveWriter continues segment numbering
+        let mut writer = ArchiveWriter::new(&dir, config).unwrap();
+        writer.write(&messages[0]).unwrap();
+        writer.finish_segment().unwrap();
+        assert_eq!(read_index(&dir).unwrap()[2].file, "segment-00000002.zst");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_decode_record() {
+        struct TestCase {
+            input: Vec<u8>,
+            expected: Result<RawMessage, ()>,
+        }
+
+        let valid = message(1_700_000_000_000_000_000, RawPayload::Binary(vec![9]));
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid record
+                input: encode_record(&valid).unwrap()[4..].to_vec(),
+                expected: Ok(valid.clone()),
+            },
+            TestCase {
+                // TC1: truncated record
+                input: encode_record(&valid).unwrap()[4..10].to_vec(),
+                expected: Err(()),
+            },
+            TestCase {
+                // TC2: unknown payload kind
+                input: {
+                    let mut record = encode_record(&valid).unwrap()[4..].to_vec();
+                    let kind = record.len() - 2;
+                    record[kind] = 9;
+                    record
+                },
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = decode_record(&test.input).map_err(|_| ());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{exchange::ExchangeId, subscription::Map};
+use barter_integration::{model::instrument::Instrument, protocol::websocket::WsMessage};
+use chrono::{DateTime, Utc};
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+/// Opt-in accounting of the bytes received by every exchange connection configured with it, for
+/// capacity planning.
+///
+/// A [`BandwidthMeter`] is attached to connections via
+/// [`StreamConfig::with_bandwidth_meter`](super::config::StreamConfig::with_bandwidth_meter), and
+/// can be shared across many connections. Each established connection registers a
+/// [`ConnectionMeter`], so re-connections appear as new connections.
+///
+/// ### Notes
+/// - Bytes are the WebSocket frame payload lengths, excluding WebSocket framing & TLS overhead.
+/// - Per subscription bytes are approximate: each text or binary frame is attributed to the first
+///   subscribed [`Instrument`] whose exchange market identifier (eg/ "BTCUSDT") appears in the
+///   payload, so subscriptions of different [`SubKind`](crate::subscription::SubKind)s to the
+///   same [`Instrument`] are attributed together. Frames that match no market (eg/ subscription
+///   responses & pongs) are only attributed to the connection.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{bandwidth::BandwidthMeter, config::StreamConfig};
+///
+/// let meter = BandwidthMeter::default();
+/// let config = StreamConfig::default().with_bandwidth_meter(meter.clone());
+///
+/// // Later, eg/ periodically for capacity planning
+/// for connection in meter.connections() {
+///     println!("{} {}: {} bytes", connection.exchange, connection.id, connection.bytes);
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BandwidthMeter {
+    connections: Arc<Mutex<Vec<Arc<ConnectionBandwidth>>>>,
+}
+
+impl BandwidthMeter {
+    /// Register a new exchange connection serving the subscriptions of the provided
+    /// [`Map<Instrument>`], returning the [`ConnectionMeter`] that records it's received frames.
+    pub fn register(&self, exchange: ExchangeId, map: &Map<Instrument>) -> ConnectionMeter {
+        let mut subscriptions = map
+            .0
+            .iter()
+            .filter_map(|(subscription_id, instrument)| {
+                // SubscriptionIds are formatted "{channel}|{market}"
+                let market = subscription_id
+                    .as_ref()
+                    .rsplit_once('|')
+                    .map(|(_, market)| market)
+                    .unwrap_or_else(|| subscription_id.as_ref());
+
+                (!market.is_empty()).then(|| SubscriptionBandwidth {
+                    market: market.as_bytes().to_vec(),
+                    instrument: instrument.clone(),
+                    bytes: AtomicU64::new(0),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Longest markets first, so markets that prefix another (eg/ "BTCUSD" & "BTCUSDT") are
+        // not attributed the other market's frames
+        subscriptions.sort_by(|a, b| {
+            b.market
+                .len()
+                .cmp(&a.market.len())
+                .then_with(|| a.market.cmp(&b.market))
+        });
+        subscriptions.dedup_by(|a, b| a.instrument == b.instrument);
+
+        let mut connections = self
+            .connections
+            .lock()
+            .expect("BandwidthMeter lock poisoned");
+        let connection = Arc::new(ConnectionBandwidth {
+            id: connections.len() as u64,
+            exchange,
+            connected_time: Utc::now(),
+            active: AtomicBool::new(true),
+            frames: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            subscriptions,
+        });
+        connections.push(Arc::clone(&connection));
+
+        ConnectionMeter(connection)
+    }
+
+    /// Snapshot the [`ConnectionUsage`] of every connection registered with this meter, in the
+    /// order they were established.
+    pub fn connections(&self) -> Vec<ConnectionUsage> {
+        self.connections
+            .lock()
+            .expect("BandwidthMeter lock poisoned")
+            .iter()
+            .map(|connection| connection.usage())
+            .collect()
+    }
+
+    /// Total bytes received by every connection registered with this meter.
+    pub fn total_bytes(&self) -> u64 {
+        self.connections
+            .lock()
+            .expect("BandwidthMeter lock poisoned")
+            .iter()
+            .map(|connection| connection.bytes.load(Ordering::Relaxed))
+            .sum()
+    }
+}
+
+impl PartialEq for BandwidthMeter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.connections, &other.connections)
+    }
+}
+
+impl Eq for BandwidthMeter {}
+
+/// Shared bandwidth counters of one exchange connection.
+#[derive(Debug)]
+struct ConnectionBandwidth {
+    id: u64,
+    exchange: ExchangeId,
+    connected_time: DateTime<Utc>,
+    active: AtomicBool,
+    frames: AtomicU64,
+    bytes: AtomicU64,
+    subscriptions: Vec<SubscriptionBandwidth>,
+}
+
+impl ConnectionBandwidth {
+    fn usage(&self) -> ConnectionUsage {
+        ConnectionUsage {
+            id: self.id,
+            exchange: self.exchange,
+            connected_time: self.connected_time,
+            active: self.active.load(Ordering::Relaxed),
+            frames: self.frames.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            subscriptions: self
+                .subscriptions
+                .iter()
+                .map(|subscription| SubscriptionUsage {
+                    instrument: subscription.instrument.clone(),
+                    bytes: subscription.bytes.load(Ordering::Relaxed),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Bandwidth counter of the frames attributed to one subscribed [`Instrument`].
+#[derive(Debug)]
+struct SubscriptionBandwidth {
+    market: Vec<u8>,
+    instrument: Instrument,
+    bytes: AtomicU64,
+}
+
+/// Records the frames received by one exchange connection registered with a [`BandwidthMeter`].
+///
+/// The connection is marked inactive once the [`ConnectionMeter`] is dropped.
+#[derive(Debug)]
+pub struct ConnectionMeter(Arc<ConnectionBandwidth>);
+
+impl ConnectionMeter {
+    /// Record a [`WsMessage`] frame received by the connection.
+    pub fn record(&self, message: &WsMessage) {
+        let bytes = message.len() as u64;
+        self.0.frames.fetch_add(1, Ordering::Relaxed);
+        self.0.bytes.fetch_add(bytes, Ordering::Relaxed);
+
+        let payload = match message {
+            WsMessage::Text(text) => text.as_bytes(),
+            WsMessage::Binary(payload) => payload.as_slice(),
+            _ => return,
+        };
+
+        if let Some(subscription) = self.0.subscriptions.iter().find(|subscription| {
+            payload
+                .windows(subscription.market.len())
+                .any(|window| window == subscription.market.as_slice())
+        }) {
+            subscription.bytes.fetch_add(bytes, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Drop for ConnectionMeter {
+    fn drop(&mut self) {
+        self.0.active.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Snapshot of the bytes received by one exchange connection.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConnectionUsage {
+    /// Sequential identifier of the connection within it's [`BandwidthMeter`].
+    pub id: u64,
+    pub exchange: ExchangeId,
+    pub connected_time: DateTime<Utc>,
+    /// False once the connection has been closed (eg/ before re-connecting).
+    pub active: bool,
+    pub frames: u64,
+    pub bytes: u64,
+    pub subscriptions: Vec<SubscriptionUsage>,
+}
+
+/// Approximate bytes received by one exchange connection for a subscribed [`Instrument`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SubscriptionUsage {
+    pub instrument: Instrument,
+    pub bytes: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_connection_meter_record() {
+        let btc_usd = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let map = Map::from_iter([
+            (SubscriptionId::from("@trade|BTCUSD"), btc_usd.clone()),
+            (SubscriptionId::from("@trade|BTCUSDT"), btc_usdt.clone()),
+        ]);
+
+        let meter = BandwidthMeter::default();
+        let connection = meter.register(ExchangeId::BinanceSpot, &map);
+
+        let messages = vec![
+            // TC0: attributed to BTCUSDT rather than the BTCUSD prefix
+            WsMessage::Text(r#"{"e":"trade","s":"BTCUSDT"}"#.to_owned()),
+            // TC1: attributed to BTCUSD
+            WsMessage::Text(r#"{"e":"trade","s":"BTCUSD"}"#.to_owned()),
+            // TC2: binary frame attributed to BTCUSDT
+            WsMessage::Binary(b"\x07BTCUSDT".to_vec()),
+            // TC3: subscription response only attributed to the connection
+            WsMessage::Text(r#"{"result":null,"id":1}"#.to_owned()),
+            // TC4: control frame only attributed to the connection
+            WsMessage::Ping(vec![1, 2]),
+        ];
+        for message in &messages {
+            connection.record(message);
+        }
+
+        let usage = meter.connections();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].exchange, ExchangeId::BinanceSpot);
+        assert!(usage[0].active);
+        assert_eq!(usage[0].frames, 5);
+        assert_eq!(usage[0].bytes, 27 + 26 + 8 + 22 + 2);
+        assert_eq!(
+            usage[0].subscriptions,
+            vec![
+                SubscriptionUsage {
+                    instrument: btc_usdt,
+                    bytes: 27 + 8
+                },
+                SubscriptionUsage {
+                    instrument: btc_usd,
+                    bytes: 26
+                },
+            ]
+        );
+        assert_eq!(meter.total_bytes(), 85);
+
+        // Dropped ConnectionMeter is inactive, and new connections are registered separately
+        drop(connection);
+        let _reconnection = meter.register(ExchangeId::BinanceSpot, &map);
+        let usage = meter.connections();
+        assert!(!usage[0].active);
+        assert_eq!(usage[1].id, 1);
+        assert_eq!(usage[1].bytes, 0);
+        assert_eq!(meter.total_bytes(), 85);
+    }
+}
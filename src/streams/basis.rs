@@ -0,0 +1,363 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    funding::{FundingCalendar, DEFAULT_FUNDING_INTERVAL_HOURS},
+    subscription::funding::FundingRate,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+    Exchange,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Configuration of a [`BasisComposite`] operator.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BasisConfig {
+    /// Maximum `exchange_time` difference between the spot & perpetual legs of a [`Basis`].
+    pub max_leg_age: Duration,
+}
+
+impl Default for BasisConfig {
+    fn default() -> Self {
+        Self {
+            max_leg_age: Duration::seconds(5),
+        }
+    }
+}
+
+/// Basis between the spot price of an underlying on one exchange, and the perpetual mark price &
+/// funding of the same underlying on another (or the same) exchange.
+///
+/// The [`MarketEvent<Basis>`] exchange & [`Instrument`] identify the perpetual leg.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Basis {
+    /// Exchange of the spot leg.
+    pub spot_exchange: Exchange,
+    pub spot_price: f64,
+    /// Mark price of the perpetual leg.
+    pub perpetual_price: f64,
+    /// Fractional basis of the perpetual over spot, ie/ `(perpetual - spot) / spot`.
+    pub basis: f64,
+    /// Estimated funding rate paid at the `next_funding_time` of the perpetual.
+    pub funding_rate: f64,
+    /// Funding rate annualised using the funding interval of the perpetual.
+    pub annualised_funding_rate: f64,
+    pub next_funding_time: DateTime<Utc>,
+}
+
+/// Latest spot price of an underlying on an exchange.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct SpotLeg {
+    time: DateTime<Utc>,
+    price: f64,
+}
+
+/// Latest [`FundingRate`] of a perpetual [`Instrument`] on an exchange.
+#[derive(Clone, PartialEq, Debug)]
+struct PerpetualLeg {
+    time: DateTime<Utc>,
+    instrument: Instrument,
+    funding: FundingRate,
+}
+
+/// Composite operator that joins the spot [`PublicTrade`](crate::subscription::trade::PublicTrade)s
+/// & [`OrderBookL1`](crate::subscription::book::OrderBookL1)s of an underlying with the perpetual
+/// [`FundingRate`]s of the same underlying across exchanges, emitting annualised [`Basis`]
+/// events.
+///
+/// Legs are joined by the (base, quote) of their [`Instrument`], so exchange specific asset codes
+/// should be normalised beforehand (see [`AssetNormaliser`](crate::normalise::AssetNormaliser)).
+/// Spot prices are the L1 mid price, or the last trade price.
+#[derive(Debug, Default)]
+pub struct BasisComposite {
+    config: BasisConfig,
+    calendar: FundingCalendar,
+    spots: HashMap<(Symbol, Symbol), HashMap<Exchange, SpotLeg>>,
+    perpetuals: HashMap<(Symbol, Symbol), HashMap<Exchange, PerpetualLeg>>,
+}
+
+impl BasisComposite {
+    /// Construct a new [`Self`] using the provided [`BasisConfig`].
+    pub fn new(config: BasisConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Use the provided [`FundingCalendar`] (eg/ fetched via
+    /// [`FundingCalendar::fetch`]) to annualise funding rates, rather than inferring funding
+    /// intervals from the received [`FundingRate`]s.
+    pub fn with_calendar(self, calendar: FundingCalendar) -> Self {
+        Self { calendar, ..self }
+    }
+
+    /// Process a spot [`MarketEvent<DataKind>`], returning the [`Basis`] against every recent
+    /// perpetual leg of the same underlying.
+    ///
+    /// Events of non-spot [`Instrument`]s, or of kinds without a price, are ignored.
+    pub fn process_spot(&mut self, event: &MarketEvent<DataKind>) -> Vec<MarketEvent<Basis>> {
+        let price = match &event.kind {
+            _ if event.instrument.kind != InstrumentKind::Spot => return Vec::new(),
+            DataKind::Trade(trade) => trade.price,
+            DataKind::OrderBookL1(book) => book.mid_price(),
+            _ => return Vec::new(),
+        };
+
+        let spot = SpotLeg {
+            time: event.exchange_time,
+            price,
+        };
+        let underlying = underlying(&event.instrument);
+        self.spots
+            .entry(underlying.clone())
+            .or_default()
+            .insert(event.exchange.clone(), spot);
+
+        let Some(perpetuals) = self.perpetuals.get(&underlying) else {
+            return Vec::new();
+        };
+
+        perpetuals
+            .iter()
+            .filter(|(_, perpetual)| self.is_recent(spot.time, perpetual.time))
+            .map(|(perpetual_exchange, perpetual)| {
+                self.basis(event, &event.exchange, spot, perpetual_exchange, perpetual)
+            })
+            .collect()
+    }
+
+    /// Process a perpetual [`MarketEvent<FundingRate>`], returning the [`Basis`] against every
+    /// recent spot leg of the same underlying.
+    pub fn process_funding(&mut self, event: &MarketEvent<FundingRate>) -> Vec<MarketEvent<Basis>> {
+        self.calendar.update(event);
+
+        let perpetual = PerpetualLeg {
+            time: event.exchange_time,
+            instrument: event.instrument.clone(),
+            funding: event.kind,
+        };
+        let underlying = underlying(&event.instrument);
+        self.perpetuals
+            .entry(underlying.clone())
+            .or_default()
+            .insert(event.exchange.clone(), perpetual.clone());
+
+        let Some(spots) = self.spots.get(&underlying) else {
+            return Vec::new();
+        };
+
+        spots
+            .iter()
+            .filter(|(_, spot)| self.is_recent(spot.time, perpetual.time))
+            .map(|(spot_exchange, spot)| {
+                self.basis(event, spot_exchange, *spot, &event.exchange, &perpetual)
+            })
+            .collect()
+    }
+
+    fn is_recent(&self, spot: DateTime<Utc>, perpetual: DateTime<Utc>) -> bool {
+        (spot - perpetual).abs() <= self.config.max_leg_age
+    }
+
+    fn basis<T>(
+        &self,
+        trigger: &MarketEvent<T>,
+        spot_exchange: &Exchange,
+        spot: SpotLeg,
+        perpetual_exchange: &Exchange,
+        perpetual: &PerpetualLeg,
+    ) -> MarketEvent<Basis> {
+        let interval = self
+            .calendar
+            .0
+            .get(&(perpetual_exchange.clone(), perpetual.instrument.clone()))
+            .map(|schedule| schedule.interval)
+            .unwrap_or_else(|| Duration::hours(DEFAULT_FUNDING_INTERVAL_HOURS));
+
+        let FundingRate {
+            rate,
+            mark_price,
+            next_funding_time,
+        } = perpetual.funding;
+
+        MarketEvent {
+            exchange_time: spot.time.max(perpetual.time),
+            received_time: trigger.received_time,
+            received_monotonic: trigger.received_monotonic,
+            origin: trigger.origin,
+            exchange: perpetual_exchange.clone(),
+            instrument: perpetual.instrument.clone(),
+            kind: Basis {
+                spot_exchange: spot_exchange.clone(),
+                spot_price: spot.price,
+                perpetual_price: mark_price,
+                basis: (mark_price - spot.price) / spot.price,
+                funding_rate: rate,
+                annualised_funding_rate: annualise(rate, interval),
+                next_funding_time,
+            },
+        }
+    }
+}
+
+/// (base, quote) underlying of an [`Instrument`], used to join spot & perpetual legs.
+fn underlying(instrument: &Instrument) -> (Symbol, Symbol) {
+    (instrument.base.clone(), instrument.quote.clone())
+}
+
+/// Annualise a rate paid every `interval`.
+fn annualise(rate: f64, interval: Duration) -> f64 {
+    match interval.num_seconds() {
+        seconds if seconds > 0 => rate * Duration::days(365).num_seconds() as f64 / seconds as f64,
+        _ => 0.0,
+    }
+}
+
+/// Join the spot [`MarketEvent<DataKind>`]s received on the `spot_rx` with the perpetual
+/// [`MarketEvent<FundingRate>`]s received on the `funding_rx` using a [`BasisComposite`],
+/// forwarding every [`MarketEvent<Basis>`] to the `basis_tx`.
+///
+/// Runs until both receivers are exhausted or the `basis_tx` receiver has been dropped.
+pub async fn basis(
+    mut spot_rx: mpsc::UnboundedReceiver<MarketEvent<DataKind>>,
+    mut funding_rx: mpsc::UnboundedReceiver<MarketEvent<FundingRate>>,
+    basis_tx: mpsc::UnboundedSender<MarketEvent<Basis>>,
+    mut composite: BasisComposite,
+) {
+    let (mut spot_open, mut funding_open) = (true, true);
+
+    while spot_open || funding_open {
+        let basis = tokio::select! {
+            event = spot_rx.recv(), if spot_open => match event {
+                Some(event) => composite.process_spot(&event),
+                None => {
+                    spot_open = false;
+                    continue;
+                }
+            },
+            event = funding_rx.recv(), if funding_open => match event {
+                Some(event) => composite.process_funding(&event),
+                None => {
+                    funding_open = false;
+                    continue;
+                }
+            },
+        };
+
+        for basis in basis {
+            if basis_tx.send(basis).is_err() {
+                debug!("Basis receiver dropped - stopping basis composite");
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixture::{self, market_event},
+        subscription::book::{Level, OrderBookL1},
+    };
+    use chrono::TimeZone;
+
+    fn time(second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, second).unwrap()
+    }
+
+    fn event<T>(
+        exchange: &'static str,
+        kind: InstrumentKind,
+        second: u32,
+        data: T,
+    ) -> MarketEvent<T> {
+        MarketEvent {
+            exchange_time: time(second),
+            received_time: time(second),
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", kind)),
+            ..market_event(data)
+        }
+    }
+
+    fn spot_trade(exchange: &'static str, second: u32, price: f64) -> MarketEvent<DataKind> {
+        event(
+            exchange,
+            InstrumentKind::Spot,
+            second,
+            DataKind::Trade(fixture::trade(price, 1.0)),
+        )
+    }
+
+    fn funding(exchange: &'static str, second: u32, mark_price: f64) -> MarketEvent<FundingRate> {
+        event(
+            exchange,
+            InstrumentKind::Perpetual,
+            second,
+            FundingRate {
+                rate: 0.0001,
+                mark_price,
+                next_funding_time: Utc.with_ymd_and_hms(2023, 1, 1, 8, 0, 0).unwrap(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_basis_composite() {
+        let mut composite = BasisComposite::default();
+
+        // Spot leg without a perpetual leg yields no Basis
+        assert!(composite
+            .process_spot(&spot_trade("coinbase", 0, 100.0))
+            .is_empty());
+
+        // Perpetual leg joins the recent spot leg
+        let actual = composite.process_funding(&funding("binance_futures_usd", 1, 101.0));
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].exchange, Exchange::from("binance_futures_usd"));
+        assert_eq!(actual[0].exchange_time, time(1));
+        assert_eq!(
+            actual[0].kind,
+            Basis {
+                spot_exchange: Exchange::from("coinbase"),
+                spot_price: 100.0,
+                perpetual_price: 101.0,
+                basis: 0.01,
+                funding_rate: 0.0001,
+                annualised_funding_rate: 0.0001 * 3.0 * 365.0,
+                next_funding_time: Utc.with_ymd_and_hms(2023, 1, 1, 8, 0, 0).unwrap(),
+            }
+        );
+
+        // Spot L1 leg of another exchange joins using the mid price
+        let actual = composite.process_spot(&event(
+            "kraken",
+            InstrumentKind::Spot,
+            2,
+            DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time(2),
+                best_bid: Level::new(99.0, 1.0),
+                best_ask: Level::new(101.0, 1.0),
+            }),
+        ));
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].kind.spot_exchange, Exchange::from("kraken"));
+        assert_eq!(actual[0].kind.spot_price, 100.0);
+
+        // Stale spot legs are not joined
+        let actual = composite.process_funding(&funding("okx", 9, 102.0));
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_annualise() {
+        assert_eq!(annualise(0.0001, Duration::hours(8)), 0.0001 * 3.0 * 365.0);
+        assert_eq!(annualise(0.0001, Duration::hours(1)), 0.0001 * 24.0 * 365.0);
+        assert_eq!(annualise(0.0001, Duration::zero()), 0.0);
+    }
+}
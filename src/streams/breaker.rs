@@ -0,0 +1,293 @@
+use crate::{
+    error::DataError,
+    exchange::ExchangeId,
+    notice::{ExchangeNotice, NoticeKind},
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default window over which a [`ErrorTolerance`] counts deserialisation errors.
+pub const DEFAULT_ERROR_WINDOW: Duration = Duration::from_secs(60);
+
+/// Action taken by the consumer loop once a [`CircuitBreaker`] trips.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TripAction {
+    /// Re-initialise the connection & resubscribe, resetting the [`CircuitBreaker`].
+    Reconnect,
+    /// Permanently disable the connection, stopping the consumer loop of it's subscriptions
+    /// with a [`DataError::CircuitBreakerTripped`].
+    Disable,
+}
+
+/// Tolerance policy of the deserialisation errors consumed from a connection, after which a
+/// [`CircuitBreaker`] trips rather than letting a changed or corrupted feed pollute the stream.
+///
+/// Configured via [`StreamConfig::with_error_tolerance`](super::config::StreamConfig::with_error_tolerance).
+/// Without an [`ErrorTolerance`], every deserialisation error is logged & skipped.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{
+///     breaker::{ErrorTolerance, TripAction},
+///     config::StreamConfig,
+/// };
+/// use tokio::sync::mpsc;
+///
+/// // Tolerate up to 10 deserialisation errors per minute, then disable the connection
+/// let (notice_tx, notice_rx) = mpsc::unbounded_channel();
+/// let config = StreamConfig::default().with_error_tolerance(
+///     ErrorTolerance::new(10)
+///         .with_action(TripAction::Disable)
+///         .with_notices(notice_tx),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ErrorTolerance {
+    /// Number of deserialisation errors tolerated per `window`. The [`CircuitBreaker`] trips on
+    /// the next error.
+    pub max_errors: u32,
+    /// Sliding window over which deserialisation errors are counted.
+    pub window: Duration,
+    /// Action taken once the [`CircuitBreaker`] trips.
+    pub action: TripAction,
+    /// Optional receiver of an [`ExchangeNotice`] for every tolerated error & trip.
+    pub notice_tx: Option<mpsc::UnboundedSender<ExchangeNotice>>,
+}
+
+impl ErrorTolerance {
+    /// Construct a new [`Self`] that tolerates up to `max_errors` deserialisation errors per
+    /// [`DEFAULT_ERROR_WINDOW`], then reconnects.
+    pub fn new(max_errors: u32) -> Self {
+        Self {
+            max_errors,
+            window: DEFAULT_ERROR_WINDOW,
+            action: TripAction::Reconnect,
+            notice_tx: None,
+        }
+    }
+
+    /// Count deserialisation errors over the provided sliding window.
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Take the provided [`TripAction`] once the [`CircuitBreaker`] trips.
+    pub fn with_action(mut self, action: TripAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Send an [`ExchangeNotice`] for every tolerated error & trip to the provided `notice_tx`.
+    pub fn with_notices(mut self, notice_tx: mpsc::UnboundedSender<ExchangeNotice>) -> Self {
+        self.notice_tx = Some(notice_tx);
+        self
+    }
+}
+
+impl PartialEq for ErrorTolerance {
+    fn eq(&self, other: &Self) -> bool {
+        let notices_eq = match (&self.notice_tx, &other.notice_tx) {
+            (Some(notice_tx), Some(other)) => notice_tx.same_channel(other),
+            (None, None) => true,
+            _ => false,
+        };
+
+        self.max_errors == other.max_errors
+            && self.window == other.window
+            && self.action == other.action
+            && notices_eq
+    }
+}
+
+impl Eq for ErrorTolerance {}
+
+/// Outcome of a deserialisation error recorded by a [`CircuitBreaker`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BreakerStatus {
+    /// Error tolerated, with the number of errors counted in the current window.
+    Tolerated(u32),
+    /// Tolerance exceeded, so the [`TripAction`] must be taken.
+    Tripped(TripAction),
+}
+
+/// Circuit breaker counting the deserialisation errors consumed from one exchange connection
+/// against it's [`ErrorTolerance`].
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    exchange: ExchangeId,
+    tolerance: ErrorTolerance,
+    errors: VecDeque<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Construct a new [`Self`] for the provided exchange using the [`ErrorTolerance`].
+    pub fn new(exchange: ExchangeId, tolerance: ErrorTolerance) -> Self {
+        Self {
+            exchange,
+            tolerance,
+            errors: VecDeque::new(),
+        }
+    }
+
+    /// Record a [`DataError`] consumed at `now`, returning `None` if it is not a deserialisation
+    /// error.
+    ///
+    /// Once tripped, the [`CircuitBreaker`] is reset so a re-initialised connection starts with
+    /// a new window.
+    pub fn record(&mut self, error: &DataError, now: Instant) -> Option<BreakerStatus> {
+        if !error.is_deserialise() {
+            return None;
+        }
+
+        while let Some(oldest) = self.errors.front() {
+            match now.saturating_duration_since(*oldest) >= self.tolerance.window {
+                true => self.errors.pop_front(),
+                false => break,
+            };
+        }
+        self.errors.push_back(now);
+
+        let errors = self.errors.len() as u32;
+        if errors <= self.tolerance.max_errors {
+            self.notify(
+                NoticeKind::ParseError,
+                format!(
+                    "tolerated deserialisation error {errors}/{} per {:?}: {error}",
+                    self.tolerance.max_errors, self.tolerance.window
+                ),
+            );
+            return Some(BreakerStatus::Tolerated(errors));
+        }
+
+        self.notify(
+            NoticeKind::CircuitBreaker,
+            format!(
+                "circuit breaker tripped after {errors} deserialisation errors in {:?}, action: {:?}",
+                self.tolerance.window, self.tolerance.action
+            ),
+        );
+        self.errors.clear();
+        Some(BreakerStatus::Tripped(self.tolerance.action))
+    }
+
+    /// [`DataError`] describing a tripped [`CircuitBreaker`].
+    pub fn tripped_error(&self) -> DataError {
+        DataError::CircuitBreakerTripped {
+            exchange: self.exchange,
+            max_errors: self.tolerance.max_errors,
+            window: self.tolerance.window,
+        }
+    }
+
+    fn notify(&self, kind: NoticeKind, message: String) {
+        let Some(notice_tx) = &self.tolerance.notice_tx else {
+            return;
+        };
+
+        let notice = ExchangeNotice {
+            exchange: self.exchange,
+            received_time: Utc::now(),
+            kind,
+            code: None,
+            message,
+        };
+
+        if notice_tx.send(notice).is_err() {
+            debug!(exchange = %self.exchange, "ExchangeNotice receiver dropped");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::error::SocketError;
+
+    fn deserialise_error() -> DataError {
+        DataError::Socket(SocketError::Deserialise {
+            error: serde_json::from_str::<u64>("{").unwrap_err(),
+            payload: "{".to_owned(),
+        })
+    }
+
+    #[test]
+    fn test_circuit_breaker_record() {
+        struct TestCase {
+            input: (DataError, Duration),
+            expected: Option<BreakerStatus>,
+        }
+
+        let (notice_tx, mut notice_rx) = mpsc::unbounded_channel();
+        let tolerance = ErrorTolerance::new(2)
+            .with_window(Duration::from_secs(10))
+            .with_action(TripAction::Disable)
+            .with_notices(notice_tx);
+        let mut breaker = CircuitBreaker::new(ExchangeId::BinanceSpot, tolerance);
+        let start = Instant::now();
+
+        let tests = vec![
+            TestCase {
+                // TC0: non deserialisation errors are not counted
+                input: (DataError::Socket(SocketError::Sink), Duration::ZERO),
+                expected: None,
+            },
+            TestCase {
+                // TC1: first error tolerated
+                input: (deserialise_error(), Duration::ZERO),
+                expected: Some(BreakerStatus::Tolerated(1)),
+            },
+            TestCase {
+                // TC2: second error tolerated
+                input: (deserialise_error(), Duration::from_secs(5)),
+                expected: Some(BreakerStatus::Tolerated(2)),
+            },
+            TestCase {
+                // TC3: first error expired from the window, so still tolerated
+                input: (deserialise_error(), Duration::from_secs(10)),
+                expected: Some(BreakerStatus::Tolerated(2)),
+            },
+            TestCase {
+                // TC4: tolerance exceeded
+                input: (deserialise_error(), Duration::from_secs(11)),
+                expected: Some(BreakerStatus::Tripped(TripAction::Disable)),
+            },
+            TestCase {
+                // TC5: reset after tripping
+                input: (deserialise_error(), Duration::from_secs(12)),
+                expected: Some(BreakerStatus::Tolerated(1)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (error, elapsed) = test.input;
+            let actual = breaker.record(&error, start + elapsed);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        let kinds = std::iter::from_fn(|| notice_rx.try_recv().ok())
+            .map(|notice| notice.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                NoticeKind::ParseError,
+                NoticeKind::ParseError,
+                NoticeKind::ParseError,
+                NoticeKind::CircuitBreaker,
+                NoticeKind::ParseError,
+            ]
+        );
+        assert!(matches!(
+            breaker.tripped_error(),
+            DataError::CircuitBreakerTripped { max_errors: 2, .. }
+        ));
+    }
+}
@@ -1,13 +1,32 @@
-use super::{consumer::consume, Streams};
+use super::{
+    config::StreamConfig,
+    consumer::consume_with,
+    group::{supervise_group, StreamGroups},
+    reconcile::{reconcile_trades_with, RecentTrades, ReconcileTrigger},
+    redundant::{deduplicate, Deduplicate, DEFAULT_DEDUP_WINDOW},
+    Streams,
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
     exchange::{ExchangeId, StreamSelector},
-    subscription::{SubKind, Subscription},
+    ranking::VolumeRanking,
+    subscription::{trade::PublicTrades, SubKind, Subscription},
+    transformer::{
+        enrich::{Enricher, SharedEnricher},
+        filter::{EventFilter, SharedFilter},
+    },
     Identifier,
 };
 use barter_integration::{error::SocketError, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
 use tokio::sync::mpsc;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
@@ -15,6 +34,11 @@ use tokio::sync::mpsc;
 /// [`StreamBuilder<SubKind>`](StreamBuilder)s.
 pub mod multi;
 
+/// Defines the [`SharedStreamBuilder`](shared::SharedStreamBuilder) API for multiplexing many
+/// [`SubKind`]s over a single exchange
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
+pub mod shared;
+
 /// Communicative type alias representing the [`Future`] result of a [`Subscription`] [`validate`]
 /// call generated whilst executing [`StreamBuilder::subscribe`].
 pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
@@ -28,6 +52,12 @@ where
 {
     pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    pub groups: StreamGroups,
+    /// Number of de-duplicated [`Subscription`]s added for each exchange, checked against the
+    /// [`SubscriptionLimits::total_topics`](crate::exchange::SubscriptionLimits) limit.
+    pub topics: HashMap<ExchangeId, usize>,
+    /// Optional [`Enricher`] applied to each event of the [`Subscription`]s subsequently added.
+    pub enricher: Option<SharedEnricher<Kind::Event>>,
 }
 
 impl<Kind> Debug for StreamBuilder<Kind>
@@ -38,6 +68,9 @@ where
         f.debug_struct("StreamBuilder<SubKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("groups", &self.groups.names())
+            .field("topics", &self.topics)
+            .field("enricher", &self.enricher)
             .finish()
     }
 }
@@ -51,15 +84,32 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            groups: StreamGroups::new(),
+            topics: HashMap::new(),
+            enricher: None,
         }
     }
 
+    /// Apply the provided [`Enricher`] to each normalised [`MarketEvent<SubKind::Event>`](MarketEvent)
+    /// before emission, avoiding an extra consumer hop for cheap per-event transforms.
+    ///
+    /// Only applied to the [`Subscription`]s added to the [`StreamBuilder`] after this call, and
+    /// replaces any previously configured [`Enricher`]. Not applied to
+    /// [`subscribe_reconciled`](StreamBuilder::subscribe_reconciled) REST backfilled trades.
+    pub fn with_enricher<E>(mut self, enricher: E) -> Self
+    where
+        E: Enricher<Kind::Event> + 'static,
+    {
+        self.enricher = Some(Arc::new(enricher));
+        self
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
-    pub fn subscribe<SubIter, Sub, Exchange>(mut self, subscriptions: SubIter) -> Self
+    pub fn subscribe<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<Exchange, Kind>>,
@@ -68,24 +118,304 @@ where
         Kind::Event: Send,
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Construct Vec<Subscriptions> from input SubIter
-        let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        self.subscribe_with(subscriptions, StreamConfig::default())
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
+    /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection
+    /// configured by the provided [`StreamConfig`] (eg/ a custom WebSocket endpoint).
+    ///
+    /// [`Subscription`]s exceeding the exchange
+    /// [`SubscriptionLimits`](crate::exchange::SubscriptionLimits) are split across the fewest
+    /// connections allowed, or rejected with a clear error if the total topics limit is exceeded.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_with<SubIter, Sub, Exchange>(
+        self,
+        subscriptions: SubIter,
+        config: StreamConfig,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_with_filter(subscriptions, config, None)
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] as per
+    /// [`subscribe_with`](StreamBuilder::subscribe_with), forwarding only the events accepted by
+    /// the provided [`EventFilter`].
+    ///
+    /// The filter is applied on the connection task before the channel send, so discarded events
+    /// never reach a consumer. It receives the events of every [`Subscription`] in the
+    /// collection, so can discriminate by
+    /// [`Instrument`](barter_integration::model::instrument::Instrument) if required.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_filtered<SubIter, Sub, Exchange, Filter>(
+        self,
+        subscriptions: SubIter,
+        config: StreamConfig,
+        filter: Filter,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        Filter: EventFilter<Kind::Event> + 'static,
+    {
+        self.subscribe_with_filter(subscriptions, config, Some(Arc::new(filter)))
+    }
+
+    fn subscribe_with_filter<SubIter, Sub, Exchange>(
+        mut self,
+        subscriptions: SubIter,
+        config: StreamConfig,
+        filter: Option<SharedFilter<Kind::Event>>,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct the connections required to action the Subscriptions from input SubIter
+        let connections = self.connections(subscriptions.into_iter().map(Sub::into).collect());
 
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let enricher = self.enricher.clone();
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
-            // Validate Subscriptions
-            validate(&subscriptions)?;
+            // Spawn a MarketStream consumer loop per connection of Subscriptions<Exchange, Kind>
+            for subscriptions in connections? {
+                tokio::spawn(consume_with(
+                    subscriptions,
+                    config.clone(),
+                    exchange_tx.clone(),
+                    filter.clone(),
+                    enricher.clone(),
+                ));
+            }
+
+            Ok(())
+        }));
+
+        self
+    }
+
+    /// Add [`Subscription`]s of the provided [`SubKind`] for the `n` exchange
+    /// [`Instrument`](barter_integration::model::instrument::Instrument)s quoted in `quote` with
+    /// the highest rolling 24 hour volume, fetched via the exchange [`VolumeRanking`] REST
+    /// endpoint, to the [`StreamBuilder`]. They are actioned as per
+    /// [`subscribe_with`](StreamBuilder::subscribe_with).
+    ///
+    /// The universe is selected once, so it does not change as volumes do.
+    ///
+    /// ### Examples
+    /// ```rust,no_run
+    /// use barter_data::{
+    ///     exchange::binance::spot::BinanceSpot,
+    ///     streams::{config::StreamConfig, Streams},
+    ///     subscription::trade::PublicTrades,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), barter_data::error::DataError> {
+    /// // Subscribe to the PublicTrades of the 20 most traded usdt markets on BinanceSpot
+    /// let streams = Streams::<PublicTrades>::builder()
+    ///     .subscribe_top_by_volume(BinanceSpot::default(), "usdt", 20, PublicTrades, StreamConfig::default())
+    ///     .await?
+    ///     .init()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_top_by_volume<Exchange>(
+        self,
+        exchange: Exchange,
+        quote: &str,
+        n: usize,
+        kind: Kind,
+        config: StreamConfig,
+    ) -> Result<Self, DataError>
+    where
+        Exchange: StreamSelector<Kind> + VolumeRanking + Clone + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let instruments = Exchange::top_by_volume(quote, n).await?;
+
+        Ok(self.subscribe_with(
+            instruments
+                .into_iter()
+                .map(|instrument| Subscription::new(exchange.clone(), instrument, kind.clone())),
+            config,
+        ))
+    }
+
+    /// Add a collection of [`Subscription`]s tagged with the named subscription `group` to the
+    /// [`StreamBuilder`] that will be actioned on a distinct
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
+    ///
+    /// Every connection of a group can later be paused, resumed & cancelled together via the
+    /// [`Streams`] [`StreamGroups`] handle (eg/ "alts-universe" vs "majors").
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_group<Group, SubIter, Sub, Exchange>(
+        self,
+        group: Group,
+        subscriptions: SubIter,
+    ) -> Self
+    where
+        Group: Into<String>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Clone + Send + Sync + 'static,
+        Kind: Ord + Clone + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_group_with(group, subscriptions, StreamConfig::default())
+    }
 
-            // Remove duplicate Subscriptions
-            subscriptions.sort();
-            subscriptions.dedup();
+    /// Add a collection of [`Subscription`]s tagged with the named subscription `group` to the
+    /// [`StreamBuilder`] that will be actioned on a distinct
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection configured
+    /// by the provided [`StreamConfig`] (see [`StreamBuilder::subscribe_group`]).
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_group_with<Group, SubIter, Sub, Exchange>(
+        mut self,
+        group: Group,
+        subscriptions: SubIter,
+        config: StreamConfig,
+    ) -> Self
+    where
+        Group: Into<String>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Clone + Send + Sync + 'static,
+        Kind: Ord + Clone + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct the connections required to action the Subscriptions from input SubIter
+        let connections = self.connections(subscriptions.into_iter().map(Sub::into).collect());
 
-            // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+        // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
+        // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
+        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+
+        let enricher = self.enricher.clone();
+
+        // Register each connection with the subscription group
+        let group = group.into();
+        let connections = connections.map(|connections| {
+            connections
+                .into_iter()
+                .map(|subscriptions| (subscriptions, self.groups.register(&group)))
+                .collect::<Vec<_>>()
+        });
+
+        // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
+        self.futures.push(Box::pin(async move {
+            // Spawn a supervised MarketStream consumer loop per connection of
+            // Subscriptions<Exchange, Kind>
+            for (subscriptions, state_rx) in connections? {
+                let (config, exchange_tx) = (config.clone(), exchange_tx.clone());
+                let enricher = enricher.clone();
+                tokio::spawn(supervise_group(group.clone(), state_rx, move || {
+                    consume_with(
+                        subscriptions.clone(),
+                        config.clone(),
+                        exchange_tx.clone(),
+                        None,
+                        enricher.clone(),
+                    )
+                }));
+            }
+
+            Ok(())
+        }));
+
+        self
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on a
+    /// distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection for
+    /// each of the provided [`StreamConfig`]s (eg/ the same feed from two different endpoints).
+    ///
+    /// Events are deduplicated across the redundant connections, with the first arrival of each
+    /// [`MarketEvent<SubKind::Event>`](MarketEvent) forwarded downstream and any duplicates
+    /// discarded. This masks the disconnection & lag of any single connection.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_redundant<SubIter, Sub, Exchange, ConfigIter>(
+        mut self,
+        subscriptions: SubIter,
+        configs: ConfigIter,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        ConfigIter: IntoIterator<Item = StreamConfig>,
+        Exchange: StreamSelector<Kind> + Ord + Clone + Send + Sync + 'static,
+        Kind: Ord + Clone + Send + Sync + 'static,
+        Kind::Event: Deduplicate + Send + 'static,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct the connections required to action the Subscriptions & Vec<StreamConfig> from
+        // inputs
+        let connections = self.connections(subscriptions.into_iter().map(Sub::into).collect());
+        let configs = configs.into_iter().collect::<Vec<_>>();
+
+        // Acquire channel Sender to send Market<Kind::Event> from deduplication task to user
+        // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
+        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let enricher = self.enricher.clone();
+
+        // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
+        self.futures.push(Box::pin(async move {
+            // Ensure the Subscriptions are valid & at least one connection has been configured
+            let connections = connections?;
+            if configs.is_empty() {
+                return Err(DataError::Socket(SocketError::Subscribe(
+                    "StreamBuilder redundant Subscriptions contain no StreamConfig".to_owned(),
+                )));
+            }
+
+            // Spawn a MarketStream consumer loop per connection & StreamConfig, feeding the
+            // deduplication task
+            let (redundant_tx, redundant_rx) = mpsc::unbounded_channel();
+            for subscriptions in connections {
+                for config in &configs {
+                    tokio::spawn(consume_with(
+                        subscriptions.clone(),
+                        config.clone(),
+                        redundant_tx.clone(),
+                        None,
+                        enricher.clone(),
+                    ));
+                }
+            }
+            tokio::spawn(deduplicate(redundant_rx, exchange_tx, DEFAULT_DEDUP_WINDOW));
 
             Ok(())
         }));
@@ -93,6 +423,48 @@ where
         self
     }
 
+    /// Validate & de-duplicate the provided [`Subscription`]s, splitting them into a connection
+    /// per exchange server (see [`Connector::channel_url`](crate::exchange::Connector::channel_url)),
+    /// and then into the connections required by the exchange
+    /// [`SubscriptionLimits`](crate::exchange::SubscriptionLimits).
+    ///
+    /// Any error is returned when the [`init()`](StreamBuilder::init()) method is invoked.
+    fn connections<Exchange>(
+        &mut self,
+        mut subscriptions: Vec<Subscription<Exchange, Kind>>,
+    ) -> Result<Vec<Vec<Subscription<Exchange, Kind>>>, DataError>
+    where
+        Exchange: StreamSelector<Kind> + Ord,
+        Kind: Ord,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel>,
+    {
+        // Validate Subscriptions
+        validate(&subscriptions)?;
+
+        // Remove duplicate Subscriptions
+        subscriptions.sort();
+        subscriptions.dedup();
+
+        // Group Subscriptions by the exchange server that serves them (eg/ Okx candles)
+        let mut servers = BTreeMap::<_, Vec<_>>::new();
+        for subscription in subscriptions {
+            let url = Exchange::channel_url(&Identifier::<Exchange::Channel>::id(&subscription))?;
+            servers.entry(url).or_default().push(subscription);
+        }
+
+        // Split each server's Subscriptions into connections within the SubscriptionLimits
+        let topics = self.topics.entry(Exchange::ID).or_default();
+        *topics += servers.values().map(Vec::len).sum::<usize>();
+
+        let limits = Exchange::subscription_limits();
+        let mut connections = Vec::with_capacity(servers.len());
+        for subscriptions in servers.into_values() {
+            connections.extend(limits.split(Exchange::ID, subscriptions, *topics)?);
+        }
+
+        Ok(connections)
+    }
+
     /// Spawn a [`MarketEvent<SubKind::Event>`](MarketEvent) consumer loop for each collection of
     /// [`Subscription`]s added to [`StreamBuilder`] via the
     /// [`subscribe()`](StreamBuilder::subscribe()) method.
@@ -110,10 +482,86 @@ where
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            groups: self.groups,
         })
     }
 }
 
+impl StreamBuilder<PublicTrades> {
+    /// Add a collection of [`PublicTrades`] [`Subscription`]s to the [`StreamBuilder`] that will be
+    /// actioned on a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket)
+    /// connection configured by the provided [`StreamConfig`], and reconciled against the
+    /// exchange [`RecentTrades`] REST endpoint every `interval`.
+    ///
+    /// Trades missing from the WebSocket stream (eg/ during a re-connection) are injected, and
+    /// trades already received are discarded, with both deduplicated by trade id (see
+    /// [`reconcile_trades`](super::reconcile::reconcile_trades)). Trade id gaps detected by the connection (eg/ via the Coinbase
+    /// heartbeat channel) trigger the immediate reconciliation of the affected instrument.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_reconciled<SubIter, Sub, Exchange>(
+        mut self,
+        subscriptions: SubIter,
+        config: StreamConfig,
+        interval: Duration,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, PublicTrades>>,
+        Exchange: StreamSelector<PublicTrades> + RecentTrades + Ord + Send + Sync + 'static,
+        Subscription<Exchange, PublicTrades>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct the connections required to action the Subscriptions from input SubIter
+        let connections = self.connections(subscriptions.into_iter().map(Sub::into).collect());
+
+        // Acquire channel Sender to send MarketEvent<PublicTrade> from deduplication task to user
+        // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
+        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let enricher = self.enricher.clone();
+
+        // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
+        self.futures.push(Box::pin(async move {
+            let connections = connections?;
+
+            let mut instruments = connections
+                .iter()
+                .flatten()
+                .map(|subscription| subscription.instrument.clone())
+                .collect::<Vec<_>>();
+            instruments.sort();
+            instruments.dedup();
+
+            // Spawn a MarketStream consumer loop per connection & a reconciliation task, all
+            // feeding the deduplication task
+            let (reconcile_tx, reconcile_rx) = mpsc::unbounded_channel();
+            let (trigger, trigger_rx) = ReconcileTrigger::new();
+            let config = config.with_reconcile_trigger(trigger);
+            for subscriptions in connections {
+                tokio::spawn(consume_with(
+                    subscriptions,
+                    config.clone(),
+                    reconcile_tx.clone(),
+                    None,
+                    enricher.clone(),
+                ));
+            }
+            tokio::spawn(reconcile_trades_with::<Exchange>(
+                instruments,
+                interval,
+                trigger_rx,
+                reconcile_tx,
+            ));
+            tokio::spawn(deduplicate(reconcile_rx, exchange_tx, DEFAULT_DEDUP_WINDOW));
+
+            Ok(())
+        }));
+
+        self
+    }
+}
+
 /// Convenient type that holds the [`mpsc::UnboundedSender`] and [`mpsc::UnboundedReceiver`] for a
 /// [`MarketEvent<T>`](MarketEvent) channel.
 #[derive(Debug)]
@@ -137,7 +585,7 @@ impl<T> Default for ExchangeChannel<T> {
 }
 
 /// Validate the provided collection of [`Subscription`]s, ensuring that the associated exchange
-/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind).
+/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind).
 pub fn validate<Exchange, Kind>(
     subscriptions: &[Subscription<Exchange, Kind>],
 ) -> Result<(), DataError>
@@ -166,7 +614,7 @@ mod tests {
     use super::*;
     use crate::exchange::coinbase::Coinbase;
     use crate::subscription::trade::PublicTrades;
-    use barter_integration::model::InstrumentKind;
+    use barter_integration::model::instrument::kind::InstrumentKind;
 
     #[test]
     fn test_validate() {
@@ -199,12 +647,12 @@ mod tests {
                 ))]),
             },
             TestCase {
-                // TC2: Invalid StreamBuilder w/ invalid Coinbase FuturePerpetual sub
+                // TC2: Invalid StreamBuilder w/ invalid Coinbase Perpetual sub
                 input: vec![Subscription::from((
                     Coinbase,
                     "base",
                     "quote",
-                    InstrumentKind::FuturePerpetual,
+                    InstrumentKind::Perpetual,
                     PublicTrades,
                 ))],
                 expected: Err(SocketError::Subscribe("".to_string())),
@@ -228,4 +676,131 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_stream_builder_connections() {
+        use crate::{
+            exchange::{binance::spot::BinanceSpot, okx::Okx},
+            subscription::book::OrderBooksL2,
+        };
+
+        // Subscriptions served by the same exchange server share a connection
+        let mut builder = StreamBuilder::<PublicTrades>::new();
+        let connections = builder
+            .connections(vec![
+                Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades)),
+                Subscription::from((Okx, "eth", "usdt", InstrumentKind::Spot, PublicTrades)),
+            ])
+            .unwrap();
+        assert_eq!(connections.len(), 1);
+
+        // Subscriptions served by different exchange servers are split across connections
+        // '--> BinanceSpot partial depth streams are served by the combined stream endpoint
+        let mut builder = StreamBuilder::<OrderBooksL2>::new();
+        let connections = builder
+            .connections(vec![
+                Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBooksL2::default(),
+                )),
+                Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBooksL2::partial(5),
+                )),
+                Subscription::from((
+                    BinanceSpot::default(),
+                    "eth",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBooksL2::partial(5),
+                )),
+            ])
+            .unwrap();
+        assert_eq!(
+            connections.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+    }
+
+    fn mock_trades(prices: &[&str]) -> crate::testing::MockExchange {
+        use crate::testing::{FaultScript, MockExchange};
+
+        let script = prices.iter().enumerate().fold(
+            FaultScript::new()
+                .await_request()
+                .send_text(r#"{"result":null,"id":1}"#),
+            |script, (id, price)| {
+                script.send_text(format!(
+                    r#"{{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":{id},"p":"{price}","q":"1.0","T":1649324825173,"m":false}}"#
+                ))
+            },
+        );
+
+        MockExchange::new([script])
+    }
+
+    #[tokio::test]
+    async fn test_stream_builder_enricher() {
+        use crate::{exchange::binance::spot::BinanceSpot, subscription::trade::PublicTrade};
+
+        let mut streams = StreamBuilder::<PublicTrades>::new()
+            .with_enricher(|event: &mut MarketEvent<PublicTrade>| event.kind.price /= 2.0)
+            .subscribe_with(
+                [(
+                    BinanceSpot::default(),
+                    "eth",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )],
+                StreamConfig::default().with_transport(mock_trades(&["10000.0"])),
+            )
+            .init()
+            .await
+            .unwrap();
+
+        let trade = streams
+            .select(ExchangeId::BinanceSpot)
+            .unwrap()
+            .recv()
+            .await
+            .unwrap();
+        assert_eq!(trade.kind.price, 5000.0);
+    }
+
+    #[tokio::test]
+    async fn test_stream_builder_subscribe_filtered() {
+        use crate::{exchange::binance::spot::BinanceSpot, subscription::trade::PublicTrade};
+
+        let mut streams = StreamBuilder::<PublicTrades>::new()
+            .subscribe_filtered(
+                [(
+                    BinanceSpot::default(),
+                    "eth",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )],
+                StreamConfig::default().with_transport(mock_trades(&["10.0", "5000.0", "20.0"])),
+                |trade: &MarketEvent<PublicTrade>| trade.kind.price * trade.kind.amount >= 1000.0,
+            )
+            .init()
+            .await
+            .unwrap();
+
+        // Only the trade above the notional threshold is forwarded
+        let mut trades = streams.select(ExchangeId::BinanceSpot).unwrap();
+        assert_eq!(trades.recv().await.unwrap().kind.price, 5000.0);
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(100), trades.recv())
+                .await
+                .is_err()
+        );
+    }
 }
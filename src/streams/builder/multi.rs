@@ -1,5 +1,20 @@
-use super::{ExchangeChannel, StreamBuilder, Streams};
-use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId, subscription::SubKind};
+use super::{shared::SharedStreamBuilder, ExchangeChannel, StreamBuilder, Streams};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, StreamSelector},
+    streams::{
+        config::StreamConfig,
+        group::StreamGroups,
+        shared::{consume_shared, SharedRoutes, SharedStream},
+    },
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+    SubscriptionId,
+};
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 
 /// Communicative type alias representing the [`Future`] result of a [`StreamBuilder::init`] call
@@ -12,6 +27,7 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 pub struct MultiStreamBuilder<Output> {
     pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
     pub futures: Vec<BuilderInitFuture>,
+    pub groups: StreamGroups,
 }
 
 impl<Output> Debug for MultiStreamBuilder<Output>
@@ -22,6 +38,7 @@ where
         f.debug_struct("MultiStreamBuilder<Output>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("groups", &self.groups.names())
             .finish()
     }
 }
@@ -32,6 +49,7 @@ impl<Output> MultiStreamBuilder<Output> {
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            groups: StreamGroups::new(),
         }
     }
 
@@ -42,7 +60,7 @@ impl<Output> MultiStreamBuilder<Output> {
     /// Note that the created [`Future`] is not awaited until the [`MultiStreamBuilder::init`]
     /// method is invoked.
     #[allow(clippy::should_implement_trait)]
-    pub fn add<Kind>(mut self, builder: StreamBuilder<Kind>) -> Self
+    pub fn add<Kind>(mut self, mut builder: StreamBuilder<Kind>) -> Self
     where
         Output: From<MarketEvent<Kind::Event>> + Send + 'static,
         Kind: SubKind + 'static,
@@ -60,6 +78,9 @@ impl<Output> MultiStreamBuilder<Output> {
             exchange_txs.insert(exchange, exchange_tx);
         }
 
+        // Move any subscription groups of the StreamBuilder into Self
+        self.groups.absorb(std::mem::take(&mut builder.groups));
+
         // Init Streams<Kind::Event> & send mapped Outputs to the associated exchange_tx
         self.futures.push(Box::pin(async move {
             builder
@@ -88,6 +109,85 @@ impl<Output> MultiStreamBuilder<Output> {
         self
     }
 
+    /// Add a [`SharedStreamBuilder`] to the [`MultiStreamBuilder`]. Creates a [`Future`] that
+    /// spawns a consumer loop multiplexing every [`SubKind`] in the [`SharedStreamBuilder`] over
+    /// a single exchange [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+    ///
+    /// Note that the created [`Future`] is not awaited until the [`MultiStreamBuilder::init`]
+    /// method is invoked.
+    pub fn add_shared<Exchange>(mut self, builder: SharedStreamBuilder<Exchange, Output>) -> Self
+    where
+        Exchange: Connector + Send + Sync + 'static,
+        ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+        Output: Send + 'static,
+    {
+        // Acquire channel Sender to send Output from the shared consumer loop to user
+        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+
+        self.futures.push(Box::pin(async move {
+            let SharedStreamBuilder { routes, config } = builder;
+
+            // Ensure at least one SubKind has been provided & validate it's Subscriptions
+            let routes = SharedRoutes::new(routes)?;
+
+            // Spawn a shared MarketStream consumer loop with every SubKind
+            tokio::spawn(consume_shared(routes, config, exchange_tx));
+
+            Ok(())
+        }));
+
+        self
+    }
+
+    /// Add a composite [`Subscription`] to the [`MultiStreamBuilder`], subscribing to every
+    /// [`SubKind`] in the provided tuple for a single exchange [`Instrument`].
+    ///
+    /// eg/ `((BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot), (PublicTrades, OrderBooksL1))`
+    ///
+    /// Every [`SubKind`] is multiplexed over a single shared
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection, as if added
+    /// via a [`SharedStreamBuilder`] to [`MultiStreamBuilder::add_shared`]. Therefore, each
+    /// [`SubKind`] must be served by the same exchange server.
+    pub fn subscribe<Exchange, S, Kinds>(
+        self,
+        composite: ((Exchange, S, S, InstrumentKind), Kinds),
+    ) -> Self
+    where
+        Exchange: Connector + Send + Sync + 'static,
+        ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+        Output: Send + 'static,
+        S: Into<Symbol>,
+        Kinds: SubKinds<Exchange, Output>,
+    {
+        self.subscribe_with(composite, StreamConfig::default())
+    }
+
+    /// Add a composite [`Subscription`] to the [`MultiStreamBuilder`] (see
+    /// [`MultiStreamBuilder::subscribe`]), configuring the shared connection with the provided
+    /// [`StreamConfig`].
+    pub fn subscribe_with<Exchange, S, Kinds>(
+        self,
+        ((exchange, base, quote, instrument_kind), kinds): (
+            (Exchange, S, S, InstrumentKind),
+            Kinds,
+        ),
+        config: StreamConfig,
+    ) -> Self
+    where
+        Exchange: Connector + Send + Sync + 'static,
+        ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+        Output: Send + 'static,
+        S: Into<Symbol>,
+        Kinds: SubKinds<Exchange, Output>,
+    {
+        let instrument = Instrument::from((base.into(), quote.into(), instrument_kind));
+        self.add_shared(kinds.add_shared(
+            SharedStreamBuilder::new().with_config(config),
+            exchange,
+            instrument,
+        ))
+    }
+
     /// Initialise each [`StreamBuilder<SubKind>`](StreamBuilder) that was added to the
     /// [`MultiStreamBuilder`] and map all [`Streams<SubKind::Event>`](Streams) into a common
     /// [`Streams<Output>`](Streams).
@@ -102,6 +202,119 @@ impl<Output> MultiStreamBuilder<Output> {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            groups: self.groups,
         })
     }
 }
+
+/// Defines a tuple of [`SubKind`]s that can be subscribed to for a single exchange [`Instrument`]
+/// via [`MultiStreamBuilder::subscribe`].
+///
+/// Implemented for tuples of up to eight [`SubKind`]s, eg/ `(PublicTrades, OrderBooksL1)`.
+pub trait SubKinds<Exchange, Output> {
+    /// Add the [`Subscription`] of each [`SubKind`] to the provided [`SharedStreamBuilder`],
+    /// subscribing to the exchange [`Instrument`] provided.
+    fn add_shared(
+        self,
+        builder: SharedStreamBuilder<Exchange, Output>,
+        exchange: Exchange,
+        instrument: Instrument,
+    ) -> SharedStreamBuilder<Exchange, Output>;
+}
+
+macro_rules! impl_sub_kinds {
+    ($($kind:ident),+) => {
+        impl<Exchange, Output, $($kind),+> SubKinds<Exchange, Output> for ($($kind,)+)
+        where
+            Exchange: Clone + Ord + Send + Sync + 'static,
+            $(
+                Exchange: StreamSelector<$kind>,
+                <Exchange as StreamSelector<$kind>>::Stream: SharedStream<Exchange, $kind>,
+                $kind: SubKind + Ord + Send + Sync + 'static,
+                $kind::Event: Send,
+                Subscription<Exchange, $kind>:
+                    Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+                Output: From<MarketEvent<$kind::Event>> + Send + 'static,
+            )+
+        {
+            #[allow(non_snake_case)]
+            fn add_shared(
+                self,
+                builder: SharedStreamBuilder<Exchange, Output>,
+                exchange: Exchange,
+                instrument: Instrument,
+            ) -> SharedStreamBuilder<Exchange, Output> {
+                let ($($kind,)+) = self;
+                $(
+                    let builder = builder.subscribe([
+                        Subscription::new(exchange.clone(), instrument.clone(), $kind)
+                    ]);
+                )+
+                builder
+            }
+        }
+    };
+}
+
+impl_sub_kinds!(A);
+impl_sub_kinds!(A, B);
+impl_sub_kinds!(A, B, C);
+impl_sub_kinds!(A, B, C, D);
+impl_sub_kinds!(A, B, C, D, E);
+impl_sub_kinds!(A, B, C, D, E, F);
+impl_sub_kinds!(A, B, C, D, E, F, G);
+impl_sub_kinds!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::DataKind,
+        exchange::binance::spot::BinanceSpot,
+        subscription::{book::OrderBooksL1, trade::PublicTrades},
+        testing::{FaultScript, MockExchange},
+    };
+    use barter_integration::protocol::websocket::WsMessage;
+
+    #[tokio::test]
+    async fn test_multi_stream_builder_subscribe_shares_connection() {
+        let exchange = MockExchange::new([FaultScript::new()
+            .await_request()
+            .send_text(r#"{"result":null,"id":1}"#)
+            .send_text(
+                r#"{"e":"trade","E":1,"s":"BTCUSDT","t":1,"p":"1.0","q":"1.0","T":1,"m":false}"#,
+            )
+            .send_text(r#"{"u":1,"s":"BTCUSDT","b":"1.0","B":"1.0","a":"2.0","A":"1.0"}"#)]);
+
+        let mut streams = MultiStreamBuilder::<MarketEvent<DataKind>>::new()
+            .subscribe_with(
+                (
+                    (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot),
+                    (PublicTrades, OrderBooksL1),
+                ),
+                StreamConfig::default().with_transport(exchange.clone()),
+            )
+            .init()
+            .await
+            .unwrap();
+
+        let mut exchange_rx = streams.select(ExchangeId::BinanceSpot).unwrap();
+        let first = exchange_rx.recv().await.unwrap();
+        let second = exchange_rx.recv().await.unwrap();
+
+        // Every SubKind is subscribed to over a single connection, with a single request
+        assert_eq!(exchange.connections(), 1);
+        assert_eq!(
+            exchange
+                .received(0)
+                .iter()
+                .filter(|message| matches!(message, WsMessage::Text(_)))
+                .count(),
+            1
+        );
+
+        // Each message is routed to it's own SubKind
+        assert!(matches!(first.kind, DataKind::Trade(_)));
+        assert!(matches!(second.kind, DataKind::OrderBookL1(_)));
+    }
+}
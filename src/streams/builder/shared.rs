@@ -0,0 +1,86 @@
+use crate::{
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, StreamSelector},
+    streams::{
+        config::StreamConfig,
+        shared::{SharedKindRoute, SharedRoute, SharedStream},
+    },
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use std::fmt::{Debug, Formatter};
+
+/// Builder to configure a single exchange [`WebSocket`](barter_integration::protocol::websocket::WebSocket)
+/// connection that multiplexes [`Subscription`]s to many different [`SubKind`]s, distributing a
+/// common `Output` (eg/ [`MarketEvent<DataKind>`](crate::event::DataKind)).
+///
+/// Initialised by adding it to a [`MultiStreamBuilder`](super::multi::MultiStreamBuilder) via
+/// [`MultiStreamBuilder::add_shared`](super::multi::MultiStreamBuilder::add_shared).
+///
+/// Note that every [`Subscription`] must be served by the same exchange server (eg/
+/// [`Okx`](crate::exchange::okx::Okx) candles are served by a different server to trades), and
+/// the exchange must identify subscriptions with the [`SubscriptionId`]s generated by the
+/// [`Connector`](crate::exchange::Connector) (ie/ not [`Bitfinex`](crate::exchange::bitfinex::Bitfinex)).
+pub struct SharedStreamBuilder<Exchange, Output> {
+    pub routes: Vec<Box<dyn SharedRoute<Exchange, Output>>>,
+    pub config: StreamConfig,
+}
+
+impl<Exchange, Output> Debug for SharedStreamBuilder<Exchange, Output> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedStreamBuilder<Exchange, Output>")
+            .field("routes", &self.routes)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl<Exchange, Output> Default for SharedStreamBuilder<Exchange, Output> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Exchange, Output> SharedStreamBuilder<Exchange, Output> {
+    /// Construct a new [`Self`].
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            config: StreamConfig::default(),
+        }
+    }
+
+    /// Configure the shared connection with the provided [`StreamConfig`].
+    pub fn with_config(mut self, config: StreamConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Add a collection of [`Subscription`]s to a [`SubKind`] that will be actioned on the
+    /// shared [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`MultiStreamBuilder::init()`](super::multi::MultiStreamBuilder::init()) method is invoked.
+    pub fn subscribe<SubIter, Sub, Kind>(mut self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Exchange::Stream: SharedStream<Exchange, Kind>,
+        Kind: SubKind + Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+        Output: From<MarketEvent<Kind::Event>> + Send + 'static,
+    {
+        // Construct Vec<Subscriptions> from input SubIter & remove duplicates
+        let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        subscriptions.sort();
+        subscriptions.dedup();
+
+        self.routes
+            .push(Box::new(SharedKindRoute { subscriptions }));
+        self
+    }
+}
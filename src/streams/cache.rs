@@ -0,0 +1,331 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        candle::Candle,
+        greeks::Greeks,
+        liquidation::Liquidation,
+        ticker::{MiniTicker, Ticker},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Kind of [`MarketEvent<T>`](MarketEvent) cached by a [`LastValueCache`], identifying the
+/// [`SubKind`](crate::subscription::SubKind) that produced it.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CachedKind {
+    Trade,
+    OrderBookL1,
+    OrderBook,
+    Candle,
+    Liquidation,
+    Greeks,
+    Ticker,
+    MiniTicker,
+}
+
+/// Event that can be stored in a [`LastValueCache`], keyed by it's [`CachedKind`].
+pub trait Cacheable {
+    fn cached_kind(&self) -> CachedKind;
+}
+
+macro_rules! impl_cacheable {
+    ($($event:ty => $kind:ident),* $(,)?) => {
+        $(
+            impl Cacheable for $event {
+                fn cached_kind(&self) -> CachedKind {
+                    CachedKind::$kind
+                }
+            }
+        )*
+    };
+}
+
+impl_cacheable!(
+    PublicTrade => Trade,
+    OrderBookL1 => OrderBookL1,
+    OrderBook => OrderBook,
+    Candle => Candle,
+    Liquidation => Liquidation,
+    Greeks => Greeks,
+    Ticker => Ticker,
+    MiniTicker => MiniTicker,
+);
+
+impl Cacheable for DataKind {
+    fn cached_kind(&self) -> CachedKind {
+        match self {
+            DataKind::Trade(_) => CachedKind::Trade,
+            DataKind::OrderBookL1(_) => CachedKind::OrderBookL1,
+            DataKind::OrderBook(_) => CachedKind::OrderBook,
+            DataKind::Candle(_) => CachedKind::Candle,
+            DataKind::Liquidation(_) => CachedKind::Liquidation,
+            DataKind::Greeks(_) => CachedKind::Greeks,
+            DataKind::Ticker(_) => CachedKind::Ticker,
+            DataKind::MiniTicker(_) => CachedKind::MiniTicker,
+        }
+    }
+}
+
+/// Communicative type alias for the key of a [`LastValueCache`] entry.
+type CacheKey = (Exchange, Instrument, CachedKind);
+
+/// Optional last-value cache of the latest [`MarketEvent<T>`](MarketEvent) per (exchange,
+/// [`Instrument`], [`CachedKind`]), populated by the [`cache_last_values`] stage.
+///
+/// The synchronous query API allows request/response components (eg/ REST servers, UIs) to read
+/// the latest price or book without subscribing to the stream. Cloning a [`LastValueCache`]
+/// yields a handle to the same cache.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::{
+///     event::DataKind,
+///     exchange::ExchangeId,
+///     streams::cache::{CachedKind, LastValueCache},
+/// };
+/// use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+///
+/// let cache = LastValueCache::<DataKind>::new();
+///
+/// let latest_book = cache.latest(
+///     ExchangeId::BinanceSpot,
+///     &Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+///     CachedKind::OrderBookL1,
+/// );
+/// assert!(latest_book.is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct LastValueCache<T> {
+    events: Arc<RwLock<HashMap<CacheKey, MarketEvent<T>>>>,
+}
+
+impl<T> Default for LastValueCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> LastValueCache<T> {
+    /// Construct a new empty [`Self`].
+    pub fn new() -> Self {
+        Self {
+            events: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Number of cached [`MarketEvent<T>`](MarketEvent)s.
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Determine if the [`LastValueCache`] contains no [`MarketEvent<T>`](MarketEvent)s.
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Remove every cached [`MarketEvent<T>`](MarketEvent).
+    pub fn clear(&self) {
+        self.write().clear()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<CacheKey, MarketEvent<T>>> {
+        self.events
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<CacheKey, MarketEvent<T>>> {
+        self.events
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T> LastValueCache<T>
+where
+    T: Cacheable + Clone,
+{
+    /// Replace the cached value of the [`MarketEvent<T>`](MarketEvent)'s (exchange,
+    /// [`Instrument`], [`CachedKind`]) with the provided event.
+    pub fn update(&self, event: &MarketEvent<T>) {
+        let key = (
+            event.exchange.clone(),
+            event.instrument.clone(),
+            event.kind.cached_kind(),
+        );
+        self.write().insert(key, event.clone());
+    }
+
+    /// Latest [`MarketEvent<T>`](MarketEvent) of the provided exchange [`Instrument`] &
+    /// [`CachedKind`], if any has been received.
+    pub fn latest(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        kind: CachedKind,
+    ) -> Option<MarketEvent<T>> {
+        self.read()
+            .get(&(Exchange::from(exchange), instrument.clone(), kind))
+            .cloned()
+    }
+
+    /// Latest [`MarketEvent<T>`](MarketEvent) of every [`CachedKind`] of the provided exchange
+    /// [`Instrument`].
+    pub fn latest_all(&self, exchange: ExchangeId, instrument: &Instrument) -> Vec<MarketEvent<T>> {
+        let exchange = Exchange::from(exchange);
+        let mut events = self
+            .read()
+            .iter()
+            .filter(|((key_exchange, key_instrument, _), _)| {
+                *key_exchange == exchange && key_instrument == instrument
+            })
+            .map(|((_, _, kind), event)| (*kind, event.clone()))
+            .collect::<Vec<_>>();
+
+        events.sort_by_key(|(kind, _)| *kind);
+        events.into_iter().map(|(_, event)| event).collect()
+    }
+
+    /// Snapshot of every cached [`MarketEvent<T>`](MarketEvent).
+    pub fn snapshot(&self) -> Vec<MarketEvent<T>> {
+        self.read().values().cloned().collect()
+    }
+}
+
+/// Forward every [`MarketEvent<T>`](MarketEvent) received on the `event_rx` to the `event_tx`,
+/// updating the [`LastValueCache`] with each.
+pub async fn cache_last_values<T>(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    event_tx: mpsc::UnboundedSender<MarketEvent<T>>,
+    cache: LastValueCache<T>,
+) where
+    T: Cacheable + Clone,
+{
+    while let Some(event) = event_rx.recv().await {
+        cache.update(&event);
+
+        if event_tx.send(event).is_err() {
+            debug!("MarketEvent receiver dropped - stopping LastValueCache updates");
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixture::{self, market_event},
+        subscription::book::Level,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+
+    fn event(exchange: ExchangeId, base: &str, kind: DataKind) -> MarketEvent<DataKind> {
+        MarketEvent {
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            ..market_event(kind)
+        }
+    }
+
+    fn trade(price: f64) -> DataKind {
+        DataKind::Trade(fixture::trade(price, 1.0))
+    }
+
+    fn l1(price: f64) -> DataKind {
+        DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: Utc::now(),
+            best_bid: Level::new(price, 1.0),
+            best_ask: Level::new(price + 1.0, 1.0),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_cache_last_values() {
+        let cache = LastValueCache::new();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (output_tx, mut output_rx) = mpsc::unbounded_channel();
+
+        let events = vec![
+            event(ExchangeId::BinanceSpot, "btc", trade(100.0)),
+            event(ExchangeId::BinanceSpot, "btc", l1(99.0)),
+            event(ExchangeId::BinanceSpot, "btc", trade(101.0)),
+            event(ExchangeId::Coinbase, "btc", trade(102.0)),
+            event(ExchangeId::BinanceSpot, "eth", trade(10.0)),
+        ];
+        for event in events.iter().cloned() {
+            event_tx.send(event).unwrap();
+        }
+        drop(event_tx);
+
+        cache_last_values(event_rx, output_tx, cache.clone()).await;
+
+        // Every event is forwarded
+        let mut forwarded = Vec::new();
+        while let Some(event) = output_rx.recv().await {
+            forwarded.push(event);
+        }
+        assert_eq!(forwarded, events);
+
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        assert_eq!(cache.len(), 4);
+
+        struct TestCase {
+            exchange: ExchangeId,
+            kind: CachedKind,
+            expected: Option<MarketEvent<DataKind>>,
+        }
+
+        let cases = vec![
+            // TC0: latest trade replaces the earlier trade
+            TestCase {
+                exchange: ExchangeId::BinanceSpot,
+                kind: CachedKind::Trade,
+                expected: Some(events[2].clone()),
+            },
+            // TC1: book is cached independently of trades
+            TestCase {
+                exchange: ExchangeId::BinanceSpot,
+                kind: CachedKind::OrderBookL1,
+                expected: Some(events[1].clone()),
+            },
+            // TC2: exchanges are cached independently
+            TestCase {
+                exchange: ExchangeId::Coinbase,
+                kind: CachedKind::Trade,
+                expected: Some(events[3].clone()),
+            },
+            // TC3: kind never received
+            TestCase {
+                exchange: ExchangeId::Coinbase,
+                kind: CachedKind::OrderBookL1,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = cache.latest(test.exchange, &btc, test.kind);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        assert_eq!(
+            cache.latest_all(ExchangeId::BinanceSpot, &btc),
+            vec![events[2].clone(), events[1].clone()]
+        );
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
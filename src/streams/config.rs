@@ -0,0 +1,409 @@
+#[cfg(feature = "profiling")]
+use super::profiling::TaskProfiler;
+use super::{
+    bandwidth::BandwidthMeter, breaker::ErrorTolerance, limits::FrameLimits, probe::LatencyProbe,
+    reconcile::ReconcileTrigger, sample::SampleConfig, tap::RawTap,
+};
+use crate::{
+    exchange::Connector,
+    normalise::AssetNormaliser,
+    transport::{BoxTransport, SharedTransport, TransportConnector, TungsteniteConnector},
+};
+use barter_integration::error::SocketError;
+use std::borrow::Borrow;
+use url::Url;
+
+/// Re-exported since [`Credentials`] are provided to private streams via the [`StreamConfig`].
+#[cfg(feature = "private")]
+pub use crate::credentials::Credentials;
+
+/// Connection level configuration shared by every [`MarketStream`](crate::MarketStream)
+/// initialised from one batch of [`Subscription`](crate::subscription::Subscription)s.
+///
+/// A default [`StreamConfig`] connects to the exchange server defined by the [`Connector`].
+///
+/// A [`LatencyProbe`] can be configured to select the lowest latency regional endpoint of the
+/// [`Connector`] each time a connection is established, rather than hard-coding a region.
+///
+/// Ordered backup WebSocket [`Url`]s can be configured, which the consumer loop fails over to
+/// (in order, wrapping back to the primary endpoint) after repeated connection failures.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{config::StreamConfig, probe::LatencyProbe};
+/// use url::Url;
+///
+/// // Connect to a regional endpoint rather than the Connector default
+/// let config = StreamConfig::default()
+///     .with_url(Url::parse("wss://stream.binance.us:9443/ws").unwrap());
+///
+/// // Connect to the lowest latency Connector regional endpoint
+/// let config = StreamConfig::default().with_latency_probe(LatencyProbe::default());
+///
+/// // Fail over from the Connector default to a mirror endpoint
+/// let config = StreamConfig::default()
+///     .with_backup_url(Url::parse("wss://data-stream.binance.vision/ws").unwrap());
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct StreamConfig {
+    /// Optional WebSocket [`Url`] that overrides the [`Connector::channel_url`] (eg/ a regional endpoint,
+    /// a testnet, or a local proxy).
+    pub url: Option<Url>,
+
+    /// Ordered backup WebSocket [`Url`]s, failed over to after repeated connection failures to
+    /// the active endpoint. Like the [`Self::url`] override, a backup serves every channel.
+    pub backup_urls: Vec<Url>,
+
+    /// Index of the active endpoint, where `0` is the primary endpoint ([`Self::url`], or the
+    /// [`Connector`] default) and `n` is `backup_urls[n - 1]`.
+    endpoint: usize,
+
+    /// Optional API [`Credentials`] used to authenticate private account streams (eg/
+    /// [`UserTrades`](crate::subscription::trade::UserTrades)).
+    #[cfg(feature = "private")]
+    pub credentials: Option<Credentials>,
+
+    /// Optional [`RawTap`] that every raw frame received by the connection is broadcast to.
+    pub raw_tap: Option<RawTap>,
+
+    /// Optional [`BandwidthMeter`] that records the bytes received by the connection.
+    pub bandwidth_meter: Option<BandwidthMeter>,
+
+    /// Optional [`TaskProfiler`] that records the poll durations & allocations of the connection
+    /// task.
+    #[cfg(feature = "profiling")]
+    pub profiler: Option<TaskProfiler>,
+
+    /// Optional [`FrameLimits`] enforced on every frame received by the connection.
+    pub frame_limits: Option<FrameLimits>,
+
+    /// Optional [`SharedTransport`] used to connect to the exchange, otherwise the default
+    /// [`TungsteniteConnector`] is used.
+    pub transport: Option<SharedTransport>,
+
+    /// Optional [`LatencyProbe`] used to select the lowest latency [`Connector`] regional
+    /// endpoint when no [`Self::url`] override is configured.
+    pub latency_probe: Option<LatencyProbe>,
+
+    /// [`AssetNormaliser`] applied during subscription mapping, defaulting to the built-in
+    /// exchange asset code aliases.
+    pub normaliser: AssetNormaliser,
+
+    /// Optional [`ErrorTolerance`] of the deserialisation errors consumed from the connection,
+    /// otherwise every deserialisation error is logged & skipped.
+    pub error_tolerance: Option<ErrorTolerance>,
+
+    /// Optional [`SampleConfig`] rate limiting the normalised events forwarded per
+    /// [`Instrument`](barter_integration::model::instrument::Instrument) by the
+    /// [`consume`](super::consumer::consume) loop, for low priority feeds. Not applied to
+    /// [`SharedStreamBuilder`](super::builder::shared::SharedStreamBuilder) connections.
+    pub sample: Option<SampleConfig>,
+
+    /// Optional [`ReconcileTrigger`] fired by the [`consume`](super::consumer::consume) loop
+    /// for the [`Instrument`](barter_integration::model::instrument::Instrument) of each
+    /// [`DataError::TradeGap`](crate::error::DataError) detected by the connection.
+    pub reconcile_trigger: Option<ReconcileTrigger>,
+}
+
+impl StreamConfig {
+    /// Override the [`Connector::url`] with the provided WebSocket [`Url`].
+    pub fn with_url(mut self, url: Url) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Add a backup WebSocket [`Url`], failed over to after any previously added backups.
+    pub fn with_backup_url(mut self, url: Url) -> Self {
+        self.backup_urls.push(url);
+        self
+    }
+
+    /// Number of configured endpoints, including the primary endpoint.
+    pub fn num_endpoints(&self) -> usize {
+        self.backup_urls.len() + 1
+    }
+
+    /// Rotate to the next configured endpoint, wrapping back to the primary endpoint after the
+    /// final backup. Returns false if there are no backup endpoints to fail over to.
+    pub fn failover(&mut self) -> bool {
+        if self.backup_urls.is_empty() {
+            return false;
+        }
+
+        self.endpoint = (self.endpoint + 1) % self.num_endpoints();
+        true
+    }
+
+    /// Authenticate private account streams with the provided API [`Credentials`].
+    #[cfg(feature = "private")]
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Broadcast every raw frame received by the connection to the provided [`RawTap`].
+    pub fn with_raw_tap(mut self, tap: RawTap) -> Self {
+        self.raw_tap = Some(tap);
+        self
+    }
+
+    /// Record the bytes received by the connection with the provided [`BandwidthMeter`].
+    pub fn with_bandwidth_meter(mut self, meter: BandwidthMeter) -> Self {
+        self.bandwidth_meter = Some(meter);
+        self
+    }
+
+    /// Record the poll durations & allocations of the connection task with the provided
+    /// [`TaskProfiler`].
+    #[cfg(feature = "profiling")]
+    pub fn with_profiler(mut self, profiler: TaskProfiler) -> Self {
+        self.profiler = Some(profiler);
+        self
+    }
+
+    /// Enforce the provided [`FrameLimits`] on every frame received by the connection.
+    pub fn with_frame_limits(mut self, limits: FrameLimits) -> Self {
+        self.frame_limits = Some(limits);
+        self
+    }
+
+    /// Connect to the exchange via the provided [`TransportConnector`] rather than the default
+    /// [`TungsteniteConnector`] (eg/ a custom tokio-tungstenite configuration, an alternative
+    /// WebSocket client, or an in-memory test transport).
+    pub fn with_transport<Connector>(mut self, connector: Connector) -> Self
+    where
+        Connector: TransportConnector + 'static,
+    {
+        self.transport = Some(SharedTransport::new(connector));
+        self
+    }
+
+    /// Select the lowest latency [`Connector`] regional endpoint with the provided
+    /// [`LatencyProbe`] each time a connection is (re)established.
+    pub fn with_latency_probe(mut self, probe: LatencyProbe) -> Self {
+        self.latency_probe = Some(probe);
+        self
+    }
+
+    /// Forward a sample of the normalised events of each
+    /// [`Instrument`](barter_integration::model::instrument::Instrument), rate limited by the
+    /// provided [`SampleConfig`] (eg/ for dashboards & loggers that do not need every tick).
+    pub fn with_sampling(mut self, config: SampleConfig) -> Self {
+        self.sample = Some(config);
+        self
+    }
+
+    /// Request the immediate REST reconciliation of an
+    /// [`Instrument`](barter_integration::model::instrument::Instrument) via the provided
+    /// [`ReconcileTrigger`] whenever the connection detects a trade id gap.
+    pub fn with_reconcile_trigger(mut self, trigger: ReconcileTrigger) -> Self {
+        self.reconcile_trigger = Some(trigger);
+        self
+    }
+
+    /// Override the default [`AssetNormaliser`] (eg/ with additional asset code aliases).
+    pub fn with_normaliser(mut self, normaliser: AssetNormaliser) -> Self {
+        self.normaliser = normaliser;
+        self
+    }
+
+    /// Tolerate the deserialisation errors consumed from the connection according to the
+    /// provided [`ErrorTolerance`], tripping a
+    /// [`CircuitBreaker`](super::breaker::CircuitBreaker) once exceeded.
+    pub fn with_error_tolerance(mut self, tolerance: ErrorTolerance) -> Self {
+        self.error_tolerance = Some(tolerance);
+        self
+    }
+
+    /// Connect a [`BoxTransport`] to the provided exchange server [`Url`] using the configured
+    /// [`SharedTransport`], or the default [`TungsteniteConnector`] (limited by the
+    /// [`FrameLimits`] if configured).
+    pub async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+        match (&self.transport, &self.frame_limits) {
+            (Some(transport), _) => transport.connect(url).await,
+            (None, Some(limits)) => limits.connect(url).await,
+            (None, None) => TungsteniteConnector.connect(url).await,
+        }
+    }
+
+    /// Determine the WebSocket [`Url`] to connect to for the provided exchange channel. Uses the
+    /// active backup [`Url`] if failed over, else the override [`Url`] if configured, otherwise
+    /// falls back to the [`Connector::channel_url`].
+    pub fn resolve_url<Exchange>(&self, channel: &Exchange::Channel) -> Result<Url, SocketError>
+    where
+        Exchange: Connector,
+    {
+        let backup = self
+            .endpoint
+            .checked_sub(1)
+            .and_then(|index| self.backup_urls.get(index));
+
+        match backup.or(self.url.as_ref()) {
+            Some(url) => Ok(url.clone()),
+            None => Exchange::channel_url(channel),
+        }
+    }
+
+    /// Determine the WebSocket [`Url`] to connect to for a single connection serving every
+    /// provided exchange channel (see [`Self::resolve_url`]).
+    ///
+    /// Returns an error if no channels are provided, or if the channels are served by different
+    /// exchange servers and therefore cannot share a connection.
+    pub fn resolve_connection_url<Exchange, Channels, Channel>(
+        &self,
+        channels: Channels,
+    ) -> Result<Url, SocketError>
+    where
+        Exchange: Connector,
+        Channels: IntoIterator<Item = Channel>,
+        Channel: Borrow<Exchange::Channel>,
+    {
+        let mut urls = channels
+            .into_iter()
+            .map(|channel| self.resolve_url::<Exchange>(channel.borrow()))
+            .collect::<Result<Vec<_>, SocketError>>()?;
+        urls.sort();
+        urls.dedup();
+
+        match urls.as_slice() {
+            [url] => Ok(url.clone()),
+            [] => Err(SocketError::Subscribe(
+                "no Subscriptions to action".to_owned(),
+            )),
+            _ => Err(SocketError::Subscribe(format!(
+                "{} Subscriptions are served by different servers and cannot share a connection: {urls:?}",
+                Exchange::ID
+            ))),
+        }
+    }
+
+    /// Select the endpoint to connect to given the [`Self::resolve_url`] [`Url`]. If a
+    /// [`LatencyProbe`] is configured and the [`Url`] is the [`Connector`] default, the lowest
+    /// latency regional endpoint is selected, otherwise the provided [`Url`] is used as is.
+    pub async fn select_url<Exchange>(&self, url: Url) -> Url
+    where
+        Exchange: Connector,
+    {
+        match (&self.latency_probe, &self.url, self.endpoint) {
+            (Some(probe), None, 0) => probe.select::<Exchange>(self, url).await,
+            _ => url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{
+        coinbase::{channel::CoinbaseChannel, Coinbase},
+        okx::{channel::OkxChannel, Okx, BASE_URL_OKX, BASE_URL_OKX_BUSINESS},
+    };
+
+    #[test]
+    fn test_resolve_url() {
+        struct TestCase {
+            input: StreamConfig,
+            channel: OkxChannel,
+            expected: Url,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: default StreamConfig uses Connector::url for a public channel
+                input: StreamConfig::default(),
+                channel: OkxChannel::TRADES,
+                expected: Url::parse(BASE_URL_OKX).unwrap(),
+            },
+            TestCase {
+                // TC1: default StreamConfig routes candle channel to secondary server
+                input: StreamConfig::default(),
+                channel: OkxChannel::CANDLE_1M,
+                expected: Url::parse(BASE_URL_OKX_BUSINESS).unwrap(),
+            },
+            TestCase {
+                // TC2: StreamConfig w/ override Url takes precedence over channel routing
+                input: StreamConfig::default()
+                    .with_url(Url::parse("wss://localhost:8080/ws").unwrap()),
+                channel: OkxChannel::CANDLE_1M,
+                expected: Url::parse("wss://localhost:8080/ws").unwrap(),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.resolve_url::<Okx>(&test.channel).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        // Connector without secondary servers always uses Connector::url
+        assert_eq!(
+            StreamConfig::default()
+                .resolve_url::<Coinbase>(&CoinbaseChannel::TRADES)
+                .unwrap(),
+            Coinbase::url().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resolve_connection_url() {
+        let config = StreamConfig::default();
+
+        // Channels served by the same exchange server share a connection
+        assert_eq!(
+            config
+                .resolve_connection_url::<Okx, _, _>([OkxChannel::TRADES, OkxChannel::TRADES])
+                .unwrap(),
+            Url::parse(BASE_URL_OKX).unwrap()
+        );
+
+        // Channels served by different exchange servers cannot share a connection
+        assert!(config
+            .resolve_connection_url::<Okx, _, _>([OkxChannel::TRADES, OkxChannel::CANDLE_1M])
+            .is_err());
+
+        // Override Url serves every channel
+        assert_eq!(
+            StreamConfig::default()
+                .with_url(Url::parse("wss://localhost:8080/ws").unwrap())
+                .resolve_connection_url::<Okx, _, _>([OkxChannel::TRADES, OkxChannel::CANDLE_1M])
+                .unwrap(),
+            Url::parse("wss://localhost:8080/ws").unwrap()
+        );
+
+        // At least one channel must be provided
+        assert!(config
+            .resolve_connection_url::<Okx, _, OkxChannel>([])
+            .is_err());
+    }
+
+    #[test]
+    fn test_failover() {
+        let backup_0 = Url::parse("wss://backup-0:8080/ws").unwrap();
+        let backup_1 = Url::parse("wss://backup-1:8080/ws").unwrap();
+
+        // StreamConfig without backups cannot fail over
+        let mut config = StreamConfig::default();
+        assert!(!config.failover());
+        assert_eq!(
+            config.resolve_url::<Okx>(&OkxChannel::TRADES).unwrap(),
+            Url::parse(BASE_URL_OKX).unwrap()
+        );
+
+        // StreamConfig rotates through backups in order, wrapping back to the primary endpoint
+        let mut config = StreamConfig::default()
+            .with_backup_url(backup_0.clone())
+            .with_backup_url(backup_1.clone());
+        assert_eq!(config.num_endpoints(), 3);
+
+        let expected = vec![
+            backup_0,
+            backup_1,
+            Url::parse(BASE_URL_OKX_BUSINESS).unwrap(),
+        ];
+
+        for (index, expected) in expected.into_iter().enumerate() {
+            assert!(config.failover(), "TC{} failed", index);
+            let actual = config.resolve_url::<Okx>(&OkxChannel::CANDLE_1M).unwrap();
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,12 +1,20 @@
+#[cfg(feature = "profiling")]
+use crate::streams::profiling::ProfiledStream;
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::StreamSelector,
+    exchange::{ExchangeId, StreamSelector},
+    streams::{
+        breaker::{BreakerStatus, CircuitBreaker, TripAction},
+        config::StreamConfig,
+        sample::sample,
+    },
     subscription::{SubKind, Subscription},
+    transformer::{enrich::SharedEnricher, filter::SharedFilter},
     Identifier, MarketStream,
 };
 use futures::StreamExt;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
@@ -15,18 +23,61 @@ use tracing::{error, info, warn};
 /// of repeated disconnections with re-initialisation failures.
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
+/// Maximum duration that the [`consume`] function waits between attempts to re-initialise a
+/// [`MarketStream`], bounding the exponential backoff.
+pub const MAX_RECONNECT_BACKOFF_MS: u64 = 30_000;
+
+/// Number of consecutive [`MarketStream`] re-initialisation failures after which the consumer
+/// loop fails over to the next [`StreamConfig`] endpoint (if any backups are configured).
+pub const FAILOVER_AFTER_FAILURES: u32 = 3;
+
 /// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
 ///
-/// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
+/// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s and the
+/// connection level [`StreamConfig`]. Consumed
 /// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
 /// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
+///
+/// If the [`StreamConfig`] contains backup endpoints, the initial connection tries each endpoint
+/// in turn, and re-connections fail over to the next endpoint after
+/// [`FAILOVER_AFTER_FAILURES`] consecutive failures.
+///
+/// If the [`StreamConfig`] contains a [`SampleConfig`](super::sample::SampleConfig), the
+/// consumed events are sampled before being distributed downstream.
+///
+/// If the [`StreamConfig`] contains an [`ErrorTolerance`](super::breaker::ErrorTolerance),
+/// deserialisation errors are counted by a [`CircuitBreaker`] that re-initialises the
+/// [`MarketStream`], or disables it by returning [`DataError::CircuitBreakerTripped`], once the
+/// tolerance is exceeded.
 pub async fn consume<Exchange, Kind>(
     subscriptions: Vec<Subscription<Exchange, Kind>>,
+    config: StreamConfig,
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
+) -> DataError
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Kind::Event: Send + 'static,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    consume_with(subscriptions, config, exchange_tx, None, None).await
+}
+
+/// Central [`MarketEvent<T>`](MarketEvent) consumer loop, as per [`consume`], that discards
+/// each consumed event rejected by the optional [`EventFilter`](crate::transformer::filter::EventFilter),
+/// then applies the optional [`Enricher`](crate::transformer::enrich::Enricher), before it is
+/// distributed downstream.
+pub async fn consume_with<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    mut config: StreamConfig,
     exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
+    filter: Option<SharedFilter<Kind::Event>>,
+    enricher: Option<SharedEnricher<Kind::Event>>,
 ) -> DataError
 where
     Exchange: StreamSelector<Kind>,
     Kind: SubKind,
+    Kind::Event: Send + 'static,
     Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
 {
     // Determine ExchangeId associated with these Subscriptions
@@ -35,45 +86,89 @@ where
     info!(
         %exchange,
         ?subscriptions,
+        ?config,
         policy = "retry connection with exponential backoff",
         "MarketStream consumer loop running",
     );
 
+    // Sample consumed events per Instrument if configured, applied after normalisation
+    let exchange_tx = match config.sample {
+        Some(sample_config) => {
+            let (sample_tx, sample_rx) = mpsc::unbounded_channel();
+            tokio::spawn(sample(sample_rx, exchange_tx, sample_config));
+            sample_tx
+        }
+        None => exchange_tx,
+    };
+
+    // Count deserialisation errors against the ErrorTolerance if configured
+    let mut breaker = config
+        .error_tolerance
+        .clone()
+        .map(|tolerance| CircuitBreaker::new(exchange, tolerance));
+
     // Consumer loop retry parameters
     let mut attempt: u32 = 0;
     let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    let mut initialised = false;
 
     loop {
-        // Increment retry parameters at start of every iteration
         attempt += 1;
-        backoff_ms *= 2;
         info!(%exchange, attempt, "attempting to initialise MarketStream");
 
         // Attempt to initialise MarketStream: if it fails on first attempt return DataError
-        let mut stream = match Exchange::Stream::init(&subscriptions).await {
+        let stream = match Exchange::Stream::init(&subscriptions, &config).await {
             Ok(stream) => {
                 info!(%exchange, attempt, "successfully initialised MarketStream");
                 attempt = 0;
                 backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
+                initialised = true;
                 stream
             }
             Err(error) => {
-                error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
+                error!(
+                    %exchange,
+                    attempt,
+                    ?error,
+                    backoff_ms,
+                    "failed to initialise MarketStream"
+                );
 
-                // Exit function function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
+                // Exit function if Stream::init failed the first attempt at every endpoint
+                if !initialised && attempt as usize >= config.num_endpoints() {
                     return error;
-                } else {
-                    continue;
                 }
+
+                // Fail over to the next endpoint, else retry
+                if (!initialised || attempt.is_multiple_of(FAILOVER_AFTER_FAILURES))
+                    && config.failover()
+                {
+                    warn!(%exchange, attempt, "failing over to next MarketStream endpoint");
+                }
+
+                // Backoff before the next attempt, so repeated failures do not hammer the exchange
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                backoff_ms = next_backoff_ms(backoff_ms);
+                continue;
             }
         };
 
+        // Profile the polls of the MarketStream connection task if configured
+        let mut stream = profile(stream, &config, exchange);
+
         // Consume Result<MarketEvent<T>, DataError> from MarketStream
         while let Some(event_result) = stream.next().await {
             match event_result {
-                // If Ok: send MarketEvent<T> to exchange receiver
-                Ok(market_event) => {
+                // If Ok: filter, enrich & send MarketEvent<T> to exchange receiver
+                Ok(mut market_event) => {
+                    if let Some(filter) = &filter {
+                        if !filter.retain(&market_event) {
+                            continue;
+                        }
+                    }
+                    if let Some(enricher) = &enricher {
+                        enricher.enrich(&mut market_event);
+                    }
                     let _ = exchange_tx.send(market_event).map_err(|err| {
                         error!(
                             payload = ?err.0,
@@ -93,15 +188,48 @@ where
                     break;
                 }
 
-                // If non-terminal DataError: log & continue
+                // If non-terminal DataError: log & continue, unless it trips the CircuitBreaker
                 Err(error) => {
-                    warn!(
-                        %exchange,
-                        %error,
-                        action = "skipping message",
-                        "consumed DataError from MarketStream",
-                    );
-                    continue;
+                    // Backfill the trades missed by a trade id gap via REST if configured
+                    if let (DataError::TradeGap { instrument, .. }, Some(trigger)) =
+                        (&error, &config.reconcile_trigger)
+                    {
+                        trigger.trigger(instrument.clone());
+                    }
+
+                    let status = breaker
+                        .as_mut()
+                        .and_then(|breaker| breaker.record(&error, Instant::now()));
+
+                    match (status, &breaker) {
+                        (Some(BreakerStatus::Tripped(TripAction::Reconnect)), _) => {
+                            error!(
+                                %exchange,
+                                %error,
+                                action = "re-initialising Stream",
+                                "deserialisation error CircuitBreaker tripped",
+                            );
+                            break;
+                        }
+                        (Some(BreakerStatus::Tripped(TripAction::Disable)), Some(breaker)) => {
+                            error!(
+                                %exchange,
+                                %error,
+                                action = "disabling Stream",
+                                "deserialisation error CircuitBreaker tripped",
+                            );
+                            return breaker.tripped_error();
+                        }
+                        _ => {
+                            warn!(
+                                %exchange,
+                                %error,
+                                action = "skipping message",
+                                "consumed DataError from MarketStream",
+                            );
+                            continue;
+                        }
+                    }
                 }
             }
         }
@@ -114,5 +242,85 @@ where
             "exchange MarketStream unexpectedly ended"
         );
         tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+        backoff_ms = next_backoff_ms(backoff_ms);
+    }
+}
+
+/// Double the provided reconnection backoff, up to the [`MAX_RECONNECT_BACKOFF_MS`].
+pub(super) fn next_backoff_ms(backoff_ms: u64) -> u64 {
+    backoff_ms.saturating_mul(2).min(MAX_RECONNECT_BACKOFF_MS)
+}
+
+/// Wrap the [`MarketStream`] of a connection in a [`ProfiledStream`] that records it's polls with
+/// the [`StreamConfig`] [`TaskProfiler`](super::profiling::TaskProfiler), if configured.
+#[cfg(feature = "profiling")]
+fn profile<Stream>(
+    stream: Stream,
+    config: &StreamConfig,
+    exchange: ExchangeId,
+) -> ProfiledStream<Stream> {
+    ProfiledStream::new(
+        stream,
+        config
+            .profiler
+            .as_ref()
+            .map(|profiler| profiler.register(exchange)),
+    )
+}
+
+/// Connection task profiling is only available via the `profiling` feature.
+#[cfg(not(feature = "profiling"))]
+fn profile<Stream>(stream: Stream, _: &StreamConfig, _: ExchangeId) -> Stream {
+    stream
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::spot::BinanceSpot,
+        subscription::trade::PublicTrades,
+        testing::{FaultScript, MockExchange},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use url::Url;
+
+    #[test]
+    fn test_next_backoff_ms() {
+        assert_eq!(next_backoff_ms(STARTING_RECONNECT_BACKOFF_MS), 250);
+        assert_eq!(next_backoff_ms(20_000), MAX_RECONNECT_BACKOFF_MS);
+        assert_eq!(next_backoff_ms(u64::MAX), MAX_RECONNECT_BACKOFF_MS);
+    }
+
+    #[tokio::test]
+    async fn test_consume_backs_off_after_init_failure() {
+        // Primary endpoint is refused, so the consumer fails over to the backup endpoint
+        let exchange = MockExchange::new([
+            FaultScript::refused(),
+            FaultScript::new()
+                .await_request()
+                .send_text(r#"{"result":null,"id":1}"#)
+                .send_text(r#"{"e":"trade","E":1,"s":"ETHUSDT","t":1,"p":"1.0","q":"1.0","T":1,"m":false}"#),
+        ]);
+        let config = StreamConfig::default()
+            .with_transport(exchange.clone())
+            .with_backup_url(Url::parse("wss://backup.mock.exchange/ws").unwrap());
+
+        let subscriptions = vec![Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        let start = Instant::now();
+        tokio::spawn(consume(subscriptions, config, exchange_tx));
+        assert!(exchange_rx.recv().await.is_some());
+
+        // Backup endpoint is only attempted after the reconnection backoff
+        assert_eq!(exchange.connections(), 2);
+        assert!(start.elapsed() >= Duration::from_millis(STARTING_RECONNECT_BACKOFF_MS));
     }
 }
@@ -0,0 +1,351 @@
+use crate::{
+    event::{MarketEvent, Origin},
+    exchange::Connector,
+    subscription::candle::{Candle, Candles, Interval},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Exchange [`Connector`] that serves historical [`Candle`]s via HTTP, used to backfill gaps in a
+/// WebSocket candle stream (see [`check_candle_continuity`]).
+#[async_trait]
+pub trait HistoricalCandles
+where
+    Self: Connector,
+{
+    /// Fetch the closed [`Candle`]s of the provided [`Instrument`] & [`Candles`] variant that
+    /// close between the `start` and `end` times (inclusive).
+    async fn historical_candles(
+        instrument: &Instrument,
+        kind: Candles,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MarketEvent<Candle>>, SocketError>;
+}
+
+/// Missing [`Interval`]s detected in a [`Candle`] stream (eg/ a dropped 1m bar during a
+/// re-connection).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CandleGap {
+    pub interval: Interval,
+    /// Close time of the last [`Candle`] received before the gap.
+    pub previous_close_time: DateTime<Utc>,
+    /// Close time of the first [`Candle`] received after the gap.
+    pub close_time: DateTime<Utc>,
+    /// Number of missing [`Candle`]s.
+    pub missing: u32,
+}
+
+impl CandleGap {
+    /// Determine if the provided close time lies within this [`CandleGap`] (exclusive).
+    pub fn contains(&self, close_time: DateTime<Utc>) -> bool {
+        close_time > self.previous_close_time && close_time < self.close_time
+    }
+}
+
+/// Output of the [`check_candle_continuity`] stage.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum ContinuityEvent {
+    /// [`Candle`] received from the stream.
+    Candle(Candle),
+    /// [`CandleGap`] detected before the next streamed [`Candle`].
+    Gap(CandleGap),
+    /// Correction [`Candle`] backfilled into a [`CandleGap`] via HTTP, emitted after the
+    /// [`ContinuityEvent::Gap`] and before the next streamed [`Candle`].
+    Correction(Candle),
+}
+
+/// Checker detecting missing [`Interval`]s in a [`Candle`] stream, tracking the last close time
+/// of each exchange [`Instrument`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CandleContinuityChecker {
+    interval: Interval,
+    last_close_times: HashMap<(Exchange, Instrument), DateTime<Utc>>,
+}
+
+impl CandleContinuityChecker {
+    /// Construct a new [`Self`] for [`Candle`]s of the provided [`Interval`].
+    pub fn new(interval: Interval) -> Self {
+        Self {
+            interval,
+            last_close_times: HashMap::new(),
+        }
+    }
+
+    /// Process the next [`Candle`], returning the [`CandleGap`] since the previous [`Candle`] of
+    /// the same exchange [`Instrument`], if any.
+    ///
+    /// Duplicate & out of order [`Candle`]s never produce a [`CandleGap`].
+    pub fn process(&mut self, candle: &MarketEvent<Candle>) -> Option<CandleGap> {
+        let close_time = candle.kind.close_time;
+        let previous_close_time = self
+            .last_close_times
+            .entry((candle.exchange.clone(), candle.instrument.clone()))
+            .or_insert(close_time);
+
+        if close_time <= *previous_close_time {
+            return None;
+        }
+
+        let elapsed = close_time - *previous_close_time;
+        let gap = CandleGap {
+            interval: self.interval,
+            previous_close_time: *previous_close_time,
+            close_time,
+            missing: missing_intervals(elapsed, self.interval.duration()),
+        };
+        *previous_close_time = close_time;
+
+        (gap.missing > 0).then_some(gap)
+    }
+}
+
+/// Number of whole [`Interval`]s missing between two consecutive close times, tolerating up to
+/// half an interval of close time jitter.
+fn missing_intervals(elapsed: chrono::Duration, interval: chrono::Duration) -> u32 {
+    let elapsed = elapsed.num_milliseconds();
+    let interval = interval.num_milliseconds().max(1);
+    let intervals = (elapsed + interval / 2) / interval;
+    u32::try_from(intervals.saturating_sub(1)).unwrap_or(u32::MAX)
+}
+
+/// Forward every [`MarketEvent<Candle>`] received on the `candle_rx` to the `event_tx` as a
+/// [`ContinuityEvent::Candle`], detecting missing [`Interval`]s using a
+/// [`CandleContinuityChecker`].
+///
+/// On detecting a gap a [`ContinuityEvent::Gap`] is emitted, followed by a
+/// [`ContinuityEvent::Correction`] for each missing [`Candle`] backfilled via the exchange
+/// [`HistoricalCandles`] HTTP API with an [`Origin::Backfill`] `origin`. Gaps that cannot be backfilled are only notified.
+pub async fn check_candle_continuity<Exchange>(
+    mut candle_rx: mpsc::UnboundedReceiver<MarketEvent<Candle>>,
+    event_tx: mpsc::UnboundedSender<MarketEvent<ContinuityEvent>>,
+    kind: Candles,
+) where
+    Exchange: HistoricalCandles,
+{
+    let exchange = Exchange::ID;
+    let mut checker = CandleContinuityChecker::new(kind.interval());
+
+    while let Some(candle) = candle_rx.recv().await {
+        let mut events = Vec::new();
+
+        if let Some(gap) = checker.process(&candle) {
+            warn!(%exchange, instrument = %candle.instrument, ?gap, "detected candle gap");
+            events.push(candle_event(
+                &candle,
+                gap.close_time,
+                ContinuityEvent::Gap(gap),
+            ));
+
+            match Exchange::historical_candles(
+                &candle.instrument,
+                kind,
+                gap.previous_close_time,
+                gap.close_time,
+            )
+            .await
+            {
+                Ok(backfilled) => {
+                    let mut backfilled = backfilled
+                        .into_iter()
+                        .filter(|backfill| gap.contains(backfill.kind.close_time))
+                        .collect::<Vec<_>>();
+                    backfilled.sort_by_key(|backfill| backfill.kind.close_time);
+                    backfilled.dedup_by_key(|backfill| backfill.kind.close_time);
+
+                    if backfilled.len() < gap.missing as usize {
+                        warn!(
+                            %exchange,
+                            instrument = %candle.instrument,
+                            missing = gap.missing,
+                            backfilled = backfilled.len(),
+                            "candle gap only partially backfilled"
+                        );
+                    }
+
+                    events.extend(backfilled.into_iter().map(|backfill| {
+                        let mut correction = candle_event(
+                            &backfill,
+                            backfill.kind.close_time,
+                            ContinuityEvent::Correction(backfill.kind),
+                        );
+                        correction.origin = Origin::Backfill;
+                        correction
+                    }));
+                }
+                Err(error) => {
+                    warn!(%exchange, instrument = %candle.instrument, %error, "failed to backfill candle gap");
+                }
+            }
+        }
+
+        events.push(candle_event(
+            &candle,
+            candle.exchange_time,
+            ContinuityEvent::Candle(candle.kind),
+        ));
+
+        for event in events {
+            if event_tx.send(event).is_err() {
+                debug!(%exchange, "ContinuityEvent receiver dropped - stopping candle continuity checks");
+                return;
+            }
+        }
+    }
+}
+
+/// Construct a [`MarketEvent<ContinuityEvent>`] using the metadata of the provided
+/// [`MarketEvent<Candle>`].
+fn candle_event(
+    candle: &MarketEvent<Candle>,
+    exchange_time: DateTime<Utc>,
+    kind: ContinuityEvent,
+) -> MarketEvent<ContinuityEvent> {
+    MarketEvent {
+        exchange_time,
+        received_time: candle.received_time,
+        received_monotonic: candle.received_monotonic,
+        origin: candle.origin,
+        exchange: candle.exchange.clone(),
+        instrument: candle.instrument.clone(),
+        kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::coinbase::Coinbase, fixture::market_event};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn close_time(minute: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(minute * 60, 0).unwrap()
+    }
+
+    fn candle(minute: i64) -> MarketEvent<Candle> {
+        MarketEvent {
+            exchange_time: close_time(minute),
+            exchange: Exchange::from(Coinbase::ID),
+            instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+            ..market_event(Candle {
+                close_time: close_time(minute),
+                open: minute as f64,
+                high: minute as f64,
+                low: minute as f64,
+                close: minute as f64,
+                volume: 1.0,
+                trade_count: 1,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl HistoricalCandles for Coinbase {
+        async fn historical_candles(
+            _: &Instrument,
+            _: Candles,
+            _: DateTime<Utc>,
+            _: DateTime<Utc>,
+        ) -> Result<Vec<MarketEvent<Candle>>, SocketError> {
+            // Includes candles outside of the gap, which must be discarded
+            Ok((1..=6).map(candle).collect())
+        }
+    }
+
+    #[test]
+    fn test_candle_continuity_checker() {
+        let mut checker = CandleContinuityChecker::new(Interval::Minute1);
+
+        struct TestCase {
+            input: MarketEvent<Candle>,
+            expected: Option<CandleGap>,
+        }
+
+        let cases = vec![
+            // TC0: first candle
+            TestCase {
+                input: candle(1),
+                expected: None,
+            },
+            // TC1: consecutive candle
+            TestCase {
+                input: candle(2),
+                expected: None,
+            },
+            // TC2: duplicate candle
+            TestCase {
+                input: candle(2),
+                expected: None,
+            },
+            // TC3: two missing candles
+            TestCase {
+                input: candle(5),
+                expected: Some(CandleGap {
+                    interval: Interval::Minute1,
+                    previous_close_time: close_time(2),
+                    close_time: close_time(5),
+                    missing: 2,
+                }),
+            },
+            // TC4: out of order candle
+            TestCase {
+                input: candle(4),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = checker.process(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_candle_continuity() {
+        let (candle_tx, candle_rx) = mpsc::unbounded_channel();
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        candle_tx.send(candle(1)).unwrap();
+        candle_tx.send(candle(2)).unwrap();
+        candle_tx.send(candle(5)).unwrap();
+        drop(candle_tx);
+
+        check_candle_continuity::<Coinbase>(candle_rx, event_tx, Candles::Last(Interval::Minute1))
+            .await;
+
+        let mut actual = Vec::new();
+        while let Some(event) = event_rx.recv().await {
+            let expected_origin = match event.kind {
+                ContinuityEvent::Correction(_) => Origin::Backfill,
+                _ => Origin::Live,
+            };
+            assert_eq!(event.origin, expected_origin);
+            actual.push(event.kind);
+        }
+
+        assert_eq!(
+            actual,
+            vec![
+                ContinuityEvent::Candle(candle(1).kind),
+                ContinuityEvent::Candle(candle(2).kind),
+                ContinuityEvent::Gap(CandleGap {
+                    interval: Interval::Minute1,
+                    previous_close_time: close_time(2),
+                    close_time: close_time(5),
+                    missing: 2,
+                }),
+                ContinuityEvent::Correction(candle(3).kind),
+                ContinuityEvent::Correction(candle(4).kind),
+                ContinuityEvent::Candle(candle(5).kind),
+            ]
+        );
+    }
+}
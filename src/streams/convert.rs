@@ -0,0 +1,379 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{Level, OrderBook, OrderBookL1, OrderBookSide},
+        candle::Candle,
+        liquidation::Liquidation,
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::symbol::Symbol, Side};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default maximum `exchange_time` difference between an event & the reference price used to
+/// convert it.
+pub const DEFAULT_MAX_RATE_AGE_SECS: i64 = 60;
+
+/// Normalised event whose prices can be re-denominated into another quote currency.
+pub trait Convert
+where
+    Self: Sized,
+{
+    /// Re-denominate every price & quote asset amount by multiplying it by the conversion
+    /// `rate`. Amounts denominated in the base asset are unchanged.
+    fn convert(&self, rate: f64) -> Self;
+}
+
+impl Convert for PublicTrade {
+    fn convert(&self, rate: f64) -> Self {
+        Self {
+            price: self.price * rate,
+            notional: self.notional.map(|notional| notional * rate),
+            ..self.clone()
+        }
+    }
+}
+
+impl Convert for OrderBookL1 {
+    fn convert(&self, rate: f64) -> Self {
+        Self {
+            last_update_time: self.last_update_time,
+            best_bid: convert_level(&self.best_bid, rate),
+            best_ask: convert_level(&self.best_ask, rate),
+        }
+    }
+}
+
+impl Convert for OrderBook {
+    fn convert(&self, rate: f64) -> Self {
+        let convert_side = |side: Side, levels: &OrderBookSide| {
+            OrderBookSide::new(
+                side,
+                levels
+                    .levels()
+                    .iter()
+                    .map(|level| convert_level(level, rate)),
+            )
+        };
+
+        Self {
+            last_update_time: self.last_update_time,
+            bids: convert_side(Side::Buy, &self.bids),
+            asks: convert_side(Side::Sell, &self.asks),
+            sequence: self.sequence,
+            delta: self.delta,
+        }
+    }
+}
+
+impl Convert for Candle {
+    fn convert(&self, rate: f64) -> Self {
+        Self {
+            open: self.open * rate,
+            high: self.high * rate,
+            low: self.low * rate,
+            close: self.close * rate,
+            ..*self
+        }
+    }
+}
+
+impl Convert for Liquidation {
+    fn convert(&self, rate: f64) -> Self {
+        Self {
+            price: self.price * rate,
+            ..*self
+        }
+    }
+}
+
+fn convert_level(level: &Level, rate: f64) -> Level {
+    Level::new(level.price * rate, level.amount)
+}
+
+/// Event carrying both it's native quote currency values & the values converted into the
+/// reference currency of a [`QuoteConverter`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Converted<T> {
+    /// Event denominated in the native quote currency of it's
+    /// [`Instrument`](barter_integration::model::instrument::Instrument).
+    pub native: T,
+    /// Reference quote currency of the `converted` event.
+    pub reference: Symbol,
+    /// Rate the native prices were multiplied by, or `None` if no recent reference price of the
+    /// native quote currency was available.
+    pub rate: Option<f64>,
+    /// Event denominated in the `reference` quote currency, if a `rate` was available.
+    pub converted: Option<T>,
+}
+
+/// Latest rate of a native quote currency to the reference currency.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Rate {
+    time: DateTime<Utc>,
+    rate: f64,
+}
+
+/// Quote currency conversion stage that re-denominates the prices of events quoted in a native
+/// currency (eg/ BTC or EUR quoted pairs) into a chosen reference currency (eg/ USDT).
+///
+/// Rates are derived from a designated reference price stream of
+/// [`MarketEvent<DataKind>`]s (see [`Self::process_reference`]): a `native/reference` price
+/// (eg/ btc_usdt) is used directly, and a `reference/native` price (eg/ usdt_eur) is inverted.
+/// Reference prices are the L1 mid price, or the last trade price.
+///
+/// Currencies are matched by [`Symbol`], so exchange specific asset codes should be normalised
+/// beforehand (see [`AssetNormaliser`](crate::normalise::AssetNormaliser)).
+#[derive(Clone, PartialEq, Debug)]
+pub struct QuoteConverter {
+    reference: Symbol,
+    max_rate_age: Duration,
+    rates: HashMap<Symbol, Rate>,
+}
+
+impl QuoteConverter {
+    /// Construct a new [`Self`] that converts into the provided `reference` currency, using
+    /// reference prices up to [`DEFAULT_MAX_RATE_AGE_SECS`] old.
+    pub fn new<S>(reference: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            reference: reference.into(),
+            max_rate_age: Duration::seconds(DEFAULT_MAX_RATE_AGE_SECS),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Only convert events using reference prices within `max_rate_age` of their `exchange_time`.
+    pub fn with_max_rate_age(self, max_rate_age: Duration) -> Self {
+        Self {
+            max_rate_age,
+            ..self
+        }
+    }
+
+    /// Process a [`MarketEvent<DataKind>`] of the reference price stream, updating the rate of
+    /// the native currency it prices against the reference currency.
+    ///
+    /// Events of [`Instrument`](barter_integration::model::instrument::Instrument)s not quoted
+    /// in or priced in the reference currency, or of kinds without a price, are ignored.
+    pub fn process_reference(&mut self, event: &MarketEvent<DataKind>) {
+        let price = match &event.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::OrderBookL1(book) => book.mid_price(),
+            _ => return,
+        };
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+
+        let (native, rate) = match &event.instrument {
+            instrument if instrument.quote == self.reference => (&instrument.base, price),
+            instrument if instrument.base == self.reference => (&instrument.quote, 1.0 / price),
+            _ => return,
+        };
+
+        self.rates.insert(
+            native.clone(),
+            Rate {
+                time: event.exchange_time,
+                rate,
+            },
+        );
+    }
+
+    /// Rate of the `native` currency to the reference currency at `time`, if a recent reference
+    /// price is available.
+    pub fn rate(&self, native: &Symbol, time: DateTime<Utc>) -> Option<f64> {
+        if *native == self.reference {
+            return Some(1.0);
+        }
+
+        self.rates
+            .get(native)
+            .filter(|rate| (time - rate.time).abs() <= self.max_rate_age)
+            .map(|rate| rate.rate)
+    }
+
+    /// Convert a [`MarketEvent<T>`] into the reference currency, retaining it's native values.
+    pub fn process<T>(&self, event: MarketEvent<T>) -> MarketEvent<Converted<T>>
+    where
+        T: Convert,
+    {
+        let rate = self.rate(&event.instrument.quote, event.exchange_time);
+
+        MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: Converted {
+                converted: rate.map(|rate| event.kind.convert(rate)),
+                native: event.kind,
+                reference: self.reference.clone(),
+                rate,
+            },
+        }
+    }
+}
+
+/// Convert the [`MarketEvent<T>`]s received on the `event_rx` into the reference currency of the
+/// [`QuoteConverter`], using the reference prices received on the `reference_rx`, and forward
+/// every [`MarketEvent<Converted<T>>`] to the `converted_tx`.
+///
+/// Runs until the `event_rx` is exhausted or the `converted_tx` receiver has been dropped.
+pub async fn convert<T>(
+    mut reference_rx: mpsc::UnboundedReceiver<MarketEvent<DataKind>>,
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    converted_tx: mpsc::UnboundedSender<MarketEvent<Converted<T>>>,
+    mut converter: QuoteConverter,
+) where
+    T: Convert,
+{
+    let mut reference_open = true;
+
+    loop {
+        tokio::select! {
+            // Apply reference prices first, so events are converted with the latest rate
+            biased;
+
+            event = reference_rx.recv(), if reference_open => match event {
+                Some(event) => converter.process_reference(&event),
+                None => reference_open = false,
+            },
+            event = event_rx.recv() => {
+                let Some(event) = event else {
+                    return;
+                };
+
+                if converted_tx.send(converter.process(event)).is_err() {
+                    debug!("Converted receiver dropped - stopping quote conversion");
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::{self, market_event};
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+    use chrono::TimeZone;
+
+    fn time(second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, second).unwrap()
+    }
+
+    fn event<T>(base: &str, quote: &str, second: u32, data: T) -> MarketEvent<T> {
+        MarketEvent {
+            exchange_time: time(second),
+            received_time: time(second),
+            instrument: Instrument::from((base, quote, InstrumentKind::Spot)),
+            ..market_event(data)
+        }
+    }
+
+    fn trade(price: f64) -> PublicTrade {
+        fixture::trade(price, 2.0)
+    }
+
+    #[test]
+    fn test_quote_converter() {
+        let mut converter = QuoteConverter::new("usdt").with_max_rate_age(Duration::seconds(10));
+
+        // btc_usdt L1 mid & usdt_eur trade reference prices
+        converter.process_reference(&event(
+            "btc",
+            "usdt",
+            0,
+            DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time(0),
+                best_bid: Level::new(19999.0, 1.0),
+                best_ask: Level::new(20001.0, 1.0),
+            }),
+        ));
+        converter.process_reference(&event("usdt", "eur", 0, DataKind::Trade(trade(0.5))));
+
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: (Option<f64>, Option<f64>),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: btc quoted trade converted using the btc_usdt price
+                input: event("eth", "btc", 5, trade(0.05)),
+                expected: (Some(20000.0), Some(1000.0)),
+            },
+            TestCase {
+                // TC1: eur quoted trade converted using the inverted usdt_eur price
+                input: event("eth", "eur", 5, trade(500.0)),
+                expected: (Some(2.0), Some(1000.0)),
+            },
+            TestCase {
+                // TC2: reference quoted trade is unchanged
+                input: event("eth", "usdt", 5, trade(1000.0)),
+                expected: (Some(1.0), Some(1000.0)),
+            },
+            TestCase {
+                // TC3: stale reference price is not used
+                input: event("eth", "btc", 11, trade(0.05)),
+                expected: (None, None),
+            },
+            TestCase {
+                // TC4: quote currency without a reference price
+                input: event("eth", "gbp", 5, trade(800.0)),
+                expected: (None, None),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let native = test.input.kind.clone();
+            let actual = converter.process(test.input);
+            let converted = actual.kind.converted.as_ref().map(|trade| trade.price);
+
+            assert_eq!(
+                (actual.kind.rate, converted),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(actual.kind.native, native, "TC{} failed", index);
+            assert_eq!(
+                actual.kind.reference,
+                Symbol::from("usdt"),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_order_book() {
+        let book = OrderBook {
+            last_update_time: time(0),
+            bids: OrderBookSide::new(Side::Buy, vec![(0.05, 1.0), (0.04, 2.0)]),
+            asks: OrderBookSide::new(Side::Sell, vec![(0.06, 3.0)]),
+            sequence: Some(7),
+            delta: false,
+        };
+
+        let actual = book.convert(100.0);
+
+        assert_eq!(
+            actual.bids.levels(),
+            &[Level::new(5.0, 1.0), Level::new(4.0, 2.0)]
+        );
+        assert_eq!(actual.asks.levels(), &[Level::new(6.0, 3.0)]);
+        assert_eq!(actual.sequence, Some(7));
+    }
+}
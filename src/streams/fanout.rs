@@ -0,0 +1,202 @@
+use crate::{event::MarketEvent, exchange::ExchangeId};
+use barter_integration::model::instrument::Instrument;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::{broadcast, mpsc, watch};
+
+/// Default number of events buffered per [`BroadcastStreams`] receiver before it lags.
+pub const DEFAULT_BROADCAST_CAPACITY: usize = 1024;
+
+/// Multi-consumer [`Streams`](super::Streams) that broadcast every event of each exchange to any
+/// number of receivers, constructed via [`Streams::broadcast`](super::Streams::broadcast).
+///
+/// Broadcasting never applies backpressure to the exchange streams: if no receivers exist the
+/// event is dropped, and receivers that lag more than the capacity behind miss the oldest events
+/// (see [`broadcast::error::RecvError::Lagged`]).
+#[derive(Clone, Debug)]
+pub struct BroadcastStreams<T> {
+    pub streams: HashMap<ExchangeId, broadcast::Sender<T>>,
+}
+
+impl<T> BroadcastStreams<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Forward every event of each exchange [`mpsc::UnboundedReceiver`] to a
+    /// [`broadcast::Sender`] buffering up to `capacity` events per receiver.
+    ///
+    /// Panics if the `capacity` is zero.
+    pub fn new(streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>, capacity: usize) -> Self {
+        let streams = streams
+            .into_iter()
+            .map(|(exchange, mut exchange_rx)| {
+                let (tx, _) = broadcast::channel(capacity);
+                let broadcast_tx = tx.clone();
+                tokio::spawn(async move {
+                    while let Some(event) = exchange_rx.recv().await {
+                        // Error only indicates there are currently no receivers
+                        let _ = broadcast_tx.send(event);
+                    }
+                });
+                (exchange, tx)
+            })
+            .collect();
+
+        Self { streams }
+    }
+
+    /// Construct a new [`broadcast::Receiver`] of every event from the provided exchange
+    /// broadcast after this call.
+    pub fn subscribe(&self, exchange: ExchangeId) -> Option<broadcast::Receiver<T>> {
+        self.streams
+            .get(&exchange)
+            .map(broadcast::Sender::subscribe)
+    }
+
+    /// Construct a new [`broadcast::Receiver`] for every exchange.
+    pub fn subscribe_all(&self) -> HashMap<ExchangeId, broadcast::Receiver<T>> {
+        self.streams
+            .iter()
+            .map(|(exchange, tx)| (*exchange, tx.subscribe()))
+            .collect()
+    }
+}
+
+/// Communicative type alias for the [`watch::Sender`]s of the latest
+/// [`MarketEvent<T>`](MarketEvent) of each exchange [`Instrument`].
+type WatchMap<T> = HashMap<(ExchangeId, Instrument), watch::Sender<Option<MarketEvent<T>>>>;
+
+/// Multi-consumer [`Streams`](super::Streams) that only retain the latest
+/// [`MarketEvent<T>`](MarketEvent) of each exchange [`Instrument`] (eg/ the current
+/// [`OrderBook`](crate::subscription::book::OrderBook) snapshot), constructed via
+/// [`Streams::watch`](super::Streams::watch).
+///
+/// Receivers never lag, and observe only the most recent value when they are slower than the
+/// exchange stream.
+#[derive(Clone, Debug)]
+pub struct WatchStreams<T> {
+    watches: Arc<Mutex<WatchMap<T>>>,
+}
+
+impl<T> WatchStreams<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Forward every [`MarketEvent<T>`](MarketEvent) of each exchange
+    /// [`mpsc::UnboundedReceiver`] to the [`watch::Sender`] of it's [`Instrument`].
+    pub fn new(streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<MarketEvent<T>>>) -> Self {
+        let watches = Arc::new(Mutex::new(WatchMap::new()));
+
+        for (exchange, mut exchange_rx) in streams {
+            let watches = Arc::clone(&watches);
+            tokio::spawn(async move {
+                while let Some(event) = exchange_rx.recv().await {
+                    let key = (exchange, event.instrument.clone());
+                    lock(&watches)
+                        .entry(key)
+                        .or_insert_with(|| watch::channel(None).0)
+                        .send_replace(Some(event));
+                }
+            });
+        }
+
+        Self { watches }
+    }
+
+    /// Construct a new [`watch::Receiver`] of the latest [`MarketEvent<T>`](MarketEvent) of the
+    /// provided exchange [`Instrument`], which is `None` until the first event is received.
+    pub fn subscribe(
+        &self,
+        exchange: ExchangeId,
+        instrument: Instrument,
+    ) -> watch::Receiver<Option<MarketEvent<T>>> {
+        lock(&self.watches)
+            .entry((exchange, instrument))
+            .or_insert_with(|| watch::channel(None).0)
+            .subscribe()
+    }
+}
+
+/// Lock the [`WatchMap`], recovering it if a previous holder panicked.
+fn lock<T>(watches: &Mutex<WatchMap<T>>) -> std::sync::MutexGuard<'_, WatchMap<T>> {
+    watches
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixture::market_event,
+        subscription::book::{Level, OrderBookL1},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+
+    fn l1(base: &str, price: f64) -> MarketEvent<OrderBookL1> {
+        MarketEvent {
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            ..market_event(OrderBookL1 {
+                last_update_time: Utc::now(),
+                best_bid: Level::new(price, 1.0),
+                best_ask: Level::new(price + 1.0, 1.0),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_streams() {
+        let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+        let streams = BroadcastStreams::new(
+            HashMap::from([(ExchangeId::BinanceSpot, exchange_rx)]),
+            DEFAULT_BROADCAST_CAPACITY,
+        );
+
+        let mut rx_a = streams.subscribe(ExchangeId::BinanceSpot).unwrap();
+        let mut rx_b = streams
+            .subscribe_all()
+            .remove(&ExchangeId::BinanceSpot)
+            .unwrap();
+        assert!(streams.subscribe(ExchangeId::Coinbase).is_none());
+
+        exchange_tx.send(1).unwrap();
+        exchange_tx.send(2).unwrap();
+        drop(exchange_tx);
+
+        for rx in [&mut rx_a, &mut rx_b] {
+            assert_eq!(rx.recv().await.unwrap(), 1);
+            assert_eq!(rx.recv().await.unwrap(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_watch_streams() {
+        let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+        let streams = WatchStreams::new(HashMap::from([(ExchangeId::BinanceSpot, exchange_rx)]));
+
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut btc_rx = streams.subscribe(ExchangeId::BinanceSpot, btc.clone());
+        assert!(btc_rx.borrow().is_none());
+
+        exchange_tx.send(l1("btc", 100.0)).unwrap();
+        exchange_tx.send(l1("eth", 10.0)).unwrap();
+        exchange_tx.send(l1("btc", 101.0)).unwrap();
+
+        // Slow receivers only observe the latest event of their Instrument
+        let eth_rx = loop {
+            btc_rx.changed().await.unwrap();
+            let latest = btc_rx.borrow_and_update().clone().unwrap();
+            if latest.kind.best_bid.price == 101.0 {
+                break streams.subscribe(
+                    ExchangeId::BinanceSpot,
+                    Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                );
+            }
+        };
+
+        assert_eq!(eth_rx.borrow().as_ref().unwrap().kind.best_bid.price, 10.0);
+    }
+}
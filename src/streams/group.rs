@@ -0,0 +1,288 @@
+use crate::error::DataError;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::watch;
+use tracing::{error, info};
+
+/// Lifecycle state of a named subscription group (see [`StreamGroups`]).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupState {
+    /// Connected & forwarding events.
+    Running,
+    /// Disconnected until resumed, at which point every connection of the group resubscribes.
+    Paused,
+    /// Permanently disconnected.
+    Cancelled,
+}
+
+/// Communicative type alias for the [`watch::Sender`]s controlling each connection of every
+/// named subscription group.
+type GroupMap = HashMap<String, Vec<watch::Sender<GroupState>>>;
+
+/// Handle used to pause, resume & cancel every connection of a named subscription group (eg/
+/// "alts-universe" vs "majors") on a running [`Streams`](super::Streams).
+///
+/// Subscriptions are tagged into groups at build time via
+/// [`StreamBuilder::subscribe_group`](super::builder::StreamBuilder::subscribe_group). Cloning a
+/// [`StreamGroups`] yields a handle to the same groups. If every handle is dropped each group
+/// continues in it's current [`GroupState`].
+#[derive(Clone, Debug, Default)]
+pub struct StreamGroups {
+    groups: Arc<Mutex<GroupMap>>,
+}
+
+impl StreamGroups {
+    /// Construct a new empty [`Self`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorted names of every subscription group.
+    pub fn names(&self) -> Vec<String> {
+        let mut names = self.lock().keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Current [`GroupState`] of the provided subscription group, if it exists.
+    pub fn state(&self, group: &str) -> Option<GroupState> {
+        self.lock()
+            .get(group)
+            .and_then(|connections| connections.first())
+            .map(|state_tx| *state_tx.borrow())
+    }
+
+    /// Pause every connection of the provided subscription group, returning false if the group
+    /// does not exist or has been cancelled.
+    pub fn pause(&self, group: &str) -> bool {
+        self.transition(group, GroupState::Paused)
+    }
+
+    /// Resume every connection of the provided paused subscription group, returning false if the
+    /// group does not exist or has been cancelled.
+    pub fn resume(&self, group: &str) -> bool {
+        self.transition(group, GroupState::Running)
+    }
+
+    /// Permanently cancel every connection of the provided subscription group, returning false
+    /// if the group does not exist or has already been cancelled.
+    pub fn cancel(&self, group: &str) -> bool {
+        self.transition(group, GroupState::Cancelled)
+    }
+
+    /// Register a new connection of the provided subscription group, returning the
+    /// [`watch::Receiver`] used to control it.
+    pub(crate) fn register(&self, group: &str) -> watch::Receiver<GroupState> {
+        let (state_tx, state_rx) = watch::channel(GroupState::Running);
+        self.lock()
+            .entry(group.to_owned())
+            .or_default()
+            .push(state_tx);
+        state_rx
+    }
+
+    /// Move every subscription group of the provided [`StreamGroups`] into this one.
+    pub(crate) fn absorb(&self, other: StreamGroups) {
+        let other = std::mem::take(&mut *other.lock());
+        let mut groups = self.lock();
+        for (group, connections) in other {
+            groups.entry(group).or_default().extend(connections);
+        }
+    }
+
+    fn transition(&self, group: &str, next: GroupState) -> bool {
+        let groups = self.lock();
+        let Some(connections) = groups.get(group) else {
+            return false;
+        };
+
+        let mut transitioned = false;
+        for state_tx in connections {
+            let current = *state_tx.borrow();
+            if current == GroupState::Cancelled {
+                continue;
+            }
+            if current != next {
+                state_tx.send_replace(next);
+            }
+            transitioned = true;
+        }
+
+        if transitioned {
+            info!(group, state = ?next, "subscription group transitioned");
+        }
+        transitioned
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, GroupMap> {
+        self.groups
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Supervise a single connection of a subscription group, running the consumer loop
+/// constructed by `consumer` (eg/ [`consume`](super::consumer::consume)) while the group is
+/// [`GroupState::Running`].
+///
+/// Pausing aborts the consumer loop, and resuming constructs a new one that resubscribes. Runs
+/// until the group is cancelled, or it's [`StreamGroups`] handles are dropped & the current
+/// consumer loop ends.
+pub async fn supervise_group<Consumer, Fut>(
+    group: String,
+    mut state_rx: watch::Receiver<GroupState>,
+    mut consumer: Consumer,
+) where
+    Consumer: FnMut() -> Fut,
+    Fut: Future<Output = DataError> + Send + 'static,
+{
+    loop {
+        let state = *state_rx.borrow_and_update();
+        match state {
+            GroupState::Cancelled => break,
+            GroupState::Paused => {
+                if state_rx.changed().await.is_err() {
+                    break;
+                }
+                continue;
+            }
+            GroupState::Running => {}
+        }
+
+        let mut consumer_task = tokio::spawn(consumer());
+
+        tokio::select! {
+            result = &mut consumer_task => {
+                if let Ok(error) = result {
+                    error!(%group, %error, "subscription group consumer loop ended");
+                }
+
+                // Await a pause & resume before re-initialising the consumer loop
+                if state_rx.changed().await.is_err() {
+                    break;
+                }
+            }
+            changed = state_rx.changed() => {
+                if changed.is_err() {
+                    // Every StreamGroups handle dropped, so continue in the Running state
+                    let _ = consumer_task.await;
+                    break;
+                }
+                consumer_task.abort();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    #[test]
+    fn test_stream_groups_transitions() {
+        let groups = StreamGroups::new();
+        let _majors = groups.register("majors");
+        let _alts = groups.register("alts-universe");
+
+        struct TestCase {
+            action: fn(&StreamGroups, &str) -> bool,
+            group: &'static str,
+            expected: bool,
+            expected_state: Option<GroupState>,
+        }
+
+        let cases = vec![
+            // TC0: pause running group
+            TestCase {
+                action: StreamGroups::pause,
+                group: "majors",
+                expected: true,
+                expected_state: Some(GroupState::Paused),
+            },
+            // TC1: resume paused group
+            TestCase {
+                action: StreamGroups::resume,
+                group: "majors",
+                expected: true,
+                expected_state: Some(GroupState::Running),
+            },
+            // TC2: cancel running group
+            TestCase {
+                action: StreamGroups::cancel,
+                group: "majors",
+                expected: true,
+                expected_state: Some(GroupState::Cancelled),
+            },
+            // TC3: cancelled group cannot be resumed
+            TestCase {
+                action: StreamGroups::resume,
+                group: "majors",
+                expected: false,
+                expected_state: Some(GroupState::Cancelled),
+            },
+            // TC4: unknown group
+            TestCase {
+                action: StreamGroups::pause,
+                group: "defi",
+                expected: false,
+                expected_state: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = (test.action)(&groups, test.group);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                groups.state(test.group),
+                test.expected_state,
+                "TC{} state failed",
+                index
+            );
+        }
+
+        // Other groups are unaffected
+        assert_eq!(groups.state("alts-universe"), Some(GroupState::Running));
+        assert_eq!(groups.names(), vec!["alts-universe", "majors"]);
+    }
+
+    #[tokio::test]
+    async fn test_supervise_group() {
+        let groups = StreamGroups::new();
+        let (started_tx, mut started_rx) = mpsc::unbounded_channel();
+
+        let supervisor = tokio::spawn(supervise_group(
+            "majors".to_owned(),
+            groups.register("majors"),
+            move || {
+                let started_tx = started_tx.clone();
+                async move {
+                    let _ = started_tx.send(());
+                    std::future::pending::<DataError>().await
+                }
+            },
+        ));
+
+        // Consumer loop is initialised while running
+        started_rx.recv().await.unwrap();
+
+        // Pausing aborts the consumer loop until resumed
+        assert!(groups.pause("majors"));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(started_rx.try_recv().is_err());
+
+        // Resuming re-initialises the consumer loop
+        assert!(groups.resume("majors"));
+        started_rx.recv().await.unwrap();
+
+        // Cancelling stops supervision
+        assert!(groups.cancel("majors"));
+        supervisor.await.unwrap();
+    }
+}
@@ -0,0 +1,279 @@
+use crate::transport::BoxTransport;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WsError, WsMessage},
+};
+use thiserror::Error;
+use tokio_tungstenite::{connect_async_with_config, tungstenite::protocol::WebSocketConfig};
+use tracing::debug;
+use url::Url;
+
+/// Default maximum size (bytes) of a received WebSocket message.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 << 20;
+
+/// Default maximum number of elements of any JSON array in a received text frame.
+pub const DEFAULT_MAX_ARRAY_LEN: usize = 100_000;
+
+/// Configurable limits of the frames received by a connection, enforced before parsing so a
+/// misbehaving exchange or tampered connection cannot exhaust the memory of the process.
+///
+/// Configured via [`StreamConfig::with_frame_limits`](super::config::StreamConfig::with_frame_limits).
+///
+/// ### Notes
+/// - The `max_frame_size` is enforced while the frame is being read when connecting via the
+///   default tokio-tungstenite transport (see [`FrameLimits::connect`]), and by the
+///   [`TappedWsStream`](super::tap::TappedWsStream) for every
+///   [`Transport`](crate::transport::Transport).
+/// - The `max_array_len` is enforced on text frames by a single pass scan of the JSON payload,
+///   which also rejects frames with unbalanced brackets.
+/// - Frames that exceed a limit are not forwarded to the
+///   [`RawTap`](super::tap::RawTap), and are yielded as a typed [`FrameLimitError`] (see
+///   [`DataError::frame_limit`](crate::error::DataError::frame_limit)) which the consumer loop
+///   logs & skips.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{config::StreamConfig, limits::FrameLimits};
+///
+/// let config = StreamConfig::default().with_frame_limits(
+///     FrameLimits::default()
+///         .with_max_frame_size(1 << 20)
+///         .with_max_array_len(10_000),
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FrameLimits {
+    /// Maximum size (bytes) of a received WebSocket message.
+    pub max_frame_size: usize,
+    /// Maximum number of elements of any JSON array in a received text frame (eg/ the levels
+    /// of an order book snapshot).
+    pub max_array_len: usize,
+}
+
+impl Default for FrameLimits {
+    fn default() -> Self {
+        Self {
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            max_array_len: DEFAULT_MAX_ARRAY_LEN,
+        }
+    }
+}
+
+/// Received frame that violates the [`FrameLimits`] of it's connection.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Error)]
+pub enum FrameLimitError {
+    #[error("frame of {size} bytes exceeds the maximum frame size of {max} bytes")]
+    FrameTooLarge { size: usize, max: usize },
+
+    #[error("frame array of at least {len} elements exceeds the maximum array length of {max}")]
+    ArrayTooLong { len: usize, max: usize },
+
+    #[error("malformed frame: {0}")]
+    Malformed(&'static str),
+}
+
+impl FrameLimits {
+    /// Limit the size (bytes) of received WebSocket messages.
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    /// Limit the number of elements of any JSON array in received text frames.
+    pub fn with_max_array_len(mut self, max_array_len: usize) -> Self {
+        self.max_array_len = max_array_len;
+        self
+    }
+
+    /// tokio-tungstenite [`WebSocketConfig`] that rejects messages & frames larger than the
+    /// `max_frame_size` while they are being read.
+    pub fn websocket_config(&self) -> WebSocketConfig {
+        WebSocketConfig {
+            max_message_size: Some(self.max_frame_size),
+            max_frame_size: Some(self.max_frame_size),
+            ..WebSocketConfig::default()
+        }
+    }
+
+    /// Connect a tokio-tungstenite [`WebSocket`](barter_integration::protocol::websocket::WebSocket)
+    /// configured with the [`Self::websocket_config`].
+    pub async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+        debug!(%url, limits = ?self, "attempting to establish WebSocket connection");
+        connect_async_with_config(url, Some(self.websocket_config()))
+            .await
+            .map(|(websocket, _)| Box::new(websocket) as BoxTransport)
+            .map_err(SocketError::WebSocket)
+    }
+
+    /// Check a received [`WsMessage`] against the [`FrameLimits`].
+    pub fn check(&self, message: &WsMessage) -> Result<(), FrameLimitError> {
+        let size = message.len();
+        if size > self.max_frame_size {
+            return Err(FrameLimitError::FrameTooLarge {
+                size,
+                max: self.max_frame_size,
+            });
+        }
+
+        match message {
+            WsMessage::Text(text) => check_arrays(text.as_bytes(), self.max_array_len),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check a received [`WsMessage`] against the [`FrameLimits`], converting a violation into a
+    /// [`WsError`] that wraps the [`FrameLimitError`].
+    pub fn guard(&self, message: &WsMessage) -> Result<(), WsError> {
+        self.check(message).map_err(|error| {
+            WsError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+        })
+    }
+}
+
+/// Scan a JSON payload, returning an error if any array has more than `max_len` elements or the
+/// brackets of the payload are unbalanced.
+fn check_arrays(payload: &[u8], max_len: usize) -> Result<(), FrameLimitError> {
+    // Number of elements of each open container, or None for objects
+    let mut open: Vec<Option<usize>> = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for byte in payload {
+        if in_string {
+            match (escaped, byte) {
+                (true, _) => escaped = false,
+                (false, b'\\') => escaped = true,
+                (false, b'"') => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match byte {
+            b' ' | b'\t' | b'\n' | b'\r' => continue,
+            b']' => match open.pop() {
+                Some(Some(_)) => continue,
+                _ => return Err(FrameLimitError::Malformed("unbalanced ']'")),
+            },
+            b'}' => match open.pop() {
+                Some(None) => continue,
+                _ => return Err(FrameLimitError::Malformed("unbalanced '}'")),
+            },
+            b',' => {
+                if let Some(Some(len)) = open.last_mut() {
+                    *len += 1;
+                    if *len > max_len {
+                        return Err(FrameLimitError::ArrayTooLong {
+                            len: *len,
+                            max: max_len,
+                        });
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        // First byte of a value opens the first element of an empty array
+        if let Some(Some(len @ 0)) = open.last_mut() {
+            *len = 1;
+            if *len > max_len {
+                return Err(FrameLimitError::ArrayTooLong {
+                    len: *len,
+                    max: max_len,
+                });
+            }
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'[' => open.push(Some(0)),
+            b'{' => open.push(None),
+            _ => {}
+        }
+    }
+
+    match (open.is_empty(), in_string) {
+        (true, false) => Ok(()),
+        (_, true) => Err(FrameLimitError::Malformed("unterminated string")),
+        (false, false) => Err(FrameLimitError::Malformed("unclosed bracket")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DataError;
+
+    #[test]
+    fn test_frame_limits_check() {
+        struct TestCase {
+            input: WsMessage,
+            expected: Result<(), FrameLimitError>,
+        }
+
+        let limits = FrameLimits::default()
+            .with_max_frame_size(64)
+            .with_max_array_len(3);
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid frame within limits
+                input: WsMessage::Text(r#"{"b":[["1","2"],["3","4"]],"a":[]}"#.to_owned()),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: array at the maximum length, with brackets & commas inside strings
+                input: WsMessage::Text(r#"[1, "[,,,", {"k": "\"]"}]"#.to_owned()),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: nested array exceeds the maximum length
+                input: WsMessage::Text(r#"{"b":[[1],[2],[3],[4]]}"#.to_owned()),
+                expected: Err(FrameLimitError::ArrayTooLong { len: 4, max: 3 }),
+            },
+            TestCase {
+                // TC3: frame exceeds the maximum size
+                input: WsMessage::Binary(vec![0; 65]),
+                expected: Err(FrameLimitError::FrameTooLarge { size: 65, max: 64 }),
+            },
+            TestCase {
+                // TC4: unbalanced brackets
+                input: WsMessage::Text(r#"{"b":[1,2}"#.to_owned()),
+                expected: Err(FrameLimitError::Malformed("unbalanced '}'")),
+            },
+            TestCase {
+                // TC5: unclosed bracket
+                input: WsMessage::Text(r#"{"b":[1,2]"#.to_owned()),
+                expected: Err(FrameLimitError::Malformed("unclosed bracket")),
+            },
+            TestCase {
+                // TC6: binary frames are only checked against the maximum size
+                input: WsMessage::Binary(b"[1,2,3,4]".to_vec()),
+                expected: Ok(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = limits.check(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_frame_limits_guard() {
+        let limits = FrameLimits::default().with_max_array_len(1);
+
+        let error = limits
+            .guard(&WsMessage::Text("[1,2]".to_owned()))
+            .map_err(|error| DataError::Socket(SocketError::WebSocket(error)))
+            .unwrap_err();
+        assert_eq!(
+            error.frame_limit(),
+            Some(&FrameLimitError::ArrayTooLong { len: 2, max: 1 })
+        );
+
+        assert!(limits.guard(&WsMessage::Text("[1]".to_owned())).is_ok());
+        assert_eq!(DataError::Socket(SocketError::Sink).frame_limit(), None);
+    }
+}
@@ -0,0 +1,798 @@
+use super::{builder::validate, config::StreamConfig, consumer::consume};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{ExchangeId, StreamSelector},
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::model::instrument::Instrument;
+use futures::Stream;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+use tokio::{
+    sync::{mpsc, watch},
+    task::JoinHandle,
+};
+use tracing::{info, warn};
+
+/// Multi-tenant manager that lets many in-process consumers request overlapping
+/// [`Subscription`]s to a [`SubKind`], sharing the underlying exchange connections.
+///
+/// Every [`Subscription`] is reference counted by the [`ManagedStream`]s requesting it:
+/// - [`Subscription`]s that are already served by an open connection (including released
+///   [`Subscription`]s whose connection is still serving others) are served by that connection.
+/// - [`Subscription`]s that are new to a [`SubscriptionManager::subscribe`] call share one new
+///   connection, except [`Subscription`]s to the same [`Instrument`] with different
+///   [`SubKind`]s, which each require their own connection since events are routed by
+///   [`Instrument`].
+/// - A connection is closed once the last [`ManagedStream`] using any of its [`Subscription`]s
+///   is dropped.
+///
+/// Cloning a [`SubscriptionManager`] yields a handle to the same shared registry.
+#[derive(Debug)]
+pub struct SubscriptionManager<Kind>
+where
+    Kind: SubKind,
+{
+    registry: Arc<Mutex<Registry<Kind>>>,
+    config: StreamConfig,
+}
+
+impl<Kind> Clone for SubscriptionManager<Kind>
+where
+    Kind: SubKind,
+{
+    fn clone(&self) -> Self {
+        Self {
+            registry: Arc::clone(&self.registry),
+            config: self.config.clone(),
+        }
+    }
+}
+
+impl<Kind> Default for SubscriptionManager<Kind>
+where
+    Kind: SubKind + Ord,
+{
+    fn default() -> Self {
+        Self::new(StreamConfig::default())
+    }
+}
+
+/// Registry of the active [`Subscription`]s & connections of a [`SubscriptionManager`].
+#[derive(Debug)]
+struct Registry<Kind>
+where
+    Kind: SubKind,
+{
+    next_consumer: u64,
+    next_connection: u64,
+    subscriptions: BTreeMap<Subscription<ExchangeId, Kind>, SharedSubscription<Kind::Event>>,
+    connections: HashMap<u64, Connection>,
+}
+
+/// Communicative type alias for the consumer senders a connection routes a [`Subscription`]'s
+/// events to.
+type Consumers<Event> = Arc<[mpsc::UnboundedSender<MarketEvent<Event>>]>;
+
+/// [`Subscription`] served by an open connection of a [`SubscriptionManager`], and the consumers
+/// referencing it. Released [`Subscription`]s are kept without consumers until their connection
+/// closes, so re-requesting them re-uses the connection.
+///
+/// A snapshot of the consumer senders is published to the connection's router on every change,
+/// so events are routed without locking the [`Registry`].
+#[derive(Debug)]
+struct SharedSubscription<Event> {
+    connection: u64,
+    consumers: Vec<(u64, mpsc::UnboundedSender<MarketEvent<Event>>)>,
+    snapshot: watch::Sender<Consumers<Event>>,
+}
+
+impl<Event> SharedSubscription<Event> {
+    /// Publish a snapshot of the current consumer senders to the connection's router.
+    fn publish(&self) {
+        self.snapshot.send_replace(
+            self.consumers
+                .iter()
+                .map(|(_, consumer_tx)| consumer_tx.clone())
+                .collect(),
+        );
+    }
+}
+
+/// Exchange connection serving one or more [`Subscription`]s.
+#[derive(Debug)]
+struct Connection {
+    /// Number of the connection's [`Subscription`]s referenced by at least one consumer.
+    subscriptions: usize,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl<Kind> SubscriptionManager<Kind>
+where
+    Kind: SubKind + Ord,
+{
+    /// Construct a new [`Self`] that initialises every exchange connection using the provided
+    /// [`StreamConfig`].
+    pub fn new(config: StreamConfig) -> Self {
+        Self {
+            registry: Arc::new(Mutex::new(Registry {
+                next_consumer: 0,
+                next_connection: 0,
+                subscriptions: BTreeMap::new(),
+                connections: HashMap::new(),
+            })),
+            config,
+        }
+    }
+
+    /// Number of active [`Subscription`]s, each referenced by at least one [`ManagedStream`].
+    pub fn num_subscriptions(&self) -> usize {
+        self.lock()
+            .subscriptions
+            .values()
+            .filter(|shared| !shared.consumers.is_empty())
+            .count()
+    }
+
+    /// Number of active exchange connections.
+    pub fn num_connections(&self) -> usize {
+        self.lock().connections.len()
+    }
+
+    /// Number of [`ManagedStream`]s referencing the provided [`Subscription`].
+    pub fn ref_count(&self, subscription: &Subscription<ExchangeId, Kind>) -> usize {
+        self.lock()
+            .subscriptions
+            .get(subscription)
+            .map_or(0, |shared| shared.consumers.len())
+    }
+
+    /// Request a collection of [`Subscription`]s, returning a [`ManagedStream`] of their
+    /// [`MarketEvent<SubKind::Event>`](MarketEvent)s.
+    ///
+    /// [`Subscription`]s that are not yet active are actioned on a new
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection, which must
+    /// be spawned from within a tokio runtime.
+    pub fn subscribe<SubIter, Sub, Exchange>(
+        &self,
+        subscriptions: SubIter,
+    ) -> Result<ManagedStream<Kind>, DataError>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Send + Sync + 'static,
+        Kind::Event: Clone + Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct Vec<Subscriptions> from input SubIter & remove duplicates
+        let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        validate(&subscriptions)?;
+        subscriptions.sort();
+        subscriptions.dedup();
+
+        let keys = subscriptions
+            .iter()
+            .map(|subscription| Subscription {
+                exchange: Exchange::ID,
+                instrument: subscription.instrument.clone(),
+                kind: subscription.kind.clone(),
+            })
+            .collect();
+
+        let mut subscriptions = subscriptions.into_iter().map(Some).collect::<Vec<_>>();
+        Ok(self.acquire(keys, |new, exchange_tx| {
+            let subscriptions = new
+                .into_iter()
+                .filter_map(|index| subscriptions[index].take())
+                .collect();
+            let config = self.config.clone();
+
+            async move {
+                let error = consume(subscriptions, config, exchange_tx).await;
+                warn!(exchange = %Exchange::ID, %error, "SubscriptionManager connection terminated");
+            }
+        }))
+    }
+
+    /// Reference the provided [`Subscription`]s on behalf of a new [`ManagedStream`], using the
+    /// `connect` closure to construct a connection future for each batch of indexes of the keys
+    /// that are not yet served by an open connection.
+    fn acquire<Connect, Fut>(
+        &self,
+        keys: Vec<Subscription<ExchangeId, Kind>>,
+        mut connect: Connect,
+    ) -> ManagedStream<Kind>
+    where
+        Connect: FnMut(Vec<usize>, mpsc::UnboundedSender<MarketEvent<Kind::Event>>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+        Kind: Send + 'static,
+        Kind::Event: Clone + Send,
+    {
+        let (consumer_tx, consumer_rx) = mpsc::unbounded_channel();
+        let mut registry = self.lock();
+
+        let consumer = registry.next_consumer;
+        registry.next_consumer += 1;
+
+        // Determine which Subscriptions require a new connection, batching them such that each
+        // connection routes every normalised Instrument to exactly one Subscription
+        let mut batches: Vec<HashMap<Instrument, usize>> = Vec::new();
+        for (index, key) in keys.iter().enumerate() {
+            if registry.subscriptions.contains_key(key) {
+                continue;
+            }
+            let instrument = self
+                .config
+                .normaliser
+                .normalise_instrument(key.exchange, &key.instrument);
+            match batches
+                .iter_mut()
+                .find(|batch| !batch.contains_key(&instrument))
+            {
+                Some(batch) => {
+                    batch.insert(instrument, index);
+                }
+                None => batches.push(HashMap::from([(instrument, index)])),
+            }
+        }
+
+        for batch in batches {
+            let connection = registry.next_connection;
+            registry.next_connection += 1;
+
+            let mut new = batch.values().copied().collect::<Vec<_>>();
+            new.sort_unstable();
+
+            // Route events by the normalised Instrument of each new Subscription
+            let routes = batch
+                .into_iter()
+                .map(|(instrument, index)| {
+                    let (snapshot, snapshot_rx) = watch::channel(Consumers::default());
+                    registry.subscriptions.insert(
+                        keys[index].clone(),
+                        SharedSubscription {
+                            connection,
+                            consumers: Vec::new(),
+                            snapshot,
+                        },
+                    );
+                    (instrument, snapshot_rx)
+                })
+                .collect::<HashMap<_, _>>();
+
+            info!(
+                connection,
+                subscriptions = new.len(),
+                "SubscriptionManager opening connection"
+            );
+
+            let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+            let connect = connect(new, exchange_tx);
+            let route = route(
+                Arc::downgrade(&self.registry),
+                connection,
+                routes,
+                exchange_rx,
+            );
+
+            let handle = tokio::spawn(async move {
+                tokio::join!(connect, route);
+            });
+
+            registry.connections.insert(
+                connection,
+                Connection {
+                    subscriptions: 0,
+                    handle: Some(handle),
+                },
+            );
+        }
+
+        let Registry {
+            subscriptions,
+            connections,
+            ..
+        } = &mut *registry;
+
+        for key in &keys {
+            let Some(shared) = subscriptions.get_mut(key) else {
+                continue;
+            };
+
+            // First consumer of a new or released Subscription references it's connection
+            if shared.consumers.is_empty() {
+                if let Some(connection) = connections.get_mut(&shared.connection) {
+                    connection.subscriptions += 1;
+                }
+            }
+            shared.consumers.push((consumer, consumer_tx.clone()));
+            shared.publish();
+        }
+
+        drop(registry);
+        ManagedStream {
+            consumer,
+            subscriptions: keys,
+            registry: Arc::clone(&self.registry),
+            rx: consumer_rx,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Registry<Kind>> {
+        lock(&self.registry)
+    }
+}
+
+/// Lock the [`Registry`], recovering it if a previous holder panicked.
+fn lock<Kind>(registry: &Mutex<Registry<Kind>>) -> std::sync::MutexGuard<'_, Registry<Kind>>
+where
+    Kind: SubKind,
+{
+    registry
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Distribute the events of a connection to every consumer referencing the associated
+/// [`Subscription`], removing the connection's [`Subscription`]s once it terminates.
+///
+/// Events are sent using the latest published snapshot of each [`Subscription`]'s consumer
+/// senders, so the [`Registry`] is only locked once the connection terminates.
+async fn route<Kind>(
+    registry: Weak<Mutex<Registry<Kind>>>,
+    connection: u64,
+    routes: HashMap<Instrument, watch::Receiver<Consumers<Kind::Event>>>,
+    mut exchange_rx: mpsc::UnboundedReceiver<MarketEvent<Kind::Event>>,
+) where
+    Kind: SubKind + Ord,
+    Kind::Event: Clone,
+{
+    while let Some(event) = exchange_rx.recv().await {
+        if registry.strong_count() == 0 {
+            return;
+        }
+
+        let Some(snapshot) = routes.get(&event.instrument) else {
+            warn!(connection, instrument = %event.instrument, "SubscriptionManager received unrouted event");
+            continue;
+        };
+
+        let consumers = Arc::clone(&snapshot.borrow());
+        for consumer_tx in consumers.iter() {
+            let _ = consumer_tx.send(event.clone());
+        }
+    }
+
+    // Connection terminated: forget its Subscriptions so they can be re-requested
+    if let Some(registry) = registry.upgrade() {
+        let mut registry = lock(&registry);
+        registry
+            .subscriptions
+            .retain(|_, shared| shared.connection != connection);
+        registry.connections.remove(&connection);
+    }
+}
+
+/// Stream of [`MarketEvent<SubKind::Event>`](MarketEvent)s for the [`Subscription`]s requested
+/// via a [`SubscriptionManager`].
+///
+/// Dropping the [`ManagedStream`] releases its reference to each [`Subscription`].
+#[derive(Debug)]
+pub struct ManagedStream<Kind>
+where
+    Kind: SubKind + Ord,
+{
+    consumer: u64,
+    subscriptions: Vec<Subscription<ExchangeId, Kind>>,
+    registry: Arc<Mutex<Registry<Kind>>>,
+    rx: mpsc::UnboundedReceiver<MarketEvent<Kind::Event>>,
+}
+
+impl<Kind> ManagedStream<Kind>
+where
+    Kind: SubKind + Ord,
+{
+    /// [`Subscription`]s referenced by this [`ManagedStream`].
+    pub fn subscriptions(&self) -> &[Subscription<ExchangeId, Kind>] {
+        &self.subscriptions
+    }
+
+    /// Receive the next [`MarketEvent<SubKind::Event>`](MarketEvent).
+    ///
+    /// Returns `None` once every connection serving this [`ManagedStream`] has terminated.
+    pub async fn recv(&mut self) -> Option<MarketEvent<Kind::Event>> {
+        self.rx.recv().await
+    }
+
+    /// Release this [`ManagedStream`]'s reference to each of the provided [`Subscription`]s,
+    /// which are no longer delivered to it.
+    ///
+    /// A connection is closed once none of its [`Subscription`]s are referenced.
+    pub fn unsubscribe<'a, SubIter>(&mut self, subscriptions: SubIter)
+    where
+        SubIter: IntoIterator<Item = &'a Subscription<ExchangeId, Kind>>,
+        Kind: 'a,
+    {
+        let released = subscriptions
+            .into_iter()
+            .filter_map(|subscription| {
+                let index = self
+                    .subscriptions
+                    .iter()
+                    .position(|key| key == subscription)?;
+                Some(self.subscriptions.swap_remove(index))
+            })
+            .collect::<Vec<_>>();
+
+        self.release(&released);
+    }
+
+    /// Remove this [`ManagedStream`] as a consumer of the provided [`Subscription`] keys,
+    /// closing any connection left without referenced [`Subscription`]s.
+    fn release(&self, keys: &[Subscription<ExchangeId, Kind>]) {
+        let mut registry = lock(&self.registry);
+        let Registry {
+            subscriptions,
+            connections,
+            ..
+        } = &mut *registry;
+
+        for key in keys {
+            let Some(shared) = subscriptions.get_mut(key) else {
+                continue;
+            };
+
+            let referenced = !shared.consumers.is_empty();
+            shared
+                .consumers
+                .retain(|(consumer, _)| *consumer != self.consumer);
+            shared.publish();
+            if !referenced || !shared.consumers.is_empty() {
+                continue;
+            }
+
+            // Last consumer of the Subscription released: close the connection once unused,
+            // otherwise keep the Subscription so it can be re-requested from the connection
+            let connection_id = shared.connection;
+            let Some(connection) = connections.get_mut(&connection_id) else {
+                continue;
+            };
+            connection.subscriptions -= 1;
+            if connection.subscriptions == 0 {
+                info!(
+                    connection = connection_id,
+                    "SubscriptionManager closing unused connection"
+                );
+                if let Some(handle) = connection.handle.take() {
+                    handle.abort();
+                }
+                connections.remove(&connection_id);
+                subscriptions.retain(|_, shared| shared.connection != connection_id);
+            }
+        }
+    }
+}
+
+impl<Kind> Stream for ManagedStream<Kind>
+where
+    Kind: SubKind + Ord + Unpin,
+{
+    type Item = MarketEvent<Kind::Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl<Kind> Drop for ManagedStream<Kind>
+where
+    Kind: SubKind + Ord,
+{
+    fn drop(&mut self) {
+        self.release(&self.subscriptions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixture::{market_event, trade_event},
+        subscription::{
+            ticker::{Ticker, TickerWindow, Tickers},
+            trade::{PublicTrade, PublicTrades, TradeId},
+        },
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+
+    type Connected<Event> = (Vec<usize>, mpsc::UnboundedSender<MarketEvent<Event>>);
+    type FakeConnection = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    fn key(base: &str) -> Subscription<ExchangeId, PublicTrades> {
+        Subscription::from((
+            ExchangeId::BinanceSpot,
+            base,
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))
+    }
+
+    fn trade(base: &str) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            ..trade_event(base, 1.0, 1.0)
+        }
+    }
+
+    /// Fake connection that forwards the events sent by the test via the sender it publishes to
+    /// `connect_tx`, along with the indexes of the new keys it serves.
+    fn connect<Event>(
+        connect_tx: mpsc::UnboundedSender<Connected<Event>>,
+    ) -> impl FnMut(Vec<usize>, mpsc::UnboundedSender<MarketEvent<Event>>) -> FakeConnection
+    where
+        Event: Send + 'static,
+    {
+        move |new, exchange_tx| {
+            let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+            connect_tx.send((new, event_tx)).unwrap();
+            Box::pin(async move {
+                while let Some(event) = event_rx.recv().await {
+                    if exchange_tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscription_manager_ref_counting() {
+        let manager = SubscriptionManager::<PublicTrades>::default();
+        let (connect_tx, mut connect_rx) = mpsc::unbounded_channel();
+
+        // Consumer A opens a connection for both of its Subscriptions
+        let mut stream_a =
+            manager.acquire(vec![key("btc"), key("eth")], connect(connect_tx.clone()));
+        let (new, connection_0) = connect_rx.recv().await.unwrap();
+        assert_eq!(new, vec![0, 1]);
+        assert_eq!(manager.num_connections(), 1);
+
+        // Consumer B only opens a connection for the Subscription that is not yet active
+        let mut stream_b =
+            manager.acquire(vec![key("eth"), key("sol")], connect(connect_tx.clone()));
+        let (new, connection_1) = connect_rx.recv().await.unwrap();
+        assert_eq!(new, vec![1]);
+        assert_eq!(manager.num_connections(), 2);
+        assert_eq!(manager.num_subscriptions(), 3);
+        assert_eq!(manager.ref_count(&key("eth")), 2);
+
+        // Shared Subscription events are delivered to both consumers
+        connection_0.send(trade("eth")).unwrap();
+        connection_0.send(trade("btc")).unwrap();
+        assert_eq!(stream_a.recv().await.unwrap().kind.id, TradeId::from("eth"));
+        assert_eq!(stream_a.recv().await.unwrap().kind.id, TradeId::from("btc"));
+        assert_eq!(stream_b.recv().await.unwrap().kind.id, TradeId::from("eth"));
+
+        connection_1.send(trade("sol")).unwrap();
+        assert_eq!(stream_b.recv().await.unwrap().kind.id, TradeId::from("sol"));
+
+        // Connection 0 is kept open whilst consumer B still references the eth Subscription
+        drop(stream_a);
+        assert_eq!(manager.ref_count(&key("btc")), 0);
+        assert_eq!(manager.ref_count(&key("eth")), 1);
+        assert_eq!(manager.num_connections(), 2);
+
+        // Last consumer dropped: every connection is closed
+        drop(stream_b);
+        assert_eq!(manager.num_subscriptions(), 0);
+        assert_eq!(manager.num_connections(), 0);
+
+        // Aborted connections drop their event receivers
+        tokio::task::yield_now().await;
+        assert!(connection_0.send(trade("eth")).is_err());
+        assert!(connection_1.send(trade("sol")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_unsubscribe() {
+        let manager = SubscriptionManager::<PublicTrades>::default();
+        let (connect_tx, mut connect_rx) = mpsc::unbounded_channel();
+
+        let mut stream = manager.acquire(vec![key("btc"), key("eth")], connect(connect_tx));
+        let (_, connection) = connect_rx.recv().await.unwrap();
+
+        // Released Subscriptions are no longer delivered, but the connection is kept open
+        stream.unsubscribe(&[key("btc"), key("sol")]);
+        assert_eq!(stream.subscriptions(), &[key("eth")]);
+        assert_eq!(manager.ref_count(&key("btc")), 0);
+        assert_eq!(manager.num_subscriptions(), 1);
+        assert_eq!(manager.num_connections(), 1);
+
+        connection.send(trade("btc")).unwrap();
+        connection.send(trade("eth")).unwrap();
+        assert_eq!(stream.recv().await.unwrap().kind.id, TradeId::from("eth"));
+
+        // Last Subscription of the connection released: the connection is closed
+        stream.unsubscribe(&[key("eth")]);
+        assert_eq!(manager.num_subscriptions(), 0);
+        assert_eq!(manager.num_connections(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_manager_resubscribe_reuses_connection() {
+        let manager = SubscriptionManager::<PublicTrades>::default();
+        let (connect_tx, mut connect_rx) = mpsc::unbounded_channel();
+
+        let mut stream_a =
+            manager.acquire(vec![key("btc"), key("eth")], connect(connect_tx.clone()));
+        let (_, connection) = connect_rx.recv().await.unwrap();
+
+        // Released btc Subscription is still served by the connection kept open for eth
+        stream_a.unsubscribe(&[key("btc")]);
+        assert_eq!(manager.num_subscriptions(), 1);
+
+        // Re-requesting btc re-uses the open connection rather than opening a duplicate
+        let mut stream_b = manager.acquire(vec![key("btc")], connect(connect_tx.clone()));
+        assert!(connect_rx.try_recv().is_err());
+        assert_eq!(manager.num_connections(), 1);
+        assert_eq!(manager.ref_count(&key("btc")), 1);
+
+        connection.send(trade("btc")).unwrap();
+        connection.send(trade("eth")).unwrap();
+        assert_eq!(stream_b.recv().await.unwrap().kind.id, TradeId::from("btc"));
+        assert_eq!(stream_a.recv().await.unwrap().kind.id, TradeId::from("eth"));
+
+        // Connection is only closed once both of it's re-referenced Subscriptions are released
+        drop(stream_a);
+        assert_eq!(manager.num_connections(), 1);
+        drop(stream_b);
+        assert_eq!(manager.num_subscriptions(), 0);
+        assert_eq!(manager.num_connections(), 0);
+
+        // Subscriptions of a closed connection are requested on a new connection
+        let _stream_c = manager.acquire(vec![key("btc")], connect(connect_tx));
+        let (new, _) = connect_rx.recv().await.unwrap();
+        assert_eq!(new, vec![0]);
+        assert_eq!(manager.num_connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_manager_routes_same_instrument_kinds() {
+        fn key(window: TickerWindow) -> Subscription<ExchangeId, Tickers> {
+            Subscription::from((
+                ExchangeId::BinanceSpot,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Tickers(window),
+            ))
+        }
+
+        fn ticker(trade_count: u64) -> MarketEvent<Ticker> {
+            MarketEvent {
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                ..market_event(Ticker {
+                    open_time: Utc::now(),
+                    close_time: Utc::now(),
+                    open: 1.0,
+                    high: 1.0,
+                    low: 1.0,
+                    last: 1.0,
+                    price_change: 0.0,
+                    price_change_percent: 0.0,
+                    weighted_avg_price: 1.0,
+                    volume: 1.0,
+                    quote_volume: 1.0,
+                    trade_count,
+                })
+            }
+        }
+
+        let manager = SubscriptionManager::<Tickers>::default();
+        let (connect_tx, mut connect_rx) = mpsc::unbounded_channel();
+
+        let mut stream_hour =
+            manager.acquire(vec![key(TickerWindow::Hour1)], connect(connect_tx.clone()));
+        let (_, connection_hour) = connect_rx.recv().await.unwrap();
+
+        // Subscriptions to the same Instrument with different SubKinds are each served by their
+        // own connection, so events are routed to the correct Subscription
+        let mut stream_both = manager.acquire(
+            vec![
+                key(TickerWindow::Hour1),
+                key(TickerWindow::Day1),
+                key(TickerWindow::Hour4),
+            ],
+            connect(connect_tx),
+        );
+        let (new_day, connection_day) = connect_rx.recv().await.unwrap();
+        let (new_hour4, connection_hour4) = connect_rx.recv().await.unwrap();
+        assert_eq!(new_day, vec![1]);
+        assert_eq!(new_hour4, vec![2]);
+        assert_eq!(manager.num_connections(), 3);
+
+        connection_day.send(ticker(24)).unwrap();
+        connection_hour4.send(ticker(4)).unwrap();
+        connection_hour.send(ticker(1)).unwrap();
+
+        assert_eq!(stream_hour.recv().await.unwrap().kind.trade_count, 1);
+        assert!(stream_hour.rx.try_recv().is_err());
+
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            received.push(stream_both.recv().await.unwrap().kind.trade_count);
+        }
+        received.sort_unstable();
+        assert_eq!(received, vec![1, 4, 24]);
+        assert!(stream_both.rx.try_recv().is_err());
+
+        // Releasing one SubKind does not affect the Subscription to the same Instrument
+        stream_both.unsubscribe(&[key(TickerWindow::Day1)]);
+        assert_eq!(manager.ref_count(&key(TickerWindow::Day1)), 0);
+        assert_eq!(manager.ref_count(&key(TickerWindow::Hour4)), 1);
+        assert_eq!(manager.ref_count(&key(TickerWindow::Hour1)), 2);
+        assert_eq!(manager.num_connections(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_subscription_manager_routes_without_registry_lock() {
+        let manager = SubscriptionManager::<PublicTrades>::default();
+        let (connect_tx, mut connect_rx) = mpsc::unbounded_channel();
+
+        let mut stream_a = manager.acquire(vec![key("btc")], connect(connect_tx.clone()));
+        let (_, connection) = connect_rx.recv().await.unwrap();
+        let mut stream_b = manager.acquire(vec![key("btc")], connect(connect_tx));
+
+        // Hold the Registry lock on another thread whilst the connection routes an event
+        let registry = Arc::clone(&manager.registry);
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+        let (unlock_tx, unlock_rx) = std::sync::mpsc::channel::<()>();
+        let holder = std::thread::spawn(move || {
+            let _registry = lock(&registry);
+            locked_tx.send(()).unwrap();
+            let _ = unlock_rx.recv();
+        });
+        locked_rx.recv().unwrap();
+
+        connection.send(trade("btc")).unwrap();
+        for stream in [&mut stream_a, &mut stream_b] {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(1), stream.recv())
+                .await
+                .expect("event routed whilst the Registry is locked")
+                .unwrap();
+            assert_eq!(event.kind.id, TradeId::from("btc"));
+        }
+
+        drop(unlock_tx);
+        holder.join().unwrap();
+
+        // Remaining consumers are still routed to once a released consumer is unpublished
+        drop(stream_a);
+        connection.send(trade("btc")).unwrap();
+        assert_eq!(stream_b.recv().await.unwrap().kind.id, TradeId::from("btc"));
+        assert_eq!(manager.ref_count(&key("btc")), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscription_manager_connection_terminated() {
+        let manager = SubscriptionManager::<PublicTrades>::default();
+
+        // Connection that terminates immediately (eg/ failed to connect)
+        let mut stream = manager.acquire(vec![key("btc")], |_, _| async {});
+
+        assert_eq!(stream.recv().await, None);
+        assert_eq!(manager.num_subscriptions(), 0);
+        assert_eq!(manager.num_connections(), 0);
+    }
+}
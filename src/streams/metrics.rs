@@ -0,0 +1,309 @@
+use crate::{
+    event::MarketEvent,
+    subscription::book::{Level, OrderBook},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default number of [`Level`]s per side summed into the [`BookMetrics`] bid & ask pressure.
+pub const DEFAULT_PRESSURE_DEPTH: usize = 10;
+
+/// Microstructure metrics derived from consecutive [`OrderBook`] snapshots of an exchange
+/// [`Instrument`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BookMetrics {
+    /// Order flow imbalance (OFI) at the top of the book since the previous snapshot, positive
+    /// when net buying pressure was added.
+    ///
+    /// See docs: <https://arxiv.org/abs/1011.6402>
+    pub order_flow_imbalance: f64,
+    /// Total amount resting on the top `depth` bid [`Level`]s.
+    pub bid_pressure: f64,
+    /// Total amount resting on the top `depth` ask [`Level`]s.
+    pub ask_pressure: f64,
+    /// Normalised pressure imbalance `(bid - ask) / (bid + ask)`, in the range [-1, 1].
+    pub pressure_imbalance: f64,
+    /// Rate (amount per second) at which the best bid queue was consumed or cancelled since the
+    /// previous snapshot.
+    pub bid_depletion_rate: f64,
+    /// Rate (amount per second) at which the best ask queue was consumed or cancelled since the
+    /// previous snapshot.
+    pub ask_depletion_rate: f64,
+}
+
+/// Stage that computes [`BookMetrics`] from the [`MarketEvent<OrderBook>`]s of each exchange
+/// [`Instrument`].
+///
+/// The first [`OrderBook`] of each exchange [`Instrument`] initialises it's state, and every
+/// subsequent [`OrderBook`] yields a [`MarketEvent<BookMetrics>`] relative to the previous one.
+/// Delta [`OrderBook`]s (see [`OrderBook::delta`]) are ignored, since they do not contain the
+/// full book.
+#[derive(Debug)]
+pub struct BookMetricsCalculator {
+    depth: usize,
+    books: HashMap<(Exchange, Instrument), TopOfBook>,
+}
+
+/// Best bid & ask [`Level`]s of the previous [`OrderBook`] of an exchange [`Instrument`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct TopOfBook {
+    time: DateTime<Utc>,
+    best_bid: Option<Level>,
+    best_ask: Option<Level>,
+}
+
+impl Default for BookMetricsCalculator {
+    fn default() -> Self {
+        Self::new(DEFAULT_PRESSURE_DEPTH)
+    }
+}
+
+impl BookMetricsCalculator {
+    /// Construct a new [`Self`] that sums the top `depth` [`Level`]s per side into the
+    /// [`BookMetrics`] bid & ask pressure.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            books: HashMap::new(),
+        }
+    }
+
+    /// Process a [`MarketEvent<OrderBook>`] snapshot, returning the [`BookMetrics`] relative to
+    /// the previous [`OrderBook`] of the same exchange [`Instrument`], if any.
+    pub fn process(&mut self, event: &MarketEvent<OrderBook>) -> Option<MarketEvent<BookMetrics>> {
+        let book = &event.kind;
+        if book.delta {
+            return None;
+        }
+
+        let current = TopOfBook {
+            time: event.exchange_time,
+            best_bid: book.bids.levels().first().copied(),
+            best_ask: book.asks.levels().first().copied(),
+        };
+
+        let previous = self
+            .books
+            .insert((event.exchange.clone(), event.instrument.clone()), current)?;
+
+        let bid_pressure = pressure(book.bids.levels(), self.depth);
+        let ask_pressure = pressure(book.asks.levels(), self.depth);
+        let elapsed = (current.time - previous.time)
+            .to_std()
+            .map(|elapsed| elapsed.as_secs_f64())
+            .unwrap_or_default();
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
+            exchange: event.exchange.clone(),
+            instrument: event.instrument.clone(),
+            kind: BookMetrics {
+                order_flow_imbalance: bid_flow(previous.best_bid, current.best_bid)
+                    - ask_flow(previous.best_ask, current.best_ask),
+                bid_pressure,
+                ask_pressure,
+                pressure_imbalance: imbalance(bid_pressure, ask_pressure),
+                bid_depletion_rate: depletion_rate(
+                    previous.best_bid,
+                    current.best_bid,
+                    elapsed,
+                    |previous, current| current < previous,
+                ),
+                ask_depletion_rate: depletion_rate(
+                    previous.best_ask,
+                    current.best_ask,
+                    elapsed,
+                    |previous, current| current > previous,
+                ),
+            },
+        })
+    }
+}
+
+/// Order flow contribution of the best bid [`Level`] between consecutive snapshots.
+fn bid_flow(previous: Option<Level>, current: Option<Level>) -> f64 {
+    match (previous, current) {
+        (Some(previous), Some(current)) if current.price > previous.price => current.amount,
+        (Some(previous), Some(current)) if current.price < previous.price => -previous.amount,
+        (Some(previous), Some(current)) => current.amount - previous.amount,
+        (None, Some(current)) => current.amount,
+        (Some(previous), None) => -previous.amount,
+        (None, None) => 0.0,
+    }
+}
+
+/// Order flow contribution of the best ask [`Level`] between consecutive snapshots.
+fn ask_flow(previous: Option<Level>, current: Option<Level>) -> f64 {
+    match (previous, current) {
+        (Some(previous), Some(current)) if current.price < previous.price => current.amount,
+        (Some(previous), Some(current)) if current.price > previous.price => -previous.amount,
+        (Some(previous), Some(current)) => current.amount - previous.amount,
+        (None, Some(current)) => current.amount,
+        (Some(previous), None) => -previous.amount,
+        (None, None) => 0.0,
+    }
+}
+
+/// Total amount resting on the top `depth` [`Level`]s of an [`OrderBookSide`](crate::subscription::book::OrderBookSide).
+fn pressure(levels: &[Level], depth: usize) -> f64 {
+    levels.iter().take(depth).map(|level| level.amount).sum()
+}
+
+/// Normalised imbalance of the bid & ask pressure, or zero if both sides are empty.
+fn imbalance(bid_pressure: f64, ask_pressure: f64) -> f64 {
+    let total = bid_pressure + ask_pressure;
+    match total > 0.0 {
+        true => (bid_pressure - ask_pressure) / total,
+        false => 0.0,
+    }
+}
+
+/// Rate at which the best [`Level`] queue was depleted over `elapsed` seconds.
+///
+/// A queue that shrank at the same price is depleted by the reduction, and a queue that was traded
+/// through (ie/ the best price moved `through` it) is depleted by it's full previous amount.
+fn depletion_rate(
+    previous: Option<Level>,
+    current: Option<Level>,
+    elapsed: f64,
+    through: fn(f64, f64) -> bool,
+) -> f64 {
+    if elapsed <= 0.0 {
+        return 0.0;
+    }
+
+    let depleted = match (previous, current) {
+        (Some(previous), Some(current)) if through(previous.price, current.price) => {
+            previous.amount
+        }
+        (Some(previous), Some(current)) if current.eq_price(previous.price) => {
+            (previous.amount - current.amount).max(0.0)
+        }
+        (Some(previous), None) => previous.amount,
+        _ => 0.0,
+    };
+
+    depleted / elapsed
+}
+
+/// Compute the [`BookMetrics`] of each received [`MarketEvent<OrderBook>`] using a
+/// [`BookMetricsCalculator`], forwarding them as [`MarketEvent<BookMetrics>`]s.
+pub async fn book_metrics(
+    mut book_rx: mpsc::UnboundedReceiver<MarketEvent<OrderBook>>,
+    metrics_tx: mpsc::UnboundedSender<MarketEvent<BookMetrics>>,
+    depth: usize,
+) {
+    let mut calculator = BookMetricsCalculator::new(depth);
+
+    while let Some(book) = book_rx.recv().await {
+        let Some(metrics) = calculator.process(&book) else {
+            continue;
+        };
+
+        if metrics_tx.send(metrics).is_err() {
+            debug!("BookMetrics receiver dropped - stopping book metrics");
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixture::market_event, subscription::book::OrderBookSide};
+    use barter_integration::model::Side;
+    use chrono::TimeZone;
+
+    fn book(second: u32, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> MarketEvent<OrderBook> {
+        let time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, second).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            ..market_event(OrderBook {
+                last_update_time: time,
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+                sequence: None,
+                delta: false,
+            })
+        }
+    }
+
+    #[test]
+    fn test_book_metrics_calculator() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Option<BookMetrics>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first OrderBook initialises state
+                input: book(0, vec![(100.0, 2.0), (99.0, 3.0)], vec![(101.0, 1.0)]),
+                expected: None,
+            },
+            TestCase {
+                // TC1: best bid queue shrinks & best ask queue grows at the same prices
+                input: book(2, vec![(100.0, 1.0), (99.0, 3.0)], vec![(101.0, 3.0)]),
+                expected: Some(BookMetrics {
+                    order_flow_imbalance: -1.0 - 2.0,
+                    bid_pressure: 4.0,
+                    ask_pressure: 3.0,
+                    pressure_imbalance: 1.0 / 7.0,
+                    bid_depletion_rate: 0.5,
+                    ask_depletion_rate: 0.0,
+                }),
+            },
+            TestCase {
+                // TC2: best bid traded through & best ask improves
+                input: book(3, vec![(99.0, 3.0)], vec![(100.5, 1.0)]),
+                expected: Some(BookMetrics {
+                    order_flow_imbalance: -1.0 - 1.0,
+                    bid_pressure: 3.0,
+                    ask_pressure: 1.0,
+                    pressure_imbalance: 0.5,
+                    bid_depletion_rate: 1.0,
+                    ask_depletion_rate: 0.0,
+                }),
+            },
+            TestCase {
+                // TC3: best bid improves & best ask traded through
+                input: book(5, vec![(100.0, 4.0)], vec![(101.0, 2.0)]),
+                expected: Some(BookMetrics {
+                    order_flow_imbalance: 4.0 + 1.0,
+                    bid_pressure: 4.0,
+                    ask_pressure: 2.0,
+                    pressure_imbalance: 2.0 / 6.0,
+                    bid_depletion_rate: 0.0,
+                    ask_depletion_rate: 0.5,
+                }),
+            },
+        ];
+
+        let mut calculator = BookMetricsCalculator::default();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = calculator.process(&test.input).map(|event| event.kind);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_pressure_depth() {
+        let levels = vec![
+            Level::new(100.0, 1.0),
+            Level::new(99.0, 2.0),
+            Level::new(98.0, 4.0),
+        ];
+        assert_eq!(pressure(&levels, 2), 3.0);
+        assert_eq!(pressure(&levels, 10), 7.0);
+        assert_eq!(imbalance(0.0, 0.0), 0.0);
+    }
+}
@@ -1,22 +1,169 @@
-use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubKind};
+use self::{
+    builder::{multi::MultiStreamBuilder, StreamBuilder},
+    fanout::{BroadcastStreams, WatchStreams},
+    group::StreamGroups,
+    ordered::{merge_ordered, OrderedMergeConfig},
+    redundant::{deduplicate, Deduplicate},
+};
+use crate::{event::MarketEvent, exchange::ExchangeId, subscription::SubKind};
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
 
+/// Optional [`AggressorInference`](aggressor::AggressorInference) stage that classifies the
+/// aggressor side of [`PublicTrade`](crate::subscription::trade::PublicTrade)s from the
+/// prevailing quotes, for exchanges that do not tag the taker side reliably.
+pub mod aggressor;
+
+/// Opt-in [`BandwidthMeter`](bandwidth::BandwidthMeter) accounting of the bytes received per
+/// exchange connection & per subscription.
+pub mod bandwidth;
+
+/// [`BasisComposite`](basis::BasisComposite) operator joining spot prices & perpetual funding of
+/// the same underlying across exchanges into annualised [`Basis`](basis::Basis) events.
+pub mod basis;
+
+/// [`QuoteConverter`](convert::QuoteConverter) stage re-denominating the prices of events quoted
+/// in a native currency into a reference currency, using a designated reference price stream.
+pub mod convert;
+
+/// Deserialisation [`ErrorTolerance`](breaker::ErrorTolerance) policy enforced per connection by
+/// a [`CircuitBreaker`](breaker::CircuitBreaker) that reconnects or disables the connection.
+pub mod breaker;
+
 /// Defines the [`StreamBuilder`](builder::StreamBuilder) and
 /// [`MultiStreamBuilder`](builder::multi::MultiStreamBuilder) APIs for ergonomically initialising
 /// [`MarketStream`](super::MarketStream) [`Streams`].
 pub mod builder;
 
+/// Optional [`LastValueCache`](cache::LastValueCache) of the latest event per (exchange,
+/// instrument, [`CachedKind`](cache::CachedKind)), with a synchronous query API for
+/// request/response components.
+pub mod cache;
+
+/// Connection level [`StreamConfig`](config::StreamConfig) used to customise how each
+/// [`MarketStream`](super::MarketStream) connects to an exchange server (eg/ WebSocket [`Url`](url::Url) override).
+pub mod config;
+
+/// Compressed segment file [`ArchiveWriter`](archive::ArchiveWriter) of the raw frames
+/// broadcast by a [`RawTap`](tap::RawTap), suitable for exact replay & long term retention.
+/// Enabled via the `archive` feature.
+#[cfg(feature = "archive")]
+pub mod archive;
+
 /// Central consumer loop functionality used by the [`StreamBuilder`](builder::StreamBuilder) to
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// [`CandleContinuityChecker`](continuity::CandleContinuityChecker) detecting missing
+/// [`Candle`](crate::subscription::candle::Candle) intervals, with a
+/// [`check_candle_continuity`](continuity::check_candle_continuity) stage that backfills gaps via
+/// HTTP and emits correction events.
+pub mod continuity;
+
+/// Multi-consumer [`BroadcastStreams`](fanout::BroadcastStreams) & latest value
+/// [`WatchStreams`](fanout::WatchStreams) output, so several tasks can consume the same exchange
+/// stream without an external fan-out layer.
+pub mod fanout;
+
+/// Configurable [`FrameLimits`](limits::FrameLimits) (eg/ maximum frame size & JSON array length)
+/// enforced on received frames before parsing.
+pub mod limits;
+
+/// Derived [`BookMetrics`](metrics::BookMetrics) microstructure events (eg/ order flow
+/// imbalance, bid & ask pressure, queue depletion rates) computed in-process from L2
+/// [`OrderBook`](crate::subscription::book::OrderBook) updates.
+pub mod metrics;
+
+/// Event-time ordered merge of [`MarketEvent<T>`](crate::event::MarketEvent)s across
+/// subscriptions of one or more exchanges, using a bounded
+/// [`ReorderBuffer`](ordered::ReorderBuffer).
+pub mod ordered;
+
+/// Sliding window deduplication of [`MarketEvent<T>`](crate::event::MarketEvent)s received more
+/// than once (eg/ over redundant exchange connections, re-connections or backfill), used by
+/// [`StreamBuilder::subscribe_redundant`].
+pub mod redundant;
+
+/// REST [`RecentTrades`](reconcile::RecentTrades) reconciliation that fills gaps in WebSocket
+/// trade streams.
+pub mod reconcile;
+
+/// [`Resampler`](resample::Resampler) operator that downsamples any
+/// [`MarketEvent<T>`](crate::event::MarketEvent) stream to fixed intervals, using a selectable
+/// [`Aggregation`](resample::Aggregation) per field.
+pub mod resample;
+
+/// Deterministic [`replay`](replay::replay) of archived raw frames through exchange
+/// [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)s for regression testing.
+pub mod replay;
+
+/// Optional [`SanityChecker`](sanity::SanityChecker) stage that flags obviously bad exchange data
+/// as [`DataAnomaly`](sanity::DataAnomaly) events rather than silently forwarding it, applying a
+/// per subscription [`AnomalyPolicy`](sanity::AnomalyPolicy).
+pub mod sanity;
+
+/// [`sample`](sample::sample) stage that rate limits the events of low priority feeds per
+/// [`Instrument`](barter_integration::model::instrument::Instrument), keeping the latest.
+pub mod sample;
+
+/// Trading [`SessionCalendar`](session::SessionCalendar)s of venues with sessions or scheduled
+/// downtime, used to emit session open & close events and suppress stale feed alarms while out
+/// of session.
+pub mod session;
+
+/// Named subscription groups with independent lifecycle control, paused, resumed & cancelled
+/// via the [`StreamGroups`](group::StreamGroups) handle of a running [`Streams`].
+pub mod group;
+
+/// Multi-tenant [`SubscriptionManager`](manager::SubscriptionManager) that reference counts the
+/// overlapping [`Subscription`](crate::subscription::Subscription)s of in-process consumers,
+/// sharing exchange connections and closing them once the last consumer is dropped.
+pub mod manager;
+
+/// Periodic re-evaluation of a [`UniverseRule`](universe::UniverseRule) (eg/ top-N by volume),
+/// adding & removing the [`Subscription`](crate::subscription::Subscription)s of a live
+/// [`SubscriptionManager`](manager::SubscriptionManager) universe as it changes.
+pub mod universe;
+
+/// Startup [`LatencyProbe`](probe::LatencyProbe) that selects the lowest latency regional
+/// endpoint of an exchange.
+pub mod probe;
+
+/// Opt-in [`TaskProfiler`](profiling::TaskProfiler) of the poll durations & allocations of each
+/// exchange connection task. Enabled via the `profiling` feature.
+#[cfg(feature = "profiling")]
+pub mod profiling;
+
+/// Persistent [`StateStore`](state::StateStore) of the active subscription set & last
+/// [`OrderBook`](crate::subscription::book::OrderBook) checkpoints, used to resubscribe
+/// identically after a warm restart.
+pub mod state;
+
+/// At-least-once [`spill_buffer`](spill::spill_buffer) stage that spools events to an on-disk
+/// [`SpillQueue`](spill::SpillQueue) while the consumer lags or a downstream sink restarts.
+pub mod spill;
+
+/// Multiplexing of many [`SubKind`]s over a shared exchange
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection, used by the
+/// [`SharedStreamBuilder`](builder::shared::SharedStreamBuilder).
+pub mod shared;
+
+/// Opt-in [`RawTap`](tap::RawTap) broadcast of the raw frames received by exchange connections.
+pub mod tap;
+
+/// Optional [`TapeClassifier`](tape::TapeClassifier) analytics stage that detects likely
+/// [`Sweep`](tape::Sweep)s & [`IcebergRefill`](tape::IcebergRefill)s from the trade tape and
+/// prevailing quotes.
+pub mod tape;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
     pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+    /// Handle used to pause, resume & cancel the named subscription groups of the
+    /// [`Streams`], which should be cloned before the receivers are consumed (eg/ joined).
+    pub groups: StreamGroups,
 }
 
 impl<T> Streams<T> {
@@ -60,6 +207,16 @@ impl<T> Streams<T> {
         joined_rx
     }
 
+    /// Convert the single consumer exchange [`mpsc::UnboundedReceiver`]s into
+    /// [`BroadcastStreams`], from which any number of tasks can subscribe to each exchange
+    /// stream. Every receiver buffers up to `capacity` events before it lags.
+    pub async fn broadcast(self, capacity: usize) -> BroadcastStreams<T>
+    where
+        T: Clone + Send + 'static,
+    {
+        BroadcastStreams::new(self.streams, capacity)
+    }
+
     /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified [`StreamMap`].
     pub async fn join_map(self) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>> {
         self.streams
@@ -70,3 +227,45 @@ impl<T> Streams<T> {
             })
     }
 }
+
+impl<T> Streams<MarketEvent<T>> {
+    /// Convert the single consumer exchange [`mpsc::UnboundedReceiver`]s into [`WatchStreams`],
+    /// from which any number of tasks can observe the latest [`MarketEvent<T>`] of each exchange
+    /// [`Instrument`](barter_integration::model::instrument::Instrument) (eg/ order books).
+    pub async fn watch(self) -> WatchStreams<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        WatchStreams::new(self.streams)
+    }
+
+    /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified
+    /// [`mpsc::UnboundedReceiver`] that yields [`MarketEvent<T>`]s in `exchange_time` order,
+    /// reordering events within the [`OrderedMergeConfig`] max lateness (see [`merge_ordered`]).
+    pub async fn join_ordered(
+        self,
+        config: OrderedMergeConfig,
+    ) -> mpsc::UnboundedReceiver<MarketEvent<T>>
+    where
+        T: Send + 'static,
+    {
+        let merged_rx = self.join().await;
+        let (ordered_tx, ordered_rx) = mpsc::unbounded_channel();
+        tokio::spawn(merge_ordered(merged_rx, ordered_tx, config));
+        ordered_rx
+    }
+
+    /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified
+    /// [`mpsc::UnboundedReceiver`] that yields the first arrival of each [`MarketEvent<T>`],
+    /// discarding duplicates keyed by (exchange, instrument, id) within the most recent `window`
+    /// events (see [`deduplicate`]).
+    pub async fn join_deduplicated(self, window: usize) -> mpsc::UnboundedReceiver<MarketEvent<T>>
+    where
+        T: Deduplicate + Send + 'static,
+    {
+        let joined_rx = self.join().await;
+        let (deduplicated_tx, deduplicated_rx) = mpsc::unbounded_channel();
+        tokio::spawn(deduplicate(joined_rx, deduplicated_tx, window));
+        deduplicated_rx
+    }
+}
@@ -0,0 +1,407 @@
+use crate::event::MarketEvent;
+use chrono::{DateTime, Utc};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Default maximum lateness of a [`MarketEvent<T>`] `exchange_time` behind the watermark before
+/// it is considered late.
+pub const DEFAULT_MAX_LATENESS: Duration = Duration::from_millis(500);
+
+/// Default maximum number of [`MarketEvent<T>`]s buffered by a [`ReorderBuffer`] before the
+/// earliest events are released regardless of the watermark.
+pub const DEFAULT_REORDER_CAPACITY: usize = 100_000;
+
+/// Configuration of an event-time ordered merge (see [`merge_ordered`]).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct OrderedMergeConfig {
+    /// Maximum lateness of an event `exchange_time` behind the latest `exchange_time` seen that
+    /// is still reordered. Later events are dropped.
+    pub max_lateness: Duration,
+
+    /// Maximum number of buffered events, after which the earliest events are released early.
+    pub capacity: usize,
+}
+
+impl Default for OrderedMergeConfig {
+    fn default() -> Self {
+        Self {
+            max_lateness: DEFAULT_MAX_LATENESS,
+            capacity: DEFAULT_REORDER_CAPACITY,
+        }
+    }
+}
+
+/// Bounded watermark buffer that reorders [`MarketEvent<T>`]s into `exchange_time` order.
+///
+/// The watermark trails the latest `exchange_time` seen by the configured `max_lateness`, and
+/// every buffered event at or before the watermark is released in `exchange_time` order (ties
+/// are released in arrival order). Events older than the last released event are late, and are
+/// dropped to preserve the ordering guarantee.
+#[derive(Debug)]
+pub struct ReorderBuffer<T> {
+    config: OrderedMergeConfig,
+    buffer: BinaryHeap<Reverse<Buffered<T>>>,
+    sequence: u64,
+    watermark: Option<DateTime<Utc>>,
+    released: Option<DateTime<Utc>>,
+    late: u64,
+}
+
+impl<T> ReorderBuffer<T> {
+    /// Construct a new empty [`Self`] using the provided [`OrderedMergeConfig`].
+    pub fn new(config: OrderedMergeConfig) -> Self {
+        Self {
+            config,
+            buffer: BinaryHeap::new(),
+            sequence: 0,
+            watermark: None,
+            released: None,
+            late: 0,
+        }
+    }
+
+    /// Number of buffered [`MarketEvent<T>`]s.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns true if no [`MarketEvent<T>`]s are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Number of late [`MarketEvent<T>`]s dropped so far.
+    pub fn late(&self) -> u64 {
+        self.late
+    }
+
+    /// Buffer the [`MarketEvent<T>`], returning every event released as a result in
+    /// `exchange_time` order.
+    pub fn push(&mut self, event: MarketEvent<T>) -> Vec<MarketEvent<T>> {
+        if self
+            .released
+            .is_some_and(|released| event.exchange_time < released)
+        {
+            self.late += 1;
+            return vec![];
+        }
+
+        // A max_lateness reaching beyond the earliest representable time never advances the
+        // watermark, so buffered events are only released early or flushed
+        let watermark = event
+            .exchange_time
+            .checked_sub_signed(max_lateness(&self.config));
+        self.sequence += 1;
+        self.buffer.push(Reverse(Buffered {
+            sequence: self.sequence,
+            event,
+        }));
+
+        let mut released = match watermark {
+            Some(watermark) => self.advance(watermark),
+            None => vec![],
+        };
+
+        // Release the earliest events early if the buffer is full
+        while self.buffer.len() > self.config.capacity {
+            released.extend(self.pop());
+        }
+
+        released
+    }
+
+    /// Advance the watermark to the provided time (if later than the current watermark),
+    /// returning every buffered [`MarketEvent<T>`] at or before it in `exchange_time` order.
+    pub fn advance(&mut self, watermark: DateTime<Utc>) -> Vec<MarketEvent<T>> {
+        let watermark = match self.watermark {
+            Some(current) if current >= watermark => current,
+            _ => watermark,
+        };
+        self.watermark = Some(watermark);
+
+        let mut released = Vec::new();
+        while self
+            .buffer
+            .peek()
+            .is_some_and(|Reverse(next)| next.event.exchange_time <= watermark)
+        {
+            released.extend(self.pop());
+        }
+
+        released
+    }
+
+    /// Release every buffered [`MarketEvent<T>`] in `exchange_time` order.
+    pub fn flush(&mut self) -> Vec<MarketEvent<T>> {
+        let mut released = Vec::with_capacity(self.buffer.len());
+        while let Some(event) = self.pop() {
+            released.push(event);
+        }
+        released
+    }
+
+    fn pop(&mut self) -> Option<MarketEvent<T>> {
+        self.buffer.pop().map(|Reverse(buffered)| {
+            self.released = Some(buffered.event.exchange_time);
+            buffered.event
+        })
+    }
+}
+
+/// [`MarketEvent<T>`] buffered by a [`ReorderBuffer`], ordered by `exchange_time` then arrival.
+#[derive(Debug)]
+struct Buffered<T> {
+    sequence: u64,
+    event: MarketEvent<T>,
+}
+
+impl<T> PartialEq for Buffered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Buffered<T> {}
+
+impl<T> PartialOrd for Buffered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Buffered<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.event
+            .exchange_time
+            .cmp(&other.event.exchange_time)
+            .then_with(|| self.sequence.cmp(&other.sequence))
+    }
+}
+
+fn max_lateness(config: &OrderedMergeConfig) -> chrono::Duration {
+    chrono::Duration::from_std(config.max_lateness).unwrap_or(chrono::Duration::MAX)
+}
+
+/// Forward the [`MarketEvent<T>`]s received via `merged_rx` (eg/ from every subscription of one
+/// or more exchanges) to the `ordered_tx` in `exchange_time` order, using a [`ReorderBuffer`].
+///
+/// The watermark is driven by event time only, so historical (eg/ replayed) events are ordered in
+/// the same way as live events. So that events are not held indefinitely while the inputs are
+/// quiet, every buffered event is flushed if no event arrives for `max_lateness` of wall clock
+/// time, and once all inputs have ended.
+///
+/// Runs until every input sender has been dropped, or the downstream receiver has been dropped.
+pub async fn merge_ordered<T>(
+    mut merged_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    ordered_tx: mpsc::UnboundedSender<MarketEvent<T>>,
+    config: OrderedMergeConfig,
+) {
+    let mut buffer = ReorderBuffer::new(config);
+    let idle = config.max_lateness.max(Duration::from_millis(1));
+
+    loop {
+        let released = match tokio::time::timeout(idle, merged_rx.recv()).await {
+            Ok(Some(event)) => {
+                let late = buffer.late();
+                let released = buffer.push(event);
+                if buffer.late() > late {
+                    warn!(
+                        late = buffer.late(),
+                        max_lateness = ?config.max_lateness,
+                        "dropped late MarketEvent from ordered merge"
+                    );
+                }
+                released
+            }
+            Ok(None) => {
+                for event in buffer.flush() {
+                    let _ = ordered_tx.send(event);
+                }
+                debug!("ordered merge inputs ended - stopping ordered merge");
+                break;
+            }
+            // Inputs are idle, so release every buffered event
+            Err(_) => buffer.flush(),
+        };
+
+        for event in released {
+            if ordered_tx.send(event).is_err() {
+                debug!("ordered MarketEvent receiver dropped - stopping ordered merge");
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixture::trade_event,
+        subscription::trade::{PublicTrade, TradeId},
+    };
+    use barter_integration::model::Exchange;
+
+    fn trade(exchange: &'static str, id: u64, millis: i64) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: DateTime::from_timestamp_millis(millis).unwrap(),
+            exchange: Exchange::from(exchange),
+            ..trade_event(id, 1.0, 1.0)
+        }
+    }
+
+    fn ids(events: Vec<MarketEvent<PublicTrade>>) -> Vec<TradeId> {
+        events.into_iter().map(|event| event.kind.id).collect()
+    }
+
+    #[test]
+    fn test_reorder_buffer() {
+        struct TestCase {
+            config: OrderedMergeConfig,
+            input: Vec<MarketEvent<PublicTrade>>,
+            expected: Vec<u64>,
+            expected_late: u64,
+        }
+
+        let config = OrderedMergeConfig {
+            max_lateness: Duration::from_millis(100),
+            capacity: 100,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: events within max lateness are released in exchange_time order
+                config,
+                input: vec![
+                    trade("binance_spot", 2, 1_050),
+                    trade("okx", 1, 1_000),
+                    trade("binance_spot", 3, 1_100),
+                    trade("okx", 4, 1_300),
+                ],
+                expected: vec![1, 2, 3],
+                expected_late: 0,
+            },
+            TestCase {
+                // TC1: events with equal exchange_time are released in arrival order
+                config,
+                input: vec![
+                    trade("okx", 1, 1_000),
+                    trade("binance_spot", 2, 1_000),
+                    trade("okx", 3, 1_200),
+                ],
+                expected: vec![1, 2],
+                expected_late: 0,
+            },
+            TestCase {
+                // TC2: events older than the last released event are dropped as late
+                config,
+                input: vec![
+                    trade("okx", 1, 1_000),
+                    trade("okx", 2, 1_200),
+                    trade("binance_spot", 3, 900),
+                    trade("binance_spot", 4, 1_150),
+                ],
+                expected: vec![1],
+                expected_late: 1,
+            },
+            TestCase {
+                // TC3: full buffer releases the earliest events regardless of the watermark
+                config: OrderedMergeConfig {
+                    max_lateness: Duration::from_secs(60),
+                    capacity: 2,
+                },
+                input: vec![
+                    trade("okx", 3, 1_200),
+                    trade("okx", 1, 1_000),
+                    trade("okx", 2, 1_100),
+                ],
+                expected: vec![1],
+                expected_late: 0,
+            },
+            TestCase {
+                // TC4: max lateness beyond the representable time range never releases events
+                // by watermark, rather than overflowing
+                config: OrderedMergeConfig {
+                    max_lateness: Duration::MAX,
+                    capacity: 2,
+                },
+                input: vec![
+                    trade("okx", 2, 1_100),
+                    trade("okx", 1, 1_000),
+                    trade("okx", 3, 1_200),
+                ],
+                expected: vec![1],
+                expected_late: 0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut buffer = ReorderBuffer::new(test.config);
+            let actual = test
+                .input
+                .into_iter()
+                .flat_map(|event| buffer.push(event))
+                .collect::<Vec<_>>();
+            let expected = test
+                .expected
+                .into_iter()
+                .map(TradeId::U64)
+                .collect::<Vec<_>>();
+            assert_eq!(ids(actual), expected, "TC{} failed", index);
+            assert_eq!(buffer.late(), test.expected_late, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_reorder_buffer_advance_and_flush() {
+        let mut buffer = ReorderBuffer::new(OrderedMergeConfig {
+            max_lateness: Duration::from_secs(10),
+            capacity: DEFAULT_REORDER_CAPACITY,
+        });
+        assert!(buffer.push(trade("okx", 2, 2_000)).is_empty());
+        assert!(buffer.push(trade("okx", 1, 1_000)).is_empty());
+        assert!(buffer.push(trade("okx", 3, 3_000)).is_empty());
+
+        // Advancing the watermark releases events at or before it
+        let released = buffer.advance(DateTime::from_timestamp_millis(2_000).unwrap());
+        assert_eq!(ids(released), vec![TradeId::U64(1), TradeId::U64(2)]);
+
+        // Watermark never moves backwards
+        assert!(buffer
+            .advance(DateTime::from_timestamp_millis(0).unwrap())
+            .is_empty());
+
+        assert_eq!(ids(buffer.flush()), vec![TradeId::U64(3)]);
+        assert!(buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_merge_ordered() {
+        let (merged_tx, merged_rx) = mpsc::unbounded_channel();
+        let (ordered_tx, mut ordered_rx) = mpsc::unbounded_channel();
+
+        // Events that arrive out of exchange_time order across exchanges
+        for (exchange, id, millis) in [
+            ("okx", 2, 1_020),
+            ("binance_spot", 1, 1_000),
+            ("binance_spot", 4, 1_040),
+            ("okx", 3, 1_030),
+        ] {
+            merged_tx.send(trade(exchange, id, millis)).unwrap();
+        }
+        drop(merged_tx);
+
+        merge_ordered(merged_rx, ordered_tx, OrderedMergeConfig::default()).await;
+
+        let mut actual = Vec::new();
+        while let Some(event) = ordered_rx.recv().await {
+            actual.push(event.kind.id);
+        }
+        assert_eq!(actual, [1, 2, 3, 4].map(TradeId::U64).to_vec());
+    }
+}
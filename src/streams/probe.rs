@@ -0,0 +1,247 @@
+use super::config::StreamConfig;
+use crate::exchange::Connector;
+use barter_integration::error::SocketError;
+use futures::{future::join_all, SinkExt};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+use url::Url;
+
+/// Default maximum [`Duration`] a [`LatencyProbe`] waits for each candidate endpoint to connect.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Startup probe that measures the connection round trip time to each regional endpoint of an
+/// exchange, selecting the lowest latency one.
+///
+/// Candidate endpoints are the [`Connector::channel_url`] plus the same [`Url`] served by each of
+/// the [`Connector::regional_hosts`]. Latency is measured as the time taken to establish a
+/// [`Transport`](crate::transport::Transport) connection (ie/ TCP, TLS & WebSocket handshakes)
+/// via the configured [`StreamConfig::transport`], and every candidate is probed concurrently.
+///
+/// Configured via [`StreamConfig::with_latency_probe`]. A [`StreamConfig::url`] override, or an
+/// active backup endpoint, always takes precedence over the probed endpoint.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct LatencyProbe {
+    /// Maximum [`Duration`] to wait for each candidate endpoint to connect, after which the
+    /// candidate is discarded.
+    pub timeout: Duration,
+}
+
+impl Default for LatencyProbe {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_PROBE_TIMEOUT,
+        }
+    }
+}
+
+impl LatencyProbe {
+    /// Construct a new [`Self`] using the provided candidate connection timeout.
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+
+    /// Determine the candidate endpoints for the provided [`Connector`] server [`Url`], starting
+    /// with the [`Url`] itself followed by each unique [`Connector::regional_hosts`] equivalent.
+    pub fn candidates<Exchange>(url: &Url) -> Vec<Url>
+    where
+        Exchange: Connector,
+    {
+        let mut candidates = vec![url.clone()];
+
+        for host in Exchange::regional_hosts() {
+            let (host, port) = match host.split_once(':') {
+                Some((host, port)) => (host, port.parse::<u16>().ok()),
+                None => (*host, None),
+            };
+
+            let mut candidate = url.clone();
+            if candidate.set_host(Some(host)).is_err() || candidate.set_port(port).is_err() {
+                warn!(exchange = %Exchange::ID, %host, "skipping invalid regional host");
+                continue;
+            }
+
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+
+    /// Measure the [`Duration`] taken to connect to the provided endpoint [`Url`] using the
+    /// [`StreamConfig`] transport. The probe connection is closed once established.
+    pub async fn measure(&self, config: &StreamConfig, url: Url) -> Result<Duration, SocketError> {
+        let start = Instant::now();
+        let mut transport = tokio::time::timeout(self.timeout, config.connect(url))
+            .await
+            .map_err(|_| {
+                SocketError::Subscribe(format!("latency probe timeout reached: {:?}", self.timeout))
+            })??;
+        let latency = start.elapsed();
+
+        // Probe connection is not used, so failing to close it gracefully is not an error
+        let _ = transport.close().await;
+
+        Ok(latency)
+    }
+
+    /// Concurrently [`Self::measure`] every candidate endpoint, returning the lowest latency
+    /// [`Url`] & its latency, or `None` if no candidate could be connected to.
+    pub async fn fastest(
+        &self,
+        config: &StreamConfig,
+        candidates: Vec<Url>,
+    ) -> Option<(Url, Duration)> {
+        let latencies = join_all(candidates.into_iter().map(|url| async move {
+            let latency = self.measure(config, url.clone()).await;
+            (url, latency)
+        }))
+        .await;
+
+        latencies
+            .into_iter()
+            .filter_map(|(url, latency)| match latency {
+                Ok(latency) => {
+                    debug!(%url, ?latency, "probed endpoint latency");
+                    Some((url, latency))
+                }
+                Err(error) => {
+                    warn!(%url, %error, "failed to probe endpoint latency");
+                    None
+                }
+            })
+            .min_by_key(|(_, latency)| *latency)
+    }
+
+    /// Select the lowest latency endpoint out of the [`Self::candidates`] for the provided
+    /// [`Connector`] server [`Url`], falling back to the provided [`Url`] if no candidate could be
+    /// connected to.
+    pub async fn select<Exchange>(&self, config: &StreamConfig, url: Url) -> Url
+    where
+        Exchange: Connector,
+    {
+        let candidates = Self::candidates::<Exchange>(&url);
+        if candidates.len() < 2 {
+            return url;
+        }
+
+        match self.fastest(config, candidates).await {
+            Some((selected, latency)) => {
+                info!(exchange = %Exchange::ID, url = %selected, ?latency, "selected lowest latency endpoint");
+                selected
+            }
+            None => url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::spot::BinanceSpot,
+            coinbase::Coinbase,
+            okx::{channel::OkxChannel, Okx},
+        },
+        transport::{BoxTransport, ChannelTransport, TransportConnector},
+    };
+    use async_trait::async_trait;
+
+    /// [`TransportConnector`] that simulates a fixed connection latency per host, failing to
+    /// connect to unknown hosts.
+    #[derive(Debug)]
+    struct RegionConnector(Vec<(&'static str, Duration)>);
+
+    #[async_trait]
+    impl TransportConnector for RegionConnector {
+        async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+            let (_, latency) = self
+                .0
+                .iter()
+                .find(|(host, _)| url.host_str() == Some(*host))
+                .ok_or_else(|| SocketError::Subscribe(format!("unreachable: {url}")))?;
+
+            tokio::time::sleep(*latency).await;
+            Ok(Box::new(ChannelTransport::pair().0))
+        }
+    }
+
+    #[test]
+    fn test_candidates() {
+        struct TestCase {
+            actual: Vec<Url>,
+            expected: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Okx public url includes each other regional host
+                actual: LatencyProbe::candidates::<Okx>(&Okx::url().unwrap()),
+                expected: vec![
+                    "wss://wsaws.okx.com:8443/ws/v5/public",
+                    "wss://ws.okx.com:8443/ws/v5/public",
+                ],
+            },
+            TestCase {
+                // TC1: Okx business url retains its path on each regional host
+                actual: LatencyProbe::candidates::<Okx>(
+                    &Okx::channel_url(&OkxChannel::CANDLE_1M).unwrap(),
+                ),
+                expected: vec![
+                    "wss://ws.okx.com:8443/ws/v5/business",
+                    "wss://wsaws.okx.com:8443/ws/v5/business",
+                ],
+            },
+            TestCase {
+                // TC2: regional host port replaces the url port
+                actual: LatencyProbe::candidates::<BinanceSpot>(&BinanceSpot::url().unwrap()),
+                expected: vec![
+                    "wss://stream.binance.com:9443/ws",
+                    "wss://stream.binance.com/ws",
+                    "wss://data-stream.binance.vision/ws",
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let expected = test
+                .expected
+                .into_iter()
+                .map(|url| Url::parse(url).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(test.actual, expected, "TC{} failed", index);
+        }
+
+        // Connector without regional hosts only has one candidate
+        assert_eq!(
+            LatencyProbe::candidates::<Coinbase>(&Coinbase::url().unwrap()),
+            vec![Coinbase::url().unwrap()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select() {
+        let probe = LatencyProbe::new(Duration::from_millis(500));
+
+        // Lowest latency reachable regional host is selected
+        let config = StreamConfig::default().with_transport(RegionConnector(vec![
+            ("wsaws.okx.com", Duration::from_millis(80)),
+            ("ws.okx.com", Duration::from_millis(10)),
+        ]));
+        assert_eq!(
+            probe.select::<Okx>(&config, Okx::url().unwrap()).await,
+            Url::parse("wss://ws.okx.com:8443/ws/v5/public").unwrap()
+        );
+
+        // Candidates that fail or exceed the probe timeout are discarded
+        let config = StreamConfig::default().with_transport(RegionConnector(vec![(
+            "wsaws.okx.com",
+            Duration::from_secs(5),
+        )]));
+        assert_eq!(
+            probe.select::<Okx>(&config, Okx::url().unwrap()).await,
+            Okx::url().unwrap()
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    /// Number of allocations made by the current thread via the [`CountingAllocator`].
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    /// Number of bytes allocated by the current thread via the [`CountingAllocator`].
+    static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// [`GlobalAlloc`] wrapper that counts the allocations made by each thread, so a
+/// [`TaskProfiler`] can attribute allocations to the connection task being polled.
+///
+/// Allocation counts are only recorded if a [`CountingAllocator`] is installed as the
+/// `#[global_allocator]` of the binary, otherwise they are always zero.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::profiling::CountingAllocator;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator<System> = CountingAllocator::new(System);
+///
+/// fn main() {
+///     // Allocations of profiled connection tasks are now counted
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CountingAllocator<Allocator> {
+    allocator: Allocator,
+}
+
+impl<Allocator> CountingAllocator<Allocator> {
+    /// Construct a new [`Self`] that counts the allocations made via the provided `Allocator`.
+    pub const fn new(allocator: Allocator) -> Self {
+        Self { allocator }
+    }
+}
+
+unsafe impl<Allocator> GlobalAlloc for CountingAllocator<Allocator>
+where
+    Allocator: GlobalAlloc,
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        self.allocator.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.allocator.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        self.allocator.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        self.allocator.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Count an allocation of the current thread. Allocations made while the thread local counters
+/// are being destroyed are not counted.
+fn count_allocation(bytes: usize) {
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+    let _ = ALLOCATED_BYTES.try_with(|allocated| allocated.set(allocated.get() + bytes as u64));
+}
+
+/// Number of allocations & allocated bytes counted by the [`CountingAllocator`] on the current
+/// thread.
+fn thread_allocations() -> (u64, u64) {
+    (
+        ALLOCATIONS.try_with(Cell::get).unwrap_or_default(),
+        ALLOCATED_BYTES.try_with(Cell::get).unwrap_or_default(),
+    )
+}
+
+/// Opt-in profiling of the poll durations & allocations of every exchange connection task
+/// configured with it, to identify which exchange's parser is burning CPU.
+///
+/// A [`TaskProfiler`] is attached to connections via
+/// [`StreamConfig::with_profiler`](super::config::StreamConfig::with_profiler), and can be
+/// shared across many connections. Each established connection registers a
+/// [`ConnectionProfiler`], so re-connections appear as new connections. Enabled via the
+/// `profiling` feature.
+///
+/// ### Notes
+/// - Each poll of a connection's [`MarketStream`](crate::MarketStream) is timed, which includes
+///   reading from the socket, deserialising & transforming exchange messages.
+/// - Allocations are only counted if a [`CountingAllocator`] is installed as the
+///   `#[global_allocator]`.
+/// - Not applied to [`SharedStreamBuilder`](super::builder::shared::SharedStreamBuilder)
+///   connections.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{config::StreamConfig, profiling::TaskProfiler};
+///
+/// let profiler = TaskProfiler::default();
+/// let config = StreamConfig::default().with_profiler(profiler.clone());
+///
+/// // Later, eg/ periodically to find the busiest connections
+/// for connection in profiler.connections() {
+///     println!("{} {}: {:?} busy", connection.exchange, connection.id, connection.busy);
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TaskProfiler {
+    connections: Arc<Mutex<Vec<Arc<ConnectionCounters>>>>,
+}
+
+impl TaskProfiler {
+    /// Register a new exchange connection task, returning the [`ConnectionProfiler`] that records
+    /// it's polls.
+    pub fn register(&self, exchange: ExchangeId) -> ConnectionProfiler {
+        let mut connections = self.connections.lock().expect("TaskProfiler lock poisoned");
+        let connection = Arc::new(ConnectionCounters {
+            id: connections.len() as u64,
+            exchange,
+            connected_time: Utc::now(),
+            active: AtomicBool::new(true),
+            polls: AtomicU64::new(0),
+            busy_nanos: AtomicU64::new(0),
+            max_poll_nanos: AtomicU64::new(0),
+            allocations: AtomicU64::new(0),
+            allocated_bytes: AtomicU64::new(0),
+        });
+        connections.push(Arc::clone(&connection));
+
+        ConnectionProfiler(connection)
+    }
+
+    /// Snapshot the [`ConnectionProfile`] of every connection registered with this profiler, in
+    /// the order they were established.
+    pub fn connections(&self) -> Vec<ConnectionProfile> {
+        self.connections
+            .lock()
+            .expect("TaskProfiler lock poisoned")
+            .iter()
+            .map(|connection| connection.profile())
+            .collect()
+    }
+}
+
+impl PartialEq for TaskProfiler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.connections, &other.connections)
+    }
+}
+
+impl Eq for TaskProfiler {}
+
+/// Shared profiling counters of one exchange connection task.
+#[derive(Debug)]
+struct ConnectionCounters {
+    id: u64,
+    exchange: ExchangeId,
+    connected_time: DateTime<Utc>,
+    active: AtomicBool,
+    polls: AtomicU64,
+    busy_nanos: AtomicU64,
+    max_poll_nanos: AtomicU64,
+    allocations: AtomicU64,
+    allocated_bytes: AtomicU64,
+}
+
+impl ConnectionCounters {
+    fn profile(&self) -> ConnectionProfile {
+        ConnectionProfile {
+            id: self.id,
+            exchange: self.exchange,
+            connected_time: self.connected_time,
+            active: self.active.load(Ordering::Relaxed),
+            polls: self.polls.load(Ordering::Relaxed),
+            busy: Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed)),
+            max_poll: Duration::from_nanos(self.max_poll_nanos.load(Ordering::Relaxed)),
+            allocations: self.allocations.load(Ordering::Relaxed),
+            allocated_bytes: self.allocated_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Records the polls of one exchange connection task registered with a [`TaskProfiler`].
+///
+/// The connection is marked inactive once the [`ConnectionProfiler`] is dropped.
+#[derive(Debug)]
+pub struct ConnectionProfiler(Arc<ConnectionCounters>);
+
+impl ConnectionProfiler {
+    /// Record a poll of the connection task that took `elapsed`, making the provided number of
+    /// allocations.
+    pub fn record(&self, elapsed: Duration, allocations: u64, allocated_bytes: u64) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.0.polls.fetch_add(1, Ordering::Relaxed);
+        self.0.busy_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.0.max_poll_nanos.fetch_max(nanos, Ordering::Relaxed);
+        self.0.allocations.fetch_add(allocations, Ordering::Relaxed);
+        self.0
+            .allocated_bytes
+            .fetch_add(allocated_bytes, Ordering::Relaxed);
+    }
+}
+
+impl Drop for ConnectionProfiler {
+    fn drop(&mut self) {
+        self.0.active.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Snapshot of the poll durations & allocations of one exchange connection task.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConnectionProfile {
+    /// Sequential identifier of the connection within it's [`TaskProfiler`].
+    pub id: u64,
+    pub exchange: ExchangeId,
+    pub connected_time: DateTime<Utc>,
+    /// False once the connection has been closed (eg/ before re-connecting).
+    pub active: bool,
+    pub polls: u64,
+    /// Total time spent polling the connection.
+    pub busy: Duration,
+    /// Longest single poll of the connection.
+    pub max_poll: Duration,
+    /// Allocations made while polling the connection (zero without a [`CountingAllocator`]).
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl ConnectionProfile {
+    /// Mean [`Duration`] of each poll of the connection.
+    pub fn mean_poll(&self) -> Duration {
+        match self.polls {
+            0 => Duration::ZERO,
+            polls => self.busy / u32::try_from(polls).unwrap_or(u32::MAX),
+        }
+    }
+}
+
+/// [`Stream`] wrapper that records the duration & allocations of every poll of the inner
+/// [`Stream`] with an optional [`ConnectionProfiler`].
+#[derive(Debug)]
+pub struct ProfiledStream<St> {
+    stream: St,
+    profiler: Option<ConnectionProfiler>,
+}
+
+impl<St> ProfiledStream<St> {
+    /// Construct a new [`Self`], profiling the inner [`Stream`] if a [`ConnectionProfiler`] is
+    /// provided.
+    pub fn new(stream: St, profiler: Option<ConnectionProfiler>) -> Self {
+        Self { stream, profiler }
+    }
+}
+
+impl<St> Stream for ProfiledStream<St>
+where
+    St: Stream + Unpin,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let Some(profiler) = &this.profiler else {
+            return Pin::new(&mut this.stream).poll_next(cx);
+        };
+
+        let (start_allocations, start_bytes) = thread_allocations();
+        let start = Instant::now();
+        let poll = Pin::new(&mut this.stream).poll_next(cx);
+        let elapsed = start.elapsed();
+        let (end_allocations, end_bytes) = thread_allocations();
+
+        profiler.record(
+            elapsed,
+            end_allocations.saturating_sub(start_allocations),
+            end_bytes.saturating_sub(start_bytes),
+        );
+
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+    use std::alloc::System;
+
+    #[test]
+    fn test_counting_allocator() {
+        let allocator = CountingAllocator::new(System);
+        let layout = Layout::from_size_align(64, 8).unwrap();
+
+        let (start_allocations, start_bytes) = thread_allocations();
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            let ptr = allocator.realloc(ptr, layout, 128);
+            allocator.dealloc(ptr, Layout::from_size_align(128, 8).unwrap());
+        }
+        let (end_allocations, end_bytes) = thread_allocations();
+
+        assert_eq!(end_allocations - start_allocations, 2);
+        assert_eq!(end_bytes - start_bytes, 64 + 128);
+    }
+
+    #[tokio::test]
+    async fn test_profiled_stream() {
+        let profiler = TaskProfiler::default();
+
+        let connection = profiler.register(ExchangeId::BinanceSpot);
+        let events = ProfiledStream::new(stream::iter([1, 2, 3]), Some(connection))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events, vec![1, 2, 3]);
+
+        // Every poll is recorded, including the poll that ended the Stream
+        let profiles = profiler.connections();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].exchange, ExchangeId::BinanceSpot);
+        assert_eq!(profiles[0].polls, 4);
+        assert!(profiles[0].max_poll <= profiles[0].busy);
+        assert!(!profiles[0].active);
+
+        // Unprofiled Streams are passed through, and re-connections are registered separately
+        let events = ProfiledStream::new(stream::iter([4]), None)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events, vec![4]);
+        let _reconnection = profiler.register(ExchangeId::BinanceSpot);
+        let profiles = profiler.connections();
+        assert_eq!(profiles[1].id, 1);
+        assert_eq!(profiles[1].polls, 0);
+        assert_eq!(profiles[1].mean_poll(), Duration::ZERO);
+        assert!(profiles[1].active);
+    }
+}
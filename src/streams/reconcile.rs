@@ -0,0 +1,233 @@
+use crate::{
+    event::{MarketEvent, Origin},
+    exchange::Connector,
+    subscription::trade::PublicTrade,
+};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use chrono::Utc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Default interval between [`RecentTrades`] REST reconciliation requests.
+pub const DEFAULT_RECONCILE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Handle used to request the immediate [`RecentTrades`] reconciliation of an [`Instrument`],
+/// rather than waiting for the next reconciliation interval (eg/ after an exchange
+/// [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) detects a trade id gap).
+///
+/// Configured via [`StreamConfig::with_reconcile_trigger`](super::config::StreamConfig), and
+/// fired by the consumer loop upon each [`DataError::TradeGap`](crate::error::DataError).
+#[derive(Clone, Debug)]
+pub struct ReconcileTrigger {
+    tx: mpsc::UnboundedSender<Instrument>,
+}
+
+impl ReconcileTrigger {
+    /// Construct a new [`Self`], and the [`mpsc::UnboundedReceiver`] of the triggered
+    /// [`Instrument`]s to provide to [`reconcile_trades_with`].
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<Instrument>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Self { tx }, rx)
+    }
+
+    /// Request the immediate reconciliation of the provided [`Instrument`].
+    pub fn trigger(&self, instrument: Instrument) {
+        // Error only indicates the reconciliation task has stopped
+        let _ = self.tx.send(instrument);
+    }
+}
+
+impl PartialEq for ReconcileTrigger {
+    fn eq(&self, other: &Self) -> bool {
+        self.tx.same_channel(&other.tx)
+    }
+}
+
+impl Eq for ReconcileTrigger {}
+
+/// Exchange [`Connector`] that serves it's most recent [`PublicTrade`]s via HTTP, used to fill
+/// gaps in a WebSocket trade stream (see [`reconcile_trades`]).
+#[async_trait]
+pub trait RecentTrades
+where
+    Self: Connector,
+{
+    /// Fetch the most recent [`PublicTrade`]s of the provided [`Instrument`].
+    ///
+    /// Trades must be identified by the same [`TradeId`](crate::subscription::trade::TradeId) as
+    /// the WebSocket trade stream, so duplicates can be discarded.
+    async fn recent_trades(
+        instrument: &Instrument,
+    ) -> Result<Vec<MarketEvent<PublicTrade>>, SocketError>;
+}
+
+/// Periodically fetch the [`RecentTrades`] of every [`Instrument`] every `interval`, sending
+/// each trade to the `trade_tx`.
+///
+/// Used alongside a WebSocket trade stream feeding the same
+/// [`deduplicate`](super::redundant::deduplicate) task, so any trades missed by the WebSocket
+/// (eg/ during a re-connection) are injected, and trades already received are discarded. Only
+/// trades executed after reconciliation started are sent, and injected trades arrive later than
+/// the surrounding WebSocket trades with an [`Origin::Backfill`] `origin`. The REST trade limit should be smaller than the
+/// deduplication window.
+///
+/// Runs until the `trade_tx` receiver has been dropped.
+pub async fn reconcile_trades<Exchange>(
+    instruments: Vec<Instrument>,
+    interval: Duration,
+    trade_tx: mpsc::UnboundedSender<MarketEvent<PublicTrade>>,
+) where
+    Exchange: RecentTrades,
+{
+    // Hold the ReconcileTrigger so the trigger receiver never yields None
+    let (_trigger, trigger_rx) = ReconcileTrigger::new();
+    reconcile_trades_with::<Exchange>(instruments, interval, trigger_rx, trade_tx).await
+}
+
+/// As per [`reconcile_trades`], but also reconciles each [`Instrument`] received from the
+/// `trigger_rx` of a [`ReconcileTrigger`] immediately.
+///
+/// Triggered [`Instrument`]s not in the provided `instruments` are ignored. Runs until the
+/// `trade_tx` receiver has been dropped, continuing on the interval alone if every
+/// [`ReconcileTrigger`] is dropped.
+pub async fn reconcile_trades_with<Exchange>(
+    instruments: Vec<Instrument>,
+    interval: Duration,
+    mut trigger_rx: mpsc::UnboundedReceiver<Instrument>,
+    trade_tx: mpsc::UnboundedSender<MarketEvent<PublicTrade>>,
+) where
+    Exchange: RecentTrades,
+{
+    let exchange = Exchange::ID;
+    let start = Utc::now();
+
+    // First reconciliation is after one interval, giving the WebSocket time to subscribe
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut triggers_open = true;
+
+    loop {
+        let reconcile = tokio::select! {
+            _ = interval.tick() => instruments.iter().collect::<Vec<_>>(),
+            triggered = trigger_rx.recv(), if triggers_open => match triggered {
+                Some(triggered) => {
+                    debug!(%exchange, instrument = %triggered, "triggered trade reconciliation");
+                    instruments
+                        .iter()
+                        .filter(|instrument| **instrument == triggered)
+                        .collect()
+                }
+                None => {
+                    triggers_open = false;
+                    continue;
+                }
+            },
+        };
+
+        for instrument in reconcile {
+            let trades = match Exchange::recent_trades(instrument).await {
+                Ok(trades) => trades,
+                Err(error) => {
+                    warn!(%exchange, %instrument, %error, "failed to fetch recent trades");
+                    continue;
+                }
+            };
+            debug!(%exchange, %instrument, trades = trades.len(), "reconciling recent trades");
+
+            for mut trade in trades
+                .into_iter()
+                .filter(|trade| trade.exchange_time >= start)
+            {
+                trade.origin = Origin::Backfill;
+                if trade_tx.send(trade).is_err() {
+                    debug!(%exchange, "reconciled trade receiver dropped - stopping reconciliation");
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::okx::Okx, fixture::trade_event, subscription::trade::TradeId};
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange};
+    use chrono::{DateTime, Duration as ChronoDuration};
+
+    fn trade(id: u64, exchange_time: DateTime<Utc>) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time,
+            exchange: Exchange::from(Okx::ID),
+            instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+            ..trade_event(id, 1.0, 1.0)
+        }
+    }
+
+    #[async_trait]
+    impl RecentTrades for Okx {
+        async fn recent_trades(
+            _: &Instrument,
+        ) -> Result<Vec<MarketEvent<PublicTrade>>, SocketError> {
+            Ok(vec![
+                trade(1, DateTime::from_timestamp_millis(0).unwrap()),
+                trade(2, Utc::now() + ChronoDuration::hours(1)),
+            ])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_trades() {
+        let (trade_tx, mut trade_rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(reconcile_trades::<Okx>(
+            vec![Instrument::from(("btc", "usd", InstrumentKind::Spot))],
+            Duration::from_millis(10),
+            trade_tx,
+        ));
+
+        // Trades executed before reconciliation started are not sent
+        let actual = trade_rx.recv().await.unwrap();
+        assert_eq!(actual.kind.id, TradeId::U64(2));
+
+        // Reconciliation stops once the receiver is dropped
+        drop(trade_rx);
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_trades_with_trigger() {
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let (trigger, trigger_rx) = ReconcileTrigger::new();
+        let (trade_tx, mut trade_rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(reconcile_trades_with::<Okx>(
+            vec![instrument.clone()],
+            Duration::from_secs(3600),
+            trigger_rx,
+            trade_tx,
+        ));
+
+        // Triggered Instrument is reconciled before the first interval elapses
+        trigger.trigger(instrument);
+        let actual = tokio::time::timeout(Duration::from_secs(1), trade_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(actual.kind.id, TradeId::U64(2));
+        assert_eq!(actual.origin, Origin::Backfill);
+
+        // Triggered Instrument that is not reconciled is ignored
+        trigger.trigger(Instrument::from(("eth", "usd", InstrumentKind::Spot)));
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), trade_rx.recv())
+                .await
+                .is_err()
+        );
+
+        handle.abort();
+    }
+}
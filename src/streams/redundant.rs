@@ -0,0 +1,348 @@
+use crate::{
+    event::MarketEvent,
+    subscription::trade::{PublicTrade, TradeId, UserTrade},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use futures::Stream;
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default number of most recent [`MarketEvent<T>`] keys remembered by a [`Deduplicator`].
+///
+/// This only needs to cover the maximum lag between the redundant connections, since a duplicate
+/// arriving after its key has been evicted is forwarded again.
+pub const DEFAULT_DEDUP_WINDOW: usize = 10_000;
+
+/// [`MarketEvent<T>`] kind that can be uniquely identified, allowing duplicates received over
+/// redundant exchange connections to be discarded.
+///
+/// Only implemented for kinds where the exchange provides a unique identifier for each event
+/// (eg/ a trade id), since events such as in-progress candles legitimately repeat a key.
+pub trait Deduplicate {
+    type Key: Clone + Eq + Hash + Debug + Send;
+
+    /// Key that uniquely identifies this event for a particular exchange [`Instrument`], or
+    /// `None` if this event cannot be identified (in which case it is always forwarded).
+    fn dedup_key(&self) -> Option<Self::Key>;
+}
+
+impl Deduplicate for PublicTrade {
+    type Key = TradeId;
+
+    fn dedup_key(&self) -> Option<Self::Key> {
+        match self.id {
+            TradeId::None => None,
+            _ => Some(self.id.clone()),
+        }
+    }
+}
+
+impl Deduplicate for UserTrade {
+    type Key = String;
+
+    fn dedup_key(&self) -> Option<Self::Key> {
+        Some(self.id.clone())
+    }
+}
+
+/// Key used to identify a [`MarketEvent<T>`] across redundant connections.
+pub type MarketEventKey<T> = (Exchange, Instrument, <T as Deduplicate>::Key);
+
+/// Bounded memory of recently seen keys, used to forward only the first arrival of each event.
+///
+/// Keys are evicted oldest first once the `window` capacity is reached.
+#[derive(Debug)]
+pub struct Deduplicator<Key> {
+    window: usize,
+    seen: HashSet<Key>,
+    order: VecDeque<Key>,
+}
+
+impl<Key> Deduplicator<Key>
+where
+    Key: Clone + Eq + Hash,
+{
+    /// Construct a new [`Self`] that remembers the most recent `window` keys.
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            seen: HashSet::with_capacity(window),
+            order: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// Returns true if the key has not been seen within the window, recording it as seen.
+    pub fn is_first(&mut self, key: Key) -> bool {
+        if self.window == 0 {
+            return true;
+        }
+
+        if self.seen.contains(&key) {
+            return false;
+        }
+
+        if self.order.len() == self.window {
+            if let Some(evicted) = self.order.pop_front() {
+                self.seen.remove(&evicted);
+            }
+        }
+
+        self.seen.insert(key.clone());
+        self.order.push_back(key);
+        true
+    }
+}
+
+/// [`Deduplicator`] of [`MarketEvent<T>`]s keyed by `(exchange, instrument, key)`, usable
+/// wherever the same event can be received more than once (eg/ combining backfill, re-connections
+/// & redundant connections).
+#[derive(Debug)]
+pub struct MarketEventDeduplicator<T>
+where
+    T: Deduplicate,
+{
+    deduplicator: Deduplicator<MarketEventKey<T>>,
+}
+
+impl<T> MarketEventDeduplicator<T>
+where
+    T: Deduplicate,
+{
+    /// Construct a new [`Self`] that remembers the keys of the most recent `window` events.
+    pub fn new(window: usize) -> Self {
+        Self {
+            deduplicator: Deduplicator::new(window),
+        }
+    }
+
+    /// Returns true if the [`MarketEvent<T>`] should be forwarded, ie/ it cannot be identified or
+    /// it's key has not been seen within the window.
+    pub fn is_first(&mut self, event: &MarketEvent<T>) -> bool {
+        match event.kind.dedup_key() {
+            Some(key) => {
+                self.deduplicator
+                    .is_first((event.exchange.clone(), event.instrument.clone(), key))
+            }
+            None => true,
+        }
+    }
+}
+
+/// [`Stream`] adaptor that yields the first arrival of each [`MarketEvent<T>`] yielded by the
+/// inner [`Stream`], discarding duplicates within the window of a [`MarketEventDeduplicator`].
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::binance::spot::BinanceSpot,
+///     streams::{redundant::{Deduplicated, DEFAULT_DEDUP_WINDOW}, Streams},
+///     subscription::trade::PublicTrades,
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+/// use futures::StreamExt;
+/// use tokio_stream::wrappers::UnboundedReceiverStream;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let streams = Streams::<PublicTrades>::builder()
+///         .subscribe([(BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades)])
+///         .init()
+///         .await
+///         .unwrap();
+///
+///     let joined = UnboundedReceiverStream::new(streams.join().await);
+///     let mut trades = Deduplicated::new(joined, DEFAULT_DEDUP_WINDOW);
+///
+///     while let Some(trade) = trades.next().await {
+///         println!("{trade:?}");
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Deduplicated<St, T>
+where
+    T: Deduplicate,
+{
+    stream: St,
+    deduplicator: MarketEventDeduplicator<T>,
+}
+
+impl<St, T> Deduplicated<St, T>
+where
+    T: Deduplicate,
+{
+    /// Construct a new [`Self`] that deduplicates the provided [`Stream`] over the most recent
+    /// `window` events.
+    pub fn new(stream: St, window: usize) -> Self {
+        Self {
+            stream,
+            deduplicator: MarketEventDeduplicator::new(window),
+        }
+    }
+}
+
+impl<St, T> Stream for Deduplicated<St, T>
+where
+    St: Stream<Item = MarketEvent<T>> + Unpin,
+    T: Deduplicate,
+    T::Key: Unpin,
+{
+    type Item = MarketEvent<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) if !self.deduplicator.is_first(&event) => continue,
+                poll => return poll,
+            }
+        }
+    }
+}
+
+/// Forward the first arrival of each [`MarketEvent<T>`] received via `redundant_rx` (eg/ from
+/// redundant connections, or a connection & it's backfill) to the `exchange_tx`, discarding any
+/// duplicates.
+///
+/// Runs until every input has dropped its sender, or the downstream receiver has been dropped.
+pub async fn deduplicate<T>(
+    mut redundant_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    exchange_tx: mpsc::UnboundedSender<MarketEvent<T>>,
+    window: usize,
+) where
+    T: Deduplicate,
+{
+    let mut deduplicator = MarketEventDeduplicator::<T>::new(window);
+
+    while let Some(event) = redundant_rx.recv().await {
+        if !deduplicator.is_first(&event) {
+            continue;
+        }
+
+        if exchange_tx.send(event).is_err() {
+            debug!("redundant MarketEvent receiver dropped - stopping deduplication");
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::trade_event;
+
+    #[test]
+    fn test_deduplicator_is_first() {
+        struct TestCase {
+            window: usize,
+            keys: Vec<u64>,
+            expected: Vec<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: duplicates within window are discarded
+                window: 10,
+                keys: vec![1, 2, 1, 3, 2],
+                expected: vec![true, true, false, true, false],
+            },
+            TestCase {
+                // TC1: duplicate of a key evicted from the window is considered first
+                window: 2,
+                keys: vec![1, 2, 3, 1, 3],
+                expected: vec![true, true, true, true, false],
+            },
+            TestCase {
+                // TC2: zero window disables deduplication
+                window: 0,
+                keys: vec![1, 1],
+                expected: vec![true, true],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut deduplicator = Deduplicator::new(test.window);
+            let actual = test
+                .keys
+                .into_iter()
+                .map(|key| deduplicator.is_first(key))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deduplicate() {
+        let (redundant_tx, redundant_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        // Trades arriving over two redundant connections, with the second lagging behind
+        let ids = [1, 2, 1, 3, 2, 3].map(TradeId::U64);
+        for id in ids {
+            redundant_tx.send(trade_event(id, 1.0, 1.0)).unwrap();
+        }
+
+        // Trades without an id cannot be deduplicated, so are always forwarded
+        redundant_tx
+            .send(trade_event(TradeId::None, 1.0, 1.0))
+            .unwrap();
+        redundant_tx
+            .send(trade_event(TradeId::None, 1.0, 1.0))
+            .unwrap();
+        drop(redundant_tx);
+
+        deduplicate(redundant_rx, exchange_tx, DEFAULT_DEDUP_WINDOW).await;
+
+        let mut actual = Vec::new();
+        while let Some(event) = exchange_rx.recv().await {
+            actual.push(event.kind.id);
+        }
+
+        assert_eq!(
+            actual,
+            vec![
+                TradeId::U64(1),
+                TradeId::U64(2),
+                TradeId::U64(3),
+                TradeId::None,
+                TradeId::None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deduplicated_stream() {
+        use futures::StreamExt;
+
+        // Same print received via backfill, a re-connection, and from another exchange
+        let mut okx_trade = trade_event(TradeId::U64(1), 1.0, 1.0);
+        okx_trade.exchange = Exchange::from("okx");
+        let events = vec![
+            trade_event(TradeId::U64(1), 1.0, 1.0),
+            trade_event(TradeId::U64(2), 1.0, 1.0),
+            trade_event(TradeId::U64(1), 1.0, 1.0),
+            okx_trade,
+            trade_event(TradeId::U64(2), 1.0, 1.0),
+        ];
+
+        let actual = Deduplicated::new(futures::stream::iter(events), DEFAULT_DEDUP_WINDOW)
+            .map(|event| (event.exchange, event.kind.id))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            actual,
+            vec![
+                (Exchange::from("binance_spot"), TradeId::U64(1)),
+                (Exchange::from("binance_spot"), TradeId::U64(2)),
+                (Exchange::from("okx"), TradeId::U64(1)),
+            ]
+        );
+    }
+}
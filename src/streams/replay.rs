@@ -0,0 +1,183 @@
+use super::{
+    shared::SharedStream,
+    tap::{RawMessage, RawPayload},
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, Origin},
+    exchange::StreamSelector,
+    normalise::AssetNormaliser,
+    subscriber::{mapper::SubscriptionMapper, Subscriber},
+    subscription::{SubKind, Subscription},
+    transformer::ExchangeTransformer,
+    Identifier, OutboundQueue,
+};
+use barter_integration::{
+    protocol::{
+        websocket::{WebSocketParser, WsMessage},
+        StreamParser,
+    },
+    Transformer,
+};
+
+/// Deterministically replay archived [`RawMessage`]s (eg/ read from an `ArchiveWriter` segment
+/// via the `archive` feature) through the same
+/// [`ExchangeTransformer`] used by the live [`StreamSelector::Stream`] of the provided
+/// [`Subscription`]s, returning every transformed output in order.
+///
+/// Intended as a regression test harness, so parser changes can be validated against captured
+/// production data. Only [`RawMessage`]s received from the `Exchange` are replayed, and the
+/// `received_time` of each [`MarketEvent<T>`] is that of the [`RawMessage`] it was transformed
+/// from, with an [`Origin::SnapshotReplay`] `origin`. Messages the live stream would have skipped (eg/ subscription responses) are returned
+/// as non-terminal [`DataError`]s.
+///
+/// Note that messages sent back to the exchange by the [`ExchangeTransformer`] (eg/ pongs) are
+/// discarded, and the [`SubscriptionId`](barter_integration::model::SubscriptionId)s of
+/// exchanges that assign them during subscription validation (eg/ Bitfinex channel ids) are
+/// not reproduced.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::{binance::spot::BinanceSpot, ExchangeId},
+///     streams::{
+///         replay::replay,
+///         tap::{RawMessage, RawPayload},
+///     },
+///     subscription::{trade::PublicTrades, Subscription},
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+/// use chrono::Utc;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let subscriptions = [Subscription::from((
+///         BinanceSpot::default(),
+///         "btc",
+///         "usdt",
+///         InstrumentKind::Spot,
+///         PublicTrades,
+///     ))];
+///
+///     // Captured production frames, one per line
+///     let messages = std::fs::read_to_string("./captured.jsonl")
+///         .unwrap()
+///         .lines()
+///         .map(|line| RawMessage {
+///             exchange: ExchangeId::BinanceSpot,
+///             received_time: Utc::now(),
+///             payload: RawPayload::Text(line.to_owned()),
+///         })
+///         .collect::<Vec<_>>();
+///
+///     let trades = replay(&subscriptions, messages).await.unwrap();
+/// }
+/// ```
+pub async fn replay<Exchange, Kind, Messages>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    messages: Messages,
+) -> Result<Vec<Result<MarketEvent<Kind::Event>, DataError>>, DataError>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Exchange::Stream: SharedStream<Exchange, Kind>,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Messages: IntoIterator<Item = RawMessage>,
+{
+    // Map Subscriptions to the SubscriptionId - Instrument Map a live stream would use
+    let instrument_map = <Exchange::Subscriber as Subscriber>::SubMapper::map::<Exchange, Kind>(
+        subscriptions,
+        &AssetNormaliser::default(),
+    )
+    .instrument_map;
+
+    // Construct the Transformer used by the live stream, discarding any messages it sends
+    let (outbound_tx, _) = OutboundQueue::new();
+    let mut transformer = <Exchange::Stream as SharedStream<Exchange, Kind>>::Transformer::new(
+        outbound_tx,
+        instrument_map,
+    )
+    .await?;
+
+    let mut outputs = Vec::new();
+
+    for message in messages
+        .into_iter()
+        .filter(|message| message.exchange == Exchange::ID)
+    {
+        let ws_message = match message.payload {
+            RawPayload::Text(text) => WsMessage::Text(text),
+            RawPayload::Binary(bytes) => WsMessage::Binary(bytes),
+        };
+
+        let input = match WebSocketParser::parse(Ok(ws_message)) {
+            Some(Ok(input)) => input,
+            Some(Err(error)) => {
+                outputs.push(Err(DataError::from(error)));
+                continue;
+            }
+            None => continue,
+        };
+
+        outputs.extend(transformer.transform(input).into_iter().map(|output| {
+            output.map(|mut event| {
+                event.received_time = message.received_time;
+                event.origin = Origin::SnapshotReplay;
+                event
+            })
+        }));
+    }
+
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, ExchangeId},
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::DateTime;
+
+    #[tokio::test]
+    async fn test_replay() {
+        let subscriptions = [Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+
+        let message = |exchange, nanos, text: &str| RawMessage {
+            exchange,
+            received_time: DateTime::from_timestamp_nanos(nanos),
+            payload: RawPayload::Text(text.to_owned()),
+        };
+
+        let trade = r#"{
+            "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,"p":"10000.19",
+            "q":"0.239000","b":10108767791,"a":10108764858,"T":1649324825173,"m":false,"M":true
+        }"#;
+
+        let messages = vec![
+            // Subscription response is skipped by the live stream
+            message(ExchangeId::BinanceSpot, 1, r#"{"result":null,"id":1}"#),
+            message(ExchangeId::BinanceSpot, 2, trade),
+            // Message received from another exchange is not replayed
+            message(ExchangeId::Kraken, 3, trade),
+        ];
+
+        let actual = replay(&subscriptions, messages).await.unwrap();
+        assert_eq!(actual.len(), 2);
+        assert!(matches!(&actual[0], Err(error) if !error.is_terminal()));
+
+        let event = actual[1].as_ref().unwrap();
+        assert_eq!(event.received_time, DateTime::from_timestamp_nanos(2));
+        assert_eq!(event.origin, Origin::SnapshotReplay);
+        assert_eq!(event.kind.price, 10000.19);
+        assert_eq!(event.kind.amount, 0.239);
+    }
+}
@@ -0,0 +1,467 @@
+use crate::event::{MarketEvent, MonotonicTime, Origin};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Aggregation applied to a resampled field over each interval.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    First,
+    Last,
+    Ohlc,
+    Sum,
+}
+
+/// Aggregated value of a resampled field over an interval.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregate {
+    First(f64),
+    Last(f64),
+    Ohlc {
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+    },
+    Sum(f64),
+}
+
+impl Aggregate {
+    /// Construct a new [`Self`] of the provided [`Aggregation`] from the first value of an
+    /// interval.
+    pub fn new(aggregation: Aggregation, value: f64) -> Self {
+        match aggregation {
+            Aggregation::First => Self::First(value),
+            Aggregation::Last => Self::Last(value),
+            Aggregation::Ohlc => Self::Ohlc {
+                open: value,
+                high: value,
+                low: value,
+                close: value,
+            },
+            Aggregation::Sum => Self::Sum(value),
+        }
+    }
+
+    /// Update [`Self`] with a subsequent value of the same interval.
+    pub fn update(&mut self, value: f64) {
+        match self {
+            Self::First(_) => {}
+            Self::Last(last) => *last = value,
+            Self::Ohlc {
+                high, low, close, ..
+            } => {
+                *high = high.max(value);
+                *low = low.min(value);
+                *close = value;
+            }
+            Self::Sum(sum) => *sum += value,
+        }
+    }
+
+    /// [`Self`] of an interval without any values, carried forward from the previous interval.
+    fn carry(&self) -> Self {
+        match *self {
+            Self::Ohlc { close, .. } => Self::new(Aggregation::Ohlc, close),
+            Self::Sum(_) => Self::Sum(0.0),
+            carried => carried,
+        }
+    }
+}
+
+/// Field of a [`MarketEvent<T>`] kind resampled by a [`Resampler`].
+#[derive(Copy, Clone, Debug)]
+pub struct ResampleField<T> {
+    pub name: &'static str,
+    /// Extract the field value of an event, if present.
+    pub extract: fn(&T) -> Option<f64>,
+    pub aggregation: Aggregation,
+}
+
+/// Fixed interval resampling of a [`MarketEvent<T>`] stream, containing the last event & the
+/// [`Aggregate`] of each [`ResampleField`] over the interval.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct Resampled<T> {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Number of events received during the interval (zero for gap filled intervals).
+    pub count: usize,
+    /// Last event received during (or before, if gap filled) the interval.
+    pub last: T,
+    pub fields: BTreeMap<&'static str, Aggregate>,
+}
+
+/// In progress interval of an exchange [`Instrument`].
+#[derive(Clone, Debug)]
+struct Bucket<T> {
+    resampled: Resampled<T>,
+    received: (DateTime<Utc>, MonotonicTime, Origin),
+}
+
+/// Resampling operator that converts a noisy [`MarketEvent<T>`] stream into a fixed frequency
+/// stream of [`Resampled<T>`] events per exchange [`Instrument`] (eg/ 1 second
+/// [`OrderBook`](crate::subscription::book::OrderBook) snapshots).
+///
+/// Intervals are aligned to multiples of the interval since the Unix epoch using the
+/// `exchange_time` of each event, and are emitted once an event of a later interval is received.
+/// Late events are aggregated into the current interval. Enabling gap filling emits intervals
+/// without events, carrying forward the last event & values.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::{
+///     streams::resample::{Aggregation, Resampler},
+///     subscription::trade::PublicTrade,
+/// };
+/// use chrono::Duration;
+///
+/// let resampler = Resampler::<PublicTrade>::new(Duration::seconds(1))
+///     .with_field("price", |trade: &PublicTrade| Some(trade.price), Aggregation::Ohlc)
+///     .with_field("volume", |trade: &PublicTrade| Some(trade.amount), Aggregation::Sum)
+///     .with_gap_fill();
+/// ```
+#[derive(Debug)]
+pub struct Resampler<T> {
+    interval: Duration,
+    fill_gaps: bool,
+    fields: Vec<ResampleField<T>>,
+    buckets: HashMap<(Exchange, Instrument), Bucket<T>>,
+}
+
+impl<T> Resampler<T>
+where
+    T: Clone,
+{
+    /// Construct a new [`Self`] that resamples to the provided interval (minimum 1ms).
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::milliseconds(1)),
+            fill_gaps: false,
+            fields: Vec::new(),
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Resample a field of each event using the provided [`Aggregation`].
+    pub fn with_field(
+        mut self,
+        name: &'static str,
+        extract: fn(&T) -> Option<f64>,
+        aggregation: Aggregation,
+    ) -> Self {
+        self.fields.push(ResampleField {
+            name,
+            extract,
+            aggregation,
+        });
+        self
+    }
+
+    /// Emit intervals without any events, carrying forward the previous interval's last event &
+    /// values.
+    pub fn with_gap_fill(self) -> Self {
+        Self {
+            fill_gaps: true,
+            ..self
+        }
+    }
+
+    /// Process a [`MarketEvent<T>`], returning the [`Resampled<T>`] intervals it completes.
+    pub fn process(&mut self, event: MarketEvent<T>) -> Vec<MarketEvent<Resampled<T>>> {
+        let start = self.interval_start(event.exchange_time);
+        let key = (event.exchange.clone(), event.instrument.clone());
+
+        let Some(bucket) = self.buckets.get_mut(&key) else {
+            let bucket = open(&self.fields, start, self.interval, &event);
+            self.buckets.insert(key, bucket);
+            return Vec::new();
+        };
+
+        // Current or late event: aggregate into the current interval
+        if start <= bucket.resampled.start {
+            aggregate(&self.fields, bucket, &event);
+            return Vec::new();
+        }
+
+        let completed = std::mem::replace(bucket, open(&self.fields, start, self.interval, &event));
+        let mut resampled = vec![completed.clone()];
+
+        if self.fill_gaps {
+            let mut gap_start = completed.resampled.end;
+            let mut previous = completed;
+            while gap_start < start {
+                let gap = carry(&previous, gap_start, self.interval);
+                gap_start = gap.resampled.end;
+                resampled.push(gap.clone());
+                previous = gap;
+            }
+        }
+
+        resampled
+            .into_iter()
+            .map(|bucket| into_event(&key, bucket))
+            .collect()
+    }
+
+    /// Flush the in progress interval of every exchange [`Instrument`].
+    pub fn flush(&mut self) -> Vec<MarketEvent<Resampled<T>>> {
+        self.buckets
+            .drain()
+            .map(|(key, bucket)| into_event(&key, bucket))
+            .collect()
+    }
+
+    fn interval_start(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let interval = self.interval.num_milliseconds();
+        let millis = time.timestamp_millis();
+        Utc.timestamp_millis_opt(millis - millis.rem_euclid(interval))
+            .single()
+            .unwrap_or(time)
+    }
+}
+
+/// Open a new [`Bucket`] starting at `start` with the first [`MarketEvent<T>`] of the interval.
+fn open<T>(
+    fields: &[ResampleField<T>],
+    start: DateTime<Utc>,
+    interval: Duration,
+    event: &MarketEvent<T>,
+) -> Bucket<T>
+where
+    T: Clone,
+{
+    Bucket {
+        resampled: Resampled {
+            start,
+            end: start + interval,
+            count: 1,
+            last: event.kind.clone(),
+            fields: fields
+                .iter()
+                .filter_map(|field| {
+                    let value = (field.extract)(&event.kind)?;
+                    Some((field.name, Aggregate::new(field.aggregation, value)))
+                })
+                .collect(),
+        },
+        received: (event.received_time, event.received_monotonic, event.origin),
+    }
+}
+
+/// Aggregate a [`MarketEvent<T>`] into the in progress [`Bucket`].
+fn aggregate<T>(fields: &[ResampleField<T>], bucket: &mut Bucket<T>, event: &MarketEvent<T>)
+where
+    T: Clone,
+{
+    let resampled = &mut bucket.resampled;
+    resampled.count += 1;
+    resampled.last = event.kind.clone();
+
+    for field in fields {
+        let Some(value) = (field.extract)(&event.kind) else {
+            continue;
+        };
+
+        resampled
+            .fields
+            .entry(field.name)
+            .and_modify(|aggregate| aggregate.update(value))
+            .or_insert_with(|| Aggregate::new(field.aggregation, value));
+    }
+
+    bucket.received = (event.received_time, event.received_monotonic, event.origin);
+}
+
+/// Gap filled [`Bucket`] starting at `start`, carried forward from the `previous` [`Bucket`].
+fn carry<T>(previous: &Bucket<T>, start: DateTime<Utc>, interval: Duration) -> Bucket<T>
+where
+    T: Clone,
+{
+    Bucket {
+        resampled: Resampled {
+            start,
+            end: start + interval,
+            count: 0,
+            last: previous.resampled.last.clone(),
+            fields: previous
+                .resampled
+                .fields
+                .iter()
+                .map(|(name, aggregate)| (*name, aggregate.carry()))
+                .collect(),
+        },
+        received: previous.received,
+    }
+}
+
+fn into_event<T>(
+    (exchange, instrument): &(Exchange, Instrument),
+    bucket: Bucket<T>,
+) -> MarketEvent<Resampled<T>> {
+    MarketEvent {
+        exchange_time: bucket.resampled.end,
+        received_time: bucket.received.0,
+        received_monotonic: bucket.received.1,
+        origin: bucket.received.2,
+        exchange: exchange.clone(),
+        instrument: instrument.clone(),
+        kind: bucket.resampled,
+    }
+}
+
+/// Resample every [`MarketEvent<T>`] received on the `event_rx` using a [`Resampler`],
+/// forwarding each completed [`Resampled<T>`] interval to the `resampled_tx`.
+///
+/// In progress intervals are flushed once the `event_rx` is exhausted.
+pub async fn resample<T>(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    resampled_tx: mpsc::UnboundedSender<MarketEvent<Resampled<T>>>,
+    mut resampler: Resampler<T>,
+) where
+    T: Clone,
+{
+    while let Some(event) = event_rx.recv().await {
+        for resampled in resampler.process(event) {
+            if resampled_tx.send(resampled).is_err() {
+                debug!("Resampled MarketEvent receiver dropped - stopping resampler");
+                return;
+            }
+        }
+    }
+
+    for resampled in resampler.flush() {
+        let _ = resampled_tx.send(resampled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixture::trade_event,
+        subscription::trade::{PublicTrade, TradeId},
+    };
+
+    fn time(millis: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(1_672_531_200_000 + millis)
+            .unwrap()
+    }
+
+    fn trade(millis: i64, price: f64, amount: f64) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: time(millis),
+            received_time: time(millis),
+            ..trade_event(TradeId::None, price, amount)
+        }
+    }
+
+    fn resampler() -> Resampler<PublicTrade> {
+        Resampler::new(Duration::seconds(1))
+            .with_field(
+                "open",
+                |trade: &PublicTrade| Some(trade.price),
+                Aggregation::First,
+            )
+            .with_field(
+                "price",
+                |trade: &PublicTrade| Some(trade.price),
+                Aggregation::Ohlc,
+            )
+            .with_field(
+                "volume",
+                |trade: &PublicTrade| Some(trade.amount),
+                Aggregation::Sum,
+            )
+    }
+
+    fn ohlc(open: f64, high: f64, low: f64, close: f64) -> Aggregate {
+        Aggregate::Ohlc {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    #[test]
+    fn test_resampler() {
+        let mut resampler = resampler();
+
+        assert!(resampler.process(trade(100, 10.0, 1.0)).is_empty());
+        assert!(resampler.process(trade(500, 12.0, 2.0)).is_empty());
+        assert!(resampler.process(trade(900, 9.0, 1.0)).is_empty());
+
+        // Late event within the current interval is aggregated
+        assert!(resampler.process(trade(50, 11.0, 1.0)).is_empty());
+
+        let actual = resampler.process(trade(1200, 13.0, 1.0));
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].exchange_time, time(1000));
+        assert_eq!(
+            actual[0].kind,
+            Resampled {
+                start: time(0),
+                end: time(1000),
+                count: 4,
+                last: trade(50, 11.0, 1.0).kind,
+                fields: BTreeMap::from([
+                    ("open", Aggregate::First(10.0)),
+                    ("price", ohlc(10.0, 12.0, 9.0, 11.0)),
+                    ("volume", Aggregate::Sum(5.0)),
+                ]),
+            }
+        );
+
+        let flushed = resampler.flush();
+        assert_eq!(flushed.len(), 1);
+        assert_eq!(flushed[0].kind.count, 1);
+        assert_eq!(flushed[0].kind.start, time(1000));
+    }
+
+    #[test]
+    fn test_resampler_gap_fill() {
+        let mut resampler = resampler().with_gap_fill();
+
+        assert!(resampler.process(trade(100, 10.0, 1.0)).is_empty());
+
+        let actual = resampler
+            .process(trade(3100, 11.0, 1.0))
+            .into_iter()
+            .map(|event| event.kind)
+            .collect::<Vec<_>>();
+
+        let gap = |start| Resampled {
+            start: time(start),
+            end: time(start + 1000),
+            count: 0,
+            last: trade(100, 10.0, 1.0).kind,
+            fields: BTreeMap::from([
+                ("open", Aggregate::First(10.0)),
+                ("price", ohlc(10.0, 10.0, 10.0, 10.0)),
+                ("volume", Aggregate::Sum(0.0)),
+            ]),
+        };
+
+        assert_eq!(
+            actual,
+            vec![
+                Resampled {
+                    count: 1,
+                    fields: BTreeMap::from([
+                        ("open", Aggregate::First(10.0)),
+                        ("price", ohlc(10.0, 10.0, 10.0, 10.0)),
+                        ("volume", Aggregate::Sum(1.0)),
+                    ]),
+                    ..gap(0)
+                },
+                gap(1000),
+                gap(2000),
+            ]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::event::MarketEvent;
+use barter_integration::model::{instrument::Instrument, Exchange};
+use std::{collections::HashMap, num::NonZeroU32, time::Duration};
+use tokio::{sync::mpsc, time::Instant};
+use tracing::debug;
+
+/// Sampling configuration of a low priority feed (eg/ for dashboards & loggers), forwarding at
+/// most one [`MarketEvent<T>`] per `interval` for each exchange [`Instrument`].
+///
+/// The first event after a quiet `interval` is forwarded immediately, and any events received
+/// within the `interval` are conflated so only the latest is forwarded once it elapses.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{config::StreamConfig, sample::SampleConfig};
+/// use std::num::NonZeroU32;
+///
+/// // Forward at most 5 events/sec per Instrument, keeping the latest
+/// let config = StreamConfig::default()
+///     .with_sampling(SampleConfig::per_second(NonZeroU32::new(5).unwrap()));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SampleConfig {
+    pub interval: Duration,
+}
+
+impl SampleConfig {
+    /// Construct a [`SampleConfig`] that forwards at most one event per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self { interval }
+    }
+
+    /// Construct a [`SampleConfig`] that forwards at most `events` per second.
+    pub fn per_second(events: NonZeroU32) -> Self {
+        Self::new(Duration::from_secs(1) / events.get())
+    }
+}
+
+/// Sampling state of an exchange [`Instrument`].
+#[derive(Debug)]
+struct SampleState<T> {
+    last_sent: Instant,
+    pending: Option<MarketEvent<T>>,
+}
+
+/// Forward a sample of the [`MarketEvent<T>`]s received on the `event_rx` to the `event_tx`,
+/// rate limited per exchange [`Instrument`] by the provided [`SampleConfig`].
+///
+/// Sampling never applies backpressure to the `event_rx`, so a slow feed cannot lag. Any
+/// pending events are flushed once the `event_rx` is closed, and the stage runs until the
+/// `event_tx` receiver has been dropped.
+pub async fn sample<T>(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    event_tx: mpsc::UnboundedSender<MarketEvent<T>>,
+    config: SampleConfig,
+) {
+    let mut states = HashMap::<(Exchange, Instrument), SampleState<T>>::new();
+
+    loop {
+        // Determine when the next pending event is due to be forwarded
+        let next_due = states
+            .values()
+            .filter(|state| state.pending.is_some())
+            .map(|state| state.last_sent + config.interval)
+            .min();
+
+        let event = tokio::select! {
+            event = event_rx.recv() => event,
+            _ = sleep_until(next_due) => {
+                if !flush_due(&mut states, &event_tx, config.interval, false) {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let Some(event) = event else {
+            flush_due(&mut states, &event_tx, config.interval, true);
+            break;
+        };
+
+        let now = Instant::now();
+        let key = (event.exchange.clone(), event.instrument.clone());
+
+        match states.get_mut(&key) {
+            Some(state) if now < state.last_sent + config.interval => {
+                // Conflate events received within the interval, keeping the latest
+                state.pending = Some(event);
+            }
+            _ => {
+                if event_tx.send(event).is_err() {
+                    debug!("MarketEvent receiver dropped - stopping MarketEvent sampling");
+                    break;
+                }
+                states.insert(
+                    key,
+                    SampleState {
+                        last_sent: now,
+                        pending: None,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Sleep until the provided [`Instant`], or forever if there is none.
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Forward every pending event that is due (or every pending event if `force`), returning false
+/// if the `event_tx` receiver has been dropped.
+fn flush_due<T>(
+    states: &mut HashMap<(Exchange, Instrument), SampleState<T>>,
+    event_tx: &mpsc::UnboundedSender<MarketEvent<T>>,
+    interval: Duration,
+    force: bool,
+) -> bool {
+    let now = Instant::now();
+
+    for state in states.values_mut() {
+        if !force && now < state.last_sent + interval {
+            continue;
+        }
+
+        if let Some(event) = state.pending.take() {
+            if event_tx.send(event).is_err() {
+                debug!("MarketEvent receiver dropped - stopping MarketEvent sampling");
+                return false;
+            }
+            state.last_sent = now;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::market_event;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn event(base: &str, value: u32) -> MarketEvent<u32> {
+        MarketEvent {
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            ..market_event(value)
+        }
+    }
+
+    #[test]
+    fn test_sample_config_per_second() {
+        assert_eq!(
+            SampleConfig::per_second(NonZeroU32::new(4).unwrap()),
+            SampleConfig::new(Duration::from_millis(250))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sample() {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (sampled_tx, mut sampled_rx) = mpsc::unbounded_channel();
+        tokio::spawn(sample(
+            event_rx,
+            sampled_tx,
+            SampleConfig::new(Duration::from_millis(50)),
+        ));
+
+        // First event of each Instrument is forwarded immediately, later events are conflated
+        for value in 0..5 {
+            event_tx.send(event("btc", value)).unwrap();
+        }
+        event_tx.send(event("eth", 100)).unwrap();
+
+        assert_eq!(sampled_rx.recv().await.unwrap().kind, 0);
+        assert_eq!(sampled_rx.recv().await.unwrap().kind, 100);
+
+        // Latest conflated event is forwarded once the interval elapses
+        assert_eq!(sampled_rx.recv().await.unwrap().kind, 4);
+
+        // Pending events are flushed once the input is closed
+        event_tx.send(event("btc", 5)).unwrap();
+        event_tx.send(event("btc", 6)).unwrap();
+        drop(event_tx);
+
+        let mut remaining = Vec::new();
+        while let Some(event) = sampled_rx.recv().await {
+            remaining.push(event.kind);
+        }
+        assert_eq!(remaining, vec![6]);
+    }
+}
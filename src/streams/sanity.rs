@@ -0,0 +1,632 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    exchange::ExchangeId,
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        candle::Candle,
+        liquidation::Liquidation,
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Default maximum fractional deviation of a price from it's rolling median (ie/ 10%).
+pub const DEFAULT_MAX_PRICE_DEVIATION: f64 = 0.1;
+
+/// Default number of most recent prices per exchange [`Instrument`] used for the rolling median.
+pub const DEFAULT_MEDIAN_WINDOW: usize = 100;
+
+/// Default number of prices required before price deviations are checked.
+pub const DEFAULT_MIN_SAMPLES: usize = 10;
+
+/// Configuration of a [`SanityChecker`] stage.
+///
+/// Timestamp bounds compare the `exchange_time` of each [`MarketEvent<T>`] with it's
+/// `received_time`, so archived data replayed later is checked the same way as live data.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SanityConfig {
+    /// Maximum fractional deviation of a price from the rolling median (eg/ 0.1 for 10%).
+    pub max_price_deviation: f64,
+    /// Number of most recent prices per exchange [`Instrument`] used for the rolling median.
+    pub median_window: usize,
+    /// Number of prices required before price deviations are checked.
+    pub min_samples: usize,
+    /// Maximum duration an `exchange_time` may be ahead of the `received_time`.
+    pub max_future: Duration,
+    /// Maximum duration an `exchange_time` may be behind the `received_time`.
+    pub max_past: Duration,
+}
+
+impl Default for SanityConfig {
+    fn default() -> Self {
+        Self {
+            max_price_deviation: DEFAULT_MAX_PRICE_DEVIATION,
+            median_window: DEFAULT_MEDIAN_WINDOW,
+            min_samples: DEFAULT_MIN_SAMPLES,
+            max_future: Duration::seconds(10),
+            max_past: Duration::hours(1),
+        }
+    }
+}
+
+/// [`MarketEvent<T>`] kind that can be sanity checked by a [`SanityChecker`].
+pub trait SanityFields {
+    /// Price compared against the rolling median of recent prices, if any.
+    fn price(&self) -> Option<f64>;
+
+    /// Amounts that must be positive (eg/ a trade amount).
+    fn amounts(&self) -> Vec<f64>;
+}
+
+impl SanityFields for PublicTrade {
+    fn price(&self) -> Option<f64> {
+        Some(self.price)
+    }
+
+    fn amounts(&self) -> Vec<f64> {
+        vec![self.amount]
+    }
+}
+
+impl SanityFields for OrderBookL1 {
+    fn price(&self) -> Option<f64> {
+        Some(self.mid_price())
+    }
+
+    fn amounts(&self) -> Vec<f64> {
+        vec![self.best_bid.amount, self.best_ask.amount]
+    }
+}
+
+impl SanityFields for OrderBook {
+    fn price(&self) -> Option<f64> {
+        // Delta Levels do not determine the mid price of the book
+        if self.delta {
+            return None;
+        }
+        self.mid_price()
+    }
+
+    fn amounts(&self) -> Vec<f64> {
+        Vec::new()
+    }
+}
+
+impl SanityFields for Candle {
+    fn price(&self) -> Option<f64> {
+        Some(self.close)
+    }
+
+    fn amounts(&self) -> Vec<f64> {
+        Vec::new()
+    }
+}
+
+impl SanityFields for Liquidation {
+    fn price(&self) -> Option<f64> {
+        Some(self.price)
+    }
+
+    fn amounts(&self) -> Vec<f64> {
+        vec![self.quantity]
+    }
+}
+
+impl SanityFields for DataKind {
+    fn price(&self) -> Option<f64> {
+        match self {
+            DataKind::Trade(trade) => trade.price(),
+            DataKind::OrderBookL1(book) => book.price(),
+            DataKind::OrderBook(book) => book.price(),
+            DataKind::Candle(candle) => candle.price(),
+            DataKind::Liquidation(liquidation) => liquidation.price(),
+            DataKind::Ticker(ticker) => Some(ticker.last),
+            DataKind::MiniTicker(ticker) => Some(ticker.last),
+            DataKind::Greeks(_) => None,
+        }
+    }
+
+    fn amounts(&self) -> Vec<f64> {
+        match self {
+            DataKind::Trade(trade) => trade.amounts(),
+            DataKind::OrderBookL1(book) => book.amounts(),
+            DataKind::Liquidation(liquidation) => liquidation.amounts(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Reason a [`MarketEvent<T>`] was flagged by a [`SanityChecker`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum AnomalyKind {
+    /// Price deviates from the rolling median by more than the configured maximum.
+    PriceDeviation { price: f64, median: f64 },
+    /// Price is zero, negative or not finite.
+    InvalidPrice { price: f64 },
+    /// Amount is zero, negative or not finite.
+    InvalidAmount { amount: f64 },
+    /// Exchange timestamp is further ahead of the received time than the configured maximum.
+    FutureTimestamp { exchange_time: DateTime<Utc> },
+    /// Exchange timestamp is further behind the received time than the configured maximum.
+    StaleTimestamp { exchange_time: DateTime<Utc> },
+}
+
+/// [`MarketEvent<T>`] flagged by a [`SanityChecker`], along with the [`AnomalyKind`] reason.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DataAnomaly<T> {
+    pub kind: AnomalyKind,
+    pub event: MarketEvent<T>,
+}
+
+/// Output of the [`sanity_check`] stage.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Checked<T> {
+    Event(MarketEvent<T>),
+    /// Anomaly forwarded by an [`AnomalyPolicy::Forward`] or [`AnomalyPolicy::Halt`] subscription.
+    Anomaly(DataAnomaly<T>),
+    /// Anomaly that exceeded the [`AnomalyPolicy::Halt`] threshold of it's subscription, after
+    /// which no further events of the subscription are forwarded.
+    Halted(DataAnomaly<T>),
+}
+
+/// Policy applied to the anomalies of a subscription flagged by a [`SanityChecker`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum AnomalyPolicy {
+    /// Forward anomalies tagged as a [`Checked::Anomaly`].
+    #[default]
+    Forward,
+    /// Discard anomalies, forwarding only valid events.
+    Drop,
+    /// Forward anomalies tagged as a [`Checked::Anomaly`] until more than `max_anomalies` have
+    /// been flagged, then send a [`Checked::Halted`] and discard every subsequent event of the
+    /// subscription.
+    Halt { max_anomalies: u64 },
+}
+
+/// [`AnomalyPolicy`] of each subscription (ie/ exchange [`Instrument`]), falling back to a default
+/// [`AnomalyPolicy`] for subscriptions without one.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct AnomalyPolicies {
+    pub default: AnomalyPolicy,
+    pub subscriptions: HashMap<(Exchange, Instrument), AnomalyPolicy>,
+}
+
+impl AnomalyPolicies {
+    /// Construct a new [`Self`] applying the provided default [`AnomalyPolicy`].
+    pub fn new(default: AnomalyPolicy) -> Self {
+        Self {
+            default,
+            subscriptions: HashMap::new(),
+        }
+    }
+
+    /// Apply the provided [`AnomalyPolicy`] to the exchange [`Instrument`] subscription.
+    pub fn with_policy<I>(
+        mut self,
+        exchange: ExchangeId,
+        instrument: I,
+        policy: AnomalyPolicy,
+    ) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        self.subscriptions
+            .insert((Exchange::from(exchange), instrument.into()), policy);
+        self
+    }
+
+    /// Determine the [`AnomalyPolicy`] of the exchange [`Instrument`] subscription.
+    pub fn policy(&self, exchange: &Exchange, instrument: &Instrument) -> AnomalyPolicy {
+        self.subscriptions
+            .get(&(exchange.clone(), instrument.clone()))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// Stage that flags obviously bad [`MarketEvent<T>`]s sent by exchanges, such as prices that
+/// deviate from the rolling median of recent prices, zero or negative amounts, and timestamps
+/// far in the future or past.
+///
+/// Every valid price is included in the rolling median of it's exchange [`Instrument`], including
+/// those flagged as deviating, so a genuine sustained price move stops being flagged once it
+/// dominates the window, whereas isolated bad prices are always flagged.
+#[derive(Debug, Default)]
+pub struct SanityChecker {
+    config: SanityConfig,
+    prices: HashMap<(Exchange, Instrument), VecDeque<f64>>,
+}
+
+impl SanityChecker {
+    /// Construct a new [`Self`] using the provided [`SanityConfig`].
+    pub fn new(config: SanityConfig) -> Self {
+        Self {
+            config,
+            prices: HashMap::new(),
+        }
+    }
+
+    /// Check a [`MarketEvent<T>`], returning the first [`AnomalyKind`] detected, if any.
+    pub fn check<T>(&mut self, event: &MarketEvent<T>) -> Option<AnomalyKind>
+    where
+        T: SanityFields,
+    {
+        let time_anomaly = self.check_time(event.exchange_time, event.received_time);
+
+        let amount_anomaly = event
+            .kind
+            .amounts()
+            .into_iter()
+            .find(|amount| !amount.is_finite() || *amount <= 0.0)
+            .map(|amount| AnomalyKind::InvalidAmount { amount });
+
+        let price_anomaly = event
+            .kind
+            .price()
+            .and_then(|price| self.check_price(&event.exchange, &event.instrument, price));
+
+        time_anomaly.or(amount_anomaly).or(price_anomaly)
+    }
+
+    fn check_time(
+        &self,
+        exchange_time: DateTime<Utc>,
+        received_time: DateTime<Utc>,
+    ) -> Option<AnomalyKind> {
+        if exchange_time - received_time > self.config.max_future {
+            Some(AnomalyKind::FutureTimestamp { exchange_time })
+        } else if received_time - exchange_time > self.config.max_past {
+            Some(AnomalyKind::StaleTimestamp { exchange_time })
+        } else {
+            None
+        }
+    }
+
+    fn check_price(
+        &mut self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        price: f64,
+    ) -> Option<AnomalyKind> {
+        if !price.is_finite() || price <= 0.0 {
+            return Some(AnomalyKind::InvalidPrice { price });
+        }
+
+        let prices = self
+            .prices
+            .entry((exchange.clone(), instrument.clone()))
+            .or_default();
+
+        let anomaly = (prices.len() >= self.config.min_samples)
+            .then(|| median(prices))
+            .filter(|median| ((price - median) / median).abs() > self.config.max_price_deviation)
+            .map(|median| AnomalyKind::PriceDeviation { price, median });
+
+        if prices.len() >= self.config.median_window {
+            prices.pop_front();
+        }
+        prices.push_back(price);
+
+        anomaly
+    }
+}
+
+/// Applies the [`AnomalyPolicies`] of each subscription to the anomalies flagged by a
+/// [`SanityChecker`].
+#[derive(Debug, Default)]
+pub struct AnomalyFilter {
+    checker: SanityChecker,
+    policies: AnomalyPolicies,
+    anomalies: HashMap<(Exchange, Instrument), u64>,
+    halted: HashSet<(Exchange, Instrument)>,
+}
+
+impl AnomalyFilter {
+    /// Construct a new [`Self`] using the provided [`SanityConfig`] & [`AnomalyPolicies`].
+    pub fn new(config: SanityConfig, policies: AnomalyPolicies) -> Self {
+        Self {
+            checker: SanityChecker::new(config),
+            policies,
+            anomalies: HashMap::new(),
+            halted: HashSet::new(),
+        }
+    }
+
+    /// Check a [`MarketEvent<T>`], returning the [`Checked<T>`] output to forward, or `None` if
+    /// the event is discarded by the [`AnomalyPolicy`] of it's subscription.
+    pub fn filter<T>(&mut self, event: MarketEvent<T>) -> Option<Checked<T>>
+    where
+        T: SanityFields,
+    {
+        let key = (event.exchange.clone(), event.instrument.clone());
+        if self.halted.contains(&key) {
+            return None;
+        }
+
+        let Some(kind) = self.checker.check(&event) else {
+            return Some(Checked::Event(event));
+        };
+        warn!(
+            exchange = %event.exchange,
+            instrument = %event.instrument,
+            ?kind,
+            "flagged anomalous MarketEvent"
+        );
+
+        match self.policies.policy(&event.exchange, &event.instrument) {
+            AnomalyPolicy::Forward => Some(Checked::Anomaly(DataAnomaly { kind, event })),
+            AnomalyPolicy::Drop => None,
+            AnomalyPolicy::Halt { max_anomalies } => {
+                let anomalies = self.anomalies.entry(key.clone()).or_default();
+                *anomalies += 1;
+
+                if *anomalies > max_anomalies {
+                    warn!(
+                        exchange = %event.exchange,
+                        instrument = %event.instrument,
+                        anomalies,
+                        "halting subscription after exceeding max anomalies"
+                    );
+                    self.halted.insert(key);
+                    Some(Checked::Halted(DataAnomaly { kind, event }))
+                } else {
+                    Some(Checked::Anomaly(DataAnomaly { kind, event }))
+                }
+            }
+        }
+    }
+}
+
+/// Median of the provided non-empty prices.
+fn median(prices: &VecDeque<f64>) -> f64 {
+    let mut sorted = prices.iter().copied().collect::<Vec<_>>();
+    sorted.sort_by(f64::total_cmp);
+
+    let middle = sorted.len() / 2;
+    match sorted.len() % 2 {
+        0 => (sorted[middle - 1] + sorted[middle]) / 2.0,
+        _ => sorted[middle],
+    }
+}
+
+/// Forward every [`MarketEvent<T>`] received on the `event_rx` to the `checked_tx`, applying
+/// the [`AnomalyPolicies`] of each subscription to the events flagged by a [`SanityChecker`]
+/// (see [`AnomalyFilter`]).
+///
+/// Runs until the `event_rx` is exhausted or the `checked_tx` receiver has been dropped.
+pub async fn sanity_check<T>(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    checked_tx: mpsc::UnboundedSender<Checked<T>>,
+    config: SanityConfig,
+    policies: AnomalyPolicies,
+) where
+    T: SanityFields,
+{
+    let mut filter = AnomalyFilter::new(config, policies);
+
+    while let Some(event) = event_rx.recv().await {
+        let Some(checked) = filter.filter(event) else {
+            continue;
+        };
+
+        if checked_tx.send(checked).is_err() {
+            debug!("sanity checked MarketEvent receiver dropped - stopping sanity checks");
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixture::trade_event, subscription::trade::TradeId};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn trade(price: f64, amount: f64, exchange_time: DateTime<Utc>) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time,
+            received_time: exchange_time,
+            ..trade_event(TradeId::None, price, amount)
+        }
+    }
+
+    #[test]
+    fn test_sanity_checker_check() {
+        let now = Utc::now();
+        let mut checker = SanityChecker::new(SanityConfig {
+            min_samples: 3,
+            ..SanityConfig::default()
+        });
+
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: Option<AnomalyKind>,
+        }
+
+        let mut future = trade(100.0, 1.0, now + Duration::minutes(1));
+        future.received_time = now;
+        let mut stale = trade(100.0, 1.0, now - Duration::hours(2));
+        stale.received_time = now;
+
+        let tests = vec![
+            TestCase {
+                // TC0: deviations are not checked before the minimum samples
+                input: trade(100.0, 1.0, now),
+                expected: None,
+            },
+            TestCase {
+                // TC1: deviations are not checked before the minimum samples
+                input: trade(200.0, 1.0, now),
+                expected: None,
+            },
+            TestCase {
+                // TC2: deviations are not checked before the minimum samples
+                input: trade(101.0, 1.0, now),
+                expected: None,
+            },
+            TestCase {
+                // TC3: price within the maximum deviation of the median
+                input: trade(105.0, 1.0, now),
+                expected: None,
+            },
+            TestCase {
+                // TC4: price deviating from the median of 103.0
+                input: trade(50.0, 1.0, now),
+                expected: Some(AnomalyKind::PriceDeviation {
+                    price: 50.0,
+                    median: 103.0,
+                }),
+            },
+            TestCase {
+                // TC5: zero amount
+                input: trade(101.0, 0.0, now),
+                expected: Some(AnomalyKind::InvalidAmount { amount: 0.0 }),
+            },
+            TestCase {
+                // TC6: negative price
+                input: trade(-1.0, 1.0, now),
+                expected: Some(AnomalyKind::InvalidPrice { price: -1.0 }),
+            },
+            TestCase {
+                // TC7: exchange time far ahead of the received time
+                input: future,
+                expected: Some(AnomalyKind::FutureTimestamp {
+                    exchange_time: now + Duration::minutes(1),
+                }),
+            },
+            TestCase {
+                // TC8: exchange time far behind the received time
+                input: stale,
+                expected: Some(AnomalyKind::StaleTimestamp {
+                    exchange_time: now - Duration::hours(2),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = checker.check(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_anomaly_filter() {
+        let now = Utc::now();
+        let drop = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let halt = Instrument::from(("sol", "usdt", InstrumentKind::Spot));
+        let mut filter = AnomalyFilter::new(
+            SanityConfig::default(),
+            AnomalyPolicies::default()
+                .with_policy(ExchangeId::BinanceSpot, drop.clone(), AnomalyPolicy::Drop)
+                .with_policy(
+                    ExchangeId::BinanceSpot,
+                    halt.clone(),
+                    AnomalyPolicy::Halt { max_anomalies: 1 },
+                ),
+        );
+
+        let event = |instrument: &Instrument, amount: f64| MarketEvent {
+            instrument: instrument.clone(),
+            ..trade(100.0, amount, now)
+        };
+        let forward = event(
+            &Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            1.0,
+        );
+
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: default Forward policy tags anomalies
+                input: MarketEvent {
+                    kind: PublicTrade {
+                        amount: 0.0,
+                        ..forward.kind.clone()
+                    },
+                    ..forward.clone()
+                },
+                expected: Some("anomaly"),
+            },
+            TestCase {
+                // TC1: Drop policy forwards valid events
+                input: event(&drop, 1.0),
+                expected: Some("event"),
+            },
+            TestCase {
+                // TC2: Drop policy discards anomalies
+                input: event(&drop, 0.0),
+                expected: None,
+            },
+            TestCase {
+                // TC3: Halt policy tags anomalies within the threshold
+                input: event(&halt, 0.0),
+                expected: Some("anomaly"),
+            },
+            TestCase {
+                // TC4: Halt policy halts once the threshold is exceeded
+                input: event(&halt, 0.0),
+                expected: Some("halted"),
+            },
+            TestCase {
+                // TC5: halted subscriptions discard valid events
+                input: event(&halt, 1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC6: other subscriptions are unaffected by a halt
+                input: forward,
+                expected: Some("event"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = filter.filter(test.input).map(|checked| match checked {
+                Checked::Event(_) => "event",
+                Checked::Anomaly(_) => "anomaly",
+                Checked::Halted(_) => "halted",
+            });
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sanity_check() {
+        let now = Utc::now();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (checked_tx, mut checked_rx) = mpsc::unbounded_channel();
+
+        event_tx.send(trade(100.0, 1.0, now)).unwrap();
+        event_tx.send(trade(100.0, -1.0, now)).unwrap();
+        drop(event_tx);
+
+        sanity_check(
+            event_rx,
+            checked_tx,
+            SanityConfig::default(),
+            AnomalyPolicies::default(),
+        )
+        .await;
+
+        assert!(matches!(
+            checked_rx.recv().await,
+            Some(Checked::Event(event)) if event.kind.amount == 1.0
+        ));
+        assert!(matches!(
+            checked_rx.recv().await,
+            Some(Checked::Anomaly(DataAnomaly {
+                kind: AnomalyKind::InvalidAmount { amount },
+                ..
+            })) if amount == -1.0
+        ));
+        assert_eq!(checked_rx.recv().await, None);
+    }
+}
@@ -0,0 +1,414 @@
+use crate::{event::MarketEvent, exchange::ExchangeId};
+use barter_integration::model::Exchange;
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Number of seconds in a week, used to wrap [`WeeklySession`] offsets.
+const SECONDS_PER_WEEK: i64 = 7 * 24 * 60 * 60;
+
+/// Default interval at which the [`session_events`] stage checks for session transitions & stale
+/// feeds while no [`MarketEvent<T>`]s are received.
+pub const DEFAULT_SESSION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Recurring weekly trading session of a venue, in UTC (eg/ CME Globex Sunday 23:00 to Monday
+/// 22:00). A session with a `close` before it's `open` wraps around the end of the week.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct WeeklySession {
+    pub open: (Weekday, NaiveTime),
+    pub close: (Weekday, NaiveTime),
+}
+
+impl WeeklySession {
+    /// Seconds since Monday 00:00 UTC of the session open & close.
+    fn offsets(&self) -> (i64, i64) {
+        (week_offset(self.open), week_offset(self.close))
+    }
+
+    /// Determine if the provided seconds since Monday 00:00 UTC are within the session.
+    fn contains(&self, offset: i64) -> bool {
+        let (open, close) = self.offsets();
+        match open <= close {
+            true => open <= offset && offset < close,
+            false => offset >= open || offset < close,
+        }
+    }
+}
+
+/// Seconds since Monday 00:00 UTC of a weekday & time.
+fn week_offset((day, time): (Weekday, NaiveTime)) -> i64 {
+    i64::from(day.num_days_from_monday()) * 86_400 + i64::from(time.num_seconds_from_midnight())
+}
+
+/// One-off scheduled downtime of a venue (eg/ a Kraken futures maintenance window).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Downtime {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// Trading session calendar of a venue.
+///
+/// A [`SessionCalendar`] without [`WeeklySession`]s is always in session (eg/ 24/7 crypto
+/// venues), apart from any scheduled [`Downtime`].
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SessionCalendar {
+    pub sessions: Vec<WeeklySession>,
+    pub downtime: Vec<Downtime>,
+}
+
+impl SessionCalendar {
+    /// Add a recurring [`WeeklySession`] to the [`SessionCalendar`].
+    pub fn with_session(mut self, open: (Weekday, NaiveTime), close: (Weekday, NaiveTime)) -> Self {
+        self.sessions.push(WeeklySession { open, close });
+        self
+    }
+
+    /// Add a one-off scheduled [`Downtime`] to the [`SessionCalendar`].
+    pub fn with_downtime(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.downtime.push(Downtime { start, end });
+        self
+    }
+
+    /// Determine if the venue is in session at the provided time.
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        let in_session = self.sessions.is_empty() || {
+            let offset = week_offset((time.weekday(), time.time()));
+            self.sessions.iter().any(|session| session.contains(offset))
+        };
+
+        in_session && !self.is_downtime(time)
+    }
+
+    /// Determine if the provided time is within a scheduled [`Downtime`].
+    pub fn is_downtime(&self, time: DateTime<Utc>) -> bool {
+        self.downtime
+            .iter()
+            .any(|downtime| downtime.start <= time && time < downtime.end)
+    }
+
+    /// Time of the next session open or close boundary strictly after the provided time, if any.
+    pub fn next_transition(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let base = Utc.timestamp_opt(time.timestamp(), 0).single()?;
+        let offset = week_offset((time.weekday(), time.time()));
+
+        let sessions = self
+            .sessions
+            .iter()
+            .flat_map(|session| {
+                let (open, close) = session.offsets();
+                [open, close]
+            })
+            .map(
+                |boundary| match (boundary - offset).rem_euclid(SECONDS_PER_WEEK) {
+                    0 => SECONDS_PER_WEEK,
+                    delta => delta,
+                },
+            )
+            .map(|delta| base + Duration::seconds(delta));
+
+        let downtime = self
+            .downtime
+            .iter()
+            .flat_map(|downtime| [downtime.start, downtime.end])
+            .filter(|boundary| *boundary > time);
+
+        sessions.chain(downtime).min()
+    }
+}
+
+/// Collection of [`SessionCalendar`]s for each exchange. Exchanges without a [`SessionCalendar`]
+/// are always in session.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SessionCalendars(pub HashMap<Exchange, SessionCalendar>);
+
+impl SessionCalendars {
+    /// Set the [`SessionCalendar`] of an exchange.
+    pub fn with_calendar(mut self, exchange: ExchangeId, calendar: SessionCalendar) -> Self {
+        self.0.insert(Exchange::from(exchange), calendar);
+        self
+    }
+
+    /// Determine if an exchange is in session at the provided time.
+    pub fn is_open(&self, exchange: &Exchange, time: DateTime<Utc>) -> bool {
+        self.0
+            .get(exchange)
+            .is_none_or(|calendar| calendar.is_open(time))
+    }
+}
+
+/// Output of the [`session_events`] stage.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Sessioned<T> {
+    /// [`MarketEvent<T>`] forwarded unchanged.
+    Event(MarketEvent<T>),
+    /// Exchange session opened (or scheduled [`Downtime`] ended).
+    SessionOpen {
+        exchange: Exchange,
+        time: DateTime<Utc>,
+    },
+    /// Exchange session closed (or scheduled [`Downtime`] started).
+    SessionClose {
+        exchange: Exchange,
+        time: DateTime<Utc>,
+    },
+    /// No [`MarketEvent<T>`] has been received from an in session exchange within the stale
+    /// threshold. Never emitted while an exchange is out of session.
+    StaleFeed {
+        exchange: Exchange,
+        last_event: DateTime<Utc>,
+    },
+}
+
+/// Per exchange state of a [`SessionTracker`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct SessionState {
+    open: bool,
+    last_event: DateTime<Utc>,
+    stale: bool,
+}
+
+/// Tracks the session state & feed liveness of each exchange using [`SessionCalendars`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SessionTracker {
+    calendars: SessionCalendars,
+    stale_after: Option<Duration>,
+    states: HashMap<Exchange, SessionState>,
+}
+
+impl SessionTracker {
+    /// Construct a new [`Self`] using the provided [`SessionCalendars`] and optional stale feed
+    /// threshold.
+    pub fn new(
+        calendars: SessionCalendars,
+        stale_after: Option<Duration>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let states = calendars
+            .0
+            .iter()
+            .map(|(exchange, calendar)| {
+                let state = SessionState {
+                    open: calendar.is_open(now),
+                    last_event: now,
+                    stale: false,
+                };
+                (exchange.clone(), state)
+            })
+            .collect();
+
+        Self {
+            calendars,
+            stale_after,
+            states,
+        }
+    }
+
+    /// Record a [`MarketEvent<T>`] received from an exchange at the provided time.
+    pub fn observe(&mut self, exchange: &Exchange, now: DateTime<Utc>) {
+        let open = self.calendars.is_open(exchange, now);
+        let state = self.states.entry(exchange.clone()).or_insert(SessionState {
+            open,
+            last_event: now,
+            stale: false,
+        });
+        state.last_event = now;
+        state.stale = false;
+    }
+
+    /// Generate the session transitions & stale feed alarms of every exchange at the provided
+    /// time.
+    pub fn poll<T>(&mut self, now: DateTime<Utc>) -> Vec<Sessioned<T>> {
+        let mut events = Vec::new();
+
+        for (exchange, state) in self.states.iter_mut() {
+            let open = self.calendars.is_open(exchange, now);
+
+            if open != state.open {
+                state.open = open;
+                // Restart the stale threshold so a quiet open is not immediately alarmed
+                state.last_event = now;
+                state.stale = false;
+                events.push(match open {
+                    true => Sessioned::SessionOpen {
+                        exchange: exchange.clone(),
+                        time: now,
+                    },
+                    false => Sessioned::SessionClose {
+                        exchange: exchange.clone(),
+                        time: now,
+                    },
+                });
+                continue;
+            }
+
+            let Some(stale_after) = self.stale_after else {
+                continue;
+            };
+
+            if state.open && !state.stale && now - state.last_event >= stale_after {
+                state.stale = true;
+                events.push(Sessioned::StaleFeed {
+                    exchange: exchange.clone(),
+                    last_event: state.last_event,
+                });
+            }
+        }
+
+        events
+    }
+}
+
+/// Forward [`MarketEvent<T>`]s as [`Sessioned::Event`]s, emitting [`Sessioned::SessionOpen`] &
+/// [`Sessioned::SessionClose`] events as each exchange enters & leaves session, and
+/// [`Sessioned::StaleFeed`] alarms for in session exchanges that go quiet for `stale_after`.
+///
+/// Stale feed alarms are suppressed while an exchange is out of session or in scheduled
+/// [`Downtime`].
+pub async fn session_events<T>(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<T>>,
+    sessioned_tx: mpsc::UnboundedSender<Sessioned<T>>,
+    calendars: SessionCalendars,
+    stale_after: Option<Duration>,
+) {
+    let mut tracker = SessionTracker::new(calendars, stale_after, Utc::now());
+    let mut poll = tokio::time::interval(DEFAULT_SESSION_POLL_INTERVAL);
+
+    loop {
+        let events = tokio::select! {
+            event = event_rx.recv() => match event {
+                Some(event) => {
+                    tracker.observe(&event.exchange, Utc::now());
+                    vec![Sessioned::Event(event)]
+                }
+                None => break,
+            },
+            _ = poll.tick() => tracker.poll(Utc::now()),
+        };
+
+        for event in events {
+            if sessioned_tx.send(event).is_err() {
+                debug!("sessioned MarketEvent receiver dropped - stopping session events");
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(day: u32, hour: u32) -> DateTime<Utc> {
+        // 2023-01-02 is a Monday
+        Utc.with_ymd_and_hms(2023, 1, day, hour, 0, 0).unwrap()
+    }
+
+    fn cme() -> SessionCalendar {
+        let hour = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        SessionCalendar::default()
+            .with_session((Weekday::Sun, hour(23)), (Weekday::Mon, hour(22)))
+            .with_session((Weekday::Mon, hour(23)), (Weekday::Tue, hour(22)))
+    }
+
+    #[test]
+    fn test_session_calendar() {
+        struct TestCase {
+            calendar: SessionCalendar,
+            time: DateTime<Utc>,
+            expected_open: bool,
+            expected_next: Option<DateTime<Utc>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty calendar is always open
+                calendar: SessionCalendar::default(),
+                time: time(2, 12),
+                expected_open: true,
+                expected_next: None,
+            },
+            TestCase {
+                // TC1: within session spanning midnight
+                calendar: cme(),
+                time: time(2, 12),
+                expected_open: true,
+                expected_next: Some(time(2, 22)),
+            },
+            TestCase {
+                // TC2: daily break between sessions
+                calendar: cme(),
+                time: time(2, 22),
+                expected_open: false,
+                expected_next: Some(time(2, 23)),
+            },
+            TestCase {
+                // TC3: scheduled downtime within a session
+                calendar: cme().with_downtime(time(3, 2), time(3, 4)),
+                time: time(3, 3),
+                expected_open: false,
+                expected_next: Some(time(3, 4)),
+            },
+            TestCase {
+                // TC4: out of session after the last weekly session, next open wraps the week
+                calendar: cme(),
+                time: time(4, 12),
+                expected_open: false,
+                expected_next: Some(time(8, 23)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.calendar.is_open(test.time),
+                test.expected_open,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                test.calendar.next_transition(test.time),
+                test.expected_next,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_session_tracker() {
+        let exchange = Exchange::from(ExchangeId::Kraken);
+        let calendars = SessionCalendars::default().with_calendar(
+            ExchangeId::Kraken,
+            SessionCalendar::default().with_downtime(time(2, 2), time(2, 4)),
+        );
+        let mut tracker = SessionTracker::new(calendars, Some(Duration::minutes(30)), time(2, 0));
+
+        // In session & quiet beyond the stale threshold
+        let events = tracker.poll::<()>(time(2, 1));
+        assert!(matches!(events.as_slice(), [Sessioned::StaleFeed { .. }]));
+
+        // Stale alarm is only emitted once
+        assert!(tracker
+            .poll::<()>(time(2, 1) + Duration::minutes(1))
+            .is_empty());
+
+        // Scheduled downtime closes the session
+        let events = tracker.poll::<()>(time(2, 2));
+        assert!(matches!(
+            events.as_slice(),
+            [Sessioned::SessionClose { .. }]
+        ));
+
+        // No stale alarm during scheduled downtime
+        assert!(tracker.poll::<()>(time(2, 3)).is_empty());
+
+        // Session re-opens, and the stale threshold restarts
+        let events = tracker.poll::<()>(time(2, 4));
+        assert!(matches!(events.as_slice(), [Sessioned::SessionOpen { .. }]));
+        tracker.observe(&exchange, time(2, 4) + Duration::minutes(20));
+        assert!(tracker
+            .poll::<()>(time(2, 4) + Duration::minutes(40))
+            .is_empty());
+    }
+}
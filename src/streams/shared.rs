@@ -0,0 +1,832 @@
+use super::{
+    config::StreamConfig,
+    consumer::{next_backoff_ms, FAILOVER_AFTER_FAILURES, STARTING_RECONNECT_BACKOFF_MS},
+    tap::TappedWsStream,
+};
+use crate::{
+    delta_instruments, distribute_messages_to_exchange,
+    error::DataError,
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, StreamSelector},
+    initial_snapshots,
+    normalise::AssetNormaliser,
+    schedule_pings_to_exchange,
+    subscriber::validator::SubscriptionValidator,
+    subscription::{Map, SubKind, Subscription},
+    transformer::ExchangeTransformer,
+    transport::BoxTransport,
+    ExchangeWsStream, Identifier, OutboundQueue, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::{
+        websocket::{WebSocketParser, WsError, WsMessage},
+        StreamParser,
+    },
+    ExchangeStream, Transformer,
+};
+use futures::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    time::Duration,
+};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::{debug, error, info, warn};
+use url::Url;
+
+/// Communicative type alias for the [`ExchangeStream`] of a single [`SubKind`] that is
+/// multiplexed over a shared [`WebSocket`] connection.
+pub type SharedExchangeStream<Transformer> = ExchangeStream<
+    SharedWebSocketParser,
+    futures::stream::Map<
+        UnboundedReceiverStream<WsMessage>,
+        fn(WsMessage) -> Result<WsMessage, WsError>,
+    >,
+    Transformer,
+>;
+
+/// Defines the [`ExchangeTransformer`] used by a [`StreamSelector::Stream`], allowing the
+/// [`SubKind`] to be multiplexed over a shared [`WebSocket`] connection.
+///
+/// Implemented for every [`ExchangeWsStream`].
+pub trait SharedStream<Exchange, Kind>
+where
+    Kind: SubKind,
+{
+    type Transformer: ExchangeTransformer<Exchange, Kind> + Send;
+}
+
+impl<Exchange, Kind, Transformer> SharedStream<Exchange, Kind> for ExchangeWsStream<Transformer>
+where
+    Kind: SubKind,
+    Transformer: ExchangeTransformer<Exchange, Kind> + Send,
+{
+    type Transformer = Transformer;
+}
+
+/// Communicative type alias for the exchange message deserialised by the [`Transformer`] of a
+/// [`SubKind`] multiplexed over a shared [`WebSocket`] connection.
+type SharedInput<Exchange, Kind> = <<<Exchange as StreamSelector<Kind>>::Stream as SharedStream<
+    Exchange,
+    Kind,
+>>::Transformer as Transformer>::Input;
+
+/// [`StreamParser`] for a [`SubKind`] multiplexed over a shared [`WebSocket`] connection.
+///
+/// Each message received over the shared connection is only distributed to the [`SharedRoute`]s
+/// that accept it (see [`SharedRoute::accepts`]), so messages are parsed as per the
+/// [`WebSocketParser`] and any deserialisation error is surfaced.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SharedWebSocketParser;
+
+impl StreamParser for SharedWebSocketParser {
+    type Stream = BoxTransport;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        WebSocketParser::parse::<Output>(input)
+    }
+}
+
+/// Type erased collection of [`Subscription`]s for a single [`SubKind`] that is multiplexed
+/// over a shared [`WebSocket`] connection, distributing a common `Output`.
+#[async_trait]
+pub trait SharedRoute<Exchange, Output>
+where
+    Self: Debug + Send + Sync,
+    Exchange: Connector,
+{
+    /// Validate the [`Subscription`]s of this [`SharedRoute`].
+    fn validate(&self) -> Result<(), DataError>;
+
+    /// Determine if a message received over the shared [`WebSocket`] is associated with this
+    /// [`SharedRoute`], returning the [`SocketError::Deserialise`] if it is not.
+    fn accepts(&self, message: &WsMessage) -> Result<(), SocketError>;
+
+    /// Map the [`Subscription`]s of this [`SharedRoute`] into [`ExchangeSub`]s, and the
+    /// [`SubscriptionId`] - [`Instrument`] [`Map`] used to identify incoming messages.
+    #[allow(clippy::type_complexity)]
+    fn map(
+        &self,
+        normaliser: &AssetNormaliser,
+    ) -> (
+        Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>,
+        Map<Instrument>,
+    );
+
+    /// Validate the exchange responses to every [`SharedRoute`] subscription request sent over
+    /// the shared [`WebSocket`].
+    async fn validate_responses(
+        &self,
+        instrument_map: Map<Instrument>,
+        websocket: &mut BoxTransport,
+    ) -> Result<Map<Instrument>, SocketError>;
+
+    /// Spawn a task that transforms the messages received over the shared [`WebSocket`] into
+    /// `Output`s, sending them via the provided `output_tx`.
+    async fn spawn(
+        &self,
+        outbound_tx: OutboundTx,
+        instrument_map: Map<Instrument>,
+        ws_rx: mpsc::UnboundedReceiver<WsMessage>,
+        output_tx: mpsc::UnboundedSender<Output>,
+        normaliser: &AssetNormaliser,
+    ) -> Result<JoinHandle<()>, DataError>;
+}
+
+/// [`SharedRoute`] for a collection of [`Subscription`]s to the same [`SubKind`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SharedKindRoute<Exchange, Kind> {
+    pub subscriptions: Vec<Subscription<Exchange, Kind>>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Output> SharedRoute<Exchange, Output> for SharedKindRoute<Exchange, Kind>
+where
+    Exchange: StreamSelector<Kind> + Send + Sync + 'static,
+    Exchange::Stream: SharedStream<Exchange, Kind>,
+    Kind: SubKind + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+    Output: From<MarketEvent<Kind::Event>> + Send + 'static,
+{
+    fn validate(&self) -> Result<(), DataError> {
+        super::builder::validate(&self.subscriptions)
+    }
+
+    fn accepts(&self, message: &WsMessage) -> Result<(), SocketError> {
+        let payload = match message {
+            WsMessage::Text(text) => text.as_bytes(),
+            WsMessage::Binary(binary) => binary.as_slice(),
+            // Control frames are handled by every SharedRoute
+            _ => return Ok(()),
+        };
+
+        serde_json::from_slice::<SharedInput<Exchange, Kind>>(payload)
+            .map(|_| ())
+            .map_err(|error| SocketError::Deserialise {
+                error,
+                payload: String::from_utf8_lossy(payload).into_owned(),
+            })
+    }
+
+    fn map(
+        &self,
+        normaliser: &AssetNormaliser,
+    ) -> (
+        Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>,
+        Map<Instrument>,
+    ) {
+        let mut instrument_map = Map(HashMap::with_capacity(self.subscriptions.len()));
+
+        let exchange_subs = self
+            .subscriptions
+            .iter()
+            .map(|subscription| {
+                let exchange_sub =
+                    ExchangeSub::new(&normaliser.exchange_subscription(subscription));
+                instrument_map.0.insert(
+                    exchange_sub.id(),
+                    normaliser.normalise_instrument(Exchange::ID, &subscription.instrument),
+                );
+                exchange_sub
+            })
+            .collect();
+
+        (exchange_subs, instrument_map)
+    }
+
+    async fn validate_responses(
+        &self,
+        instrument_map: Map<Instrument>,
+        websocket: &mut BoxTransport,
+    ) -> Result<Map<Instrument>, SocketError> {
+        Exchange::SubValidator::validate::<Exchange, Kind>(instrument_map, websocket).await
+    }
+
+    async fn spawn(
+        &self,
+        outbound_tx: OutboundTx,
+        instrument_map: Map<Instrument>,
+        ws_rx: mpsc::UnboundedReceiver<WsMessage>,
+        output_tx: mpsc::UnboundedSender<Output>,
+        normaliser: &AssetNormaliser,
+    ) -> Result<JoinHandle<()>, DataError> {
+        // Construct Transformer associated with this Exchange and SubKind
+        let mut transformer = <Exchange::Stream as SharedStream<Exchange, Kind>>::Transformer::new(
+            outbound_tx,
+            instrument_map,
+        )
+        .await?;
+        transformer.yield_deltas(&delta_instruments(&self.subscriptions, normaliser));
+        let initial_events =
+            transformer.initial_events(&initial_snapshots(&self.subscriptions, normaliser));
+
+        let mut stream = SharedExchangeStream::new(
+            UnboundedReceiverStream::new(ws_rx).map(Ok as fn(WsMessage) -> _),
+            transformer,
+        );
+        stream.buffer.extend(initial_events);
+
+        Ok(tokio::spawn(async move {
+            let exchange = Exchange::ID;
+
+            while let Some(event_result) = stream.next().await {
+                match event_result {
+                    Ok(market_event) => {
+                        let _ = output_tx.send(Output::from(market_event));
+                    }
+                    // Messages of another SubKind sharing the connection may be unidentifiable
+                    Err(DataError::Socket(SocketError::Unidentifiable(subscription_id))) => {
+                        debug!(
+                            %exchange,
+                            %subscription_id,
+                            action = "skipping message",
+                            "shared MarketStream message not associated with this SubKind",
+                        );
+                    }
+                    Err(error) if error.is_terminal() => {
+                        error!(
+                            %exchange,
+                            %error,
+                            action = "re-initialising shared connection",
+                            "consumed DataError from shared MarketStream",
+                        );
+                        break;
+                    }
+                    Err(error) => {
+                        warn!(
+                            %exchange,
+                            %error,
+                            action = "skipping message",
+                            "consumed DataError from shared MarketStream",
+                        );
+                    }
+                }
+            }
+        }))
+    }
+}
+
+/// Non-empty collection of validated [`SharedRoute`]s that are multiplexed over a shared
+/// [`WebSocket`] connection.
+pub struct SharedRoutes<Exchange, Output> {
+    first: Box<dyn SharedRoute<Exchange, Output>>,
+    rest: Vec<Box<dyn SharedRoute<Exchange, Output>>>,
+}
+
+impl<Exchange, Output> Debug for SharedRoutes<Exchange, Output> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entry(&self.first)
+            .entries(self.rest.iter())
+            .finish()
+    }
+}
+
+impl<Exchange, Output> SharedRoutes<Exchange, Output>
+where
+    Exchange: Connector,
+{
+    /// Construct a new [`Self`], ensuring at least one [`SharedRoute`] has been provided and
+    /// validating the [`Subscription`]s of every [`SharedRoute`].
+    pub fn new(routes: Vec<Box<dyn SharedRoute<Exchange, Output>>>) -> Result<Self, DataError> {
+        let mut routes = routes.into_iter();
+
+        // Ensure at least one SharedRoute has been provided
+        let first = routes.next().ok_or_else(|| {
+            DataError::Socket(SocketError::Subscribe(
+                "SharedStreamBuilder contains no Subscription to action".to_owned(),
+            ))
+        })?;
+        let routes = Self {
+            first,
+            rest: routes.collect(),
+        };
+
+        // Validate Subscriptions of every SharedRoute
+        routes.iter().try_for_each(|route| route.validate())?;
+
+        Ok(routes)
+    }
+
+    /// First [`SharedRoute`], used to validate the subscription responses of every
+    /// [`SharedRoute`] sent over the shared [`WebSocket`].
+    pub fn first(&self) -> &dyn SharedRoute<Exchange, Output> {
+        self.first.as_ref()
+    }
+
+    /// Iterate over every [`SharedRoute`].
+    pub fn iter(&self) -> impl Iterator<Item = &dyn SharedRoute<Exchange, Output>> {
+        std::iter::once(&self.first)
+            .chain(self.rest.iter())
+            .map(Box::as_ref)
+    }
+}
+
+/// Connect to the exchange server and action the [`Subscription`]s of every provided
+/// [`SharedRoute`] over a single [`WebSocket`].
+///
+/// Returns the [`BoxTransport`] and the validated [`Map<Instrument>`] of each [`SharedRoute`].
+pub async fn subscribe_shared<Exchange, Output>(
+    routes: &SharedRoutes<Exchange, Output>,
+    config: &StreamConfig,
+) -> Result<(BoxTransport, Vec<Map<Instrument>>), SocketError>
+where
+    Exchange: Connector,
+    ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+{
+    let exchange = Exchange::ID;
+
+    // Construct the connection Url & subscription requests of every SharedRoute
+    let (url, requests, route_maps) = shared_requests(routes, config)?;
+    let url = config.select_url::<Exchange>(url).await;
+    debug!(%exchange, %url, "subscribing to shared WebSocket");
+    let instrument_map = route_maps
+        .iter()
+        .flat_map(|map| map.0.clone())
+        .collect::<Map<Instrument>>();
+
+    // Connect to exchange
+    let mut websocket = config.connect(url).await?;
+    debug!(%exchange, "connected to shared WebSocket");
+
+    // Send Subscriptions of every SharedRoute over the WebSocket
+    for subscription in requests {
+        debug!(%exchange, payload = ?subscription, "sending exchange subscription");
+        websocket.send(subscription).await?;
+    }
+
+    // Validate Subscription responses of every SharedRoute
+    let validated = routes
+        .first()
+        .validate_responses(instrument_map, &mut websocket)
+        .await?;
+
+    // Ensure each SharedRoute SubscriptionId is unchanged by the exchange validation
+    let route_maps = route_maps
+        .into_iter()
+        .map(|map| {
+            map.0
+                .into_keys()
+                .map(|id| validated.find(&id).map(|instrument| (id, instrument)))
+                .collect::<Result<Map<Instrument>, SocketError>>()
+        })
+        .collect::<Result<Vec<_>, SocketError>>()
+        .map_err(|_| {
+            SocketError::Subscribe(format!(
+                "{exchange} does not support sharing a connection across SubKinds"
+            ))
+        })?;
+
+    info!(%exchange, "subscribed to shared WebSocket");
+    Ok((websocket, route_maps))
+}
+
+/// Map every [`SharedRoute`] into the exchange server [`Url`] to connect to, the
+/// [`WsMessage`] subscription requests to send, and the [`Map<Instrument>`] of each
+/// [`SharedRoute`].
+#[allow(clippy::type_complexity)]
+fn shared_requests<Exchange, Output>(
+    routes: &SharedRoutes<Exchange, Output>,
+    config: &StreamConfig,
+) -> Result<(Url, Vec<WsMessage>, Vec<Map<Instrument>>), SocketError>
+where
+    Exchange: Connector,
+    ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+{
+    // Map every SharedRoute into ExchangeSubs & it's own Map<Instrument>
+    let (exchange_subs, route_maps): (Vec<_>, Vec<_>) = routes
+        .iter()
+        .map(|route| route.map(&config.normaliser))
+        .unzip();
+    let exchange_subs = exchange_subs.into_iter().flatten().collect::<Vec<_>>();
+
+    // Every ExchangeSub must be served by the same exchange server to share a connection
+    let url = config
+        .resolve_connection_url::<Exchange, _, _>(exchange_subs.iter().map(|sub| &sub.channel))?;
+
+    Ok((url, Exchange::requests(exchange_subs), route_maps))
+}
+
+/// Central consumer loop for a shared [`WebSocket`] connection.
+///
+/// Initialises a [`SharedExchangeStream`] for every [`SharedRoute`] multiplexed over the
+/// connection, distributing each received message to every [`SharedRoute`] that accepts it. If the connection
+/// ends, or a [`SharedRoute`] consumes a terminal [`DataError`], every [`SharedRoute`] is
+/// re-initialised over a new connection, backing off exponentially between failed attempts.
+///
+/// Backup [`StreamConfig`] endpoints are failed over to in the same manner as the
+/// [`consume`](super::consumer::consume) loop.
+pub async fn consume_shared<Exchange, Output>(
+    routes: SharedRoutes<Exchange, Output>,
+    mut config: StreamConfig,
+    exchange_tx: mpsc::UnboundedSender<Output>,
+) -> DataError
+where
+    Exchange: Connector,
+    ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+{
+    // Determine ExchangeId associated with these SharedRoutes
+    let exchange = Exchange::ID;
+
+    info!(
+        %exchange,
+        ?routes,
+        ?config,
+        policy = "retry connection with exponential backoff",
+        "shared MarketStream consumer loop running",
+    );
+
+    // Consumer loop retry parameters
+    let mut attempt: u32 = 0;
+    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    let mut initialised = false;
+
+    loop {
+        // Increment attempt at start of every iteration
+        attempt += 1;
+        info!(%exchange, attempt, "attempting to initialise shared MarketStream");
+
+        // Attempt to initialise shared MarketStream: if it fails on first attempt return DataError
+        let SharedConnection {
+            mut ws_stream,
+            route_txs,
+            mut handles,
+        } = match init_shared(&routes, &config, &exchange_tx).await {
+            Ok(connection) => {
+                info!(%exchange, attempt, "successfully initialised shared MarketStream");
+                attempt = 0;
+                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
+                initialised = true;
+                connection
+            }
+            Err(error) => {
+                error!(%exchange, attempt, ?error, "failed to initialise shared MarketStream");
+
+                // Exit function if init failed the first attempt at every endpoint
+                if !initialised && attempt as usize >= config.num_endpoints() {
+                    return error;
+                }
+
+                // Fail over to the next endpoint, else retry after backoff
+                if (!initialised || attempt.is_multiple_of(FAILOVER_AFTER_FAILURES))
+                    && config.failover()
+                {
+                    warn!(%exchange, attempt, "failing over to next shared MarketStream endpoint");
+                }
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                backoff_ms = next_backoff_ms(backoff_ms);
+                continue;
+            }
+        };
+
+        // Distribute messages to the SharedRoutes that accept them until the connection or a
+        // SharedRoute ends
+        let distribute = async {
+            while let Some(message) = ws_stream.next().await {
+                match message {
+                    Ok(message) => {
+                        route_message(&routes, &route_txs, message, exchange);
+                    }
+                    Err(error) => {
+                        warn!(%exchange, %error, "consumed error from shared WebSocket");
+                        break;
+                    }
+                }
+            }
+        };
+        tokio::select! {
+            _ = distribute => {},
+            _ = futures::future::select_all(handles.iter_mut()) => {},
+        }
+        handles.iter().for_each(JoinHandle::abort);
+
+        // If shared MarketStream ends unexpectedly, attempt re-connection after backoff_ms
+        warn!(
+            %exchange,
+            backoff_ms,
+            action = "attempt re-connection after backoff",
+            "exchange shared MarketStream unexpectedly ended"
+        );
+        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+        backoff_ms = next_backoff_ms(backoff_ms);
+    }
+}
+
+/// Distribute a message received over the shared [`WebSocket`] to every [`SharedRoute`] that
+/// accepts it, surfacing the deserialisation error if no [`SharedRoute`] does.
+///
+/// Returns the number of [`SharedRoute`]s the message was distributed to.
+fn route_message<Exchange, Output>(
+    routes: &SharedRoutes<Exchange, Output>,
+    route_txs: &[mpsc::UnboundedSender<WsMessage>],
+    message: WsMessage,
+    exchange: ExchangeId,
+) -> usize
+where
+    Exchange: Connector,
+{
+    let mut accepted = 0;
+    let mut rejection = None;
+
+    for (route, route_tx) in routes.iter().zip(route_txs) {
+        match route.accepts(&message) {
+            Ok(()) => {
+                accepted += 1;
+                let _ = route_tx.send(message.clone());
+            }
+            Err(error) => rejection = Some(error),
+        }
+    }
+
+    if let (0, Some(error)) = (accepted, rejection) {
+        warn!(
+            %exchange,
+            %error,
+            action = "skipping message",
+            "shared WebSocket message not accepted by any SharedRoute",
+        );
+    }
+
+    accepted
+}
+
+/// Initialised shared [`WebSocket`] connection, along with the [`SharedRoute`] message
+/// transmitters and task handles.
+struct SharedConnection {
+    ws_stream: TappedWsStream,
+    route_txs: Vec<mpsc::UnboundedSender<WsMessage>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+/// Connect & subscribe every [`SharedRoute`] over a single [`WebSocket`], spawning the tasks
+/// that transform each [`SharedRoute`] message and distribute it's `Output`.
+async fn init_shared<Exchange, Output>(
+    routes: &SharedRoutes<Exchange, Output>,
+    config: &StreamConfig,
+    exchange_tx: &mpsc::UnboundedSender<Output>,
+) -> Result<SharedConnection, DataError>
+where
+    Exchange: Connector,
+    ExchangeSub<Exchange::Channel, Exchange::Market>: Identifier<SubscriptionId>,
+{
+    // Connect & subscribe
+    let (websocket, route_maps) = subscribe_shared(routes, config).await?;
+
+    // Split Transport into TransportStream & TransportSink components
+    let (ws_sink, ws_stream) = websocket.split();
+
+    // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
+    let (outbound_tx, outbound) = OutboundQueue::new();
+    tokio::spawn(distribute_messages_to_exchange(
+        Exchange::ID,
+        ws_sink,
+        outbound,
+    ));
+
+    // Spawn optional task to distribute custom application-level pings to the exchange
+    if let Some(ping_interval) = Exchange::ping_interval() {
+        tokio::spawn(schedule_pings_to_exchange(
+            Exchange::ID,
+            outbound_tx.control.clone(),
+            ping_interval,
+        ));
+    }
+
+    // Register the connection with the BandwidthMeter if configured
+    let meter = config.bandwidth_meter.as_ref().map(|meter| {
+        let map = route_maps
+            .iter()
+            .flat_map(|map| map.0.clone())
+            .collect::<Map<Instrument>>();
+        meter.register(Exchange::ID, &map)
+    });
+
+    // Spawn a transformer task for each SharedRoute
+    let mut route_txs = Vec::new();
+    let mut handles = Vec::new();
+    for (route, instrument_map) in routes.iter().zip(route_maps) {
+        let (route_tx, route_rx) = mpsc::unbounded_channel();
+        let handle = route
+            .spawn(
+                outbound_tx.clone(),
+                instrument_map,
+                route_rx,
+                exchange_tx.clone(),
+                &config.normaliser,
+            )
+            .await;
+
+        match handle {
+            Ok(handle) => {
+                route_txs.push(route_tx);
+                handles.push(handle);
+            }
+            Err(error) => {
+                handles.iter().for_each(JoinHandle::abort);
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(SharedConnection {
+        ws_stream: TappedWsStream::new(Exchange::ID, ws_stream, config.raw_tap.clone())
+            .with_meter(meter)
+            .with_limits(config.frame_limits),
+        route_txs,
+        handles,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, okx::Okx},
+        subscription::{
+            book::OrderBooksL1, candle::Candles, candle::Interval, trade::PublicTrades,
+        },
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    type Output = MarketEvent<crate::event::DataKind>;
+
+    fn binance_routes() -> SharedRoutes<BinanceSpot, Output> {
+        SharedRoutes::new(vec![
+            Box::new(SharedKindRoute {
+                subscriptions: vec![Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))],
+            }),
+            Box::new(SharedKindRoute {
+                subscriptions: vec![Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBooksL1,
+                ))],
+            }),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_shared_web_socket_parser() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Message {
+            #[allow(dead_code)]
+            id: u64,
+        }
+
+        // Messages that cannot be deserialised are surfaced
+        assert!(matches!(
+            SharedWebSocketParser::parse::<Message>(Ok(WsMessage::Text(
+                r#"{"other": 1}"#.to_owned()
+            ))),
+            Some(Err(SocketError::Deserialise { .. }))
+        ));
+
+        // Messages of this SubKind are deserialised
+        assert!(matches!(
+            SharedWebSocketParser::parse::<Message>(Ok(WsMessage::Text(r#"{"id": 1}"#.to_owned()))),
+            Some(Ok(Message { id: 1 }))
+        ));
+    }
+
+    #[test]
+    fn test_shared_routes_new() {
+        // SharedRoutes must contain at least one SharedRoute
+        let routes: Vec<Box<dyn SharedRoute<BinanceSpot, Output>>> = vec![];
+        assert!(matches!(
+            SharedRoutes::new(routes),
+            Err(DataError::Socket(SocketError::Subscribe(_)))
+        ));
+
+        // Every SharedRoute must contain at least one Subscription
+        let routes: Vec<Box<dyn SharedRoute<BinanceSpot, Output>>> =
+            vec![Box::new(SharedKindRoute::<BinanceSpot, PublicTrades> {
+                subscriptions: vec![],
+            })];
+        assert!(SharedRoutes::new(routes).is_err());
+
+        assert_eq!(binance_routes().iter().count(), 2);
+    }
+
+    #[test]
+    fn test_route_message() {
+        struct TestCase {
+            input: WsMessage,
+            expected_accepted: usize,
+            expected_routed: [bool; 2],
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: PublicTrades message is only routed to the PublicTrades SharedRoute
+                input: WsMessage::Text(
+                    r#"{"e":"trade","E":1,"s":"BTCUSDT","t":1,"p":"1.0","q":"1.0","T":1,"m":false}"#
+                        .to_owned(),
+                ),
+                expected_accepted: 1,
+                expected_routed: [true, false],
+            },
+            TestCase {
+                // TC1: OrderBooksL1 message is only routed to the OrderBooksL1 SharedRoute
+                input: WsMessage::Text(
+                    r#"{"u":1,"s":"BTCUSDT","b":"1.0","B":"1.0","a":"2.0","A":"1.0"}"#.to_owned(),
+                ),
+                expected_accepted: 1,
+                expected_routed: [false, true],
+            },
+            TestCase {
+                // TC2: message not accepted by any SharedRoute is skipped
+                input: WsMessage::Text(r#"{"unknown":1}"#.to_owned()),
+                expected_accepted: 0,
+                expected_routed: [false, false],
+            },
+            TestCase {
+                // TC3: control frames are routed to every SharedRoute
+                input: WsMessage::Ping(vec![]),
+                expected_accepted: 2,
+                expected_routed: [true, true],
+            },
+        ];
+
+        let routes = binance_routes();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (txs, mut rxs): (Vec<_>, Vec<_>) =
+                (0..2).map(|_| mpsc::unbounded_channel()).unzip();
+
+            let accepted = route_message(&routes, &txs, test.input, ExchangeId::BinanceSpot);
+            assert_eq!(accepted, test.expected_accepted, "TC{} failed", index);
+
+            let routed = [rxs[0].try_recv().is_ok(), rxs[1].try_recv().is_ok()];
+            assert_eq!(routed, test.expected_routed, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_shared_requests() {
+        // BinanceSpot PublicTrades & OrderBooksL1 share a single subscription request
+        let routes = binance_routes();
+
+        let (url, requests, route_maps) =
+            shared_requests(&routes, &StreamConfig::default()).unwrap();
+        assert_eq!(url, BinanceSpot::url().unwrap());
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            route_maps
+                .iter()
+                .map(|map| map.0.keys().cloned().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![SubscriptionId::from("@trade|BTCUSDT")],
+                vec![SubscriptionId::from("@bookTicker|BTCUSDT")],
+            ]
+        );
+
+        // Okx PublicTrades & Candles are served by different servers, so cannot be shared
+        let routes: SharedRoutes<Okx, Output> = SharedRoutes::new(vec![
+            Box::new(SharedKindRoute {
+                subscriptions: vec![Subscription::from((
+                    Okx,
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))],
+            }),
+            Box::new(SharedKindRoute {
+                subscriptions: vec![Subscription::from((
+                    Okx,
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    Candles::Last(Interval::Minute1),
+                ))],
+            }),
+        ])
+        .unwrap();
+
+        assert!(shared_requests(&routes, &StreamConfig::default()).is_err());
+    }
+}
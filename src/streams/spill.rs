@@ -0,0 +1,398 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::VecDeque,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, ErrorKind, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Default size of a [`SpillQueue`] segment file after which a new segment is started.
+pub const DEFAULT_MAX_SPILL_SEGMENT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// File name prefix of [`SpillQueue`] segment files.
+const SEGMENT_PREFIX: &str = "spill-";
+
+/// File name extension of [`SpillQueue`] segment files.
+const SEGMENT_EXTENSION: &str = "jsonl";
+
+/// Configuration of a [`SpillQueue`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SpillConfig {
+    /// Size of a segment file after which a new segment is started.
+    pub max_segment_bytes: u64,
+    /// Sync every spilled event to disk, so events survive an operating system crash rather than
+    /// only a process crash.
+    pub sync: bool,
+}
+
+impl Default for SpillConfig {
+    fn default() -> Self {
+        Self {
+            max_segment_bytes: DEFAULT_MAX_SPILL_SEGMENT_BYTES,
+            sync: false,
+        }
+    }
+}
+
+/// Segment file of a [`SpillQueue`] being appended to.
+#[derive(Debug)]
+struct SegmentWriter {
+    id: u64,
+    writer: BufWriter<File>,
+    bytes: u64,
+}
+
+/// Segment file of a [`SpillQueue`] being drained.
+#[derive(Debug)]
+struct SegmentReader {
+    id: u64,
+    reader: BufReader<File>,
+}
+
+/// On-disk FIFO queue of JSON encoded events, stored as a directory of append-only segment
+/// files.
+///
+/// Drained segments are deleted, and segments left over by a previous process are drained first
+/// when the [`SpillQueue`] is re-opened. Events are only removed from disk once the segment they
+/// belong to has been drained, so events spilled before a crash are delivered at least once.
+#[derive(Debug)]
+pub struct SpillQueue<T> {
+    dir: PathBuf,
+    config: SpillConfig,
+    segments: VecDeque<u64>,
+    writer: Option<SegmentWriter>,
+    reader: Option<SegmentReader>,
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> SpillQueue<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    /// Open the [`SpillQueue`] in the provided directory, creating it if required, and
+    /// recovering the events of any existing segment files.
+    pub fn open<P>(dir: P, config: SpillConfig) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        let mut segments = std::fs::read_dir(&dir)?
+            .filter_map(|entry| segment_id(&entry.ok()?.path()))
+            .collect::<Vec<_>>();
+        segments.sort_unstable();
+
+        let len = segments
+            .iter()
+            .map(|id| count_events(&segment_path(&dir, *id)))
+            .sum::<io::Result<usize>>()?;
+
+        Ok(Self {
+            dir,
+            config,
+            segments: segments.into(),
+            writer: None,
+            reader: None,
+            len,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Number of events in the [`SpillQueue`].
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determine if the [`SpillQueue`] contains no events.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append an event to the back of the [`SpillQueue`].
+    pub fn push(&mut self, event: &T) -> io::Result<()> {
+        let mut line = serde_json::to_vec(event)?;
+        line.push(b'\n');
+
+        match &self.writer {
+            Some(writer) if writer.bytes < self.config.max_segment_bytes => {}
+            _ => self.roll()?,
+        }
+
+        let writer = self
+            .writer
+            .as_mut()
+            .expect("SpillQueue segment writer rolled");
+        writer.writer.write_all(&line)?;
+        writer.writer.flush()?;
+        if self.config.sync {
+            writer.writer.get_ref().sync_data()?;
+        }
+        writer.bytes += line.len() as u64;
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove the event at the front of the [`SpillQueue`], if any.
+    ///
+    /// Events that can no longer be decoded (eg/ partially written before a crash) are skipped.
+    pub fn pop(&mut self) -> io::Result<Option<T>> {
+        loop {
+            if self.reader.is_none() {
+                let Some(id) = self.segments.front().copied() else {
+                    return Ok(None);
+                };
+                self.reader = Some(SegmentReader {
+                    id,
+                    reader: BufReader::new(File::open(segment_path(&self.dir, id))?),
+                });
+            }
+
+            let reader = self
+                .reader
+                .as_mut()
+                .expect("SpillQueue segment reader opened");
+            let mut line = String::new();
+            let bytes = reader.reader.read_line(&mut line)?;
+
+            if bytes > 0 {
+                self.len = self.len.saturating_sub(1);
+
+                // Partial lines are only left by a crash mid-write
+                if !line.ends_with('\n') {
+                    warn!(segment = reader.id, "skipping partially spilled event");
+                    continue;
+                }
+
+                match serde_json::from_str(&line) {
+                    Ok(event) => {
+                        self.compact()?;
+                        return Ok(Some(event));
+                    }
+                    Err(error) => {
+                        warn!(%error, segment = reader.id, "skipping undecodable spilled event");
+                        continue;
+                    }
+                }
+            }
+
+            // Active segment drained: wait for more events to be pushed
+            let id = reader.id;
+            if self.writer.as_ref().is_some_and(|writer| writer.id == id) {
+                return Ok(None);
+            }
+
+            self.remove_front()?;
+        }
+    }
+
+    /// Start a new segment file to append events to.
+    fn roll(&mut self) -> io::Result<()> {
+        let id = self
+            .segments
+            .back()
+            .map_or(0, |id| id + 1)
+            .max(self.writer.as_ref().map_or(0, |writer| writer.id + 1));
+
+        let file = OpenOptions::new()
+            .create_new(true)
+            .append(true)
+            .open(segment_path(&self.dir, id))?;
+
+        self.segments.push_back(id);
+        self.writer = Some(SegmentWriter {
+            id,
+            writer: BufWriter::new(file),
+            bytes: 0,
+        });
+        Ok(())
+    }
+
+    /// Delete every segment once the [`SpillQueue`] has been fully drained, so an idle queue
+    /// occupies no disk space.
+    fn compact(&mut self) -> io::Result<()> {
+        if !self.is_empty() {
+            return Ok(());
+        }
+
+        self.writer = None;
+        while !self.segments.is_empty() {
+            self.remove_front()?;
+        }
+        Ok(())
+    }
+
+    /// Delete the drained front segment file.
+    fn remove_front(&mut self) -> io::Result<()> {
+        self.reader = None;
+        if let Some(id) = self.segments.pop_front() {
+            match std::fs::remove_file(segment_path(&self.dir, id)) {
+                Err(error) if error.kind() != ErrorKind::NotFound => return Err(error),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path of the segment file with the provided id.
+fn segment_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("{SEGMENT_PREFIX}{id:020}.{SEGMENT_EXTENSION}"))
+}
+
+/// Parse the id of a segment file path, if it is a segment file.
+fn segment_id(path: &Path) -> Option<u64> {
+    if path.extension()?.to_str()? != SEGMENT_EXTENSION {
+        return None;
+    }
+    path.file_stem()?
+        .to_str()?
+        .strip_prefix(SEGMENT_PREFIX)?
+        .parse()
+        .ok()
+}
+
+/// Count the complete events of a segment file.
+fn count_events(path: &Path) -> io::Result<usize> {
+    BufReader::new(File::open(path)?)
+        .split(b'\n')
+        .try_fold(0, |count, line| Ok(count + usize::from(!line?.is_empty())))
+}
+
+/// At-least-once delivery stage that forwards every event received on the `event_rx` to the
+/// bounded `sink_tx`, spilling events to the [`SpillQueue`] whenever the sink lags (ie/ is full)
+/// or has been dropped (eg/ a downstream sink restart), and draining them in order once the sink
+/// has capacity.
+///
+/// Events left in the [`SpillQueue`] when the `sink_tx` receiver is dropped remain on disk, to
+/// be drained by the next process that opens the same [`SpillQueue`] directory.
+pub async fn spill_buffer<T>(
+    mut event_rx: mpsc::UnboundedReceiver<T>,
+    sink_tx: mpsc::Sender<T>,
+    mut queue: SpillQueue<T>,
+) -> io::Result<()>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut event_rx_open = true;
+
+    loop {
+        if sink_tx.is_closed() {
+            break;
+        }
+
+        tokio::select! {
+            // Drain spilled events first to preserve ordering
+            permit = sink_tx.reserve(), if !queue.is_empty() => match (permit, queue.pop()?) {
+                (Ok(permit), Some(event)) => permit.send(event),
+                (Ok(_), None) => {}
+                (Err(_), _) => break,
+            },
+            event = event_rx.recv(), if event_rx_open => match event {
+                Some(event) if queue.is_empty() => match sink_tx.try_send(event) {
+                    Ok(()) => {}
+                    Err(mpsc::error::TrySendError::Full(event)) => {
+                        debug!(spilled = queue.len() + 1, "sink lagging - spilling events to disk");
+                        queue.push(&event)?;
+                    }
+                    Err(mpsc::error::TrySendError::Closed(event)) => {
+                        queue.push(&event)?;
+                        break;
+                    }
+                },
+                Some(event) => queue.push(&event)?,
+                None => event_rx_open = false,
+            },
+            else => break,
+        }
+    }
+
+    // Sink dropped: spill the remaining events so they survive for the next sink
+    if event_rx_open {
+        warn!("sink dropped - spilling remaining events to disk");
+        while let Some(event) = event_rx.recv().await {
+            queue.push(&event)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("barter-data-spill-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_spill_queue() {
+        let dir = dir("queue");
+        let config = SpillConfig {
+            max_segment_bytes: 8,
+            sync: false,
+        };
+
+        let mut queue = SpillQueue::<u64>::open(&dir, config).unwrap();
+        for event in 0..5 {
+            queue.push(&event).unwrap();
+        }
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.pop().unwrap(), Some(0));
+        assert_eq!(queue.pop().unwrap(), Some(1));
+        drop(queue);
+
+        // Re-opened queue delivers the undrained segments at least once
+        let mut queue = SpillQueue::<u64>::open(&dir, config).unwrap();
+        let mut actual = Vec::new();
+        while let Some(event) = queue.pop().unwrap() {
+            actual.push(event);
+        }
+        assert!(actual.ends_with(&[2, 3, 4]), "{actual:?}");
+        assert!(queue.is_empty());
+
+        // Fully drained queue removes every segment file
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        queue.push(&5).unwrap();
+        assert_eq!(queue.pop().unwrap(), Some(5));
+        assert_eq!(queue.pop().unwrap(), None);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_spill_buffer_lagging_sink() {
+        let dir = dir("buffer");
+        let queue = SpillQueue::<u64>::open(&dir, SpillConfig::default()).unwrap();
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (sink_tx, mut sink_rx) = mpsc::channel(2);
+
+        for event in 0..100 {
+            event_tx.send(event).unwrap();
+        }
+        drop(event_tx);
+
+        let buffer = tokio::spawn(spill_buffer(event_rx, sink_tx, queue));
+
+        let mut actual = Vec::new();
+        while let Some(event) = sink_rx.recv().await {
+            actual.push(event);
+        }
+
+        buffer.await.unwrap().unwrap();
+        assert_eq!(actual, (0..100).collect::<Vec<_>>());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,483 @@
+use crate::{
+    event::MarketEvent,
+    exchange::{Connector, ExchangeId},
+    subscription::{book::OrderBook, Subscription},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Current version of the persisted [`SubscriptionState`] format.
+pub const STATE_VERSION: u32 = 1;
+
+/// Default minimum duration between [`OrderBook`] checkpoint saves of the [`checkpoint_books`]
+/// stage.
+pub const DEFAULT_CHECKPOINT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Persisted set of active [`Subscription`]s, grouped by connection, and the last
+/// [`BookCheckpoint`] of each exchange [`OrderBook`].
+///
+/// A restarted process loads the [`SubscriptionState`] to resubscribe identically (see
+/// [`Self::connections`]), and to seed each local [`OrderBook`] from it's [`BookCheckpoint`]
+/// while the exchange resynchronises, knowing the exact data loss window.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SubscriptionState {
+    pub version: u32,
+    pub connections: Vec<ConnectionState>,
+    pub books: Vec<BookCheckpoint>,
+}
+
+impl Default for SubscriptionState {
+    fn default() -> Self {
+        Self {
+            version: STATE_VERSION,
+            connections: Vec::new(),
+            books: Vec::new(),
+        }
+    }
+}
+
+/// [`Subscription`]s actioned on a single exchange connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConnectionState {
+    pub exchange: ExchangeId,
+    pub subscriptions: Vec<PersistedSubscription>,
+}
+
+/// Persisted [`Subscription`] with a serialised [`SubKind`](crate::subscription::SubKind).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PersistedSubscription {
+    #[serde(flatten)]
+    pub instrument: Instrument,
+    pub kind: serde_json::Value,
+}
+
+/// Last [`OrderBook`] snapshot of an exchange [`Instrument`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BookCheckpoint {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    pub book: OrderBook,
+}
+
+impl SubscriptionState {
+    /// Load the [`SubscriptionState`] persisted at the provided path, or an empty
+    /// [`SubscriptionState`] if none exists.
+    pub fn load<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+
+        let state = serde_json::from_reader::<_, Self>(BufReader::new(file))?;
+        if state.version > STATE_VERSION {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "SubscriptionState version {} is newer than supported {STATE_VERSION}",
+                    state.version
+                ),
+            ));
+        }
+
+        Ok(state)
+    }
+
+    /// Persist the [`SubscriptionState`] to the provided path, replacing the previous state
+    /// atomically so a crash mid-write never leaves a partial file.
+    pub fn save<P>(&self, path: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let temp = path.with_extension("tmp");
+
+        let mut writer = BufWriter::new(File::create(&temp)?);
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+
+        std::fs::rename(temp, path)
+    }
+
+    /// Record the [`Subscription`]s actioned on a single exchange connection, ignoring
+    /// connections that have already been recorded (eg/ after a warm restart).
+    pub fn record<Exchange, Kind>(&mut self, subscriptions: &[Subscription<Exchange, Kind>])
+    where
+        Exchange: Connector,
+        Kind: Serialize,
+    {
+        let connection = ConnectionState {
+            exchange: Exchange::ID,
+            subscriptions: subscriptions
+                .iter()
+                .filter_map(|subscription| {
+                    Some(PersistedSubscription {
+                        instrument: subscription.instrument.clone(),
+                        kind: serde_json::to_value(&subscription.kind).ok()?,
+                    })
+                })
+                .collect(),
+        };
+
+        if !self.connections.contains(&connection) {
+            self.connections.push(connection);
+        }
+    }
+
+    /// [`Subscription`]s of each recorded connection to the provided exchange with a
+    /// [`SubKind`](crate::subscription::SubKind) of type `Kind`, in the order they were recorded.
+    ///
+    /// Pass each connection to a [`StreamBuilder::subscribe`](super::builder::StreamBuilder::subscribe)
+    /// call of the exchange's [`Connector`] to resubscribe identically.
+    pub fn connections<Kind>(
+        &self,
+        exchange: ExchangeId,
+    ) -> Vec<Vec<Subscription<ExchangeId, Kind>>>
+    where
+        Kind: DeserializeOwned,
+    {
+        self.connections
+            .iter()
+            .filter(|connection| connection.exchange == exchange)
+            .filter_map(|connection| {
+                connection
+                    .subscriptions
+                    .iter()
+                    .map(|subscription| {
+                        let kind = Kind::deserialize(&subscription.kind).ok()?;
+                        Some(Subscription::from((
+                            exchange,
+                            subscription.instrument.clone(),
+                            kind,
+                        )))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect()
+    }
+
+    /// Upsert the [`BookCheckpoint`] of an exchange [`Instrument`] with the provided
+    /// [`MarketEvent<OrderBook>`] snapshot.
+    pub fn checkpoint(&mut self, event: &MarketEvent<OrderBook>) {
+        let checkpoint = BookCheckpoint {
+            exchange: event.exchange.clone(),
+            instrument: event.instrument.clone(),
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            book: event.kind.clone(),
+        };
+
+        match self.books.iter_mut().find(|book| {
+            book.exchange == checkpoint.exchange && book.instrument == checkpoint.instrument
+        }) {
+            Some(book) => *book = checkpoint,
+            None => self.books.push(checkpoint),
+        }
+    }
+
+    /// Get the last [`BookCheckpoint`] of an exchange [`Instrument`].
+    pub fn book(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<&BookCheckpoint> {
+        let exchange = Exchange::from(exchange);
+        self.books
+            .iter()
+            .find(|book| book.exchange == exchange && &book.instrument == instrument)
+    }
+}
+
+/// Shared handle to a [`SubscriptionState`] persisted at a path.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::{binance::spot::BinanceSpot, ExchangeId},
+///     streams::{state::StateStore, Streams},
+///     subscription::trade::PublicTrades,
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let store = StateStore::open("subscriptions.json").unwrap();
+///
+///     // Resubscribe identically to the connections recorded before a restart
+///     let connections = store.state().connections::<PublicTrades>(ExchangeId::BinanceSpot);
+///
+///     let mut builder = Streams::<PublicTrades>::builder();
+///     for connection in connections {
+///         builder = builder.subscribe(store.record(connection.into_iter().map(|subscription| {
+///             (BinanceSpot::default(), subscription.instrument, subscription.kind)
+///         })));
+///     }
+///
+///     // Record new connections before subscribing
+///     let builder = builder.subscribe(store.record([
+///         (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades),
+///     ]));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StateStore {
+    path: PathBuf,
+    state: Arc<Mutex<SubscriptionState>>,
+}
+
+impl StateStore {
+    /// Open the [`StateStore`] at the provided path, loading any persisted
+    /// [`SubscriptionState`].
+    pub fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let state = SubscriptionState::load(&path)?;
+        Ok(Self {
+            path,
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    /// Clone of the current [`SubscriptionState`].
+    pub fn state(&self) -> SubscriptionState {
+        self.lock().clone()
+    }
+
+    /// Record & persist the [`Subscription`]s of a single exchange connection, returning them to
+    /// be passed to a [`StreamBuilder`](super::builder::StreamBuilder).
+    ///
+    /// Failing to persist is logged rather than preventing the subscription.
+    pub fn record<SubIter, Sub, Exchange, Kind>(
+        &self,
+        subscriptions: SubIter,
+    ) -> Vec<Subscription<Exchange, Kind>>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: Connector,
+        Kind: Serialize,
+    {
+        let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+
+        let mut state = self.lock();
+        state.record(&subscriptions);
+        if let Err(error) = state.save(&self.path) {
+            warn!(%error, path = ?self.path, "failed to persist SubscriptionState");
+        }
+
+        subscriptions
+    }
+
+    /// Upsert the [`BookCheckpoint`] of an exchange [`Instrument`] without persisting it.
+    pub fn checkpoint(&self, event: &MarketEvent<OrderBook>) {
+        self.lock().checkpoint(event)
+    }
+
+    /// Persist the current [`SubscriptionState`].
+    pub fn save(&self) -> io::Result<()> {
+        self.lock().save(&self.path)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SubscriptionState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Forward every [`MarketEvent<OrderBook>`] received on the `book_rx` to the `book_tx`,
+/// checkpointing each into the [`StateStore`] and persisting it at most once per `interval`.
+///
+/// The final checkpoints are persisted once the `book_rx` is exhausted.
+pub async fn checkpoint_books(
+    mut book_rx: mpsc::UnboundedReceiver<MarketEvent<OrderBook>>,
+    book_tx: mpsc::UnboundedSender<MarketEvent<OrderBook>>,
+    store: StateStore,
+    interval: Duration,
+) {
+    let mut last_save = Instant::now();
+
+    while let Some(book) = book_rx.recv().await {
+        store.checkpoint(&book);
+
+        if last_save.elapsed() >= interval {
+            if let Err(error) = store.save() {
+                warn!(%error, "failed to persist OrderBook checkpoints");
+            }
+            last_save = Instant::now();
+        }
+
+        if book_tx.send(book).is_err() {
+            debug!("OrderBook receiver dropped - stopping OrderBook checkpoints");
+            break;
+        }
+    }
+
+    if let Err(error) = store.save() {
+        warn!(%error, "failed to persist OrderBook checkpoints");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::MonotonicTime,
+        exchange::{binance::spot::BinanceSpot, okx::Okx},
+        fixture::market_event,
+        subscription::{
+            book::{Level, OrderBookSide, OrderBooksL2},
+            trade::PublicTrades,
+        },
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    fn path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "barter-data-state-{name}-{}.json",
+            std::process::id()
+        ))
+    }
+
+    fn book(price: f64) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            exchange_time: DateTime::<Utc>::MIN_UTC,
+            received_time: DateTime::<Utc>::MIN_UTC,
+            received_monotonic: MonotonicTime::default(),
+            ..market_event(OrderBook {
+                last_update_time: DateTime::<Utc>::MIN_UTC,
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(price, 1.0)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(price + 1.0, 1.0)]),
+                sequence: None,
+                delta: false,
+            })
+        }
+    }
+
+    #[test]
+    fn test_state_store_warm_restart() {
+        let path = path("warm-restart");
+        let _ = std::fs::remove_file(&path);
+
+        let store = StateStore::open(&path).unwrap();
+        store.record([
+            (
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ),
+            (
+                BinanceSpot::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ),
+        ]);
+        store.record([(Okx, "btc", "usdt", InstrumentKind::Perpetual, PublicTrades)]);
+        store.record([(
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            OrderBooksL2::default(),
+        )]);
+        store.checkpoint(&book(100.0));
+        store.checkpoint(&book(101.0));
+        store.save().unwrap();
+
+        // Restarted process loads the persisted SubscriptionState
+        let restarted = StateStore::open(&path).unwrap();
+        let state = restarted.state();
+        assert_eq!(state, store.state());
+
+        let trades = state.connections::<PublicTrades>(ExchangeId::BinanceSpot);
+        assert_eq!(
+            trades,
+            vec![vec![
+                Subscription::from((
+                    ExchangeId::BinanceSpot,
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades
+                )),
+                Subscription::from((
+                    ExchangeId::BinanceSpot,
+                    "eth",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades
+                )),
+            ]]
+        );
+        assert_eq!(
+            state
+                .connections::<OrderBooksL2>(ExchangeId::BinanceSpot)
+                .len(),
+            1
+        );
+        assert_eq!(state.connections::<PublicTrades>(ExchangeId::Okx).len(), 1);
+
+        // Resubscribing the recorded connections does not duplicate them
+        restarted.record([
+            (
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ),
+            (
+                BinanceSpot::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ),
+        ]);
+        assert_eq!(restarted.state().connections.len(), 3);
+
+        let checkpoint = state
+            .book(
+                ExchangeId::BinanceSpot,
+                &Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            )
+            .unwrap();
+        assert_eq!(state.books.len(), 1);
+        assert_eq!(checkpoint.book, book(101.0).kind);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_subscription_state_load_newer_version() {
+        let path = path("newer-version");
+        let state = SubscriptionState {
+            version: STATE_VERSION + 1,
+            ..SubscriptionState::default()
+        };
+        state.save(&path).unwrap();
+
+        let error = SubscriptionState::load(&path).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
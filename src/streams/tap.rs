@@ -0,0 +1,210 @@
+use super::{bandwidth::ConnectionMeter, limits::FrameLimits};
+use crate::{exchange::ExchangeId, transport::TransportStream};
+use barter_integration::protocol::websocket::{WsError, WsMessage};
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+/// Default number of [`RawMessage`]s buffered by a [`RawTap`] before lagging receivers start
+/// missing messages.
+pub const DEFAULT_RAW_TAP_CAPACITY: usize = 10_000;
+
+/// Raw text or binary frame received from an exchange WebSocket connection, before any
+/// deserialisation or normalisation.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RawMessage {
+    pub exchange: ExchangeId,
+    pub received_time: DateTime<Utc>,
+    pub payload: RawPayload,
+}
+
+/// Payload of a [`RawMessage`] WebSocket frame.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum RawPayload {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl RawPayload {
+    /// Raw payload bytes, regardless of the frame type.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            RawPayload::Text(text) => text.as_bytes(),
+            RawPayload::Binary(bytes) => bytes,
+        }
+    }
+}
+
+/// Opt-in broadcast of every [`RawMessage`] received by the exchange connections configured with
+/// it, alongside the normalised [`MarketEvent<T>`](crate::event::MarketEvent)s (eg/ to archive
+/// lossless raw data).
+///
+/// A [`RawTap`] is attached to connections via
+/// [`StreamConfig::with_raw_tap`](super::config::StreamConfig::with_raw_tap), and can be shared
+/// across many connections. Control frames (eg/ pings) are not tapped.
+///
+/// Tapping never applies backpressure to the normalised stream: if no receivers exist the
+/// [`RawMessage`] is dropped, and receivers that lag more than the capacity behind miss the
+/// oldest [`RawMessage`]s (see [`broadcast::error::RecvError::Lagged`]).
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::streams::{
+///     config::StreamConfig,
+///     tap::{RawTap, DEFAULT_RAW_TAP_CAPACITY},
+/// };
+///
+/// let tap = RawTap::new(DEFAULT_RAW_TAP_CAPACITY);
+/// let mut raw_rx = tap.subscribe();
+///
+/// let config = StreamConfig::default().with_raw_tap(tap);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RawTap {
+    tx: broadcast::Sender<RawMessage>,
+}
+
+impl RawTap {
+    /// Construct a new [`Self`] that buffers up to `capacity` [`RawMessage`]s per receiver.
+    ///
+    /// Panics if the `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Self { tx }
+    }
+
+    /// Construct a new [`broadcast::Receiver`] of every [`RawMessage`] tapped after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<RawMessage> {
+        self.tx.subscribe()
+    }
+
+    /// Broadcast the text or binary `message` received from the `exchange` to every receiver.
+    pub fn send(&self, exchange: ExchangeId, message: &WsMessage) {
+        let payload = match message {
+            WsMessage::Text(text) => RawPayload::Text(text.clone()),
+            WsMessage::Binary(bytes) => RawPayload::Binary(bytes.clone()),
+            _ => return,
+        };
+
+        // Error only indicates there are currently no receivers
+        let _ = self.tx.send(RawMessage {
+            exchange,
+            received_time: Utc::now(),
+            payload,
+        });
+    }
+}
+
+impl PartialEq for RawTap {
+    fn eq(&self, other: &Self) -> bool {
+        self.tx.same_channel(&other.tx)
+    }
+}
+
+impl Eq for RawTap {}
+
+/// [`TransportStream`] that sends every received frame to an optional [`RawTap`], and records it
+/// with an optional [`ConnectionMeter`], before yielding it.
+///
+/// If [`FrameLimits`] are provided, frames that exceed them are yielded as an error rather than
+/// being tapped.
+#[derive(Debug)]
+pub struct TappedWsStream {
+    pub exchange: ExchangeId,
+    pub stream: TransportStream,
+    pub tap: Option<RawTap>,
+    pub meter: Option<ConnectionMeter>,
+    pub limits: Option<FrameLimits>,
+}
+
+impl TappedWsStream {
+    /// Construct a new [`Self`] that taps the `stream` if a [`RawTap`] is provided.
+    pub fn new(exchange: ExchangeId, stream: TransportStream, tap: Option<RawTap>) -> Self {
+        Self {
+            exchange,
+            stream,
+            tap,
+            meter: None,
+            limits: None,
+        }
+    }
+
+    /// Record every received frame with the provided [`ConnectionMeter`], if any.
+    pub fn with_meter(mut self, meter: Option<ConnectionMeter>) -> Self {
+        self.meter = meter;
+        self
+    }
+
+    /// Check every received frame against the provided [`FrameLimits`], if any.
+    pub fn with_limits(mut self, limits: Option<FrameLimits>) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl Stream for TappedWsStream {
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.stream.poll_next_unpin(cx);
+
+        if let Poll::Ready(Some(Ok(message))) = &poll {
+            if let Some(meter) = &self.meter {
+                meter.record(message);
+            }
+            if let Some(Err(error)) = self.limits.map(|limits| limits.guard(message)) {
+                warn!(exchange = %self.exchange, %error, "received frame exceeds FrameLimits");
+                return Poll::Ready(Some(Err(error)));
+            }
+            if let Some(tap) = &self.tap {
+                tap.send(self.exchange, message);
+            }
+        }
+
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_tap_send() {
+        let tap = RawTap::new(DEFAULT_RAW_TAP_CAPACITY);
+        let mut raw_rx = tap.subscribe();
+
+        let messages = vec![
+            WsMessage::Text("{\"e\":\"trade\"}".to_owned()),
+            WsMessage::Ping(vec![1]),
+            WsMessage::Binary(vec![1, 2, 3]),
+        ];
+        for message in &messages {
+            tap.send(ExchangeId::BinanceSpot, message);
+        }
+
+        let expected = vec![
+            RawPayload::Text("{\"e\":\"trade\"}".to_owned()),
+            RawPayload::Binary(vec![1, 2, 3]),
+        ];
+
+        for (index, expected) in expected.into_iter().enumerate() {
+            let actual = raw_rx.try_recv().unwrap();
+            assert_eq!(
+                actual.exchange,
+                ExchangeId::BinanceSpot,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(actual.payload, expected, "TC{} failed", index);
+        }
+
+        // Control frames are not tapped
+        assert!(raw_rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,434 @@
+use crate::{
+    event::{DataKind, MarketEvent, MonotonicTime, Origin},
+    subscription::{
+        book::{Level, OrderBookL1},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default minimum number of distinct price levels a burst of same side trades must consume to
+/// be classified as a [`Sweep`].
+pub const DEFAULT_MIN_SWEEP_LEVELS: usize = 2;
+
+/// Configuration of a [`TapeClassifier`] stage.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TapeConfig {
+    /// Maximum `exchange_time` gap between consecutive trades of the same burst.
+    pub sweep_window: Duration,
+    /// Minimum number of distinct price levels a burst must consume to be a [`Sweep`].
+    pub min_sweep_levels: usize,
+}
+
+impl Default for TapeConfig {
+    fn default() -> Self {
+        Self {
+            sweep_window: Duration::milliseconds(10),
+            min_sweep_levels: DEFAULT_MIN_SWEEP_LEVELS,
+        }
+    }
+}
+
+/// Burst of same side [`PublicTrade`]s that consumed multiple price levels in quick succession.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Sweep {
+    /// Aggressor [`Side`] of the sweep.
+    pub side: Side,
+    pub start_price: f64,
+    pub end_price: f64,
+    /// Number of distinct price levels consumed.
+    pub levels: usize,
+    /// Total amount traded.
+    pub amount: f64,
+    /// Number of [`PublicTrade`]s in the sweep.
+    pub trades: usize,
+    pub start_time: DateTime<Utc>,
+}
+
+/// Best bid or ask that was replenished at the same price after more than it's displayed amount
+/// was executed, indicating a likely iceberg order.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct IcebergRefill {
+    /// [`Side`] of the resting (ie/ iceberg) order.
+    pub side: Side,
+    pub price: f64,
+    /// Amount executed against the level since the previous quote.
+    pub executed: f64,
+    /// Amount displayed at the level before executions.
+    pub displayed: f64,
+    /// Amount displayed at the level after the refill.
+    pub refilled: f64,
+}
+
+/// Trade tape signal detected by a [`TapeClassifier`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum TapeSignal {
+    Sweep(Sweep),
+    IcebergRefill(IcebergRefill),
+}
+
+/// Output of the [`classify_tape`] stage.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Tape {
+    Event(MarketEvent<DataKind>),
+    Signal(MarketEvent<TapeSignal>),
+}
+
+/// Optional analytics stage that detects likely [`Sweep`]s (multi-level aggressions) and
+/// [`IcebergRefill`]s from [`PublicTrade`]s & [`OrderBookL1`]s.
+///
+/// A [`Sweep`] is emitted when the burst of same side trades it describes ends, ie/ when the next
+/// trade of the exchange [`Instrument`] does not extend it (see [`Self::flush`] for bursts still
+/// pending at the end of a stream).
+///
+/// Apply this stage to a combined [`MarketEvent<DataKind>`] stream containing both the
+/// [`PublicTrade`]s and [`OrderBookL1`]s of the affected instruments.
+#[derive(Debug, Default)]
+pub struct TapeClassifier {
+    config: TapeConfig,
+    states: HashMap<(Exchange, Instrument), TapeState>,
+}
+
+/// Prevailing quote, executions at that quote & current trade burst of an exchange
+/// [`Instrument`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+struct TapeState {
+    quote: Option<(Level, Level)>,
+    executed_bid: f64,
+    executed_ask: f64,
+    burst: Option<Burst>,
+}
+
+/// Burst of consecutive same side trades that may be a [`Sweep`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Burst {
+    sweep: Sweep,
+    last_price: f64,
+    last_time: DateTime<Utc>,
+    last_received: (DateTime<Utc>, MonotonicTime, Origin),
+}
+
+impl Burst {
+    fn new<T>(event: &MarketEvent<T>, trade: &PublicTrade) -> Self {
+        Self {
+            sweep: Sweep {
+                side: trade.side,
+                start_price: trade.price,
+                end_price: trade.price,
+                levels: 1,
+                amount: trade.amount,
+                trades: 1,
+                start_time: event.exchange_time,
+            },
+            last_price: trade.price,
+            last_time: event.exchange_time,
+            last_received: (event.received_time, event.received_monotonic, event.origin),
+        }
+    }
+
+    /// Extend the [`Burst`] with a trade if it is the same side, within the `window` of the last
+    /// trade & does not reverse the direction of the aggression.
+    fn extend<T>(&mut self, event: &MarketEvent<T>, trade: &PublicTrade, window: Duration) -> bool {
+        let continues = match self.sweep.side {
+            Side::Buy => trade.price >= self.last_price,
+            Side::Sell => trade.price <= self.last_price,
+        };
+
+        if trade.side != self.sweep.side
+            || !continues
+            || event.exchange_time - self.last_time > window
+        {
+            return false;
+        }
+
+        if trade.price != self.last_price {
+            self.sweep.levels += 1;
+        }
+        self.sweep.end_price = trade.price;
+        self.sweep.amount += trade.amount;
+        self.sweep.trades += 1;
+        self.last_price = trade.price;
+        self.last_time = event.exchange_time;
+        self.last_received = (event.received_time, event.received_monotonic, event.origin);
+        true
+    }
+}
+
+impl TapeClassifier {
+    /// Construct a new [`Self`] using the provided [`TapeConfig`].
+    pub fn new(config: TapeConfig) -> Self {
+        Self {
+            config,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Process a [`MarketEvent<DataKind>`], returning any [`TapeSignal`]s it completes.
+    pub fn process(&mut self, event: &MarketEvent<DataKind>) -> Vec<MarketEvent<TapeSignal>> {
+        match &event.kind {
+            DataKind::Trade(trade) => self.process_trade(event, trade).into_iter().collect(),
+            DataKind::OrderBookL1(book) => self.process_quote(event, book),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Flush the pending trade burst of every exchange [`Instrument`], returning those that are
+    /// [`Sweep`]s.
+    pub fn flush(&mut self) -> Vec<MarketEvent<TapeSignal>> {
+        let min_levels = self.config.min_sweep_levels;
+        self.states
+            .iter_mut()
+            .filter_map(|((exchange, instrument), state)| {
+                sweep_event(exchange, instrument, state.burst.take()?, min_levels)
+            })
+            .collect()
+    }
+
+    fn process_trade<T>(
+        &mut self,
+        event: &MarketEvent<T>,
+        trade: &PublicTrade,
+    ) -> Option<MarketEvent<TapeSignal>> {
+        let TapeConfig {
+            sweep_window,
+            min_sweep_levels,
+        } = self.config;
+        let state = self.state(&event.exchange, &event.instrument);
+
+        // Accumulate executions against the prevailing quote
+        if let Some((best_bid, best_ask)) = state.quote {
+            match trade.side {
+                Side::Buy if best_ask.eq_price(trade.price) => state.executed_ask += trade.amount,
+                Side::Sell if best_bid.eq_price(trade.price) => state.executed_bid += trade.amount,
+                _ => {}
+            }
+        }
+
+        if let Some(burst) = &mut state.burst {
+            if burst.extend(event, trade, sweep_window) {
+                return None;
+            }
+        }
+
+        let ended = state.burst.replace(Burst::new(event, trade))?;
+        sweep_event(&event.exchange, &event.instrument, ended, min_sweep_levels)
+    }
+
+    fn process_quote<T>(
+        &mut self,
+        event: &MarketEvent<T>,
+        book: &OrderBookL1,
+    ) -> Vec<MarketEvent<TapeSignal>> {
+        let state = self.state(&event.exchange, &event.instrument);
+        let previous = state.quote.replace((book.best_bid, book.best_ask));
+        let executed = (
+            std::mem::take(&mut state.executed_bid),
+            std::mem::take(&mut state.executed_ask),
+        );
+
+        let Some((previous_bid, previous_ask)) = previous else {
+            return Vec::new();
+        };
+
+        [
+            refill(Side::Buy, previous_bid, book.best_bid, executed.0),
+            refill(Side::Sell, previous_ask, book.best_ask, executed.1),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|refill| MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            received_monotonic: event.received_monotonic,
+            origin: event.origin,
+            exchange: event.exchange.clone(),
+            instrument: event.instrument.clone(),
+            kind: TapeSignal::IcebergRefill(refill),
+        })
+        .collect()
+    }
+
+    fn state(&mut self, exchange: &Exchange, instrument: &Instrument) -> &mut TapeState {
+        self.states
+            .entry((exchange.clone(), instrument.clone()))
+            .or_default()
+    }
+}
+
+/// Construct a [`TapeSignal::Sweep`] [`MarketEvent`] from an ended [`Burst`], if it consumed at
+/// least `min_levels` distinct price levels.
+fn sweep_event(
+    exchange: &Exchange,
+    instrument: &Instrument,
+    burst: Burst,
+    min_levels: usize,
+) -> Option<MarketEvent<TapeSignal>> {
+    (burst.sweep.levels >= min_levels).then(|| MarketEvent {
+        exchange_time: burst.last_time,
+        received_time: burst.last_received.0,
+        received_monotonic: burst.last_received.1,
+        origin: burst.last_received.2,
+        exchange: exchange.clone(),
+        instrument: instrument.clone(),
+        kind: TapeSignal::Sweep(burst.sweep),
+    })
+}
+
+/// Detect an [`IcebergRefill`] of the resting `side` level, which is replenished at the same
+/// price after executions consumed at least it's previously displayed amount.
+fn refill(side: Side, previous: Level, current: Level, executed: f64) -> Option<IcebergRefill> {
+    (executed > 0.0
+        && executed >= previous.amount
+        && current.amount > 0.0
+        && current.eq_price(previous.price))
+    .then_some(IcebergRefill {
+        side,
+        price: previous.price,
+        executed,
+        displayed: previous.amount,
+        refilled: current.amount,
+    })
+}
+
+/// Forward every [`MarketEvent<DataKind>`] received on the `event_rx` as a [`Tape::Event`],
+/// preceded by any [`Tape::Signal`]s detected by a [`TapeClassifier`].
+///
+/// Pending [`Sweep`]s are flushed once the `event_rx` is exhausted.
+pub async fn classify_tape(
+    mut event_rx: mpsc::UnboundedReceiver<MarketEvent<DataKind>>,
+    tape_tx: mpsc::UnboundedSender<Tape>,
+    config: TapeConfig,
+) {
+    let mut classifier = TapeClassifier::new(config);
+
+    while let Some(event) = event_rx.recv().await {
+        let output = classifier
+            .process(&event)
+            .into_iter()
+            .map(Tape::Signal)
+            .chain(std::iter::once(Tape::Event(event)));
+
+        for tape in output {
+            if tape_tx.send(tape).is_err() {
+                debug!("Tape receiver dropped - stopping tape classification");
+                return;
+            }
+        }
+    }
+
+    for signal in classifier.flush() {
+        let _ = tape_tx.send(Tape::Signal(signal));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixture::market_event, subscription::trade::TradeId};
+    use chrono::TimeZone;
+
+    fn event(millis: i64, kind: DataKind) -> MarketEvent<DataKind> {
+        let time = Utc
+            .timestamp_millis_opt(1_672_531_200_000 + millis)
+            .unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            ..market_event(kind)
+        }
+    }
+
+    fn trade(millis: i64, side: Side, price: f64, amount: f64) -> MarketEvent<DataKind> {
+        event(
+            millis,
+            DataKind::Trade(PublicTrade::new(TradeId::None, price, amount, side)),
+        )
+    }
+
+    fn quote(millis: i64, bid: (f64, f64), ask: (f64, f64)) -> MarketEvent<DataKind> {
+        let time = Utc
+            .timestamp_millis_opt(1_672_531_200_000 + millis)
+            .unwrap();
+        event(
+            millis,
+            DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time,
+                best_bid: Level::from(bid),
+                best_ask: Level::from(ask),
+            }),
+        )
+    }
+
+    #[test]
+    fn test_tape_classifier() {
+        struct TestCase {
+            input: MarketEvent<DataKind>,
+            expected: Vec<TapeSignal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: initial quote
+                input: quote(0, (99.0, 1.0), (100.0, 2.0)),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: buy trade at best ask starts a burst
+                input: trade(1, Side::Buy, 100.0, 2.0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: buy trade at the next level within the window extends the burst
+                input: trade(2, Side::Buy, 101.0, 1.0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: sell trade ends the burst, emitting the two level sweep
+                input: trade(50, Side::Sell, 99.0, 0.5),
+                expected: vec![TapeSignal::Sweep(Sweep {
+                    side: Side::Buy,
+                    start_price: 100.0,
+                    end_price: 101.0,
+                    levels: 2,
+                    amount: 3.0,
+                    trades: 2,
+                    start_time: Utc.timestamp_millis_opt(1_672_531_200_001).unwrap(),
+                })],
+            },
+            TestCase {
+                // TC4: best ask refilled at the same price after executing it's displayed amount
+                input: quote(60, (99.0, 0.5), (100.0, 2.0)),
+                expected: vec![TapeSignal::IcebergRefill(IcebergRefill {
+                    side: Side::Sell,
+                    price: 100.0,
+                    executed: 2.0,
+                    displayed: 2.0,
+                    refilled: 2.0,
+                })],
+            },
+            TestCase {
+                // TC5: single level burst is not a sweep
+                input: trade(100, Side::Buy, 100.0, 1.0),
+                expected: vec![],
+            },
+        ];
+
+        let mut classifier = TapeClassifier::default();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = classifier
+                .process(&test.input)
+                .into_iter()
+                .map(|signal| signal.kind)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        assert!(classifier.flush().is_empty());
+    }
+}
@@ -0,0 +1,379 @@
+use super::manager::{ManagedStream, SubscriptionManager};
+use crate::{
+    event::MarketEvent,
+    exchange::{ExchangeId, StreamSelector},
+    ranking::VolumeRanking,
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use futures::{stream::SelectAll, Stream, StreamExt};
+use std::{
+    collections::BTreeSet,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{sync::mpsc, task::JoinHandle, time::MissedTickBehavior};
+use tracing::{info, warn};
+
+/// Default interval between re-evaluations of a [`UniverseRule`].
+pub const DEFAULT_UNIVERSE_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Rule that selects the [`Instrument`]s of a subscribed universe, re-evaluated periodically by
+/// [`subscribe_universe`].
+///
+/// Implemented for [`TopByVolume`], and for any async closure returning the selected
+/// [`Instrument`]s (eg/ every perpetual with an open interest above a threshold, fetched from a
+/// custom REST client).
+#[async_trait]
+pub trait UniverseRule
+where
+    Self: Send + Sync,
+{
+    /// Select the [`Instrument`]s that should currently be subscribed.
+    async fn evaluate(&self) -> Result<Vec<Instrument>, SocketError>;
+}
+
+#[async_trait]
+impl<F, Fut> UniverseRule for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Vec<Instrument>, SocketError>> + Send,
+{
+    async fn evaluate(&self) -> Result<Vec<Instrument>, SocketError> {
+        (self)().await
+    }
+}
+
+/// [`UniverseRule`] selecting the `n` exchange [`Instrument`]s quoted in `quote` with the
+/// highest rolling 24 hour volume, fetched via the exchange [`VolumeRanking`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TopByVolume<Exchange> {
+    pub quote: String,
+    pub n: usize,
+    phantom: PhantomData<Exchange>,
+}
+
+impl<Exchange> TopByVolume<Exchange> {
+    /// Construct a new [`Self`] selecting the top `n` [`Instrument`]s quoted in `quote`.
+    pub fn new<S>(quote: S, n: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            quote: quote.into(),
+            n,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Exchange> UniverseRule for TopByVolume<Exchange>
+where
+    Exchange: VolumeRanking + Send + Sync,
+{
+    async fn evaluate(&self) -> Result<Vec<Instrument>, SocketError> {
+        Exchange::top_by_volume(&self.quote, self.n).await
+    }
+}
+
+/// Subscribe to the universe of [`Instrument`]s selected by the [`UniverseRule`] via the
+/// [`SubscriptionManager`], re-evaluating the rule every `interval` and adding & removing
+/// [`Subscription`]s of the [`SubKind`] as the universe changes.
+///
+/// ### Notes
+/// - The rule is first evaluated immediately, and a failed evaluation keeps the current
+///   universe until the next `interval`.
+/// - Added [`Instrument`]s share one new connection per refresh. Removed [`Instrument`]s are
+///   released, closing their connection once it serves no referenced [`Subscription`].
+/// - [`Instrument`]s whose connection terminated are re-subscribed on the next refresh.
+/// - The refresh task is stopped when the returned [`UniverseStream`] is dropped.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::binance::futures::BinanceFuturesUsd,
+///     streams::{
+///         manager::SubscriptionManager,
+///         universe::{subscribe_universe, TopByVolume, DEFAULT_UNIVERSE_REFRESH_INTERVAL},
+///     },
+///     subscription::trade::PublicTrades,
+/// };
+/// use futures::StreamExt;
+///
+/// # async fn example() {
+/// // Trade the 10 most traded usdt perpetuals, refreshed hourly
+/// let manager = SubscriptionManager::<PublicTrades>::default();
+/// let mut universe = subscribe_universe(
+///     &manager,
+///     BinanceFuturesUsd::default(),
+///     PublicTrades,
+///     TopByVolume::<BinanceFuturesUsd>::new("usdt", 10),
+///     DEFAULT_UNIVERSE_REFRESH_INTERVAL,
+/// );
+///
+/// while let Some(trade) = universe.next().await {
+///     println!("{trade:?}");
+/// }
+/// # }
+/// ```
+pub fn subscribe_universe<Exchange, Kind, Rule>(
+    manager: &SubscriptionManager<Kind>,
+    exchange: Exchange,
+    kind: Kind,
+    rule: Rule,
+    interval: Duration,
+) -> UniverseStream<Kind>
+where
+    Exchange: StreamSelector<Kind> + Clone + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Unpin + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Rule: UniverseRule + 'static,
+{
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    let universe = Arc::new(Mutex::new(Vec::new()));
+
+    let handle = tokio::spawn(refresh_universe(
+        manager.clone(),
+        exchange,
+        kind,
+        rule,
+        interval,
+        Arc::clone(&universe),
+        event_tx,
+    ));
+
+    UniverseStream {
+        universe,
+        event_rx,
+        handle,
+    }
+}
+
+/// Refresh task of a [`UniverseStream`], forwarding the events of every [`ManagedStream`] of
+/// the universe to the `event_tx`.
+async fn refresh_universe<Exchange, Kind, Rule>(
+    manager: SubscriptionManager<Kind>,
+    exchange: Exchange,
+    kind: Kind,
+    rule: Rule,
+    interval: Duration,
+    universe: Arc<Mutex<Vec<Instrument>>>,
+    event_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
+) where
+    Exchange: StreamSelector<Kind> + Clone + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Unpin + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Rule: UniverseRule,
+{
+    let mut streams = SelectAll::<ManagedStream<Kind>>::new();
+    let mut refresh = tokio::time::interval(interval);
+    refresh.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = refresh.tick() => {
+                let target = match rule.evaluate().await {
+                    Ok(instruments) => instruments.into_iter().collect::<BTreeSet<_>>(),
+                    Err(error) => {
+                        warn!(exchange = %Exchange::ID, %error, "failed to evaluate UniverseRule, keeping current universe");
+                        continue;
+                    }
+                };
+
+                // Current universe of the ManagedStreams whose connections are still running
+                let current = streams
+                    .iter()
+                    .flat_map(ManagedStream::subscriptions)
+                    .map(|subscription| subscription.instrument.clone())
+                    .collect::<BTreeSet<_>>();
+
+                let (added, removed) = diff(&current, &target);
+
+                if !removed.is_empty() {
+                    let keys = removed
+                        .iter()
+                        .map(|instrument| Subscription::new(Exchange::ID, instrument.clone(), kind.clone()))
+                        .collect::<Vec<Subscription<ExchangeId, Kind>>>();
+
+                    for stream in streams.iter_mut() {
+                        stream.unsubscribe(&keys);
+                    }
+                    streams = streams
+                        .into_iter()
+                        .filter(|stream| !stream.subscriptions().is_empty())
+                        .collect();
+                }
+
+                if !added.is_empty() {
+                    let subscriptions = added
+                        .iter()
+                        .map(|instrument| Subscription::new(exchange.clone(), instrument.clone(), kind.clone()));
+
+                    match manager.subscribe(subscriptions) {
+                        Ok(stream) => streams.push(stream),
+                        Err(error) => {
+                            warn!(exchange = %Exchange::ID, %error, "failed to subscribe to added universe Instruments");
+                        }
+                    }
+                }
+
+                let instruments = streams
+                    .iter()
+                    .flat_map(ManagedStream::subscriptions)
+                    .map(|subscription| subscription.instrument.clone())
+                    .collect::<BTreeSet<_>>();
+
+                info!(
+                    exchange = %Exchange::ID,
+                    added = added.len(),
+                    removed = removed.len(),
+                    instruments = instruments.len(),
+                    "refreshed subscribed universe"
+                );
+
+                *universe.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+                    instruments.into_iter().collect();
+            }
+
+            Some(event) = streams.next(), if !streams.is_empty() => {
+                if event_tx.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Determine the [`Instrument`]s `added` to & `removed` from the `current` universe to reach
+/// the `target` universe.
+fn diff(
+    current: &BTreeSet<Instrument>,
+    target: &BTreeSet<Instrument>,
+) -> (Vec<Instrument>, Vec<Instrument>) {
+    let added = target.difference(current).cloned().collect();
+    let removed = current.difference(target).cloned().collect();
+    (added, removed)
+}
+
+/// Stream of [`MarketEvent<SubKind::Event>`](MarketEvent)s of a universe of [`Instrument`]s
+/// refreshed by [`subscribe_universe`].
+///
+/// Dropping the [`UniverseStream`] stops the refresh task & releases every [`Subscription`] of
+/// the universe.
+#[derive(Debug)]
+pub struct UniverseStream<Kind>
+where
+    Kind: SubKind,
+{
+    universe: Arc<Mutex<Vec<Instrument>>>,
+    event_rx: mpsc::UnboundedReceiver<MarketEvent<Kind::Event>>,
+    handle: JoinHandle<()>,
+}
+
+impl<Kind> UniverseStream<Kind>
+where
+    Kind: SubKind,
+{
+    /// [`Instrument`]s of the universe as of the last refresh.
+    pub fn instruments(&self) -> Vec<Instrument> {
+        self.universe
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Receive the next [`MarketEvent<SubKind::Event>`](MarketEvent).
+    pub async fn recv(&mut self) -> Option<MarketEvent<Kind::Event>> {
+        self.event_rx.recv().await
+    }
+}
+
+impl<Kind> Stream for UniverseStream<Kind>
+where
+    Kind: SubKind,
+{
+    type Item = MarketEvent<Kind::Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.event_rx.poll_recv(cx)
+    }
+}
+
+impl<Kind> Drop for UniverseStream<Kind>
+where
+    Kind: SubKind,
+{
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn instruments(bases: &[&str]) -> BTreeSet<Instrument> {
+        bases
+            .iter()
+            .map(|base| Instrument::from((*base, "usdt", InstrumentKind::Perpetual)))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        struct TestCase {
+            input: (BTreeSet<Instrument>, BTreeSet<Instrument>),
+            expected: (BTreeSet<Instrument>, BTreeSet<Instrument>),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: initial universe is entirely added
+                input: (instruments(&[]), instruments(&["btc", "eth"])),
+                expected: (instruments(&["btc", "eth"]), instruments(&[])),
+            },
+            TestCase {
+                // TC1: unchanged universe
+                input: (instruments(&["btc", "eth"]), instruments(&["eth", "btc"])),
+                expected: (instruments(&[]), instruments(&[])),
+            },
+            TestCase {
+                // TC2: Instruments rotated in & out of the universe
+                input: (
+                    instruments(&["btc", "eth", "xrp"]),
+                    instruments(&["btc", "sol"]),
+                ),
+                expected: (instruments(&["sol"]), instruments(&["eth", "xrp"])),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (added, removed) = diff(&test.input.0, &test.input.1);
+            let actual = (
+                added.into_iter().collect::<BTreeSet<_>>(),
+                removed.into_iter().collect::<BTreeSet<_>>(),
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_universe_rule_closure() {
+        let rule = || async { Ok(instruments(&["btc"]).into_iter().collect()) };
+
+        assert_eq!(
+            UniverseRule::evaluate(&rule).await.unwrap(),
+            vec![Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     exchange::{subscription::ExchangeSub, Connector},
+    normalise::AssetNormaliser,
     subscription::{Map, SubKind, Subscription, SubscriptionMeta},
     Identifier,
 };
@@ -9,8 +10,15 @@ use std::collections::HashMap;
 
 /// Defines how to map a collection of Barter [`Subscription`]s into exchange specific
 /// [`SubscriptionMeta`], containing subscription payloads that are sent to the exchange.
+///
+/// The [`AssetNormaliser`] translates each [`Subscription`] to the exchange asset codes, and
+/// normalises the [`Instrument`](barter_integration::model::instrument::Instrument) associated
+/// with each [`SubscriptionId`].
 pub trait SubscriptionMapper {
-    fn map<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> SubscriptionMeta
+    fn map<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        normaliser: &AssetNormaliser,
+    ) -> SubscriptionMeta
     where
         Exchange: Connector,
         Kind: SubKind,
@@ -23,7 +31,10 @@ pub trait SubscriptionMapper {
 pub struct WebSocketSubMapper;
 
 impl SubscriptionMapper for WebSocketSubMapper {
-    fn map<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> SubscriptionMeta
+    fn map<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        normaliser: &AssetNormaliser,
+    ) -> SubscriptionMeta
     where
         Exchange: Connector,
         Kind: SubKind,
@@ -38,15 +49,17 @@ impl SubscriptionMapper for WebSocketSubMapper {
             .iter()
             .map(|subscription| {
                 // Translate Barter Subscription to exchange specific subscription
-                let exchange_sub = ExchangeSub::new(subscription);
+                let exchange_sub =
+                    ExchangeSub::new(&normaliser.exchange_subscription(subscription));
 
                 // Determine the SubscriptionId associated with this exchange specific subscription
                 let subscription_id = exchange_sub.id();
 
-                // Use ExchangeSub SubscriptionId as the link to this Barter Subscription
-                instrument_map
-                    .0
-                    .insert(subscription_id, subscription.instrument.clone());
+                // Use ExchangeSub SubscriptionId as the link to this normalised Barter Subscription
+                instrument_map.0.insert(
+                    subscription_id,
+                    normaliser.normalise_instrument(Exchange::ID, &subscription.instrument),
+                );
 
                 exchange_sub
             })
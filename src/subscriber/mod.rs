@@ -4,15 +4,13 @@ use self::{
 };
 use crate::{
     exchange::Connector,
+    streams::config::StreamConfig,
     subscription::{Map, SubKind, Subscription, SubscriptionMeta},
+    transport::BoxTransport,
     Identifier,
 };
 use async_trait::async_trait;
-use barter_integration::{
-    error::SocketError,
-    model::Instrument,
-    protocol::websocket::{connect, WebSocket},
-};
+use barter_integration::{error::SocketError, model::instrument::Instrument};
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
@@ -21,6 +19,12 @@ use tracing::{debug, info};
 /// collection of Barter [`Subscription`]s into exchange specific [`SubscriptionMeta`].
 pub mod mapper;
 
+/// [`PrivateWebSocketSubscriber`](private::PrivateWebSocketSubscriber) that authenticates and
+/// subscribes to private account streams served by a
+/// [`PrivateConnector`](crate::exchange::PrivateConnector). Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub mod private;
+
 /// [`SubscriptionValidator`](validator::SubscriptionValidator) implementations defining how to
 /// validate actioned [`Subscription`]s were successful.
 pub mod validator;
@@ -32,7 +36,8 @@ pub trait Subscriber {
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        config: &StreamConfig,
+    ) -> Result<(BoxTransport, Map<Instrument>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -49,7 +54,8 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        config: &StreamConfig,
+    ) -> Result<(BoxTransport, Map<Instrument>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -57,18 +63,25 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
+
+        // Every Subscription must be served by the same exchange server to share a connection
+        let url = config.resolve_connection_url::<Exchange, _, _>(
+            subscriptions
+                .iter()
+                .map(Identifier::<Exchange::Channel>::id),
+        )?;
+        let url = config.select_url::<Exchange>(url).await;
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
         // Connect to exchange
-        let mut websocket = connect(url).await?;
+        let mut websocket = config.connect(url).await?;
         debug!(%exchange, ?subscriptions, "connected to WebSocket");
 
         // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
         let SubscriptionMeta {
             instrument_map,
             subscriptions,
-        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions);
+        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions, &config.normaliser);
 
         // Send Subscriptions over WebSocket
         for subscription in subscriptions {
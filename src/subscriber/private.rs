@@ -0,0 +1,98 @@
+use super::validator::WebSocketSubValidator;
+use crate::{
+    credentials::KeepAlive,
+    exchange::{subscription::ExchangeSub, PrivateConnector, PrivateSession},
+    streams::config::StreamConfig,
+    subscription::{Map, SubKind, Subscription},
+    transport::BoxTransport,
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use futures::SinkExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+/// WebSocket subscriber for private account streams served by a [`PrivateConnector`].
+///
+/// Authenticates using the [`Credentials`](crate::credentials::Credentials) provided in the
+/// [`StreamConfig`], connects to the private WebSocket server, sends the login and private
+/// subscription requests, and validates the responses. Any session [`KeepAlive`] task is returned
+/// so it can be owned by the stream.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct PrivateWebSocketSubscriber;
+
+impl PrivateWebSocketSubscriber {
+    pub async fn subscribe<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &StreamConfig,
+    ) -> Result<(BoxTransport, Map<Instrument>, Option<KeepAlive>), SocketError>
+    where
+        Exchange: PrivateConnector + Send + Sync,
+        Kind: SubKind + Send + Sync,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Define variables for logging ergonomics
+        let exchange = Exchange::ID;
+        let credentials = config.credentials.as_ref().ok_or_else(|| {
+            SocketError::Subscribe(format!(
+                "{exchange} private streams require StreamConfig credentials"
+            ))
+        })?;
+        if subscriptions.is_empty() {
+            return Err(SocketError::Subscribe(
+                "no Subscriptions to action".to_owned(),
+            ));
+        }
+
+        // Authenticate with the exchange (url is not logged since it may contain a session token)
+        let PrivateSession {
+            url,
+            requests,
+            keep_alive,
+        } = Exchange::authenticate(credentials).await?;
+        debug!(%exchange, ?subscriptions, "subscribing to private WebSocket");
+
+        // Connect to exchange private server
+        let mut websocket = config.connect(url).await?;
+        debug!(%exchange, ?subscriptions, "connected to private WebSocket");
+
+        // Map &[Subscription<Exchange, Kind>] to ExchangeSubs & the associated Map<Instrument>
+        let mut instrument_map = Map(HashMap::with_capacity(subscriptions.len()));
+        let exchange_subs = subscriptions
+            .iter()
+            .map(|subscription| {
+                let exchange_sub =
+                    ExchangeSub::new(&config.normaliser.exchange_subscription(subscription));
+                instrument_map.0.insert(
+                    exchange_sub.id(),
+                    config
+                        .normaliser
+                        .normalise_instrument(Exchange::ID, &subscription.instrument),
+                );
+                exchange_sub
+            })
+            .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
+
+        // Send login requests followed by private subscriptions over WebSocket
+        for request in requests
+            .into_iter()
+            .chain(Exchange::private_requests(exchange_subs))
+        {
+            debug!(%exchange, "sending exchange private request");
+            websocket.send(request).await?;
+        }
+
+        // Validate login & subscription responses
+        let expected_responses = Exchange::private_expected_responses(&instrument_map);
+        let map = WebSocketSubValidator::validate_responses::<Exchange>(
+            instrument_map,
+            &mut websocket,
+            expected_responses,
+        )
+        .await?;
+
+        info!(%exchange, "subscribed to private WebSocket");
+        Ok((websocket, map, keep_alive))
+    }
+}
@@ -1,15 +1,13 @@
 use crate::{
     exchange::Connector,
     subscription::{Map, SubKind},
+    transport::BoxTransport,
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    model::Instrument,
-    protocol::{
-        websocket::{WebSocket, WebSocketParser},
-        StreamParser,
-    },
+    model::instrument::Instrument,
+    protocol::{websocket::WebSocketParser, StreamParser},
     Validator,
 };
 use futures::StreamExt;
@@ -24,7 +22,7 @@ pub trait SubscriptionValidator {
 
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
-        websocket: &mut WebSocket,
+        websocket: &mut BoxTransport,
     ) -> Result<Map<Instrument>, SocketError>
     where
         Exchange: Connector + Send,
@@ -41,15 +39,33 @@ impl SubscriptionValidator for WebSocketSubValidator {
 
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
-        websocket: &mut WebSocket,
+        websocket: &mut BoxTransport,
     ) -> Result<Map<Instrument>, SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
+    {
+        let expected_responses = Exchange::expected_responses(&instrument_map);
+        Self::validate_responses::<Exchange>(instrument_map, websocket, expected_responses).await
+    }
+}
+
+impl WebSocketSubValidator {
+    /// Wait until the provided number of expected success responses have been received from the
+    /// exchange, returning the [`Map<Instrument>`] if every response was a success.
+    ///
+    /// Used directly where the number of expected responses is not defined by
+    /// [`Connector::expected_responses`] (eg/ private streams that also send login requests).
+    pub async fn validate_responses<Exchange>(
+        instrument_map: Map<Instrument>,
+        websocket: &mut BoxTransport,
+        expected_responses: usize,
+    ) -> Result<Map<Instrument>, SocketError>
+    where
+        Exchange: Connector,
     {
         // Establish exchange specific subscription validation parameters
         let timeout = Exchange::subscription_timeout();
-        let expected_responses = Exchange::expected_responses(&instrument_map);
 
         // Parameter to keep track of successful Subscription outcomes
         let mut success_responses = 0usize;
@@ -75,7 +91,7 @@ impl SubscriptionValidator for WebSocketSubValidator {
                         None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
                     };
 
-                    match Self::Parser::parse::<Exchange::SubResponse>(response) {
+                    match WebSocketParser::parse::<Exchange::SubResponse>(response) {
                         Some(Ok(response)) => match response.validate() {
                             // Subscription success
                             Ok(response) => {
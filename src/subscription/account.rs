@@ -0,0 +1,89 @@
+use super::SubKind;
+use barter_integration::model::{instrument::symbol::Symbol, Side};
+use barter_macro::{DeSubKind, SerSubKind};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields private account
+/// [`ExecutionEvent`] order lifecycle [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Requires API [`Credentials`](crate::credentials::Credentials) to be provided in the
+/// [`StreamConfig`](crate::streams::config::StreamConfig).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderUpdates;
+
+impl SubKind for OrderUpdates {
+    type Event = ExecutionEvent;
+}
+
+/// Normalised Barter [`ExecutionEvent`] model, representing a change in the lifecycle of one of
+/// the user's orders.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ExecutionEvent {
+    pub order_id: String,
+    pub client_order_id: Option<String>,
+    pub side: Side,
+    pub status: OrderStatus,
+    /// Order limit price (zero for market orders).
+    pub price: f64,
+    pub quantity: f64,
+    /// Cumulative quantity filled so far.
+    pub filled_quantity: f64,
+}
+
+/// Normalised Barter order lifecycle status.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Rejected,
+    Expired,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields private account
+/// [`Balance`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Balances are per asset rather than per market, so a [`Balance`] is yielded for each
+/// subscribed [`Instrument`](barter_integration::model::instrument::Instrument) that has the
+/// asset as its base or quote.
+///
+/// Requires API [`Credentials`](crate::credentials::Credentials) to be provided in the
+/// [`StreamConfig`](crate::streams::config::StreamConfig).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct Balances;
+
+impl SubKind for Balances {
+    type Event = Balance;
+}
+
+/// Normalised Barter [`Balance`] model, representing the latest wallet balance of an asset.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Balance {
+    pub asset: Symbol,
+    pub total: f64,
+    /// Balance available to trade (ie/ not locked in open orders).
+    pub free: f64,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields private account
+/// [`Position`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Requires API [`Credentials`](crate::credentials::Credentials) to be provided in the
+/// [`StreamConfig`](crate::streams::config::StreamConfig).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct Positions;
+
+impl SubKind for Positions {
+    type Event = Position;
+}
+
+/// Normalised Barter [`Position`] model, representing the latest state of a derivative position.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Position {
+    /// Signed position size, which is negative for short positions and zero once closed.
+    pub quantity: f64,
+    pub entry_price: f64,
+    pub unrealised_pnl: f64,
+}
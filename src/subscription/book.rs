@@ -1,9 +1,10 @@
 use super::SubKind;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, MonotonicTime, Origin},
     exchange::ExchangeId,
+    normalise::ContractSized,
 };
-use barter_integration::model::{Exchange, Instrument, Side};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,9 @@ use tracing::debug;
 ///
 /// Level 1 refers to the best non-aggregated bid and ask [`Level`] on each side of the
 /// [`OrderBook`].
+///
+/// Every [`OrderBookL1`] is a full snapshot of the best bid and ask, so the first event yielded
+/// is always a snapshot without requiring an `initial_snapshot` option.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL1;
 
@@ -51,11 +55,134 @@ impl OrderBookL1 {
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
 ///
 /// Level 2 refers to the [`OrderBook`] aggregated by price.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
-pub struct OrderBooksL2;
+///
+/// By default the full local [`OrderBook`] is maintained from diff (delta) updates. Providing a
+/// `depth` instead subscribes to periodic partial snapshots of the top `depth` [`Level`]s per
+/// side, where supported by the exchange.
+///
+/// Enabling `initial_snapshot` guarantees the first [`OrderBook`] yielded for each subscription
+/// is the initial full snapshot (fetched via HTTP, or the exchange's WebSocket snapshot message),
+/// before any delta updates have been applied.
+///
+/// By default every update yields the full (sorted) local [`OrderBook`]. Enabling `deltas`
+/// instead yields only the [`Level`]s of each delta update, flagged via [`OrderBook::delta`],
+/// which avoids copying the full depth of the book per update. Full snapshots (eg/ the initial
+/// snapshot, or a partial depth snapshot) are still yielded in full.
+///
+/// Serialises as "order_books_l2" when no options are provided, or as
+/// `{"depth": <levels>, "initial_snapshot": <bool>, "deltas": <bool>}` with each option being
+/// optional.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct OrderBooksL2 {
+    pub depth: Option<u16>,
+    pub initial_snapshot: bool,
+    pub deltas: bool,
+}
+
+impl OrderBooksL2 {
+    /// Construct an [`OrderBooksL2`] that yields partial snapshots of the top `depth` [`Level`]s
+    /// on each side of the [`OrderBook`].
+    pub fn partial(depth: u16) -> Self {
+        Self {
+            depth: Some(depth),
+            initial_snapshot: false,
+            deltas: false,
+        }
+    }
+
+    /// Yield the initial full [`OrderBook`] snapshot of each subscription before any delta
+    /// updates.
+    pub fn with_initial_snapshot(self) -> Self {
+        Self {
+            initial_snapshot: true,
+            ..self
+        }
+    }
+
+    /// Yield only the delta [`Level`]s of each update, rather than the full [`OrderBook`].
+    pub fn with_deltas(self) -> Self {
+        Self {
+            deltas: true,
+            ..self
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderBooksL2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OrderBooksL2De {
+            Name(String),
+            Options {
+                depth: Option<u16>,
+                #[serde(default)]
+                initial_snapshot: bool,
+                #[serde(default)]
+                deltas: bool,
+            },
+        }
+
+        match OrderBooksL2De::deserialize(deserializer)? {
+            OrderBooksL2De::Name(name) if name == "order_books_l2" => Ok(Self::default()),
+            OrderBooksL2De::Name(name) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&name),
+                &"order_books_l2",
+            )),
+            OrderBooksL2De::Options {
+                depth,
+                initial_snapshot,
+                deltas,
+            } => Ok(Self {
+                depth,
+                initial_snapshot,
+                deltas,
+            }),
+        }
+    }
+}
+
+impl Serialize for OrderBooksL2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        if *self == Self::default() {
+            return serializer.serialize_str("order_books_l2");
+        }
+
+        let mut state = serializer.serialize_struct("OrderBooksL2", 3)?;
+        match self.depth {
+            Some(depth) => state.serialize_field("depth", &depth)?,
+            None => state.skip_field("depth")?,
+        }
+        match self.initial_snapshot {
+            true => state.serialize_field("initial_snapshot", &true)?,
+            false => state.skip_field("initial_snapshot")?,
+        }
+        match self.deltas {
+            true => state.serialize_field("deltas", &true)?,
+            false => state.skip_field("deltas")?,
+        }
+        state.end()
+    }
+}
 
 impl SubKind for OrderBooksL2 {
     type Event = OrderBook;
+
+    fn initial_snapshot(&self) -> bool {
+        self.initial_snapshot
+    }
+
+    fn deltas(&self) -> bool {
+        self.deltas
+    }
 }
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 3 [`OrderBook`]
@@ -70,12 +197,20 @@ impl SubKind for OrderBooksL3 {
     type Event = OrderBook;
 }
 
-/// Normalised Barter [`OrderBook`] snapshot.
+/// Normalised Barter [`OrderBook`] snapshot, or the delta [`Level`]s of an update if
+/// [`OrderBook::delta`] (see [`OrderBooksL2::deltas`]).
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct OrderBook {
     pub last_update_time: DateTime<Utc>,
     pub bids: OrderBookSide,
     pub asks: OrderBookSide,
+    /// Exchange sequence number (eg/ update id) of the last update applied, where provided.
+    #[serde(default)]
+    pub sequence: Option<u64>,
+    /// True if the `bids` & `asks` only contain the [`Level`]s changed by an update (where a zero
+    /// amount removes the [`Level`]), rather than the full book.
+    #[serde(default)]
+    pub delta: bool,
 }
 
 impl OrderBook {
@@ -192,6 +327,18 @@ impl OrderBookSide {
         };
     }
 
+    /// [`Level`]s of this [`OrderBookSide`], sorted if the [`OrderBook`] is a
+    /// [`OrderBook::snapshot`].
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Sort this [`OrderBookSide`] and discard every [`Level`] beyond the best `depth`.
+    pub fn truncate(&mut self, depth: usize) {
+        self.sort();
+        self.levels.truncate(depth);
+    }
+
     /// Sort this [`OrderBookSide`] (bids are reversed).
     pub fn sort(&mut self) {
         // Sort Levels
@@ -204,8 +351,22 @@ impl OrderBookSide {
     }
 }
 
+impl ContractSized for OrderBookSide {
+    fn scale_amounts(&mut self, multiplier: f64) {
+        self.levels
+            .iter_mut()
+            .for_each(|level| level.amount *= multiplier);
+    }
+}
+
 /// Normalised Barter OrderBook [`Level`].
 #[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize),
+    archive(check_bytes),
+    archive_attr(derive(Debug))
+)]
 pub struct Level {
     pub price: f64,
     pub amount: f64,
@@ -227,6 +388,8 @@ impl Ord for Level {
     }
 }
 
+// Levels with a NaN price or amount are partially ordered, so partial_cmp cannot defer to cmp
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self.price.partial_cmp(&other.price)? {
@@ -278,6 +441,8 @@ impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
         Self(vec![Ok(MarketEvent {
             exchange_time: book.last_update_time,
             received_time: Utc::now(),
+            received_monotonic: MonotonicTime::now(),
+            origin: Origin::Live,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: book,
@@ -289,6 +454,87 @@ impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
 mod tests {
     use super::*;
 
+    mod order_books_l2 {
+        use super::*;
+
+        #[test]
+        fn test_de_order_books_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OrderBooksL2, ()>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: diff OrderBooksL2
+                    input: r#""order_books_l2""#,
+                    expected: Ok(OrderBooksL2::default()),
+                },
+                TestCase {
+                    // TC1: partial OrderBooksL2
+                    input: r#"{"depth": 10}"#,
+                    expected: Ok(OrderBooksL2::partial(10)),
+                },
+                TestCase {
+                    // TC2: diff OrderBooksL2 with initial snapshot
+                    input: r#"{"initial_snapshot": true}"#,
+                    expected: Ok(OrderBooksL2::default().with_initial_snapshot()),
+                },
+                TestCase {
+                    // TC3: partial OrderBooksL2 with initial snapshot
+                    input: r#"{"depth": 5, "initial_snapshot": true}"#,
+                    expected: Ok(OrderBooksL2::partial(5).with_initial_snapshot()),
+                },
+                TestCase {
+                    // TC4: diff OrderBooksL2 yielding deltas
+                    input: r#"{"deltas": true}"#,
+                    expected: Ok(OrderBooksL2::default().with_deltas()),
+                },
+                TestCase {
+                    // TC5: invalid SubKind name
+                    input: r#""order_books_l3""#,
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OrderBooksL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_ser_order_books_l2() {
+            assert_eq!(
+                serde_json::to_string(&OrderBooksL2::default()).unwrap(),
+                r#""order_books_l2""#
+            );
+            assert_eq!(
+                serde_json::to_string(&OrderBooksL2::partial(5)).unwrap(),
+                r#"{"depth":5}"#
+            );
+            assert_eq!(
+                serde_json::to_string(&OrderBooksL2::default().with_initial_snapshot()).unwrap(),
+                r#"{"initial_snapshot":true}"#
+            );
+            assert_eq!(
+                serde_json::to_string(&OrderBooksL2::default().with_deltas()).unwrap(),
+                r#"{"deltas":true}"#
+            );
+        }
+    }
+
     mod order_book_l1 {
         use super::*;
 
@@ -404,6 +650,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: None,
                 },
@@ -419,6 +667,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(100.0),
                 },
@@ -434,6 +684,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(50.0),
                 },
@@ -449,6 +701,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(150.0),
                 },
@@ -479,6 +733,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: None,
                 },
@@ -494,6 +750,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(100.0),
                 },
@@ -509,6 +767,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(50.0),
                 },
@@ -524,6 +784,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(150.0),
                 },
@@ -539,6 +801,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![Level::new(200.0, 1000.0), Level::new(300.0, 100.0)],
                         },
+                        sequence: None,
+                        delta: false,
                     },
                     expected: Some(175.0),
                 },
@@ -1,16 +1,110 @@
 use super::SubKind;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
-/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for the provided [`Interval`].
+///
+/// [`Candles::Last`] summarise the last traded price, whereas [`Candles::MarkPrice`] and
+/// [`Candles::IndexPrice`] summarise the derivative mark price and underlying index price, and
+/// so have no volume.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-pub struct Candles;
+#[serde(rename_all = "snake_case")]
+pub enum Candles {
+    Last(Interval),
+    MarkPrice(Interval),
+    IndexPrice(Interval),
+}
+
+impl Candles {
+    /// Time [`Interval`] that each [`Candle`] summarises.
+    pub fn interval(&self) -> Interval {
+        match self {
+            Candles::Last(interval)
+            | Candles::MarkPrice(interval)
+            | Candles::IndexPrice(interval) => *interval,
+        }
+    }
+}
 
 impl SubKind for Candles {
     type Event = Candle;
 }
 
+/// Time [`Interval`] that each [`Candle`] summarises.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Interval {
+    #[serde(rename = "1m")]
+    Minute1,
+    #[serde(rename = "3m")]
+    Minute3,
+    #[serde(rename = "5m")]
+    Minute5,
+    #[serde(rename = "15m")]
+    Minute15,
+    #[serde(rename = "30m")]
+    Minute30,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "2h")]
+    Hour2,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "6h")]
+    Hour6,
+    #[serde(rename = "12h")]
+    Hour12,
+    #[serde(rename = "1d")]
+    Day1,
+    #[serde(rename = "1w")]
+    Week1,
+}
+
+impl Interval {
+    /// Return the &str representation of this [`Interval`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::Minute1 => "1m",
+            Interval::Minute3 => "3m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour2 => "2h",
+            Interval::Hour4 => "4h",
+            Interval::Hour6 => "6h",
+            Interval::Hour12 => "12h",
+            Interval::Day1 => "1d",
+            Interval::Week1 => "1w",
+        }
+    }
+
+    /// Return the [`chrono::Duration`] spanned by this [`Interval`].
+    pub fn duration(&self) -> chrono::Duration {
+        match self {
+            Interval::Minute1 => chrono::Duration::minutes(1),
+            Interval::Minute3 => chrono::Duration::minutes(3),
+            Interval::Minute5 => chrono::Duration::minutes(5),
+            Interval::Minute15 => chrono::Duration::minutes(15),
+            Interval::Minute30 => chrono::Duration::minutes(30),
+            Interval::Hour1 => chrono::Duration::hours(1),
+            Interval::Hour2 => chrono::Duration::hours(2),
+            Interval::Hour4 => chrono::Duration::hours(4),
+            Interval::Hour6 => chrono::Duration::hours(6),
+            Interval::Hour12 => chrono::Duration::hours(12),
+            Interval::Day1 => chrono::Duration::days(1),
+            Interval::Week1 => chrono::Duration::weeks(1),
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Normalised Barter OHLCV [`Candle`] model.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Candle {
@@ -0,0 +1,24 @@
+use super::SubKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields perpetual [`FundingRate`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct FundingRates;
+
+impl SubKind for FundingRates {
+    type Event = FundingRate;
+}
+
+/// Normalised Barter perpetual [`FundingRate`] model, containing the estimated rate paid at the
+/// next funding time.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    /// Estimated funding rate (eg/ 0.0001 for 0.01%) paid at the `next_funding_time`.
+    pub rate: f64,
+    pub mark_price: f64,
+    pub next_funding_time: DateTime<Utc>,
+}
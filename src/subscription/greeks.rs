@@ -0,0 +1,28 @@
+use super::SubKind;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields option [`Greeks`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OptionGreeks;
+
+impl SubKind for OptionGreeks {
+    type Event = Greeks;
+}
+
+/// Normalised Barter option [`Greeks`] & implied volatility model.
+///
+/// Implied volatilities are expressed as a fraction (eg/ 0.5 => 50%).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    /// Option mark price, if provided by the exchange alongside the greeks.
+    pub mark_price: Option<f64>,
+    pub mark_iv: f64,
+    pub bid_iv: Option<f64>,
+    pub ask_iv: Option<f64>,
+    pub underlying_price: f64,
+}
@@ -0,0 +1,38 @@
+use super::SubKind;
+use barter_integration::model::instrument::symbol::Symbol;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`IndexConstituents`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct CompositeIndices;
+
+impl SubKind for CompositeIndices {
+    type Event = IndexConstituents;
+}
+
+/// Normalised Barter [`IndexConstituents`] model, containing the index price and the basket of
+/// [`IndexConstituent`]s it is composed of.
+///
+/// Exchanges that only stream the index price (eg/ [`Okx`](crate::exchange::okx::Okx)) yield an
+/// empty `constituents` basket.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct IndexConstituents {
+    pub price: f64,
+    pub constituents: Vec<IndexConstituent>,
+}
+
+/// Normalised Barter [`IndexConstituent`] model.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct IndexConstituent {
+    pub base: Symbol,
+    pub quote: Symbol,
+    /// Quantity of the `base` asset held by the index basket.
+    pub weight: f64,
+    /// Fraction of the index price contributed by this constituent.
+    pub weight_percent: f64,
+    /// Price of this constituent used to calculate the index price.
+    pub price: f64,
+}
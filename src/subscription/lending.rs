@@ -0,0 +1,204 @@
+use super::{trade::TradeId, SubKind};
+use barter_integration::model::Side;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Number of days in a year used to annualise daily lending rates.
+pub const DAYS_PER_YEAR: f64 = 365.0;
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields margin funding (lending)
+/// [`LendingTrade`] [`MarketEvent<T>`](crate::event::MarketEvent) events (eg/ Bitfinex fUSD).
+///
+/// Lending markets are for a single currency rather than a pair, so they are distinguished by
+/// this [`SubKind`] rather than the [`Instrument`](barter_integration::model::instrument::Instrument)
+/// kind: the funding currency is the [`Instrument`] base, and the quote is ignored
+/// (conventionally the same currency, eg/ `("usd", "usd", InstrumentKind::Spot)`).
+///
+/// [`Instrument`]: barter_integration::model::instrument::Instrument
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct LendingTrades;
+
+impl SubKind for LendingTrades {
+    type Event = LendingTrade;
+}
+
+/// Normalised Barter margin funding [`LendingTrade`] model.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct LendingTrade {
+    pub id: TradeId,
+    /// Daily interest rate (eg/ 0.0002 for 0.02% per day).
+    pub rate: f64,
+    /// Duration of the loan in days.
+    pub period_days: u32,
+    /// Amount of the funding currency lent.
+    pub amount: f64,
+    /// [`Side::Buy`] if the taker borrowed, or [`Side::Sell`] if the taker lent.
+    pub side: Side,
+}
+
+impl LendingTrade {
+    /// Annualised (simple) interest rate of the [`LendingTrade`].
+    pub fn annual_rate(&self) -> f64 {
+        self.rate * DAYS_PER_YEAR
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields margin funding (lending)
+/// [`LendingBook`] [`MarketEvent<T>`](crate::event::MarketEvent) events (eg/ Bitfinex fUSD).
+///
+/// See [`LendingTrades`] for how lending market [`Instrument`]s are specified.
+///
+/// [`Instrument`]: barter_integration::model::instrument::Instrument
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct LendingBooks;
+
+impl SubKind for LendingBooks {
+    type Event = LendingBook;
+}
+
+/// Normalised Barter margin funding [`LendingBook`], where the `bids` are the demand from
+/// borrowers (highest rate first), and the `asks` are the offers from lenders (lowest rate first).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct LendingBook {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: Vec<LendingLevel>,
+    pub asks: Vec<LendingLevel>,
+}
+
+impl LendingBook {
+    /// Upsert a [`LendingLevel`] into the provided [`Side`] of the [`LendingBook`], identified by
+    /// it's `rate` & `period_days`. A zero `amount` removes the level.
+    pub fn upsert(&mut self, side: Side, level: LendingLevel) {
+        let levels = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+
+        let existing = levels.iter().position(|existing| {
+            existing.rate == level.rate && existing.period_days == level.period_days
+        });
+
+        match (existing, level.amount == 0.0) {
+            (Some(index), true) => {
+                levels.remove(index);
+            }
+            (Some(index), false) => levels[index] = level,
+            (None, false) => levels.push(level),
+            (None, true) => {}
+        }
+    }
+
+    /// Sort the `bids` by descending rate, and the `asks` by ascending rate, with ties broken by
+    /// ascending `period_days`.
+    pub fn sort(&mut self) {
+        let by_rate = |a: &LendingLevel, b: &LendingLevel| {
+            a.rate.partial_cmp(&b.rate).unwrap_or(Ordering::Equal)
+        };
+        self.asks
+            .sort_by(|a, b| by_rate(a, b).then(a.period_days.cmp(&b.period_days)));
+        self.bids
+            .sort_by(|a, b| by_rate(b, a).then(a.period_days.cmp(&b.period_days)));
+    }
+
+    /// Best (highest) borrower bid rate, if any.
+    pub fn best_bid_rate(&self) -> Option<f64> {
+        self.bids.first().map(|level| level.rate)
+    }
+
+    /// Best (lowest) lender offer rate, if any.
+    pub fn best_ask_rate(&self) -> Option<f64> {
+        self.asks.first().map(|level| level.rate)
+    }
+}
+
+/// Normalised Barter [`LendingBook`] level, aggregating every offer at the same `rate` &
+/// `period_days`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct LendingLevel {
+    /// Daily interest rate (eg/ 0.0002 for 0.02% per day).
+    pub rate: f64,
+    /// Duration of the loan in days.
+    pub period_days: u32,
+    /// Total amount of the funding currency offered.
+    pub amount: f64,
+    /// Number of offers aggregated in this level.
+    pub count: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(rate: f64, period_days: u32, amount: f64) -> LendingLevel {
+        LendingLevel {
+            rate,
+            period_days,
+            amount,
+            count: 1,
+        }
+    }
+
+    #[test]
+    fn test_lending_book_upsert() {
+        struct TestCase {
+            input: (Side, LendingLevel),
+            expected: (Vec<LendingLevel>, Vec<LendingLevel>),
+        }
+
+        let mut book = LendingBook::default();
+
+        let tests = vec![
+            TestCase {
+                // TC0: insert bid
+                input: (Side::Buy, level(0.0002, 2, 100.0)),
+                expected: (vec![level(0.0002, 2, 100.0)], vec![]),
+            },
+            TestCase {
+                // TC1: insert better bid w/ a different period
+                input: (Side::Buy, level(0.0003, 30, 50.0)),
+                expected: (
+                    vec![level(0.0003, 30, 50.0), level(0.0002, 2, 100.0)],
+                    vec![],
+                ),
+            },
+            TestCase {
+                // TC2: insert asks, sorted by ascending rate then period
+                input: (Side::Sell, level(0.0001, 7, 10.0)),
+                expected: (
+                    vec![level(0.0003, 30, 50.0), level(0.0002, 2, 100.0)],
+                    vec![level(0.0001, 7, 10.0)],
+                ),
+            },
+            TestCase {
+                // TC3: replace bid at the same rate & period
+                input: (Side::Buy, level(0.0002, 2, 80.0)),
+                expected: (
+                    vec![level(0.0003, 30, 50.0), level(0.0002, 2, 80.0)],
+                    vec![level(0.0001, 7, 10.0)],
+                ),
+            },
+            TestCase {
+                // TC4: remove bid w/ zero amount
+                input: (Side::Buy, level(0.0003, 30, 0.0)),
+                expected: (vec![level(0.0002, 2, 80.0)], vec![level(0.0001, 7, 10.0)]),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (side, level) = test.input;
+            book.upsert(side, level);
+            book.sort();
+            assert_eq!(
+                (book.bids.clone(), book.asks.clone()),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use super::SubKind;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`PriceLimit`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct PriceLimits;
+
+impl SubKind for PriceLimits {
+    type Event = PriceLimit;
+}
+
+/// Normalised Barter [`PriceLimit`] model, defining the band that order prices must be within.
+///
+/// A `None` limit means the exchange is not currently enforcing that side of the band.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct PriceLimit {
+    /// Highest price a buy order can be placed at.
+    pub buy_limit: Option<f64>,
+    /// Lowest price a sell order can be placed at.
+    pub sell_limit: Option<f64>,
+}
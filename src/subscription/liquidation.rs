@@ -5,11 +5,26 @@ use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Liquidation`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-pub struct Liquidations;
+///
+/// [`Liquidations::AllMarket`] is market-wide, subscribing to a single exchange-wide stream
+/// that yields every [`Liquidation`] quoted in the
+/// [`Subscription::market_wide`](super::Subscription::market_wide) quote asset.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Liquidations {
+    #[default]
+    Instrument,
+    AllMarket,
+}
 
 impl SubKind for Liquidations {
     type Event = Liquidation;
+
+    fn is_market_wide(&self) -> bool {
+        matches!(self, Liquidations::AllMarket)
+    }
 }
 
 /// Normalised Barter [`Liquidation`] model.
@@ -0,0 +1,361 @@
+use super::{Map, MARKET_WIDE_BASE};
+use crate::exchange::{Connector, ExchangeId};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{symbol::Symbol, Instrument},
+        SubscriptionId,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+/// Base & quote assets of every market listed by an exchange, keyed by the exchange symbol
+/// (eg/ "BNBUSDT" -> ("bnb", "usdt")).
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SymbolTable(pub HashMap<String, (Symbol, Symbol)>);
+
+impl FromIterator<(String, (Symbol, Symbol))> for SymbolTable {
+    fn from_iter<Iter>(iter: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (String, (Symbol, Symbol))>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl SymbolTable {
+    /// Get the base & quote assets of the provided exchange symbol, if it is listed.
+    pub fn get(&self, symbol: &str) -> Option<&(Symbol, Symbol)> {
+        self.0.get(symbol)
+    }
+}
+
+/// Exchange [`Connector`] that serves the [`SymbolTable`] of it's markets via HTTP.
+#[async_trait]
+pub trait MarketSymbols
+where
+    Self: Connector,
+{
+    /// Fetch the [`SymbolTable`] of every market listed by the exchange.
+    async fn market_symbols() -> Result<SymbolTable, SocketError>;
+}
+
+/// Process wide cache of each exchange [`SymbolTable`], so it is only fetched once.
+static SYMBOL_TABLES: OnceLock<Mutex<HashMap<ExchangeId, Arc<SymbolTable>>>> = OnceLock::new();
+
+/// Get the cached [`SymbolTable`] of the exchange, fetching it via [`MarketSymbols`] if it has
+/// not been fetched yet.
+pub async fn cached_market_symbols<Exchange>() -> Result<Arc<SymbolTable>, SocketError>
+where
+    Exchange: MarketSymbols,
+{
+    let tables = SYMBOL_TABLES.get_or_init(Default::default);
+
+    if let Some(table) = tables.lock().unwrap().get(&Exchange::ID) {
+        return Ok(Arc::clone(table));
+    }
+
+    let table = Arc::new(Exchange::market_symbols().await?);
+    Ok(Arc::clone(
+        tables.lock().unwrap().entry(Exchange::ID).or_insert(table),
+    ))
+}
+
+/// [`Instrument`] map supporting both individual [`Instrument`] and market-wide
+/// [`Subscription`](super::Subscription)s.
+///
+/// Market-wide [`Subscription`](super::Subscription)s have no base asset (see
+/// [`MARKET_WIDE_BASE`]) and act as a quote asset filter, so exchange messages for any symbol
+/// quoted in that asset (eg/ "BNBUSDT") are resolved per message to an [`Instrument`] using the
+/// base & quote assets listed in the exchange [`SymbolTable`]. Symbols that are not listed are
+/// ignored, rather than guessing the base asset from the symbol.
+///
+/// Resolved [`Instrument`]s are cached by symbol.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct MarketWideMap {
+    pub instrument_map: Map<Instrument>,
+    pub market_wide: Vec<Instrument>,
+    pub symbols: Arc<SymbolTable>,
+    pub cache: HashMap<String, Option<Instrument>>,
+}
+
+impl MarketWideMap {
+    /// Construct a new [`MarketWideMap`] from the provided [`Instrument`] map & exchange
+    /// [`SymbolTable`].
+    pub fn new(instrument_map: Map<Instrument>, symbols: Arc<SymbolTable>) -> Self {
+        let (market_wide, instrument_map): (Vec<_>, Vec<_>) = instrument_map
+            .0
+            .into_iter()
+            .partition(|(_, instrument)| instrument.base.as_ref() == MARKET_WIDE_BASE);
+
+        Self {
+            instrument_map: instrument_map.into_iter().collect(),
+            market_wide: market_wide
+                .into_iter()
+                .map(|(_, instrument)| instrument)
+                .collect(),
+            symbols,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Initialise a new [`MarketWideMap`] from the provided [`Instrument`] map, using the cached
+    /// exchange [`SymbolTable`]. The [`SymbolTable`] is only fetched if the map contains
+    /// market-wide subscriptions.
+    pub async fn init<Exchange>(instrument_map: Map<Instrument>) -> Result<Self, SocketError>
+    where
+        Exchange: MarketSymbols,
+    {
+        let market_wide = instrument_map
+            .0
+            .values()
+            .any(|instrument| instrument.base.as_ref() == MARKET_WIDE_BASE);
+
+        let symbols = match market_wide {
+            true => cached_market_symbols::<Exchange>().await?,
+            false => Arc::default(),
+        };
+
+        Ok(Self::new(instrument_map, symbols))
+    }
+
+    /// Find the market-wide [`Instrument`] associated with the provided exchange symbol (eg/
+    /// "BNBUSDT"), if it is listed in the [`SymbolTable`] & quoted in the quote asset of a
+    /// market-wide subscription.
+    pub fn find_market_wide(&mut self, symbol: &str) -> Option<Instrument> {
+        if self.market_wide.is_empty() {
+            return None;
+        }
+
+        if let Some(cached) = self.cache.get(symbol) {
+            return cached.clone();
+        }
+
+        let instrument = self.symbols.get(symbol).and_then(|(base, quote)| {
+            self.market_wide
+                .iter()
+                .find(|filter| &filter.quote == quote)
+                .map(|filter| Instrument::from((base.clone(), quote.clone(), filter.kind)))
+        });
+
+        self.cache.insert(symbol.to_owned(), instrument.clone());
+        instrument
+    }
+
+    /// Resolve the [`Instrument`] associated with the provided individual symbol
+    /// [`SubscriptionId`] (eg/ "@forceOrder|BNBUSDT").
+    ///
+    /// Returns `None` if the message should be ignored because it is for a symbol that is not
+    /// listed in the [`SymbolTable`], or not quoted in any market-wide subscription quote asset.
+    pub fn resolve(
+        &mut self,
+        subscription_id: &SubscriptionId,
+    ) -> Option<Result<Instrument, SocketError>> {
+        if let Ok(instrument) = self.instrument_map.find(subscription_id) {
+            return Some(Ok(instrument));
+        }
+
+        if self.market_wide.is_empty() {
+            return Some(Err(SocketError::Unidentifiable(subscription_id.clone())));
+        }
+
+        subscription_id
+            .as_ref()
+            .split_once('|')
+            .and_then(|(_, symbol)| self.find_market_wide(symbol))
+            .map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn symbols() -> Arc<SymbolTable> {
+        Arc::new(SymbolTable::from_iter(
+            [
+                ("ETHBTC", "eth", "btc"),
+                ("BNBUSDT", "bnb", "usdt"),
+                ("BNBBUSD", "bnb", "busd"),
+                ("BTCUSD", "btc", "usd"),
+                ("BTCTUSD", "btc", "tusd"),
+                ("USDTUSD", "usdt", "usd"),
+            ]
+            .map(|(symbol, base, quote)| {
+                (symbol.to_owned(), (Symbol::new(base), Symbol::new(quote)))
+            }),
+        ))
+    }
+
+    #[test]
+    fn test_resolve() {
+        struct TestCase {
+            input: SubscriptionId,
+            expected: Option<Result<Instrument, ()>>,
+        }
+
+        let individual = || {
+            Map::from_iter([(
+                SubscriptionId::from("@forceOrder|ETHBTC"),
+                Instrument::from(("eth", "btc", InstrumentKind::Perpetual)),
+            )])
+        };
+        let mut with_market_wide = individual();
+        with_market_wide.0.insert(
+            SubscriptionId::from("!forceOrder@arr|*USDT"),
+            Instrument::from(("*", "usdt", InstrumentKind::Perpetual)),
+        );
+
+        let mut map = MarketWideMap::new(individual(), symbols());
+        let tests = vec![
+            TestCase {
+                // TC0: individual symbol subscription
+                input: SubscriptionId::from("@forceOrder|ETHBTC"),
+                expected: Some(Ok(Instrument::from((
+                    "eth",
+                    "btc",
+                    InstrumentKind::Perpetual,
+                )))),
+            },
+            TestCase {
+                // TC1: unidentifiable w/o market-wide subscription
+                input: SubscriptionId::from("@forceOrder|BNBUSDT"),
+                expected: Some(Err(())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            match (map.resolve(&test.input), test.expected) {
+                (Some(Ok(actual)), Some(Ok(expected))) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Some(Err(_)), Some(Err(_))) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        let mut map = MarketWideMap::new(with_market_wide, symbols());
+        let tests = vec![
+            TestCase {
+                // TC2: market-wide subscription w/ matching quote asset
+                input: SubscriptionId::from("@forceOrder|BNBUSDT"),
+                expected: Some(Ok(Instrument::from((
+                    "bnb",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                )))),
+            },
+            TestCase {
+                // TC3: cached market-wide Instrument
+                input: SubscriptionId::from("@forceOrder|BNBUSDT"),
+                expected: Some(Ok(Instrument::from((
+                    "bnb",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                )))),
+            },
+            TestCase {
+                // TC4: market-wide subscription w/o matching quote asset is ignored
+                input: SubscriptionId::from("@forceOrder|BNBBUSD"),
+                expected: None,
+            },
+            TestCase {
+                // TC5: individual Instrument not in a market-wide quote is ignored
+                input: SubscriptionId::from("!forceOrder@arr|ETHBTC"),
+                expected: None,
+            },
+            TestCase {
+                // TC6: symbol quoted in USDT that is not listed by the exchange is ignored
+                input: SubscriptionId::from("@forceOrder|XYZUSDT"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            match (map.resolve(&test.input), test.expected) {
+                (Some(Ok(actual)), Some(Ok(expected))) => {
+                    assert_eq!(actual, expected, "TC{} failed", index + 2)
+                }
+                (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!(
+                        "TC{} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n",
+                        index + 2
+                    );
+                }
+            }
+        }
+
+        assert!(map.cache.contains_key("BNBUSDT"));
+    }
+
+    #[test]
+    fn test_find_market_wide_ambiguous_suffix() {
+        struct TestCase {
+            market_wide: Vec<&'static str>,
+            input: &'static str,
+            expected: Option<Instrument>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: "BTCTUSD" is quoted in TUSD, not "BTCT" quoted in USD
+                market_wide: vec!["usd"],
+                input: "BTCTUSD",
+                expected: None,
+            },
+            TestCase {
+                // TC1: "BTCTUSD" resolved to the listed base & quote assets
+                market_wide: vec!["usd", "tusd"],
+                input: "BTCTUSD",
+                expected: Some(Instrument::from(("btc", "tusd", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC2: "USDTUSD" is "USDT" quoted in USD, not "" quoted in USDT
+                market_wide: vec!["usdt", "usd"],
+                input: "USDTUSD",
+                expected: Some(Instrument::from(("usdt", "usd", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC3: "USDTUSD" is not quoted in USDT
+                market_wide: vec!["usdt"],
+                input: "USDTUSD",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let instrument_map = test
+                .market_wide
+                .into_iter()
+                .map(|quote| {
+                    (
+                        SubscriptionId::from(format!("!miniTicker@arr|*{}", quote.to_uppercase())),
+                        Instrument::from(("*", quote, InstrumentKind::Spot)),
+                    )
+                })
+                .collect();
+
+            let mut map = MarketWideMap::new(instrument_map, symbols());
+            assert_eq!(
+                map.find_market_wide(test.input),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use crate::exchange::StreamSelector;
+use crate::exchange::{
+    subscription::{write_subscription_id, SubscriptionKey},
+    StreamSelector,
+};
 use barter_integration::{
     error::SocketError,
-    model::{Instrument, InstrumentKind, SubscriptionId, Symbol},
+    model::{
+        instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+        SubscriptionId,
+    },
     protocol::websocket::WsMessage,
     Validator,
 };
@@ -11,26 +17,85 @@ use std::{
     fmt::{Debug, Display, Formatter},
 };
 
+/// Private account [`SubKind`]s and the associated Barter output data models.
+pub mod account;
+
 /// OrderBook [`SubKind`]s and the associated Barter output data models.
 pub mod book;
 
 /// Candle [`SubKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Funding rate [`SubKind`] and the associated Barter output data model.
+pub mod funding;
+
+/// Option Greeks [`SubKind`] and the associated Barter output data model.
+pub mod greeks;
+
+/// Composite index [`SubKind`] and the associated Barter output data models.
+pub mod index;
+
+/// Margin funding (lending) [`SubKind`]s and the associated Barter output data models.
+pub mod lending;
+
+/// Price limit [`SubKind`] and the associated Barter output data model.
+pub mod limit;
+
 /// Liquidation [`SubKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Market-wide [`Subscription`] [`Instrument`] resolution.
+pub mod market_wide;
+
+/// Insurance fund & auto-deleveraging [`SubKind`]s and the associated Barter output data models.
+pub mod risk;
+
+/// Pre-computed [`RoutingTable`](route::RoutingTable) of [`SubscriptionId`]s, used to route
+/// high rate exchange messages to their associated [`Instrument`].
+pub mod route;
+
+/// Exchange system status [`SubKind`] and the associated Barter output data model.
+pub mod status;
+
+/// Rolling window ticker [`SubKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubKind`] and the associated Barter output data model.
 pub mod trade;
 
+/// Implied volatility index [`SubKind`] and the associated Barter output data model.
+pub mod volatility;
+
 /// Defines the type of a [`Subscription`], and the output [`Self::Event`] that it yields.
 pub trait SubKind
 where
     Self: Debug + Clone,
 {
     type Event: Debug;
+
+    /// Determine if this [`SubKind`] is market-wide, meaning it is subscribed to without an
+    /// individual [`Instrument`] (see [`Subscription::market_wide`]).
+    fn is_market_wide(&self) -> bool {
+        false
+    }
+
+    /// Determine if this [`SubKind`] requires the initial full snapshot of each subscription to be
+    /// yielded before any delta updates (see [`OrderBooksL2`](book::OrderBooksL2)).
+    fn initial_snapshot(&self) -> bool {
+        false
+    }
+
+    /// Determine if this [`SubKind`] yields only the delta of each update, rather than the full
+    /// updated state (see [`OrderBooksL2`](book::OrderBooksL2)).
+    fn deltas(&self) -> bool {
+        false
+    }
 }
 
+/// [`Instrument`] base asset used by market-wide [`Subscription`]s, which are subscribed to
+/// without an individual [`Instrument`].
+pub const MARKET_WIDE_BASE: &str = "*";
+
 /// Barter [`Subscription`] used to subscribe to a [`SubKind`] for a particular exchange
 /// [`Instrument`].
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
@@ -85,6 +150,32 @@ impl<Exchange, Kind> Subscription<Exchange, Kind> {
             kind,
         }
     }
+
+    /// Constructs a new market-wide [`Subscription`] that yields events for every exchange
+    /// symbol quoted in the provided quote asset.
+    ///
+    /// The [`Instrument`] of each event is resolved per message from the exchange symbol (see
+    /// [`MarketWideMap`](market_wide::MarketWideMap)).
+    pub fn market_wide<S>(
+        exchange: Exchange,
+        quote: S,
+        instrument_kind: InstrumentKind,
+        kind: Kind,
+    ) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self::new(
+            exchange,
+            (Symbol::new(MARKET_WIDE_BASE), quote.into(), instrument_kind),
+            kind,
+        )
+    }
+
+    /// Determine if this is a market-wide [`Subscription`] (see [`Self::market_wide`]).
+    pub fn is_market_wide(&self) -> bool {
+        self.instrument.base.as_ref() == MARKET_WIDE_BASE
+    }
 }
 
 impl<Exchange, Kind> Validator for &Subscription<Exchange, Kind>
@@ -99,10 +190,19 @@ where
         // Determine ExchangeId associated with this Subscription
         let exchange = Exchange::ID;
 
+        // Validate market-wide Subscriptions are only used with a market-wide SubKind
+        if self.is_market_wide() != self.kind.is_market_wide() {
+            return Err(SocketError::Unsupported {
+                entity: exchange.as_str(),
+                item: format!("{:?} for Instrument {}", self.kind, self.instrument),
+            });
+        }
+
         // Validate the Exchange supports the Subscription InstrumentKind
         match self.instrument.kind {
             InstrumentKind::Spot if exchange.supports_spot() => Ok(self),
-            InstrumentKind::FuturePerpetual if exchange.supports_futures() => Ok(self),
+            InstrumentKind::Perpetual if exchange.supports_futures() => Ok(self),
+            InstrumentKind::Option(_) if exchange.supports_options() => Ok(self),
             other => Err(SocketError::Unsupported {
                 entity: exchange.as_str(),
                 item: other.to_string(),
@@ -117,6 +217,11 @@ where
 pub struct SubscriptionMeta {
     /// `HashMap` containing the mapping between a [`SubscriptionId`] and
     /// it's associated Barter [`Instrument`].
+    ///
+    /// Note that market-wide [`Subscription`]s (eg/
+    /// [`MiniTickers::AllMarket`](ticker::MiniTickers::AllMarket)) map to an [`Instrument`]
+    /// quote asset filter rather than a single [`Instrument`], which is resolved per message
+    /// using a [`MarketWideMap`](market_wide::MarketWideMap).
     pub instrument_map: Map<Instrument>,
     /// Collection of [`WsMessage`]s containing exchange specific subscription payloads to be sent.
     pub subscriptions: Vec<WsMessage>,
@@ -156,6 +261,39 @@ impl<T> Map<T> {
             .get_mut(id)
             .ok_or_else(|| SocketError::Unidentifiable(id.clone()))
     }
+
+    /// Find the `T` associated with the canonical [`SubscriptionId`] of the provided exchange
+    /// channel & market (see [`subscription_id`](crate::exchange::subscription::subscription_id)).
+    ///
+    /// Unlike constructing a [`SubscriptionId`] per message, the canonical id is written into a
+    /// reusable thread local buffer, so successful lookups do not allocate.
+    pub fn find_channel_market(&self, channel: &str, market: &str) -> Result<T, SocketError>
+    where
+        T: Clone,
+    {
+        thread_local! {
+            static BUFFER: std::cell::RefCell<SubscriptionId> =
+                const { std::cell::RefCell::new(SubscriptionId(String::new())) };
+        }
+
+        BUFFER.with(|buffer| {
+            let mut id = buffer.borrow_mut();
+            id.0.clear();
+            write_subscription_id(&mut id.0, channel, market);
+            self.0
+                .get(&*id)
+                .cloned()
+                .ok_or_else(|| SocketError::Unidentifiable(id.clone()))
+        })
+    }
+
+    /// Find the `T` associated with the provided [`SubscriptionKey`] without allocating.
+    pub fn find_key(&self, key: &SubscriptionKey) -> Result<T, SocketError>
+    where
+        T: Clone,
+    {
+        self.find_channel_market(key.channel.as_str(), key.market.as_str())
+    }
 }
 
 #[cfg(test)]
@@ -184,7 +322,7 @@ mod tests {
                     "exchange": "okx",
                     "base": "btc",
                     "quote": "usdt",
-                    "instrument_type": "spot",
+                    "instrument_kind": "spot",
                     "kind": "public_trades"
                 }
                 "#;
@@ -199,7 +337,7 @@ mod tests {
                     "exchange": "binance_spot",
                     "base": "btc",
                     "quote": "usdt",
-                    "instrument_type": "spot",
+                    "instrument_kind": "spot",
                     "kind": "public_trades"
                 }
                 "#;
@@ -214,7 +352,7 @@ mod tests {
                     "exchange": "binance_futures_usd",
                     "base": "btc",
                     "quote": "usdt",
-                    "instrument_type": "future_perpetual",
+                    "instrument_kind": "perpetual",
                     "kind": "order_books_l2"
                 }
                 "#;
@@ -230,7 +368,7 @@ mod tests {
                     "exchange": "gateio_futures_usd",
                     "base": "btc",
                     "quote": "usdt",
-                    "instrument_type": "future_perpetual",
+                    "instrument_kind": "perpetual",
                     "kind": "public_trades"
                 }
                 "#;
@@ -266,12 +404,12 @@ mod tests {
                     ))),
                 },
                 TestCase {
-                    // TC1: Invalid Coinbase FuturePerpetual PublicTrades subscription
+                    // TC1: Invalid Coinbase Perpetual PublicTrades subscription
                     input: Subscription::from((
                         Coinbase,
                         "base",
                         "quote",
-                        InstrumentKind::FuturePerpetual,
+                        InstrumentKind::Perpetual,
                         PublicTrades,
                     )),
                     expected: Err(SocketError::Unsupported {
@@ -324,19 +462,19 @@ mod tests {
                     ))),
                 },
                 TestCase {
-                    // TC1: Valid Okx FuturePerpetual PublicTrades subscription
+                    // TC1: Valid Okx Perpetual PublicTrades subscription
                     input: Subscription::from((
                         Okx,
                         "base",
                         "quote",
-                        InstrumentKind::FuturePerpetual,
+                        InstrumentKind::Perpetual,
                         PublicTrades,
                     )),
                     expected: Ok(Subscription::from((
                         Okx,
                         "base",
                         "quote",
-                        InstrumentKind::FuturePerpetual,
+                        InstrumentKind::Perpetual,
                         PublicTrades,
                     ))),
                 },
@@ -358,6 +496,63 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_validate_market_wide() {
+            use crate::exchange::binance::futures::BinanceFuturesUsd;
+            use crate::subscription::liquidation::Liquidations;
+
+            struct TestCase {
+                input: Subscription<BinanceFuturesUsd, Liquidations>,
+                expected: Result<(), ()>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid market-wide Subscription w/ market-wide SubKind
+                    input: Subscription::market_wide(
+                        BinanceFuturesUsd::default(),
+                        "usdt",
+                        InstrumentKind::Perpetual,
+                        Liquidations::AllMarket,
+                    ),
+                    expected: Ok(()),
+                },
+                TestCase {
+                    // TC1: Invalid market-wide Subscription w/ individual Instrument SubKind
+                    input: Subscription::market_wide(
+                        BinanceFuturesUsd::default(),
+                        "usdt",
+                        InstrumentKind::Perpetual,
+                        Liquidations::Instrument,
+                    ),
+                    expected: Err(()),
+                },
+                TestCase {
+                    // TC2: Invalid individual Instrument Subscription w/ market-wide SubKind
+                    input: Subscription::from((
+                        BinanceFuturesUsd::default(),
+                        "btc",
+                        "usdt",
+                        InstrumentKind::Perpetual,
+                        Liquidations::AllMarket,
+                    )),
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                match (test.input.validate(), test.expected) {
+                    (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
     }
 
     mod instrument_map {
@@ -0,0 +1,65 @@
+use super::SubKind;
+use barter_integration::model::instrument::symbol::Symbol;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`InsuranceFund`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Exchanges often share an insurance fund between many contracts, in which case each update is
+/// yielded once for every subscribed [`Instrument`](barter_integration::model::instrument::Instrument)
+/// it covers.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct InsuranceFunds;
+
+impl SubKind for InsuranceFunds {
+    type Event = InsuranceFund;
+}
+
+/// Normalised Barter [`InsuranceFund`] balance model.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct InsuranceFund {
+    pub asset: Symbol,
+    pub balance: f64,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`AdlIndicator`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct AdlIndicators;
+
+impl SubKind for AdlIndicators {
+    type Event = AdlIndicator;
+}
+
+/// Normalised Barter auto-deleveraging (ADL) [`AdlIndicator`] model.
+///
+/// Exchanges report ADL risk differently, so only the insurance fund balance is always present:
+/// - Bybit: auto-deleveraging of a contract is triggered once it's `pnl_ratio` drawdown falls to
+///   the `pnl_ratio_threshold`, subject to the `trigger_threshold` and `stop_ratio`.
+/// - Okx: the [`AdlState`] of the insurance fund is reported directly.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct AdlIndicator {
+    pub state: Option<AdlState>,
+    pub insurance_balance: f64,
+    pub max_insurance_balance: Option<f64>,
+    pub pnl_ratio: Option<f64>,
+    pub pnl_ratio_threshold: Option<f64>,
+    pub trigger_threshold: Option<f64>,
+    pub stop_ratio: Option<f64>,
+}
+
+/// Normalised Barter auto-deleveraging (ADL) state of an insurance fund.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdlState {
+    /// Insurance fund is healthy.
+    Normal,
+    /// Insurance fund is declining towards the auto-deleveraging threshold.
+    Warning,
+    /// Auto-deleveraging is in progress.
+    Active,
+}
@@ -0,0 +1,220 @@
+use super::Map;
+use barter_integration::{error::SocketError, model::SubscriptionId};
+use serde::Serialize;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::{BuildHasher, BuildHasherDefault, Hasher},
+};
+
+/// Fast non-cryptographic [`Hasher`] for short keys (eg/ "@trade|BTCUSDT" [`SubscriptionId`]s),
+/// using the multiply-rotate FxHash algorithm.
+///
+/// Not resistant to HashDoS, which is acceptable since routing keys are derived from
+/// subscriptions rather than untrusted input.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+/// [`BuildHasher`] of [`FxHasher`]s.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+const FX_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FxHasher {
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(FX_SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            self.add_to_hash(u64::from_le_bytes(word));
+        }
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let mut word = [0; 8];
+            word[..remainder.len()].copy_from_slice(remainder);
+            self.add_to_hash(u64::from_le_bytes(word) ^ remainder.len() as u64);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.add_to_hash(u64::from(value))
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.add_to_hash(value)
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.add_to_hash(value as u64)
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Pre-computed routing id of a [`SubscriptionId`], which is it's [`FxHasher`] hash.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct RouteId(pub u64);
+
+impl RouteId {
+    /// Compute the [`RouteId`] of the provided [`SubscriptionId`].
+    pub fn new(id: &SubscriptionId) -> Self {
+        Self(FxBuildHasher::default().hash_one(id))
+    }
+}
+
+/// [`Hasher`] of pre-computed [`RouteId`]s, which are used as is.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct RouteIdHasher {
+    hash: u64,
+}
+
+impl Hasher for RouteIdHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        // Only RouteIds are expected, so fall back to FxHash for any other input
+        let mut hasher = FxHasher { hash: self.hash };
+        hasher.write(bytes);
+        self.hash = hasher.finish();
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.hash = value;
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Routing table from exchange message [`SubscriptionId`]s to some associated type `T` (eg/ an
+/// [`Instrument`](barter_integration::model::instrument::Instrument)), built once at
+/// subscription time from a [`Map`].
+///
+/// Each route is keyed by it's pre-computed [`RouteId`], so a lookup costs a single short key
+/// [`FxHasher`] hash & equality check, rather than the default SipHash of a `String` key. Routes
+/// with colliding [`RouteId`]s are kept in a fallback [`Map`].
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct RoutingTable<T> {
+    routes: HashMap<RouteId, (SubscriptionId, T), BuildHasherDefault<RouteIdHasher>>,
+    collisions: Map<T>,
+}
+
+impl<T> RoutingTable<T> {
+    /// Number of routes in the [`RoutingTable`].
+    pub fn len(&self) -> usize {
+        self.routes.len() + self.collisions.0.len()
+    }
+
+    /// Determine if the [`RoutingTable`] contains no routes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Find a reference to the `T` associated with the provided [`SubscriptionId`].
+    pub fn get(&self, id: &SubscriptionId) -> Option<&T> {
+        match self.routes.get(&RouteId::new(id)) {
+            Some((route_id, value)) if route_id == id => Some(value),
+            _ if self.collisions.0.is_empty() => None,
+            _ => self.collisions.0.get(id),
+        }
+    }
+
+    /// Find the `T` associated with the provided [`SubscriptionId`].
+    pub fn find(&self, id: &SubscriptionId) -> Result<T, SocketError>
+    where
+        T: Clone,
+    {
+        self.get(id)
+            .cloned()
+            .ok_or_else(|| SocketError::Unidentifiable(id.clone()))
+    }
+}
+
+impl<T> From<Map<T>> for RoutingTable<T> {
+    fn from(map: Map<T>) -> Self {
+        let mut routes =
+            HashMap::with_capacity_and_hasher(map.0.len(), BuildHasherDefault::default());
+        let mut collisions = Map(HashMap::new());
+
+        for (id, value) in map.0 {
+            match routes.entry(RouteId::new(&id)) {
+                Entry::Occupied(_) => {
+                    collisions.0.insert(id, value);
+                }
+                Entry::Vacant(route) => {
+                    route.insert((id, value));
+                }
+            }
+        }
+
+        Self { routes, collisions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::subscription::subscription_id;
+    use std::hash::Hash;
+
+    #[test]
+    fn test_fx_hasher() {
+        let hash = |input: &str| {
+            let mut hasher = FxHasher::default();
+            input.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // Deterministic, and distinguishes ids differing only in their trailing bytes
+        assert_eq!(hash("@trade|BTCUSDT"), hash("@trade|BTCUSDT"));
+        assert_ne!(hash("@trade|BTCUSDT"), hash("@trade|BTCUSDC"));
+        assert_ne!(hash("@trade|BTC"), hash("@trade|BTC\0"));
+    }
+
+    #[test]
+    fn test_routing_table() {
+        let table = RoutingTable::from(Map::from_iter([
+            (subscription_id("@trade", "BTCUSDT"), 1),
+            (subscription_id("@trade", "ETHUSDT"), 2),
+            (subscription_id("@depth@100ms", "BTCUSDT"), 3),
+        ]));
+        assert_eq!(table.len(), 3);
+
+        struct TestCase {
+            input: SubscriptionId,
+            expected: Option<u8>,
+        }
+
+        let cases = vec![
+            // TC0: routed trade
+            TestCase {
+                input: subscription_id("@trade", "BTCUSDT"),
+                expected: Some(1),
+            },
+            // TC1: same market, different channel
+            TestCase {
+                input: subscription_id("@depth@100ms", "BTCUSDT"),
+                expected: Some(3),
+            },
+            // TC2: un-subscribed market
+            TestCase {
+                input: subscription_id("@trade", "SOLUSDT"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = table.find(&test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
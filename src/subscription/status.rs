@@ -0,0 +1,54 @@
+use super::SubKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`ExchangeStatus`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Exchange wide status updates (eg/ system maintenance) are yielded once for every subscribed
+/// [`Instrument`](barter_integration::model::instrument::Instrument), so downstream consumers
+/// can pause trading each affected market.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct SystemStatus;
+
+impl SubKind for SystemStatus {
+    type Event = ExchangeStatus;
+}
+
+/// Normalised Barter [`ExchangeStatus`] model.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ExchangeStatus {
+    pub status: TradingStatus,
+    /// Exchange provided description of the status (eg/ the maintenance title).
+    pub message: Option<String>,
+    /// Scheduled start of a maintenance window.
+    pub start_time: Option<DateTime<Utc>>,
+    /// Scheduled end of a maintenance window.
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+/// Normalised Barter trading status of an exchange or market.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradingStatus {
+    Online,
+    /// Maintenance is scheduled, but trading is not yet affected.
+    MaintenanceScheduled,
+    Maintenance,
+    CancelOnly,
+    PostOnly,
+    LimitOnly,
+    Offline,
+}
+
+impl TradingStatus {
+    /// Determine if new orders can be placed, possibly with restrictions on the order type.
+    pub fn is_trading(&self) -> bool {
+        matches!(
+            self,
+            Self::Online | Self::MaintenanceScheduled | Self::PostOnly | Self::LimitOnly
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use super::SubKind;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields rolling window [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for the provided [`TickerWindow`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Tickers(pub TickerWindow);
+
+impl SubKind for Tickers {
+    type Event = Ticker;
+}
+
+/// Rolling time window that each [`Ticker`] summarises.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TickerWindow {
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "1d")]
+    Day1,
+}
+
+impl TickerWindow {
+    /// Return the &str representation of this [`TickerWindow`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TickerWindow::Hour1 => "1h",
+            TickerWindow::Hour4 => "4h",
+            TickerWindow::Day1 => "1d",
+        }
+    }
+}
+
+impl Display for TickerWindow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Normalised Barter rolling window [`Ticker`] statistics model.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub open_time: DateTime<Utc>,
+    pub close_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub last: f64,
+    pub price_change: f64,
+    pub price_change_percent: f64,
+    pub weighted_avg_price: f64,
+    pub volume: f64,
+    pub quote_volume: f64,
+    pub trade_count: u64,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields rolling 24hr
+/// [`MiniTicker`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// [`MiniTickers::AllMarket`] is market-wide, subscribing to a single all-market stream that
+/// yields a [`MiniTicker`] for every exchange symbol quoted in the
+/// [`Subscription`](super::Subscription) quote asset. It must be used with a
+/// [`Subscription::market_wide`](super::Subscription::market_wide).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MiniTickers {
+    #[default]
+    Instrument,
+    AllMarket,
+}
+
+impl SubKind for MiniTickers {
+    type Event = MiniTicker;
+
+    fn is_market_wide(&self) -> bool {
+        matches!(self, MiniTickers::AllMarket)
+    }
+}
+
+/// Normalised Barter rolling 24hr [`MiniTicker`] statistics model.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MiniTicker {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub last: f64,
+    pub volume: f64,
+    pub quote_volume: f64,
+}
@@ -1,7 +1,12 @@
 use super::SubKind;
-use barter_integration::model::Side;
+use barter_integration::model::{instrument::symbol::Symbol, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use serde::{Deserialize, Serialize};
+use std::{
+    convert::Infallible,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`PublicTrade`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
@@ -15,8 +20,256 @@ impl SubKind for PublicTrades {
 /// Normalised Barter [`PublicTrade`] model.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PublicTrade {
+    pub id: TradeId,
+    pub price: f64,
+    pub amount: f64,
+    /// Aggressor (ie/ taker) side of the trade, for every exchange: [`Side::Buy`] if a buy order
+    /// lifted a resting sell order. Exchanges that report the maker side (eg/ Coinbase) are
+    /// inverted when normalised.
+    pub side: Side,
+    /// True if the aggressor `side` was inferred from the prevailing quotes & previous trades by
+    /// an [`AggressorInference`](crate::streams::aggressor::AggressorInference) stage, rather
+    /// than provided by the exchange.
+    #[serde(default)]
+    pub side_inferred: bool,
+    /// True if the trade is a privately negotiated block trade (eg/ an RFQ) reported to the
+    /// exchange, rather than a lit trade matched on the order book.
+    #[serde(default)]
+    pub block: bool,
+    /// Exchange order id of the buy side order that was filled, where provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buyer_order_id: Option<String>,
+    /// Exchange order id of the sell side order that was filled, where provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seller_order_id: Option<String>,
+    /// True if the buy side order was the resting maker order (ie/ a sell aggressor), where
+    /// explicitly provided by the exchange.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buyer_is_maker: Option<bool>,
+    /// Notional value of the trade in the quote asset (`price * amount`), where computed by a
+    /// [`TradeValuation`](crate::transformer::enrich::TradeValuation) enricher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notional: Option<f64>,
+    /// Approximate USD value of the trade, where the quote asset has a stable USD reference
+    /// (eg/ usdt), computed by a [`TradeValuation`](crate::transformer::enrich::TradeValuation)
+    /// enricher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usd_value: Option<f64>,
+}
+
+impl PublicTrade {
+    /// Construct a new lit [`PublicTrade`] with an exchange provided aggressor `side`, and none of
+    /// the optional order, maker & valuation fields.
+    ///
+    /// Optional fields are set using struct update syntax, eg/
+    /// `PublicTrade { buyer_is_maker: Some(true), ..PublicTrade::new(id, price, amount, side) }`.
+    pub fn new<Id>(id: Id, price: f64, amount: f64, side: Side) -> Self
+    where
+        Id: Into<TradeId>,
+    {
+        Self {
+            id: id.into(),
+            price,
+            amount,
+            side,
+            side_inferred: false,
+            block: false,
+            buyer_order_id: None,
+            seller_order_id: None,
+            buyer_is_maker: None,
+            notional: None,
+            usd_value: None,
+        }
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields public block trade
+/// [`PublicTrade`] [`MarketEvent<T>`](crate::event::MarketEvent) events, flagged via
+/// [`PublicTrade::block`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct BlockTrades;
+
+impl SubKind for BlockTrades {
+    type Event = PublicTrade;
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields aggregated
+/// [`PublicTrade`] [`MarketEvent<T>`](crate::event::MarketEvent) events, where the fills of an
+/// aggressor order at the same price are aggregated into a single trade (eg/ Binance
+/// "@aggTrade").
+///
+/// Aggregated trades arrive at a lower rate than [`PublicTrades`], at the cost of granularity.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct AggTrades;
+
+impl SubKind for AggTrades {
+    type Event = PublicTrade;
+}
+
+/// Normalised [`PublicTrade`] identifier, as provided by the exchange.
+///
+/// Numeric exchange trade ids are stored without allocating, and trades from exchanges that do
+/// not provide an id are represented by [`TradeId::None`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum TradeId {
+    U64(u64),
+    String(String),
+    None,
+}
+
+impl From<u64> for TradeId {
+    fn from(id: u64) -> Self {
+        Self::U64(id)
+    }
+}
+
+impl From<String> for TradeId {
+    fn from(id: String) -> Self {
+        Self::String(id)
+    }
+}
+
+impl From<&str> for TradeId {
+    fn from(id: &str) -> Self {
+        Self::String(id.to_owned())
+    }
+}
+
+impl FromStr for TradeId {
+    type Err = Infallible;
+
+    /// Parse an exchange trade id string, storing canonical numeric ids (eg/ "130639474") as a
+    /// [`TradeId::U64`], and falling back to a [`TradeId::String`] for any other id (eg/ UUIDs, or
+    /// numeric ids with leading zeros that would not survive the round trip).
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let canonical = !id.starts_with(['0', '+']) || id == "0";
+        match id.parse::<u64>() {
+            Ok(numeric) if canonical => Ok(Self::U64(numeric)),
+            _ => Ok(Self::String(id.to_owned())),
+        }
+    }
+}
+
+impl Display for TradeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeId::U64(id) => write!(f, "{id}"),
+            TradeId::String(id) => write!(f, "{id}"),
+            TradeId::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields private account
+/// [`UserTrade`] (fill) [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Requires API [`Credentials`](crate::credentials::Credentials) to be provided in the
+/// [`StreamConfig`](crate::streams::config::StreamConfig).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct UserTrades;
+
+impl SubKind for UserTrades {
+    type Event = UserTrade;
+}
+
+/// Normalised Barter [`UserTrade`] model, representing a fill of one of the user's orders.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UserTrade {
     pub id: String,
+    pub order_id: String,
     pub price: f64,
     pub amount: f64,
     pub side: Side,
+    /// Fee paid for this fill, denominated in the `fee_asset` (negative for rebates).
+    pub fee: f64,
+    pub fee_asset: Option<Symbol>,
+    /// True if the user's order was the maker (passive) side of the trade.
+    pub maker: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_trade_id() {
+            struct TestCase {
+                input: &'static str,
+                expected: TradeId,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: numeric trade id
+                    input: "12345",
+                    expected: TradeId::U64(12345),
+                },
+                TestCase {
+                    // TC1: string trade id
+                    input: r#""20f43950-d8dd-5b31-9112-a178eb6023af""#,
+                    expected: TradeId::from("20f43950-d8dd-5b31-9112-a178eb6023af"),
+                },
+                TestCase {
+                    // TC2: missing trade id
+                    input: "null",
+                    expected: TradeId::None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<TradeId>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_trade_id_from_str() {
+            struct TestCase {
+                input: &'static str,
+                expected: TradeId,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: numeric trade id string
+                    input: "633971452580106242",
+                    expected: TradeId::U64(633971452580106242),
+                },
+                TestCase {
+                    // TC1: zero trade id string
+                    input: "0",
+                    expected: TradeId::U64(0),
+                },
+                TestCase {
+                    // TC2: numeric trade id string with leading zeros is kept verbatim
+                    input: "00123",
+                    expected: TradeId::from("00123"),
+                },
+                TestCase {
+                    // TC3: numeric trade id string with a sign is kept verbatim
+                    input: "+123",
+                    expected: TradeId::from("+123"),
+                },
+                TestCase {
+                    // TC4: numeric trade id string overflowing a u64 is kept verbatim
+                    input: "18446744073709551616",
+                    expected: TradeId::from("18446744073709551616"),
+                },
+                TestCase {
+                    // TC5: non-numeric trade id string
+                    input: "20f43950-d8dd-5b31-9112-a178eb6023af",
+                    expected: TradeId::from("20f43950-d8dd-5b31-9112-a178eb6023af"),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = TradeId::from_str(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
 }
@@ -0,0 +1,26 @@
+use super::SubKind;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields implied
+/// [`VolatilityIndex`] [`MarketEvent<T>`](crate::event::MarketEvent) events (eg/ Deribit DVOL).
+///
+/// Volatility indices are subscribed to using the index currency pair of a
+/// [`Spot`](barter_integration::model::instrument::kind::InstrumentKind::Spot)
+/// [`Instrument`](barter_integration::model::instrument::Instrument) (eg/ `("btc", "usd")`).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct VolatilityIndices;
+
+impl SubKind for VolatilityIndices {
+    type Event = VolatilityIndex;
+}
+
+/// Normalised Barter [`VolatilityIndex`] model.
+///
+/// As per option [`Greeks`](super::greeks::Greeks), the annualised implied volatility is
+/// expressed as a fraction (eg/ 0.5 => 50%).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct VolatilityIndex {
+    pub volatility: f64,
+}
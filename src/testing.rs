@@ -0,0 +1,442 @@
+use crate::transport::{BoxTransport, ChannelTransport, TransportConnector};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use futures::{SinkExt, StreamExt};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+use tracing::debug;
+use url::Url;
+
+/// Communicative type alias for the frames received from the client of one [`MockExchange`]
+/// connection.
+type Received = Arc<Mutex<Vec<WsMessage>>>;
+
+/// Scripted step of a [`MockExchange`] connection, used to inject exchange faults
+/// deterministically.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ScriptStep {
+    /// Send a frame to the client.
+    Send(WsMessage),
+    /// Send a text frame truncated to it's first `len` bytes (ie/ a partial JSON frame).
+    SendPartial { frame: String, len: usize },
+    /// Send the previously sent frame again (eg/ a duplicate sequence number).
+    Duplicate,
+    /// Wait for the next non-ping frame from the client (eg/ a subscription request).
+    AwaitRequest,
+    /// Wait for the provided [`Duration`] while still serving client pings.
+    Delay(Duration),
+    /// Respond to every subsequent client ping after the provided [`Duration`].
+    DelayPongs(Duration),
+    /// Stop sending & responding to the client, without closing the connection (ie/ a half-open
+    /// connection), for the rest of the connection.
+    HalfOpen,
+    /// Close the connection.
+    Close,
+}
+
+/// Script of the [`ScriptStep`]s a [`MockExchange`] performs for one connection.
+///
+/// Once every step is performed the connection remains open, responding to client pings, until
+/// the client disconnects.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FaultScript {
+    /// Refuse the connection attempt (ie/ an exchange outage) rather than performing any steps.
+    pub refuse: bool,
+    pub steps: Vec<ScriptStep>,
+}
+
+impl FaultScript {
+    /// Construct a new empty [`Self`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new [`Self`] that refuses the connection attempt.
+    pub fn refused() -> Self {
+        Self {
+            refuse: true,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Add a [`ScriptStep`].
+    pub fn step(mut self, step: ScriptStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Send a text frame to the client.
+    pub fn send_text<Text>(self, text: Text) -> Self
+    where
+        Text: Into<String>,
+    {
+        self.step(ScriptStep::Send(WsMessage::Text(text.into())))
+    }
+
+    /// Send the first `len` bytes of a text frame to the client.
+    pub fn send_partial<Text>(self, text: Text, len: usize) -> Self
+    where
+        Text: Into<String>,
+    {
+        self.step(ScriptStep::SendPartial {
+            frame: text.into(),
+            len,
+        })
+    }
+
+    /// Send the previously sent frame again.
+    pub fn duplicate(self) -> Self {
+        self.step(ScriptStep::Duplicate)
+    }
+
+    /// Wait for the next non-ping frame from the client.
+    pub fn await_request(self) -> Self {
+        self.step(ScriptStep::AwaitRequest)
+    }
+
+    /// Wait for the provided [`Duration`] while still serving client pings.
+    pub fn delay(self, duration: Duration) -> Self {
+        self.step(ScriptStep::Delay(duration))
+    }
+
+    /// Respond to every subsequent client ping after the provided [`Duration`].
+    pub fn delay_pongs(self, duration: Duration) -> Self {
+        self.step(ScriptStep::DelayPongs(duration))
+    }
+
+    /// Stop sending & responding to the client without closing the connection.
+    pub fn half_open(self) -> Self {
+        self.step(ScriptStep::HalfOpen)
+    }
+
+    /// Close the connection.
+    pub fn close(self) -> Self {
+        self.step(ScriptStep::Close)
+    }
+}
+
+/// In-memory mock exchange server that performs a [`FaultScript`] for each connection, so
+/// resiliency features (eg/ re-connection, stale feed detection, sequence validation & error
+/// tolerance) can be integration tested deterministically without a network connection.
+///
+/// A [`MockExchange`] is a [`TransportConnector`], configured via
+/// [`StreamConfig::with_transport`](crate::streams::config::StreamConfig::with_transport). Each
+/// connection attempt performs the next [`FaultScript`], and connection attempts after the final
+/// [`FaultScript`] are refused. Cloning a [`MockExchange`] yields a handle to the same server.
+/// Enabled for downstream tests via the `testing` feature.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     streams::config::StreamConfig,
+///     testing::{FaultScript, MockExchange},
+/// };
+/// use std::time::Duration;
+///
+/// let trade = r#"{"e":"trade","E":1,"s":"BTCUSDT","t":1,"p":"1.0","q":"1.0","T":1,"m":true}"#;
+///
+/// let exchange = MockExchange::new([
+///     // First connection: duplicate trade, partial frame, then the connection goes half-open
+///     FaultScript::new()
+///         .await_request()
+///         .send_text(r#"{"result":null,"id":1}"#)
+///         .send_text(trade)
+///         .duplicate()
+///         .send_partial(trade, 20)
+///         .half_open(),
+///     // Second connection: exchange outage
+///     FaultScript::refused(),
+///     // Third connection: recovers with slow pongs
+///     FaultScript::new()
+///         .await_request()
+///         .send_text(r#"{"result":null,"id":1}"#)
+///         .delay_pongs(Duration::from_secs(5)),
+/// ]);
+///
+/// let config = StreamConfig::default().with_transport(exchange.clone());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockExchange {
+    scripts: Arc<Mutex<VecDeque<FaultScript>>>,
+    connections: Arc<Mutex<Vec<Received>>>,
+}
+
+impl MockExchange {
+    /// Construct a new [`Self`] that performs the provided [`FaultScript`]s for each connection
+    /// attempt in turn.
+    pub fn new<Scripts>(scripts: Scripts) -> Self
+    where
+        Scripts: IntoIterator<Item = FaultScript>,
+    {
+        Self {
+            scripts: Arc::new(Mutex::new(scripts.into_iter().collect())),
+            connections: Arc::default(),
+        }
+    }
+
+    /// Number of connection attempts, including refused attempts.
+    pub fn connections(&self) -> usize {
+        self.connections
+            .lock()
+            .expect("MockExchange lock poisoned")
+            .len()
+    }
+
+    /// Every frame received from the client of the provided connection attempt, in the order
+    /// they were received.
+    pub fn received(&self, connection: usize) -> Vec<WsMessage> {
+        self.connections
+            .lock()
+            .expect("MockExchange lock poisoned")
+            .get(connection)
+            .map(|received| received.lock().expect("MockExchange lock poisoned").clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl TransportConnector for MockExchange {
+    async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+        let script = self
+            .scripts
+            .lock()
+            .expect("MockExchange lock poisoned")
+            .pop_front()
+            .unwrap_or_else(FaultScript::refused);
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let connection = {
+            let mut connections = self.connections.lock().expect("MockExchange lock poisoned");
+            connections.push(Arc::clone(&received));
+            connections.len() - 1
+        };
+
+        if script.refuse {
+            debug!(%url, connection, "MockExchange refused connection");
+            return Err(SocketError::Subscribe(format!(
+                "MockExchange refused connection {connection} to {url}"
+            )));
+        }
+
+        let (client, exchange) = ChannelTransport::pair();
+        tokio::spawn(run_script(exchange, script, received));
+
+        Ok(Box::new(client))
+    }
+}
+
+/// Mock exchange side of one [`MockExchange`] connection.
+struct MockConnection {
+    transport: ChannelTransport,
+    received: Received,
+    pong_delay: Duration,
+}
+
+impl MockConnection {
+    /// Receive the next client frame, responding to pings. Returns `None` once the client
+    /// disconnects.
+    async fn recv(&mut self) -> Option<WsMessage> {
+        let message = self.transport.next().await?.ok()?;
+        self.received
+            .lock()
+            .expect("MockExchange lock poisoned")
+            .push(message.clone());
+
+        if let WsMessage::Ping(payload) = &message {
+            tokio::time::sleep(self.pong_delay).await;
+            self.transport
+                .send(WsMessage::Pong(payload.clone()))
+                .await
+                .ok()?;
+        }
+
+        Some(message)
+    }
+
+    /// Serve client frames until the `deadline`, if provided. Returns false once the client
+    /// disconnects.
+    async fn serve(&mut self, deadline: Option<Instant>) -> bool {
+        loop {
+            let message = match deadline {
+                Some(deadline) => match tokio::time::timeout_at(deadline, self.recv()).await {
+                    Ok(message) => message,
+                    Err(_) => return true,
+                },
+                None => self.recv().await,
+            };
+
+            if message.is_none() {
+                return false;
+            }
+        }
+    }
+}
+
+/// Perform the [`FaultScript`] of one [`MockExchange`] connection.
+async fn run_script(transport: ChannelTransport, script: FaultScript, received: Received) {
+    let mut connection = MockConnection {
+        transport,
+        received,
+        pong_delay: Duration::ZERO,
+    };
+    let mut last_sent = None;
+
+    for step in script.steps {
+        let message = match step {
+            ScriptStep::Send(message) => message,
+            ScriptStep::SendPartial { frame, len } => {
+                let mut len = len.min(frame.len());
+                while !frame.is_char_boundary(len) {
+                    len -= 1;
+                }
+                WsMessage::Text(frame[..len].to_owned())
+            }
+            ScriptStep::Duplicate => match &last_sent {
+                Some(message) => WsMessage::clone(message),
+                None => continue,
+            },
+            ScriptStep::AwaitRequest => {
+                loop {
+                    match connection.recv().await {
+                        Some(WsMessage::Ping(_)) => continue,
+                        Some(_) => break,
+                        None => return,
+                    }
+                }
+                continue;
+            }
+            ScriptStep::Delay(duration) => {
+                match connection.serve(Some(Instant::now() + duration)).await {
+                    true => continue,
+                    false => return,
+                }
+            }
+            ScriptStep::DelayPongs(duration) => {
+                connection.pong_delay = duration;
+                continue;
+            }
+            ScriptStep::HalfOpen => {
+                // Hold the connection open, recording client frames without responding
+                while let Some(Ok(message)) = connection.transport.next().await {
+                    connection
+                        .received
+                        .lock()
+                        .expect("MockExchange lock poisoned")
+                        .push(message);
+                }
+                return;
+            }
+            ScriptStep::Close => {
+                let _ = connection.transport.close().await;
+                return;
+            }
+        };
+
+        if connection.transport.send(message.clone()).await.is_err() {
+            return;
+        }
+        last_sent = Some(message);
+    }
+
+    connection.serve(None).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, StreamSelector},
+        streams::config::StreamConfig,
+        subscription::{trade::PublicTrades, Subscription},
+        MarketStream,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    const SUB_RESPONSE: &str = r#"{"result":null,"id":1}"#;
+    const TRADE: &str = r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":1,"p":"10000.19","q":"0.239","T":1649324825173,"m":false}"#;
+
+    fn url() -> Url {
+        Url::parse("wss://mock.exchange/ws").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_faults() {
+        let exchange = MockExchange::new([
+            FaultScript::new()
+                .await_request()
+                .send_text(SUB_RESPONSE)
+                .send_text(TRADE)
+                .duplicate()
+                .send_partial(TRADE, 20)
+                .close(),
+            FaultScript::refused(),
+        ]);
+        let config = StreamConfig::default().with_transport(exchange.clone());
+
+        let subscriptions = [Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let mut stream =
+            <BinanceSpot as StreamSelector<PublicTrades>>::Stream::init(&subscriptions, &config)
+                .await
+                .unwrap();
+
+        // Trade & it's duplicate are both yielded
+        let trade = stream.next().await.unwrap().unwrap();
+        let duplicate = stream.next().await.unwrap().unwrap();
+        assert_eq!(trade.kind.id, duplicate.kind.id);
+
+        // Partial JSON frame fails to deserialise, then the closed connection ends the stream
+        let partial = stream.next().await.unwrap().unwrap_err();
+        assert!(partial.is_deserialise());
+        assert!(stream.next().await.is_none());
+
+        // Subscription request was received, and the reconnection attempt is refused
+        assert!(exchange.received(0)[0]
+            .to_text()
+            .unwrap()
+            .contains("ethusdt@trade"));
+        assert!(exchange.connect(url()).await.is_err());
+        assert!(exchange.connect(url()).await.is_err());
+        assert_eq!(exchange.connections(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_mock_exchange_pongs() {
+        let delay = Duration::from_millis(50);
+        let exchange = MockExchange::new([
+            FaultScript::new()
+                .delay_pongs(delay)
+                .send_text(SUB_RESPONSE),
+            FaultScript::new().half_open(),
+        ]);
+
+        // Delayed pong is received after the pong delay
+        let mut client = exchange.connect(url()).await.unwrap();
+        assert_eq!(
+            client.next().await.unwrap().unwrap(),
+            WsMessage::Text(SUB_RESPONSE.to_owned())
+        );
+        let start = Instant::now();
+        client.send(WsMessage::Ping(vec![1])).await.unwrap();
+        assert_eq!(
+            client.next().await.unwrap().unwrap(),
+            WsMessage::Pong(vec![1])
+        );
+        assert!(start.elapsed() >= delay);
+
+        // Half-open connection never responds, but remains open
+        let mut client = exchange.connect(url()).await.unwrap();
+        client.send(WsMessage::Ping(vec![2])).await.unwrap();
+        let response = tokio::time::timeout(delay, client.next()).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    subscription::{Map, SubKind},
+    Identifier, OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    Transformer,
+};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Private account stream message, which may notify that the authenticated session has expired
+/// (eg/ a Binance "listenKeyExpired" event).
+pub trait AccountMessage {
+    /// Determine if this message notifies that the authenticated session has expired, meaning
+    /// the stream must re-initialise with a new session.
+    fn is_session_expired(&self) -> bool {
+        false
+    }
+}
+
+/// Generic stateless [`ExchangeTransformer`] for private account streams (eg/
+/// [`UserTrades`](crate::subscription::trade::UserTrades)).
+///
+/// Account streams deliver events for every market the account trades, and a single message may
+/// contain events for many markets, so each `Input` item is identified individually and events
+/// for markets that were not subscribed to are ignored.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct AccountTransformer<Exchange, Kind, Input> {
+    instrument_map: Map<Instrument>,
+    phantom: PhantomData<(Exchange, Kind, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, Kind>
+    for AccountTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: AccountMessage + IntoIterator + for<'de> Deserialize<'de> + Send,
+    Input::Item: Identifier<Option<SubscriptionId>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<Exchange, Kind, Input> Transformer for AccountTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Input: AccountMessage + IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<SubscriptionId>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        if input.is_session_expired() {
+            return vec![Err(DataError::SessionExpired(Exchange::ID))];
+        }
+
+        input
+            .into_iter()
+            .filter_map(|item| {
+                // Ignore items without a SubscriptionId (eg/ non-fill order updates), or for
+                // markets that were not subscribed to
+                let instrument = self.instrument_map.find(&item.id()?).ok()?;
+                Some(MarketIter::<Kind::Event>::from((Exchange::ID, instrument, item)).0)
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{spot::BinanceSpot, user::BinanceUserData},
+        subscription::trade::UserTrades,
+    };
+
+    #[test]
+    fn test_account_transformer_session_expired() {
+        let mut transformer = AccountTransformer::<BinanceSpot, UserTrades, BinanceUserData> {
+            instrument_map: Map(Default::default()),
+            phantom: PhantomData,
+        };
+
+        // Ignored messages yield nothing, but an expired session yields a terminal DataError
+        assert!(transformer.transform(BinanceUserData::Other).is_empty());
+        assert!(matches!(
+            transformer
+                .transform(BinanceUserData::ListenKeyExpired)
+                .as_slice(),
+            [Err(DataError::SessionExpired(ExchangeId::BinanceSpot))]
+        ));
+    }
+}
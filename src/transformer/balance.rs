@@ -0,0 +1,165 @@
+use super::{account::AccountMessage, ExchangeTransformer};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::Connector,
+    subscription::{
+        account::{Balance, Balances},
+        Map,
+    },
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{model::instrument::Instrument, Transformer};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Asset [`Balance`] update yielded by an exchange specific balance message.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BalanceUpdate {
+    pub time: DateTime<Utc>,
+    pub balance: Balance,
+}
+
+/// [`ExchangeTransformer`] for private [`Balances`] streams.
+///
+/// Each [`BalanceUpdate`] is yielded once for every subscribed [`Instrument`] that has the
+/// [`Balance`] asset as its base or quote, and ignored if there are none.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct BalanceTransformer<Exchange, Input> {
+    instruments: Vec<Instrument>,
+    phantom: PhantomData<(Exchange, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, Balances>
+    for BalanceTransformer<Exchange, Input>
+where
+    Exchange: Connector + Send,
+    Input: AccountMessage + IntoIterator<Item = BalanceUpdate> + for<'de> Deserialize<'de> + Send,
+{
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self::from(instrument_map))
+    }
+}
+
+impl<Exchange, Input> From<Map<Instrument>> for BalanceTransformer<Exchange, Input> {
+    fn from(instrument_map: Map<Instrument>) -> Self {
+        let mut instruments = instrument_map.0.into_values().collect::<Vec<_>>();
+        instruments.sort();
+        instruments.dedup();
+
+        Self {
+            instruments,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Exchange, Input> Transformer for BalanceTransformer<Exchange, Input>
+where
+    Exchange: Connector,
+    Input: AccountMessage + IntoIterator<Item = BalanceUpdate> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Balance>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        if input.is_session_expired() {
+            return vec![Err(DataError::SessionExpired(Exchange::ID))];
+        }
+
+        input
+            .into_iter()
+            .flat_map(|update| {
+                self.instruments
+                    .iter()
+                    .filter(|instrument| {
+                        instrument.base == update.balance.asset
+                            || instrument.quote == update.balance.asset
+                    })
+                    .map(|instrument| {
+                        Ok(MarketEvent {
+                            exchange_time: update.time,
+                            received_time: Utc::now(),
+                            received_monotonic: MonotonicTime::now(),
+                            origin: Origin::Live,
+                            exchange: Exchange::ID.into(),
+                            instrument: instrument.clone(),
+                            kind: update.balance.clone(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::binance::spot::BinanceSpot;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, symbol::Symbol},
+        SubscriptionId,
+    };
+
+    impl AccountMessage for Vec<BalanceUpdate> {}
+
+    #[test]
+    fn test_balance_transformer() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let mut transformer =
+            BalanceTransformer::<BinanceSpot, Vec<BalanceUpdate>>::from(Map::from_iter([
+                (SubscriptionId::from("@userData|BTCUSDT"), btc_usdt.clone()),
+                (SubscriptionId::from("@userData|ETHUSDT"), eth_usdt.clone()),
+            ]));
+
+        let update = |asset: &str| BalanceUpdate {
+            time: Utc::now(),
+            balance: Balance {
+                asset: Symbol::new(asset),
+                total: 1.0,
+                free: 0.5,
+            },
+        };
+
+        struct TestCase {
+            input: BalanceUpdate,
+            expected: Vec<Instrument>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: base asset of one subscribed Instrument
+                input: update("BTC"),
+                expected: vec![btc_usdt.clone()],
+            },
+            TestCase {
+                // TC1: quote asset shared by both subscribed Instruments
+                input: update("USDT"),
+                expected: vec![btc_usdt, eth_usdt],
+            },
+            TestCase {
+                // TC2: asset of no subscribed Instrument
+                input: update("BNB"),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut actual = transformer
+                .transform(vec![test.input])
+                .into_iter()
+                .map(|event| event.unwrap().instrument)
+                .collect::<Vec<_>>();
+            actual.sort();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -2,19 +2,21 @@ use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::Connector,
-    subscription::{book::OrderBook, Map, SubKind},
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide},
+        Map, SubKind,
+    },
     transformer::ExchangeTransformer,
-    Identifier,
+    Identifier, OutboundTx,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    model::{Instrument, SubscriptionId},
-    protocol::websocket::WsMessage,
+    model::{instrument::Instrument, Side, SubscriptionId},
     Transformer,
 };
+use futures::future::Either;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
-use tokio::sync::mpsc;
+use std::{collections::HashSet, marker::PhantomData};
 
 /// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`].
 #[async_trait]
@@ -25,22 +27,62 @@ where
     type OrderBook;
     type Update;
 
+    /// Determines if [`Self::init`] fetches the initial full [`Self::OrderBook`] snapshot (eg/ via
+    /// HTTP), rather than the exchange sending it over the WebSocket.
+    const INIT_SNAPSHOT: bool = false;
+
     /// Initialises the [`InstrumentOrderBook`] for the provided [`Instrument`]. This often requires
     /// a HTTP call to receive a starting [`OrderBook`] snapshot.
     async fn init<Exchange, Kind>(
-        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        outbound_tx: OutboundTx,
         instrument: Instrument,
     ) -> Result<InstrumentOrderBook<Self>, DataError>
     where
         Exchange: Send,
         Kind: Send;
 
-    /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`].
-    fn update(
+    /// Initialises the [`InstrumentOrderBook`] of a partial depth subscription (eg/ Binance
+    /// "@depth5@100ms"), where every update is a full snapshot that replaces the local
+    /// [`Self::OrderBook`], so [`Self::init`] is not required (eg/ no HTTP snapshot request).
+    ///
+    /// Defaults to `None`, since most subscriptions are initialised via [`Self::init`].
+    fn init_partial(
+        _subscription_id: &SubscriptionId,
+        _instrument: &Instrument,
+    ) -> Option<InstrumentOrderBook<Self>> {
+        None
+    }
+
+    /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`], returning the
+    /// [`BookUpdate`] that was applied, or `None` if the update was dropped.
+    fn apply(
         &mut self,
         book: &mut Self::OrderBook,
         update: Self::Update,
-    ) -> Result<Option<Self::OrderBook>, DataError>;
+    ) -> Result<Option<BookUpdate>, DataError>;
+
+    /// Apply the [`Self::Update`] to the provided mutable [`OrderBook`], returning the updated
+    /// [`OrderBook::snapshot`], or `None` if the update was dropped.
+    fn update(
+        &mut self,
+        book: &mut OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<OrderBook>, DataError>
+    where
+        Self: OrderBookUpdater<OrderBook = OrderBook>,
+    {
+        self.apply(book, update)
+            .map(|applied| applied.map(|_| book.snapshot()))
+    }
+}
+
+/// [`OrderBookUpdater::apply`] outcome of an update to the local [`OrderBook`].
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub enum BookUpdate {
+    /// The local [`OrderBook`] was replaced by a full snapshot.
+    Snapshot,
+    /// The delta [`Level`]s were upserted into the local [`OrderBook`].
+    Delta { bids: Vec<Level>, asks: Vec<Level> },
 }
 
 /// [`OrderBook`] for an [`Instrument`] with an exchange specific [`OrderBookUpdater`] to define
@@ -55,9 +97,17 @@ pub struct InstrumentOrderBook<Updater> {
 /// Standard generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
 /// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookUpdater`]
 /// implementation.
+///
+/// Subscriptions that require deltas (see [`SubKind::deltas`]) yield only the [`Level`]s of each
+/// [`BookUpdate::Delta`], rather than a full [`OrderBook::snapshot`].
+///
+/// Partial depth subscriptions (see [`OrderBookUpdater::init_partial`]) are initialised by the
+/// first snapshot received over the WebSocket.
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct MultiBookTransformer<Exchange, Kind, Updater> {
     pub book_map: Map<InstrumentOrderBook<Updater>>,
+    pub deltas: HashSet<SubscriptionId>,
+    pub partial: HashSet<SubscriptionId>,
     phantom: PhantomData<(Exchange, Kind)>,
 }
 
@@ -70,19 +120,25 @@ where
     Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send,
     Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
 {
-    async fn new(
-        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
-        map: Map<Instrument>,
-    ) -> Result<Self, DataError> {
-        // Initialise InstrumentOrderBooks for all Subscriptions
+    async fn new(outbound_tx: OutboundTx, map: Map<Instrument>) -> Result<Self, DataError> {
+        // Initialise InstrumentOrderBooks for all Subscriptions, only requesting an initial
+        // OrderBook snapshot for those that are not partial depth subscriptions
+        let mut partial = HashSet::new();
         let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
             .0
             .into_iter()
             .map(|(sub_id, instrument)| {
-                (
-                    sub_id,
-                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
-                )
+                let request = match Updater::init_partial(&sub_id, &instrument) {
+                    Some(book) => {
+                        partial.insert(sub_id.clone());
+                        Either::Left(futures::future::ready(Ok(book)))
+                    }
+                    None => Either::Right(Updater::init::<Exchange, Kind>(
+                        outbound_tx.clone(),
+                        instrument,
+                    )),
+                };
+                (sub_id, request)
             })
             .unzip();
 
@@ -95,14 +151,57 @@ where
         // Construct OrderBookMap if all requests successful
         let book_map = sub_ids
             .into_iter()
-            .zip(init_order_books.into_iter())
+            .zip(init_order_books)
             .collect::<Map<InstrumentOrderBook<Updater>>>();
 
         Ok(Self {
             book_map,
-            phantom: PhantomData::default(),
+            deltas: HashSet::new(),
+            partial,
+            phantom: PhantomData,
         })
     }
+
+    fn initial_events(
+        &mut self,
+        instruments: &[Instrument],
+    ) -> Vec<Result<MarketEvent<Kind::Event>, DataError>> {
+        // Initial OrderBooks received over the WebSocket are already yielded first
+        if !Updater::INIT_SNAPSHOT {
+            return Vec::new();
+        }
+
+        self.book_map
+            .0
+            .iter_mut()
+            .filter(|(subscription_id, book)| {
+                !self.partial.contains(*subscription_id) && instruments.contains(&book.instrument)
+            })
+            .map(|(_, book)| book)
+            .flat_map(
+                |InstrumentOrderBook {
+                     instrument, book, ..
+                 }| {
+                    MarketIter::<OrderBook>::from((
+                        Exchange::ID,
+                        instrument.clone(),
+                        book.snapshot(),
+                    ))
+                    .0
+                },
+            )
+            .collect()
+    }
+
+    fn yield_deltas(&mut self, instruments: &[Instrument]) {
+        self.deltas = self
+            .book_map
+            .0
+            .iter()
+            .filter(|(_, book)| instruments.contains(&book.instrument))
+            .map(|(subscription_id, _)| subscription_id.clone())
+            .collect();
+    }
 }
 
 impl<Exchange, Kind, Updater> Transformer for MultiBookTransformer<Exchange, Kind, Updater>
@@ -124,6 +223,9 @@ where
             None => return vec![],
         };
 
+        // Determine if this subscription yields deltas rather than full OrderBook snapshots
+        let deltas = self.deltas.contains(&subscription_id);
+
         // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
         let book = match self.book_map.find_mut(&subscription_id) {
             Ok(book) => book,
@@ -137,13 +239,215 @@ where
             updater,
         } = book;
 
-        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
-        match updater.update(book, update) {
-            Ok(Some(book)) => {
-                MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), book)).0
-            }
-            Ok(None) => vec![],
-            Err(error) => vec![Err(error)],
+        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot, or
+        // delta if required
+        let output = match updater.apply(book, update) {
+            Ok(Some(BookUpdate::Delta { bids, asks })) if deltas => OrderBook {
+                last_update_time: book.last_update_time,
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+                sequence: book.sequence,
+                delta: true,
+            },
+            Ok(Some(_)) => book.snapshot(),
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        };
+
+        MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), output)).0
+    }
+}
+
+/// Bitwise CRC-32 (IEEE 802.3) of the provided bytes, as used by exchange OrderBook checksums
+/// (eg/ Kraken).
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        (0..8).fold(crc ^ u32::from(*byte), |crc, _| {
+            (crc >> 1) ^ (0xEDB8_8320 & (!(crc & 1)).wrapping_add(1))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::binance::{
+        book::{
+            l2::{
+                BinanceOrderBookL2Partial, BinanceOrderBookL2PartialData, BinanceOrderBookL2Update,
+            },
+            BinanceLevel,
+        },
+        spot::{
+            l2::{BinanceSpotBookUpdater, BinanceSpotOrderBookL2Delta},
+            BinanceSpot,
+        },
+    };
+    use crate::subscription::book::OrderBooksL2;
+    use crate::OutboundQueue;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::Utc;
+
+    type SpotBookTransformer =
+        MultiBookTransformer<BinanceSpot, OrderBooksL2, BinanceSpotBookUpdater>;
+
+    fn snapshot() -> OrderBook {
+        OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(1.0, 1.0)]),
+            asks: OrderBookSide::new(Side::Sell, vec![Level::new(2.0, 1.0)]),
+            sequence: Some(100),
+            delta: false,
+        }
+    }
+
+    fn transformer(instruments: &[&Instrument], snapshot: &OrderBook) -> SpotBookTransformer {
+        MultiBookTransformer {
+            book_map: instruments
+                .iter()
+                .map(|instrument| {
+                    (
+                        SubscriptionId::from(format!(
+                            "@depth@100ms|{}{}",
+                            instrument.base.as_ref().to_uppercase(),
+                            instrument.quote.as_ref().to_uppercase()
+                        )),
+                        InstrumentOrderBook {
+                            instrument: (*instrument).clone(),
+                            updater: BinanceSpotBookUpdater::new(100),
+                            book: snapshot.clone(),
+                        },
+                    )
+                })
+                .collect(),
+            deltas: HashSet::new(),
+            partial: HashSet::new(),
+            phantom: PhantomData,
         }
     }
+
+    #[test]
+    fn test_initial_events() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let snapshot = snapshot();
+        let mut transformer = transformer(&[&btc_usdt, &eth_usdt], &snapshot);
+
+        // Only Instruments requiring an initial snapshot yield the initialised OrderBook
+        let actual = transformer.initial_events(std::slice::from_ref(&btc_usdt));
+        assert_eq!(actual.len(), 1);
+        let event = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(event.instrument, btc_usdt);
+        assert_eq!(event.kind, snapshot);
+
+        assert!(transformer.initial_events(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_yield_deltas() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let mut transformer = transformer(&[&btc_usdt, &eth_usdt], &snapshot());
+        transformer.yield_deltas(std::slice::from_ref(&btc_usdt));
+
+        let update = |market: &str| {
+            BinanceOrderBookL2Update::Delta(BinanceSpotOrderBookL2Delta {
+                subscription_id: SubscriptionId::from(format!("@depth@100ms|{market}")),
+                first_update_id: 101,
+                last_update_id: 101,
+                bids: vec![BinanceLevel {
+                    price: 1.5,
+                    amount: 2.0,
+                }],
+                asks: vec![],
+            })
+        };
+
+        // Subscription requiring deltas yields only the delta Levels & sequence
+        let delta = transformer
+            .transform(update("BTCUSDT"))
+            .remove(0)
+            .unwrap()
+            .kind;
+        assert!(delta.delta);
+        assert_eq!(delta.sequence, Some(101));
+        assert_eq!(delta.bids.levels(), &[Level::new(1.5, 2.0)]);
+        assert!(delta.asks.levels().is_empty());
+
+        // Other subscriptions yield the full OrderBook snapshot
+        let full = transformer
+            .transform(update("ETHUSDT"))
+            .remove(0)
+            .unwrap()
+            .kind;
+        assert!(!full.delta);
+        assert_eq!(full.sequence, Some(101));
+        assert_eq!(
+            full.bids.levels(),
+            &[Level::new(1.5, 2.0), Level::new(1.0, 1.0)]
+        );
+        assert_eq!(full.asks.levels(), &[Level::new(2.0, 1.0)]);
+    }
+
+    #[tokio::test]
+    async fn test_partial_depth_skips_initial_snapshot() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let subscription_id = SubscriptionId::from("@depth5@100ms|BTCUSDT");
+        let (outbound_tx, _outbound) = OutboundQueue::new();
+
+        // Diff depth subscriptions require an initial snapshot
+        assert!(BinanceSpotBookUpdater::init_partial(
+            &SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            &btc_usdt
+        )
+        .is_none());
+
+        // Partial depth subscriptions are initialised without a HTTP snapshot request
+        let mut transformer = SpotBookTransformer::new(
+            outbound_tx,
+            Map(std::collections::HashMap::from([(
+                subscription_id.clone(),
+                btc_usdt.clone(),
+            )])),
+        )
+        .await
+        .unwrap();
+        assert!(transformer.partial.contains(&subscription_id));
+
+        let book = transformer.book_map.find(&subscription_id).unwrap();
+        assert!(book.book.bids.levels().is_empty());
+        assert_eq!(book.book.sequence, None);
+
+        // Empty partial depth OrderBooks are not yielded as initial snapshots
+        assert!(transformer
+            .initial_events(std::slice::from_ref(&btc_usdt))
+            .is_empty());
+
+        // First partial depth snapshot received over the WebSocket initialises the OrderBook
+        let snapshot = transformer
+            .transform(BinanceOrderBookL2Update::Partial(
+                BinanceOrderBookL2Partial {
+                    subscription_id,
+                    data: BinanceOrderBookL2PartialData {
+                        last_update_id: 1,
+                        bids: vec![BinanceLevel {
+                            price: 1.0,
+                            amount: 1.0,
+                        }],
+                        asks: vec![],
+                    },
+                },
+            ))
+            .remove(0)
+            .unwrap()
+            .kind;
+        assert_eq!(snapshot.sequence, Some(1));
+        assert_eq!(snapshot.bids.levels(), &[Level::new(1.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
 }
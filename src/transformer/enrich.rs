@@ -0,0 +1,169 @@
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_integration::model::instrument::symbol::Symbol;
+use std::{fmt::Debug, sync::Arc};
+
+/// Quote assets treated as a stable USD reference by a [`TradeValuation`] by default.
+pub const DEFAULT_USD_QUOTES: &[&str] = &["usd", "usdt", "usdc", "busd", "fdusd", "tusd", "dai"];
+
+/// Shared [`Enricher`] of the [`MarketEvent<T>`](MarketEvent)s of every connection of a
+/// [`StreamBuilder`](crate::streams::builder::StreamBuilder).
+pub type SharedEnricher<T> = Arc<dyn Enricher<T>>;
+
+/// User-pluggable enrichment of each normalised [`MarketEvent<T>`](MarketEvent), applied by the
+/// [`consume`](crate::streams::consumer::consume_with) loop after the [`ExchangeTransformer`]
+/// and before the event is emitted (eg/ attach internal ids, convert the quote currency, tag
+/// the trading session).
+///
+/// Runs inline on the connection task, so it avoids an extra consumer hop but must be cheap &
+/// non-blocking.
+///
+/// Implemented for any closure `Fn(&mut MarketEvent<T>)`.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     event::MarketEvent,
+///     exchange::binance::spot::BinanceSpot,
+///     streams::Streams,
+///     subscription::trade::{PublicTrade, PublicTrades},
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// # async fn example() {
+/// // Convert usdt prices to usd at a fixed rate before emission
+/// let streams = Streams::<PublicTrades>::builder()
+///     .with_enricher(|event: &mut MarketEvent<PublicTrade>| event.kind.price *= 0.9995)
+///     .subscribe([(BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades)])
+///     .init()
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+///
+/// [`ExchangeTransformer`]: super::ExchangeTransformer
+pub trait Enricher<T>
+where
+    Self: Send + Sync,
+{
+    /// Enrich the normalised [`MarketEvent<T>`](MarketEvent) in place.
+    fn enrich(&self, event: &mut MarketEvent<T>);
+}
+
+impl<T, F> Enricher<T> for F
+where
+    F: Fn(&mut MarketEvent<T>) + Send + Sync,
+{
+    fn enrich(&self, event: &mut MarketEvent<T>) {
+        (self)(event)
+    }
+}
+
+impl<T> Debug for dyn Enricher<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Enricher")
+    }
+}
+
+/// [`Enricher`] computing the [`PublicTrade::notional`] (`price * amount`) of every trade, and
+/// the approximate [`PublicTrade::usd_value`] of trades quoted in a stable USD reference asset,
+/// so downstream consumers don't duplicate the calculation.
+///
+/// Quote assets are matched by [`Symbol`], so exchange specific asset codes should be normalised
+/// beforehand (see [`AssetNormaliser`](crate::normalise::AssetNormaliser)). Trades quoted in
+/// other currencies can be re-denominated via a
+/// [`QuoteConverter`](crate::streams::convert::QuoteConverter).
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     exchange::binance::spot::BinanceSpot,
+///     streams::Streams,
+///     subscription::trade::PublicTrades,
+///     transformer::enrich::TradeValuation,
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// # async fn example() {
+/// let streams = Streams::<PublicTrades>::builder()
+///     .with_enricher(TradeValuation::default())
+///     .subscribe([(BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades)])
+///     .init()
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TradeValuation {
+    usd_quotes: Vec<Symbol>,
+}
+
+impl Default for TradeValuation {
+    fn default() -> Self {
+        Self::new(DEFAULT_USD_QUOTES.iter().copied())
+    }
+}
+
+impl TradeValuation {
+    /// Construct a new [`Self`] treating the provided quote assets as a stable USD reference.
+    pub fn new<Iter, S>(usd_quotes: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = S>,
+        S: Into<Symbol>,
+    {
+        Self {
+            usd_quotes: usd_quotes.into_iter().map(S::into).collect(),
+        }
+    }
+}
+
+impl Enricher<PublicTrade> for TradeValuation {
+    fn enrich(&self, event: &mut MarketEvent<PublicTrade>) {
+        let notional = event.kind.price * event.kind.amount;
+        event.kind.notional = Some(notional);
+        if self.usd_quotes.contains(&event.instrument.quote) {
+            event.kind.usd_value = Some(notional);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixture, subscription::trade::TradeId};
+    use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+
+    fn trade(quote: &str) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            instrument: Instrument::from(("eth", quote, InstrumentKind::Spot)),
+            ..fixture::trade_event(TradeId::None, 2.5, 4.0)
+        }
+    }
+
+    #[test]
+    fn test_trade_valuation() {
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: (Option<f64>, Option<f64>),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trade quoted in a stable USD reference
+                input: trade("usdt"),
+                expected: (Some(10.0), Some(10.0)),
+            },
+            TestCase {
+                // TC1: trade quoted in a non USD asset only has a notional
+                input: trade("btc"),
+                expected: (Some(10.0), None),
+            },
+        ];
+
+        let valuation = TradeValuation::default();
+        for (index, mut test) in tests.into_iter().enumerate() {
+            valuation.enrich(&mut test.input);
+            let actual = (test.input.kind.notional, test.input.kind.usd_value);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::event::MarketEvent;
+use std::{fmt::Debug, sync::Arc};
+
+/// Shared [`EventFilter`] of the [`MarketEvent<T>`](MarketEvent)s of one batch of
+/// [`Subscription`](crate::subscription::Subscription)s.
+pub type SharedFilter<T> = Arc<dyn EventFilter<T>>;
+
+/// User-pluggable predicate of each normalised [`MarketEvent<T>`](MarketEvent), applied by the
+/// [`consume`](crate::streams::consumer::consume_with) loop before the event is sent to the
+/// channel, so discarded events never reach a consumer (eg/ only trades above a notional
+/// threshold, only book updates crossing the mid price).
+///
+/// Applied before any [`Enricher`](super::enrich::Enricher), and runs inline on the connection
+/// task so it must be cheap & non-blocking.
+///
+/// Implemented for any closure `Fn(&MarketEvent<T>) -> bool`.
+///
+/// ### Examples
+/// ```rust,no_run
+/// use barter_data::{
+///     event::MarketEvent,
+///     exchange::binance::spot::BinanceSpot,
+///     streams::{config::StreamConfig, Streams},
+///     subscription::trade::{PublicTrade, PublicTrades},
+/// };
+/// use barter_integration::model::instrument::kind::InstrumentKind;
+///
+/// # async fn example() {
+/// // Only forward btc_usdt trades with a notional of at least 100k usdt
+/// let streams = Streams::<PublicTrades>::builder()
+///     .subscribe_filtered(
+///         [(BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades)],
+///         StreamConfig::default(),
+///         |trade: &MarketEvent<PublicTrade>| trade.kind.price * trade.kind.amount >= 100_000.0,
+///     )
+///     .init()
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub trait EventFilter<T>
+where
+    Self: Send + Sync,
+{
+    /// Determine if the normalised [`MarketEvent<T>`](MarketEvent) should be forwarded.
+    fn retain(&self, event: &MarketEvent<T>) -> bool;
+}
+
+impl<T, F> EventFilter<T> for F
+where
+    F: Fn(&MarketEvent<T>) -> bool + Send + Sync,
+{
+    fn retain(&self, event: &MarketEvent<T>) -> bool {
+        (self)(event)
+    }
+}
+
+impl<T> Debug for dyn EventFilter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EventFilter")
+    }
+}
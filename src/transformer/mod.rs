@@ -2,18 +2,41 @@ use crate::{
     error::DataError,
     event::MarketEvent,
     subscription::{Map, SubKind},
+    OutboundTx,
 };
 use async_trait::async_trait;
-use barter_integration::{model::Instrument, protocol::websocket::WsMessage, Transformer};
-use tokio::sync::mpsc;
+use barter_integration::{model::instrument::Instrument, Transformer};
+
+/// Generic stateless [`ExchangeTransformer`] for private account streams (eg/
+/// [`UserTrades`](crate::subscription::trade::UserTrades)). Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub mod account;
+
+/// [`ExchangeTransformer`] for private [`Balances`](crate::subscription::account::Balances)
+/// streams, which resolves each asset balance to the subscribed [`Instrument`]s it applies to.
+/// Enabled via the `private` feature.
+#[cfg(feature = "private")]
+pub mod balance;
 
 /// Generic OrderBook [`ExchangeTransformer`]s.
 pub mod book;
 
+/// User-pluggable [`Enricher`](enrich::Enricher) of each normalised
+/// [`MarketEvent`], applied before emission.
+pub mod enrich;
+
+/// User-pluggable [`EventFilter`](filter::EventFilter) predicate of each normalised
+/// [`MarketEvent`], applied before the channel send.
+pub mod filter;
+
 /// Generic stateless [`ExchangeTransformer`] often used for transforming
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
 pub mod stateless;
 
+/// Generic [`ExchangeTransformer`] for [`SystemStatus`](crate::subscription::status::SystemStatus)
+/// streams, which resolves each exchange wide status update to every subscribed [`Instrument`].
+pub mod status;
+
 /// Defines how to construct a [`Transformer`] used by [`MarketStream`](super::MarketStream)s to
 /// translate exchange specific types to normalised Barter types.
 #[async_trait]
@@ -24,9 +47,28 @@ where
 {
     /// Construct a new [`Self`].
     ///
-    /// The [`mpsc::UnboundedSender`] can be used by [`Self`] to send messages back to the exchange.
+    /// The [`OutboundTx`] can be used by [`Self`] to send messages back to the exchange, using the
+    /// control lane for high priority messages (eg/ unsubscribe, resubscribe), and the bulk lane
+    /// for everything else (eg/ pongs).
     async fn new(
-        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        outbound_tx: OutboundTx,
         instrument_map: Map<Instrument>,
     ) -> Result<Self, DataError>;
+
+    /// [`MarketEvent`]s to yield before any transformed exchange messages, for the provided
+    /// subscribed [`Instrument`]s that require an initial snapshot (see
+    /// [`SubKind::initial_snapshot`]).
+    ///
+    /// Defaults to none, since most exchanges send the initial snapshot over the WebSocket.
+    fn initial_events(
+        &mut self,
+        _instruments: &[Instrument],
+    ) -> Vec<Result<MarketEvent<Kind::Event>, DataError>> {
+        Vec::new()
+    }
+    /// Yield only the delta of each update for the provided subscribed [`Instrument`]s that
+    /// require deltas (see [`SubKind::deltas`]).
+    ///
+    /// Defaults to ignoring the request, since most [`SubKind`]s are not stateful.
+    fn yield_deltas(&mut self, _instruments: &[Instrument]) {}
 }
@@ -3,18 +3,17 @@ use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::{Connector, ExchangeId},
-    subscription::{Map, SubKind},
-    Identifier,
+    subscription::{route::RoutingTable, Map, SubKind},
+    Identifier, OutboundTx,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    model::{Instrument, SubscriptionId},
-    protocol::websocket::WsMessage,
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
     Transformer,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::marker::PhantomData;
-use tokio::sync::mpsc;
 
 /// Standard generic stateless [`ExchangeTransformer`] to translate exchange specific types into
 /// normalised Barter types. Often used with
@@ -22,7 +21,7 @@ use tokio::sync::mpsc;
 /// [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) streams.
 #[derive(Clone, Eq, PartialEq, Debug, Serialize)]
 pub struct StatelessTransformer<Exchange, Kind, Input> {
-    instrument_map: Map<Instrument>,
+    instrument_map: RoutingTable<Instrument>,
     phantom: PhantomData<(Exchange, Kind, Input)>,
 }
 
@@ -35,13 +34,10 @@ where
     Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
     MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input)>,
 {
-    async fn new(
-        _: mpsc::UnboundedSender<WsMessage>,
-        instrument_map: Map<Instrument>,
-    ) -> Result<Self, DataError> {
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
         Ok(Self {
-            instrument_map,
-            phantom: PhantomData::default(),
+            instrument_map: RoutingTable::from(instrument_map),
+            phantom: PhantomData,
         })
     }
 }
@@ -72,3 +68,51 @@ where
         }
     }
 }
+
+/// Translate a raw exchange payload into the normalised [`MarketIter`] of the provided
+/// [`Instrument`], via the same `Input` deserialisation & [`MarketIter`] conversion used by a
+/// [`StatelessTransformer`].
+///
+/// Synchronous and free of connection state, so it is suitable as a fuzzing entry point
+/// (eg/ cargo-fuzz harnesses) to harden exchange deserialisation against malformed payloads.
+///
+/// ### Examples
+/// ```rust
+/// use barter_data::{
+///     exchange::{binance::trade::BinanceTrade, ExchangeId},
+///     subscription::trade::PublicTrade,
+///     transformer::stateless::transform_raw,
+/// };
+/// use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+///
+/// let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+///
+/// let result = transform_raw::<BinanceTrade, PublicTrade>(
+///     ExchangeId::BinanceSpot,
+///     instrument,
+///     br#"{"e":"trade","s":"BTCUSDT","t":1,"p":"not a number"}"#,
+/// );
+///
+/// assert!(result.is_err());
+/// ```
+pub fn transform_raw<Input, Event>(
+    exchange: ExchangeId,
+    instrument: Instrument,
+    payload: &[u8],
+) -> Result<MarketIter<Event>, DataError>
+where
+    Input: Identifier<Option<SubscriptionId>> + DeserializeOwned,
+    MarketIter<Event>: From<(ExchangeId, Instrument, Input)>,
+{
+    let input = serde_json::from_slice::<Input>(payload).map_err(|error| {
+        DataError::Socket(SocketError::Deserialise {
+            error,
+            payload: String::from_utf8_lossy(payload).into_owned(),
+        })
+    })?;
+
+    // Exercise SubscriptionId identification, as a StatelessTransformer would
+    let _ = input.id();
+
+    Ok(MarketIter::from((exchange, instrument, input)))
+}
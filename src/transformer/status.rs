@@ -0,0 +1,172 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MonotonicTime, Origin},
+    exchange::Connector,
+    subscription::{
+        status::{ExchangeStatus, SystemStatus},
+        Map,
+    },
+    OutboundTx,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`ExchangeStatus`] update yielded by an exchange specific status message.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StatusUpdate {
+    pub time: DateTime<Utc>,
+    /// [`SubscriptionId`] of the market this status applies to, or `None` if it applies to the
+    /// whole exchange.
+    pub subscription_id: Option<SubscriptionId>,
+    pub status: ExchangeStatus,
+}
+
+/// [`ExchangeTransformer`] for [`SystemStatus`] streams.
+///
+/// Each exchange wide [`StatusUpdate`] is yielded once for every subscribed [`Instrument`], whereas
+/// market specific updates are only yielded for the associated [`Instrument`], and ignored if it
+/// was not subscribed to.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StatusTransformer<Exchange, Input> {
+    instrument_map: Map<Instrument>,
+    instruments: Vec<Instrument>,
+    phantom: PhantomData<(Exchange, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, SystemStatus>
+    for StatusTransformer<Exchange, Input>
+where
+    Exchange: Connector + Send,
+    Input: IntoIterator<Item = StatusUpdate> + for<'de> Deserialize<'de> + Send,
+{
+    async fn new(_: OutboundTx, instrument_map: Map<Instrument>) -> Result<Self, DataError> {
+        Ok(Self::from(instrument_map))
+    }
+}
+
+impl<Exchange, Input> From<Map<Instrument>> for StatusTransformer<Exchange, Input> {
+    fn from(instrument_map: Map<Instrument>) -> Self {
+        let mut instruments = instrument_map.0.values().cloned().collect::<Vec<_>>();
+        instruments.sort();
+        instruments.dedup();
+
+        Self {
+            instrument_map,
+            instruments,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Exchange, Input> Transformer for StatusTransformer<Exchange, Input>
+where
+    Exchange: Connector,
+    Input: IntoIterator<Item = StatusUpdate> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<ExchangeStatus>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .into_iter()
+            .flat_map(|update| {
+                let instruments = match &update.subscription_id {
+                    Some(subscription_id) => self
+                        .instrument_map
+                        .find(subscription_id)
+                        .ok()
+                        .into_iter()
+                        .collect(),
+                    None => self.instruments.clone(),
+                };
+
+                instruments
+                    .into_iter()
+                    .map(|instrument| {
+                        Ok(MarketEvent {
+                            exchange_time: update.time,
+                            received_time: Utc::now(),
+                            received_monotonic: MonotonicTime::now(),
+                            origin: Origin::Live,
+                            exchange: Exchange::ID.into(),
+                            instrument,
+                            kind: update.status.clone(),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::okx::Okx, subscription::status::TradingStatus};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_status_transformer() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let mut transformer = StatusTransformer::<Okx, Vec<StatusUpdate>>::from(Map::from_iter([
+            (SubscriptionId::from("status|BTC-USDT"), btc_usdt.clone()),
+            (SubscriptionId::from("status|ETH-USDT"), eth_usdt.clone()),
+        ]));
+
+        let status = |status| ExchangeStatus {
+            status,
+            message: None,
+            start_time: None,
+            end_time: None,
+        };
+
+        let actual = transformer
+            .transform(vec![
+                // Exchange wide update yielded for every Instrument
+                StatusUpdate {
+                    time: Utc::now(),
+                    subscription_id: None,
+                    status: status(TradingStatus::Maintenance),
+                },
+                // Market update yielded for the associated Instrument
+                StatusUpdate {
+                    time: Utc::now(),
+                    subscription_id: Some(SubscriptionId::from("status|ETH-USDT")),
+                    status: status(TradingStatus::CancelOnly),
+                },
+                // Market update for an un-subscribed market is ignored
+                StatusUpdate {
+                    time: Utc::now(),
+                    subscription_id: Some(SubscriptionId::from("status|SOL-USDT")),
+                    status: status(TradingStatus::Offline),
+                },
+            ])
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind.status)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (btc_usdt, TradingStatus::Maintenance),
+                (eth_usdt.clone(), TradingStatus::Maintenance),
+                (eth_usdt, TradingStatus::CancelOnly),
+            ]
+        );
+    }
+}
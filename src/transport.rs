@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{connect, WsError, WsMessage},
+};
+use futures::{
+    stream::{SplitSink, SplitStream},
+    Sink, Stream,
+};
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::{
+    client::IntoClientRequest,
+    http::{HeaderName, HeaderValue},
+};
+use url::Url;
+
+/// Bidirectional WebSocket frame transport an exchange connection is established over.
+///
+/// A [`Transport`] is any [`Stream`] of received [`WsMessage`]s that is also a [`Sink`] of
+/// outbound [`WsMessage`]s, so `send`, `next` & `close` are provided by the
+/// [`SinkExt`](futures::SinkExt) & [`StreamExt`](futures::StreamExt) extension traits. It is
+/// implemented for every such type, including a tokio-tungstenite
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket). Alternative WebSocket
+/// clients (eg/ fastwebsockets) can be used by adapting them to these traits.
+pub trait Transport
+where
+    Self: Stream<Item = Result<WsMessage, WsError>>
+        + Sink<WsMessage, Error = WsError>
+        + Send
+        + Unpin
+        + Debug,
+{
+}
+
+impl<T> Transport for T where
+    T: Stream<Item = Result<WsMessage, WsError>>
+        + Sink<WsMessage, Error = WsError>
+        + Send
+        + Unpin
+        + Debug
+{
+}
+
+/// Type erased [`Transport`] used by every exchange connection.
+pub type BoxTransport = Box<dyn Transport>;
+
+/// Receiving half of a split [`BoxTransport`].
+pub type TransportStream = SplitStream<BoxTransport>;
+
+/// Sending half of a split [`BoxTransport`].
+pub type TransportSink = SplitSink<BoxTransport, WsMessage>;
+
+/// Defines how to connect a [`Transport`] to an exchange server [`Url`].
+///
+/// Configured per connection via
+/// [`StreamConfig::with_transport`](crate::streams::config::StreamConfig::with_transport),
+/// otherwise the [`TungsteniteConnector`] is used.
+#[async_trait]
+pub trait TransportConnector: Debug + Send + Sync {
+    async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError>;
+}
+
+/// Default [`TransportConnector`] that connects a tokio-tungstenite
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) with the default
+/// configuration.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct TungsteniteConnector;
+
+#[async_trait]
+impl TransportConnector for TungsteniteConnector {
+    async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+        connect(url)
+            .await
+            .map(|websocket| Box::new(websocket) as BoxTransport)
+    }
+}
+
+/// [`TransportConnector`] that connects a tokio-tungstenite
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) with additional HTTP headers
+/// in the handshake request (eg/ an API key header required by the exchange).
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct HeaderConnector {
+    pub headers: Vec<(String, String)>,
+}
+
+impl HeaderConnector {
+    /// Add a header to the WebSocket handshake request.
+    pub fn with_header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+#[async_trait]
+impl TransportConnector for HeaderConnector {
+    async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+        let mut request = url.as_str().into_client_request()?;
+
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|error| {
+                SocketError::Subscribe(format!("invalid header name {name}: {error}"))
+            })?;
+            let value = HeaderValue::from_str(value).map_err(|error| {
+                SocketError::Subscribe(format!("invalid header value for {name}: {error}"))
+            })?;
+            request.headers_mut().insert(name, value);
+        }
+
+        connect(request)
+            .await
+            .map(|websocket| Box::new(websocket) as BoxTransport)
+    }
+}
+
+/// Cheaply cloneable [`TransportConnector`] shared by every connection initialised from the same
+/// [`StreamConfig`](crate::streams::config::StreamConfig).
+#[derive(Clone)]
+pub struct SharedTransport(Arc<dyn TransportConnector>);
+
+impl SharedTransport {
+    /// Construct a new [`Self`] from the provided [`TransportConnector`].
+    pub fn new<Connector>(connector: Connector) -> Self
+    where
+        Connector: TransportConnector + 'static,
+    {
+        Self(Arc::new(connector))
+    }
+
+    /// Connect a [`Transport`] to the provided exchange server [`Url`].
+    pub async fn connect(&self, url: Url) -> Result<BoxTransport, SocketError> {
+        self.0.connect(url).await
+    }
+}
+
+impl Debug for SharedTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedTransport").field(&self.0).finish()
+    }
+}
+
+impl PartialEq for SharedTransport {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedTransport {}
+
+/// In-memory [`Transport`] where every [`WsMessage`] sent is received by its paired
+/// [`ChannelTransport`], useful for testing exchange integrations without a network connection.
+#[derive(Debug)]
+pub struct ChannelTransport {
+    tx: Option<mpsc::UnboundedSender<WsMessage>>,
+    rx: mpsc::UnboundedReceiver<WsMessage>,
+}
+
+impl ChannelTransport {
+    /// Construct a connected pair of [`ChannelTransport`]s (eg/ a client & a mock exchange).
+    pub fn pair() -> (Self, Self) {
+        let (a_tx, b_rx) = mpsc::unbounded_channel();
+        let (b_tx, a_rx) = mpsc::unbounded_channel();
+        (
+            Self {
+                tx: Some(a_tx),
+                rx: a_rx,
+            },
+            Self {
+                tx: Some(b_tx),
+                rx: b_rx,
+            },
+        )
+    }
+}
+
+impl Stream for ChannelTransport {
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx).map(|message| message.map(Ok))
+    }
+}
+
+impl Sink<WsMessage> for ChannelTransport {
+    type Error = WsError;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: WsMessage) -> Result<(), Self::Error> {
+        self.tx
+            .as_ref()
+            .ok_or(WsError::AlreadyClosed)?
+            .send(item)
+            .map_err(|_| WsError::ConnectionClosed)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.tx = None;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, StreamSelector},
+        streams::config::StreamConfig,
+        subscription::{trade::PublicTrades, Subscription},
+        MarketStream,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use futures::{SinkExt, StreamExt};
+    use std::sync::Mutex;
+
+    /// [`TransportConnector`] that hands out a single pre-connected [`ChannelTransport`].
+    #[derive(Debug)]
+    struct OnceConnector(Mutex<Option<ChannelTransport>>);
+
+    #[async_trait]
+    impl TransportConnector for OnceConnector {
+        async fn connect(&self, _: Url) -> Result<BoxTransport, SocketError> {
+            self.0
+                .lock()
+                .unwrap()
+                .take()
+                .map(|transport| Box::new(transport) as BoxTransport)
+                .ok_or_else(|| SocketError::Subscribe("transport already connected".to_owned()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_over_custom_transport() {
+        let (client, mut exchange) = ChannelTransport::pair();
+        let config =
+            StreamConfig::default().with_transport(OnceConnector(Mutex::new(Some(client))));
+
+        // Mock exchange accepts the subscription, then publishes a trade
+        exchange
+            .send(WsMessage::Text(r#"{"result":null,"id":1}"#.to_owned()))
+            .await
+            .unwrap();
+        exchange
+            .send(WsMessage::Text(
+                r#"{
+                    "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,"p":"10000.19",
+                    "q":"0.239000","b":10108767791,"a":10108764858,"T":1649324825173,"m":false,
+                    "M":true
+                }"#
+                .to_owned(),
+            ))
+            .await
+            .unwrap();
+
+        let subscriptions = [Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let mut stream =
+            <BinanceSpot as StreamSelector<PublicTrades>>::Stream::init(&subscriptions, &config)
+                .await
+                .unwrap();
+
+        // Subscription request was sent over the custom transport
+        let request = exchange.next().await.unwrap().unwrap();
+        assert!(request.to_text().unwrap().contains("ethusdt@trade"));
+
+        let trade = stream.next().await.unwrap().unwrap();
+        assert_eq!(trade.kind.price, 10000.19);
+        assert_eq!(trade.kind.amount, 0.239);
+    }
+}
@@ -0,0 +1,841 @@
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent, MonotonicTime, Origin},
+    subscription::{
+        book::{ArchivedLevel, Level, OrderBook, OrderBookL1, OrderBookSide},
+        candle::Candle,
+        greeks::Greeks,
+        liquidation::Liquidation,
+        ticker::{MiniTicker, Ticker},
+        trade::{PublicTrade, TradeId},
+    },
+};
+use barter_integration::model::{
+    instrument::{
+        kind::{FutureContract, InstrumentKind, OptionContract, OptionExercise, OptionKind},
+        Instrument,
+    },
+    Exchange, Side,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use rkyv::{
+    ser::{
+        serializers::{
+            AlignedSerializer, AllocScratch, CompositeSerializer, FallbackScratch, HeapScratch,
+            SharedSerializeMap,
+        },
+        Serializer,
+    },
+    with::{Map, RefAsBox},
+    AlignedVec, Archive,
+};
+use std::{collections::HashMap, io::Write};
+
+/// Size of the [`RecordEncoder`] stack scratch space, above which scratch space is allocated.
+const SCRATCH_SIZE: usize = 4096;
+
+/// Size of the [`RecordWriter`] frame header (the record length, padded to the record
+/// alignment).
+const FRAME_HEADER: usize = 16;
+
+/// Alignment of each framed record, matching the [`AlignedVec`] alignment.
+const FRAME_ALIGNMENT: usize = AlignedVec::ALIGNMENT;
+
+type RecordSerializer = CompositeSerializer<
+    AlignedSerializer<AlignedVec>,
+    FallbackScratch<HeapScratch<SCRATCH_SIZE>, AllocScratch>,
+    SharedSerializeMap,
+>;
+
+/// Zero-copy [`rkyv`] record of a [`MarketEvent<T>`], borrowing from the event so it can be
+/// serialised without intermediate per-event allocations.
+///
+/// Recorded bytes are read back without deserialising via [`access`], which yields an
+/// [`ArchivedEventRecord`] whose fields can be read directly from shared memory or disk.
+/// Timestamps are nanoseconds since the Unix epoch. The [`MarketEvent`] `received_monotonic`
+/// timestamp is omitted since it is only comparable within the process that generated it.
+#[derive(Debug, Archive, rkyv::Serialize)]
+#[archive(check_bytes)]
+pub struct EventRecord<'a> {
+    pub exchange_time: i64,
+    pub received_time: i64,
+    pub origin: OriginRecord,
+    #[with(RefAsBox)]
+    pub exchange: &'a str,
+    #[with(RefAsBox)]
+    pub base: &'a str,
+    #[with(RefAsBox)]
+    pub quote: &'a str,
+    pub instrument_kind: InstrumentKindRecord,
+    pub kind: KindRecord<'a>,
+}
+
+/// [`EventRecord`] representation of an [`InstrumentKind`].
+#[derive(Copy, Clone, PartialEq, Debug, Archive, rkyv::Serialize)]
+#[archive(check_bytes)]
+#[archive_attr(derive(Debug))]
+pub enum InstrumentKindRecord {
+    Spot,
+    Perpetual,
+    Future {
+        expiry: i64,
+    },
+    Option {
+        call: bool,
+        exercise: ExerciseRecord,
+        expiry: i64,
+        /// Serialised [`rust_decimal::Decimal`] strike.
+        strike: [u8; 16],
+    },
+}
+
+/// [`EventRecord`] representation of an [`Origin`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Archive, rkyv::Serialize)]
+#[archive(check_bytes)]
+#[archive_attr(derive(Debug))]
+pub enum OriginRecord {
+    Live,
+    Backfill,
+    SnapshotReplay,
+}
+
+/// [`EventRecord`] representation of an [`OptionExercise`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Archive, rkyv::Serialize)]
+#[archive(check_bytes)]
+#[archive_attr(derive(Debug))]
+pub enum ExerciseRecord {
+    American,
+    Bermudan,
+    European,
+}
+
+/// [`EventRecord`] representation of a [`TradeId`].
+#[derive(Debug, Archive, rkyv::Serialize)]
+#[archive(check_bytes)]
+pub enum TradeIdRecord<'a> {
+    U64(u64),
+    String(#[with(RefAsBox)] &'a str),
+    None,
+}
+
+/// [`EventRecord`] representation of the [`MarketEvent<T>`] kind.
+///
+/// Buy sides are recorded as `buy: true`, and sell sides as `buy: false`.
+#[derive(Debug, Archive, rkyv::Serialize)]
+#[archive(check_bytes)]
+pub enum KindRecord<'a> {
+    Trade {
+        id: TradeIdRecord<'a>,
+        price: f64,
+        amount: f64,
+        buy: bool,
+        side_inferred: bool,
+        block: bool,
+        #[with(Map<RefAsBox>)]
+        buyer_order_id: Option<&'a str>,
+        #[with(Map<RefAsBox>)]
+        seller_order_id: Option<&'a str>,
+        buyer_is_maker: Option<bool>,
+        notional: Option<f64>,
+        usd_value: Option<f64>,
+    },
+    OrderBookL1 {
+        last_update_time: i64,
+        best_bid: Level,
+        best_ask: Level,
+    },
+    OrderBook {
+        last_update_time: i64,
+        #[with(RefAsBox)]
+        bids: &'a [Level],
+        #[with(RefAsBox)]
+        asks: &'a [Level],
+        sequence: Option<u64>,
+        delta: bool,
+    },
+    Candle {
+        close_time: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+        trade_count: u64,
+    },
+    Liquidation {
+        buy: bool,
+        price: f64,
+        quantity: f64,
+        time: i64,
+    },
+    Greeks {
+        delta: f64,
+        gamma: f64,
+        vega: f64,
+        theta: f64,
+        mark_price: Option<f64>,
+        mark_iv: f64,
+        bid_iv: Option<f64>,
+        ask_iv: Option<f64>,
+        underlying_price: f64,
+    },
+    Ticker {
+        open_time: i64,
+        close_time: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        last: f64,
+        price_change: f64,
+        price_change_percent: f64,
+        weighted_avg_price: f64,
+        volume: f64,
+        quote_volume: f64,
+        trade_count: u64,
+    },
+    MiniTicker {
+        open: f64,
+        high: f64,
+        low: f64,
+        last: f64,
+        volume: f64,
+        quote_volume: f64,
+    },
+}
+
+/// [`MarketEvent<T>`] kind that can be recorded as a zero-copy [`KindRecord`].
+pub trait RecordKind {
+    /// Borrow the [`KindRecord`] representation of this kind.
+    fn record(&self) -> KindRecord<'_>;
+}
+
+impl RecordKind for PublicTrade {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::Trade {
+            id: match &self.id {
+                TradeId::U64(id) => TradeIdRecord::U64(*id),
+                TradeId::String(id) => TradeIdRecord::String(id),
+                TradeId::None => TradeIdRecord::None,
+            },
+            price: self.price,
+            amount: self.amount,
+            buy: self.side == Side::Buy,
+            side_inferred: self.side_inferred,
+            block: self.block,
+            buyer_order_id: self.buyer_order_id.as_deref(),
+            seller_order_id: self.seller_order_id.as_deref(),
+            buyer_is_maker: self.buyer_is_maker,
+            notional: self.notional,
+            usd_value: self.usd_value,
+        }
+    }
+}
+
+impl RecordKind for OrderBookL1 {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::OrderBookL1 {
+            last_update_time: nanos(self.last_update_time),
+            best_bid: self.best_bid,
+            best_ask: self.best_ask,
+        }
+    }
+}
+
+impl RecordKind for OrderBook {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::OrderBook {
+            last_update_time: nanos(self.last_update_time),
+            bids: self.bids.levels(),
+            asks: self.asks.levels(),
+            sequence: self.sequence,
+            delta: self.delta,
+        }
+    }
+}
+
+impl RecordKind for Candle {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::Candle {
+            close_time: nanos(self.close_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: self.trade_count,
+        }
+    }
+}
+
+impl RecordKind for Liquidation {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::Liquidation {
+            buy: self.side == Side::Buy,
+            price: self.price,
+            quantity: self.quantity,
+            time: nanos(self.time),
+        }
+    }
+}
+
+impl RecordKind for Greeks {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::Greeks {
+            delta: self.delta,
+            gamma: self.gamma,
+            vega: self.vega,
+            theta: self.theta,
+            mark_price: self.mark_price,
+            mark_iv: self.mark_iv,
+            bid_iv: self.bid_iv,
+            ask_iv: self.ask_iv,
+            underlying_price: self.underlying_price,
+        }
+    }
+}
+
+impl RecordKind for Ticker {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::Ticker {
+            open_time: nanos(self.open_time),
+            close_time: nanos(self.close_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            last: self.last,
+            price_change: self.price_change,
+            price_change_percent: self.price_change_percent,
+            weighted_avg_price: self.weighted_avg_price,
+            volume: self.volume,
+            quote_volume: self.quote_volume,
+            trade_count: self.trade_count,
+        }
+    }
+}
+
+impl RecordKind for MiniTicker {
+    fn record(&self) -> KindRecord<'_> {
+        KindRecord::MiniTicker {
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            last: self.last,
+            volume: self.volume,
+            quote_volume: self.quote_volume,
+        }
+    }
+}
+
+impl RecordKind for DataKind {
+    fn record(&self) -> KindRecord<'_> {
+        match self {
+            DataKind::Trade(trade) => trade.record(),
+            DataKind::OrderBookL1(book) => book.record(),
+            DataKind::OrderBook(book) => book.record(),
+            DataKind::Candle(candle) => candle.record(),
+            DataKind::Liquidation(liquidation) => liquidation.record(),
+            DataKind::Greeks(greeks) => greeks.record(),
+            DataKind::Ticker(ticker) => ticker.record(),
+            DataKind::MiniTicker(ticker) => ticker.record(),
+        }
+    }
+}
+
+impl<'a> EventRecord<'a> {
+    /// Construct a new [`Self`] borrowing from the provided [`MarketEvent<T>`] and exchange name.
+    pub fn new<T>(exchange: &'a str, event: &'a MarketEvent<T>) -> Self
+    where
+        T: RecordKind,
+    {
+        Self {
+            exchange_time: nanos(event.exchange_time),
+            received_time: nanos(event.received_time),
+            origin: OriginRecord::from(event.origin),
+            exchange,
+            base: event.instrument.base.as_ref(),
+            quote: event.instrument.quote.as_ref(),
+            instrument_kind: InstrumentKindRecord::from(&event.instrument.kind),
+            kind: event.kind.record(),
+        }
+    }
+}
+
+impl From<Origin> for OriginRecord {
+    fn from(origin: Origin) -> Self {
+        match origin {
+            Origin::Live => Self::Live,
+            Origin::Backfill => Self::Backfill,
+            Origin::SnapshotReplay => Self::SnapshotReplay,
+        }
+    }
+}
+
+impl From<&ArchivedOriginRecord> for Origin {
+    fn from(origin: &ArchivedOriginRecord) -> Self {
+        match origin {
+            ArchivedOriginRecord::Live => Self::Live,
+            ArchivedOriginRecord::Backfill => Self::Backfill,
+            ArchivedOriginRecord::SnapshotReplay => Self::SnapshotReplay,
+        }
+    }
+}
+
+impl From<&InstrumentKind> for InstrumentKindRecord {
+    fn from(kind: &InstrumentKind) -> Self {
+        match kind {
+            InstrumentKind::Spot => Self::Spot,
+            InstrumentKind::Perpetual => Self::Perpetual,
+            InstrumentKind::Future(future) => Self::Future {
+                expiry: nanos(future.expiry),
+            },
+            InstrumentKind::Option(option) => Self::Option {
+                call: option.kind == OptionKind::Call,
+                exercise: match option.exercise {
+                    OptionExercise::American => ExerciseRecord::American,
+                    OptionExercise::Bermudan => ExerciseRecord::Bermudan,
+                    OptionExercise::European => ExerciseRecord::European,
+                },
+                expiry: nanos(option.expiry),
+                strike: option.strike.serialize(),
+            },
+        }
+    }
+}
+
+/// Reusable serialiser of [`EventRecord`]s, retaining it's output buffer & scratch space (and the
+/// name of each exchange) between events so steady state encoding does not allocate.
+#[derive(Debug, Default)]
+pub struct RecordEncoder {
+    buffer: AlignedVec,
+    scratch: FallbackScratch<HeapScratch<SCRATCH_SIZE>, AllocScratch>,
+    exchanges: HashMap<Exchange, Box<str>>,
+}
+
+impl RecordEncoder {
+    /// Encode a [`MarketEvent<T>`] as an [`EventRecord`], returning the aligned bytes.
+    pub fn encode<T>(&mut self, event: &MarketEvent<T>) -> Result<&[u8], DataError>
+    where
+        T: RecordKind,
+    {
+        let exchange = self
+            .exchanges
+            .entry(event.exchange.clone())
+            .or_insert_with(|| event.exchange.to_string().into_boxed_str());
+        let record = EventRecord::new(exchange, event);
+
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+
+        let mut serializer = RecordSerializer::new(
+            AlignedSerializer::new(buffer),
+            std::mem::take(&mut self.scratch),
+            SharedSerializeMap::new(),
+        );
+        let result = serializer.serialize_value(&record);
+
+        let (serializer, scratch, _) = serializer.into_components();
+        self.buffer = serializer.into_inner();
+        self.scratch = scratch;
+
+        result.map_err(|error| DataError::Record(format!("{error:?}")))?;
+        Ok(self.buffer.as_slice())
+    }
+}
+
+/// Access the [`ArchivedEventRecord`] of the provided aligned bytes without deserialising,
+/// validating the bytes first.
+pub fn access(bytes: &[u8]) -> Result<&ArchivedEventRecord<'static>, DataError> {
+    rkyv::check_archived_root::<EventRecord<'static>>(bytes)
+        .map_err(|error| DataError::Record(error.to_string()))
+}
+
+/// Writer of framed [`EventRecord`]s (eg/ to a file or shared memory), readable with [`records`].
+///
+/// Each record is prefixed with a [`FRAME_HEADER`] containing it's little endian `u64` length,
+/// and padded so every record remains aligned when the written bytes are loaded into aligned
+/// memory.
+#[derive(Debug)]
+pub struct RecordWriter<W> {
+    writer: W,
+    encoder: RecordEncoder,
+}
+
+impl<W> RecordWriter<W>
+where
+    W: Write,
+{
+    /// Construct a new [`Self`] writing to the provided [`Write`]r.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            encoder: RecordEncoder::default(),
+        }
+    }
+
+    /// Encode & write a framed [`EventRecord`] of the provided [`MarketEvent<T>`].
+    pub fn write<T>(&mut self, event: &MarketEvent<T>) -> Result<(), DataError>
+    where
+        T: RecordKind,
+    {
+        let record = self.encoder.encode(event)?;
+
+        let mut header = [0u8; FRAME_HEADER];
+        header[..8].copy_from_slice(&(record.len() as u64).to_le_bytes());
+        let padding = [0u8; FRAME_ALIGNMENT];
+
+        self.writer
+            .write_all(&header)
+            .and_then(|_| self.writer.write_all(record))
+            .and_then(|_| self.writer.write_all(&padding[..padding_len(record.len())]))
+            .map_err(|error| DataError::Record(error.to_string()))
+    }
+
+    /// Flush & return the inner [`Write`]r.
+    pub fn into_inner(mut self) -> Result<W, DataError> {
+        self.writer
+            .flush()
+            .map_err(|error| DataError::Record(error.to_string()))?;
+        Ok(self.writer)
+    }
+}
+
+/// Iterate over the [`ArchivedEventRecord`]s of bytes written by a [`RecordWriter`], without
+/// deserialising. The bytes must be aligned to [`AlignedVec::ALIGNMENT`] (eg/ an [`AlignedVec`]
+/// or a memory mapped file).
+pub fn records(
+    bytes: &[u8],
+) -> impl Iterator<Item = Result<&ArchivedEventRecord<'static>, DataError>> {
+    let mut remaining = bytes;
+    std::iter::from_fn(move || {
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let Some((header, rest)) = remaining.split_at_checked(FRAME_HEADER) else {
+            remaining = &[];
+            return Some(Err(DataError::Record("truncated record header".to_owned())));
+        };
+
+        let len = u64::from_le_bytes(header[..8].try_into().unwrap_or_default()) as usize;
+        let frame = len + padding_len(len);
+        let Some((record, rest)) = rest.split_at_checked(frame) else {
+            remaining = &[];
+            return Some(Err(DataError::Record("truncated record".to_owned())));
+        };
+
+        remaining = rest;
+        Some(access(&record[..len]))
+    })
+}
+
+/// Padding required after a record of the provided length to keep the next frame aligned.
+fn padding_len(len: usize) -> usize {
+    (FRAME_ALIGNMENT - len % FRAME_ALIGNMENT) % FRAME_ALIGNMENT
+}
+
+impl TryFrom<&ArchivedEventRecord<'_>> for MarketEvent<DataKind> {
+    type Error = DataError;
+
+    /// Note: the `received_monotonic` timestamp is not recorded, so it is set to the default.
+    fn try_from(record: &ArchivedEventRecord<'_>) -> Result<Self, Self::Error> {
+        let kind = match &record.instrument_kind {
+            ArchivedInstrumentKindRecord::Spot => InstrumentKind::Spot,
+            ArchivedInstrumentKindRecord::Perpetual => InstrumentKind::Perpetual,
+            ArchivedInstrumentKindRecord::Future { expiry } => {
+                InstrumentKind::Future(FutureContract {
+                    expiry: datetime(*expiry),
+                })
+            }
+            ArchivedInstrumentKindRecord::Option {
+                call,
+                exercise,
+                expiry,
+                strike,
+            } => InstrumentKind::Option(OptionContract {
+                kind: match call {
+                    true => OptionKind::Call,
+                    false => OptionKind::Put,
+                },
+                exercise: match exercise {
+                    ArchivedExerciseRecord::American => OptionExercise::American,
+                    ArchivedExerciseRecord::Bermudan => OptionExercise::Bermudan,
+                    ArchivedExerciseRecord::European => OptionExercise::European,
+                },
+                expiry: datetime(*expiry),
+                strike: rust_decimal::Decimal::deserialize(*strike),
+            }),
+        };
+
+        Ok(Self {
+            exchange_time: datetime(record.exchange_time),
+            received_time: datetime(record.received_time),
+            received_monotonic: MonotonicTime::default(),
+            origin: Origin::from(&record.origin),
+            exchange: Exchange::from(record.exchange.to_string()),
+            instrument: Instrument::from((&*record.base, &*record.quote, kind)),
+            kind: DataKind::from(&record.kind),
+        })
+    }
+}
+
+impl From<&ArchivedKindRecord<'_>> for DataKind {
+    fn from(kind: &ArchivedKindRecord<'_>) -> Self {
+        match kind {
+            ArchivedKindRecord::Trade {
+                id,
+                price,
+                amount,
+                buy,
+                side_inferred,
+                block,
+                buyer_order_id,
+                seller_order_id,
+                buyer_is_maker,
+                notional,
+                usd_value,
+            } => Self::Trade(PublicTrade {
+                id: match id {
+                    ArchivedTradeIdRecord::U64(id) => TradeId::U64(*id),
+                    ArchivedTradeIdRecord::String(id) => TradeId::String(id.to_string()),
+                    ArchivedTradeIdRecord::None => TradeId::None,
+                },
+                price: *price,
+                amount: *amount,
+                side: side(*buy),
+                side_inferred: *side_inferred,
+                block: *block,
+                buyer_order_id: buyer_order_id.as_ref().map(|id| id.to_string()),
+                seller_order_id: seller_order_id.as_ref().map(|id| id.to_string()),
+                buyer_is_maker: buyer_is_maker.as_ref().copied(),
+                notional: notional.as_ref().copied(),
+                usd_value: usd_value.as_ref().copied(),
+            }),
+            ArchivedKindRecord::OrderBookL1 {
+                last_update_time,
+                best_bid,
+                best_ask,
+            } => Self::OrderBookL1(OrderBookL1 {
+                last_update_time: datetime(*last_update_time),
+                best_bid: level(best_bid),
+                best_ask: level(best_ask),
+            }),
+            ArchivedKindRecord::OrderBook {
+                last_update_time,
+                bids,
+                asks,
+                sequence,
+                delta,
+            } => Self::OrderBook(OrderBook {
+                last_update_time: datetime(*last_update_time),
+                bids: OrderBookSide::new(Side::Buy, bids.iter().map(level)),
+                asks: OrderBookSide::new(Side::Sell, asks.iter().map(level)),
+                sequence: sequence.as_ref().copied(),
+                delta: *delta,
+            }),
+            ArchivedKindRecord::Candle {
+                close_time,
+                open,
+                high,
+                low,
+                close,
+                volume,
+                trade_count,
+            } => Self::Candle(Candle {
+                close_time: datetime(*close_time),
+                open: *open,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: *volume,
+                trade_count: *trade_count,
+            }),
+            ArchivedKindRecord::Liquidation {
+                buy,
+                price,
+                quantity,
+                time,
+            } => Self::Liquidation(Liquidation {
+                side: side(*buy),
+                price: *price,
+                quantity: *quantity,
+                time: datetime(*time),
+            }),
+            ArchivedKindRecord::Greeks {
+                delta,
+                gamma,
+                vega,
+                theta,
+                mark_price,
+                mark_iv,
+                bid_iv,
+                ask_iv,
+                underlying_price,
+            } => Self::Greeks(Greeks {
+                delta: *delta,
+                gamma: *gamma,
+                vega: *vega,
+                theta: *theta,
+                mark_price: mark_price.as_ref().copied(),
+                mark_iv: *mark_iv,
+                bid_iv: bid_iv.as_ref().copied(),
+                ask_iv: ask_iv.as_ref().copied(),
+                underlying_price: *underlying_price,
+            }),
+            ArchivedKindRecord::Ticker {
+                open_time,
+                close_time,
+                open,
+                high,
+                low,
+                last,
+                price_change,
+                price_change_percent,
+                weighted_avg_price,
+                volume,
+                quote_volume,
+                trade_count,
+            } => Self::Ticker(Ticker {
+                open_time: datetime(*open_time),
+                close_time: datetime(*close_time),
+                open: *open,
+                high: *high,
+                low: *low,
+                last: *last,
+                price_change: *price_change,
+                price_change_percent: *price_change_percent,
+                weighted_avg_price: *weighted_avg_price,
+                volume: *volume,
+                quote_volume: *quote_volume,
+                trade_count: *trade_count,
+            }),
+            ArchivedKindRecord::MiniTicker {
+                open,
+                high,
+                low,
+                last,
+                volume,
+                quote_volume,
+            } => Self::MiniTicker(MiniTicker {
+                open: *open,
+                high: *high,
+                low: *low,
+                last: *last,
+                volume: *volume,
+                quote_volume: *quote_volume,
+            }),
+        }
+    }
+}
+
+/// Nanoseconds since the Unix epoch of a [`DateTime<Utc>`].
+fn nanos(time: DateTime<Utc>) -> i64 {
+    time.timestamp_nanos_opt().unwrap_or_default()
+}
+
+/// [`DateTime<Utc>`] of the provided nanoseconds since the Unix epoch.
+fn datetime(nanos: i64) -> DateTime<Utc> {
+    Utc.timestamp_nanos(nanos)
+}
+
+/// [`Side`] of a recorded `buy` flag.
+fn side(buy: bool) -> Side {
+    match buy {
+        true => Side::Buy,
+        false => Side::Sell,
+    }
+}
+
+/// [`Level`] of an [`ArchivedLevel`].
+fn level(level: &ArchivedLevel) -> Level {
+    Level::new(level.price, level.amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::market_event;
+
+    fn event(kind: DataKind) -> MarketEvent<DataKind> {
+        let time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            received_monotonic: MonotonicTime::default(),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            ..market_event(kind)
+        }
+    }
+
+    fn events() -> Vec<MarketEvent<DataKind>> {
+        vec![
+            event(DataKind::Trade(PublicTrade {
+                block: true,
+                buyer_order_id: Some("1".to_owned()),
+                buyer_is_maker: Some(false),
+                ..PublicTrade::new("abc", 100.0, 1.0, Side::Sell)
+            })),
+            event(DataKind::OrderBook(OrderBook {
+                last_update_time: Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 1).unwrap(),
+                bids: OrderBookSide::new(Side::Buy, vec![(100.0, 1.0), (99.0, 2.0)]),
+                asks: OrderBookSide::new(Side::Sell, vec![(101.0, 1.0)]),
+                sequence: Some(7),
+                delta: true,
+            })),
+            event(DataKind::Greeks(Greeks {
+                delta: 0.5,
+                gamma: 0.1,
+                vega: 10.0,
+                theta: -1.0,
+                mark_price: Some(5.0),
+                mark_iv: 0.6,
+                bid_iv: None,
+                ask_iv: Some(0.65),
+                underlying_price: 100.0,
+            })),
+            MarketEvent {
+                origin: Origin::Backfill,
+                ..event(DataKind::Trade(PublicTrade::new(1, 100.0, 1.0, Side::Buy)))
+            },
+            MarketEvent {
+                origin: Origin::SnapshotReplay,
+                ..event(DataKind::Trade(PublicTrade::new(2, 100.0, 1.0, Side::Buy)))
+            },
+        ]
+    }
+
+    #[test]
+    fn test_record_encoder_round_trip() {
+        let mut encoder = RecordEncoder::default();
+
+        for (index, test) in events().into_iter().enumerate() {
+            let bytes = encoder.encode(&test).unwrap();
+            let archived = access(bytes).unwrap();
+            assert_eq!(&*archived.exchange, "binance_spot", "TC{} failed", index);
+
+            let actual = MarketEvent::<DataKind>::try_from(archived).unwrap();
+            assert_eq!(actual, test, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_record_writer() {
+        let mut writer = RecordWriter::new(Vec::new());
+        for event in events() {
+            writer.write(&event).unwrap();
+        }
+        let written = writer.into_inner().unwrap();
+
+        // Load into aligned memory before zero-copy access
+        let mut bytes = AlignedVec::new();
+        bytes.extend_from_slice(&written);
+
+        let actual = records(&bytes)
+            .map(|record| MarketEvent::<DataKind>::try_from(record.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, events());
+
+        // Truncated bytes yield an error
+        let truncated = records(&bytes[..bytes.len() - FRAME_ALIGNMENT]).last();
+        assert!(matches!(truncated, Some(Err(DataError::Record(_)))));
+    }
+}